@@ -0,0 +1,58 @@
+CREATE TABLE guilds (
+    guild_id BIGINT PRIMARY KEY,
+    theme_color BIGINT,
+    theme_footer TEXT,
+    theme_thumbnail TEXT,
+    language TEXT
+);
+
+CREATE TABLE tiers (
+    guild_id BIGINT NOT NULL,
+    min_robux BIGINT NOT NULL,
+    -- GBP per 1,000 Robux.
+    rate DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (guild_id, min_robux)
+);
+
+CREATE TABLE priceboards (
+    guild_id BIGINT PRIMARY KEY,
+    channel_id BIGINT NOT NULL,
+    message_id BIGINT NOT NULL,
+    refresh_minutes BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL,
+    language TEXT NOT NULL
+);
+
+CREATE TABLE calculations (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT,
+    user_id BIGINT NOT NULL,
+    command TEXT NOT NULL,
+    robux BIGINT NOT NULL,
+    gbp DOUBLE PRECISION NOT NULL,
+    usd DOUBLE PRECISION NOT NULL,
+    rate DOUBLE PRECISION NOT NULL,
+    timestamp BIGINT NOT NULL
+);
+
+CREATE INDEX calculations_user ON calculations (user_id, id);
+
+CREATE TABLE alerts (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL,
+    guild_id BIGINT,
+    channel_id BIGINT NOT NULL,
+    pair TEXT NOT NULL,
+    threshold DOUBLE PRECISION NOT NULL,
+    direction TEXT NOT NULL,
+    language TEXT NOT NULL
+);
+
+CREATE INDEX alerts_user ON alerts (user_id);
+
+CREATE TABLE rate_history (
+    timestamp BIGINT NOT NULL,
+    gbp_to_usd DOUBLE PRECISION NOT NULL
+);
+
+CREATE INDEX rate_history_timestamp ON rate_history (timestamp);
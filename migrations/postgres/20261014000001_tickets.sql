@@ -0,0 +1,21 @@
+ALTER TABLE guilds ADD COLUMN ticket_category BIGINT;
+ALTER TABLE guilds ADD COLUMN ticket_staff_role BIGINT;
+ALTER TABLE guilds ADD COLUMN ticket_log_channel BIGINT;
+
+CREATE TABLE orders (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    buyer_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    robux BIGINT NOT NULL,
+    after_tax BOOLEAN NOT NULL,
+    method TEXT NOT NULL,
+    gbp DOUBLE PRECISION NOT NULL,
+    usd DOUBLE PRECISION NOT NULL,
+    rate DOUBLE PRECISION NOT NULL,
+    status TEXT NOT NULL,
+    created_at BIGINT NOT NULL,
+    closed_at BIGINT
+);
+
+CREATE INDEX orders_channel ON orders (channel_id);
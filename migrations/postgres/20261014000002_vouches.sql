@@ -0,0 +1,14 @@
+ALTER TABLE guilds ADD COLUMN vouch_channel BIGINT;
+
+CREATE TABLE vouches (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    order_id BIGINT NOT NULL UNIQUE,
+    buyer_id BIGINT NOT NULL,
+    seller_id BIGINT NOT NULL,
+    stars SMALLINT NOT NULL,
+    comment TEXT NOT NULL,
+    created_at BIGINT NOT NULL
+);
+
+CREATE INDEX vouches_seller ON vouches (guild_id, seller_id);
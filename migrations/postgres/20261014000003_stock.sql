@@ -0,0 +1,9 @@
+CREATE TABLE stock (
+    guild_id BIGINT NOT NULL,
+    seller_id BIGINT NOT NULL,
+    robux BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, seller_id)
+);
+
+ALTER TABLE orders ADD COLUMN seller_id BIGINT;
+ALTER TABLE orders ADD COLUMN completed_at BIGINT;
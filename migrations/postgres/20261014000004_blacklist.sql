@@ -0,0 +1,10 @@
+CREATE TABLE blacklist (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT,
+    user_id BIGINT NOT NULL,
+    reason TEXT NOT NULL,
+    added_by BIGINT NOT NULL,
+    created_at BIGINT NOT NULL
+);
+
+CREATE INDEX blacklist_user ON blacklist (user_id);
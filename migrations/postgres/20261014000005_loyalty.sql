@@ -0,0 +1,9 @@
+CREATE TABLE loyalty_discounts (
+    guild_id BIGINT NOT NULL,
+    -- Lifetime Robux purchased before the discount applies.
+    min_robux BIGINT NOT NULL,
+    percent DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (guild_id, min_robux)
+);
+
+CREATE INDEX orders_buyer ON orders (guild_id, buyer_id);
@@ -0,0 +1,12 @@
+CREATE TABLE coupons (
+    guild_id BIGINT NOT NULL,
+    code TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    value DOUBLE PRECISION NOT NULL,
+    max_uses BIGINT,
+    uses BIGINT NOT NULL DEFAULT 0,
+    expires_at BIGINT,
+    created_by BIGINT NOT NULL,
+    created_at BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, code)
+);
@@ -0,0 +1,5 @@
+ALTER TABLE guilds ADD COLUMN vat_rate DOUBLE PRECISION;
+ALTER TABLE guilds ADD COLUMN vat_region TEXT;
+
+-- VAT included in gbp, for the seller's records.
+ALTER TABLE orders ADD COLUMN tax_gbp DOUBLE PRECISION NOT NULL DEFAULT 0;
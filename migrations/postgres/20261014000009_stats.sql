@@ -0,0 +1 @@
+CREATE INDEX orders_completed ON orders (guild_id, completed_at);
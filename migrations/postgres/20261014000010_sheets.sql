@@ -0,0 +1,2 @@
+ALTER TABLE guilds ADD COLUMN sheets_spreadsheet_id TEXT;
+ALTER TABLE guilds ADD COLUMN sheets_sheet TEXT;
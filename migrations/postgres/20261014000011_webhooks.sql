@@ -0,0 +1,6 @@
+CREATE TABLE webhooks (
+    guild_id BIGINT NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    PRIMARY KEY (guild_id, url)
+);
@@ -0,0 +1,3 @@
+-- SHA-256 of the guild's HTTP API key.
+ALTER TABLE guilds ADD COLUMN api_key_hash TEXT;
+CREATE UNIQUE INDEX guilds_api_key_hash ON guilds (api_key_hash);
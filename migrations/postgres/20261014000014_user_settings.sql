@@ -0,0 +1,6 @@
+-- Per-user preferences that follow the user across guilds.
+CREATE TABLE user_settings (
+    user_id BIGINT PRIMARY KEY,
+    -- ISO 4217 code shown alongside GBP and USD.
+    currency TEXT
+);
@@ -0,0 +1,19 @@
+ALTER TABLE orders ADD COLUMN roblox_username TEXT;
+
+CREATE TABLE payouts (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    order_id BIGINT NOT NULL,
+    roblox_user_id BIGINT NOT NULL,
+    roblox_username TEXT NOT NULL,
+    robux BIGINT NOT NULL,
+    status TEXT NOT NULL,
+    requested_by BIGINT NOT NULL,
+    requested_at BIGINT NOT NULL,
+    reviewed_by BIGINT,
+    reviewed_at BIGINT,
+    transaction_id TEXT,
+    error TEXT
+);
+
+CREATE INDEX payouts_order ON payouts (order_id);
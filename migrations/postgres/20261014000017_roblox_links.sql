@@ -0,0 +1,15 @@
+-- Discord users' Roblox accounts, confirmed with /verify, and verifications in progress.
+CREATE TABLE roblox_links (
+    user_id BIGINT PRIMARY KEY,
+    roblox_user_id BIGINT NOT NULL,
+    roblox_username TEXT NOT NULL,
+    verified_at BIGINT NOT NULL
+);
+
+CREATE TABLE roblox_verifications (
+    user_id BIGINT PRIMARY KEY,
+    roblox_user_id BIGINT NOT NULL,
+    roblox_username TEXT NOT NULL,
+    phrase TEXT NOT NULL,
+    created_at BIGINT NOT NULL
+);
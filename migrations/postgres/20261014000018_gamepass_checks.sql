@@ -0,0 +1,14 @@
+-- Every /verifygamepass run on an order, so staff can see what was checked
+-- before paying.
+CREATE TABLE gamepass_checks (
+    id BIGSERIAL PRIMARY KEY,
+    order_id BIGINT NOT NULL,
+    gamepass_id BIGINT NOT NULL,
+    expected_price BIGINT NOT NULL,
+    price BIGINT,
+    owned_by_buyer BOOLEAN NOT NULL,
+    checked_by BIGINT NOT NULL,
+    checked_at BIGINT NOT NULL
+);
+
+CREATE INDEX gamepass_checks_order ON gamepass_checks (order_id);
@@ -0,0 +1,11 @@
+-- The 14-day wait before a group payout order's buyer can be paid.
+CREATE TABLE payout_holds (
+    order_id BIGINT PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    buyer_id BIGINT NOT NULL,
+    eligible_at BIGINT NOT NULL,
+    notified_at BIGINT
+);
+
+CREATE INDEX payout_holds_due ON payout_holds (eligible_at);
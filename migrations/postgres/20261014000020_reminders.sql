@@ -0,0 +1,14 @@
+CREATE TABLE reminders (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL,
+    guild_id BIGINT,
+    -- NULL to DM the user.
+    channel_id BIGINT,
+    note TEXT NOT NULL,
+    remind_at BIGINT NOT NULL,
+    created_at BIGINT NOT NULL,
+    language TEXT NOT NULL
+);
+
+CREATE INDEX reminders_due ON reminders (remind_at);
+CREATE INDEX reminders_user ON reminders (user_id);
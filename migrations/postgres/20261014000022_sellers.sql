@@ -0,0 +1,14 @@
+CREATE TABLE sellers (
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    rate DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (guild_id, user_id)
+);
+
+CREATE TABLE seller_payment_handles (
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    method TEXT NOT NULL,
+    handle TEXT NOT NULL,
+    PRIMARY KEY (guild_id, user_id, method)
+);
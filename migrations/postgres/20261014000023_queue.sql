@@ -0,0 +1,7 @@
+CREATE TABLE queue_entries (
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    robux BIGINT,
+    joined_at BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, user_id)
+);
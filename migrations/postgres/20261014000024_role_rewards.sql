@@ -0,0 +1,7 @@
+CREATE TABLE role_rewards (
+    guild_id BIGINT NOT NULL,
+    -- Lifetime Robux purchased before the role is given.
+    min_robux BIGINT NOT NULL,
+    role_id BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, min_robux)
+);
@@ -0,0 +1,2 @@
+ALTER TABLE guilds ADD COLUMN rate_announce_channel BIGINT;
+ALTER TABLE guilds ADD COLUMN rate_announce_role BIGINT;
@@ -0,0 +1,23 @@
+ALTER TABLE guilds ADD COLUMN dispute_channel BIGINT;
+
+CREATE TABLE disputes (
+    order_id BIGINT PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    opened_by BIGINT NOT NULL,
+    reason TEXT NOT NULL,
+    opened_at BIGINT NOT NULL
+);
+
+CREATE TABLE refunds (
+    id BIGSERIAL PRIMARY KEY,
+    order_id BIGINT NOT NULL,
+    guild_id BIGINT NOT NULL,
+    gbp DOUBLE PRECISION NOT NULL,
+    usd DOUBLE PRECISION NOT NULL,
+    reason TEXT NOT NULL,
+    refunded_by BIGINT NOT NULL,
+    refunded_at BIGINT NOT NULL
+);
+
+CREATE INDEX refunds_order ON refunds (order_id);
+CREATE INDEX refunds_guild ON refunds (guild_id, refunded_at);
@@ -0,0 +1,10 @@
+ALTER TABLE guilds ADD COLUMN ticket_middleman_role BIGINT;
+
+CREATE TABLE middleman_progress (
+    order_id BIGINT PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    received_by BIGINT NOT NULL,
+    received_at BIGINT NOT NULL,
+    released_by BIGINT,
+    released_at BIGINT
+);
@@ -0,0 +1,2 @@
+ALTER TABLE guilds ADD COLUMN rounding_mode TEXT NOT NULL DEFAULT 'nearest';
+ALTER TABLE guilds ADD COLUMN rounding_decimals BIGINT NOT NULL DEFAULT 2;
@@ -0,0 +1,2 @@
+ALTER TABLE guilds ADD COLUMN sell_rate DOUBLE PRECISION NOT NULL DEFAULT 3.5;
+ALTER TABLE guilds ADD COLUMN buy_rate DOUBLE PRECISION;
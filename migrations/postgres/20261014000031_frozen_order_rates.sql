@@ -0,0 +1,4 @@
+ALTER TABLE orders ADD COLUMN frozen_base_rate DOUBLE PRECISION;
+ALTER TABLE orders ADD COLUMN frozen_gbp_to_usd DOUBLE PRECISION;
+ALTER TABLE orders ADD COLUMN frozen_roblox_fee DOUBLE PRECISION;
+ALTER TABLE orders ADD COLUMN frozen_vat_percent DOUBLE PRECISION;
@@ -0,0 +1,7 @@
+CREATE TABLE role_discounts (
+    guild_id BIGINT NOT NULL,
+    role_id BIGINT NOT NULL,
+    -- Taken off quotes for members with the role.
+    percent DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (guild_id, role_id)
+);
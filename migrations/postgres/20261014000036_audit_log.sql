@@ -0,0 +1,14 @@
+ALTER TABLE guilds ADD COLUMN audit_channel BIGINT;
+
+CREATE TABLE audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    actor_id BIGINT NOT NULL,
+    action TEXT NOT NULL,
+    setting TEXT NOT NULL,
+    old_value TEXT,
+    new_value TEXT,
+    created_at BIGINT NOT NULL
+);
+
+CREATE INDEX audit_log_guild ON audit_log (guild_id, created_at);
@@ -0,0 +1,4 @@
+-- Days calculations and closed orders are kept before the purge job deletes
+-- them, set with /retention. NULL keeps them.
+ALTER TABLE guilds ADD COLUMN retention_history_days BIGINT;
+ALTER TABLE guilds ADD COLUMN retention_order_days BIGINT;
@@ -0,0 +1,6 @@
+-- Command groups that are off by default, turned on with /features.
+CREATE TABLE enabled_features (
+    guild_id BIGINT NOT NULL,
+    feature TEXT NOT NULL,
+    PRIMARY KEY (guild_id, feature)
+);
@@ -0,0 +1,14 @@
+-- Every change /setrates and /setrate make to a guild's rates, for
+-- /ratehistory. rate is sell, buy or forex; a NULL value is not buying or the
+-- market exchange rate.
+CREATE TABLE rate_changes (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    changed_by BIGINT NOT NULL,
+    rate TEXT NOT NULL,
+    previous DOUBLE PRECISION,
+    value DOUBLE PRECISION,
+    changed_at BIGINT NOT NULL
+);
+
+CREATE INDEX rate_changes_guild ON rate_changes (guild_id, changed_at);
@@ -0,0 +1,58 @@
+CREATE TABLE guilds (
+    guild_id INTEGER PRIMARY KEY NOT NULL,
+    theme_color INTEGER,
+    theme_footer TEXT,
+    theme_thumbnail TEXT,
+    language TEXT
+);
+
+CREATE TABLE tiers (
+    guild_id INTEGER NOT NULL,
+    min_robux INTEGER NOT NULL,
+    -- GBP per 1,000 Robux.
+    rate REAL NOT NULL,
+    PRIMARY KEY (guild_id, min_robux)
+);
+
+CREATE TABLE priceboards (
+    guild_id INTEGER PRIMARY KEY NOT NULL,
+    channel_id INTEGER NOT NULL,
+    message_id INTEGER NOT NULL,
+    refresh_minutes INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    language TEXT NOT NULL
+);
+
+CREATE TABLE calculations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id INTEGER,
+    user_id INTEGER NOT NULL,
+    command TEXT NOT NULL,
+    robux INTEGER NOT NULL,
+    gbp REAL NOT NULL,
+    usd REAL NOT NULL,
+    rate REAL NOT NULL,
+    timestamp INTEGER NOT NULL
+);
+
+CREATE INDEX calculations_user ON calculations (user_id, id);
+
+CREATE TABLE alerts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    user_id INTEGER NOT NULL,
+    guild_id INTEGER,
+    channel_id INTEGER NOT NULL,
+    pair TEXT NOT NULL,
+    threshold REAL NOT NULL,
+    direction TEXT NOT NULL,
+    language TEXT NOT NULL
+);
+
+CREATE INDEX alerts_user ON alerts (user_id);
+
+CREATE TABLE rate_history (
+    timestamp INTEGER NOT NULL,
+    gbp_to_usd REAL NOT NULL
+);
+
+CREATE INDEX rate_history_timestamp ON rate_history (timestamp);
@@ -0,0 +1,21 @@
+ALTER TABLE guilds ADD COLUMN ticket_category INTEGER;
+ALTER TABLE guilds ADD COLUMN ticket_staff_role INTEGER;
+ALTER TABLE guilds ADD COLUMN ticket_log_channel INTEGER;
+
+CREATE TABLE orders (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id INTEGER NOT NULL,
+    buyer_id INTEGER NOT NULL,
+    channel_id INTEGER NOT NULL,
+    robux INTEGER NOT NULL,
+    after_tax BOOLEAN NOT NULL,
+    method TEXT NOT NULL,
+    gbp REAL NOT NULL,
+    usd REAL NOT NULL,
+    rate REAL NOT NULL,
+    status TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    closed_at INTEGER
+);
+
+CREATE INDEX orders_channel ON orders (channel_id);
@@ -0,0 +1,14 @@
+ALTER TABLE guilds ADD COLUMN vouch_channel INTEGER;
+
+CREATE TABLE vouches (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id INTEGER NOT NULL,
+    order_id INTEGER NOT NULL UNIQUE,
+    buyer_id INTEGER NOT NULL,
+    seller_id INTEGER NOT NULL,
+    stars INTEGER NOT NULL,
+    comment TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX vouches_seller ON vouches (guild_id, seller_id);
@@ -0,0 +1,9 @@
+CREATE TABLE stock (
+    guild_id INTEGER NOT NULL,
+    seller_id INTEGER NOT NULL,
+    robux INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, seller_id)
+);
+
+ALTER TABLE orders ADD COLUMN seller_id INTEGER;
+ALTER TABLE orders ADD COLUMN completed_at INTEGER;
@@ -0,0 +1,10 @@
+CREATE TABLE blacklist (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id INTEGER,
+    user_id INTEGER NOT NULL,
+    reason TEXT NOT NULL,
+    added_by INTEGER NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX blacklist_user ON blacklist (user_id);
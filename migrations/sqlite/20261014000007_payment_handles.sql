@@ -0,0 +1,6 @@
+CREATE TABLE payment_handles (
+    guild_id INTEGER NOT NULL,
+    method TEXT NOT NULL,
+    handle TEXT NOT NULL,
+    PRIMARY KEY (guild_id, method)
+);
@@ -0,0 +1,5 @@
+ALTER TABLE guilds ADD COLUMN vat_rate REAL;
+ALTER TABLE guilds ADD COLUMN vat_region TEXT;
+
+-- VAT included in gbp, for the seller's records.
+ALTER TABLE orders ADD COLUMN tax_gbp REAL NOT NULL DEFAULT 0;
@@ -0,0 +1,5 @@
+CREATE TABLE autoreply_channels (
+    guild_id INTEGER NOT NULL,
+    channel_id INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, channel_id)
+);
@@ -0,0 +1,5 @@
+CREATE TABLE disabled_features (
+    guild_id INTEGER NOT NULL,
+    feature TEXT NOT NULL,
+    PRIMARY KEY (guild_id, feature)
+);
@@ -0,0 +1,19 @@
+ALTER TABLE orders ADD COLUMN roblox_username TEXT;
+
+CREATE TABLE payouts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id INTEGER NOT NULL,
+    order_id INTEGER NOT NULL,
+    roblox_user_id INTEGER NOT NULL,
+    roblox_username TEXT NOT NULL,
+    robux INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    requested_by INTEGER NOT NULL,
+    requested_at INTEGER NOT NULL,
+    reviewed_by INTEGER,
+    reviewed_at INTEGER,
+    transaction_id TEXT,
+    error TEXT
+);
+
+CREATE INDEX payouts_order ON payouts (order_id);
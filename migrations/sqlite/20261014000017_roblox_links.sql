@@ -0,0 +1,15 @@
+-- Discord users' Roblox accounts, confirmed with /verify, and verifications in progress.
+CREATE TABLE roblox_links (
+    user_id INTEGER PRIMARY KEY,
+    roblox_user_id INTEGER NOT NULL,
+    roblox_username TEXT NOT NULL,
+    verified_at INTEGER NOT NULL
+);
+
+CREATE TABLE roblox_verifications (
+    user_id INTEGER PRIMARY KEY,
+    roblox_user_id INTEGER NOT NULL,
+    roblox_username TEXT NOT NULL,
+    phrase TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
@@ -0,0 +1,14 @@
+-- Every /verifygamepass run on an order, so staff can see what was checked
+-- before paying.
+CREATE TABLE gamepass_checks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    order_id INTEGER NOT NULL,
+    gamepass_id INTEGER NOT NULL,
+    expected_price INTEGER NOT NULL,
+    price INTEGER,
+    owned_by_buyer BOOLEAN NOT NULL,
+    checked_by INTEGER NOT NULL,
+    checked_at INTEGER NOT NULL
+);
+
+CREATE INDEX gamepass_checks_order ON gamepass_checks (order_id);
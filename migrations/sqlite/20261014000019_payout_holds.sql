@@ -0,0 +1,11 @@
+-- The 14-day wait before a group payout order's buyer can be paid.
+CREATE TABLE payout_holds (
+    order_id INTEGER PRIMARY KEY,
+    guild_id INTEGER NOT NULL,
+    channel_id INTEGER NOT NULL,
+    buyer_id INTEGER NOT NULL,
+    eligible_at INTEGER NOT NULL,
+    notified_at INTEGER
+);
+
+CREATE INDEX payout_holds_due ON payout_holds (eligible_at);
@@ -0,0 +1,14 @@
+CREATE TABLE reminders (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    user_id INTEGER NOT NULL,
+    guild_id INTEGER,
+    -- NULL to DM the user.
+    channel_id INTEGER,
+    note TEXT NOT NULL,
+    remind_at INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    language TEXT NOT NULL
+);
+
+CREATE INDEX reminders_due ON reminders (remind_at);
+CREATE INDEX reminders_user ON reminders (user_id);
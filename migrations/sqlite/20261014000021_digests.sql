@@ -0,0 +1,11 @@
+CREATE TABLE digest_recipients (
+    guild_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, user_id)
+);
+
+-- The Monday each guild's digest was last sent for.
+CREATE TABLE digests_sent (
+    guild_id INTEGER PRIMARY KEY,
+    week_start INTEGER NOT NULL
+);
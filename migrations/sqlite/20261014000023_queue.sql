@@ -0,0 +1,7 @@
+CREATE TABLE queue_entries (
+    guild_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    robux INTEGER,
+    joined_at INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, user_id)
+);
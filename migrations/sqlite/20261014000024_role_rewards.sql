@@ -0,0 +1,7 @@
+CREATE TABLE role_rewards (
+    guild_id INTEGER NOT NULL,
+    -- Lifetime Robux purchased before the role is given.
+    min_robux INTEGER NOT NULL,
+    role_id INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, min_robux)
+);
@@ -0,0 +1,2 @@
+ALTER TABLE guilds ADD COLUMN rate_announce_channel INTEGER;
+ALTER TABLE guilds ADD COLUMN rate_announce_role INTEGER;
@@ -0,0 +1,23 @@
+ALTER TABLE guilds ADD COLUMN dispute_channel INTEGER;
+
+CREATE TABLE disputes (
+    order_id INTEGER PRIMARY KEY NOT NULL,
+    guild_id INTEGER NOT NULL,
+    opened_by INTEGER NOT NULL,
+    reason TEXT NOT NULL,
+    opened_at INTEGER NOT NULL
+);
+
+CREATE TABLE refunds (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    order_id INTEGER NOT NULL,
+    guild_id INTEGER NOT NULL,
+    gbp REAL NOT NULL,
+    usd REAL NOT NULL,
+    reason TEXT NOT NULL,
+    refunded_by INTEGER NOT NULL,
+    refunded_at INTEGER NOT NULL
+);
+
+CREATE INDEX refunds_order ON refunds (order_id);
+CREATE INDEX refunds_guild ON refunds (guild_id, refunded_at);
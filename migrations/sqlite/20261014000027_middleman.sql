@@ -0,0 +1,10 @@
+ALTER TABLE guilds ADD COLUMN ticket_middleman_role INTEGER;
+
+CREATE TABLE middleman_progress (
+    order_id INTEGER PRIMARY KEY NOT NULL,
+    guild_id INTEGER NOT NULL,
+    received_by INTEGER NOT NULL,
+    received_at INTEGER NOT NULL,
+    released_by INTEGER,
+    released_at INTEGER
+);
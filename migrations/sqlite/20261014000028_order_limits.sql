@@ -0,0 +1,2 @@
+ALTER TABLE guilds ADD COLUMN order_min_robux INTEGER;
+ALTER TABLE guilds ADD COLUMN order_max_robux INTEGER;
@@ -0,0 +1,4 @@
+ALTER TABLE orders ADD COLUMN frozen_base_rate REAL;
+ALTER TABLE orders ADD COLUMN frozen_gbp_to_usd REAL;
+ALTER TABLE orders ADD COLUMN frozen_roblox_fee REAL;
+ALTER TABLE orders ADD COLUMN frozen_vat_percent REAL;
@@ -0,0 +1,7 @@
+CREATE TABLE role_discounts (
+    guild_id INTEGER NOT NULL,
+    role_id INTEGER NOT NULL,
+    -- Taken off quotes for members with the role.
+    percent REAL NOT NULL,
+    PRIMARY KEY (guild_id, role_id)
+);
@@ -0,0 +1,17 @@
+CREATE TABLE embed_templates (
+    guild_id INTEGER NOT NULL,
+    command TEXT NOT NULL,
+    title TEXT,
+    description TEXT,
+    PRIMARY KEY (guild_id, command)
+);
+
+CREATE TABLE embed_template_fields (
+    guild_id INTEGER NOT NULL,
+    command TEXT NOT NULL,
+    -- Fields are shown in this order.
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (guild_id, command, position)
+);
@@ -0,0 +1,5 @@
+ALTER TABLE guilds ADD COLUMN emoji_robux TEXT;
+ALTER TABLE guilds ADD COLUMN emoji_gbp TEXT;
+ALTER TABLE guilds ADD COLUMN emoji_usd TEXT;
+ALTER TABLE guilds ADD COLUMN emoji_check TEXT;
+ALTER TABLE guilds ADD COLUMN emoji_cross TEXT;
@@ -0,0 +1,14 @@
+ALTER TABLE guilds ADD COLUMN audit_channel INTEGER;
+
+CREATE TABLE audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id INTEGER NOT NULL,
+    actor_id INTEGER NOT NULL,
+    action TEXT NOT NULL,
+    setting TEXT NOT NULL,
+    old_value TEXT,
+    new_value TEXT,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX audit_log_guild ON audit_log (guild_id, created_at);
@@ -0,0 +1,5 @@
+-- Set when a tier, coupon or order is deleted; the row is purged once the undo
+-- window has passed.
+ALTER TABLE tiers ADD COLUMN deleted_at INTEGER;
+ALTER TABLE coupons ADD COLUMN deleted_at INTEGER;
+ALTER TABLE orders ADD COLUMN deleted_at INTEGER;
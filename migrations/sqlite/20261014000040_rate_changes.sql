@@ -0,0 +1,14 @@
+-- Every change /setrates and /setrate make to a guild's rates, for
+-- /ratehistory. rate is sell, buy or forex; a NULL value is not buying or the
+-- market exchange rate.
+CREATE TABLE rate_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id INTEGER NOT NULL,
+    changed_by INTEGER NOT NULL,
+    rate TEXT NOT NULL,
+    previous REAL,
+    value REAL,
+    changed_at INTEGER NOT NULL
+);
+
+CREATE INDEX rate_changes_guild ON rate_changes (guild_id, changed_at);
@@ -0,0 +1,41 @@
+#!/bin/sh
+# Regenerates the .sqlx offline query metadata for both storage backends.
+#
+# Each backend's queries only make sense against its own database, so the crate is
+# built once per database and only the metadata for queries that appear in that
+# backend's module is kept. Queries in src/storage/sqlite.rs and
+# src/storage/postgres.rs must therefore not share identical SQL text.
+#
+# Usage: SQLITE_URL=sqlite:/tmp/build.db POSTGRES_URL=postgres://... scripts/prepare-sqlx.sh
+# Both databases must already have every migration in migrations/ applied.
+set -eu
+cd "$(dirname "$0")/.."
+
+work=$(mktemp -d)
+trap 'rm -rf "$work"' EXIT
+
+for backend in sqlite postgres; do
+    if [ "$backend" = sqlite ]; then url=$SQLITE_URL; else url=$POSTGRES_URL; fi
+    mkdir "$work/$backend"
+    touch src/storage/*.rs
+    # Queries for the other backend fail to check here; that's expected.
+    SQLX_OFFLINE=false DATABASE_URL=$url SQLX_OFFLINE_DIR=$work/$backend \
+        cargo build --quiet >/dev/null 2>&1 || true
+done
+
+rm -rf .sqlx
+mkdir .sqlx
+python3 - "$work" <<'PY'
+import glob, json, shutil, sys
+
+work = sys.argv[1]
+for backend in ("sqlite", "postgres"):
+    source = open(f"src/storage/{backend}.rs").read()
+    for path in glob.glob(f"{work}/{backend}/*.json"):
+        if '"' + json.load(open(path))["query"] + '"' in source:
+            shutil.copy(path, ".sqlx/")
+PY
+
+touch src/storage/*.rs
+cargo build --quiet
+echo "Wrote $(ls .sqlx | wc -l) query files to .sqlx"
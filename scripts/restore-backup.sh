@@ -0,0 +1,40 @@
+#!/bin/sh
+# Loads a JSON backup made by /backup on PostgreSQL back into a database.
+#
+# The database must have every migration in migrations/postgres applied and hold
+# no data yet, e.g. a fresh one the bot has been started against once. Stop the
+# bot first. SQLite backups need no script: the .db file is the database.
+#
+# Usage: DATABASE_URL=postgres://... scripts/restore-backup.sh backup-1760486400.json
+set -eu
+
+psql "$DATABASE_URL" -v ON_ERROR_STOP=1 -q <<SQL
+BEGIN;
+CREATE TEMP TABLE backup (data JSON NOT NULL);
+\copy backup FROM '$1' WITH (FORMAT csv, QUOTE E'\x01', DELIMITER E'\x02')
+DO \$\$
+DECLARE
+    tables JSON := (SELECT data FROM backup);
+    name TEXT;
+BEGIN
+    FOR name IN SELECT json_object_keys(tables) LOOP
+        EXECUTE format('INSERT INTO %I SELECT * FROM json_populate_recordset(NULL::%I, \$1)', name, name)
+            USING tables -> name;
+        -- Ids assigned after the restore must come after the restored ones.
+        IF EXISTS (
+            SELECT 1 FROM information_schema.columns
+            WHERE table_schema = current_schema() AND table_name = name AND column_name = 'id'
+        ) THEN
+            IF pg_get_serial_sequence(quote_ident(name), 'id') IS NOT NULL THEN
+                EXECUTE format(
+                    'SELECT setval(pg_get_serial_sequence(%L, ''id''), COALESCE(MAX(id), 0) + 1, false) FROM %I',
+                    name, name
+                );
+            END IF;
+        END IF;
+    END LOOP;
+END
+\$\$;
+COMMIT;
+SQL
+echo "Restored $1"
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Language;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Above,
+    Below,
+}
+
+impl Direction {
+    pub fn code(self) -> &'static str {
+        match self {
+            Direction::Above => "above",
+            Direction::Below => "below",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "below" => Direction::Below,
+            _ => Direction::Above,
+        }
+    }
+}
+
+/// A user's request to be notified when a pair crosses `threshold`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Alert {
+    pub id: u64,
+    pub user_id: u64,
+    pub guild_id: Option<u64>,
+    /// Channel the alert was created in, pinged if the user can't be DMed.
+    pub channel_id: u64,
+    pub pair: String,
+    pub threshold: f64,
+    pub direction: Direction,
+    #[serde(default)]
+    pub language: Language,
+}
+
+impl Alert {
+    /// Sets `direction` so that the alert fires once the rate moves across the
+    /// threshold from where it is now.
+    pub fn direction_for(current_rate: f64, threshold: f64) -> Direction {
+        if current_rate < threshold {
+            Direction::Above
+        } else {
+            Direction::Below
+        }
+    }
+
+    pub fn is_triggered(&self, rate: f64) -> bool {
+        match self.direction {
+            Direction::Above => rate >= self.threshold,
+            Direction::Below => rate <= self.threshold,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::tiers::{self, Tier};
+
+/// Where `/tier` changes are announced, set with `/announce ratechange`.
+#[derive(Clone, Debug)]
+pub struct RateAnnouncements {
+    pub channel_id: u64,
+    /// Pinged with each announcement, e.g. a customers role.
+    pub role_id: Option<u64>,
+}
+
+/// The rate buyers pay from `min_robux` upwards, before and after a change.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateChange {
+    pub min_robux: u64,
+    /// GBP per 1,000 Robux.
+    pub old: f64,
+    pub new: f64,
+}
+
+/// Every threshold whose rate differs between `old` and `new`, sorted by
+/// threshold. Amounts below the first tier pay `standard`.
+pub fn rate_changes(old: &[Tier], new: &[Tier], standard: f64) -> Vec<RateChange> {
+    let rate_at = |tiers: &[Tier], amount: u64| {
+        tiers::tier_for(tiers, amount).map_or(standard, |tier| tier.rate)
+    };
+    let mut thresholds: Vec<u64> = old.iter().chain(new).map(|tier| tier.min_robux).collect();
+    thresholds.sort_unstable();
+    thresholds.dedup();
+
+    thresholds
+        .into_iter()
+        .map(|min_robux| RateChange {
+            min_robux,
+            old: rate_at(old, min_robux),
+            new: rate_at(new, min_robux),
+        })
+        .filter(|change| (change.old - change.new).abs() > f64::EPSILON)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(min_robux: u64, rate: f64) -> Tier {
+        Tier { min_robux, rate }
+    }
+
+    #[test]
+    fn lists_only_changed_thresholds() {
+        let old = [tier(1000, 5.0), tier(10000, 4.5)];
+        let new = [tier(1000, 5.0), tier(10000, 4.0), tier(50000, 3.5)];
+        assert_eq!(
+            rate_changes(&old, &new, 6.0),
+            vec![
+                RateChange {
+                    min_robux: 10000,
+                    old: 4.5,
+                    new: 4.0,
+                },
+                RateChange {
+                    min_robux: 50000,
+                    old: 4.5,
+                    new: 3.5,
+                },
+            ]
+        );
+        assert!(rate_changes(&old, &old, 6.0).is_empty());
+    }
+
+    #[test]
+    fn removed_tiers_fall_back_to_the_standard_rate() {
+        let old = [tier(1000, 5.0)];
+        assert_eq!(
+            rate_changes(&old, &[], 6.0),
+            vec![RateChange {
+                min_robux: 1000,
+                old: 5.0,
+                new: 6.0,
+            }]
+        );
+    }
+}
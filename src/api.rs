@@ -0,0 +1,292 @@
+use axum::{
+    extract::{Query, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    crypto::Crypto, i18n::t, methods::DeliveryMethod, parse, pricing, secrets, state::AppState,
+    storage::GuildConfig, validation,
+};
+
+/// Header the API key can be sent in instead of the `key` query parameter.
+const KEY_HEADER: &str = "x-api-key";
+
+/// An error returned as `{"error": "..."}` with `status`.
+struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            message: message.into(),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(json!({ "error": self.message }))).into_response()
+    }
+}
+
+impl From<String> for ApiError {
+    fn from(message: String) -> Self {
+        eprintln!("API error: {}", message);
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "Internal error".to_string(),
+        }
+    }
+}
+
+/// Serves the calculator API and health check on `addr` until the listener fails.
+pub async fn serve(addr: &str, state: Arc<AppState>) -> Result<(), String> {
+    let router = Router::new()
+        .route("/healthz", get(health))
+        .route("/api/price", get(price))
+        .route("/api/convert", get(convert))
+        .route("/api/rates", get(current_rates))
+        // Lets sellers call the API from their own site's JavaScript.
+        .layer(middleware::map_response(|mut response: Response| async {
+            response.headers_mut().insert(
+                header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                HeaderValue::from_static("*"),
+            );
+            response
+        }))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Error binding API to {}: {}", addr, e))?;
+    println!("HTTP API listening on {}", addr);
+    axum::serve(listener, router)
+        .await
+        .map_err(|e| format!("API server error: {}", e))
+}
+
+/// Looks up the guild whose key was sent in [`KEY_HEADER`] or the `key` parameter.
+async fn authenticate(
+    state: &AppState,
+    headers: &HeaderMap,
+    key: Option<&str>,
+) -> Result<GuildConfig, ApiError> {
+    let key = headers
+        .get(KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .or(key)
+        .ok_or_else(|| ApiError {
+            status: StatusCode::UNAUTHORIZED,
+            message: "Missing API key".to_string(),
+        })?;
+    let guild_id = state
+        .storage
+        .guild_for_api_key(&secrets::sha256(key))
+        .await?
+        .ok_or_else(|| ApiError {
+            status: StatusCode::UNAUTHORIZED,
+            message: "Invalid API key".to_string(),
+        })?;
+    Ok(state.storage.guild(guild_id).await?)
+}
+
+#[derive(Deserialize)]
+struct PriceQuery {
+    key: Option<String>,
+    robux: String,
+    #[serde(rename = "type")]
+    price_type: Option<String>,
+    method: Option<String>,
+}
+
+/// `GET /api/price?robux=1000&type=a_t&method=gamepass`, priced with the guild's
+/// tiers and VAT like `/price`.
+async fn price(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(query): Query<PriceQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let config = authenticate(&state, &headers, query.key.as_deref()).await?;
+    let lang = config.language.unwrap_or_default();
+
+    let robux = parse::parse_robux_amount(&query.robux, lang)
+        .and_then(|robux| validation::validate_robux(robux, lang))
+        .map_err(ApiError::bad_request)?;
+    validation::validate_order_size(robux, &config.order_limits, lang)
+        .map_err(ApiError::bad_request)?;
+    let after_tax = match query.price_type.as_deref().unwrap_or("b_t") {
+        "b_t" | "b/t" => false,
+        "a_t" | "a/t" => true,
+        _ => return Err(ApiError::bad_request(t(lang, "price.invalid_type"))),
+    };
+    let method = query
+        .method
+        .as_deref()
+        .map_or(Some(DeliveryMethod::Gamepass), DeliveryMethod::parse)
+        .ok_or_else(|| ApiError::bad_request(t(lang, "price.invalid_method")))?;
+
+    let base_rate = pricing::base_rate(&config.tiers, &config.shop_rates, robux, None);
+    let quote = pricing::quote(
+        robux as f64,
+        base_rate,
+        method,
+        after_tax,
+        None,
+        config.vat.as_ref(),
+    );
+    let usd_rate = config
+        .forex_override
+        .unwrap_or(state.rates.read().await.gbp_to_usd);
+
+    Ok(Json(json!({
+        "robux": robux,
+        "type": if after_tax { "a_t" } else { "b_t" },
+        "method": method.code(),
+        "listing_price": method.listing_price(robux as f64, after_tax),
+        "rate_per_1k": quote.rate * 1000.0,
+        "gbp": quote.gbp(),
+        "usd": quote.gbp() * usd_rate,
+        "gbp_excluding_vat": quote.net,
+        "vat_gbp": quote.tax,
+    })))
+}
+
+#[derive(Deserialize)]
+struct ConvertQuery {
+    key: Option<String>,
+    amount: f64,
+    from: String,
+    to: Option<String>,
+}
+
+/// `GET /api/convert?amount=10&from=GBP&to=USD`, like `/convert`.
+async fn convert(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(query): Query<ConvertQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let config = authenticate(&state, &headers, query.key.as_deref()).await?;
+    let lang = config.language.unwrap_or_default();
+    let amount = validation::validate_fiat(query.amount, lang).map_err(ApiError::bad_request)?;
+    let invalid_currency = || ApiError::bad_request(t(lang, "error.invalid_currency"));
+
+    let rates = state.rates.read().await;
+    let usd_rate = config.forex_override.unwrap_or(rates.gbp_to_usd);
+    let from = query.from.to_uppercase();
+    let gbp = match from.as_str() {
+        "GBP" => amount,
+        "USD" => amount / usd_rate,
+        _ => return Err(invalid_currency()),
+    };
+    let to = query
+        .to
+        .map(|to| to.to_uppercase())
+        .unwrap_or_else(|| if from == "GBP" { "USD" } else { "GBP" }.to_string());
+    let result = match to.as_str() {
+        "GBP" => gbp,
+        "USD" => gbp * usd_rate,
+        code => {
+            let coin = Crypto::parse(code).ok_or_else(invalid_currency)?;
+            let price = rates
+                .crypto_gbp
+                .get(&coin)
+                .ok_or_else(|| ApiError::bad_request(t(lang, "crypto.unavailable")))?;
+            gbp / price
+        }
+    };
+
+    Ok(Json(json!({
+        "amount": amount,
+        "from": from,
+        "to": to,
+        "result": result,
+    })))
+}
+
+#[derive(Deserialize)]
+struct RatesQuery {
+    key: Option<String>,
+}
+
+/// `GET /api/rates`: the exchange rates the bot is currently quoting with.
+async fn current_rates(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(query): Query<RatesQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let config = authenticate(&state, &headers, query.key.as_deref()).await?;
+    let rates = state.rates.read().await;
+    let crypto: HashMap<_, _> = Crypto::ALL
+        .iter()
+        .filter_map(|coin| {
+            rates
+                .crypto_gbp
+                .get(coin)
+                .map(|price| (coin.symbol(), *price))
+        })
+        .collect();
+
+    Ok(Json(json!({
+        "gbp_to_usd": rates.gbp_to_usd,
+        "updated_at": rates.updated_at,
+        "gbp_per_1k_robux": pricing::base_rate(&config.tiers, &config.shop_rates, 1000, None) * 1000.0,
+        "crypto_gbp": crypto,
+        "crypto_updated_at": rates.crypto_updated_at,
+    })))
+}
+
+/// `GET /healthz`: whether every shard is connected to the gateway, the database
+/// answers and the exchange rate is fresh. Responds 503 if any of them isn't, so
+/// an orchestrator can restart the bot. Needs no API key.
+async fn health(State(state): State<Arc<AppState>>) -> (StatusCode, Json<Value>) {
+    let (connected, shards) = state.stats.connected_shards();
+    let gateway_ok = shards > 0 && connected == shards;
+    // The error can name hosts or credentials, so it stays in the logs.
+    let database_ok = match state.storage.ping().await {
+        Ok(()) => true,
+        Err(error) => {
+            eprintln!("Health check: database unreachable: {}", error);
+            false
+        }
+    };
+    let rates = state.rates.read().await;
+    let rates_ok = !rates.is_stale(state.stats.started_unix);
+
+    let healthy = gateway_ok && database_ok && rates_ok;
+    let status = if healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        status,
+        Json(json!({
+            "status": if healthy { "ok" } else { "degraded" },
+            "gateway": {
+                "ok": gateway_ok,
+                "connected_shards": connected,
+                "shards": shards,
+            },
+            "database": { "ok": database_ok },
+            "rates": {
+                "ok": rates_ok,
+                "updated_at": rates.updated_at,
+            },
+        })),
+    )
+}
+
+/// A new API key, prefixed so it's recognizable if it leaks.
+pub fn generate_key() -> Result<String, String> {
+    Ok(format!("rcb_{}", secrets::generate()?))
+}
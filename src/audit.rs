@@ -0,0 +1,196 @@
+use serenity::model::{
+    application::{
+        command::CommandOptionType,
+        interaction::application_command::{ApplicationCommandInteraction, CommandDataOption},
+    },
+    id::UserId,
+    mention::Mentionable,
+};
+use std::fmt::Debug;
+
+use crate::storage::GuildConfig;
+
+/// Longest value kept for a change, in characters. Settings like the tiers can
+/// grow long, and the log only needs enough to tell what changed.
+const MAX_VALUE: usize = 500;
+/// Longest value shown per change in `/auditlog show` and the log channel, so a
+/// page of entries fits in one embed.
+const MAX_SHOWN: usize = 80;
+/// Stands in for settings that are secret, such as webhook signing keys.
+const HIDDEN: &str = "(hidden)";
+
+/// One staff change, recorded for `/auditlog`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub guild_id: u64,
+    pub actor_id: u64,
+    /// The command that made the change, such as `/tier set`.
+    pub action: String,
+    /// What changed: a setting's name, a user or an order.
+    pub setting: String,
+    /// `None` when there was nothing before, such as a new blacklist entry.
+    pub old_value: Option<String>,
+    /// `None` when the thing was removed.
+    pub new_value: Option<String>,
+    pub created_at: u64,
+}
+
+/// One setting that differs between two versions of a guild's settings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub setting: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+/// Every setting that differs between `before` and `after`, in the order
+/// they're declared.
+pub fn changes(before: &GuildConfig, after: &GuildConfig) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut compare = |setting, old: String, new: String, secret: bool| {
+        if old != new {
+            let (old, new) = if secret {
+                (HIDDEN.to_string(), HIDDEN.to_string())
+            } else {
+                (clip(&old, MAX_VALUE), clip(&new, MAX_VALUE))
+            };
+            changes.push(Change { setting, old, new });
+        }
+    };
+
+    macro_rules! compare {
+        (shown: $($field:ident),*; secret: $($secret:ident),*) => {{
+            // Destructured without `..`, so a new setting can't be left out.
+            let GuildConfig { $($field,)* $($secret,)* } = after;
+            $(compare(stringify!($field), describe(&before.$field), describe($field), false);)*
+            $(compare(stringify!($secret), describe(&before.$secret), describe($secret), true);)*
+        }};
+    }
+    compare!(
+        shown: theme, emojis, tiers, language, priceboard, tickets, vouch_channel,
+            dispute_channel, audit_channel, loyalty_discounts, role_rewards, role_discounts,
+            templates, payment_handles, vat, sheets, autoreply_channels, disabled_features,
+            enabled_features, digest_recipients, sellers, rate_announcements, order_limits,
+            retention, rounding, shop_rates, forex_override;
+        secret: webhooks, api_key_hash
+    );
+    changes
+}
+
+/// The command and subcommand `command` ran, such as `/tier set`.
+pub fn action(command: &ApplicationCommandInteraction) -> String {
+    let mut action = format!("/{}", command.data.name);
+    let mut options: &[CommandDataOption] = &command.data.options;
+    while let Some(option) = options.first().filter(|option| {
+        matches!(
+            option.kind,
+            CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+        )
+    }) {
+        action.push(' ');
+        action.push_str(&option.name);
+        options = &option.options;
+    }
+    action
+}
+
+/// `entry` on one line for `/auditlog show` and the log channel.
+pub fn line(entry: &AuditEntry) -> String {
+    let value = |value: &Option<String>| match value {
+        Some(value) => format!("`{}`", clip(value, MAX_SHOWN).replace('`', "'")),
+        None => "—".to_string(),
+    };
+    format!(
+        "<t:{}:R> {} `{}` · **{}**: {} → {}",
+        entry.created_at,
+        UserId(entry.actor_id).mention(),
+        entry.action,
+        entry.setting,
+        value(&entry.old_value),
+        value(&entry.new_value)
+    )
+}
+
+fn describe(value: &impl Debug) -> String {
+    format!("{:?}", value)
+}
+
+/// `text` cut to `max` characters, marked with an ellipsis if it was longer.
+fn clip(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut clipped: String = text.chars().take(max - 1).collect();
+    clipped.push('…');
+    clipped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pricing::ShopRates, webhooks::Webhook};
+
+    #[test]
+    fn lists_only_changed_settings() {
+        let before = GuildConfig::default();
+        let after = GuildConfig {
+            shop_rates: ShopRates {
+                sell: 4.0,
+                buy: None,
+            },
+            audit_channel: Some(42),
+            ..GuildConfig::default()
+        };
+        let changes = changes(&before, &after);
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| change.setting)
+                .collect::<Vec<_>>(),
+            ["audit_channel", "shop_rates"]
+        );
+        assert_eq!(changes[0].old, "None");
+        assert_eq!(changes[0].new, "Some(42)");
+        assert!(changes[1].new.contains("sell: 4.0"), "{}", changes[1].new);
+        assert!(super::changes(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn hides_secrets() {
+        let after = GuildConfig {
+            webhooks: vec![Webhook {
+                url: "https://example.com/hook".to_string(),
+                secret: "abc123".to_string(),
+            }],
+            ..GuildConfig::default()
+        };
+        let changes = changes(&GuildConfig::default(), &after);
+        assert_eq!(
+            changes,
+            [Change {
+                setting: "webhooks",
+                old: HIDDEN.to_string(),
+                new: HIDDEN.to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn clips_long_values() {
+        assert_eq!(clip("abcdef", 6), "abcdef");
+        assert_eq!(clip("abcdefg", 6), "abcde…");
+        let entry = AuditEntry {
+            guild_id: 1,
+            actor_id: 2,
+            action: "/blacklist add".to_string(),
+            setting: "<@3>".to_string(),
+            old_value: None,
+            new_value: Some("scam`mer".to_string()),
+            created_at: 100,
+        };
+        assert_eq!(
+            line(&entry),
+            "<t:100:R> <@2> `/blacklist add` · **<@3>**: — → `scam'mer`"
+        );
+    }
+}
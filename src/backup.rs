@@ -0,0 +1,41 @@
+/// Largest file the bot can upload to a server without boosts.
+pub const MAX_UPLOAD_BYTES: usize = 10 * 1024 * 1024;
+
+/// A copy of the whole database made by `/backup`.
+pub struct Backup {
+    pub data: Vec<u8>,
+    /// `db` for a copy of the SQLite file, `json` for every PostgreSQL table's
+    /// rows, which `scripts/restore-backup.sh` loads back.
+    pub extension: &'static str,
+}
+
+impl Backup {
+    /// The uploaded file's name, stamped with when the backup was made.
+    pub fn filename(&self, at: u64) -> String {
+        format!("backup-{}.{}", at, self.extension)
+    }
+
+    pub fn fits_upload(&self) -> bool {
+        self.data.len() <= MAX_UPLOAD_BYTES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_files_by_time_and_format() {
+        let backup = Backup {
+            data: vec![0; MAX_UPLOAD_BYTES],
+            extension: "db",
+        };
+        assert_eq!(backup.filename(1_760_486_400), "backup-1760486400.db");
+        assert!(backup.fits_upload());
+        let backup = Backup {
+            data: vec![0; MAX_UPLOAD_BYTES + 1],
+            extension: "json",
+        };
+        assert!(!backup.fits_upload());
+    }
+}
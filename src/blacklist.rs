@@ -0,0 +1,12 @@
+/// A user barred from ordering and getting quotes.
+#[derive(Clone, Debug)]
+pub struct BlacklistEntry {
+    /// `None` for entries that apply in every guild the bot is in.
+    pub guild_id: Option<u64>,
+    pub user_id: u64,
+    pub reason: String,
+    /// Staff member who added the entry.
+    pub added_by: u64,
+    /// Unix timestamp in seconds.
+    pub created_at: u64,
+}
@@ -0,0 +1,127 @@
+use serenity::model::id::GuildId;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex as StdMutex,
+    time::{Duration, Instant},
+};
+
+/// Counters behind `/uptime` and `/botstats`, kept for the life of the process.
+pub struct BotStats {
+    started_at: Instant,
+    /// Unix time the process started, for Discord timestamps.
+    pub started_unix: u64,
+    guilds: StdMutex<HashSet<GuildId>>,
+    commands: StdMutex<HashMap<String, u64>>,
+    /// Whether each shard that has started is connected to the gateway.
+    shards: StdMutex<HashMap<u64, bool>>,
+}
+
+impl BotStats {
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            started_unix: crate::rates::now(),
+            guilds: StdMutex::new(HashSet::new()),
+            commands: StdMutex::new(HashMap::new()),
+            shards: StdMutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn uptime(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    pub fn join_guilds(&self, guild_ids: impl IntoIterator<Item = GuildId>) {
+        self.guilds
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .extend(guild_ids);
+    }
+
+    pub fn leave_guild(&self, guild_id: GuildId) {
+        self.guilds
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(&guild_id);
+    }
+
+    pub fn guild_count(&self) -> usize {
+        self.guilds
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .len()
+    }
+
+    pub fn record_command(&self, name: &str) {
+        *self
+            .commands
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(name.to_string())
+            .or_default() += 1;
+    }
+
+    pub fn set_shard_connected(&self, shard_id: u64, connected: bool) {
+        self.shards
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(shard_id, connected);
+    }
+
+    /// How many shards are connected to the gateway, out of those started.
+    #[cfg(feature = "api")]
+    pub fn connected_shards(&self) -> (usize, usize) {
+        let shards = self
+            .shards
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        (
+            shards.values().filter(|connected| **connected).count(),
+            shards.len(),
+        )
+    }
+
+    /// How often each command has run since startup, most used first.
+    pub fn command_counts(&self) -> Vec<(String, u64)> {
+        let mut counts: Vec<_> = self
+            .commands
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|(name, count)| (name.clone(), *count))
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
+}
+
+/// The process's resident memory in bytes, where `/proc` is available.
+pub fn memory_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// A duration as days, hours, minutes and seconds, e.g. `3d 4h 12m 5s`.
+pub fn duration_label(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let parts = [
+        (seconds / 86_400, "d"),
+        (seconds / 3_600 % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+    ];
+    let label: Vec<String> = parts
+        .iter()
+        .skip_while(|(value, unit)| *value == 0 && *unit != "s")
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect();
+    label.join(" ")
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+#[cfg(any(feature = "orders", feature = "roblox"))]
+use std::{future::Future, hash::Hash};
+use tokio::sync::Mutex;
+
+#[cfg(any(feature = "orders", feature = "roblox"))]
+use crate::rates;
+
+/// Responses from an external API, kept for `ttl` seconds so repeated commands
+/// don't fetch the same thing again.
+#[cfg(any(feature = "orders", feature = "roblox"))]
+pub struct TtlCache<K, V> {
+    ttl: u64,
+    /// Each value with the Unix timestamp it was fetched at.
+    entries: Mutex<HashMap<K, (V, u64)>>,
+}
+
+#[cfg(any(feature = "orders", feature = "roblox"))]
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    pub fn new(ttl: u64) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The value cached for `key`, or what `fetch` returns if there isn't a
+    /// fresh one. Errors aren't cached, so the next call tries again.
+    pub async fn get_or_fetch<E>(
+        &self,
+        key: K,
+        fetch: impl Future<Output = Result<V, E>>,
+    ) -> Result<V, E> {
+        let now = rates::now();
+        if let Some((value, fetched_at)) = self.entries.lock().await.get(&key) {
+            if now.saturating_sub(*fetched_at) < self.ttl {
+                return Ok(value.clone());
+            }
+        }
+
+        // Not locked while fetching, so a slow API doesn't hold up other keys.
+        let value = fetch.await?;
+        let mut entries = self.entries.lock().await;
+        entries.retain(|_, (_, fetched_at)| now.saturating_sub(*fetched_at) < self.ttl);
+        entries.insert(key, (value.clone(), now));
+        Ok(value)
+    }
+}
+
+/// Ids seen in the last `ttl` seconds, for noticing something delivered twice.
+pub struct SeenIds {
+    ttl: u64,
+    /// Each id with the Unix timestamp it was first seen at.
+    seen: Mutex<HashMap<u64, u64>>,
+}
+
+impl SeenIds {
+    pub fn new(ttl: u64) -> Self {
+        Self {
+            ttl,
+            seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records `id` as seen at `now`, returning false if it already was within
+    /// the ttl.
+    pub async fn insert(&self, id: u64, now: u64) -> bool {
+        let mut seen = self.seen.lock().await;
+        seen.retain(|_, seen_at| now.saturating_sub(*seen_at) < self.ttl);
+        if seen.contains_key(&id) {
+            return false;
+        }
+        seen.insert(id, now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[cfg(any(feature = "orders", feature = "roblox"))]
+    async fn reuses_fresh_values_and_retries_errors() {
+        let cache = TtlCache::new(60);
+        let calls = &Mutex::new(0);
+        let fetch = |result: Result<u64, ()>| async move {
+            *calls.lock().await += 1;
+            result
+        };
+
+        assert_eq!(cache.get_or_fetch("a", fetch(Err(()))).await, Err(()));
+        assert_eq!(cache.get_or_fetch("a", fetch(Ok(1))).await, Ok(1));
+        assert_eq!(cache.get_or_fetch("a", fetch(Ok(2))).await, Ok(1));
+        assert_eq!(cache.get_or_fetch("b", fetch(Ok(3))).await, Ok(3));
+        assert_eq!(*calls.lock().await, 3);
+
+        let expired = TtlCache::new(0);
+        assert_eq!(expired.get_or_fetch("a", fetch(Ok(1))).await, Ok(1));
+        assert_eq!(expired.get_or_fetch("a", fetch(Ok(2))).await, Ok(2));
+    }
+
+    #[tokio::test]
+    async fn notices_ids_seen_within_the_ttl() {
+        let seen = SeenIds::new(60);
+        assert!(seen.insert(1, 1000).await);
+        assert!(!seen.insert(1, 1059).await);
+        assert!(seen.insert(2, 1059).await);
+        assert!(seen.insert(1, 1060).await);
+    }
+}
@@ -0,0 +1,248 @@
+use serenity::model::id::GuildId;
+
+use crate::{
+    crypto::{self, Crypto},
+    i18n::{t, tf, Language},
+    methods::DeliveryMethod,
+    parse, pricing, rates,
+    storage::{self, GuildConfig},
+    validation, vat_summary, GBP_TO_USD_RATE,
+};
+
+const USAGE: &str = "\
+Usage: discord_bot --cli <command> [options]
+
+Commands:
+  price <robux>                 Price an amount of Robux
+      --after-tax               Price so the buyer receives <robux> after Roblox's fee
+      --method <method>         gamepass, devproduct, group or giftcard (default gamepass)
+      --currency <GBP|USD>      Currency to show the total in (default GBP)
+      --guild <id>              Use a guild's tiers and VAT from DATABASE_URL
+  robux <amount>                How much Robux an amount of money buys
+      --currency <GBP|USD>      Currency of <amount> (default GBP)
+  convert <amount> <from> [to]  Convert between GBP, USD, BTC, ETH and LTC
+
+Options for every command:
+  --usd-rate <rate>             GBP to USD rate to use instead of fetching it
+";
+
+/// Returns the arguments after `--cli` if the bot was started in CLI mode.
+pub fn args() -> Option<Vec<String>> {
+    let mut args = std::env::args().skip(1).peekable();
+    args.next_if(|arg| arg == "--cli")?;
+    Some(args.collect())
+}
+
+/// Command-line arguments split into positionals and `--flag [value]` options.
+struct Args {
+    positional: Vec<String>,
+    flags: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    /// Flags that take no value; every other flag consumes the next argument.
+    const SWITCHES: [&'static str; 1] = ["--after-tax"];
+
+    fn parse(args: Vec<String>) -> Result<Self, String> {
+        let mut parsed = Args {
+            positional: Vec::new(),
+            flags: Vec::new(),
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if !arg.starts_with("--") {
+                parsed.positional.push(arg);
+            } else if Self::SWITCHES.contains(&arg.as_str()) {
+                parsed.flags.push((arg, None));
+            } else {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))?;
+                parsed.flags.push((arg, Some(value)));
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn switch(&self, name: &str) -> bool {
+        self.flags.iter().any(|(flag, _)| flag == name)
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .find(|(flag, _)| flag == name)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    fn positional(&self, index: usize, name: &str) -> Result<&str, String> {
+        self.positional
+            .get(index)
+            .map(String::as_str)
+            .ok_or_else(|| format!("Missing <{}>\n\n{}", name, USAGE))
+    }
+}
+
+/// Runs a calculation from the terminal and prints the result.
+pub async fn run(args: Vec<String>) -> Result<(), String> {
+    let args = Args::parse(args)?;
+    let lang = Language::default();
+    match args.positional.first().map(String::as_str) {
+        Some("price") => price(&args, lang).await,
+        Some("robux") => robux(&args, lang).await,
+        Some("convert") => convert(&args, lang).await,
+        Some("help") | None => {
+            print!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => Err(format!("Unknown command {}\n\n{}", command, USAGE)),
+    }
+}
+
+/// The `--usd-rate` override, else the live rate, else the built-in fallback.
+async fn usd_rate(args: &Args) -> Result<f64, String> {
+    if let Some(rate) = args.value("--usd-rate") {
+        return rate
+            .parse::<f64>()
+            .ok()
+            .filter(|rate| rate.is_finite() && *rate > 0.0)
+            .ok_or_else(|| format!("Invalid --usd-rate {}", rate));
+    }
+    let sources = rates::ForexSource::from_env()?.with_fallback();
+    match rates::fetch_gbp_rates(&reqwest::Client::new(), &sources).await {
+        Ok(rates) => Ok(rates["USD"]),
+        Err(error) => {
+            eprintln!("{}; using {}", error, GBP_TO_USD_RATE);
+            Ok(GBP_TO_USD_RATE)
+        }
+    }
+}
+
+async fn guild_config(args: &Args) -> Result<GuildConfig, String> {
+    let Some(guild_id) = args.value("--guild") else {
+        return Ok(GuildConfig::default());
+    };
+    let guild_id = guild_id
+        .parse::<u64>()
+        .map_err(|_| format!("Invalid --guild {}", guild_id))?;
+    let url = std::env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite:data.db".to_string());
+    let storage = storage::connect(&url)
+        .await
+        .map_err(|e| format!("Error connecting to {}: {}", url, e))?;
+    storage.guild(GuildId(guild_id)).await
+}
+
+fn currency(args: &Args, lang: Language) -> Result<&str, String> {
+    match args.value("--currency").unwrap_or("GBP") {
+        currency @ ("GBP" | "USD") => Ok(currency),
+        _ => Err(t(lang, "error.invalid_currency").to_string()),
+    }
+}
+
+fn amount(args: &Args, index: usize, lang: Language) -> Result<f64, String> {
+    args.positional(index, "amount")?
+        .parse::<f64>()
+        .map_err(|_| t(lang, "error.invalid_options").to_string())
+        .and_then(|amount| validation::validate_fiat(amount, lang))
+}
+
+async fn price(args: &Args, lang: Language) -> Result<(), String> {
+    let robux = parse::parse_robux_amount(args.positional(1, "robux")?, lang)
+        .and_then(|robux| validation::validate_robux(robux, lang))?;
+    let after_tax = args.switch("--after-tax");
+    let method = DeliveryMethod::parse(args.value("--method").unwrap_or("gamepass"))
+        .ok_or(t(lang, "price.invalid_method"))?;
+    let currency = currency(args, lang)?;
+    let config = guild_config(args).await?;
+    let usd_rate = usd_rate(args).await?;
+
+    let base_rate = pricing::base_rate(&config.tiers, &config.shop_rates, robux, None);
+    let quote = pricing::quote(
+        robux as f64,
+        base_rate,
+        method,
+        after_tax,
+        None,
+        config.vat.as_ref(),
+    );
+    let format = |gbp: f64| match currency {
+        "USD" => format!("${:.2}", gbp * usd_rate),
+        _ => format!("£{:.2}", gbp),
+    };
+
+    println!(
+        "{} R$ ({}, {})",
+        robux,
+        if after_tax { "a/t" } else { "b/t" },
+        method.name(lang)
+    );
+    println!(
+        "{}: {} R$",
+        method.listing_label(lang),
+        method.listing_price(robux as f64, after_tax)
+    );
+    println!(
+        "{}: £{:.2} / 1k R$",
+        t(lang, "price.rate"),
+        base_rate * 1000.0
+    );
+    if let Some(vat) = &config.vat {
+        println!("{}: {}", t(lang, "vat.excluding"), format(quote.net));
+        println!(
+            "{}: {}",
+            tf(lang, "vat.amount", &[("summary", &vat_summary(lang, vat))]),
+            format(quote.tax)
+        );
+    }
+    println!(
+        "{}: {}",
+        tf(lang, "common.amount_in", &[("currency", &currency)]),
+        format(quote.gbp())
+    );
+    Ok(())
+}
+
+async fn robux(args: &Args, lang: Language) -> Result<(), String> {
+    let amount = amount(args, 1, lang)?;
+    let gbp = match currency(args, lang)? {
+        "USD" => amount / usd_rate(args).await?,
+        _ => amount,
+    };
+    let config = guild_config(args).await?;
+    println!("{} R$", (gbp / config.shop_rates.sell_per_robux()) as i64);
+    Ok(())
+}
+
+async fn convert(args: &Args, lang: Language) -> Result<(), String> {
+    let amount = amount(args, 1, lang)?;
+    let from = args.positional(2, "from")?.to_uppercase();
+    let to = args
+        .positional
+        .get(3)
+        .map(|to| to.to_uppercase())
+        .unwrap_or_else(|| if from == "GBP" { "USD" } else { "GBP" }.to_string());
+    let invalid_currency = || t(lang, "error.invalid_currency").to_string();
+    let usd_rate = if from == "USD" || to == "USD" {
+        usd_rate(args).await?
+    } else {
+        GBP_TO_USD_RATE
+    };
+
+    let gbp = match from.as_str() {
+        "GBP" => amount,
+        "USD" => amount / usd_rate,
+        _ => return Err(invalid_currency()),
+    };
+    let result = match to.as_str() {
+        "GBP" => format!("{:.2}", gbp),
+        "USD" => format!("{:.2}", gbp * usd_rate),
+        code => {
+            let coin = Crypto::parse(code).ok_or_else(invalid_currency)?;
+            let prices = crypto::fetch_gbp_prices(&reqwest::Client::new()).await?;
+            let price = prices.get(&coin).ok_or(t(lang, "crypto.unavailable"))?;
+            format!("{:.8}", gbp / price)
+        }
+    };
+    println!("{:.2} {} = {} {}", amount, from, result, to);
+    Ok(())
+}
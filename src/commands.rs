@@ -0,0 +1,297 @@
+use async_trait::async_trait;
+use serenity::{
+    builder::{CreateApplicationCommand, CreateApplicationCommands},
+    client::Context,
+    model::application::interaction::application_command::ApplicationCommandInteraction,
+};
+use std::{collections::HashMap, future::Future, sync::Mutex};
+
+/// Runs one slash command. Implemented for every
+/// `async fn(&Context, &ApplicationCommandInteraction) -> Result<(), String>`.
+#[async_trait]
+pub trait CommandHandler: Send + Sync {
+    async fn handle(
+        &self,
+        ctx: &Context,
+        command: &ApplicationCommandInteraction,
+    ) -> Result<(), String>;
+}
+
+/// A handler called with arguments borrowed for `'a`, so an `async fn`'s future
+/// can borrow them too.
+pub trait HandlerFn<'a>: Send + Sync {
+    type Future: Future<Output = Result<(), String>> + Send + 'a;
+
+    fn call(&self, ctx: &'a Context, command: &'a ApplicationCommandInteraction) -> Self::Future;
+}
+
+impl<'a, F, Fut> HandlerFn<'a> for F
+where
+    F: Fn(&'a Context, &'a ApplicationCommandInteraction) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), String>> + Send + 'a,
+{
+    type Future = Fut;
+
+    fn call(&self, ctx: &'a Context, command: &'a ApplicationCommandInteraction) -> Fut {
+        self(ctx, command)
+    }
+}
+
+#[async_trait]
+impl<F> CommandHandler for F
+where
+    F: for<'a> HandlerFn<'a>,
+{
+    async fn handle(
+        &self,
+        ctx: &Context,
+        command: &ApplicationCommandInteraction,
+    ) -> Result<(), String> {
+        self.call(ctx, command).await
+    }
+}
+
+/// What Discord is told about a command: its description, options and who can
+/// use it.
+pub type Definition = fn(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand;
+
+/// A command the bot can register and run.
+pub struct BotCommand {
+    /// The English name the command is registered under, which interactions
+    /// arrive with whatever the user's language.
+    pub name: &'static str,
+    handler: Box<dyn CommandHandler>,
+    define: Definition,
+    /// Seconds each user waits between uses, for commands behind rate-limited
+    /// APIs.
+    pub cooldown: u64,
+    /// Whether the command works without a guild, so it's registered globally
+    /// and can be used in DMs.
+    pub dms: bool,
+    /// Whether the guild settings changes the command makes are recorded in the
+    /// audit log.
+    pub audited: bool,
+}
+
+impl BotCommand {
+    pub fn new(
+        name: &'static str,
+        handler: impl CommandHandler + 'static,
+        define: Definition,
+    ) -> Self {
+        Self {
+            name,
+            handler: Box::new(handler),
+            define,
+            cooldown: 0,
+            dms: false,
+            audited: false,
+        }
+    }
+
+    pub fn cooldown(mut self, seconds: u64) -> Self {
+        self.cooldown = seconds;
+        self
+    }
+
+    pub fn in_dms(mut self) -> Self {
+        self.dms = true;
+        self
+    }
+
+    pub fn audited(mut self) -> Self {
+        self.audited = true;
+        self
+    }
+
+    pub async fn run(
+        &self,
+        ctx: &Context,
+        command: &ApplicationCommandInteraction,
+    ) -> Result<(), String> {
+        self.handler.handle(ctx, command).await
+    }
+}
+
+/// An optional set of commands, such as a Cargo feature's or a third party's,
+/// added to the [`Registry`] without touching dispatch.
+pub trait Plugin {
+    /// Shown when a plugin's command clashes with one already registered.
+    fn name(&self) -> &'static str;
+
+    fn commands(&self) -> Vec<BotCommand>;
+}
+
+/// Every command, looked up by name when an interaction arrives.
+pub struct Registry {
+    commands: Vec<BotCommand>,
+    /// When each user last ran each command with a cooldown, as a Unix timestamp.
+    last_used: Mutex<HashMap<(&'static str, u64), u64>>,
+}
+
+impl Registry {
+    pub fn new(commands: Vec<BotCommand>) -> Self {
+        let mut registry = Self {
+            commands: Vec::new(),
+            last_used: Mutex::new(HashMap::new()),
+        };
+        registry.add(commands, "core");
+        registry
+    }
+
+    /// Adds `plugin`'s commands after the ones already registered.
+    pub fn register(&mut self, plugin: &dyn Plugin) {
+        self.add(plugin.commands(), plugin.name());
+    }
+
+    fn add(&mut self, commands: Vec<BotCommand>, source: &str) {
+        for command in commands {
+            assert!(
+                self.get(command.name).is_none(),
+                "/{} from {} is already registered",
+                command.name,
+                source
+            );
+            self.commands.push(command);
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&BotCommand> {
+        self.commands.iter().find(|command| command.name == name)
+    }
+
+    /// Adds the commands that work in DMs, which are registered globally.
+    pub fn define_global<'a>(
+        &self,
+        commands: &'a mut CreateApplicationCommands,
+    ) -> &'a mut CreateApplicationCommands {
+        self.define_where(commands, |command| command.dms)
+    }
+
+    /// Adds the commands that only work in guilds, which are registered in the
+    /// guild so they don't clash with the global ones.
+    pub fn define_guild<'a>(
+        &self,
+        commands: &'a mut CreateApplicationCommands,
+    ) -> &'a mut CreateApplicationCommands {
+        self.define_where(commands, |command| !command.dms)
+    }
+
+    /// Adds the definitions of the commands `include` picks, in registration
+    /// order.
+    fn define_where<'a>(
+        &self,
+        commands: &'a mut CreateApplicationCommands,
+        include: impl Fn(&BotCommand) -> bool,
+    ) -> &'a mut CreateApplicationCommands {
+        for command in self.commands.iter().filter(|command| include(command)) {
+            commands.create_application_command(|definition| {
+                (command.define)(definition);
+                if command.dms {
+                    definition.dm_permission(true);
+                }
+                definition
+            });
+        }
+        commands
+    }
+
+    /// Records `user_id` running `command` at `now`, or fails with when they can
+    /// run it next if they're still cooling down.
+    pub fn start_cooldown(&self, command: &BotCommand, user_id: u64, now: u64) -> Result<(), u64> {
+        if command.cooldown == 0 {
+            return Ok(());
+        }
+        let mut last_used = self.last_used.lock().unwrap();
+        last_used.retain(|(name, _), used_at| {
+            let cooldown = self.get(name).map_or(0, |command| command.cooldown);
+            now.saturating_sub(*used_at) < cooldown
+        });
+        if let Some(used_at) = last_used.get(&(command.name, user_id)) {
+            return Err(used_at + command.cooldown);
+        }
+        last_used.insert((command.name, user_id), now);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn ok(_ctx: &Context, _command: &ApplicationCommandInteraction) -> Result<(), String> {
+        Ok(())
+    }
+
+    struct Extra;
+
+    impl Plugin for Extra {
+        fn name(&self) -> &'static str {
+            "extra"
+        }
+
+        fn commands(&self) -> Vec<BotCommand> {
+            vec![BotCommand::new("extra", ok, |command| {
+                command.name("extra")
+            })]
+        }
+    }
+
+    #[test]
+    fn plugins_add_commands() {
+        let mut registry = Registry::new(vec![BotCommand::new("price", ok, |command| {
+            command.name("price")
+        })]);
+        assert!(registry.get("extra").is_none());
+        registry.register(&Extra);
+        assert!(registry.get("price").is_some());
+        assert!(registry.get("extra").is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "/extra from extra is already registered")]
+    fn plugins_cant_replace_commands() {
+        let mut registry = Registry::new(Vec::new());
+        registry.register(&Extra);
+        registry.register(&Extra);
+    }
+
+    #[test]
+    fn registers_dm_commands_globally() {
+        let registry = Registry::new(vec![
+            BotCommand::new("price", ok, |command| command.name("price")).in_dms(),
+            BotCommand::new("stock", ok, |command| command.name("stock")),
+        ]);
+        let names = |commands: &CreateApplicationCommands| -> Vec<String> {
+            commands
+                .0
+                .iter()
+                .map(|command| command["name"].as_str().unwrap().to_string())
+                .collect()
+        };
+        let mut global = CreateApplicationCommands::default();
+        registry.define_global(&mut global);
+        assert_eq!(names(&global), ["price"]);
+        assert_eq!(global.0[0]["dm_permission"], true);
+        let mut guild = CreateApplicationCommands::default();
+        registry.define_guild(&mut guild);
+        assert_eq!(names(&guild), ["stock"]);
+    }
+
+    #[test]
+    fn cooldowns_are_per_user_and_command() {
+        let registry = Registry::new(vec![
+            BotCommand::new("rap", ok, |command| command.name("rap")).cooldown(10),
+            BotCommand::new("price", ok, |command| command.name("price")),
+        ]);
+        let rap = registry.get("rap").unwrap();
+        let price = registry.get("price").unwrap();
+
+        assert_eq!(registry.start_cooldown(rap, 1, 100), Ok(()));
+        assert_eq!(registry.start_cooldown(rap, 1, 105), Err(110));
+        assert_eq!(registry.start_cooldown(rap, 2, 105), Ok(()));
+        assert_eq!(registry.start_cooldown(price, 1, 105), Ok(()));
+        assert_eq!(registry.start_cooldown(price, 1, 105), Ok(()));
+        assert_eq!(registry.start_cooldown(rap, 1, 110), Ok(()));
+    }
+}
@@ -0,0 +1,125 @@
+/// Longest code `/coupon create` accepts.
+pub const MAX_CODE_LENGTH: u16 = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CouponKind {
+    /// Takes `value` percent off the total.
+    Percent,
+    /// Takes `value` GBP off the total.
+    Flat,
+}
+
+impl CouponKind {
+    pub fn code(self) -> &'static str {
+        match self {
+            CouponKind::Percent => "percent",
+            CouponKind::Flat => "flat",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "flat" => CouponKind::Flat,
+            _ => CouponKind::Percent,
+        }
+    }
+}
+
+/// A discount code created by staff with `/coupon create`.
+#[derive(Clone, Debug)]
+pub struct Coupon {
+    pub guild_id: u64,
+    /// Stored uppercase; see [`normalize`].
+    pub code: String,
+    pub kind: CouponKind,
+    pub value: f64,
+    /// `None` for unlimited uses.
+    pub max_uses: Option<u64>,
+    pub uses: u64,
+    /// Unix timestamps in seconds.
+    pub expires_at: Option<u64>,
+    pub created_by: u64,
+    pub created_at: u64,
+}
+
+impl Coupon {
+    /// Applies the discount to a GBP total, never going below zero.
+    pub fn apply(&self, gbp: f64) -> f64 {
+        match self.kind {
+            CouponKind::Percent => gbp * (1.0 - self.value / 100.0),
+            CouponKind::Flat => (gbp - self.value).max(0.0),
+        }
+    }
+
+    pub fn remaining_uses(&self) -> Option<u64> {
+        self.max_uses.map(|max| max.saturating_sub(self.uses))
+    }
+
+    pub fn is_usable(&self, now: u64) -> bool {
+        self.remaining_uses() != Some(0) && self.expires_at.is_none_or(|expiry| now < expiry)
+    }
+}
+
+/// Codes are case-insensitive, so they're stored and looked up uppercase.
+pub fn normalize(code: &str) -> String {
+    code.trim().to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coupon(kind: CouponKind, value: f64) -> Coupon {
+        Coupon {
+            guild_id: 1,
+            code: "SUMMER".to_string(),
+            kind,
+            value,
+            max_uses: None,
+            uses: 0,
+            expires_at: None,
+            created_by: 2,
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn takes_percentages_and_flat_amounts_off() {
+        assert!((coupon(CouponKind::Percent, 10.0).apply(35.0) - 31.5).abs() < 1e-9);
+        assert!((coupon(CouponKind::Flat, 5.0).apply(35.0) - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn never_goes_below_zero() {
+        assert_eq!(coupon(CouponKind::Flat, 50.0).apply(35.0), 0.0);
+        assert_eq!(coupon(CouponKind::Percent, 100.0).apply(35.0), 0.0);
+    }
+
+    #[test]
+    fn stops_being_usable_when_used_up_or_expired() {
+        let mut capped = coupon(CouponKind::Percent, 10.0);
+        capped.max_uses = Some(2);
+        capped.uses = 1;
+        assert!(capped.is_usable(100));
+        assert_eq!(capped.remaining_uses(), Some(1));
+        capped.uses = 2;
+        assert!(!capped.is_usable(100));
+        assert_eq!(capped.remaining_uses(), Some(0));
+
+        let mut expiring = coupon(CouponKind::Flat, 1.0);
+        expiring.expires_at = Some(100);
+        assert!(expiring.is_usable(99));
+        assert!(!expiring.is_usable(100));
+        assert!(coupon(CouponKind::Flat, 1.0).is_usable(u64::MAX));
+    }
+
+    #[test]
+    fn codes_are_case_insensitive() {
+        assert_eq!(normalize("  summer10 "), "SUMMER10");
+        assert_eq!(
+            CouponKind::from_code(CouponKind::Flat.code()),
+            CouponKind::Flat
+        );
+        assert_eq!(CouponKind::from_code("unknown"), CouponKind::Percent);
+    }
+}
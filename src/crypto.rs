@@ -0,0 +1,75 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+const PRICES_URL: &str =
+    "https://api.coingecko.com/api/v3/simple/price?ids=bitcoin,ethereum,litecoin&vs_currencies=gbp";
+
+/// Cryptocurrencies quotes can be shown in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Crypto {
+    Btc,
+    Eth,
+    Ltc,
+}
+
+impl Crypto {
+    pub const ALL: [Crypto; 3] = [Crypto::Btc, Crypto::Eth, Crypto::Ltc];
+
+    pub fn parse(code: &str) -> Option<Self> {
+        match code {
+            "BTC" => Some(Crypto::Btc),
+            "ETH" => Some(Crypto::Eth),
+            "LTC" => Some(Crypto::Ltc),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Crypto::Btc => "BTC",
+            Crypto::Eth => "ETH",
+            Crypto::Ltc => "LTC",
+        }
+    }
+
+    fn coingecko_id(self) -> &'static str {
+        match self {
+            Crypto::Btc => "bitcoin",
+            Crypto::Eth => "ethereum",
+            Crypto::Ltc => "litecoin",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Price {
+    gbp: f64,
+}
+
+/// Fetches the GBP price of one coin for each of [`Crypto::ALL`] from CoinGecko.
+pub async fn fetch_gbp_prices(client: &reqwest::Client) -> Result<HashMap<Crypto, f64>, String> {
+    let response: HashMap<String, Price> = client
+        .get(PRICES_URL)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Error fetching crypto prices: {:?}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Error parsing crypto prices: {:?}", e))?;
+
+    let prices: HashMap<Crypto, f64> = Crypto::ALL
+        .iter()
+        .filter_map(|coin| {
+            response
+                .get(coin.coingecko_id())
+                .map(|price| price.gbp)
+                .filter(|price| price.is_finite() && *price > 0.0)
+                .map(|price| (*coin, price))
+        })
+        .collect();
+    if prices.is_empty() {
+        return Err("Crypto price response did not include any coins".to_string());
+    }
+    Ok(prices)
+}
@@ -0,0 +1,136 @@
+use crate::{
+    disputes::Refund,
+    orders::Order,
+    rates::RateSnapshot,
+    stats::{self, Summary},
+    vouches::Vouch,
+};
+
+pub const WEEK_SECONDS: u64 = 7 * 24 * 60 * 60;
+/// How far into the week digests go out, so the last Sunday's rate refresh and
+/// late `/order complete`s are counted.
+pub const SEND_DELAY_SECONDS: u64 = 60 * 60;
+
+/// Last week's activity in a guild, DMed to the staff who turned on `/digest`.
+#[derive(Clone, Debug)]
+pub struct Digest {
+    /// Unix timestamps in seconds; `end` is exclusive.
+    pub start: u64,
+    pub end: u64,
+    pub orders: Summary,
+    pub vouches: u64,
+    pub average_stars: Option<f64>,
+    /// GBP/USD at the first and last refresh of the week.
+    pub rate: Option<(f64, f64)>,
+}
+
+impl Digest {
+    /// USD per GBP gained or lost over the week, as a percentage.
+    pub fn rate_change_percent(&self) -> Option<f64> {
+        self.rate
+            .map(|(first, last)| (last - first) / first * 100.0)
+    }
+}
+
+/// Monday 00:00 UTC of the week `now` falls in.
+pub fn week_start(now: u64) -> u64 {
+    const DAY: u64 = 24 * 60 * 60;
+    // 1970-01-01 was a Thursday, three days after a Monday.
+    let days = now / DAY;
+    days.saturating_sub((days + 3) % 7) * DAY
+}
+
+/// The digest for `start..end` from whatever the storage layer returned since
+/// `start`. Rows from `end` onwards are left out.
+pub fn build(
+    start: u64,
+    end: u64,
+    orders: &[Order],
+    refunds: &[Refund],
+    vouches: &[Vouch],
+    rates: &[RateSnapshot],
+) -> Digest {
+    let in_week = |timestamp: u64| (start..end).contains(&timestamp);
+    let orders: Vec<Order> = orders
+        .iter()
+        .filter(|order| order.completed_at.is_some_and(in_week))
+        .cloned()
+        .collect();
+    let refunds: Vec<Refund> = refunds
+        .iter()
+        .filter(|refund| in_week(refund.refunded_at))
+        .cloned()
+        .collect();
+    let stars: Vec<u8> = vouches
+        .iter()
+        .filter(|vouch| in_week(vouch.created_at))
+        .map(|vouch| vouch.stars)
+        .collect();
+    let mut rates = rates.iter().filter(|snapshot| in_week(snapshot.timestamp));
+    let first = rates.next();
+    let rate = first.map(|first| {
+        let last = rates.next_back().unwrap_or(first);
+        (first.gbp_to_usd, last.gbp_to_usd)
+    });
+
+    Digest {
+        start,
+        end,
+        orders: stats::summarize(&orders, &refunds),
+        vouches: stars.len() as u64,
+        average_stars: (!stars.is_empty())
+            .then(|| stars.iter().map(|stars| f64::from(*stars)).sum::<f64>() / stars.len() as f64),
+        rate,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weeks_start_on_monday() {
+        let monday = 4 * 24 * 60 * 60; // 1970-01-05
+        assert_eq!(week_start(monday), monday);
+        assert_eq!(week_start(monday + WEEK_SECONDS - 1), monday);
+        assert_eq!(week_start(monday + WEEK_SECONDS), monday + WEEK_SECONDS);
+        // Wednesday 2026-10-14 12:00 UTC.
+        assert_eq!(week_start(1_791_979_200), 1_791_763_200);
+    }
+
+    #[test]
+    fn counts_only_the_week() {
+        let vouch = |created_at, stars| Vouch {
+            guild_id: 1,
+            order_id: 1,
+            buyer_id: 2,
+            seller_id: 3,
+            stars,
+            comment: String::new(),
+            created_at,
+        };
+        let snapshot = |timestamp, gbp_to_usd| RateSnapshot {
+            timestamp,
+            gbp_to_usd,
+        };
+        let digest = build(
+            100,
+            200,
+            &[],
+            &[],
+            &[vouch(150, 5), vouch(160, 4), vouch(200, 1)],
+            &[
+                snapshot(100, 1.25),
+                snapshot(150, 1.30),
+                snapshot(250, 1.50),
+            ],
+        );
+        assert_eq!(digest.orders.orders, 0);
+        assert_eq!(digest.vouches, 2);
+        assert_eq!(digest.average_stars, Some(4.5));
+        assert_eq!(digest.rate, Some((1.25, 1.30)));
+        assert!((digest.rate_change_percent().unwrap() - 4.0).abs() < 1e-9);
+
+        assert_eq!(build(300, 400, &[], &[], &[], &[]).rate, None);
+    }
+}
@@ -0,0 +1,50 @@
+/// A problem raised on an order with `/order dispute`, by its buyer or staff.
+#[cfg(feature = "orders")]
+#[derive(Clone, Debug)]
+pub struct Dispute {
+    pub order_id: u64,
+    pub guild_id: u64,
+    pub opened_by: u64,
+    pub reason: String,
+    /// Unix timestamp in seconds.
+    pub opened_at: u64,
+}
+
+/// Money given back on a completed order with `/order refund`. An order can be
+/// refunded in several parts, up to what the buyer paid.
+#[derive(Clone, Debug)]
+pub struct Refund {
+    #[cfg_attr(not(feature = "orders"), allow(dead_code))]
+    pub order_id: u64,
+    #[cfg_attr(not(feature = "orders"), allow(dead_code))]
+    pub guild_id: u64,
+    pub gbp: f64,
+    /// `gbp` at the order's GBP/USD rate, so revenue in both currencies nets out.
+    pub usd: f64,
+    #[cfg_attr(not(feature = "orders"), allow(dead_code))]
+    pub reason: String,
+    #[cfg_attr(not(feature = "orders"), allow(dead_code))]
+    pub refunded_by: u64,
+    /// Unix timestamp in seconds.
+    pub refunded_at: u64,
+}
+
+/// What's left to refund on an order of `paid` GBP after `refunded` so far,
+/// rounded to the penny.
+#[cfg(feature = "orders")]
+pub fn refundable(paid: f64, refunded: f64) -> f64 {
+    ((paid - refunded) * 100.0).round().max(0.0) / 100.0
+}
+
+#[cfg(all(test, feature = "orders"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refundable_never_goes_negative() {
+        assert_eq!(refundable(10.0, 0.0), 10.0);
+        assert_eq!(refundable(10.0, 2.5), 7.5);
+        assert_eq!(refundable(0.3, 0.1), 0.2);
+        assert_eq!(refundable(10.0, 12.0), 0.0);
+    }
+}
@@ -0,0 +1,228 @@
+use serenity::model::channel::ReactionType;
+use std::future::Future;
+
+use crate::i18n::{t, Language};
+
+const ROBUX: &str = "R$";
+const CHECK: &str = "✅";
+const CROSS: &str = "❌";
+/// Longest unicode emoji accepted, in characters: enough for flags, skin tones
+/// and joined sequences, short enough to keep out ordinary text.
+const MAX_UNICODE_LENGTH: usize = 8;
+
+/// A guild's branding emojis, set with `/emojis`, shown in place of the plain
+/// symbols.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Emojis {
+    pub robux: Option<String>,
+    pub gbp: Option<String>,
+    pub usd: Option<String>,
+    pub check: Option<String>,
+    pub cross: Option<String>,
+}
+
+/// Which symbol an emoji replaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmojiKind {
+    Robux,
+    Gbp,
+    Usd,
+    Check,
+    Cross,
+}
+
+impl EmojiKind {
+    pub const ALL: [EmojiKind; 5] = [
+        EmojiKind::Robux,
+        EmojiKind::Gbp,
+        EmojiKind::Usd,
+        EmojiKind::Check,
+        EmojiKind::Cross,
+    ];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.code() == value)
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            EmojiKind::Robux => "robux",
+            EmojiKind::Gbp => "gbp",
+            EmojiKind::Usd => "usd",
+            EmojiKind::Check => "check",
+            EmojiKind::Cross => "cross",
+        }
+    }
+
+    pub fn name(self, lang: Language) -> &'static str {
+        let key = match self {
+            EmojiKind::Robux => "emojis.kind.robux",
+            EmojiKind::Gbp => "emojis.kind.gbp",
+            EmojiKind::Usd => "emojis.kind.usd",
+            EmojiKind::Check => "emojis.kind.check",
+            EmojiKind::Cross => "emojis.kind.cross",
+        };
+        t(lang, key)
+    }
+
+    /// What's shown when the guild hasn't set an emoji.
+    pub fn default_symbol(self) -> &'static str {
+        match self {
+            EmojiKind::Robux => ROBUX,
+            EmojiKind::Gbp => "£",
+            EmojiKind::Usd => "$",
+            EmojiKind::Check => CHECK,
+            EmojiKind::Cross => CROSS,
+        }
+    }
+}
+
+impl Emojis {
+    pub fn get(&self, kind: EmojiKind) -> Option<&str> {
+        match kind {
+            EmojiKind::Robux => self.robux.as_deref(),
+            EmojiKind::Gbp => self.gbp.as_deref(),
+            EmojiKind::Usd => self.usd.as_deref(),
+            EmojiKind::Check => self.check.as_deref(),
+            EmojiKind::Cross => self.cross.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, kind: EmojiKind, emoji: Option<String>) {
+        let slot = match kind {
+            EmojiKind::Robux => &mut self.robux,
+            EmojiKind::Gbp => &mut self.gbp,
+            EmojiKind::Usd => &mut self.usd,
+            EmojiKind::Check => &mut self.check,
+            EmojiKind::Cross => &mut self.cross,
+        };
+        *slot = emoji;
+    }
+}
+
+tokio::task_local! {
+    static EMOJIS: Emojis;
+}
+
+/// Runs `future` with the symbols below showing `emojis`, for the guild an
+/// interaction came from.
+pub async fn with_emojis<F: Future>(emojis: Emojis, future: F) -> F::Output {
+    EMOJIS.scope(emojis, future).await
+}
+
+/// Runs `f` with the plain symbols, for output that can't show Discord emojis
+/// such as rendered images.
+pub fn plain<R>(f: impl FnOnce() -> R) -> R {
+    EMOJIS.sync_scope(Emojis::default(), f)
+}
+
+/// The current guild's emoji for `kind`, or the plain symbol.
+pub fn symbol(kind: EmojiKind) -> String {
+    EMOJIS
+        .try_with(|emojis| emojis.get(kind).map(str::to_string))
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| kind.default_symbol().to_string())
+}
+
+/// The symbol for a currency code, if it has one the guild can replace.
+pub fn currency(code: &str) -> Option<String> {
+    match code {
+        "GBP" => Some(symbol(EmojiKind::Gbp)),
+        "USD" => Some(symbol(EmojiKind::Usd)),
+        _ => None,
+    }
+}
+
+pub fn robux() -> String {
+    symbol(EmojiKind::Robux)
+}
+
+/// A checkmark or cross for a yes/no result.
+pub fn mark(ok: bool) -> String {
+    symbol(if ok {
+        EmojiKind::Check
+    } else {
+        EmojiKind::Cross
+    })
+}
+
+/// The current guild's emoji for `kind` as a button emoji, if it set one.
+#[cfg(feature = "orders")]
+pub fn button(kind: EmojiKind) -> Option<ReactionType> {
+    EMOJIS
+        .try_with(|emojis| emojis.get(kind).map(str::to_string))
+        .ok()
+        .flatten()
+        .and_then(|emoji| ReactionType::try_from(emoji).ok())
+}
+
+/// Checks `input` is a custom emoji (`<:name:id>`, as Discord writes it when one
+/// is picked) or a unicode one.
+pub fn parse(input: &str, lang: Language) -> Result<String, String> {
+    let input = input.trim();
+    let invalid = || t(lang, "emojis.invalid").to_string();
+    if input.starts_with('<') {
+        return match ReactionType::try_from(input) {
+            Ok(emoji @ ReactionType::Custom { .. }) => Ok(emoji.to_string()),
+            _ => Err(invalid()),
+        };
+    }
+    let length = input.chars().count();
+    if length == 0
+        || length > MAX_UNICODE_LENGTH
+        || input
+            .chars()
+            .any(|c| c.is_ascii_alphanumeric() || c.is_whitespace())
+    {
+        return Err(invalid());
+    }
+    Ok(input.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_custom_and_unicode_emojis() {
+        assert_eq!(
+            parse(" <:robux:600404340292059257> ", Language::En),
+            Ok("<:robux:600404340292059257>".to_string())
+        );
+        assert_eq!(
+            parse("<a:spin:600404340292059257>", Language::En),
+            Ok("<a:spin:600404340292059257>".to_string())
+        );
+        assert_eq!(parse("💷", Language::En), Ok("💷".to_string()));
+        assert!(parse("<:robux:notanid>", Language::En).is_err());
+        assert!(parse("Robux", Language::En).is_err());
+        assert!(parse("", Language::En).is_err());
+    }
+
+    #[tokio::test]
+    async fn replaces_symbols_inside_the_scope() {
+        assert_eq!(robux(), "R$");
+        let emojis = Emojis {
+            robux: Some("<:robux:1>".to_string()),
+            check: Some("🟢".to_string()),
+            ..Emojis::default()
+        };
+        with_emojis(emojis, async {
+            assert_eq!(robux(), "<:robux:1>");
+            assert_eq!(currency("GBP").as_deref(), Some("£"));
+            assert_eq!(mark(true), "🟢");
+            assert_eq!(mark(false), "❌");
+            #[cfg(feature = "orders")]
+            {
+                assert!(matches!(
+                    button(EmojiKind::Robux),
+                    Some(ReactionType::Custom { .. })
+                ));
+                assert_eq!(button(EmojiKind::Cross), None);
+            }
+            assert_eq!(plain(robux), "R$");
+        })
+        .await;
+    }
+}
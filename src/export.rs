@@ -0,0 +1,126 @@
+use serde_json::{json, Value};
+
+use crate::{history::Calculation, orders::Order};
+
+/// File formats `/export` can produce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Csv => "csv",
+            Format::Json => "json",
+        }
+    }
+}
+
+/// One row or object per order. Timestamps are Unix seconds; JSON ids are strings
+/// since Discord ids don't fit in a JavaScript number.
+pub fn orders(orders: &[Order], format: Format) -> String {
+    match format {
+        Format::Csv => {
+            let mut csv = String::from(
+                "id,buyer_id,robux,type,method,gbp,usd,tax_gbp,rate,status,created_at,completed_at\n",
+            );
+            for order in orders {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{:.2},{:.2},{:.2},{},{},{},{}\n",
+                    order.id,
+                    order.buyer_id,
+                    order.robux,
+                    if order.after_tax { "a/t" } else { "b/t" },
+                    order.method.code(),
+                    order.gbp,
+                    order.usd,
+                    order.tax_gbp,
+                    order.rate,
+                    order.status.code(),
+                    order.created_at,
+                    order
+                        .completed_at
+                        .map_or(String::new(), |at| at.to_string()),
+                ));
+            }
+            csv
+        }
+        Format::Json => {
+            let orders: Vec<_> = orders.iter().map(order_json).collect();
+            serde_json::to_string_pretty(&orders).unwrap_or_default()
+        }
+    }
+}
+
+/// An order as a JSON object, shared by JSON exports and webhook payloads.
+pub fn order_json(order: &Order) -> Value {
+    json!({
+        "id": order.id,
+        "buyer_id": order.buyer_id.to_string(),
+        "robux": order.robux,
+        "type": if order.after_tax { "a/t" } else { "b/t" },
+        "method": order.method.code(),
+        "gbp": order.gbp,
+        "usd": order.usd,
+        "tax_gbp": order.tax_gbp,
+        "rate": order.rate,
+        "frozen_rates": order.frozen_rates.map(|frozen| json!({
+            "base_rate": frozen.base_rate,
+            "gbp_to_usd": frozen.gbp_to_usd,
+            "roblox_fee": frozen.roblox_fee,
+            "vat_percent": frozen.vat_percent,
+        })),
+        "status": order.status.code(),
+        "created_at": order.created_at,
+        "completed_at": order.completed_at,
+    })
+}
+
+/// One row or object per `/price` or `/robux` calculation.
+pub fn calculations(calculations: &[Calculation], format: Format) -> String {
+    match format {
+        Format::Csv => {
+            let mut csv = String::from("user_id,command,robux,gbp,usd,rate,timestamp\n");
+            for calculation in calculations {
+                csv.push_str(&format!(
+                    "{},{},{},{:.2},{:.2},{},{}\n",
+                    calculation.user_id,
+                    calculation.command,
+                    calculation.robux,
+                    calculation.gbp,
+                    calculation.usd,
+                    calculation.rate,
+                    calculation.timestamp,
+                ));
+            }
+            csv
+        }
+        Format::Json => {
+            let calculations: Vec<_> = calculations
+                .iter()
+                .map(|calculation| {
+                    json!({
+                        "user_id": calculation.user_id.to_string(),
+                        "command": calculation.command,
+                        "robux": calculation.robux,
+                        "gbp": calculation.gbp,
+                        "usd": calculation.usd,
+                        "rate": calculation.rate,
+                        "timestamp": calculation.timestamp,
+                    })
+                })
+                .collect();
+            serde_json::to_string_pretty(&calculations).unwrap_or_default()
+        }
+    }
+}
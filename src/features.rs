@@ -0,0 +1,109 @@
+use crate::{
+    i18n::{t, Language},
+    storage::GuildConfig,
+};
+
+/// A group of commands a server can turn off with `/features`. Everything is on
+/// until an admin disables it, apart from the extras [`Feature::on_by_default`]
+/// leaves off until they're enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Feature {
+    Orders,
+    Vouches,
+    Loyalty,
+    Coupons,
+    Alerts,
+    AutoReply,
+    Worth,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 7] = [
+        Feature::Orders,
+        Feature::Vouches,
+        Feature::Loyalty,
+        Feature::Coupons,
+        Feature::Alerts,
+        Feature::AutoReply,
+        Feature::Worth,
+    ];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|feature| feature.code() == value)
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Feature::Orders => "orders",
+            Feature::Vouches => "vouches",
+            Feature::Loyalty => "loyalty",
+            Feature::Coupons => "coupons",
+            Feature::Alerts => "alerts",
+            Feature::AutoReply => "autoreply",
+            Feature::Worth => "worth",
+        }
+    }
+
+    pub fn name(self, lang: Language) -> &'static str {
+        let key = match self {
+            Feature::Orders => "feature.orders",
+            Feature::Vouches => "feature.vouches",
+            Feature::Loyalty => "feature.loyalty",
+            Feature::Coupons => "feature.coupons",
+            Feature::Alerts => "feature.alerts",
+            Feature::AutoReply => "feature.autoreply",
+            Feature::Worth => "feature.worth",
+        };
+        t(lang, key)
+    }
+
+    /// The slash commands that belong to this group.
+    pub fn commands(self) -> &'static [&'static str] {
+        match self {
+            Feature::Orders => &[
+                "buy",
+                "ticket",
+                "order",
+                "stock",
+                "seller",
+                "sellers",
+                "queue",
+                "groupfunds",
+                "verifygamepass",
+                "orderform",
+                "disputechannel",
+                "middleman",
+                "orderlimits",
+            ],
+            Feature::Vouches => &["vouch", "vouchchannel", "reputation"],
+            Feature::Loyalty => &["points", "loyalty", "rolereward", "roleprice"],
+            Feature::Coupons => &["coupon"],
+            Feature::Alerts => &["alert"],
+            Feature::AutoReply => &["autoreply"],
+            Feature::Worth => &["worth"],
+        }
+    }
+
+    /// Whether the group is on in servers that haven't enabled or disabled it.
+    pub fn on_by_default(self) -> bool {
+        !matches!(self, Feature::Worth)
+    }
+
+    /// Whether the group is on in the guild with `config`.
+    pub fn is_on(self, config: &GuildConfig) -> bool {
+        if self.on_by_default() {
+            !config.disabled_features.contains(&self)
+        } else {
+            config.enabled_features.contains(&self)
+        }
+    }
+
+    /// The group `command` belongs to, if it can be turned off.
+    pub fn for_command(command: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|feature| feature.commands().contains(&command))
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    i18n::{t, tf, Language},
+    methods::DeliveryMethod,
+    numbers,
+    payments::PaymentMethod,
+};
+
+/// What a payment processor keeps from each payment: a percentage of it plus a
+/// fixed amount in GBP.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProcessorFee {
+    pub percent: f64,
+    pub fixed: f64,
+}
+
+impl ProcessorFee {
+    /// The fee on a payment of `amount` GBP.
+    pub fn on(&self, amount: f64) -> f64 {
+        if amount <= 0.0 {
+            return 0.0;
+        }
+        amount * self.percent / 100.0 + self.fixed
+    }
+
+    /// The payment that leaves `net` GBP once the fee is taken, which is what a
+    /// seller passing the fee on charges.
+    pub fn gross_up(&self, net: f64) -> f64 {
+        if net <= 0.0 {
+            return 0.0;
+        }
+        (net + self.fixed) / (1.0 - self.percent / 100.0)
+    }
+
+    /// How the fee is worked out, such as `2.9% + £0.30`, for `/fees`.
+    pub fn formula(&self, lang: Language) -> String {
+        let percent = numbers::decimal(lang, self.percent, 2);
+        match (self.percent > 0.0, self.fixed > 0.0) {
+            (true, true) => tf(
+                lang,
+                "fees.formula",
+                &[
+                    ("percent", &percent),
+                    ("fixed", &numbers::gbp(lang, self.fixed)),
+                ],
+            ),
+            (true, false) => tf(lang, "fees.formula_percent", &[("percent", &percent)]),
+            (false, true) => numbers::gbp(lang, self.fixed),
+            (false, false) => t(lang, "fees.no_fee").to_string(),
+        }
+    }
+}
+
+/// The standard UK rates for receiving a goods and services payment through
+/// `method`. Crypto only costs the network fee, which the buyer pays.
+pub fn processor_fee(method: PaymentMethod) -> ProcessorFee {
+    match method {
+        PaymentMethod::PayPal => ProcessorFee {
+            percent: 2.9,
+            fixed: 0.30,
+        },
+        PaymentMethod::CashApp => ProcessorFee {
+            percent: 2.75,
+            fixed: 0.0,
+        },
+        PaymentMethod::Crypto(_) => ProcessorFee {
+            percent: 0.0,
+            fixed: 0.0,
+        },
+    }
+}
+
+/// What a reseller makes delivering Robux they bought elsewhere.
+#[derive(Clone, Copy, Debug)]
+pub struct Margin {
+    /// Robux delivered to the buyer.
+    pub robux: u64,
+    /// Robux the reseller spends so the buyer ends up with `robux` after
+    /// Roblox's cut.
+    pub spent_robux: u64,
+    /// GBP the buyer pays.
+    pub revenue: f64,
+    /// GBP the spent Robux cost the reseller.
+    pub cost: f64,
+    /// GBP the payment processor keeps.
+    pub processor_fee: f64,
+}
+
+impl Margin {
+    pub fn profit(&self) -> f64 {
+        self.revenue - self.cost - self.processor_fee
+    }
+
+    pub fn profit_per_thousand(&self) -> f64 {
+        self.profit() / self.robux as f64 * 1000.0
+    }
+
+    /// Profit as a percentage of revenue.
+    pub fn percent(&self) -> f64 {
+        self.profit() / self.revenue * 100.0
+    }
+}
+
+/// The margin on `robux` bought at `buy_rate` and sold at `sell_rate`, both GBP
+/// per 1,000 Robux, delivered through `delivery` and paid for through `payment`.
+pub fn margin(
+    robux: u64,
+    buy_rate: f64,
+    sell_rate: f64,
+    delivery: DeliveryMethod,
+    payment: PaymentMethod,
+) -> Margin {
+    let spent_robux = delivery.exact_listing_price(robux);
+    let revenue = robux as f64 * sell_rate / 1000.0;
+    Margin {
+        robux,
+        spent_robux,
+        revenue,
+        cost: spent_robux as f64 * buy_rate / 1000.0,
+        processor_fee: processor_fee(payment).on(revenue),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::Crypto;
+
+    #[test]
+    fn grossed_up_payments_cover_the_fee() {
+        let paypal = processor_fee(PaymentMethod::PayPal);
+        for net in [0.01, 3.5, 100.0] {
+            let gross = paypal.gross_up(net);
+            assert!((gross - paypal.on(gross) - net).abs() < 1e-9);
+        }
+        assert!((paypal.gross_up(3.5) - 3.9135).abs() < 1e-4);
+        assert_eq!(paypal.gross_up(0.0), 0.0);
+        let crypto = processor_fee(PaymentMethod::Crypto(Crypto::Eth));
+        assert_eq!(crypto.gross_up(3.5), 3.5);
+    }
+
+    #[test]
+    fn margin_covers_roblox_and_processor_cuts() {
+        let gamepass = margin(
+            1000,
+            2.0,
+            4.0,
+            DeliveryMethod::Gamepass,
+            PaymentMethod::PayPal,
+        );
+        assert_eq!(gamepass.spent_robux, 1429);
+        assert!((gamepass.cost - 2.858).abs() < 1e-9);
+        assert!((gamepass.processor_fee - 0.416).abs() < 1e-9);
+        assert!((gamepass.profit() - 0.726).abs() < 1e-9);
+        assert!((gamepass.profit_per_thousand() - 0.726).abs() < 1e-9);
+
+        let group = margin(
+            10_000,
+            2.5,
+            3.5,
+            DeliveryMethod::GroupPayout,
+            PaymentMethod::Crypto(Crypto::Btc),
+        );
+        assert_eq!(group.spent_robux, 10_000);
+        assert!((group.profit() - 10.0).abs() < 1e-9);
+        assert!((group.percent() - 28.571428).abs() < 1e-4);
+    }
+}
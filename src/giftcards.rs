@@ -0,0 +1,119 @@
+/// A region Roblox sells physical and digital gift cards in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Region {
+    Us,
+    Uk,
+    Eu,
+}
+
+impl Region {
+    pub const ALL: [Region; 3] = [Region::Us, Region::Uk, Region::Eu];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Region::Us => "us",
+            Region::Uk => "uk",
+            Region::Eu => "eu",
+        }
+    }
+
+    pub fn parse(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|region| region.code() == code)
+    }
+
+    pub fn flag(self) -> &'static str {
+        match self {
+            Region::Us => "🇺🇸",
+            Region::Uk => "🇬🇧",
+            Region::Eu => "🇪🇺",
+        }
+    }
+
+    /// The currency cards in this region are printed in.
+    pub fn currency(self) -> &'static str {
+        match self {
+            Region::Us => "USD",
+            Region::Uk => "GBP",
+            Region::Eu => "EUR",
+        }
+    }
+}
+
+/// A gift card denomination and the Robux it redeems for without Premium.
+pub struct GiftCard {
+    pub region: Region,
+    pub value: f64,
+    pub robux: u64,
+}
+
+/// Gift cards as sold at retailers and on the Roblox website. Roblox changes
+/// these now and then, so keep them in step with its gift card page.
+pub const GIFT_CARDS: [GiftCard; 9] = [
+    GiftCard {
+        region: Region::Us,
+        value: 10.0,
+        robux: 800,
+    },
+    GiftCard {
+        region: Region::Us,
+        value: 25.0,
+        robux: 2000,
+    },
+    GiftCard {
+        region: Region::Us,
+        value: 50.0,
+        robux: 4500,
+    },
+    GiftCard {
+        region: Region::Uk,
+        value: 10.0,
+        robux: 800,
+    },
+    GiftCard {
+        region: Region::Uk,
+        value: 25.0,
+        robux: 2000,
+    },
+    GiftCard {
+        region: Region::Uk,
+        value: 50.0,
+        robux: 4500,
+    },
+    GiftCard {
+        region: Region::Eu,
+        value: 10.0,
+        robux: 800,
+    },
+    GiftCard {
+        region: Region::Eu,
+        value: 25.0,
+        robux: 2000,
+    },
+    GiftCard {
+        region: Region::Eu,
+        value: 50.0,
+        robux: 4500,
+    },
+];
+
+/// The gift cards sold in `region`, cheapest first.
+pub fn in_region(region: Region) -> impl Iterator<Item = &'static GiftCard> {
+    GIFT_CARDS.iter().filter(move |card| card.region == region)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_region_has_cards_in_order() {
+        for region in Region::ALL {
+            assert_eq!(Region::parse(region.code()), Some(region));
+            let cards: Vec<_> = in_region(region).collect();
+            assert!(!cards.is_empty());
+            assert!(cards
+                .windows(2)
+                .all(|pair| pair[0].value < pair[1].value && pair[0].robux < pair[1].robux));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{i18n::Language, numbers};
+
+/// How many calculations are kept per user before the oldest are dropped.
+pub const MAX_ENTRIES_PER_USER: usize = 100;
+
+/// A single `/price` or `/robux` calculation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Calculation {
+    pub guild_id: Option<u64>,
+    pub user_id: u64,
+    pub command: String,
+    pub robux: i64,
+    pub gbp: f64,
+    pub usd: f64,
+    /// GBP per Robux used for the calculation.
+    pub rate: f64,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+}
+
+impl Calculation {
+    pub fn new(
+        guild_id: Option<u64>,
+        user_id: u64,
+        command: &str,
+        robux: i64,
+        gbp: f64,
+        usd: f64,
+        rate: f64,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            guild_id,
+            user_id,
+            command: command.to_string(),
+            robux,
+            gbp,
+            usd,
+            rate,
+            timestamp,
+        }
+    }
+
+    /// Formats the calculation as one line of a `/history` embed.
+    pub fn summary(&self, lang: Language) -> String {
+        format!(
+            "<t:{}:f> `/{}` {} = {} / {} ({} / 1k R$)",
+            self.timestamp,
+            self.command,
+            numbers::robux(lang, self.robux as f64),
+            numbers::gbp(lang, self.gbp),
+            numbers::usd(lang, self.usd),
+            numbers::gbp(lang, self.rate * 1000.0)
+        )
+    }
+}
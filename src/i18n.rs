@@ -0,0 +1,9512 @@
+use serde::{Deserialize, Serialize};
+use serenity::builder::{CreateApplicationCommand, CreateApplicationCommandOption};
+use std::fmt::Display;
+
+/// Languages the bot can respond in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    En,
+    Es,
+    Pt,
+    Fr,
+}
+
+impl Language {
+    pub const ALL: [Language; 4] = [Language::En, Language::Es, Language::Pt, Language::Fr];
+
+    /// Maps a Discord locale such as `en-GB`, `es-419` or `pt-BR` to a supported language.
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        match locale.split('-').next()? {
+            "en" => Some(Language::En),
+            "es" => Some(Language::Es),
+            "pt" => Some(Language::Pt),
+            "fr" => Some(Language::Fr),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Es => "es",
+            Language::Pt => "pt",
+            Language::Fr => "fr",
+        }
+    }
+
+    /// The language's name in that language.
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::Es => "Español",
+            Language::Pt => "Português",
+            Language::Fr => "Français",
+        }
+    }
+
+    /// Discord locale codes the language is registered under.
+    pub fn discord_locales(self) -> &'static [&'static str] {
+        match self {
+            Language::En => &[],
+            Language::Es => &["es-ES", "es-419"],
+            Language::Pt => &["pt-BR"],
+            Language::Fr => &["fr"],
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Looks up `key` in the message catalog, falling back to English and then the key itself.
+pub fn t(lang: Language, key: &'static str) -> &'static str {
+    CATALOG
+        .iter()
+        .find(|(entry, _)| *entry == key)
+        .map_or(key, |(_, messages)| {
+            let message = messages[lang.index()];
+            if message.is_empty() {
+                messages[Language::En.index()]
+            } else {
+                message
+            }
+        })
+}
+
+/// Like [`t`], replacing `{name}` placeholders with the given arguments.
+pub fn tf(lang: Language, key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter()
+        .fold(t(lang, key).to_string(), |message, (name, value)| {
+            message.replace(&format!("{{{}}}", name), &value.to_string())
+        })
+}
+
+/// Sets command names and descriptions from the catalog, registering the
+/// translations as Discord localizations.
+pub trait Localized {
+    fn localized_name(&mut self, key: &'static str) -> &mut Self;
+    fn localized_description(&mut self, key: &'static str) -> &mut Self;
+}
+
+macro_rules! impl_localized {
+    ($builder:ty) => {
+        impl Localized for $builder {
+            fn localized_name(&mut self, key: &'static str) -> &mut Self {
+                let name = t(Language::En, key);
+                self.name(name);
+                for lang in Language::ALL {
+                    let translated = t(lang, key);
+                    if translated != name {
+                        for locale in lang.discord_locales() {
+                            self.name_localized(locale, translated);
+                        }
+                    }
+                }
+                self
+            }
+
+            fn localized_description(&mut self, key: &'static str) -> &mut Self {
+                self.description(t(Language::En, key));
+                for lang in Language::ALL {
+                    for locale in lang.discord_locales() {
+                        self.description_localized(locale, t(lang, key));
+                    }
+                }
+                self
+            }
+        }
+    };
+}
+
+impl_localized!(CreateApplicationCommand);
+impl_localized!(CreateApplicationCommandOption);
+
+/// Message catalog: each key maps to its English, Spanish, Portuguese and French text.
+const CATALOG: &[(&str, [&str; 4])] = &[
+    // General errors
+    (
+        "error.invalid_options",
+        [
+            "Something was wrong with the command options. Please try again.",
+            "Algo salió mal con las opciones del comando. Inténtalo de nuevo.",
+            "Algo deu errado com as opções do comando. Tente novamente.",
+            "Les options de la commande sont invalides. Veuillez réessayer.",
+        ],
+    ),
+    (
+        "error.unknown_command",
+        [
+            "Unknown command: {name}",
+            "Comando desconocido: {name}",
+            "Comando desconhecido: {name}",
+            "Commande inconnue : {name}",
+        ],
+    ),
+    (
+        "error.cooldown",
+        [
+            "You're using /{command} too quickly. Try again {time}.",
+            "Estás usando /{command} demasiado rápido. Inténtalo de nuevo {time}.",
+            "Você está usando /{command} rápido demais. Tente novamente {time}.",
+            "Vous utilisez /{command} trop rapidement. Réessayez {time}.",
+        ],
+    ),
+    (
+        "error.guild_only",
+        [
+            "This command can only be used in a server.",
+            "Este comando solo se puede usar en un servidor.",
+            "Este comando só pode ser usado em um servidor.",
+            "Cette commande ne peut être utilisée que sur un serveur.",
+        ],
+    ),
+    (
+        "error.invalid_currency",
+        [
+            "Invalid currency. Use 'GBP' or 'USD'.",
+            "Moneda no válida. Usa 'GBP' o 'USD'.",
+            "Moeda inválida. Use 'GBP' ou 'USD'.",
+            "Devise invalide. Utilisez 'GBP' ou 'USD'.",
+        ],
+    ),
+    (
+        "error.staff_only_history",
+        [
+            "Only staff can view another user's history.",
+            "Solo el staff puede ver el historial de otro usuario.",
+            "Apenas a equipe pode ver o histórico de outro usuário.",
+            "Seul le staff peut consulter l'historique d'un autre utilisateur.",
+        ],
+    ),
+    // Shared labels
+    (
+        "common.none",
+        ["None", "Ninguno", "Nenhum", "Aucun"],
+    ),
+    (
+        "common.amount_in",
+        [
+            "Amount in {currency}",
+            "Cantidad en {currency}",
+            "Valor em {currency}",
+            "Montant en {currency}",
+        ],
+    ),
+    // /price
+    (
+        "price.title",
+        [
+            "Price Calculation",
+            "Cálculo de precio",
+            "Cálculo de preço",
+            "Calcul du prix",
+        ],
+    ),
+    (
+        "price.invalid_type",
+        [
+            "Invalid type. Use 'b/t' or 'a/t'.",
+            "Tipo no válido. Usa 'b/t' o 'a/t'.",
+            "Tipo inválido. Use 'b/t' ou 'a/t'.",
+            "Type invalide. Utilisez 'b/t' ou 'a/t'.",
+        ],
+    ),
+    (
+        "price.invalid_method",
+        [
+            "Invalid delivery method.",
+            "Método de entrega no válido.",
+            "Método de entrega inválido.",
+            "Méthode de livraison invalide.",
+        ],
+    ),
+    (
+        "price.conversion_type",
+        [
+            "Conversion Type",
+            "Tipo de conversión",
+            "Tipo de conversão",
+            "Type de conversion",
+        ],
+    ),
+    (
+        "price.amount",
+        [
+            "Amount of Robux",
+            "Cantidad de Robux",
+            "Quantidade de Robux",
+            "Nombre de Robux",
+        ],
+    ),
+    ("price.rate", ["Rate", "Tarifa", "Taxa", "Tarif"]),
+    (
+        "price.tier",
+        [
+            "{min}+ R$ tier",
+            "nivel de {min}+ R$",
+            "faixa de {min}+ R$",
+            "palier {min}+ R$",
+        ],
+    ),
+    (
+        "price.method",
+        [
+            "Delivery Method",
+            "Método de entrega",
+            "Método de entrega",
+            "Méthode de livraison",
+        ],
+    ),
+    (
+        "price.premium_robux",
+        [
+            "Effective Robux with Premium",
+            "Robux efectivos con Premium",
+            "Robux efetivos com Premium",
+            "Robux effectifs avec Premium",
+        ],
+    ),
+    (
+        "price.premium_note",
+        [
+            "Assumes the buyer has Roblox Premium, which adds 10% bonus Robux on eligible purchases.",
+            "Supone que el comprador tiene Roblox Premium, que añade un 10% de Robux extra en compras elegibles.",
+            "Considera que o comprador tem Roblox Premium, que adiciona 10% de Robux bônus em compras elegíveis.",
+            "Suppose que l'acheteur a Roblox Premium, qui ajoute 10 % de Robux bonus sur les achats éligibles.",
+        ],
+    ),
+    // Delivery methods
+    (
+        "method.gamepass",
+        ["Gamepass", "Gamepass", "Gamepass", "Gamepass"],
+    ),
+    (
+        "method.devproduct",
+        [
+            "Developer Product",
+            "Producto de desarrollador",
+            "Produto de desenvolvedor",
+            "Produit développeur",
+        ],
+    ),
+    (
+        "method.group",
+        [
+            "Group Payout",
+            "Pago de grupo",
+            "Pagamento de grupo",
+            "Paiement de groupe",
+        ],
+    ),
+    (
+        "method.giftcard",
+        [
+            "Gift Card",
+            "Tarjeta regalo",
+            "Cartão-presente",
+            "Carte cadeau",
+        ],
+    ),
+    (
+        "listing.gamepass",
+        [
+            "Gamepass Price",
+            "Precio del gamepass",
+            "Preço do gamepass",
+            "Prix du gamepass",
+        ],
+    ),
+    (
+        "listing.devproduct",
+        [
+            "Product Price",
+            "Precio del producto",
+            "Preço do produto",
+            "Prix du produit",
+        ],
+    ),
+    (
+        "listing.group",
+        [
+            "Payout Amount",
+            "Cantidad del pago",
+            "Valor do pagamento",
+            "Montant du paiement",
+        ],
+    ),
+    (
+        "listing.giftcard",
+        [
+            "Card Value",
+            "Valor de la tarjeta",
+            "Valor do cartão",
+            "Valeur de la carte",
+        ],
+    ),
+    (
+        "note.group",
+        [
+            "The buyer must have been in the group for 14 days before a payout.",
+            "El comprador debe llevar 14 días en el grupo antes de recibir un pago.",
+            "O comprador precisa estar no grupo há 14 dias antes de um pagamento.",
+            "L'acheteur doit être membre du groupe depuis 14 jours avant un paiement.",
+        ],
+    ),
+    (
+        "note.giftcard",
+        [
+            "Delivered as a Roblox gift card code.",
+            "Se entrega como código de tarjeta regalo de Roblox.",
+            "Entregue como código de cartão-presente Roblox.",
+            "Livré sous forme de code de carte cadeau Roblox.",
+        ],
+    ),
+    // /convert
+    (
+        "convert.title",
+        [
+            "Currency Conversion",
+            "Conversión de moneda",
+            "Conversão de moeda",
+            "Conversion de devise",
+        ],
+    ),
+    // /robux
+    (
+        "robux.title",
+        [
+            "Robux Calculation",
+            "Cálculo de Robux",
+            "Cálculo de Robux",
+            "Calcul des Robux",
+        ],
+    ),
+    (
+        "robux.affords",
+        [
+            "{amount} {currency} affords {robux} R$ ({gbp} / {usd})",
+            "{amount} {currency} alcanzan para {robux} R$ ({gbp} / {usd})",
+            "{amount} {currency} compram {robux} R$ ({gbp} / {usd})",
+            "{amount} {currency} permettent d'obtenir {robux} R$ ({gbp} / {usd})",
+        ],
+    ),
+    // /packs
+    (
+        "packs.title",
+        [
+            "Official Robux Packs",
+            "Paquetes oficiales de Robux",
+            "Pacotes oficiais de Robux",
+            "Packs officiels de Robux",
+        ],
+    ),
+    (
+        "packs.description",
+        [
+            "Official Roblox prices compared with this server's rate, in {currency}.",
+            "Precios oficiales de Roblox comparados con la tarifa de este servidor, en {currency}.",
+            "Preços oficiais da Roblox comparados com a taxa deste servidor, em {currency}.",
+            "Prix officiels de Roblox comparés au tarif de ce serveur, en {currency}.",
+        ],
+    ),
+    ("packs.official", ["Official", "Oficial", "Oficial", "Officiel"]),
+    ("packs.seller", ["Seller", "Vendedor", "Vendedor", "Vendeur"]),
+    (
+        "packs.saves",
+        [
+            "Saves {percent}%",
+            "Ahorra un {percent}%",
+            "Economiza {percent}%",
+            "Économise {percent} %",
+        ],
+    ),
+    (
+        "packs.costs_more",
+        [
+            "Costs {percent}% more",
+            "Cuesta un {percent}% más",
+            "Custa {percent}% a mais",
+            "Coûte {percent} % de plus",
+        ],
+    ),
+    // /help
+    (
+        "help.title",
+        [
+            "Available Commands",
+            "Comandos disponibles",
+            "Comandos disponíveis",
+            "Commandes disponibles",
+        ],
+    ),
+    (
+        "help.description",
+        [
+            "Here are the available commands and their usage:\n\
+            /price: Calculate the price in GBP and USD for a given amount of Robux\n\
+            /convert: Convert between GBP and USD\n\
+            /robux: Convert GBP or USD to the amount of Robux\n\
+            /history: Show your recent price calculations\n\
+            /packs: Compare official Robux packages with this server's rate\n\
+            /pricelist show|image: Show the bulk pricing tiers for this server, or as an image to share\n\
+            /tier: Add or remove bulk pricing tiers\n\
+            /theme: Configure the embed color, footer and thumbnail for this server\n\
+            /language: Choose the language the bot replies in\n\
+            /alert: Get notified when an exchange rate crosses a threshold\n\
+            /priceboard: Post a pinned price board that updates itself\n\
+            /ratechart: Chart how GBP/USD has moved\n\
+            /buy <type> <amount>: Open a private ticket with a locked-in quote\n\
+            /vouch @seller <stars> <comment>: Rate the seller after an order\n\
+            /reputation [@user]: Show a seller's rating and recent vouches\n\
+            /stock view|set|add: Track the Robux sellers have available\n\
+            /order complete: Mark the order in this ticket delivered\n\
+            /blacklist add|remove|check: Keep known scammers from ordering\n\
+            /points: Check your loyalty points and discount\n\
+            /loyalty add|remove: Manage loyalty discounts\n\
+            /coupon create|delete|list: Manage discount codes for /price and /buy\n\
+            /payment set|remove|list: Set up the payment links shown on quotes\n\
+            /fees [robux]: See what each payment and delivery method costs, with example totals\n\
+            /vat set|clear: Add VAT to /price and /buy\n\
+            /stats [period] [csv]: Revenue summary for ticket staff\n\
+            /export orders|calculations [period] [format] [dm]: Download data for accounting\n\
+            /sheets set|clear: Log completed orders to a Google Sheet\n\
+            /webhook add|remove|list: POST order events to your own URL\n\
+            /apikey rotate|revoke: Manage the key for the HTTP API\n\
+            Right-click a message → Apps → Calculate Robux: Price the amounts it mentions\n\
+            /autoreply enable|disable [channel]: Auto-reply with prices to Robux questions\n\
+            /orderform: Post an Order button buyers can use instead of /buy\n\
+            /setcurrency [currency]: Also show /price and /robux in e.g. EUR\n\
+            /pricebatch <amounts> [type] [method]: Price up to 10 amounts in one table\n\
+            /quote bulk <amounts> [type] [method] [valid_days]: Get a formal quotation PDF for up to 10 amounts\n\
+            /ping, /uptime, /botstats: Check the bot is alive and how busy it is\n\
+            /features list|enable|disable [feature]: Turn off command groups this server doesn't use\n\
+            /groupfunds: Show the group's Robux balance and pending funds (staff only)\n\
+            /verify username: Link your Roblox account so orders use it automatically\n\
+            /limited item: Look up a limited's RAP and value on Rolimons, priced at this server's rate\n\
+            /rap username: Total the RAP of a Roblox user's limiteds, priced at this server's rate\n\
+            /setupgamepass robux: Step-by-step gamepass setup with the exact price to set\n\
+            /verifygamepass url [expected_price]: Check a buyer's gamepass owner and price before paying (staff only)\n\
+            /remind in note [dm]: Get a reminder here or by DM later, e.g. to follow up on an order\n\
+            /digest subscribe|unsubscribe|preview: Staff can get a weekly summary of orders, revenue and vouches by DM\n\
+            /seller register|payment|leave: Staff can sell at their own rate with their own payment methods\n\
+            /sellers: List the server's sellers, cheapest first, with their stock\n\
+            /queue join|leave|list|next: Wait in line for delivery; staff ping the next buyer with /queue next\n\
+            /rolereward add|remove|list: Give buyers roles like VIP once their lifetime purchases reach a milestone\n\
+            /announce ratechange [channel] [role]: Announce rate changes made with /tier in a channel, optionally pinging a role\n\
+            /order dispute|refund: Flag a ticket's order as disputed, or record a full or partial refund that /stats takes out of revenue\n\
+            /order delete <id>: Delete an order, with 10 minutes to undo it (admin only)\n\
+            /disputechannel [channel]: Log disputes and refunds to a channel\n\
+            /middleman <amount> <fee> [paid_by]: Work out what the buyer pays and the seller receives when a middleman takes a cut\n\
+            /split <robux> <people> [currency]: Split the cost of a purchase between several buyers\n\
+            /giftcards [region]: List Roblox gift cards and how they compare with this server's rate\n\
+            /value <amount> <currency>: See what an amount buys here, in official packs and as Nitro\n\
+            /worth <robux>: Put Robux in context: price here and from Roblox, hours of minimum wage and DevEx value (off until enabled with /features)\n\
+            /margin <buy_rate> <sell_rate> <amount>: Work out a reseller's profit after Roblox's cut and payment fees\n\
+            /orderlimits [min] [max]: Set the smallest and largest orders this server takes (admin only)\n\
+            /rounding <mode> [decimals]: Round prices in replies to the nearest, always up or always down (admin only)\n\
+            /rates: Show this server's sell and buy rates and the spread between them\n\
+            /setrates [sell] [buy]: Set the rates this server sells and buys Robux at; /price side:buy quotes the buy rate (admin only)\n\
+            /setrate forex <pair> <value>: Fix the exchange rate prices use, or 0 for the market rate (admin only)\n\
+            /ratehistory: See when this server's sell, buy and exchange rates changed\n\
+            /roleprice set|remove|list: Give members with a role, like boosters or VIPs, a percentage off their quotes (admin only)\n\
+            /template set|field|reset|show <command>: Reword /price and /robux replies with placeholders like {robux} and {gbp} (admin only)\n\
+            /emojis set|reset|list [kind] [emoji]: Use your server's emojis for Robux, currencies and checkmarks (admin only)\n\
+            /auditlog show|mirror [user] [count] [channel]: See who changed rates, settings, the blacklist and orders (admin only)\n\
+            /retention [history_days] [order_days]: Delete calculations and closed orders after a number of days (admin only)\n\
+            /forgetme: Delete your calculation history, preferences and Roblox link",
+            "Estos son los comandos disponibles y su uso:\n\
+            /price: Calcula el precio en GBP y USD de una cantidad de Robux\n\
+            /convert: Convierte entre GBP y USD\n\
+            /robux: Convierte GBP o USD en cantidad de Robux\n\
+            /history: Muestra tus cálculos de precio recientes\n\
+            /packs: Compara los paquetes oficiales de Robux con la tarifa de este servidor\n\
+            /pricelist show|image: Muestra los niveles de precio por volumen de este servidor, o como imagen para compartir\n\
+            /tier: Añade o elimina niveles de precio por volumen\n\
+            /theme: Configura el color, el pie y la miniatura de los embeds de este servidor\n\
+            /language: Elige el idioma en el que responde el bot\n\
+            /alert: Recibe un aviso cuando un tipo de cambio cruce un umbral\n\
+            /priceboard: Publica un tablero de precios fijado que se actualiza solo\n\
+            /ratechart: Muestra cómo ha cambiado GBP/USD\n\
+            /buy <tipo> <cantidad>: Abre un ticket privado con un presupuesto fijado\n\
+            /vouch @vendedor <estrellas> <comentario>: Valora al vendedor tras un pedido\n\
+            /reputation [@usuario]: Muestra la valoración de un vendedor\n\
+            /stock view|set|add: Controla los Robux disponibles de los vendedores\n\
+            /order complete: Marca como entregado el pedido de este ticket\n\
+            /blacklist add|remove|check: Impide que estafadores conocidos hagan pedidos\n\
+            /points: Consulta tus puntos de fidelidad y tu descuento\n\
+            /loyalty add|remove: Gestiona los descuentos por fidelidad\n\
+            /coupon create|delete|list: Gestiona códigos de descuento para /price y /buy\n\
+            /payment set|remove|list: Configura los enlaces de pago de las cotizaciones\n\
+            /fees [robux]: Mira lo que cuesta cada método de pago y de entrega, con totales de ejemplo\n\
+            /vat set|clear: Añade IVA a /price y /buy\n\
+            /stats [period] [csv]: Resumen de ingresos para el personal\n\
+            /export orders|calculations [period] [format] [dm]: Descarga datos para contabilidad\n\
+            /sheets set|clear: Registra pedidos completados en Google Sheets\n\
+            /webhook add|remove|list: Envía eventos de pedidos a tu URL\n\
+            /apikey rotate|revoke: Gestiona la clave de la API HTTP\n\
+            Clic derecho en un mensaje → Apps → Calcular Robux: Calcula las cantidades que menciona\n\
+            /autoreply enable|disable [channel]: Responde automáticamente con precios a preguntas sobre Robux\n\
+            /orderform: Publica un botón de Pedido que los compradores pueden usar en lugar de /buy\n\
+            /setcurrency [currency]: Muestra también /price y /robux en, p. ej., EUR\n\
+            /pricebatch <amounts> [type] [method]: Calcula hasta 10 cantidades en una tabla\n\
+            /quote bulk <amounts> [type] [method] [valid_days]: Obtén una cotización formal en PDF de hasta 10 cantidades\n\
+            /ping, /uptime, /botstats: Comprueba que el bot funciona y su carga\n\
+            /features list|enable|disable [feature]: Desactiva los grupos de comandos que el servidor no usa\n\
+            /groupfunds: Muestra el saldo de Robux y los fondos pendientes del grupo (solo staff)\n\
+            /verify username: Vincula tu cuenta de Roblox para que los pedidos la usen automáticamente\n\
+            /limited item: Consulta el RAP y el valor de un limitado en Rolimons, al precio de este servidor\n\
+            /rap username: Suma el RAP de los limitados de un usuario de Roblox al precio de este servidor\n\
+            /setupgamepass robux: Configuración paso a paso del gamepass con el precio exacto\n\
+            /verifygamepass url [expected_price]: Comprueba el creador y precio del gamepass antes de pagar (solo staff)\n\
+            /remind in note [dm]: Recibe un recordatorio aquí o por MD, p. ej. para seguir un pedido\n\
+            /digest subscribe|unsubscribe|preview: El staff puede recibir por MD un resumen semanal de pedidos, ingresos y vouches\n\
+            /seller register|payment|leave: El staff puede vender con su propia tarifa y métodos de pago\n\
+            /sellers: Lista los vendedores del servidor, del más barato al más caro, con su stock\n\
+            /queue join|leave|list|next: Espera en la cola de entrega; el staff menciona al siguiente con /queue next\n\
+            /rolereward add|remove|list: Da roles como VIP cuando las compras totales alcanzan un total\n\
+            /announce ratechange [channel] [role]: Anuncia en un canal los cambios de tarifa hechos con /tier, mencionando un rol si quieres\n\
+            /order dispute|refund: Marca el pedido de un ticket como en disputa o registra un reembolso total o parcial que /stats resta de los ingresos\n\
+            /order delete <id>: Elimina un pedido, con 10 minutos para deshacerlo (solo administradores)\n\
+            /disputechannel [channel]: Registra disputas y reembolsos en un canal\n\
+            /middleman <amount> <fee> [paid_by]: Calcula lo que paga el comprador y recibe el vendedor cuando un intermediario cobra comisión\n\
+            /split <robux> <people> [currency]: Divide el coste de una compra entre varios compradores\n\
+            /giftcards [region]: Muestra las tarjetas regalo de Roblox comparadas con la tarifa del servidor\n\
+            /value <amount> <currency>: Mira lo que compra una cantidad aquí, en paquetes oficiales y en Nitro\n\
+            /worth <robux>: Pon los Robux en contexto: precio aquí y en Roblox, horas de salario mínimo y valor DevEx (desactivado hasta activarlo con /features)\n\
+            /margin <buy_rate> <sell_rate> <amount>: Calcula el beneficio de un revendedor tras las comisiones\n\
+            /orderlimits [min] [max]: Establece los pedidos mínimo y máximo del servidor (solo administradores)\n\
+            /rounding <mode> [decimals]: Redondea los precios al más cercano, siempre hacia arriba o hacia abajo (solo administradores)\n\
+            /rates: Muestra las tarifas de venta y compra del servidor y su diferencia\n\
+            /setrates [sell] [buy]: Establece las tarifas de venta y compra; /price side:buy cotiza la de compra (solo administradores)\n\
+            /setrate forex <pair> <value>: Fija el tipo de cambio de los precios, o 0 para el de mercado (solo administradores)\n\
+            /ratehistory: Mira cuándo cambiaron las tarifas de venta y compra y el tipo de cambio del servidor\n\
+            /roleprice set|remove|list: Da a miembros con un rol, como boosters o VIP, un descuento en sus cotizaciones (solo administradores)\n\
+            /template set|field|reset|show <command>: Cambia el texto de /price y /robux con marcadores como {robux} y {gbp} (solo administradores)\n\
+            /emojis set|reset|list [kind] [emoji]: Usa los emojis de tu servidor para Robux, monedas y marcas (solo administradores)\n\
+            /auditlog show|mirror [user] [count] [channel]: Mira quién cambió tarifas, ajustes, la lista negra y pedidos (solo administradores)\n\
+            /retention [history_days] [order_days]: Borra los cálculos y pedidos cerrados tras un número de días (solo administradores)\n\
+            /forgetme: Borra tu historial de cálculos, preferencias y cuenta de Roblox vinculada",
+            "Estes são os comandos disponíveis e seu uso:\n\
+            /price: Calcula o preço em GBP e USD de uma quantidade de Robux\n\
+            /convert: Converte entre GBP e USD\n\
+            /robux: Converte GBP ou USD em quantidade de Robux\n\
+            /history: Mostra seus cálculos de preço recentes\n\
+            /packs: Compara os pacotes oficiais de Robux com a taxa deste servidor\n\
+            /pricelist show|image: Mostra as faixas de preço por volume deste servidor, ou como imagem para compartilhar\n\
+            /tier: Adiciona ou remove faixas de preço por volume\n\
+            /theme: Configura a cor, o rodapé e a miniatura dos embeds deste servidor\n\
+            /language: Escolhe o idioma em que o bot responde\n\
+            /alert: Receba um aviso quando uma taxa de câmbio cruzar um limite\n\
+            /priceboard: Publica um painel de preços fixado que se atualiza sozinho\n\
+            /ratechart: Mostra como GBP/USD mudou\n\
+            /buy <tipo> <quantidade>: Abre um ticket privado com um orçamento fixado\n\
+            /vouch @vendedor <estrelas> <comentário>: Avalie o vendedor após um pedido\n\
+            /reputation [@usuário]: Mostra a nota de um vendedor\n\
+            /stock view|set|add: Controla os Robux disponíveis dos vendedores\n\
+            /order complete: Marca como entregue o pedido deste ticket\n\
+            /blacklist add|remove|check: Impede que golpistas conhecidos façam pedidos\n\
+            /points: Veja seus pontos de fidelidade e seu desconto\n\
+            /loyalty add|remove: Gerencia os descontos de fidelidade\n\
+            /coupon create|delete|list: Gerencia códigos de desconto para /price e /buy\n\
+            /payment set|remove|list: Configura os links de pagamento das cotações\n\
+            /fees [robux]: Veja quanto custa cada método de pagamento e de entrega, com totais de exemplo\n\
+            /vat set|clear: Adiciona IVA a /price e /buy\n\
+            /stats [period] [csv]: Resumo de receita para a equipe\n\
+            /export orders|calculations [period] [format] [dm]: Baixa dados para contabilidade\n\
+            /sheets set|clear: Registra pedidos concluídos no Google Sheets\n\
+            /webhook add|remove|list: Envia eventos de pedidos para sua URL\n\
+            /apikey rotate|revoke: Gerencia a chave da API HTTP\n\
+            Clique com o botão direito numa mensagem → Apps → Calcular Robux: Calcula as quantias mencionadas\n\
+            /autoreply enable|disable [channel]: Responde automaticamente com preços a perguntas sobre Robux\n\
+            /orderform: Publica um botão de Pedido que os compradores podem usar em vez de /buy\n\
+            /setcurrency [currency]: Mostra também /price e /robux em, ex., EUR\n\
+            /pricebatch <amounts> [type] [method]: Calcula até 10 quantidades em uma tabela\n\
+            /quote bulk <amounts> [type] [method] [valid_days]: Receba um orçamento formal em PDF de até 10 quantidades\n\
+            /ping, /uptime, /botstats: Verifica se o bot está ativo e sua carga\n\
+            /features list|enable|disable [feature]: Desativa os grupos de comandos que o servidor não usa\n\
+            /groupfunds: Mostra o saldo de Robux e os fundos pendentes do grupo (só equipe)\n\
+            /verify username: Vincula sua conta do Roblox para que os pedidos a usem automaticamente\n\
+            /limited item: Consulta o RAP e o valor de um limitado no Rolimons, no preço deste servidor\n\
+            /rap username: Soma o RAP dos limitados de um usuário do Roblox no preço deste servidor\n\
+            /setupgamepass robux: Configuração passo a passo do gamepass com o preço exato\n\
+            /verifygamepass url [expected_price]: Confira o criador e o preço do gamepass antes de pagar (só equipe)\n\
+            /remind in note [dm]: Receba um lembrete aqui ou por DM, ex. para acompanhar um pedido\n\
+            /digest subscribe|unsubscribe|preview: A equipe pode receber por DM um resumo semanal de pedidos, receita e vouches\n\
+            /seller register|payment|leave: A equipe pode vender com sua própria taxa e métodos de pagamento\n\
+            /sellers: Lista os vendedores do servidor, do mais barato ao mais caro, com estoque\n\
+            /queue join|leave|list|next: Aguarde na fila de entrega; a equipe menciona o próximo com /queue next\n\
+            /rolereward add|remove|list: Dê cargos como VIP quando as compras totais atingem uma meta\n\
+            /announce ratechange [channel] [role]: Anuncie em um canal as mudanças de taxa feitas com /tier, mencionando um cargo se quiser\n\
+            /order dispute|refund: Marque o pedido de um ticket como em disputa ou registre um reembolso total ou parcial que /stats desconta da receita\n\
+            /order delete <id>: Exclua um pedido, com 10 minutos para desfazer (apenas administradores)\n\
+            /disputechannel [channel]: Registre disputas e reembolsos em um canal\n\
+            /middleman <amount> <fee> [paid_by]: Calcule quanto o comprador paga e o vendedor recebe quando um intermediário cobra taxa\n\
+            /split <robux> <people> [currency]: Divida o custo de uma compra entre vários compradores\n\
+            /giftcards [region]: Mostra os cartões-presente da Roblox comparados com a taxa do servidor\n\
+            /value <amount> <currency>: Veja o que uma quantia compra aqui, em pacotes oficiais e em Nitro\n\
+            /worth <robux>: Coloque Robux em contexto: preço aqui e na Roblox, horas de salário mínimo e valor DevEx (desativado até ativar com /features)\n\
+            /margin <buy_rate> <sell_rate> <amount>: Calcule o lucro de um revendedor após as taxas\n\
+            /orderlimits [min] [max]: Defina os pedidos mínimo e máximo do servidor (apenas administradores)\n\
+            /rounding <mode> [decimals]: Arredonde os preços para o mais próximo, sempre para cima ou para baixo (apenas administradores)\n\
+            /rates: Mostra as taxas de venda e compra do servidor e a diferença\n\
+            /setrates [sell] [buy]: Defina as taxas de venda e compra; /price side:buy cota a de compra (apenas administradores)\n\
+            /setrate forex <pair> <value>: Fixe a taxa de câmbio dos preços, ou 0 para a de mercado (apenas administradores)\n\
+            /ratehistory: Veja quando as taxas de venda, de compra e de câmbio do servidor mudaram\n\
+            /roleprice set|remove|list: Dê a membros com um cargo, como boosters ou VIPs, um desconto nas cotações (apenas administradores)\n\
+            /template set|field|reset|show <command>: Reescreva as respostas de /price e /robux com marcadores como {robux} e {gbp} (apenas administradores)\n\
+            /emojis set|reset|list [kind] [emoji]: Use os emojis do seu servidor para Robux, moedas e marcas (apenas administradores)\n\
+            /auditlog show|mirror [user] [count] [channel]: Veja quem alterou taxas, configurações, a lista negra e pedidos (apenas administradores)\n\
+            /retention [history_days] [order_days]: Apague cálculos e pedidos fechados após um número de dias (apenas administradores)\n\
+            /forgetme: Apague seu histórico de cálculos, preferências e conta Roblox vinculada",
+            "Voici les commandes disponibles et leur utilisation :\n\
+            /price : Calcule le prix en GBP et USD d'un nombre de Robux\n\
+            /convert : Convertit entre GBP et USD\n\
+            /robux : Convertit des GBP ou USD en Robux\n\
+            /history : Affiche vos calculs de prix récents\n\
+            /packs : Compare les packs officiels de Robux au tarif de ce serveur\n\
+            /pricelist show|image : Affiche les paliers de prix de ce serveur, ou en image à partager\n\
+            /tier : Ajoute ou supprime des paliers de prix\n\
+            /theme : Configure la couleur, le pied de page et la miniature des embeds de ce serveur\n\
+            /language : Choisit la langue des réponses du bot\n\
+            /alert : Soyez prévenu quand un taux de change franchit un seuil\n\
+            /priceboard : Publie un tableau des prix épinglé qui se met à jour tout seul\n\
+            /ratechart : Affiche l'évolution de GBP/USD\n\
+            /buy <type> <montant> : Ouvre un ticket privé avec un devis bloqué\n\
+            /vouch @vendeur <étoiles> <commentaire> : Évaluez le vendeur après une commande\n\
+            /reputation [@utilisateur] : Affiche la note d'un vendeur\n\
+            /stock view|set|add : Suit les Robux disponibles des vendeurs\n\
+            /order complete : Marque la commande de ce ticket comme livrée\n\
+            /blacklist add|remove|check : Empêche les arnaqueurs connus de commander\n\
+            /points : Consultez vos points de fidélité et votre remise\n\
+            /loyalty add|remove : Gère les remises fidélité\n\
+            /coupon create|delete|list : Gère les codes promo pour /price et /buy\n\
+            /payment set|remove|list : Configure les liens de paiement des devis\n\
+            /fees [robux] : Voyez ce que coûte chaque moyen de paiement et de livraison, avec des totaux d'exemple\n\
+            /vat set|clear : Ajoute la TVA à /price et /buy\n\
+            /stats [period] [csv] : Résumé des ventes pour le staff\n\
+            /export orders|calculations [period] [format] [dm] : Télécharge les données pour la comptabilité\n\
+            /sheets set|clear : Enregistre les commandes terminées dans Google Sheets\n\
+            /webhook add|remove|list : Envoie les événements de commande à ton URL\n\
+            /apikey rotate|revoke : Gère la clé de l'API HTTP\n\
+            Clic droit sur un message → Applications → Calculer les Robux : Calcule les montants mentionnés\n\
+            /autoreply enable|disable [channel] : Répond automatiquement avec les prix aux questions sur les Robux\n\
+            /orderform : Publie un bouton Commander que les acheteurs peuvent utiliser au lieu de /buy\n\
+            /setcurrency [currency] : Affiche aussi /price et /robux en, par ex., EUR\n\
+            /pricebatch <amounts> [type] [method] : Calcule jusqu'à 10 quantités dans un tableau\n\
+            /quote bulk <amounts> [type] [method] [valid_days] : Obtenez un devis formel en PDF pour jusqu'à 10 quantités\n\
+            /ping, /uptime, /botstats : Vérifie que le bot est en ligne et sa charge\n\
+            /features list|enable|disable [feature] : Désactive les groupes de commandes inutilisés sur le serveur\n\
+            /groupfunds : Affiche le solde de Robux et les fonds en attente du groupe (staff uniquement)\n\
+            /verify username : Lie votre compte Roblox pour que les commandes l'utilisent automatiquement\n\
+            /limited item : Consulte le RAP et la valeur d'un limité sur Rolimons, au tarif de ce serveur\n\
+            /rap username : Additionne le RAP des limités d'un utilisateur Roblox au tarif de ce serveur\n\
+            /setupgamepass robux : Configuration pas à pas du gamepass avec le prix exact\n\
+            /verifygamepass url [expected_price] : Vérifie le créateur et le prix du gamepass avant de payer (staff uniquement)\n\
+            /remind in note [dm] : Recevez un rappel ici ou en MP, ex. pour relancer une commande\n\
+            /digest subscribe|unsubscribe|preview : Le staff peut recevoir en MP un résumé hebdomadaire des commandes, revenus et vouches\n\
+            /seller register|payment|leave : Le staff peut vendre à son propre tarif avec ses moyens de paiement\n\
+            /sellers : Liste les vendeurs du serveur, du moins cher au plus cher, avec leur stock\n\
+            /queue join|leave|list|next : Attendez dans la file de livraison ; le staff mentionne le suivant avec /queue next\n\
+            /rolereward add|remove|list : Donnez des rôles comme VIP quand les achats cumulés atteignent un palier\n\
+            /announce ratechange [channel] [role] : Annoncez dans un salon les changements de tarif faits avec /tier, en mentionnant un rôle si besoin\n\
+            /order dispute|refund : Signalez une commande comme contestée ou enregistrez un remboursement total ou partiel que /stats déduit des revenus\n\
+            /order delete <id> : Supprimez une commande, avec 10 minutes pour annuler (administrateurs uniquement)\n\
+            /disputechannel [channel] : Consignez les litiges et remboursements dans un salon\n\
+            /middleman <amount> <fee> [paid_by] : Calculez ce que paie l'acheteur et reçoit le vendeur quand un intermédiaire prend une commission\n\
+            /split <robux> <people> [currency] : Partagez le coût d'un achat entre plusieurs acheteurs\n\
+            /giftcards [region] : Affiche les cartes cadeaux Roblox comparées au tarif du serveur\n\
+            /value <amount> <currency> : Voyez ce qu'une somme achète ici, en packs officiels et en Nitro\n\
+            /worth <robux> : Mettez des Robux en perspective : prix ici et chez Roblox, heures au salaire minimum et valeur DevEx (désactivé jusqu'à activation avec /features)\n\
+            /margin <buy_rate> <sell_rate> <amount> : Calculez le bénéfice d'un revendeur après les frais\n\
+            /orderlimits [min] [max] : Définissez les commandes minimale et maximale du serveur (administrateurs uniquement)\n\
+            /rounding <mode> [decimals] : Arrondissez les prix au plus proche, toujours au-dessus ou en dessous (administrateurs uniquement)\n\
+            /rates : Affiche les tarifs de vente et d'achat du serveur et leur écart\n\
+            /setrates [sell] [buy] : Définissez les tarifs de vente et d'achat ; /price side:buy utilise celui d'achat (administrateurs uniquement)\n\
+            /setrate forex <pair> <value> : Fixez le taux de change des prix, ou 0 pour le taux du marché (administrateurs uniquement)\n\
+            /ratehistory : Voyez quand les tarifs de vente et d'achat et le taux de change du serveur ont changé\n\
+            /roleprice set|remove|list : Accordez aux membres ayant un rôle, comme les boosters ou VIP, une remise sur leurs devis (administrateurs uniquement)\n\
+            /template set|field|reset|show <command> : Reformulez /price et /robux avec des marqueurs comme {robux} et {gbp} (administrateurs uniquement)\n\
+            /emojis set|reset|list [kind] [emoji] : Utilisez les emojis du serveur pour les Robux, devises et coches (administrateurs uniquement)\n\
+            /auditlog show|mirror [user] [count] [channel] : Voyez qui a modifié les taux, réglages, la liste noire et les commandes (administrateurs uniquement)\n\
+            /retention [history_days] [order_days] : Supprimez les calculs et commandes fermées après un nombre de jours (administrateurs uniquement)\n\
+            /forgetme : Supprimez votre historique de calculs, vos préférences et votre compte Roblox lié",
+        ],
+    ),
+    // /theme
+    (
+        "theme.updated",
+        [
+            "Theme Updated",
+            "Tema actualizado",
+            "Tema atualizado",
+            "Thème mis à jour",
+        ],
+    ),
+    (
+        "theme.reset",
+        [
+            "Theme Reset",
+            "Tema restablecido",
+            "Tema redefinido",
+            "Thème réinitialisé",
+        ],
+    ),
+    ("theme.color", ["Color", "Color", "Cor", "Couleur"]),
+    ("theme.footer", ["Footer", "Pie", "Rodapé", "Pied de page"]),
+    (
+        "theme.thumbnail",
+        ["Thumbnail", "Miniatura", "Miniatura", "Miniature"],
+    ),
+    (
+        "theme.nothing_to_set",
+        [
+            "Provide at least one of color, footer or thumbnail.",
+            "Indica al menos un color, pie o miniatura.",
+            "Informe pelo menos uma cor, rodapé ou miniatura.",
+            "Indiquez au moins une couleur, un pied de page ou une miniature.",
+        ],
+    ),
+    (
+        "theme.invalid_color",
+        [
+            "Invalid color. Use a hex code like '#0096FF'.",
+            "Color no válido. Usa un código hexadecimal como '#0096FF'.",
+            "Cor inválida. Use um código hexadecimal como '#0096FF'.",
+            "Couleur invalide. Utilisez un code hexadécimal comme '#0096FF'.",
+        ],
+    ),
+    (
+        "theme.invalid_thumbnail",
+        [
+            "Invalid thumbnail. Use an http(s) image URL.",
+            "Miniatura no válida. Usa una URL de imagen http(s).",
+            "Miniatura inválida. Use uma URL de imagem http(s).",
+            "Miniature invalide. Utilisez une URL d'image http(s).",
+        ],
+    ),
+    // /tier and /pricelist
+    (
+        "tier.saved",
+        [
+            "Tier Saved",
+            "Nivel guardado",
+            "Faixa salva",
+            "Palier enregistré",
+        ],
+    ),
+    (
+        "tier.removed",
+        [
+            "Tier Removed",
+            "Nivel eliminado",
+            "Faixa removida",
+            "Palier supprimé",
+        ],
+    ),
+    (
+        "tier.not_found",
+        [
+            "No tier starts at {min} R$.",
+            "Ningún nivel empieza en {min} R$.",
+            "Nenhuma faixa começa em {min} R$.",
+            "Aucun palier ne commence à {min} R$.",
+        ],
+    ),
+    (
+        "pricelist.title",
+        [
+            "Price List",
+            "Lista de precios",
+            "Tabela de preços",
+            "Grille tarifaire",
+        ],
+    ),
+    (
+        "pricelist.per_1k",
+        ["per 1k R$", "por 1k R$", "por 1k R$", "pour 1k R$"],
+    ),
+    (
+        "pricelist.under",
+        [
+            "Under {min} R$",
+            "Menos de {min} R$",
+            "Abaixo de {min} R$",
+            "Moins de {min} R$",
+        ],
+    ),
+    (
+        "pricelist.standard",
+        [
+            "Standard rate",
+            "Tarifa estándar",
+            "Taxa padrão",
+            "Tarif standard",
+        ],
+    ),
+    // /history
+    (
+        "history.title",
+        [
+            "Calculation History for {user}",
+            "Historial de cálculos de {user}",
+            "Histórico de cálculos de {user}",
+            "Historique des calculs de {user}",
+        ],
+    ),
+    (
+        "history.empty",
+        [
+            "No calculations recorded yet.",
+            "Todavía no hay cálculos registrados.",
+            "Nenhum cálculo registrado ainda.",
+            "Aucun calcul enregistré pour l'instant.",
+        ],
+    ),
+    // /language
+    (
+        "language.title",
+        [
+            "Language Updated",
+            "Idioma actualizado",
+            "Idioma atualizado",
+            "Langue mise à jour",
+        ],
+    ),
+    (
+        "language.set",
+        [
+            "Responses in this server will now be in {language}.",
+            "Las respuestas en este servidor ahora serán en {language}.",
+            "As respostas neste servidor agora serão em {language}.",
+            "Les réponses sur ce serveur seront désormais en {language}.",
+        ],
+    ),
+    (
+        "language.auto",
+        [
+            "Responses will follow this server's preferred locale.",
+            "Las respuestas seguirán el idioma preferido de este servidor.",
+            "As respostas seguirão o idioma preferido deste servidor.",
+            "Les réponses suivront la langue préférée de ce serveur.",
+        ],
+    ),
+    // Amount parsing and validation
+    (
+        "parse.invalid",
+        [
+            "Invalid amount '{input}'. Try something like 1500, 2.5k or 1m.",
+            "Cantidad no válida '{input}'. Prueba algo como 1500, 2.5k o 1m.",
+            "Valor inválido '{input}'. Tente algo como 1500, 2.5k ou 1m.",
+            "Montant invalide '{input}'. Essayez par exemple 1500, 2.5k ou 1m.",
+        ],
+    ),
+    (
+        "parse.fractional",
+        [
+            "'{input}' is not a whole number of Robux.",
+            "'{input}' no es un número entero de Robux.",
+            "'{input}' não é um número inteiro de Robux.",
+            "'{input}' n'est pas un nombre entier de Robux.",
+        ],
+    ),
+    (
+        "validation.robux_min",
+        [
+            "The amount must be at least {min} R$.",
+            "La cantidad debe ser de al menos {min} R$.",
+            "O valor deve ser de pelo menos {min} R$.",
+            "Le montant doit être d'au moins {min} R$.",
+        ],
+    ),
+    (
+        "validation.robux_max",
+        [
+            "That's more Robux than we can quote. The maximum is {max} R$.",
+            "Son más Robux de los que podemos cotizar. El máximo es {max} R$.",
+            "São mais Robux do que podemos cotar. O máximo é {max} R$.",
+            "C'est plus de Robux que nous ne pouvons en proposer. Le maximum est de {max} R$.",
+        ],
+    ),
+    (
+        "validation.fiat_min",
+        [
+            "The amount must be at least {min}.",
+            "La cantidad debe ser de al menos {min}.",
+            "O valor deve ser de pelo menos {min}.",
+            "Le montant doit être d'au moins {min}.",
+        ],
+    ),
+    (
+        "validation.fiat_max",
+        [
+            "That amount is too large. The maximum is {max}.",
+            "Esa cantidad es demasiado grande. El máximo es {max}.",
+            "Esse valor é muito alto. O máximo é {max}.",
+            "Ce montant est trop élevé. Le maximum est de {max}.",
+        ],
+    ),
+    (
+        "validation.tier_rate",
+        [
+            "The rate must be between 0 and {max} GBP per 1,000 R$.",
+            "La tarifa debe estar entre 0 y {max} GBP por 1.000 R$.",
+            "A taxa deve estar entre 0 e {max} GBP por 1.000 R$.",
+            "Le tarif doit être compris entre 0 et {max} GBP pour 1 000 R$.",
+        ],
+    ),
+    // Command registration
+    (
+        "cmd.help.name",
+        [
+            "help",
+            "ayuda",
+            "ajuda",
+            "aide",
+        ],
+    ),
+    (
+        "cmd.help",
+        [
+            "Display the available commands and their usage",
+            "Muestra los comandos disponibles y su uso",
+            "Mostra os comandos disponíveis e seu uso",
+            "Affiche les commandes disponibles et leur utilisation",
+        ],
+    ),
+    (
+        "cmd.price.name",
+        [
+            "price",
+            "precio",
+            "preco",
+            "prix",
+        ],
+    ),
+    (
+        "cmd.price",
+        [
+            "Calculate the price in GBP and USD for a given amount of Robux",
+            "Calcula el precio en GBP y USD de una cantidad de Robux",
+            "Calcula o preço em GBP e USD de uma quantidade de Robux",
+            "Calcule le prix en GBP et USD d'un nombre de Robux",
+        ],
+    ),
+    (
+        "opt.price.type",
+        [
+            "Conversion type (b/t or a/t)",
+            "Tipo de conversión (b/t o a/t)",
+            "Tipo de conversão (b/t ou a/t)",
+            "Type de conversion (b/t ou a/t)",
+        ],
+    ),
+    (
+        "opt.price.amount",
+        [
+            "Amount of Robux, e.g. 1500, 15k or 1.2m",
+            "Cantidad de Robux, p. ej. 1500, 15k o 1.2m",
+            "Quantidade de Robux, ex. 1500, 15k ou 1.2m",
+            "Nombre de Robux, par ex. 1500, 15k ou 1.2m",
+        ],
+    ),
+    (
+        "opt.price.method",
+        [
+            "Delivery method (defaults to gamepass)",
+            "Método de entrega (gamepass por defecto)",
+            "Método de entrega (gamepass por padrão)",
+            "Méthode de livraison (gamepass par défaut)",
+        ],
+    ),
+    (
+        "opt.premium",
+        [
+            "Show the bonus Robux a Roblox Premium buyer receives",
+            "Muestra los Robux extra que recibe un comprador con Roblox Premium",
+            "Mostra os Robux bônus que um comprador com Roblox Premium recebe",
+            "Affiche les Robux bonus reçus par un acheteur Roblox Premium",
+        ],
+    ),
+    (
+        "cmd.convert.name",
+        [
+            "convert",
+            "convertir",
+            "converter",
+            "convertir",
+        ],
+    ),
+    (
+        "cmd.convert",
+        [
+            "Convert between GBP and USD",
+            "Convierte entre GBP y USD",
+            "Converte entre GBP e USD",
+            "Convertit entre GBP et USD",
+        ],
+    ),
+    (
+        "opt.currency_from",
+        [
+            "Currency to convert from (GBP or USD)",
+            "Moneda de origen (GBP o USD)",
+            "Moeda de origem (GBP ou USD)",
+            "Devise de départ (GBP ou USD)",
+        ],
+    ),
+    (
+        "opt.convert_amount",
+        [
+            "Amount to convert",
+            "Cantidad a convertir",
+            "Valor a converter",
+            "Montant à convertir",
+        ],
+    ),
+    (
+        "cmd.theme.name",
+        [
+            "theme",
+            "tema",
+            "tema",
+            "theme",
+        ],
+    ),
+    (
+        "cmd.theme",
+        [
+            "Configure how the bot's embeds look in this server",
+            "Configura el aspecto de los embeds del bot en este servidor",
+            "Configura a aparência dos embeds do bot neste servidor",
+            "Configure l'apparence des embeds du bot sur ce serveur",
+        ],
+    ),
+    (
+        "opt.theme.set",
+        [
+            "Set the embed color, footer or thumbnail",
+            "Define el color, el pie o la miniatura de los embeds",
+            "Define a cor, o rodapé ou a miniatura dos embeds",
+            "Définit la couleur, le pied de page ou la miniature des embeds",
+        ],
+    ),
+    (
+        "opt.theme.color",
+        [
+            "Hex color, e.g. #0096FF",
+            "Color hexadecimal, p. ej. #0096FF",
+            "Cor hexadecimal, ex. #0096FF",
+            "Couleur hexadécimale, par ex. #0096FF",
+        ],
+    ),
+    (
+        "opt.theme.footer",
+        [
+            "Footer text, e.g. your shop name",
+            "Texto del pie, p. ej. el nombre de tu tienda",
+            "Texto do rodapé, ex. o nome da sua loja",
+            "Texte du pied de page, par ex. le nom de votre boutique",
+        ],
+    ),
+    (
+        "opt.theme.thumbnail",
+        [
+            "Thumbnail image URL",
+            "URL de la imagen en miniatura",
+            "URL da imagem em miniatura",
+            "URL de l'image miniature",
+        ],
+    ),
+    (
+        "opt.theme.reset",
+        [
+            "Restore the default embed style",
+            "Restaura el estilo predeterminado de los embeds",
+            "Restaura o estilo padrão dos embeds",
+            "Rétablit le style par défaut des embeds",
+        ],
+    ),
+    (
+        "cmd.tier.name",
+        [
+            "tier",
+            "nivel",
+            "faixa",
+            "palier",
+        ],
+    ),
+    (
+        "cmd.tier",
+        [
+            "Manage bulk pricing tiers",
+            "Gestiona los niveles de precio por volumen",
+            "Gerencia as faixas de preço por volume",
+            "Gère les paliers de prix",
+        ],
+    ),
+    (
+        "opt.tier.add",
+        [
+            "Add or replace a pricing tier",
+            "Añade o reemplaza un nivel de precio",
+            "Adiciona ou substitui uma faixa de preço",
+            "Ajoute ou remplace un palier de prix",
+        ],
+    ),
+    (
+        "opt.tier.min_robux",
+        [
+            "Smallest order (in Robux) the tier applies to",
+            "Pedido mínimo (en Robux) al que se aplica el nivel",
+            "Pedido mínimo (em Robux) ao qual a faixa se aplica",
+            "Commande minimale (en Robux) à laquelle le palier s'applique",
+        ],
+    ),
+    (
+        "opt.tier.rate",
+        [
+            "Price in GBP per 1,000 Robux",
+            "Precio en GBP por cada 1.000 Robux",
+            "Preço em GBP por 1.000 Robux",
+            "Prix en GBP pour 1 000 Robux",
+        ],
+    ),
+    (
+        "opt.tier.remove",
+        [
+            "Remove a pricing tier",
+            "Elimina un nivel de precio",
+            "Remove uma faixa de preço",
+            "Supprime un palier de prix",
+        ],
+    ),
+    (
+        "opt.tier.remove_min_robux",
+        [
+            "Threshold of the tier to remove",
+            "Umbral del nivel a eliminar",
+            "Limite da faixa a remover",
+            "Seuil du palier à supprimer",
+        ],
+    ),
+    (
+        "cmd.pricelist.name",
+        [
+            "pricelist",
+            "listaprecios",
+            "tabelaprecos",
+            "grilletarifaire",
+        ],
+    ),
+    (
+        "cmd.pricelist",
+        [
+            "Show the bulk pricing tiers for this server",
+            "Muestra los niveles de precio por volumen de este servidor",
+            "Mostra as faixas de preço por volume deste servidor",
+            "Affiche les paliers de prix de ce serveur",
+        ],
+    ),
+    (
+        "opt.pricelist.show",
+        [
+            "Show the tiers in an embed",
+            "Muestra los niveles en un mensaje",
+            "Mostra as faixas em uma mensagem",
+            "Affiche les paliers dans un message",
+        ],
+    ),
+    (
+        "opt.pricelist.image",
+        [
+            "Render the tiers and current rates as an image to share elsewhere",
+            "Genera una imagen con los niveles y tarifas actuales para compartir",
+            "Gera uma imagem com as faixas e taxas atuais para compartilhar",
+            "Génère une image des paliers et tarifs actuels à partager",
+        ],
+    ),
+    (
+        "cmd.history.name",
+        [
+            "history",
+            "historial",
+            "historico",
+            "historique",
+        ],
+    ),
+    (
+        "cmd.history",
+        [
+            "Show recent price calculations",
+            "Muestra los cálculos de precio recientes",
+            "Mostra os cálculos de preço recentes",
+            "Affiche les calculs de prix récents",
+        ],
+    ),
+    (
+        "opt.history.count",
+        [
+            "How many calculations to show (default 10)",
+            "Cuántos cálculos mostrar (10 por defecto)",
+            "Quantos cálculos mostrar (padrão 10)",
+            "Nombre de calculs à afficher (10 par défaut)",
+        ],
+    ),
+    (
+        "opt.history.user",
+        [
+            "Customer to look up (staff only)",
+            "Cliente a consultar (solo staff)",
+            "Cliente a consultar (apenas equipe)",
+            "Client à consulter (staff uniquement)",
+        ],
+    ),
+    (
+        "cmd.packs.name",
+        [
+            "packs",
+            "paquetes",
+            "pacotes",
+            "packs",
+        ],
+    ),
+    (
+        "cmd.packs",
+        [
+            "Compare official Robux packages with this server's rate",
+            "Compara los paquetes oficiales de Robux con la tarifa de este servidor",
+            "Compara os pacotes oficiais de Robux com a taxa deste servidor",
+            "Compare les packs officiels de Robux au tarif de ce serveur",
+        ],
+    ),
+    (
+        "opt.packs.currency",
+        [
+            "Currency to show prices in (defaults to GBP)",
+            "Moneda de los precios (GBP por defecto)",
+            "Moeda dos preços (GBP por padrão)",
+            "Devise d'affichage des prix (GBP par défaut)",
+        ],
+    ),
+    (
+        "cmd.language.name",
+        [
+            "language",
+            "idioma",
+            "idioma",
+            "langue",
+        ],
+    ),
+    (
+        "cmd.language",
+        [
+            "Choose the language the bot replies in",
+            "Elige el idioma en el que responde el bot",
+            "Escolhe o idioma em que o bot responde",
+            "Choisit la langue des réponses du bot",
+        ],
+    ),
+    (
+        "opt.language.language",
+        [
+            "Language to use, or auto to follow the server locale",
+            "Idioma a usar, o auto para seguir el del servidor",
+            "Idioma a usar, ou auto para seguir o do servidor",
+            "Langue à utiliser, ou auto pour suivre celle du serveur",
+        ],
+    ),
+    (
+        "cmd.robux.name",
+        [
+            "robux",
+            "robux",
+            "robux",
+            "robux",
+        ],
+    ),
+    (
+        "cmd.robux",
+        [
+            "Convert GBP or USD to the amount of Robux",
+            "Convierte GBP o USD en cantidad de Robux",
+            "Converte GBP ou USD em quantidade de Robux",
+            "Convertit des GBP ou USD en Robux",
+        ],
+    ),
+    // /alert
+    (
+        "alert.invalid_pair",
+        [
+            "Unsupported currency pair.",
+            "Par de divisas no admitido.",
+            "Par de moedas não suportado.",
+            "Paire de devises non prise en charge.",
+        ],
+    ),
+    (
+        "alert.limit",
+        [
+            "You can have at most {max} rate alerts.",
+            "Puedes tener como máximo {max} alertas de tipo de cambio.",
+            "Você pode ter no máximo {max} alertas de câmbio.",
+            "Vous pouvez avoir au maximum {max} alertes de taux.",
+        ],
+    ),
+    (
+        "alert.above",
+        [
+            "above",
+            "por encima de",
+            "acima de",
+            "au-dessus de",
+        ],
+    ),
+    (
+        "alert.below",
+        [
+            "below",
+            "por debajo de",
+            "abaixo de",
+            "en dessous de",
+        ],
+    ),
+    (
+        "alert.created",
+        [
+            "Rate Alert Created",
+            "Alerta de tipo de cambio creada",
+            "Alerta de câmbio criado",
+            "Alerte de taux créée",
+        ],
+    ),
+    (
+        "alert.created_description",
+        [
+            "Alert #{id}: you'll be notified when {pair} goes {direction} {threshold} (currently {rate}).",
+            "Alerta #{id}: te avisaremos cuando {pair} esté {direction} {threshold} (ahora {rate}).",
+            "Alerta #{id}: você será avisado quando {pair} ficar {direction} {threshold} (agora {rate}).",
+            "Alerte #{id} : vous serez prévenu quand {pair} passera {direction} {threshold} (actuellement {rate}).",
+        ],
+    ),
+    (
+        "alert.list_title",
+        [
+            "Your Rate Alerts",
+            "Tus alertas de tipo de cambio",
+            "Seus alertas de câmbio",
+            "Vos alertes de taux",
+        ],
+    ),
+    (
+        "alert.list_empty",
+        [
+            "You have no rate alerts.",
+            "No tienes alertas de tipo de cambio.",
+            "Você não tem alertas de câmbio.",
+            "Vous n'avez aucune alerte de taux.",
+        ],
+    ),
+    (
+        "alert.removed",
+        [
+            "Rate Alert Removed",
+            "Alerta de tipo de cambio eliminada",
+            "Alerta de câmbio removido",
+            "Alerte de taux supprimée",
+        ],
+    ),
+    (
+        "alert.removed_description",
+        [
+            "Alert #{id} was removed.",
+            "Se eliminó la alerta #{id}.",
+            "O alerta #{id} foi removido.",
+            "L'alerte #{id} a été supprimée.",
+        ],
+    ),
+    (
+        "alert.not_found",
+        [
+            "You have no alert #{id}.",
+            "No tienes ninguna alerta #{id}.",
+            "Você não tem o alerta #{id}.",
+            "Vous n'avez pas d'alerte #{id}.",
+        ],
+    ),
+    (
+        "alert.triggered",
+        [
+            "Rate Alert",
+            "Alerta de tipo de cambio",
+            "Alerta de câmbio",
+            "Alerte de taux",
+        ],
+    ),
+    (
+        "alert.triggered_description",
+        [
+            "{pair} is now {rate}, {direction} your alert at {threshold}.",
+            "{pair} está ahora en {rate}, {direction} tu alerta de {threshold}.",
+            "{pair} está agora em {rate}, {direction} do seu alerta de {threshold}.",
+            "{pair} est maintenant à {rate}, {direction} votre alerte à {threshold}.",
+        ],
+    ),
+    (
+        "cmd.alert.name",
+        [
+            "alert",
+            "alerta",
+            "alerta",
+            "alerte",
+        ],
+    ),
+    (
+        "cmd.alert",
+        [
+            "Get notified when an exchange rate crosses a threshold",
+            "Recibe un aviso cuando un tipo de cambio cruce un umbral",
+            "Receba um aviso quando uma taxa de câmbio cruzar um limite",
+            "Soyez prévenu quand un taux de change franchit un seuil",
+        ],
+    ),
+    (
+        "opt.alert.set",
+        [
+            "Create a rate alert",
+            "Crea una alerta de tipo de cambio",
+            "Cria um alerta de câmbio",
+            "Crée une alerte de taux",
+        ],
+    ),
+    (
+        "opt.alert.pair",
+        [
+            "Currency pair to watch",
+            "Par de divisas a vigilar",
+            "Par de moedas a acompanhar",
+            "Paire de devises à surveiller",
+        ],
+    ),
+    (
+        "opt.alert.threshold",
+        [
+            "Rate that triggers the alert",
+            "Tipo de cambio que activa la alerta",
+            "Taxa que dispara o alerta",
+            "Taux qui déclenche l'alerte",
+        ],
+    ),
+    (
+        "opt.alert.list",
+        [
+            "List your rate alerts",
+            "Muestra tus alertas de tipo de cambio",
+            "Lista seus alertas de câmbio",
+            "Liste vos alertes de taux",
+        ],
+    ),
+    (
+        "opt.alert.remove",
+        [
+            "Remove a rate alert",
+            "Elimina una alerta de tipo de cambio",
+            "Remove um alerta de câmbio",
+            "Supprime une alerte de taux",
+        ],
+    ),
+    (
+        "opt.alert.id",
+        [
+            "Id of the alert, as shown by /alert list",
+            "Id de la alerta, como aparece en /alert list",
+            "Id do alerta, como mostrado em /alert list",
+            "Id de l'alerte, tel qu'affiché par /alert list",
+        ],
+    ),
+    // /priceboard
+    (
+        "priceboard.title",
+        [
+            "Robux Price Board",
+            "Tablero de precios de Robux",
+            "Painel de preços de Robux",
+            "Tableau des prix Robux",
+        ],
+    ),
+    (
+        "priceboard.updated",
+        [
+            "Updated {time}",
+            "Actualizado {time}",
+            "Atualizado {time}",
+            "Mis à jour {time}",
+        ],
+    ),
+    (
+        "priceboard.enabled",
+        [
+            "Price Board Enabled",
+            "Tablero de precios activado",
+            "Painel de preços ativado",
+            "Tableau des prix activé",
+        ],
+    ),
+    (
+        "priceboard.enabled_description",
+        [
+            "Posted in {channel} and refreshed every {minutes} minutes.",
+            "Publicado en {channel} y actualizado cada {minutes} minutos.",
+            "Publicado em {channel} e atualizado a cada {minutes} minutos.",
+            "Publié dans {channel} et actualisé toutes les {minutes} minutes.",
+        ],
+    ),
+    (
+        "priceboard.disabled",
+        [
+            "Price Board Disabled",
+            "Tablero de precios desactivado",
+            "Painel de preços desativado",
+            "Tableau des prix désactivé",
+        ],
+    ),
+    (
+        "priceboard.disabled_description",
+        [
+            "The price board has been removed.",
+            "Se ha eliminado el tablero de precios.",
+            "O painel de preços foi removido.",
+            "Le tableau des prix a été supprimé.",
+        ],
+    ),
+    (
+        "priceboard.not_enabled",
+        [
+            "This server has no price board.",
+            "Este servidor no tiene tablero de precios.",
+            "Este servidor não tem painel de preços.",
+            "Ce serveur n'a pas de tableau des prix.",
+        ],
+    ),
+    (
+        "priceboard.post_failed",
+        [
+            "I couldn't post in {channel}. Check that I can send messages and embed links there.",
+            "No pude publicar en {channel}. Comprueba que puedo enviar mensajes e insertar enlaces allí.",
+            "Não consegui publicar em {channel}. Verifique se posso enviar mensagens e inserir links lá.",
+            "Je n'ai pas pu publier dans {channel}. Vérifiez que je peux y envoyer des messages et intégrer des liens.",
+        ],
+    ),
+    (
+        "cmd.priceboard.name",
+        [
+            "priceboard",
+            "tableroprecios",
+            "painelprecos",
+            "tableauprix",
+        ],
+    ),
+    (
+        "cmd.priceboard",
+        [
+            "Manage the auto-updating price board",
+            "Gestiona el tablero de precios que se actualiza solo",
+            "Gerencia o painel de preços com atualização automática",
+            "Gère le tableau des prix mis à jour automatiquement",
+        ],
+    ),
+    (
+        "opt.priceboard.enable",
+        [
+            "Post and pin a price board that refreshes automatically",
+            "Publica y fija un tablero de precios que se actualiza solo",
+            "Publica e fixa um painel de preços que se atualiza sozinho",
+            "Publie et épingle un tableau des prix actualisé automatiquement",
+        ],
+    ),
+    (
+        "opt.priceboard.channel",
+        [
+            "Channel to post in (defaults to this one)",
+            "Canal donde publicar (este por defecto)",
+            "Canal onde publicar (este por padrão)",
+            "Salon de publication (celui-ci par défaut)",
+        ],
+    ),
+    (
+        "opt.priceboard.minutes",
+        [
+            "Minutes between refreshes (default 10)",
+            "Minutos entre actualizaciones (10 por defecto)",
+            "Minutos entre atualizações (padrão 10)",
+            "Minutes entre deux actualisations (10 par défaut)",
+        ],
+    ),
+    (
+        "opt.priceboard.disable",
+        [
+            "Remove the price board",
+            "Elimina el tablero de precios",
+            "Remove o painel de preços",
+            "Supprime le tableau des prix",
+        ],
+    ),
+    // /ratechart
+    (
+        "ratechart.title",
+        [
+            "{pair} over the last {period}",
+            "{pair} en {period}",
+            "{pair} nos últimos {period}",
+            "{pair} sur {period}",
+        ],
+    ),
+    (
+        "ratechart.period.24h",
+        [
+            "24 hours",
+            "las últimas 24 horas",
+            "24 horas",
+            "les dernières 24 heures",
+        ],
+    ),
+    (
+        "ratechart.period.7d",
+        [
+            "7 days",
+            "los últimos 7 días",
+            "7 dias",
+            "les 7 derniers jours",
+        ],
+    ),
+    (
+        "ratechart.period.30d",
+        [
+            "30 days",
+            "los últimos 30 días",
+            "30 dias",
+            "les 30 derniers jours",
+        ],
+    ),
+    (
+        "ratechart.period.90d",
+        [
+            "90 days",
+            "los últimos 90 días",
+            "90 dias",
+            "les 90 derniers jours",
+        ],
+    ),
+    (
+        "ratechart.current",
+        [
+            "Current",
+            "Actual",
+            "Atual",
+            "Actuel",
+        ],
+    ),
+    (
+        "ratechart.high",
+        [
+            "High",
+            "Máximo",
+            "Máxima",
+            "Plus haut",
+        ],
+    ),
+    (
+        "ratechart.low",
+        [
+            "Low",
+            "Mínimo",
+            "Mínima",
+            "Plus bas",
+        ],
+    ),
+    (
+        "ratechart.change",
+        [
+            "Change",
+            "Variación",
+            "Variação",
+            "Variation",
+        ],
+    ),
+    (
+        "ratechart.not_enough_data",
+        [
+            "Not enough rate history for that period yet. Rates are recorded every time they refresh.",
+            "Todavía no hay suficiente historial de tipos para ese periodo. Los tipos se guardan cada vez que se actualizan.",
+            "Ainda não há histórico de cotações suficiente para esse período. As cotações são salvas a cada atualização.",
+            "Pas encore assez d'historique des taux pour cette période. Les taux sont enregistrés à chaque actualisation.",
+        ],
+    ),
+    (
+        "cmd.ratechart.name",
+        [
+            "ratechart",
+            "graficotipos",
+            "graficocotacao",
+            "graphiquetaux",
+        ],
+    ),
+    (
+        "cmd.ratechart",
+        [
+            "Chart how an exchange rate has moved",
+            "Muestra en un gráfico cómo ha cambiado un tipo de cambio",
+            "Mostra em um gráfico como uma cotação mudou",
+            "Affiche l'évolution d'un taux de change",
+        ],
+    ),
+    (
+        "opt.ratechart.pair",
+        [
+            "Currency pair to chart",
+            "Par de divisas a mostrar",
+            "Par de moedas a mostrar",
+            "Paire de devises à afficher",
+        ],
+    ),
+    (
+        "opt.ratechart.period",
+        [
+            "How far back to look",
+            "Periodo a mostrar",
+            "Período a mostrar",
+            "Période à afficher",
+        ],
+    ),
+    // /buy and /ticket
+    (
+        "error.manage_guild_only",
+        [
+            "You need the Manage Server permission to do that.",
+            "Necesitas el permiso Gestionar servidor para hacer eso.",
+            "Você precisa da permissão Gerenciar servidor para fazer isso.",
+            "Vous avez besoin de la permission Gérer le serveur pour faire cela.",
+        ],
+    ),
+    (
+        "ticket.not_configured",
+        [
+            "Tickets aren't set up on this server yet. An admin can run /ticket setup.",
+            "Los tickets aún no están configurados en este servidor. Un administrador puede usar /ticket setup.",
+            "Os tickets ainda não estão configurados neste servidor. Um administrador pode usar /ticket setup.",
+            "Les tickets ne sont pas encore configurés sur ce serveur. Un administrateur peut utiliser /ticket setup.",
+        ],
+    ),
+    (
+        "ticket.create_failed",
+        [
+            "I couldn't create a ticket channel. Check that I have the Manage Channels permission.",
+            "No pude crear un canal de ticket. Comprueba que tengo el permiso Gestionar canales.",
+            "Não consegui criar um canal de ticket. Verifique se tenho a permissão Gerenciar canais.",
+            "Je n'ai pas pu créer de salon de ticket. Vérifiez que j'ai la permission Gérer les salons.",
+        ],
+    ),
+    (
+        "ticket.created",
+        [
+            "Ticket Created",
+            "Ticket creado",
+            "Ticket criado",
+            "Ticket créé",
+        ],
+    ),
+    (
+        "ticket.created_description",
+        [
+            "Your order is open in {channel}.",
+            "Tu pedido está abierto en {channel}.",
+            "Seu pedido está aberto em {channel}.",
+            "Votre commande est ouverte dans {channel}.",
+        ],
+    ),
+    (
+        "ticket.setup",
+        [
+            "Tickets Configured",
+            "Tickets configurados",
+            "Tickets configurados",
+            "Tickets configurés",
+        ],
+    ),
+    (
+        "ticket.setup_description",
+        [
+            "**Staff role:** {role}\n**Category:** {category}\n**Transcript channel:** {log}",
+            "**Rol del staff:** {role}\n**Categoría:** {category}\n**Canal de transcripciones:** {log}",
+            "**Cargo da equipe:** {role}\n**Categoria:** {category}\n**Canal de transcrições:** {log}",
+            "**Rôle du staff :** {role}\n**Catégorie :** {category}\n**Salon des transcriptions :** {log}",
+        ],
+    ),
+    (
+        "ticket.not_ticket",
+        [
+            "This channel isn't an open ticket.",
+            "Este canal no es un ticket abierto.",
+            "Este canal não é um ticket aberto.",
+            "Ce salon n'est pas un ticket ouvert.",
+        ],
+    ),
+    (
+        "ticket.not_allowed",
+        [
+            "Only the buyer or staff can close this ticket.",
+            "Solo el comprador o el staff pueden cerrar este ticket.",
+            "Só o comprador ou a equipe podem fechar este ticket.",
+            "Seuls l'acheteur ou le staff peuvent fermer ce ticket.",
+        ],
+    ),
+    (
+        "ticket.closed",
+        [
+            "Ticket Closed",
+            "Ticket cerrado",
+            "Ticket fechado",
+            "Ticket fermé",
+        ],
+    ),
+    (
+        "ticket.closed_description",
+        [
+            "This channel will be deleted in a moment.",
+            "Este canal se eliminará en un momento.",
+            "Este canal será excluído em instantes.",
+            "Ce salon sera supprimé dans un instant.",
+        ],
+    ),
+    (
+        "ticket.transcript_title",
+        [
+            "Transcript: Order #{id}",
+            "Transcripción: pedido #{id}",
+            "Transcrição: pedido #{id}",
+            "Transcription : commande #{id}",
+        ],
+    ),
+    (
+        "ticket.buyer",
+        [
+            "Buyer",
+            "Comprador",
+            "Comprador",
+            "Acheteur",
+        ],
+    ),
+    (
+        "ticket.closed_by",
+        [
+            "Closed by",
+            "Cerrado por",
+            "Fechado por",
+            "Fermé par",
+        ],
+    ),
+    (
+        "order.title",
+        [
+            "Order #{id}",
+            "Pedido #{id}",
+            "Pedido #{id}",
+            "Commande #{id}",
+        ],
+    ),
+    (
+        "order.locked_in",
+        [
+            "Quote for {buyer}, locked in until this ticket is closed. Staff will be with you shortly.",
+            "Presupuesto para {buyer}, fijado hasta que se cierre este ticket. El staff te atenderá en breve.",
+            "Orçamento para {buyer}, fixado até este ticket ser fechado. A equipe vai te atender em breve.",
+            "Devis pour {buyer}, bloqué jusqu'à la fermeture de ce ticket. Le staff va vous répondre sous peu.",
+        ],
+    ),
+    (
+        "cmd.buy.name",
+        [
+            "buy",
+            "comprar",
+            "comprar",
+            "acheter",
+        ],
+    ),
+    (
+        "cmd.buy",
+        [
+            "Open a private ticket to buy Robux at the current rate",
+            "Abre un ticket privado para comprar Robux al precio actual",
+            "Abre um ticket privado para comprar Robux pela cotação atual",
+            "Ouvre un ticket privé pour acheter des Robux au tarif actuel",
+        ],
+    ),
+    (
+        "cmd.ticket.name",
+        [
+            "ticket",
+            "ticket",
+            "ticket",
+            "ticket",
+        ],
+    ),
+    (
+        "cmd.ticket",
+        [
+            "Manage purchase tickets",
+            "Gestiona los tickets de compra",
+            "Gerencia os tickets de compra",
+            "Gère les tickets d'achat",
+        ],
+    ),
+    (
+        "opt.ticket.setup",
+        [
+            "Configure purchase tickets (admins only)",
+            "Configura los tickets de compra (solo administradores)",
+            "Configura os tickets de compra (só administradores)",
+            "Configure les tickets d'achat (administrateurs uniquement)",
+        ],
+    ),
+    (
+        "opt.ticket.staff_role",
+        [
+            "Role that handles orders",
+            "Rol que gestiona los pedidos",
+            "Cargo que cuida dos pedidos",
+            "Rôle qui traite les commandes",
+        ],
+    ),
+    (
+        "opt.ticket.category",
+        [
+            "Category to create tickets in",
+            "Categoría donde crear los tickets",
+            "Categoria onde criar os tickets",
+            "Catégorie où créer les tickets",
+        ],
+    ),
+    (
+        "opt.ticket.log_channel",
+        [
+            "Channel to post closed ticket transcripts in",
+            "Canal donde publicar las transcripciones de tickets cerrados",
+            "Canal onde publicar as transcrições dos tickets fechados",
+            "Salon où publier les transcriptions des tickets fermés",
+        ],
+    ),
+    (
+        "opt.ticket.close",
+        [
+            "Close this ticket and save a transcript",
+            "Cierra este ticket y guarda una transcripción",
+            "Fecha este ticket e salva uma transcrição",
+            "Ferme ce ticket et enregistre une transcription",
+        ],
+    ),
+    // /vouch and /reputation
+    (
+        "vouch.title",
+        [
+            "New Vouch",
+            "Nueva recomendación",
+            "Nova avaliação",
+            "Nouvel avis",
+        ],
+    ),
+    (
+        "vouch.seller",
+        [
+            "Seller",
+            "Vendedor",
+            "Vendedor",
+            "Vendeur",
+        ],
+    ),
+    (
+        "vouch.order",
+        [
+            "Order",
+            "Pedido",
+            "Pedido",
+            "Commande",
+        ],
+    ),
+    (
+        "vouch.self",
+        [
+            "You can't vouch for yourself.",
+            "No puedes dejarte una recomendación a ti mismo.",
+            "Você não pode avaliar a si mesmo.",
+            "Vous ne pouvez pas vous laisser un avis.",
+        ],
+    ),
+    (
+        "vouch.no_order",
+        [
+            "You can vouch once for each of your orders a seller has completed, and that seller has no completed orders of yours left to vouch for.",
+            "Puedes dejar una recomendación por cada pedido tuyo que un vendedor haya completado, y no te quedan pedidos completados por ese vendedor por valorar.",
+            "Você pode avaliar uma vez cada pedido seu que um vendedor concluiu, e não há mais pedidos concluídos por esse vendedor para avaliar.",
+            "Vous pouvez laisser un avis pour chacune de vos commandes terminées par un vendeur, et il n'en reste aucune de ce vendeur à évaluer.",
+        ],
+    ),
+    (
+        "vouch.channel_title",
+        [
+            "Vouch Channel",
+            "Canal de recomendaciones",
+            "Canal de avaliações",
+            "Salon des avis",
+        ],
+    ),
+    (
+        "vouch.channel_set",
+        [
+            "New vouches will be posted in {channel}.",
+            "Las nuevas recomendaciones se publicarán en {channel}.",
+            "As novas avaliações serão publicadas em {channel}.",
+            "Les nouveaux avis seront publiés dans {channel}.",
+        ],
+    ),
+    (
+        "vouch.channel_cleared",
+        [
+            "Vouches will no longer be posted to a channel.",
+            "Las recomendaciones ya no se publicarán en un canal.",
+            "As avaliações não serão mais publicadas em um canal.",
+            "Les avis ne seront plus publiés dans un salon.",
+        ],
+    ),
+    (
+        "reputation.title",
+        [
+            "Reputation: {user}",
+            "Reputación: {user}",
+            "Reputação: {user}",
+            "Réputation : {user}",
+        ],
+    ),
+    (
+        "reputation.none",
+        [
+            "No vouches yet.",
+            "Aún no hay recomendaciones.",
+            "Ainda não há avaliações.",
+            "Aucun avis pour le moment.",
+        ],
+    ),
+    (
+        "reputation.average",
+        [
+            "Average Rating",
+            "Valoración media",
+            "Nota média",
+            "Note moyenne",
+        ],
+    ),
+    (
+        "reputation.total",
+        [
+            "Vouches",
+            "Recomendaciones",
+            "Avaliações",
+            "Avis",
+        ],
+    ),
+    (
+        "reputation.recent",
+        [
+            "Recent Vouches",
+            "Recomendaciones recientes",
+            "Avaliações recentes",
+            "Avis récents",
+        ],
+    ),
+    (
+        "cmd.vouch.name",
+        [
+            "vouch",
+            "recomendar",
+            "avaliar",
+            "avis",
+        ],
+    ),
+    (
+        "cmd.vouch",
+        [
+            "Rate the seller who handled your last closed order",
+            "Valora al vendedor que gestionó tu último pedido cerrado",
+            "Avalie o vendedor que cuidou do seu último pedido fechado",
+            "Évaluez le vendeur qui a traité votre dernière commande fermée",
+        ],
+    ),
+    (
+        "opt.vouch.seller",
+        [
+            "Seller who handled your order",
+            "Vendedor que gestionó tu pedido",
+            "Vendedor que cuidou do seu pedido",
+            "Vendeur qui a traité votre commande",
+        ],
+    ),
+    (
+        "opt.vouch.stars",
+        [
+            "Rating from 1 to 5 stars",
+            "Valoración de 1 a 5 estrellas",
+            "Nota de 1 a 5 estrelas",
+            "Note de 1 à 5 étoiles",
+        ],
+    ),
+    (
+        "opt.vouch.comment",
+        [
+            "A few words about the order",
+            "Unas palabras sobre el pedido",
+            "Algumas palavras sobre o pedido",
+            "Quelques mots sur la commande",
+        ],
+    ),
+    (
+        "cmd.vouchchannel.name",
+        [
+            "vouchchannel",
+            "canalrecomendaciones",
+            "canalavaliacoes",
+            "salonavis",
+        ],
+    ),
+    (
+        "cmd.vouchchannel",
+        [
+            "Choose where new vouches are posted",
+            "Elige dónde se publican las nuevas recomendaciones",
+            "Escolha onde as novas avaliações são publicadas",
+            "Choisissez où publier les nouveaux avis",
+        ],
+    ),
+    (
+        "opt.vouchchannel.channel",
+        [
+            "Channel for vouches (leave empty to stop posting)",
+            "Canal para las recomendaciones (vacío para dejar de publicar)",
+            "Canal para as avaliações (vazio para parar de publicar)",
+            "Salon des avis (laisser vide pour arrêter)",
+        ],
+    ),
+    (
+        "cmd.reputation.name",
+        [
+            "reputation",
+            "reputacion",
+            "reputacao",
+            "reputation",
+        ],
+    ),
+    (
+        "cmd.reputation",
+        [
+            "Show a seller's average rating and recent vouches",
+            "Muestra la valoración media y las recomendaciones recientes de un vendedor",
+            "Mostra a nota média e as avaliações recentes de um vendedor",
+            "Affiche la note moyenne et les avis récents d'un vendeur",
+        ],
+    ),
+    (
+        "opt.reputation.user",
+        [
+            "User to look up (defaults to you)",
+            "Usuario a consultar (tú por defecto)",
+            "Usuário a consultar (você por padrão)",
+            "Utilisateur à consulter (vous par défaut)",
+        ],
+    ),
+    // /stock and /order
+    (
+        "stock.title",
+        [
+            "Robux Stock",
+            "Stock de Robux",
+            "Estoque de Robux",
+            "Stock de Robux",
+        ],
+    ),
+    (
+        "stock.total",
+        [
+            "Total Available",
+            "Total disponible",
+            "Total disponível",
+            "Total disponible",
+        ],
+    ),
+    (
+        "stock.empty",
+        [
+            "No sellers are tracking stock.",
+            "Ningún vendedor está registrando stock.",
+            "Nenhum vendedor está controlando estoque.",
+            "Aucun vendeur ne suit son stock.",
+        ],
+    ),
+    (
+        "stock.updated",
+        [
+            "Stock Updated",
+            "Stock actualizado",
+            "Estoque atualizado",
+            "Stock mis à jour",
+        ],
+    ),
+    (
+        "stock.updated_description",
+        [
+            "You now have {robux} R$ available.",
+            "Ahora tienes {robux} R$ disponibles.",
+            "Agora você tem {robux} R$ disponíveis.",
+            "Vous avez maintenant {robux} R$ disponibles.",
+        ],
+    ),
+    (
+        "stock.staff_only",
+        [
+            "Only sellers with the ticket staff role can update stock.",
+            "Solo los vendedores con el rol de staff de tickets pueden actualizar el stock.",
+            "Só vendedores com o cargo da equipe de tickets podem atualizar o estoque.",
+            "Seuls les vendeurs avec le rôle staff des tickets peuvent modifier le stock.",
+        ],
+    ),
+    (
+        "stock.low",
+        [
+            "Only {available} R$ is in stock right now.",
+            "Ahora mismo solo hay {available} R$ en stock.",
+            "No momento só há {available} R$ em estoque.",
+            "Il n'y a que {available} R$ en stock pour le moment.",
+        ],
+    ),
+    (
+        "stock.insufficient",
+        [
+            "Sorry, only {available} R$ is in stock right now. Try a smaller amount.",
+            "Lo sentimos, ahora mismo solo hay {available} R$ en stock. Prueba con una cantidad menor.",
+            "Desculpe, no momento só há {available} R$ em estoque. Tente uma quantidade menor.",
+            "Désolé, il n'y a que {available} R$ en stock pour le moment. Essayez un montant plus petit.",
+        ],
+    ),
+    (
+        "order.staff_only",
+        [
+            "Only ticket staff can complete orders.",
+            "Solo el staff de tickets puede completar pedidos.",
+            "Só a equipe de tickets pode concluir pedidos.",
+            "Seul le staff des tickets peut terminer les commandes.",
+        ],
+    ),
+    (
+        "order.already_completed",
+        [
+            "Order #{id} has already been completed.",
+            "El pedido #{id} ya se ha completado.",
+            "O pedido #{id} já foi concluído.",
+            "La commande #{id} est déjà terminée.",
+        ],
+    ),
+    (
+        "order.completed",
+        [
+            "Order #{id} Completed",
+            "Pedido #{id} completado",
+            "Pedido #{id} concluído",
+            "Commande #{id} terminée",
+        ],
+    ),
+    (
+        "order.completed_description",
+        [
+            "{seller} delivered {robux} R$ to {buyer}. {buyer}, you can now leave a review with /vouch.",
+            "{seller} entregó {robux} R$ a {buyer}. {buyer}, ya puedes dejar una valoración con /vouch.",
+            "{seller} entregou {robux} R$ para {buyer}. {buyer}, agora você pode deixar uma avaliação com /vouch.",
+            "{seller} a livré {robux} R$ à {buyer}. {buyer}, vous pouvez maintenant laisser un avis avec /vouch.",
+        ],
+    ),
+    (
+        "cmd.stock.name",
+        [
+            "stock",
+            "stock",
+            "estoque",
+            "stock",
+        ],
+    ),
+    (
+        "cmd.stock",
+        [
+            "View or update the Robux sellers have available",
+            "Consulta o actualiza los Robux disponibles de los vendedores",
+            "Veja ou atualize os Robux disponíveis dos vendedores",
+            "Consultez ou modifiez les Robux disponibles des vendeurs",
+        ],
+    ),
+    (
+        "opt.stock.view",
+        [
+            "Show every seller's available Robux",
+            "Muestra los Robux disponibles de cada vendedor",
+            "Mostra os Robux disponíveis de cada vendedor",
+            "Affiche les Robux disponibles de chaque vendeur",
+        ],
+    ),
+    (
+        "opt.stock.set",
+        [
+            "Set how many Robux you have available",
+            "Indica cuántos Robux tienes disponibles",
+            "Define quantos Robux você tem disponíveis",
+            "Définit combien de Robux vous avez",
+        ],
+    ),
+    (
+        "opt.stock.add",
+        [
+            "Add Robux to your available stock",
+            "Añade Robux a tu stock disponible",
+            "Adiciona Robux ao seu estoque",
+            "Ajoute des Robux à votre stock",
+        ],
+    ),
+    (
+        "opt.stock.amount",
+        [
+            "Amount of Robux, e.g. 1500, 15k or 1.2m",
+            "Cantidad de Robux, p. ej. 1500, 15k o 1.2m",
+            "Quantidade de Robux, ex. 1500, 15k ou 1.2m",
+            "Nombre de Robux, par ex. 1500, 15k ou 1.2m",
+        ],
+    ),
+    (
+        "cmd.order.name",
+        [
+            "order",
+            "pedido",
+            "pedido",
+            "commande",
+        ],
+    ),
+    (
+        "cmd.order",
+        [
+            "Manage the order in this ticket",
+            "Gestiona el pedido de este ticket",
+            "Gerencia o pedido deste ticket",
+            "Gère la commande de ce ticket",
+        ],
+    ),
+    (
+        "opt.order.complete",
+        [
+            "Mark the order delivered and take it out of your stock",
+            "Marca el pedido como entregado y descuéntalo de tu stock",
+            "Marca o pedido como entregue e desconta do seu estoque",
+            "Marque la commande comme livrée et la retire de votre stock",
+        ],
+    ),
+    // /blacklist
+    (
+        "blacklist.staff_only",
+        [
+            "Only ticket staff can manage the blacklist.",
+            "Solo el staff de tickets puede gestionar la lista negra.",
+            "Só a equipe de tickets pode gerenciar a lista negra.",
+            "Seul le staff des tickets peut gérer la liste noire.",
+        ],
+    ),
+    (
+        "blacklist.owner_only",
+        [
+            "Only the bot owner can manage the global blacklist.",
+            "Solo el dueño del bot puede gestionar la lista negra global.",
+            "Só o dono do bot pode gerenciar a lista negra global.",
+            "Seul le propriétaire du bot peut gérer la liste noire globale.",
+        ],
+    ),
+    (
+        "blacklist.added",
+        [
+            "User Blacklisted",
+            "Usuario en la lista negra",
+            "Usuário na lista negra",
+            "Utilisateur sur liste noire",
+        ],
+    ),
+    (
+        "blacklist.added_description",
+        [
+            "{user} can no longer place orders or get quotes.\nReason: {reason}",
+            "{user} ya no puede hacer pedidos ni pedir cotizaciones.\nMotivo: {reason}",
+            "{user} não pode mais fazer pedidos nem pedir cotações.\nMotivo: {reason}",
+            "{user} ne peut plus passer de commandes ni demander de devis.\nRaison : {reason}",
+        ],
+    ),
+    (
+        "blacklist.removed",
+        [
+            "User Removed from Blacklist",
+            "Usuario quitado de la lista negra",
+            "Usuário removido da lista negra",
+            "Utilisateur retiré de la liste noire",
+        ],
+    ),
+    (
+        "blacklist.removed_description",
+        [
+            "{user} can place orders again.",
+            "{user} puede volver a hacer pedidos.",
+            "{user} pode fazer pedidos novamente.",
+            "{user} peut de nouveau passer des commandes.",
+        ],
+    ),
+    (
+        "blacklist.not_found",
+        [
+            "{user} isn't on that blacklist.",
+            "{user} no está en esa lista negra.",
+            "{user} não está nessa lista negra.",
+            "{user} n'est pas sur cette liste noire.",
+        ],
+    ),
+    (
+        "blacklist.title",
+        [
+            "Blacklist Check",
+            "Consulta de lista negra",
+            "Consulta da lista negra",
+            "Vérification de la liste noire",
+        ],
+    ),
+    (
+        "blacklist.clear",
+        [
+            "✅ {user} isn't blacklisted.",
+            "✅ {user} no está en la lista negra.",
+            "✅ {user} não está na lista negra.",
+            "✅ {user} n'est pas sur liste noire.",
+        ],
+    ),
+    (
+        "blacklist.listed",
+        [
+            "⛔ {user} is blacklisted.",
+            "⛔ {user} está en la lista negra.",
+            "⛔ {user} está na lista negra.",
+            "⛔ {user} est sur liste noire.",
+        ],
+    ),
+    (
+        "blacklist.scope_guild",
+        [
+            "This Server",
+            "Este servidor",
+            "Este servidor",
+            "Ce serveur",
+        ],
+    ),
+    (
+        "blacklist.scope_global",
+        [
+            "Global",
+            "Global",
+            "Global",
+            "Global",
+        ],
+    ),
+    (
+        "blacklist.refused",
+        [
+            "You've been blacklisted and can't place orders or get quotes.",
+            "Estás en la lista negra y no puedes hacer pedidos ni pedir cotizaciones.",
+            "Você está na lista negra e não pode fazer pedidos nem pedir cotações.",
+            "Vous êtes sur liste noire et ne pouvez pas passer de commandes ni demander de devis.",
+        ],
+    ),
+    (
+        "blacklist.attempt",
+        [
+            "Blacklisted User Refused",
+            "Usuario de la lista negra rechazado",
+            "Usuário da lista negra recusado",
+            "Utilisateur sur liste noire refusé",
+        ],
+    ),
+    (
+        "blacklist.attempt_description",
+        [
+            "{user} tried to use /{command}.\nReason: {reason}",
+            "{user} intentó usar /{command}.\nMotivo: {reason}",
+            "{user} tentou usar /{command}.\nMotivo: {reason}",
+            "{user} a essayé d'utiliser /{command}.\nRaison : {reason}",
+        ],
+    ),
+    (
+        "cmd.blacklist.name",
+        [
+            "blacklist",
+            "listanegra",
+            "listanegra",
+            "listenoire",
+        ],
+    ),
+    (
+        "cmd.blacklist",
+        [
+            "Keep known scammers from ordering",
+            "Impide que estafadores conocidos hagan pedidos",
+            "Impede que golpistas conhecidos façam pedidos",
+            "Empêche les arnaqueurs connus de commander",
+        ],
+    ),
+    (
+        "opt.blacklist.add",
+        [
+            "Blacklist a user",
+            "Añade un usuario a la lista negra",
+            "Adiciona um usuário à lista negra",
+            "Ajoute un utilisateur à la liste noire",
+        ],
+    ),
+    (
+        "opt.blacklist.remove",
+        [
+            "Take a user off the blacklist",
+            "Quita un usuario de la lista negra",
+            "Remove um usuário da lista negra",
+            "Retire un utilisateur de la liste noire",
+        ],
+    ),
+    (
+        "opt.blacklist.check",
+        [
+            "Check whether a user is blacklisted",
+            "Comprueba si un usuario está en la lista negra",
+            "Verifica se um usuário está na lista negra",
+            "Vérifie si un utilisateur est sur liste noire",
+        ],
+    ),
+    (
+        "opt.blacklist.user",
+        [
+            "The user",
+            "El usuario",
+            "O usuário",
+            "L'utilisateur",
+        ],
+    ),
+    (
+        "opt.blacklist.reason",
+        [
+            "Why they're being blacklisted",
+            "Por qué se añade a la lista negra",
+            "Por que está sendo adicionado à lista negra",
+            "Pourquoi il est mis sur liste noire",
+        ],
+    ),
+    (
+        "opt.blacklist.global",
+        [
+            "Apply in every server the bot is in (bot owner only)",
+            "Aplicar en todos los servidores del bot (solo el dueño del bot)",
+            "Aplicar em todos os servidores do bot (só o dono do bot)",
+            "Appliquer sur tous les serveurs du bot (propriétaire du bot uniquement)",
+        ],
+    ),
+    // /points and /loyalty
+    (
+        "validation.discount_percent",
+        [
+            "Discounts must be more than 0% and at most {max}%.",
+            "Los descuentos deben ser mayores que 0% y como máximo {max}%.",
+            "Os descontos devem ser maiores que 0% e no máximo {max}%.",
+            "Les remises doivent être supérieures à 0 % et d'au plus {max} %.",
+        ],
+    ),
+    (
+        "loyalty.discount",
+        [
+            "Loyalty Discount",
+            "Descuento por fidelidad",
+            "Desconto de fidelidade",
+            "Remise fidélité",
+        ],
+    ),
+    (
+        "loyalty.percent_off",
+        [
+            "{percent}% off",
+            "{percent}% de descuento",
+            "{percent}% de desconto",
+            "{percent} % de remise",
+        ],
+    ),
+    (
+        "loyalty.saved",
+        [
+            "Loyalty Discount Saved",
+            "Descuento por fidelidad guardado",
+            "Desconto de fidelidade salvo",
+            "Remise fidélité enregistrée",
+        ],
+    ),
+    (
+        "loyalty.removed",
+        [
+            "Loyalty Discount Removed",
+            "Descuento por fidelidad eliminado",
+            "Desconto de fidelidade removido",
+            "Remise fidélité supprimée",
+        ],
+    ),
+    (
+        "loyalty.not_found",
+        [
+            "There is no loyalty discount starting at {min} R$.",
+            "No hay ningún descuento por fidelidad que empiece en {min} R$.",
+            "Não há desconto de fidelidade começando em {min} R$.",
+            "Aucune remise fidélité ne commence à {min} R$.",
+        ],
+    ),
+    (
+        "loyalty.empty",
+        [
+            "No loyalty discounts are set up.",
+            "No hay descuentos por fidelidad configurados.",
+            "Nenhum desconto de fidelidade configurado.",
+            "Aucune remise fidélité n'est configurée.",
+        ],
+    ),
+    (
+        "loyalty.line",
+        [
+            "{min}+ R$ purchased: {percent}% off",
+            "{min}+ R$ comprados: {percent}% de descuento",
+            "{min}+ R$ comprados: {percent}% de desconto",
+            "{min}+ R$ achetés : {percent} % de remise",
+        ],
+    ),
+    (
+        "points.title",
+        [
+            "Loyalty Points",
+            "Puntos de fidelidad",
+            "Pontos de fidelidade",
+            "Points de fidélité",
+        ],
+    ),
+    (
+        "points.balance",
+        [
+            "You have **{points}** points.",
+            "Tienes **{points}** puntos.",
+            "Você tem **{points}** pontos.",
+            "Vous avez **{points}** points.",
+        ],
+    ),
+    (
+        "points.orders",
+        [
+            "Completed Orders",
+            "Pedidos completados",
+            "Pedidos concluídos",
+            "Commandes terminées",
+        ],
+    ),
+    (
+        "points.purchased",
+        [
+            "Robux Purchased",
+            "Robux comprados",
+            "Robux comprados",
+            "Robux achetés",
+        ],
+    ),
+    (
+        "points.next",
+        [
+            "Next Discount",
+            "Próximo descuento",
+            "Próximo desconto",
+            "Prochaine remise",
+        ],
+    ),
+    (
+        "points.next_description",
+        [
+            "Buy {robux} more R$ to get {percent}% off.",
+            "Compra {robux} R$ más para obtener un {percent}% de descuento.",
+            "Compre mais {robux} R$ para ganhar {percent}% de desconto.",
+            "Achetez encore {robux} R$ pour obtenir {percent} % de remise.",
+        ],
+    ),
+    (
+        "cmd.points.name",
+        [
+            "points",
+            "puntos",
+            "pontos",
+            "points",
+        ],
+    ),
+    (
+        "cmd.points",
+        [
+            "Check your loyalty points and discount",
+            "Consulta tus puntos de fidelidad y tu descuento",
+            "Veja seus pontos de fidelidade e seu desconto",
+            "Consultez vos points de fidélité et votre remise",
+        ],
+    ),
+    (
+        "cmd.loyalty.name",
+        [
+            "loyalty",
+            "fidelidad",
+            "fidelidade",
+            "fidelite",
+        ],
+    ),
+    (
+        "cmd.loyalty",
+        [
+            "Manage loyalty discounts for repeat buyers",
+            "Gestiona los descuentos por fidelidad para clientes habituales",
+            "Gerencia os descontos de fidelidade para clientes frequentes",
+            "Gère les remises fidélité des clients réguliers",
+        ],
+    ),
+    (
+        "opt.loyalty.add",
+        [
+            "Add or update a loyalty discount",
+            "Añade o actualiza un descuento por fidelidad",
+            "Adiciona ou atualiza um desconto de fidelidade",
+            "Ajoute ou modifie une remise fidélité",
+        ],
+    ),
+    (
+        "opt.loyalty.remove",
+        [
+            "Remove a loyalty discount",
+            "Elimina un descuento por fidelidad",
+            "Remove um desconto de fidelidade",
+            "Supprime une remise fidélité",
+        ],
+    ),
+    (
+        "opt.loyalty.min_robux",
+        [
+            "Total Robux a buyer must have purchased",
+            "Robux totales que el comprador debe haber comprado",
+            "Total de Robux que o comprador precisa ter comprado",
+            "Total de Robux que l'acheteur doit avoir acheté",
+        ],
+    ),
+    (
+        "opt.loyalty.percent",
+        [
+            "Percentage off every quote",
+            "Porcentaje de descuento en cada cotización",
+            "Porcentagem de desconto em cada cotação",
+            "Pourcentage de remise sur chaque devis",
+        ],
+    ),
+    (
+        "opt.loyalty.remove_min_robux",
+        [
+            "Threshold of the discount to remove",
+            "Umbral del descuento que quieres eliminar",
+            "Limite do desconto a remover",
+            "Seuil de la remise à supprimer",
+        ],
+    ),
+    // /coupon
+    (
+        "coupon.label",
+        [
+            "Coupon",
+            "Cupón",
+            "Cupom",
+            "Coupon",
+        ],
+    ),
+    (
+        "coupon.invalid",
+        [
+            "The coupon `{code}` doesn't exist, has expired or has no uses left.",
+            "El cupón `{code}` no existe, ha caducado o no le quedan usos.",
+            "O cupom `{code}` não existe, expirou ou não tem mais usos.",
+            "Le coupon `{code}` n'existe pas, a expiré ou n'a plus d'utilisations.",
+        ],
+    ),
+    (
+        "coupon.flat_off",
+        [
+            "{amount} off",
+            "{amount} de descuento",
+            "{amount} de desconto",
+            "{amount} de remise",
+        ],
+    ),
+    (
+        "coupon.uses_left",
+        [
+            "{uses} uses left",
+            "quedan {uses} usos",
+            "restam {uses} usos",
+            "{uses} utilisations restantes",
+        ],
+    ),
+    (
+        "coupon.unlimited",
+        [
+            "unlimited uses",
+            "usos ilimitados",
+            "usos ilimitados",
+            "utilisations illimitées",
+        ],
+    ),
+    (
+        "coupon.expires",
+        [
+            "expires {time}",
+            "caduca {time}",
+            "expira {time}",
+            "expire {time}",
+        ],
+    ),
+    (
+        "coupon.title",
+        [
+            "Coupons",
+            "Cupones",
+            "Cupons",
+            "Coupons",
+        ],
+    ),
+    (
+        "coupon.empty",
+        [
+            "No coupons have been created.",
+            "No se ha creado ningún cupón.",
+            "Nenhum cupom foi criado.",
+            "Aucun coupon n'a été créé.",
+        ],
+    ),
+    (
+        "coupon.created",
+        [
+            "Coupon Created",
+            "Cupón creado",
+            "Cupom criado",
+            "Coupon créé",
+        ],
+    ),
+    (
+        "coupon.exists",
+        [
+            "A coupon with the code `{code}` already exists.",
+            "Ya existe un cupón con el código `{code}`.",
+            "Já existe um cupom com o código `{code}`.",
+            "Un coupon avec le code `{code}` existe déjà.",
+        ],
+    ),
+    (
+        "coupon.not_found",
+        [
+            "There is no coupon with the code `{code}`.",
+            "No hay ningún cupón con el código `{code}`.",
+            "Não há cupom com o código `{code}`.",
+            "Aucun coupon n'a le code `{code}`.",
+        ],
+    ),
+    (
+        "coupon.deleted",
+        [
+            "Coupon Deleted",
+            "Cupón eliminado",
+            "Cupom excluído",
+            "Coupon supprimé",
+        ],
+    ),
+    (
+        "coupon.deleted_description",
+        [
+            "`{code}` can no longer be redeemed.",
+            "`{code}` ya no se puede canjear.",
+            "`{code}` não pode mais ser resgatado.",
+            "`{code}` ne peut plus être utilisé.",
+        ],
+    ),
+    (
+        "opt.coupon",
+        [
+            "Coupon code to apply",
+            "Código de cupón a aplicar",
+            "Código do cupom a aplicar",
+            "Code promo à appliquer",
+        ],
+    ),
+    (
+        "cmd.coupon.name",
+        [
+            "coupon",
+            "cupon",
+            "cupom",
+            "coupon",
+        ],
+    ),
+    (
+        "cmd.coupon",
+        [
+            "Create and manage discount codes",
+            "Crea y gestiona códigos de descuento",
+            "Crie e gerencie códigos de desconto",
+            "Crée et gère des codes de réduction",
+        ],
+    ),
+    (
+        "opt.coupon.create",
+        [
+            "Create a coupon code",
+            "Crea un código de cupón",
+            "Cria um código de cupom",
+            "Crée un code promo",
+        ],
+    ),
+    (
+        "opt.coupon.delete",
+        [
+            "Delete a coupon code",
+            "Elimina un código de cupón",
+            "Exclui um código de cupom",
+            "Supprime un code promo",
+        ],
+    ),
+    (
+        "opt.coupon.list",
+        [
+            "List this server's coupons",
+            "Muestra los cupones del servidor",
+            "Lista os cupons do servidor",
+            "Liste les coupons du serveur",
+        ],
+    ),
+    (
+        "opt.coupon.code",
+        [
+            "The code buyers enter",
+            "El código que introducen los compradores",
+            "O código que os compradores digitam",
+            "Le code saisi par les acheteurs",
+        ],
+    ),
+    (
+        "opt.coupon.kind",
+        [
+            "Percentage or flat GBP discount",
+            "Descuento en porcentaje o fijo en GBP",
+            "Desconto em porcentagem ou fixo em GBP",
+            "Remise en pourcentage ou fixe en GBP",
+        ],
+    ),
+    (
+        "opt.coupon.value",
+        [
+            "Percent off, or GBP off for flat coupons",
+            "Porcentaje de descuento, o GBP para cupones fijos",
+            "Porcentagem de desconto, ou GBP para cupons fixos",
+            "Pourcentage de remise, ou GBP pour les coupons fixes",
+        ],
+    ),
+    (
+        "opt.coupon.max_uses",
+        [
+            "How many times it can be redeemed (unlimited if empty)",
+            "Cuántas veces se puede canjear (ilimitado si se deja vacío)",
+            "Quantas vezes pode ser resgatado (ilimitado se vazio)",
+            "Nombre d'utilisations possibles (illimité si vide)",
+        ],
+    ),
+    (
+        "opt.coupon.expires_in_days",
+        [
+            "Days until the coupon expires (never if empty)",
+            "Días hasta que caduque (nunca si se deja vacío)",
+            "Dias até o cupom expirar (nunca se vazio)",
+            "Jours avant expiration (jamais si vide)",
+        ],
+    ),
+    // Crypto
+    (
+        "crypto.unavailable",
+        [
+            "Crypto prices haven't been fetched yet. Try again in a minute.",
+            "Aún no se han obtenido los precios de las criptomonedas. Inténtalo de nuevo en un minuto.",
+            "Os preços das criptomoedas ainda não foram obtidos. Tente novamente em um minuto.",
+            "Les prix des cryptomonnaies n'ont pas encore été récupérés. Réessayez dans une minute.",
+        ],
+    ),
+    (
+        "crypto.updated",
+        [
+            "Crypto prices from CoinGecko, updated {time}",
+            "Precios de criptomonedas de CoinGecko, actualizados {time}",
+            "Preços de criptomoedas do CoinGecko, atualizados {time}",
+            "Prix des cryptomonnaies de CoinGecko, mis à jour {time}",
+        ],
+    ),
+    (
+        "opt.convert_to",
+        [
+            "Currency to convert to",
+            "Moneda de destino",
+            "Moeda de destino",
+            "Devise de destination",
+        ],
+    ),
+    (
+        "opt.price.crypto",
+        [
+            "Also show the total in this cryptocurrency",
+            "Muestra también el total en esta criptomoneda",
+            "Mostra também o total nesta criptomoeda",
+            "Affiche aussi le total dans cette cryptomonnaie",
+        ],
+    ),
+    // /payment
+    (
+        "payment.pay",
+        [
+            "Pay",
+            "Pagar",
+            "Pagar",
+            "Payer",
+        ],
+    ),
+    (
+        "payment.title",
+        [
+            "Payment Methods",
+            "Métodos de pago",
+            "Métodos de pagamento",
+            "Moyens de paiement",
+        ],
+    ),
+    (
+        "payment.empty",
+        [
+            "No payment methods are set up.",
+            "No hay métodos de pago configurados.",
+            "Nenhum método de pagamento configurado.",
+            "Aucun moyen de paiement n'est configuré.",
+        ],
+    ),
+    (
+        "payment.saved",
+        [
+            "Payment Method Saved",
+            "Método de pago guardado",
+            "Método de pagamento salvo",
+            "Moyen de paiement enregistré",
+        ],
+    ),
+    (
+        "payment.removed",
+        [
+            "Payment Method Removed",
+            "Método de pago eliminado",
+            "Método de pagamento removido",
+            "Moyen de paiement supprimé",
+        ],
+    ),
+    (
+        "payment.not_found",
+        [
+            "No {method} handle is set up.",
+            "No hay ninguna cuenta de {method} configurada.",
+            "Nenhuma conta de {method} configurada.",
+            "Aucun compte {method} n'est configuré.",
+        ],
+    ),
+    (
+        "payment.invalid_handle",
+        [
+            "That doesn't look like a valid {method} handle.",
+            "Eso no parece una cuenta de {method} válida.",
+            "Isso não parece uma conta de {method} válida.",
+            "Cela ne ressemble pas à un compte {method} valide.",
+        ],
+    ),
+    (
+        "cmd.payment.name",
+        [
+            "payment",
+            "pago",
+            "pagamento",
+            "paiement",
+        ],
+    ),
+    (
+        "cmd.payment",
+        [
+            "Set up the payment links shown on quotes",
+            "Configura los enlaces de pago de las cotizaciones",
+            "Configura os links de pagamento das cotações",
+            "Configure les liens de paiement des devis",
+        ],
+    ),
+    (
+        "opt.payment.set",
+        [
+            "Set the handle for a payment method",
+            "Configura la cuenta de un método de pago",
+            "Define a conta de um método de pagamento",
+            "Définit le compte d'un moyen de paiement",
+        ],
+    ),
+    (
+        "opt.payment.remove",
+        [
+            "Remove a payment method",
+            "Elimina un método de pago",
+            "Remove um método de pagamento",
+            "Supprime un moyen de paiement",
+        ],
+    ),
+    (
+        "opt.payment.list",
+        [
+            "Show the payment methods",
+            "Muestra los métodos de pago",
+            "Mostra os métodos de pagamento",
+            "Affiche les moyens de paiement",
+        ],
+    ),
+    (
+        "opt.payment.method",
+        [
+            "Payment method",
+            "Método de pago",
+            "Método de pagamento",
+            "Moyen de paiement",
+        ],
+    ),
+    (
+        "opt.payment.handle",
+        [
+            "PayPal.me username, $cashtag or wallet address",
+            "Usuario de PayPal.me, $cashtag o dirección de billetera",
+            "Usuário do PayPal.me, $cashtag ou endereço da carteira",
+            "Nom PayPal.me, $cashtag ou adresse de portefeuille",
+        ],
+    ),
+    // QR codes
+    (
+        "opt.buy.crypto",
+        [
+            "Pay in this cryptocurrency",
+            "Pagar con esta criptomoneda",
+            "Pagar com esta criptomoeda",
+            "Payer avec cette cryptomonnaie",
+        ],
+    ),
+    // /vat
+    (
+        "vat.label",
+        [
+            "VAT",
+            "IVA",
+            "IVA",
+            "TVA",
+        ],
+    ),
+    (
+        "vat.summary",
+        [
+            "{rate}% ({region})",
+            "{rate}% ({region})",
+            "{rate}% ({region})",
+            "{rate} % ({region})",
+        ],
+    ),
+    (
+        "vat.excluding",
+        [
+            "Excl. VAT",
+            "Sin IVA",
+            "Sem IVA",
+            "HT",
+        ],
+    ),
+    (
+        "vat.amount",
+        [
+            "VAT {summary}",
+            "IVA {summary}",
+            "IVA {summary}",
+            "TVA {summary}",
+        ],
+    ),
+    (
+        "vat.title",
+        [
+            "VAT",
+            "IVA",
+            "IVA",
+            "TVA",
+        ],
+    ),
+    (
+        "vat.set_description",
+        [
+            "Prices now include {summary} VAT.",
+            "Los precios ahora incluyen un IVA del {summary}.",
+            "Os preços agora incluem IVA de {summary}.",
+            "Les prix incluent désormais une TVA de {summary}.",
+        ],
+    ),
+    (
+        "vat.cleared_description",
+        [
+            "Prices no longer include VAT.",
+            "Los precios ya no incluyen IVA.",
+            "Os preços não incluem mais IVA.",
+            "Les prix n'incluent plus la TVA.",
+        ],
+    ),
+    (
+        "vat.invalid_rate",
+        [
+            "The VAT rate must be above 0% and at most {max}%.",
+            "El IVA debe ser mayor que 0% y como máximo {max}%.",
+            "O IVA deve ser maior que 0% e no máximo {max}%.",
+            "Le taux de TVA doit être supérieur à 0 % et au plus {max} %.",
+        ],
+    ),
+    (
+        "vat.invalid_region",
+        [
+            "The region must be 2 to 8 letters, e.g. UK or DE.",
+            "La región debe tener de 2 a 8 letras, p. ej. UK o ES.",
+            "A região deve ter de 2 a 8 letras, ex. UK ou PT.",
+            "La région doit comporter 2 à 8 lettres, par ex. UK ou FR.",
+        ],
+    ),
+    (
+        "cmd.vat.name",
+        [
+            "vat",
+            "iva",
+            "iva",
+            "tva",
+        ],
+    ),
+    (
+        "cmd.vat",
+        [
+            "Add VAT or sales tax to quoted prices",
+            "Añade IVA o impuesto de ventas a los precios",
+            "Adiciona IVA ou imposto sobre vendas aos preços",
+            "Ajoute la TVA ou une taxe de vente aux prix",
+        ],
+    ),
+    (
+        "opt.vat.set",
+        [
+            "Set the VAT rate and region",
+            "Establece el tipo de IVA y la región",
+            "Define a taxa de IVA e a região",
+            "Définit le taux de TVA et la région",
+        ],
+    ),
+    (
+        "opt.vat.clear",
+        [
+            "Stop charging VAT",
+            "Deja de cobrar IVA",
+            "Para de cobrar IVA",
+            "Arrête de facturer la TVA",
+        ],
+    ),
+    (
+        "opt.vat.rate",
+        [
+            "VAT rate in percent, e.g. 20",
+            "Tipo de IVA en porcentaje, p. ej. 21",
+            "Taxa de IVA em porcentagem, ex. 23",
+            "Taux de TVA en pourcentage, par ex. 20",
+        ],
+    ),
+    (
+        "opt.vat.region",
+        [
+            "Region shown next to the rate, e.g. UK",
+            "Región mostrada junto al tipo, p. ej. ES",
+            "Região mostrada ao lado da taxa, ex. PT",
+            "Région affichée à côté du taux, par ex. FR",
+        ],
+    ),
+    // /stats
+    (
+        "stats.staff_only",
+        [
+            "Only ticket staff can view sales stats.",
+            "Solo el personal de tickets puede ver las estadísticas de ventas.",
+            "Apenas a equipe de tickets pode ver as estatísticas de vendas.",
+            "Seul le staff des tickets peut voir les statistiques de ventes.",
+        ],
+    ),
+    (
+        "stats.title",
+        [
+            "Sales over the last {period}",
+            "Ventas de los últimos {period}",
+            "Vendas nos últimos {period}",
+            "Ventes sur les derniers {period}",
+        ],
+    ),
+    (
+        "stats.empty",
+        [
+            "No orders were completed in this period.",
+            "No se completó ningún pedido en este periodo.",
+            "Nenhum pedido foi concluído neste período.",
+            "Aucune commande n'a été terminée sur cette période.",
+        ],
+    ),
+    (
+        "stats.orders",
+        [
+            "Orders",
+            "Pedidos",
+            "Pedidos",
+            "Commandes",
+        ],
+    ),
+    (
+        "stats.robux",
+        [
+            "Robux Sold",
+            "Robux vendidos",
+            "Robux vendidos",
+            "Robux vendus",
+        ],
+    ),
+    (
+        "stats.average",
+        [
+            "Average Order",
+            "Pedido medio",
+            "Pedido médio",
+            "Commande moyenne",
+        ],
+    ),
+    (
+        "stats.revenue_in",
+        [
+            "Revenue in {currency}",
+            "Ingresos en {currency}",
+            "Receita em {currency}",
+            "Chiffre d'affaires en {currency}",
+        ],
+    ),
+    (
+        "stats.top_customers",
+        [
+            "Top Customers",
+            "Mejores clientes",
+            "Principais clientes",
+            "Meilleurs clients",
+        ],
+    ),
+    (
+        "stats.order_count",
+        [
+            "{orders} orders",
+            "{orders} pedidos",
+            "{orders} pedidos",
+            "{orders} commandes",
+        ],
+    ),
+    (
+        "cmd.stats.name",
+        [
+            "stats",
+            "estadisticas",
+            "estatisticas",
+            "stats",
+        ],
+    ),
+    (
+        "cmd.stats",
+        [
+            "Revenue summary of completed orders",
+            "Resumen de ingresos de los pedidos completados",
+            "Resumo da receita dos pedidos concluídos",
+            "Résumé du chiffre d'affaires des commandes terminées",
+        ],
+    ),
+    (
+        "opt.stats.period",
+        [
+            "Period to summarize (7 days if empty)",
+            "Periodo a resumir (7 días si se deja vacío)",
+            "Período a resumir (7 dias se vazio)",
+            "Période à résumer (7 jours si vide)",
+        ],
+    ),
+    (
+        "opt.stats.csv",
+        [
+            "Attach the orders as a CSV file",
+            "Adjunta los pedidos como archivo CSV",
+            "Anexa os pedidos como arquivo CSV",
+            "Joint les commandes en fichier CSV",
+        ],
+    ),
+    // /export
+    (
+        "export.staff_only",
+        [
+            "Only ticket staff can export data.",
+            "Solo el personal de tickets puede exportar datos.",
+            "Apenas a equipe de tickets pode exportar dados.",
+            "Seul le staff des tickets peut exporter des données.",
+        ],
+    ),
+    (
+        "export.title",
+        [
+            "Export",
+            "Exportación",
+            "Exportação",
+            "Export",
+        ],
+    ),
+    (
+        "export.description",
+        [
+            "`{file}`: {count} records from {period}.",
+            "`{file}`: {count} registros de {period}.",
+            "`{file}`: {count} registros de {period}.",
+            "`{file}` : {count} enregistrements sur {period}.",
+        ],
+    ),
+    (
+        "export.all_time",
+        [
+            "all time",
+            "todo el historial",
+            "todo o histórico",
+            "toute la période",
+        ],
+    ),
+    (
+        "export.sent_dm",
+        [
+            "The export has been sent to your DMs.",
+            "La exportación se ha enviado a tus mensajes directos.",
+            "A exportação foi enviada para suas DMs.",
+            "L'export a été envoyé en message privé.",
+        ],
+    ),
+    (
+        "export.dm_failed",
+        [
+            "I couldn't DM you the export. Check that your DMs are open.",
+            "No pude enviarte la exportación por mensaje directo. Comprueba que tus MD estén abiertos.",
+            "Não consegui enviar a exportação por DM. Verifique se suas DMs estão abertas.",
+            "Impossible de t'envoyer l'export en MP. Vérifie que tes MP sont ouverts.",
+        ],
+    ),
+    (
+        "cmd.export.name",
+        [
+            "export",
+            "exportar",
+            "exportar",
+            "exporter",
+        ],
+    ),
+    (
+        "cmd.export",
+        [
+            "Download orders or calculations as CSV or JSON",
+            "Descarga pedidos o cálculos en CSV o JSON",
+            "Baixe pedidos ou cálculos em CSV ou JSON",
+            "Télécharge les commandes ou calculs en CSV ou JSON",
+        ],
+    ),
+    (
+        "opt.export.orders",
+        [
+            "Export this server's orders",
+            "Exporta los pedidos del servidor",
+            "Exporta os pedidos do servidor",
+            "Exporte les commandes du serveur",
+        ],
+    ),
+    (
+        "opt.export.calculations",
+        [
+            "Export this server's /price and /robux calculations",
+            "Exporta los cálculos de /price y /robux del servidor",
+            "Exporta os cálculos de /price e /robux do servidor",
+            "Exporte les calculs /price et /robux du serveur",
+        ],
+    ),
+    (
+        "opt.export.period",
+        [
+            "How far back to export (everything if empty)",
+            "Hasta cuándo exportar (todo si se deja vacío)",
+            "Até quando exportar (tudo se vazio)",
+            "Période à exporter (tout si vide)",
+        ],
+    ),
+    (
+        "opt.export.format",
+        [
+            "File format (CSV if empty)",
+            "Formato de archivo (CSV si se deja vacío)",
+            "Formato do arquivo (CSV se vazio)",
+            "Format du fichier (CSV si vide)",
+        ],
+    ),
+    (
+        "opt.export.dm",
+        [
+            "Send the file to your DMs instead",
+            "Envía el archivo a tus mensajes directos",
+            "Envia o arquivo para suas DMs",
+            "Envoie le fichier en message privé",
+        ],
+    ),
+    // /sheets
+    (
+        "sheets.title",
+        [
+            "Google Sheets",
+            "Google Sheets",
+            "Google Sheets",
+            "Google Sheets",
+        ],
+    ),
+    (
+        "sheets.not_configured",
+        [
+            "Google Sheets isn't set up for this bot. The host needs to set `GOOGLE_SERVICE_ACCOUNT_FILE`.",
+            "Google Sheets no está configurado en este bot. El administrador debe definir `GOOGLE_SERVICE_ACCOUNT_FILE`.",
+            "O Google Sheets não está configurado neste bot. O host precisa definir `GOOGLE_SERVICE_ACCOUNT_FILE`.",
+            "Google Sheets n'est pas configuré pour ce bot. L'hébergeur doit définir `GOOGLE_SERVICE_ACCOUNT_FILE`.",
+        ],
+    ),
+    (
+        "sheets.invalid_spreadsheet",
+        [
+            "That isn't a Google Sheets link or spreadsheet id.",
+            "Eso no es un enlace de Google Sheets ni un id de hoja de cálculo.",
+            "Isso não é um link do Google Sheets nem um id de planilha.",
+            "Ce n'est ni un lien Google Sheets ni un identifiant de feuille.",
+        ],
+    ),
+    (
+        "sheets.append_failed",
+        [
+            "I couldn't write to that sheet. Share the spreadsheet with `{email}` as an editor and check the tab name.",
+            "No pude escribir en esa hoja. Comparte la hoja de cálculo con `{email}` como editor y revisa el nombre de la pestaña.",
+            "Não consegui escrever nessa planilha. Compartilhe-a com `{email}` como editor e verifique o nome da aba.",
+            "Impossible d'écrire dans cette feuille. Partage-la avec `{email}` en tant qu'éditeur et vérifie le nom de l'onglet.",
+        ],
+    ),
+    (
+        "sheets.set_description",
+        [
+            "Completed orders will be added to the **{sheet}** tab. Make sure the spreadsheet is shared with `{email}` as an editor.",
+            "Los pedidos completados se añadirán a la pestaña **{sheet}**. Asegúrate de compartir la hoja con `{email}` como editor.",
+            "Os pedidos concluídos serão adicionados à aba **{sheet}**. Compartilhe a planilha com `{email}` como editor.",
+            "Les commandes terminées seront ajoutées à l'onglet **{sheet}**. Partage la feuille avec `{email}` en tant qu'éditeur.",
+        ],
+    ),
+    (
+        "sheets.cleared_description",
+        [
+            "Completed orders will no longer be added to a spreadsheet.",
+            "Los pedidos completados ya no se añadirán a una hoja de cálculo.",
+            "Os pedidos concluídos não serão mais adicionados a uma planilha.",
+            "Les commandes terminées ne seront plus ajoutées à une feuille.",
+        ],
+    ),
+    (
+        "cmd.sheets.name",
+        [
+            "sheets",
+            "hojas",
+            "planilhas",
+            "feuilles",
+        ],
+    ),
+    (
+        "cmd.sheets",
+        [
+            "Log completed orders to a Google Sheet",
+            "Registra los pedidos completados en Google Sheets",
+            "Registra os pedidos concluídos no Google Sheets",
+            "Enregistre les commandes terminées dans Google Sheets",
+        ],
+    ),
+    (
+        "opt.sheets.set",
+        [
+            "Choose the spreadsheet to log orders to",
+            "Elige la hoja de cálculo donde registrar los pedidos",
+            "Escolhe a planilha para registrar os pedidos",
+            "Choisit la feuille où enregistrer les commandes",
+        ],
+    ),
+    (
+        "opt.sheets.clear",
+        [
+            "Stop logging orders to Google Sheets",
+            "Deja de registrar pedidos en Google Sheets",
+            "Para de registrar pedidos no Google Sheets",
+            "Arrête d'enregistrer les commandes dans Google Sheets",
+        ],
+    ),
+    (
+        "opt.sheets.spreadsheet",
+        [
+            "Spreadsheet link or id",
+            "Enlace o id de la hoja de cálculo",
+            "Link ou id da planilha",
+            "Lien ou identifiant de la feuille",
+        ],
+    ),
+    (
+        "opt.sheets.sheet",
+        [
+            "Tab to append to (Orders if empty)",
+            "Pestaña donde añadir (Orders si se deja vacío)",
+            "Aba onde adicionar (Orders se vazio)",
+            "Onglet où ajouter (Orders si vide)",
+        ],
+    ),
+    (
+        "opt.sheets.headers",
+        [
+            "Add a heading row now, which also checks access",
+            "Añade ahora una fila de encabezados, lo que también comprueba el acceso",
+            "Adiciona agora uma linha de cabeçalho, o que também verifica o acesso",
+            "Ajoute une ligne d'en-têtes, ce qui vérifie aussi l'accès",
+        ],
+    ),
+    // /webhook
+    (
+        "webhook.title",
+        [
+            "Webhooks",
+            "Webhooks",
+            "Webhooks",
+            "Webhooks",
+        ],
+    ),
+    (
+        "webhook.empty",
+        [
+            "No webhooks have been added.",
+            "No se ha añadido ningún webhook.",
+            "Nenhum webhook foi adicionado.",
+            "Aucun webhook n'a été ajouté.",
+        ],
+    ),
+    (
+        "webhook.invalid_url",
+        [
+            "Webhook URLs must start with `https://`.",
+            "Las URL de webhook deben empezar por `https://`.",
+            "As URLs de webhook devem começar com `https://`.",
+            "Les URL de webhook doivent commencer par `https://`.",
+        ],
+    ),
+    (
+        "webhook.limit",
+        [
+            "A server can have at most {max} webhooks.",
+            "Un servidor puede tener como máximo {max} webhooks.",
+            "Um servidor pode ter no máximo {max} webhooks.",
+            "Un serveur peut avoir au plus {max} webhooks.",
+        ],
+    ),
+    (
+        "webhook.exists",
+        [
+            "That URL is already registered.",
+            "Esa URL ya está registrada.",
+            "Essa URL já está registrada.",
+            "Cette URL est déjà enregistrée.",
+        ],
+    ),
+    (
+        "webhook.not_found",
+        [
+            "That URL isn't registered.",
+            "Esa URL no está registrada.",
+            "Essa URL não está registrada.",
+            "Cette URL n'est pas enregistrée.",
+        ],
+    ),
+    (
+        "webhook.added",
+        [
+            "Webhook Added",
+            "Webhook añadido",
+            "Webhook adicionado",
+            "Webhook ajouté",
+        ],
+    ),
+    (
+        "webhook.added_description",
+        [
+            "Order events will be posted to <{url}>.\nEach request is signed in the `{header}` header with HMAC-SHA256 using this secret, which won't be shown again:\n`{secret}`",
+            "Los eventos de pedidos se enviarán a <{url}>.\nCada petición se firma en la cabecera `{header}` con HMAC-SHA256 usando este secreto, que no se volverá a mostrar:\n`{secret}`",
+            "Os eventos de pedidos serão enviados para <{url}>.\nCada requisição é assinada no cabeçalho `{header}` com HMAC-SHA256 usando este segredo, que não será mostrado novamente:\n`{secret}`",
+            "Les événements de commande seront envoyés à <{url}>.\nChaque requête est signée dans l'en-tête `{header}` en HMAC-SHA256 avec ce secret, qui ne sera plus affiché :\n`{secret}`",
+        ],
+    ),
+    (
+        "webhook.removed",
+        [
+            "Webhook Removed",
+            "Webhook eliminado",
+            "Webhook removido",
+            "Webhook supprimé",
+        ],
+    ),
+    (
+        "cmd.webhook.name",
+        [
+            "webhook",
+            "webhook",
+            "webhook",
+            "webhook",
+        ],
+    ),
+    (
+        "cmd.webhook",
+        [
+            "Send order events to your own server",
+            "Envía eventos de pedidos a tu propio servidor",
+            "Envia eventos de pedidos para o seu servidor",
+            "Envoie les événements de commande à ton serveur",
+        ],
+    ),
+    (
+        "opt.webhook.add",
+        [
+            "Register a URL for order events",
+            "Registra una URL para eventos de pedidos",
+            "Registra uma URL para eventos de pedidos",
+            "Enregistre une URL pour les événements de commande",
+        ],
+    ),
+    (
+        "opt.webhook.remove",
+        [
+            "Stop sending events to a URL",
+            "Deja de enviar eventos a una URL",
+            "Para de enviar eventos para uma URL",
+            "Arrête d'envoyer des événements à une URL",
+        ],
+    ),
+    (
+        "opt.webhook.list",
+        [
+            "List this server's webhooks",
+            "Muestra los webhooks del servidor",
+            "Lista os webhooks do servidor",
+            "Liste les webhooks du serveur",
+        ],
+    ),
+    (
+        "opt.webhook.url",
+        [
+            "HTTPS URL to POST events to",
+            "URL HTTPS a la que enviar los eventos",
+            "URL HTTPS para onde enviar os eventos",
+            "URL HTTPS où envoyer les événements",
+        ],
+    ),
+    // /apikey
+    (
+        "apikey.title",
+        [
+            "API Key",
+            "Clave de API",
+            "Chave de API",
+            "Clé d'API",
+        ],
+    ),
+    (
+        "apikey.rotated_description",
+        [
+            "Your new API key is below. It replaces any previous key and won't be shown again:\n`{key}`\nSend it as the `X-API-Key` header or the `key` query parameter.",
+            "Tu nueva clave de API está abajo. Sustituye a la anterior y no se volverá a mostrar:\n`{key}`\nEnvíala en la cabecera `X-API-Key` o en el parámetro `key`.",
+            "Sua nova chave de API está abaixo. Ela substitui a anterior e não será mostrada novamente:\n`{key}`\nEnvie-a no cabeçalho `X-API-Key` ou no parâmetro `key`.",
+            "Ta nouvelle clé d'API est ci-dessous. Elle remplace l'ancienne et ne sera plus affichée :\n`{key}`\nEnvoie-la dans l'en-tête `X-API-Key` ou le paramètre `key`.",
+        ],
+    ),
+    (
+        "apikey.revoked_description",
+        [
+            "The API key has been revoked.",
+            "La clave de API ha sido revocada.",
+            "A chave de API foi revogada.",
+            "La clé d'API a été révoquée.",
+        ],
+    ),
+    (
+        "cmd.apikey.name",
+        [
+            "apikey",
+            "clave-api",
+            "chave-api",
+            "cle-api",
+        ],
+    ),
+    (
+        "cmd.apikey",
+        [
+            "Manage this server's HTTP API key",
+            "Gestiona la clave de la API HTTP del servidor",
+            "Gerencia a chave da API HTTP do servidor",
+            "Gère la clé d'API HTTP du serveur",
+        ],
+    ),
+    (
+        "opt.apikey.rotate",
+        [
+            "Create a new key, replacing the old one",
+            "Crea una clave nueva que sustituye a la anterior",
+            "Cria uma nova chave, substituindo a anterior",
+            "Crée une nouvelle clé qui remplace l'ancienne",
+        ],
+    ),
+    (
+        "opt.apikey.revoke",
+        [
+            "Disable API access",
+            "Desactiva el acceso a la API",
+            "Desativa o acesso à API",
+            "Désactive l'accès à l'API",
+        ],
+    ),
+    // Calculate Robux message command
+    (
+        "calculate.description",
+        [
+            "Robux amounts in {link} from {author}:",
+            "Cantidades de Robux en {link} de {author}:",
+            "Quantias de Robux em {link} de {author}:",
+            "Montants de Robux dans {link} de {author} :",
+        ],
+    ),
+    (
+        "calculate.none",
+        [
+            "No Robux amounts found in that message.",
+            "No se encontraron cantidades de Robux en ese mensaje.",
+            "Nenhuma quantia de Robux encontrada nessa mensagem.",
+            "Aucun montant de Robux trouvé dans ce message.",
+        ],
+    ),
+    (
+        "cmd.calculate.name",
+        [
+            "Calculate Robux",
+            "Calcular Robux",
+            "Calcular Robux",
+            "Calculer les Robux",
+        ],
+    ),
+    // /autoreply
+    (
+        "autoreply.title",
+        [
+            "Auto-replies",
+            "Respuestas automáticas",
+            "Respostas automáticas",
+            "Réponses automatiques",
+        ],
+    ),
+    (
+        "autoreply.enabled",
+        [
+            "Messages in {channel} that ask about Robux amounts, like \"how much for 8000 robux?\", now get a price reply.",
+            "Los mensajes en {channel} que pregunten por cantidades de Robux, como \"¿cuánto por 8000 robux?\", ahora reciben una respuesta con el precio.",
+            "Mensagens em {channel} que perguntem sobre quantias de Robux, como \"quanto por 8000 robux?\", agora recebem uma resposta com o preço.",
+            "Les messages dans {channel} qui demandent un montant de Robux, comme « combien pour 8000 robux ? », reçoivent maintenant une réponse avec le prix.",
+        ],
+    ),
+    (
+        "autoreply.disabled",
+        [
+            "Auto-replies are off in {channel}.",
+            "Las respuestas automáticas están desactivadas en {channel}.",
+            "As respostas automáticas estão desativadas em {channel}.",
+            "Les réponses automatiques sont désactivées dans {channel}.",
+        ],
+    ),
+    (
+        "autoreply.channels",
+        [
+            "Active in: {channels}",
+            "Activas en: {channels}",
+            "Ativas em: {channels}",
+            "Actives dans : {channels}",
+        ],
+    ),
+    (
+        "autoreply.none",
+        [
+            "Auto-replies aren't on in any channel.",
+            "Las respuestas automáticas no están activas en ningún canal.",
+            "As respostas automáticas não estão ativas em nenhum canal.",
+            "Les réponses automatiques ne sont actives dans aucun salon.",
+        ],
+    ),
+    (
+        "cmd.autoreply.name",
+        [
+            "autoreply",
+            "autorespuesta",
+            "autoresposta",
+            "autoreponse",
+        ],
+    ),
+    (
+        "cmd.autoreply",
+        [
+            "Reply with prices to messages asking about Robux",
+            "Responde con precios a mensajes que preguntan por Robux",
+            "Responde com preços a mensagens que perguntam sobre Robux",
+            "Répond avec les prix aux messages qui demandent des Robux",
+        ],
+    ),
+    (
+        "opt.autoreply.enable",
+        [
+            "Turn on auto-replies in a channel",
+            "Activa las respuestas automáticas en un canal",
+            "Ativa as respostas automáticas em um canal",
+            "Active les réponses automatiques dans un salon",
+        ],
+    ),
+    (
+        "opt.autoreply.disable",
+        [
+            "Turn off auto-replies in a channel",
+            "Desactiva las respuestas automáticas en un canal",
+            "Desativa as respostas automáticas em um canal",
+            "Désactive les réponses automatiques dans un salon",
+        ],
+    ),
+    (
+        "opt.autoreply.channel",
+        [
+            "Channel (defaults to this one)",
+            "Canal (por defecto, este)",
+            "Canal (padrão: este)",
+            "Salon (par défaut, celui-ci)",
+        ],
+    ),
+    // Order form
+    (
+        "validation.roblox_username",
+        [
+            "`{username}` isn't a valid Roblox username.",
+            "`{username}` no es un nombre de usuario de Roblox válido.",
+            "`{username}` não é um nome de usuário do Roblox válido.",
+            "`{username}` n'est pas un nom d'utilisateur Roblox valide.",
+        ],
+    ),
+    (
+        "orderform.title",
+        [
+            "Order Robux",
+            "Pedir Robux",
+            "Pedir Robux",
+            "Commander des Robux",
+        ],
+    ),
+    (
+        "orderform.description",
+        [
+            "Press the button below and fill in the form to open an order ticket.",
+            "Pulsa el botón de abajo y completa el formulario para abrir un ticket de pedido.",
+            "Aperte o botão abaixo e preencha o formulário para abrir um ticket de pedido.",
+            "Appuie sur le bouton ci-dessous et remplis le formulaire pour ouvrir un ticket de commande.",
+        ],
+    ),
+    (
+        "orderform.button",
+        [
+            "Order",
+            "Pedir",
+            "Pedir",
+            "Commander",
+        ],
+    ),
+    (
+        "orderform.roblox_username",
+        [
+            "Roblox username",
+            "Nombre de usuario de Roblox",
+            "Nome de usuário do Roblox",
+            "Nom d'utilisateur Roblox",
+        ],
+    ),
+    (
+        "orderform.amount",
+        [
+            "Robux amount",
+            "Cantidad de Robux",
+            "Quantidade de Robux",
+            "Montant de Robux",
+        ],
+    ),
+    (
+        "orderform.type",
+        [
+            "Before or after tax (b/t or a/t)",
+            "Antes o después de impuestos (b/t o a/t)",
+            "Antes ou depois da taxa (b/t ou a/t)",
+            "Avant ou après taxe (b/t ou a/t)",
+        ],
+    ),
+    (
+        "orderform.payment",
+        [
+            "Payment method",
+            "Método de pago",
+            "Forma de pagamento",
+            "Moyen de paiement",
+        ],
+    ),
+    (
+        "orderform.invalid_payment",
+        [
+            "`{input}` isn't a payment method. Use PayPal, Cash App, BTC, ETH or LTC.",
+            "`{input}` no es un método de pago. Usa PayPal, Cash App, BTC, ETH o LTC.",
+            "`{input}` não é uma forma de pagamento. Use PayPal, Cash App, BTC, ETH ou LTC.",
+            "`{input}` n'est pas un moyen de paiement. Utilise PayPal, Cash App, BTC, ETH ou LTC.",
+        ],
+    ),
+    (
+        "orderform.payment_not_accepted",
+        [
+            "{method} isn't accepted here. Accepted: {accepted}.",
+            "{method} no se acepta aquí. Aceptados: {accepted}.",
+            "{method} não é aceito aqui. Aceitos: {accepted}.",
+            "{method} n'est pas accepté ici. Acceptés : {accepted}.",
+        ],
+    ),
+    (
+        "cmd.orderform.name",
+        [
+            "orderform",
+            "formulariopedido",
+            "formulariopedido",
+            "formulairecommande",
+        ],
+    ),
+    (
+        "cmd.orderform",
+        [
+            "Post an Order button that opens a short order form",
+            "Publica un botón de Pedido que abre un formulario breve",
+            "Publica um botão de Pedido que abre um formulário curto",
+            "Publie un bouton Commander qui ouvre un court formulaire",
+        ],
+    ),
+    // /convert currency menu
+    (
+        "convert.pick",
+        [
+            "Pick a currency to convert to.",
+            "Elige una moneda de destino.",
+            "Escolha uma moeda de destino.",
+            "Choisis une devise de destination.",
+        ],
+    ),
+    (
+        "convert.placeholder",
+        [
+            "Convert to…",
+            "Convertir a…",
+            "Converter para…",
+            "Convertir en…",
+        ],
+    ),
+    // /setcurrency
+    (
+        "setcurrency.title",
+        [
+            "Preferred currency",
+            "Moneda preferida",
+            "Moeda preferida",
+            "Devise préférée",
+        ],
+    ),
+    (
+        "setcurrency.set",
+        [
+            "/price and /robux will also show amounts in {currency}.",
+            "/price y /robux también mostrarán importes en {currency}.",
+            "/price e /robux também mostrarão valores em {currency}.",
+            "/price et /robux afficheront aussi les montants en {currency}.",
+        ],
+    ),
+    (
+        "setcurrency.cleared",
+        [
+            "/price and /robux will only show GBP and USD.",
+            "/price y /robux solo mostrarán GBP y USD.",
+            "/price e /robux mostrarão apenas GBP e USD.",
+            "/price et /robux n'afficheront que GBP et USD.",
+        ],
+    ),
+    (
+        "setcurrency.invalid",
+        [
+            "`{code}` isn't supported. Pick one of: {currencies}",
+            "`{code}` no está disponible. Elige una de: {currencies}",
+            "`{code}` não é suportada. Escolha uma de: {currencies}",
+            "`{code}` n'est pas prise en charge. Choisis parmi : {currencies}",
+        ],
+    ),
+    (
+        "cmd.setcurrency.name",
+        [
+            "setcurrency",
+            "moneda",
+            "moeda",
+            "devise",
+        ],
+    ),
+    (
+        "cmd.setcurrency",
+        [
+            "Also show prices in your own currency",
+            "Muestra también los precios en tu moneda",
+            "Mostra também os preços na sua moeda",
+            "Affiche aussi les prix dans ta devise",
+        ],
+    ),
+    (
+        "opt.setcurrency.currency",
+        [
+            "Currency code, e.g. EUR (leave empty to stop)",
+            "Código de moneda, p. ej. EUR (vacío para quitarla)",
+            "Código da moeda, ex. EUR (vazio para remover)",
+            "Code de devise, ex. EUR (vide pour arrêter)",
+        ],
+    ),
+    // /pricebatch
+    (
+        "cmd.pricebatch.name",
+        [
+            "pricebatch",
+            "preciolote",
+            "precolote",
+            "prixlot",
+        ],
+    ),
+    (
+        "cmd.pricebatch",
+        [
+            "Price several amounts of Robux at once",
+            "Calcula el precio de varias cantidades de Robux a la vez",
+            "Calcula o preço de várias quantidades de Robux de uma vez",
+            "Calcule le prix de plusieurs quantités de Robux à la fois",
+        ],
+    ),
+    (
+        "opt.pricebatch.amounts",
+        [
+            "Comma-separated amounts, e.g. 1000, 5k, 10000",
+            "Cantidades separadas por comas, p. ej. 1000, 5k, 10000",
+            "Quantidades separadas por vírgulas, ex. 1000, 5k, 10000",
+            "Quantités séparées par des virgules, par ex. 1000, 5k, 10000",
+        ],
+    ),
+    (
+        "pricebatch.title",
+        [
+            "Robux Price Table",
+            "Tabla de precios de Robux",
+            "Tabela de preços de Robux",
+            "Tableau des prix Robux",
+        ],
+    ),
+    (
+        "pricebatch.too_many",
+        [
+            "You can price at most {max} amounts at once.",
+            "Puedes calcular como máximo {max} cantidades a la vez.",
+            "Você pode calcular no máximo {max} quantidades de uma vez.",
+            "Vous pouvez calculer au plus {max} quantités à la fois.",
+        ],
+    ),
+    // /price both
+    (
+        "price.difference",
+        [
+            "Extra for a/t",
+            "Extra por a/t",
+            "Extra para a/t",
+            "Supplément a/t",
+        ],
+    ),
+    // format: text
+    (
+        "opt.format",
+        [
+            "Reply with an embed (default) or copyable plain text",
+            "Responde con un embed (por defecto) o texto plano para copiar",
+            "Responde com um embed (padrão) ou texto simples para copiar",
+            "Répond avec un embed (par défaut) ou du texte brut à copier",
+        ],
+    ),
+    // /ping, /uptime and /botstats
+    (
+        "cmd.ping.name",
+        [
+            "ping",
+            "ping",
+            "ping",
+            "ping",
+        ],
+    ),
+    (
+        "cmd.ping",
+        [
+            "Check the bot's gateway and API latency",
+            "Comprueba la latencia del gateway y de la API del bot",
+            "Verifica a latência do gateway e da API do bot",
+            "Vérifie la latence de la passerelle et de l'API du bot",
+        ],
+    ),
+    (
+        "cmd.uptime.name",
+        [
+            "uptime",
+            "tiempoactivo",
+            "tempoativo",
+            "disponibilite",
+        ],
+    ),
+    (
+        "cmd.uptime",
+        [
+            "Show how long the bot has been running",
+            "Muestra cuánto tiempo lleva activo el bot",
+            "Mostra há quanto tempo o bot está ativo",
+            "Affiche depuis combien de temps le bot fonctionne",
+        ],
+    ),
+    (
+        "cmd.botstats.name",
+        [
+            "botstats",
+            "estadisticasbot",
+            "estatisticasbot",
+            "statsbot",
+        ],
+    ),
+    (
+        "cmd.botstats",
+        [
+            "Show servers, commands run and memory use",
+            "Muestra servidores, comandos ejecutados y uso de memoria",
+            "Mostra servidores, comandos executados e uso de memória",
+            "Affiche les serveurs, les commandes exécutées et la mémoire utilisée",
+        ],
+    ),
+    (
+        "ping.pinging",
+        [
+            "Pinging…",
+            "Midiendo…",
+            "Medindo…",
+            "Mesure…",
+        ],
+    ),
+    (
+        "ping.title",
+        [
+            "Pong!",
+            "¡Pong!",
+            "Pong!",
+            "Pong !",
+        ],
+    ),
+    (
+        "ping.gateway",
+        [
+            "Gateway",
+            "Gateway",
+            "Gateway",
+            "Passerelle",
+        ],
+    ),
+    (
+        "ping.rest",
+        [
+            "API",
+            "API",
+            "API",
+            "API",
+        ],
+    ),
+    (
+        "ping.shard",
+        [
+            "Shard",
+            "Shard",
+            "Shard",
+            "Shard",
+        ],
+    ),
+    (
+        "ping.pending",
+        [
+            "Not measured yet",
+            "Aún sin medir",
+            "Ainda não medido",
+            "Pas encore mesuré",
+        ],
+    ),
+    (
+        "uptime.title",
+        [
+            "Uptime",
+            "Tiempo activo",
+            "Tempo ativo",
+            "Disponibilité",
+        ],
+    ),
+    (
+        "uptime.description",
+        [
+            "Up for **{duration}**, since {since}.",
+            "Activo desde hace **{duration}**, desde el {since}.",
+            "Ativo há **{duration}**, desde {since}.",
+            "En ligne depuis **{duration}**, depuis le {since}.",
+        ],
+    ),
+    (
+        "botstats.title",
+        [
+            "Bot Stats",
+            "Estadísticas del bot",
+            "Estatísticas do bot",
+            "Statistiques du bot",
+        ],
+    ),
+    (
+        "botstats.guilds",
+        [
+            "Servers",
+            "Servidores",
+            "Servidores",
+            "Serveurs",
+        ],
+    ),
+    (
+        "botstats.memory",
+        [
+            "Memory",
+            "Memoria",
+            "Memória",
+            "Mémoire",
+        ],
+    ),
+    (
+        "botstats.commands_run",
+        [
+            "Commands run",
+            "Comandos ejecutados",
+            "Comandos executados",
+            "Commandes exécutées",
+        ],
+    ),
+    (
+        "botstats.top_commands",
+        [
+            "Most used since startup",
+            "Más usados desde el inicio",
+            "Mais usados desde o início",
+            "Les plus utilisées depuis le démarrage",
+        ],
+    ),
+    (
+        "botstats.no_commands",
+        [
+            "No commands run yet.",
+            "Aún no se ha ejecutado ningún comando.",
+            "Nenhum comando executado ainda.",
+            "Aucune commande exécutée pour l'instant.",
+        ],
+    ),
+    // /sync
+    (
+        "cmd.sync.name",
+        [
+            "sync",
+            "sync",
+            "sync",
+            "sync",
+        ],
+    ),
+    (
+        "cmd.sync",
+        [
+            "Re-register the bot's commands (bot owner only)",
+            "Vuelve a registrar los comandos del bot (solo el dueño del bot)",
+            "Registra de novo os comandos do bot (só o dono do bot)",
+            "Réenregistre les commandes du bot (propriétaire du bot uniquement)",
+        ],
+    ),
+    (
+        "opt.sync.global",
+        [
+            "Register the commands that work in DMs globally instead of this server's",
+            "Registrar globalmente los comandos que funcionan en MD en lugar de los del servidor",
+            "Registrar globalmente os comandos que funcionam em DM em vez dos do servidor",
+            "Enregistrer globalement les commandes utilisables en MP plutôt que celles du serveur",
+        ],
+    ),
+    (
+        "sync.owner_only",
+        [
+            "Only the bot owner can sync commands.",
+            "Solo el dueño del bot puede sincronizar los comandos.",
+            "Só o dono do bot pode sincronizar os comandos.",
+            "Seul le propriétaire du bot peut synchroniser les commandes.",
+        ],
+    ),
+    (
+        "sync.title_guild",
+        [
+            "Commands synced in this server",
+            "Comandos sincronizados en este servidor",
+            "Comandos sincronizados neste servidor",
+            "Commandes synchronisées sur ce serveur",
+        ],
+    ),
+    (
+        "sync.title_global",
+        [
+            "Commands synced globally",
+            "Comandos sincronizados globalmente",
+            "Comandos sincronizados globalmente",
+            "Commandes synchronisées globalement",
+        ],
+    ),
+    (
+        "sync.registered",
+        [
+            "{count} commands registered. Global changes can take up to an hour to reach every server.",
+            "{count} comandos registrados. Los cambios globales pueden tardar hasta una hora en llegar a todos los servidores.",
+            "{count} comandos registrados. Mudanças globais podem levar até uma hora para chegar a todos os servidores.",
+            "{count} commandes enregistrées. Les changements globaux peuvent mettre jusqu'à une heure à atteindre tous les serveurs.",
+        ],
+    ),
+    (
+        "sync.added",
+        [
+            "Added",
+            "Añadidos",
+            "Adicionados",
+            "Ajoutées",
+        ],
+    ),
+    (
+        "sync.removed",
+        [
+            "Removed",
+            "Eliminados",
+            "Removidos",
+            "Supprimées",
+        ],
+    ),
+    (
+        "sync.none",
+        [
+            "None",
+            "Ninguno",
+            "Nenhum",
+            "Aucune",
+        ],
+    ),
+    // /features
+    (
+        "cmd.features.name",
+        [
+            "features",
+            "funciones",
+            "recursos",
+            "fonctions",
+        ],
+    ),
+    (
+        "cmd.features",
+        [
+            "Turn groups of commands on or off in this server",
+            "Activa o desactiva grupos de comandos en este servidor",
+            "Ative ou desative grupos de comandos neste servidor",
+            "Active ou désactive des groupes de commandes sur ce serveur",
+        ],
+    ),
+    (
+        "opt.features.list",
+        [
+            "Show which command groups are on",
+            "Muestra qué grupos de comandos están activados",
+            "Mostra quais grupos de comandos estão ativados",
+            "Affiche les groupes de commandes activés",
+        ],
+    ),
+    (
+        "opt.features.enable",
+        [
+            "Turn a command group on",
+            "Activa un grupo de comandos",
+            "Ativa um grupo de comandos",
+            "Active un groupe de commandes",
+        ],
+    ),
+    (
+        "opt.features.disable",
+        [
+            "Turn a command group off",
+            "Desactiva un grupo de comandos",
+            "Desativa um grupo de comandos",
+            "Désactive un groupe de commandes",
+        ],
+    ),
+    (
+        "opt.features.feature",
+        [
+            "Command group",
+            "Grupo de comandos",
+            "Grupo de comandos",
+            "Groupe de commandes",
+        ],
+    ),
+    (
+        "feature.orders",
+        [
+            "Orders and tickets",
+            "Pedidos y tickets",
+            "Pedidos e tickets",
+            "Commandes et tickets",
+        ],
+    ),
+    (
+        "feature.vouches",
+        [
+            "Vouches",
+            "Valoraciones",
+            "Avaliações",
+            "Avis",
+        ],
+    ),
+    (
+        "feature.loyalty",
+        [
+            "Loyalty points",
+            "Puntos de fidelidad",
+            "Pontos de fidelidade",
+            "Points de fidélité",
+        ],
+    ),
+    (
+        "feature.coupons",
+        [
+            "Coupons",
+            "Cupones",
+            "Cupons",
+            "Coupons",
+        ],
+    ),
+    (
+        "feature.alerts",
+        [
+            "Rate alerts",
+            "Alertas de tipo de cambio",
+            "Alertas de câmbio",
+            "Alertes de taux",
+        ],
+    ),
+    (
+        "feature.autoreply",
+        [
+            "Auto-reply",
+            "Respuesta automática",
+            "Resposta automática",
+            "Réponse automatique",
+        ],
+    ),
+    (
+        "features.title",
+        [
+            "Command groups",
+            "Grupos de comandos",
+            "Grupos de comandos",
+            "Groupes de commandes",
+        ],
+    ),
+    (
+        "features.enabled",
+        [
+            "**{feature}** is now on.",
+            "**{feature}** está activado.",
+            "**{feature}** agora está ativado.",
+            "**{feature}** est maintenant activé.",
+        ],
+    ),
+    (
+        "features.disabled",
+        [
+            "**{feature}** is now off. Its commands are refused in this server.",
+            "**{feature}** está desactivado. Sus comandos se rechazan en este servidor.",
+            "**{feature}** agora está desativado. Seus comandos são recusados neste servidor.",
+            "**{feature}** est maintenant désactivé. Ses commandes sont refusées sur ce serveur.",
+        ],
+    ),
+    (
+        "features.refused",
+        [
+            "{feature} is turned off in this server.",
+            "{feature} está desactivado en este servidor.",
+            "{feature} está desativado neste servidor.",
+            "{feature} est désactivé sur ce serveur.",
+        ],
+    ),
+    // group payouts
+    (
+        "opt.order.roblox_username",
+        [
+            "Buyer's Roblox username for the group payout, if the order doesn't have one",
+            "Nombre de usuario de Roblox del comprador para el pago del grupo, si el pedido no lo tiene",
+            "Nome de usuário do Roblox do comprador para o pagamento do grupo, se o pedido não tiver",
+            "Nom d'utilisateur Roblox de l'acheteur pour le paiement du groupe, si la commande n'en a pas",
+        ],
+    ),
+    (
+        "payout.title",
+        [
+            "Group payout #{id} for order #{order}",
+            "Pago de grupo #{id} del pedido #{order}",
+            "Pagamento do grupo #{id} do pedido #{order}",
+            "Paiement du groupe n°{id} pour la commande n°{order}",
+        ],
+    ),
+    (
+        "payout.recipient",
+        [
+            "Roblox account",
+            "Cuenta de Roblox",
+            "Conta do Roblox",
+            "Compte Roblox",
+        ],
+    ),
+    (
+        "payout.amount",
+        [
+            "Robux",
+            "Robux",
+            "Robux",
+            "Robux",
+        ],
+    ),
+    (
+        "payout.status",
+        [
+            "Status",
+            "Estado",
+            "Status",
+            "Statut",
+        ],
+    ),
+    (
+        "payout.requested_by",
+        [
+            "Requested by",
+            "Solicitado por",
+            "Solicitado por",
+            "Demandé par",
+        ],
+    ),
+    (
+        "payout.reviewed_by",
+        [
+            "Reviewed by",
+            "Revisado por",
+            "Revisado por",
+            "Examiné par",
+        ],
+    ),
+    (
+        "payout.transaction",
+        [
+            "Transaction",
+            "Transacción",
+            "Transação",
+            "Transaction",
+        ],
+    ),
+    (
+        "payout.error",
+        [
+            "Roblox error",
+            "Error de Roblox",
+            "Erro do Roblox",
+            "Erreur Roblox",
+        ],
+    ),
+    (
+        "payout.status.pending",
+        [
+            "Waiting for approval",
+            "Esperando aprobación",
+            "Aguardando aprovação",
+            "En attente d'approbation",
+        ],
+    ),
+    (
+        "payout.status.sending",
+        [
+            "Sending…",
+            "Enviando…",
+            "Enviando…",
+            "Envoi…",
+        ],
+    ),
+    (
+        "payout.status.paid",
+        [
+            "Paid",
+            "Pagado",
+            "Pago",
+            "Payé",
+        ],
+    ),
+    (
+        "payout.status.rejected",
+        [
+            "Rejected",
+            "Rechazado",
+            "Rejeitado",
+            "Refusé",
+        ],
+    ),
+    (
+        "payout.status.failed",
+        [
+            "Failed",
+            "Fallido",
+            "Falhou",
+            "Échoué",
+        ],
+    ),
+    (
+        "payout.approve",
+        [
+            "Approve",
+            "Aprobar",
+            "Aprovar",
+            "Approuver",
+        ],
+    ),
+    (
+        "payout.reject",
+        [
+            "Reject",
+            "Rechazar",
+            "Rejeitar",
+            "Refuser",
+        ],
+    ),
+    (
+        "payout.staff_only",
+        [
+            "Only ticket staff can approve payouts.",
+            "Solo el staff de tickets puede aprobar pagos.",
+            "Só a equipe de tickets pode aprovar pagamentos.",
+            "Seul le staff des tickets peut approuver les paiements.",
+        ],
+    ),
+    (
+        "payout.already_reviewed",
+        [
+            "This payout has already been approved or rejected.",
+            "Este pago ya fue aprobado o rechazado.",
+            "Este pagamento já foi aprovado ou rejeitado.",
+            "Ce paiement a déjà été approuvé ou refusé.",
+        ],
+    ),
+    (
+        "payout.not_found",
+        [
+            "That payout no longer exists.",
+            "Ese pago ya no existe.",
+            "Esse pagamento não existe mais.",
+            "Ce paiement n'existe plus.",
+        ],
+    ),
+    (
+        "payout.not_configured",
+        [
+            "Group payouts aren't set up for this bot.",
+            "Los pagos de grupo no están configurados en este bot.",
+            "Os pagamentos de grupo não estão configurados neste bot.",
+            "Les paiements de groupe ne sont pas configurés pour ce bot.",
+        ],
+    ),
+    (
+        "payout.not_queued",
+        [
+            "Group payout not queued",
+            "Pago de grupo no añadido a la cola",
+            "Pagamento do grupo não enfileirado",
+            "Paiement du groupe non mis en file",
+        ],
+    ),
+    (
+        "payout.no_username",
+        [
+            "The order has no Roblox username. Run `/order complete roblox_username:<name>` next time, or pay the buyer manually.",
+            "El pedido no tiene nombre de usuario de Roblox. Usa `/order complete roblox_username:<nombre>` la próxima vez, o paga al comprador manualmente.",
+            "O pedido não tem nome de usuário do Roblox. Use `/order complete roblox_username:<nome>` da próxima vez, ou pague o comprador manualmente.",
+            "La commande n'a pas de nom d'utilisateur Roblox. Utilisez `/order complete roblox_username:<nom>` la prochaine fois, ou payez l'acheteur manuellement.",
+        ],
+    ),
+    (
+        "payout.unknown_user",
+        [
+            "No Roblox account is called {username}. Pay the buyer manually.",
+            "Ninguna cuenta de Roblox se llama {username}. Paga al comprador manualmente.",
+            "Nenhuma conta do Roblox se chama {username}. Pague o comprador manualmente.",
+            "Aucun compte Roblox ne s'appelle {username}. Payez l'acheteur manuellement.",
+        ],
+    ),
+    (
+        "payout.queue_failed",
+        [
+            "Roblox couldn't be reached. Pay the buyer manually.",
+            "No se pudo contactar con Roblox. Paga al comprador manualmente.",
+            "Não foi possível contatar o Roblox. Pague o comprador manualmente.",
+            "Impossible de joindre Roblox. Payez l'acheteur manuellement.",
+        ],
+    ),
+    // /groupfunds
+    (
+        "cmd.groupfunds.name",
+        [
+            "groupfunds",
+            "fondosgrupo",
+            "fundosgrupo",
+            "fondsgroupe",
+        ],
+    ),
+    (
+        "cmd.groupfunds",
+        [
+            "Show the Roblox group's Robux balance and pending funds",
+            "Muestra el saldo de Robux y los fondos pendientes del grupo de Roblox",
+            "Mostra o saldo de Robux e os fundos pendentes do grupo do Roblox",
+            "Affiche le solde de Robux et les fonds en attente du groupe Roblox",
+        ],
+    ),
+    (
+        "groupfunds.title",
+        [
+            "Group funds",
+            "Fondos del grupo",
+            "Fundos do grupo",
+            "Fonds du groupe",
+        ],
+    ),
+    (
+        "groupfunds.description",
+        [
+            "[Roblox group {group}](https://www.roblox.com/groups/{group}), updated <t:{fetched_at}:R>.",
+            "[Grupo de Roblox {group}](https://www.roblox.com/groups/{group}), actualizado <t:{fetched_at}:R>.",
+            "[Grupo do Roblox {group}](https://www.roblox.com/groups/{group}), atualizado <t:{fetched_at}:R>.",
+            "[Groupe Roblox {group}](https://www.roblox.com/groups/{group}), mis à jour <t:{fetched_at}:R>.",
+        ],
+    ),
+    (
+        "groupfunds.balance",
+        [
+            "Available",
+            "Disponible",
+            "Disponível",
+            "Disponible",
+        ],
+    ),
+    (
+        "groupfunds.pending",
+        [
+            "Pending",
+            "Pendiente",
+            "Pendente",
+            "En attente",
+        ],
+    ),
+    (
+        "groupfunds.staff_only",
+        [
+            "Only ticket staff can see the group's funds.",
+            "Solo el staff de tickets puede ver los fondos del grupo.",
+            "Só a equipe de tickets pode ver os fundos do grupo.",
+            "Seul le staff des tickets peut voir les fonds du groupe.",
+        ],
+    ),
+    (
+        "groupfunds.not_configured",
+        [
+            "No Roblox group is set up for this bot.",
+            "No hay ningún grupo de Roblox configurado en este bot.",
+            "Nenhum grupo do Roblox está configurado neste bot.",
+            "Aucun groupe Roblox n'est configuré pour ce bot.",
+        ],
+    ),
+    (
+        "groupfunds.auth_failed",
+        [
+            "Roblox rejected the bot's login, so the group's funds can't be shown. The bot owner needs to update `ROBLOX_COOKIE`.",
+            "Roblox rechazó el inicio de sesión del bot, así que no se pueden mostrar los fondos del grupo. El dueño del bot debe actualizar `ROBLOX_COOKIE`.",
+            "O Roblox rejeitou o login do bot, então os fundos do grupo não podem ser mostrados. O dono do bot precisa atualizar `ROBLOX_COOKIE`.",
+            "Roblox a refusé la connexion du bot, les fonds du groupe ne peuvent donc pas être affichés. Le propriétaire du bot doit mettre à jour `ROBLOX_COOKIE`.",
+        ],
+    ),
+    (
+        "groupfunds.unavailable",
+        [
+            "Roblox couldn't be reached. Try again in a minute.",
+            "No se pudo contactar con Roblox. Inténtalo de nuevo en un minuto.",
+            "Não foi possível contatar o Roblox. Tente novamente em um minuto.",
+            "Impossible de joindre Roblox. Réessayez dans une minute.",
+        ],
+    ),
+    // /verify
+    (
+        "cmd.verify.name",
+        [
+            "verify",
+            "verificar",
+            "verificar",
+            "verifier",
+        ],
+    ),
+    (
+        "cmd.verify",
+        [
+            "Link your Roblox account so orders know who to deliver to",
+            "Vincula tu cuenta de Roblox para que los pedidos sepan a quién entregar",
+            "Vincule sua conta do Roblox para que os pedidos saibam para quem entregar",
+            "Liez votre compte Roblox pour que les commandes sachent à qui livrer",
+        ],
+    ),
+    (
+        "opt.verify.username",
+        [
+            "Your Roblox username",
+            "Tu nombre de usuario de Roblox",
+            "Seu nome de usuário do Roblox",
+            "Votre nom d'utilisateur Roblox",
+        ],
+    ),
+    (
+        "verify.title",
+        [
+            "Verify your Roblox account",
+            "Verifica tu cuenta de Roblox",
+            "Verifique sua conta do Roblox",
+            "Vérifiez votre compte Roblox",
+        ],
+    ),
+    (
+        "verify.instructions",
+        [
+            "Add this phrase to the About section of [{username}'s profile](https://www.roblox.com/users/{id}/profile), then press the button below:\n```{phrase}```\nYou have {minutes} minutes. You can remove the phrase once you're verified.",
+            "Añade esta frase a la sección Acerca de del [perfil de {username}](https://www.roblox.com/users/{id}/profile) y pulsa el botón de abajo:\n```{phrase}```\nTienes {minutes} minutos. Puedes quitar la frase cuando estés verificado.",
+            "Adicione esta frase à seção Sobre do [perfil de {username}](https://www.roblox.com/users/{id}/profile) e aperte o botão abaixo:\n```{phrase}```\nVocê tem {minutes} minutos. Pode remover a frase depois de verificado.",
+            "Ajoutez cette phrase à la section À propos du [profil de {username}](https://www.roblox.com/users/{id}/profile), puis appuyez sur le bouton ci-dessous :\n```{phrase}```\nVous avez {minutes} minutes. Vous pourrez retirer la phrase une fois vérifié.",
+        ],
+    ),
+    (
+        "verify.check",
+        [
+            "I've added it",
+            "Ya la añadí",
+            "Já adicionei",
+            "C'est fait",
+        ],
+    ),
+    (
+        "verify.unknown_user",
+        [
+            "No Roblox account is called {username}.",
+            "Ninguna cuenta de Roblox se llama {username}.",
+            "Nenhuma conta do Roblox se chama {username}.",
+            "Aucun compte Roblox ne s'appelle {username}.",
+        ],
+    ),
+    (
+        "verify.already_linked",
+        [
+            "You're already linked to {username}.",
+            "Ya estás vinculado a {username}.",
+            "Você já está vinculado a {username}.",
+            "Vous êtes déjà lié à {username}.",
+        ],
+    ),
+    (
+        "verify.expired",
+        [
+            "Your verification expired. Run `/verify` again.",
+            "Tu verificación caducó. Usa `/verify` de nuevo.",
+            "Sua verificação expirou. Use `/verify` de novo.",
+            "Votre vérification a expiré. Relancez `/verify`.",
+        ],
+    ),
+    (
+        "verify.phrase_missing",
+        [
+            "The phrase isn't in {username}'s About section yet. Save your profile and try again.",
+            "La frase aún no está en la sección Acerca de de {username}. Guarda tu perfil e inténtalo de nuevo.",
+            "A frase ainda não está na seção Sobre de {username}. Salve seu perfil e tente de novo.",
+            "La phrase n'est pas encore dans la section À propos de {username}. Enregistrez votre profil et réessayez.",
+        ],
+    ),
+    (
+        "verify.linked",
+        [
+            "Roblox account linked",
+            "Cuenta de Roblox vinculada",
+            "Conta do Roblox vinculada",
+            "Compte Roblox lié",
+        ],
+    ),
+    (
+        "verify.linked_description",
+        [
+            "Your Discord account is now linked to {username}. New orders will use it automatically.",
+            "Tu cuenta de Discord ahora está vinculada a {username}. Los nuevos pedidos la usarán automáticamente.",
+            "Sua conta do Discord agora está vinculada a {username}. Novos pedidos vão usá-la automaticamente.",
+            "Votre compte Discord est maintenant lié à {username}. Les nouvelles commandes l'utiliseront automatiquement.",
+        ],
+    ),
+    // buyer risk checks
+    (
+        "risk.title",
+        [
+            "Buyer's Roblox account",
+            "Cuenta de Roblox del comprador",
+            "Conta do Roblox do comprador",
+            "Compte Roblox de l'acheteur",
+        ],
+    ),
+    (
+        "risk.account",
+        [
+            "Linked with `/verify` to [{username}](https://www.roblox.com/users/{id}/profile).",
+            "Vinculada con `/verify` a [{username}](https://www.roblox.com/users/{id}/profile).",
+            "Vinculada com `/verify` a [{username}](https://www.roblox.com/users/{id}/profile).",
+            "Liée avec `/verify` à [{username}](https://www.roblox.com/users/{id}/profile).",
+        ],
+    ),
+    (
+        "risk.age",
+        [
+            "Account age",
+            "Antigüedad de la cuenta",
+            "Idade da conta",
+            "Âge du compte",
+        ],
+    ),
+    (
+        "risk.age_value",
+        [
+            "{days} days (<t:{created_at}:D>)",
+            "{days} días (<t:{created_at}:D>)",
+            "{days} dias (<t:{created_at}:D>)",
+            "{days} jours (<t:{created_at}:D>)",
+        ],
+    ),
+    (
+        "risk.friends",
+        [
+            "Friends",
+            "Amigos",
+            "Amigos",
+            "Amis",
+        ],
+    ),
+    (
+        "risk.badges",
+        [
+            "Badges",
+            "Insignias",
+            "Emblemas",
+            "Badges",
+        ],
+    ),
+    (
+        "risk.flags",
+        [
+            "Check before delivering",
+            "Revisa antes de entregar",
+            "Confira antes de entregar",
+            "À vérifier avant de livrer",
+        ],
+    ),
+    (
+        "risk.flag.new_account",
+        [
+            "Account is less than {days} days old",
+            "La cuenta tiene menos de {days} días",
+            "A conta tem menos de {days} dias",
+            "Le compte a moins de {days} jours",
+        ],
+    ),
+    (
+        "risk.flag.few_friends",
+        [
+            "Almost no friends",
+            "Casi no tiene amigos",
+            "Quase nenhum amigo",
+            "Presque aucun ami",
+        ],
+    ),
+    (
+        "risk.flag.few_badges",
+        [
+            "Almost no badges",
+            "Casi no tiene insignias",
+            "Quase nenhum emblema",
+            "Presque aucun badge",
+        ],
+    ),
+    // /limited
+    (
+        "cmd.limited.name",
+        [
+            "limited",
+            "limitado",
+            "limitado",
+            "limite",
+        ],
+    ),
+    (
+        "cmd.limited",
+        [
+            "Look up a limited item's RAP and value, priced at this server's rate",
+            "Consulta el RAP y el valor de un objeto limitado al precio de este servidor",
+            "Consulte o RAP e o valor de um item limitado no preço deste servidor",
+            "Consultez le RAP et la valeur d'un objet limité au tarif de ce serveur",
+        ],
+    ),
+    (
+        "opt.limited.item",
+        [
+            "Item name, acronym or id, e.g. Valkyrie Helm or VH",
+            "Nombre, acrónimo o id del objeto, p. ej. Valkyrie Helm o VH",
+            "Nome, sigla ou id do item, ex. Valkyrie Helm ou VH",
+            "Nom, acronyme ou id de l'objet, ex. Valkyrie Helm ou VH",
+        ],
+    ),
+    (
+        "limited.not_found",
+        [
+            "Rolimons doesn't list a limited called {item}.",
+            "Rolimons no tiene ningún limitado llamado {item}.",
+            "O Rolimons não lista nenhum limitado chamado {item}.",
+            "Rolimons ne répertorie aucun limité appelé {item}.",
+        ],
+    ),
+    (
+        "limited.rap",
+        [
+            "RAP",
+            "RAP",
+            "RAP",
+            "RAP",
+        ],
+    ),
+    (
+        "limited.value",
+        [
+            "Value",
+            "Valor",
+            "Valor",
+            "Valeur",
+        ],
+    ),
+    (
+        "limited.not_valued",
+        [
+            "Not valued",
+            "Sin valor",
+            "Sem valor",
+            "Non évalué",
+        ],
+    ),
+    (
+        "limited.footer_value",
+        [
+            "RAP and value from Rolimons. The price is for the value at this server's rate.",
+            "RAP y valor de Rolimons. El precio corresponde al valor según la tarifa de este servidor.",
+            "RAP e valor do Rolimons. O preço é do valor na taxa deste servidor.",
+            "RAP et valeur de Rolimons. Le prix correspond à la valeur au tarif de ce serveur.",
+        ],
+    ),
+    (
+        "limited.footer_rap",
+        [
+            "RAP from Rolimons. The item has no value, so the price is for its RAP at this server's rate.",
+            "RAP de Rolimons. El objeto no tiene valor, así que el precio corresponde a su RAP según la tarifa de este servidor.",
+            "RAP do Rolimons. O item não tem valor, então o preço é do RAP na taxa deste servidor.",
+            "RAP de Rolimons. L'objet n'a pas de valeur, le prix correspond donc à son RAP au tarif de ce serveur.",
+        ],
+    ),
+    // /rap
+    (
+        "cmd.rap.name",
+        [
+            "rap",
+            "rap",
+            "rap",
+            "rap",
+        ],
+    ),
+    (
+        "cmd.rap",
+        [
+            "Total the RAP of a Roblox user's limiteds, priced at this server's rate",
+            "Suma el RAP de los limitados de un usuario de Roblox al precio de este servidor",
+            "Soma o RAP dos limitados de um usuário do Roblox no preço deste servidor",
+            "Additionne le RAP des limités d'un utilisateur Roblox au tarif de ce serveur",
+        ],
+    ),
+    (
+        "opt.rap.username",
+        [
+            "Roblox username",
+            "Nombre de usuario de Roblox",
+            "Nome de usuário do Roblox",
+            "Nom d'utilisateur Roblox",
+        ],
+    ),
+    (
+        "rap.title",
+        [
+            "{username}'s limiteds",
+            "Limitados de {username}",
+            "Limitados de {username}",
+            "Limités de {username}",
+        ],
+    ),
+    (
+        "rap.items",
+        [
+            "Limiteds",
+            "Limitados",
+            "Limitados",
+            "Limités",
+        ],
+    ),
+    (
+        "rap.total",
+        [
+            "Total RAP",
+            "RAP total",
+            "RAP total",
+            "RAP total",
+        ],
+    ),
+    (
+        "rap.empty",
+        [
+            "No limiteds in this inventory.",
+            "No hay limitados en este inventario.",
+            "Nenhum limitado neste inventário.",
+            "Aucun limité dans cet inventaire.",
+        ],
+    ),
+    (
+        "rap.truncated",
+        [
+            "The inventory is too big to read in full, so only the first {count} limiteds are counted.",
+            "El inventario es demasiado grande para leerlo entero, así que solo se cuentan los primeros {count} limitados.",
+            "O inventário é grande demais para ler inteiro, então só os primeiros {count} limitados são contados.",
+            "L'inventaire est trop grand pour être lu en entier, seuls les {count} premiers limités sont donc comptés.",
+        ],
+    ),
+    (
+        "rap.unknown_user",
+        [
+            "No Roblox account is called {username}.",
+            "Ninguna cuenta de Roblox se llama {username}.",
+            "Nenhuma conta do Roblox se chama {username}.",
+            "Aucun compte Roblox ne s'appelle {username}.",
+        ],
+    ),
+    (
+        "rap.private",
+        [
+            "{username}'s inventory is private.",
+            "El inventario de {username} es privado.",
+            "O inventário de {username} é privado.",
+            "L'inventaire de {username} est privé.",
+        ],
+    ),
+    // /setupgamepass
+    (
+        "cmd.setupgamepass.name",
+        [
+            "setupgamepass",
+            "configurargamepass",
+            "configurargamepass",
+            "configurergamepass",
+        ],
+    ),
+    (
+        "cmd.setupgamepass",
+        [
+            "Get step-by-step instructions and the exact price for your order's gamepass",
+            "Obtén instrucciones paso a paso y el precio exacto del gamepass de tu pedido",
+            "Receba instruções passo a passo e o preço exato do gamepass do seu pedido",
+            "Obtenez les étapes et le prix exact du gamepass de votre commande",
+        ],
+    ),
+    (
+        "opt.setupgamepass.robux",
+        [
+            "Robux you should receive, e.g. 1000 or 10k",
+            "Robux que debes recibir, p. ej. 1000 o 10k",
+            "Robux que você deve receber, ex. 1000 ou 10k",
+            "Robux que vous devez recevoir, ex. 1000 ou 10k",
+        ],
+    ),
+    (
+        "setupgamepass.title",
+        [
+            "Set up your gamepass",
+            "Configura tu gamepass",
+            "Configure seu gamepass",
+            "Configurez votre gamepass",
+        ],
+    ),
+    (
+        "setupgamepass.steps",
+        [
+            "1. Open the [Creator Dashboard]({url}) and pick any of your experiences.\n2. Create a pass with any name and image.\n3. Open the pass's **Sales** tab, turn on **Item for Sale** and set the price to exactly **{price}** Robux.\n4. Save, then send the pass link in your ticket.\n\nRoblox keeps {fee}% of each sale, so a {price} Robux pass pays out {robux} Robux.",
+            "1. Abre el [Creator Dashboard]({url}) y elige cualquiera de tus experiencias.\n2. Crea un pase con cualquier nombre e imagen.\n3. Abre la pestaña **Ventas** del pase, activa **Artículo a la venta** y pon el precio exactamente en **{price}** Robux.\n4. Guarda y envía el enlace del pase en tu ticket.\n\nRoblox se queda con el {fee}% de cada venta, así que un pase de {price} Robux paga {robux} Robux.",
+            "1. Abra o [Creator Dashboard]({url}) e escolha qualquer uma das suas experiências.\n2. Crie um passe com qualquer nome e imagem.\n3. Abra a aba **Vendas** do passe, ative **Item à venda** e defina o preço como exatamente **{price}** Robux.\n4. Salve e envie o link do passe no seu ticket.\n\nO Roblox fica com {fee}% de cada venda, então um passe de {price} Robux rende {robux} Robux.",
+            "1. Ouvrez le [Creator Dashboard]({url}) et choisissez l'une de vos expériences.\n2. Créez un pass avec n'importe quel nom et image.\n3. Ouvrez l'onglet **Ventes** du pass, activez **Article en vente** et fixez le prix à exactement **{price}** Robux.\n4. Enregistrez, puis envoyez le lien du pass dans votre ticket.\n\nRoblox garde {fee} % de chaque vente, un pass à {price} Robux rapporte donc {robux} Robux.",
+        ],
+    ),
+    (
+        "setupgamepass.price",
+        [
+            "Gamepass price",
+            "Precio del gamepass",
+            "Preço do gamepass",
+            "Prix du gamepass",
+        ],
+    ),
+    (
+        "setupgamepass.receive",
+        [
+            "You receive",
+            "Recibes",
+            "Você recebe",
+            "Vous recevez",
+        ],
+    ),
+    // /verifygamepass
+    (
+        "cmd.verifygamepass.name",
+        [
+            "verifygamepass",
+            "verificargamepass",
+            "verificargamepass",
+            "verifiergamepass",
+        ],
+    ),
+    (
+        "cmd.verifygamepass",
+        [
+            "Check the buyer's gamepass before paying for it",
+            "Comprueba el gamepass del comprador antes de pagarlo",
+            "Confira o gamepass do comprador antes de pagar",
+            "Vérifiez le gamepass de l'acheteur avant de le payer",
+        ],
+    ),
+    (
+        "opt.verifygamepass.url",
+        [
+            "Link to the gamepass, or its id",
+            "Enlace al gamepass o su id",
+            "Link do gamepass ou o id",
+            "Lien du gamepass ou son id",
+        ],
+    ),
+    (
+        "opt.verifygamepass.expected_price",
+        [
+            "Price it should have, if not the order's gamepass price",
+            "Precio que debería tener, si no es el del pedido",
+            "Preço que deveria ter, se não for o do pedido",
+            "Prix attendu, s'il diffère de celui de la commande",
+        ],
+    ),
+    (
+        "verifygamepass.staff_only",
+        [
+            "Only ticket staff can verify gamepasses.",
+            "Solo el staff de tickets puede verificar gamepasses.",
+            "Só a equipe de tickets pode verificar gamepasses.",
+            "Seul le staff des tickets peut vérifier les gamepasses.",
+        ],
+    ),
+    (
+        "verifygamepass.no_account",
+        [
+            "This order has no Roblox account to check against. Ask the buyer to run `/verify`.",
+            "Este pedido no tiene una cuenta de Roblox con la que comparar. Pide al comprador que use `/verify`.",
+            "Este pedido não tem uma conta do Roblox para comparar. Peça ao comprador para usar `/verify`.",
+            "Cette commande n'a pas de compte Roblox à comparer. Demandez à l'acheteur d'utiliser `/verify`.",
+        ],
+    ),
+    (
+        "verifygamepass.not_found",
+        [
+            "Gamepass {id} doesn't exist.",
+            "El gamepass {id} no existe.",
+            "O gamepass {id} não existe.",
+            "Le gamepass {id} n'existe pas.",
+        ],
+    ),
+    (
+        "verifygamepass.passed",
+        [
+            "Gamepass check passed: {name}",
+            "Gamepass verificado: {name}",
+            "Gamepass verificado: {name}",
+            "Gamepass vérifié : {name}",
+        ],
+    ),
+    (
+        "verifygamepass.failed",
+        [
+            "Gamepass check failed: {name}",
+            "Verificación del gamepass fallida: {name}",
+            "Verificação do gamepass falhou: {name}",
+            "Échec de la vérification du gamepass : {name}",
+        ],
+    ),
+    (
+        "verifygamepass.owner_ok",
+        [
+            "Made by {username}",
+            "Creado por {username}",
+            "Criado por {username}",
+            "Créé par {username}",
+        ],
+    ),
+    (
+        "verifygamepass.owner_wrong",
+        [
+            "Not made by {username}",
+            "No lo creó {username}",
+            "Não foi criado por {username}",
+            "Pas créé par {username}",
+        ],
+    ),
+    (
+        "verifygamepass.price",
+        [
+            "Costs {price} (expected {expected})",
+            "Cuesta {price} (se esperaba {expected})",
+            "Custa {price} (esperado {expected})",
+            "Coûte {price} ({expected} attendus)",
+        ],
+    ),
+    (
+        "verifygamepass.not_for_sale",
+        [
+            "Not for sale (expected {expected})",
+            "No está a la venta (se esperaba {expected})",
+            "Não está à venda (esperado {expected})",
+            "Pas en vente ({expected} attendus)",
+        ],
+    ),
+    (
+        "parse.invalid_gamepass",
+        [
+            "{input} isn't a gamepass link.",
+            "{input} no es un enlace de gamepass.",
+            "{input} não é um link de gamepass.",
+            "{input} n'est pas un lien de gamepass.",
+        ],
+    ),
+    // group payout holds
+    (
+        "hold.eligible",
+        [
+            "Group payout possible",
+            "Pago de grupo posible",
+            "Pagamento do grupo possível",
+            "Paiement du groupe possible",
+        ],
+    ),
+    (
+        "hold.over",
+        [
+            "Order #{id} can be paid out",
+            "El pedido #{id} ya se puede pagar",
+            "O pedido #{id} já pode ser pago",
+            "La commande n°{id} peut être payée",
+        ],
+    ),
+    (
+        "hold.over_description",
+        [
+            "The {days}-day group payout hold is over. Make sure the buyer is still in the group, then send the payout.",
+            "La espera de {days} días para pagos de grupo terminó. Comprueba que el comprador sigue en el grupo y envía el pago.",
+            "A espera de {days} dias para pagamentos de grupo acabou. Confira se o comprador ainda está no grupo e envie o pagamento.",
+            "L'attente de {days} jours pour les paiements de groupe est terminée. Vérifiez que l'acheteur est toujours dans le groupe, puis envoyez le paiement.",
+        ],
+    ),
+    // /remind
+    (
+        "cmd.remind.name",
+        [
+            "remind",
+            "recordar",
+            "lembrar",
+            "rappel",
+        ],
+    ),
+    (
+        "cmd.remind",
+        [
+            "Get a reminder later, e.g. to follow up on an order",
+            "Recibe un recordatorio más tarde, p. ej. para hacer seguimiento de un pedido",
+            "Receba um lembrete mais tarde, ex. para acompanhar um pedido",
+            "Recevez un rappel plus tard, ex. pour relancer une commande",
+        ],
+    ),
+    (
+        "opt.remind.in",
+        [
+            "How long from now, e.g. 30m, 2h or 1d12h",
+            "Dentro de cuánto, p. ej. 30m, 2h o 1d12h",
+            "Daqui a quanto tempo, ex. 30m, 2h ou 1d12h",
+            "Dans combien de temps, ex. 30m, 2h ou 1d12h",
+        ],
+    ),
+    (
+        "opt.remind.note",
+        [
+            "What to remind you about",
+            "De qué recordarte",
+            "Do que lembrar você",
+            "De quoi vous rappeler",
+        ],
+    ),
+    (
+        "opt.remind.dm",
+        [
+            "Send it by DM instead of pinging you here",
+            "Enviarlo por MD en lugar de mencionarte aquí",
+            "Enviar por DM em vez de mencionar você aqui",
+            "L'envoyer en MP au lieu de vous mentionner ici",
+        ],
+    ),
+    (
+        "remind.set",
+        [
+            "Reminder set",
+            "Recordatorio creado",
+            "Lembrete criado",
+            "Rappel créé",
+        ],
+    ),
+    (
+        "remind.set_channel",
+        [
+            "I'll ping you here <t:{at}:R>.",
+            "Te mencionaré aquí <t:{at}:R>.",
+            "Vou mencionar você aqui <t:{at}:R>.",
+            "Je vous mentionnerai ici <t:{at}:R>.",
+        ],
+    ),
+    (
+        "remind.set_dm",
+        [
+            "I'll DM you <t:{at}:R>.",
+            "Te enviaré un MD <t:{at}:R>.",
+            "Vou te mandar uma DM <t:{at}:R>.",
+            "Je vous enverrai un MP <t:{at}:R>.",
+        ],
+    ),
+    (
+        "remind.note",
+        [
+            "Note",
+            "Nota",
+            "Nota",
+            "Note",
+        ],
+    ),
+    (
+        "remind.title",
+        [
+            "⏰ Reminder",
+            "⏰ Recordatorio",
+            "⏰ Lembrete",
+            "⏰ Rappel",
+        ],
+    ),
+    (
+        "remind.footer",
+        [
+            "Set with /remind",
+            "Creado con /remind",
+            "Criado com /remind",
+            "Créé avec /remind",
+        ],
+    ),
+    (
+        "remind.too_long",
+        [
+            "Reminders can be at most {days} days away.",
+            "Los recordatorios pueden ser como máximo a {days} días.",
+            "Os lembretes podem ser no máximo daqui a {days} dias.",
+            "Les rappels peuvent être au plus dans {days} jours.",
+        ],
+    ),
+    (
+        "remind.limit",
+        [
+            "You can have at most {max} reminders waiting.",
+            "Puedes tener como máximo {max} recordatorios pendientes.",
+            "Você pode ter no máximo {max} lembretes pendentes.",
+            "Vous pouvez avoir au plus {max} rappels en attente.",
+        ],
+    ),
+    (
+        "parse.invalid_duration",
+        [
+            "Invalid duration '{input}'. Try something like 30m, 2h or 1d12h.",
+            "Duración no válida '{input}'. Prueba algo como 30m, 2h o 1d12h.",
+            "Duração inválida '{input}'. Tente algo como 30m, 2h ou 1d12h.",
+            "Durée invalide '{input}'. Essayez par exemple 30m, 2h ou 1d12h.",
+        ],
+    ),
+    // /digest
+    (
+        "cmd.digest.name",
+        [
+            "digest",
+            "resumen",
+            "resumo",
+            "resume",
+        ],
+    ),
+    (
+        "cmd.digest",
+        [
+            "Get a weekly summary of this server's orders by DM",
+            "Recibe por MD un resumen semanal de los pedidos del servidor",
+            "Receba por DM um resumo semanal dos pedidos do servidor",
+            "Recevez en MP un résumé hebdomadaire des commandes du serveur",
+        ],
+    ),
+    (
+        "opt.digest.subscribe",
+        [
+            "DM me the summary every Monday",
+            "Envíame el resumen cada lunes",
+            "Me envie o resumo toda segunda-feira",
+            "M'envoyer le résumé chaque lundi",
+        ],
+    ),
+    (
+        "opt.digest.unsubscribe",
+        [
+            "Stop sending me the summary",
+            "Dejar de enviarme el resumen",
+            "Parar de me enviar o resumo",
+            "Ne plus m'envoyer le résumé",
+        ],
+    ),
+    (
+        "opt.digest.preview",
+        [
+            "Show the summary for this week so far",
+            "Mostrar el resumen de esta semana hasta ahora",
+            "Mostrar o resumo desta semana até agora",
+            "Afficher le résumé de la semaine en cours",
+        ],
+    ),
+    (
+        "digest.name",
+        [
+            "Weekly summary",
+            "Resumen semanal",
+            "Resumo semanal",
+            "Résumé hebdomadaire",
+        ],
+    ),
+    (
+        "digest.staff_only",
+        [
+            "Only ticket staff can get the weekly summary.",
+            "Solo el staff de tickets puede recibir el resumen semanal.",
+            "Apenas a equipe de tickets pode receber o resumo semanal.",
+            "Seul le staff des tickets peut recevoir le résumé hebdomadaire.",
+        ],
+    ),
+    (
+        "digest.subscribed",
+        [
+            "You'll get a summary of last week by DM every Monday. Make sure you allow DMs from this server's members.",
+            "Recibirás un resumen de la semana anterior por MD cada lunes. Asegúrate de permitir MD de los miembros de este servidor.",
+            "Você receberá um resumo da semana anterior por DM toda segunda-feira. Permita DMs dos membros deste servidor.",
+            "Vous recevrez un résumé de la semaine passée en MP chaque lundi. Autorisez les MP des membres de ce serveur.",
+        ],
+    ),
+    (
+        "digest.unsubscribed",
+        [
+            "You won't get the weekly summary anymore.",
+            "Ya no recibirás el resumen semanal.",
+            "Você não receberá mais o resumo semanal.",
+            "Vous ne recevrez plus le résumé hebdomadaire.",
+        ],
+    ),
+    (
+        "digest.title",
+        [
+            "📊 Weekly summary for {guild}",
+            "📊 Resumen semanal de {guild}",
+            "📊 Resumo semanal de {guild}",
+            "📊 Résumé hebdomadaire de {guild}",
+        ],
+    ),
+    (
+        "digest.period",
+        [
+            "<t:{start}:D> to <t:{end}:D>",
+            "Del <t:{start}:D> al <t:{end}:D>",
+            "De <t:{start}:D> a <t:{end}:D>",
+            "Du <t:{start}:D> au <t:{end}:D>",
+        ],
+    ),
+    (
+        "digest.vouches",
+        [
+            "New vouches",
+            "Nuevos vouches",
+            "Novos vouches",
+            "Nouveaux vouches",
+        ],
+    ),
+    (
+        "digest.vouch_count",
+        [
+            "{vouches} (avg. {stars} ⭐)",
+            "{vouches} (media {stars} ⭐)",
+            "{vouches} (média {stars} ⭐)",
+            "{vouches} (moyenne {stars} ⭐)",
+        ],
+    ),
+    (
+        "digest.no_vouches",
+        [
+            "None",
+            "Ninguno",
+            "Nenhum",
+            "Aucun",
+        ],
+    ),
+    (
+        "digest.rate",
+        [
+            "GBP/USD",
+            "GBP/USD",
+            "GBP/USD",
+            "GBP/USD",
+        ],
+    ),
+    // /seller
+    (
+        "opt.price.seller",
+        [
+            "Price with this seller's own rate",
+            "Calcular con la tarifa de este vendedor",
+            "Calcular com a taxa deste vendedor",
+            "Calculer avec le tarif de ce vendeur",
+        ],
+    ),
+    (
+        "cmd.seller.name",
+        [
+            "seller",
+            "vendedor",
+            "vendedor",
+            "vendeur",
+        ],
+    ),
+    (
+        "cmd.seller",
+        [
+            "Sell at your own rate in this server",
+            "Vende con tu propia tarifa en este servidor",
+            "Venda com sua própria taxa neste servidor",
+            "Vendez à votre propre tarif sur ce serveur",
+        ],
+    ),
+    (
+        "opt.seller.register",
+        [
+            "Register as a seller or change your rate",
+            "Regístrate como vendedor o cambia tu tarifa",
+            "Registre-se como vendedor ou mude sua taxa",
+            "Inscrivez-vous comme vendeur ou changez votre tarif",
+        ],
+    ),
+    (
+        "opt.seller.rate",
+        [
+            "Your price in GBP per 1,000 Robux",
+            "Tu precio en GBP por 1.000 Robux",
+            "Seu preço em GBP por 1.000 Robux",
+            "Votre prix en GBP pour 1 000 Robux",
+        ],
+    ),
+    (
+        "opt.seller.payment",
+        [
+            "Set or remove one of your own payment methods",
+            "Configura o elimina uno de tus métodos de pago",
+            "Defina ou remova um dos seus métodos de pagamento",
+            "Définissez ou retirez un de vos moyens de paiement",
+        ],
+    ),
+    (
+        "opt.seller.handle",
+        [
+            "Your handle or address; leave out to remove the method",
+            "Tu usuario o dirección; déjalo vacío para eliminar el método",
+            "Seu usuário ou endereço; deixe vazio para remover o método",
+            "Votre identifiant ou adresse ; laissez vide pour retirer le moyen",
+        ],
+    ),
+    (
+        "opt.seller.leave",
+        [
+            "Stop selling in this server",
+            "Dejar de vender en este servidor",
+            "Parar de vender neste servidor",
+            "Arrêter de vendre sur ce serveur",
+        ],
+    ),
+    (
+        "cmd.sellers.name",
+        [
+            "sellers",
+            "vendedores",
+            "vendedores",
+            "vendeurs",
+        ],
+    ),
+    (
+        "cmd.sellers",
+        [
+            "List this server's sellers with their rates and stock",
+            "Lista los vendedores del servidor con sus tarifas y stock",
+            "Lista os vendedores do servidor com suas taxas e estoque",
+            "Liste les vendeurs du serveur avec leurs tarifs et stock",
+        ],
+    ),
+    (
+        "seller.staff_only",
+        [
+            "Only ticket staff can register as sellers.",
+            "Solo el staff de tickets puede registrarse como vendedor.",
+            "Apenas a equipe de tickets pode se registrar como vendedor.",
+            "Seul le staff des tickets peut s'inscrire comme vendeur.",
+        ],
+    ),
+    (
+        "seller.register_first",
+        [
+            "You aren't a seller here yet. Use /seller register first.",
+            "Aún no eres vendedor aquí. Usa /seller register primero.",
+            "Você ainda não é vendedor aqui. Use /seller register primeiro.",
+            "Vous n'êtes pas encore vendeur ici. Utilisez d'abord /seller register.",
+        ],
+    ),
+    (
+        "seller.not_registered",
+        [
+            "{user} isn't a registered seller in this server.",
+            "{user} no es un vendedor registrado en este servidor.",
+            "{user} não é um vendedor registrado neste servidor.",
+            "{user} n'est pas un vendeur inscrit sur ce serveur.",
+        ],
+    ),
+    (
+        "seller.registered",
+        [
+            "Seller profile saved",
+            "Perfil de vendedor guardado",
+            "Perfil de vendedor salvo",
+            "Profil vendeur enregistré",
+        ],
+    ),
+    (
+        "seller.left",
+        [
+            "You're no longer a seller",
+            "Ya no eres vendedor",
+            "Você não é mais vendedor",
+            "Vous n'êtes plus vendeur",
+        ],
+    ),
+    (
+        "seller.left_description",
+        [
+            "Your rate and payment methods were removed. Your /stock is kept.",
+            "Se eliminaron tu tarifa y métodos de pago. Tu /stock se mantiene.",
+            "Sua taxa e métodos de pagamento foram removidos. Seu /stock foi mantido.",
+            "Votre tarif et vos moyens de paiement ont été retirés. Votre /stock est conservé.",
+        ],
+    ),
+    (
+        "seller.label",
+        [
+            "Seller",
+            "Vendedor",
+            "Vendedor",
+            "Vendeur",
+        ],
+    ),
+    (
+        "seller.title",
+        [
+            "🧑‍💼 Sellers",
+            "🧑‍💼 Vendedores",
+            "🧑‍💼 Vendedores",
+            "🧑‍💼 Vendeurs",
+        ],
+    ),
+    (
+        "seller.none",
+        [
+            "No sellers have registered yet.",
+            "Aún no se ha registrado ningún vendedor.",
+            "Nenhum vendedor se registrou ainda.",
+            "Aucun vendeur ne s'est encore inscrit.",
+        ],
+    ),
+    (
+        "seller.footer",
+        [
+            "Use /price seller:<name> to price with a seller's rate",
+            "Usa /price seller:<nombre> para calcular con la tarifa de un vendedor",
+            "Use /price seller:<nome> para calcular com a taxa de um vendedor",
+            "Utilisez /price seller:<nom> pour calculer avec le tarif d'un vendeur",
+        ],
+    ),
+    (
+        "seller.in_stock",
+        [
+            "✅ {robux} in stock",
+            "✅ {robux} en stock",
+            "✅ {robux} em estoque",
+            "✅ {robux} en stock",
+        ],
+    ),
+    (
+        "seller.out_of_stock",
+        [
+            "❌ Out of stock",
+            "❌ Sin stock",
+            "❌ Sem estoque",
+            "❌ En rupture de stock",
+        ],
+    ),
+    (
+        "seller.stock_unknown",
+        [
+            "📦 Stock not set",
+            "📦 Stock no configurado",
+            "📦 Estoque não definido",
+            "📦 Stock non défini",
+        ],
+    ),
+    // /queue
+    (
+        "cmd.queue.name",
+        [
+            "queue",
+            "cola",
+            "fila",
+            "file",
+        ],
+    ),
+    (
+        "cmd.queue",
+        [
+            "Wait your turn for delivery when stock is limited",
+            "Espera tu turno de entrega cuando el stock es limitado",
+            "Aguarde sua vez na entrega quando o estoque é limitado",
+            "Attendez votre tour de livraison quand le stock est limité",
+        ],
+    ),
+    (
+        "opt.queue.join",
+        [
+            "Join the delivery queue",
+            "Unirse a la cola de entrega",
+            "Entrar na fila de entrega",
+            "Rejoindre la file de livraison",
+        ],
+    ),
+    (
+        "opt.queue.amount",
+        [
+            "How much Robux you want, e.g. 10k",
+            "Cuántos Robux quieres, p. ej. 10k",
+            "Quantos Robux você quer, ex. 10k",
+            "Combien de Robux vous voulez, ex. 10k",
+        ],
+    ),
+    (
+        "opt.queue.leave",
+        [
+            "Leave the delivery queue",
+            "Salir de la cola de entrega",
+            "Sair da fila de entrega",
+            "Quitter la file de livraison",
+        ],
+    ),
+    (
+        "opt.queue.list",
+        [
+            "Show who's waiting",
+            "Mostrar quién está esperando",
+            "Mostrar quem está esperando",
+            "Afficher qui attend",
+        ],
+    ),
+    (
+        "opt.queue.next",
+        [
+            "Take the next buyer off the queue and ping them",
+            "Sacar al siguiente comprador de la cola y mencionarlo",
+            "Tirar o próximo comprador da fila e mencioná-lo",
+            "Retirer le prochain acheteur de la file et le mentionner",
+        ],
+    ),
+    (
+        "queue.staff_only",
+        [
+            "Only ticket staff can serve the queue.",
+            "Solo el staff de tickets puede atender la cola.",
+            "Apenas a equipe de tickets pode atender a fila.",
+            "Seul le staff des tickets peut servir la file.",
+        ],
+    ),
+    (
+        "queue.already_joined",
+        [
+            "You're already in the queue. Use /queue list to see your place.",
+            "Ya estás en la cola. Usa /queue list para ver tu lugar.",
+            "Você já está na fila. Use /queue list para ver sua posição.",
+            "Vous êtes déjà dans la file. Utilisez /queue list pour voir votre place.",
+        ],
+    ),
+    (
+        "queue.not_in_queue",
+        [
+            "You aren't in the queue.",
+            "No estás en la cola.",
+            "Você não está na fila.",
+            "Vous n'êtes pas dans la file.",
+        ],
+    ),
+    (
+        "queue.joined",
+        [
+            "You're in the queue",
+            "Estás en la cola",
+            "Você está na fila",
+            "Vous êtes dans la file",
+        ],
+    ),
+    (
+        "queue.left",
+        [
+            "You left the queue",
+            "Saliste de la cola",
+            "Você saiu da fila",
+            "Vous avez quitté la file",
+        ],
+    ),
+    (
+        "queue.left_description",
+        [
+            "You can join again any time with /queue join, at the back of the line.",
+            "Puedes volver a unirte cuando quieras con /queue join, al final de la cola.",
+            "Você pode voltar quando quiser com /queue join, no fim da fila.",
+            "Vous pouvez revenir à tout moment avec /queue join, en fin de file.",
+        ],
+    ),
+    (
+        "queue.position",
+        [
+            "You're **#{position}** in line. You'll be pinged when it's your turn.",
+            "Eres el **#{position}** en la cola. Te mencionaremos cuando sea tu turno.",
+            "Você é o **#{position}** na fila. Você será mencionado quando for sua vez.",
+            "Vous êtes **n°{position}** dans la file. Vous serez mentionné quand ce sera votre tour.",
+        ],
+    ),
+    (
+        "queue.position_wait",
+        [
+            "You're **#{position}** in line, served around <t:{at}:R> at the recent pace. You'll be pinged when it's your turn.",
+            "Eres el **#{position}** en la cola; al ritmo reciente, te atenderán aproximadamente <t:{at}:R>. Te mencionaremos cuando sea tu turno.",
+            "Você é o **#{position}** na fila; no ritmo recente, será atendido por volta de <t:{at}:R>. Você será mencionado quando for sua vez.",
+            "Vous êtes **n°{position}** dans la file ; au rythme récent, vous serez servi vers <t:{at}:R>. Vous serez mentionné quand ce sera votre tour.",
+        ],
+    ),
+    (
+        "queue.title",
+        [
+            "🕒 Delivery queue ({count})",
+            "🕒 Cola de entrega ({count})",
+            "🕒 Fila de entrega ({count})",
+            "🕒 File de livraison ({count})",
+        ],
+    ),
+    (
+        "queue.empty",
+        [
+            "Nobody is waiting in the queue.",
+            "No hay nadie esperando en la cola.",
+            "Ninguém está esperando na fila.",
+            "Personne n'attend dans la file.",
+        ],
+    ),
+    (
+        "queue.more",
+        [
+            "…and {count} more",
+            "…y {count} más",
+            "…e mais {count}",
+            "…et {count} de plus",
+        ],
+    ),
+    (
+        "queue.next_title",
+        [
+            "🔔 You're up!",
+            "🔔 ¡Es tu turno!",
+            "🔔 É a sua vez!",
+            "🔔 C'est votre tour !",
+        ],
+    ),
+    (
+        "queue.next",
+        [
+            "{user}, it's your turn. Open a ticket with /buy to place your order.",
+            "{user}, es tu turno. Abre un ticket con /buy para hacer tu pedido.",
+            "{user}, é a sua vez. Abra um ticket com /buy para fazer seu pedido.",
+            "{user}, c'est votre tour. Ouvrez un ticket avec /buy pour passer commande.",
+        ],
+    ),
+    (
+        "queue.remaining",
+        [
+            "Still waiting",
+            "Aún esperando",
+            "Ainda esperando",
+            "Encore en attente",
+        ],
+    ),
+    // /rolereward
+    (
+        "cmd.rolereward.name",
+        [
+            "rolereward",
+            "rolrecompensa",
+            "cargorecompensa",
+            "rolerecompense",
+        ],
+    ),
+    (
+        "cmd.rolereward",
+        [
+            "Give buyers roles as their lifetime purchases grow",
+            "Da roles a los compradores según sus compras totales",
+            "Dê cargos aos compradores conforme suas compras totais",
+            "Donnez des rôles aux acheteurs selon leurs achats cumulés",
+        ],
+    ),
+    (
+        "opt.rolereward.add",
+        [
+            "Give a role at a purchase milestone",
+            "Dar un rol al alcanzar un total de compras",
+            "Dar um cargo ao atingir um total de compras",
+            "Donner un rôle à un palier d'achats",
+        ],
+    ),
+    (
+        "opt.rolereward.min_robux",
+        [
+            "Lifetime Robux purchased to get the role",
+            "Robux comprados en total para obtener el rol",
+            "Robux comprados no total para receber o cargo",
+            "Robux achetés au total pour obtenir le rôle",
+        ],
+    ),
+    (
+        "opt.rolereward.role",
+        [
+            "Role to give",
+            "Rol que se da",
+            "Cargo a dar",
+            "Rôle à donner",
+        ],
+    ),
+    (
+        "opt.rolereward.remove",
+        [
+            "Stop giving the role at a milestone",
+            "Dejar de dar un rol por compras",
+            "Parar de dar um cargo por compras",
+            "Ne plus donner le rôle d'un palier",
+        ],
+    ),
+    (
+        "opt.rolereward.remove_min_robux",
+        [
+            "Milestone of the role to remove",
+            "Total de compras del rol que se elimina",
+            "Total de compras do cargo a remover",
+            "Palier du rôle à retirer",
+        ],
+    ),
+    (
+        "opt.rolereward.list",
+        [
+            "Show the purchase milestone roles",
+            "Mostrar los roles por total de compras",
+            "Mostrar os cargos por total de compras",
+            "Afficher les rôles par palier d'achats",
+        ],
+    ),
+    (
+        "rolereward.title",
+        [
+            "Purchase milestone roles",
+            "Roles por total de compras",
+            "Cargos por total de compras",
+            "Rôles par palier d'achats",
+        ],
+    ),
+    (
+        "rolereward.saved",
+        [
+            "Milestone role saved",
+            "Rol por compras guardado",
+            "Cargo por compras salvo",
+            "Rôle de palier enregistré",
+        ],
+    ),
+    (
+        "rolereward.removed",
+        [
+            "Milestone role removed",
+            "Rol por compras eliminado",
+            "Cargo por compras removido",
+            "Rôle de palier retiré",
+        ],
+    ),
+    (
+        "rolereward.not_found",
+        [
+            "There's no milestone role at {min} Robux.",
+            "No hay ningún rol en {min} Robux.",
+            "Não há cargo em {min} Robux.",
+            "Aucun rôle n'est défini à {min} Robux.",
+        ],
+    ),
+    (
+        "rolereward.invalid_role",
+        [
+            "That role can't be given by the bot. Pick a role that isn't @everyone or managed by an integration.",
+            "El bot no puede dar ese rol. Elige un rol que no sea @everyone ni esté gestionado por una integración.",
+            "O bot não pode dar esse cargo. Escolha um cargo que não seja @everyone nem gerenciado por uma integração.",
+            "Le bot ne peut pas donner ce rôle. Choisissez un rôle autre que @everyone et non géré par une intégration.",
+        ],
+    ),
+    (
+        "rolereward.empty",
+        [
+            "No milestone roles are set up.",
+            "No hay roles por compras configurados.",
+            "Nenhum cargo por compras configurado.",
+            "Aucun rôle de palier n'est configuré.",
+        ],
+    ),
+    (
+        "rolereward.line",
+        [
+            "{min} purchased: {role}",
+            "{min} comprados: {role}",
+            "{min} comprados: {role}",
+            "{min} achetés : {role}",
+        ],
+    ),
+    (
+        "rolereward.footer",
+        [
+            "Roles are given when /order complete is run. The bot's role must be above them.",
+            "Los roles se dan al usar /order complete. El rol del bot debe estar por encima.",
+            "Os cargos são dados ao usar /order complete. O cargo do bot deve estar acima deles.",
+            "Les rôles sont donnés avec /order complete. Le rôle du bot doit être au-dessus.",
+        ],
+    ),
+    (
+        "rolereward.unlocked",
+        [
+            "🏅 Milestone reached",
+            "🏅 Meta alcanzada",
+            "🏅 Meta atingida",
+            "🏅 Palier atteint",
+        ],
+    ),
+    (
+        "rolereward.unlocked_description",
+        [
+            "{buyer} has purchased {robux} in total and got {roles}.",
+            "{buyer} ha comprado {robux} en total y recibió {roles}.",
+            "{buyer} comprou {robux} no total e recebeu {roles}.",
+            "{buyer} a acheté {robux} au total et a reçu {roles}.",
+        ],
+    ),
+    // /vouch
+    (
+        "review.title",
+        [
+            "⭐ How was your order?",
+            "⭐ ¿Qué tal tu pedido?",
+            "⭐ Como foi seu pedido?",
+            "⭐ Comment s'est passée votre commande ?",
+        ],
+    ),
+    (
+        "review.prompt",
+        [
+            "Order #{id} with {seller} is complete. Pick a rating below to leave a vouch, with an optional comment.",
+            "El pedido #{id} con {seller} está completo. Elige una valoración abajo para dejar un vouch, con un comentario opcional.",
+            "O pedido #{id} com {seller} foi concluído. Escolha uma avaliação abaixo para deixar um vouch, com um comentário opcional.",
+            "La commande #{id} avec {seller} est terminée. Choisissez une note ci-dessous pour laisser un vouch, avec un commentaire facultatif.",
+        ],
+    ),
+    (
+        "review.modal_title",
+        [
+            "Your rating: {stars}",
+            "Tu valoración: {stars}",
+            "Sua avaliação: {stars}",
+            "Votre note : {stars}",
+        ],
+    ),
+    (
+        "review.comment",
+        [
+            "Comment",
+            "Comentario",
+            "Comentário",
+            "Commentaire",
+        ],
+    ),
+    (
+        "review.comment_placeholder",
+        [
+            "Optional: how did it go?",
+            "Opcional: ¿qué tal fue?",
+            "Opcional: como foi?",
+            "Facultatif : comment ça s'est passé ?",
+        ],
+    ),
+    (
+        "review.unavailable",
+        [
+            "There's no completed order with this seller left to review.",
+            "No queda ningún pedido completado con este vendedor por valorar.",
+            "Não há pedido concluído com este vendedor para avaliar.",
+            "Il ne reste aucune commande terminée avec ce vendeur à évaluer.",
+        ],
+    ),
+    (
+        "review.thanks",
+        [
+            "Thanks for your review!",
+            "¡Gracias por tu valoración!",
+            "Obrigado pela sua avaliação!",
+            "Merci pour votre avis !",
+        ],
+    ),
+    // /tier
+    (
+        "cmd.announce.name",
+        [
+            "announce",
+            "anunciar",
+            "anunciar",
+            "annoncer",
+        ],
+    ),
+    (
+        "cmd.announce",
+        [
+            "Set up announcements posted when rates change",
+            "Configura anuncios publicados cuando cambian las tarifas",
+            "Configure anúncios publicados quando as taxas mudam",
+            "Configurez les annonces publiées quand les tarifs changent",
+        ],
+    ),
+    (
+        "opt.announce.ratechange",
+        [
+            "Post rate changes made with /tier to a channel",
+            "Publicar en un canal los cambios de tarifa hechos con /tier",
+            "Publicar em um canal as mudanças de taxa feitas com /tier",
+            "Publier dans un salon les changements de tarif faits avec /tier",
+        ],
+    ),
+    (
+        "opt.announce.channel",
+        [
+            "Channel to post in (leave empty to turn off)",
+            "Canal donde publicar (déjalo vacío para desactivar)",
+            "Canal para publicar (deixe vazio para desativar)",
+            "Salon où publier (laisser vide pour désactiver)",
+        ],
+    ),
+    (
+        "opt.announce.role",
+        [
+            "Role to ping, e.g. customers",
+            "Rol a mencionar, p. ej. clientes",
+            "Cargo a mencionar, ex. clientes",
+            "Rôle à mentionner, par ex. clients",
+        ],
+    ),
+    (
+        "announce.title",
+        [
+            "Rate announcements",
+            "Anuncios de tarifas",
+            "Anúncios de taxas",
+            "Annonces de tarifs",
+        ],
+    ),
+    (
+        "announce.set",
+        [
+            "Rate changes made with /tier will be announced in {channel}.",
+            "Los cambios de tarifa hechos con /tier se anunciarán en {channel}.",
+            "As mudanças de taxa feitas com /tier serão anunciadas em {channel}.",
+            "Les changements de tarif faits avec /tier seront annoncés dans {channel}.",
+        ],
+    ),
+    (
+        "announce.set_role",
+        [
+            "Rate changes made with /tier will be announced in {channel}, pinging {role}.",
+            "Los cambios de tarifa hechos con /tier se anunciarán en {channel}, mencionando a {role}.",
+            "As mudanças de taxa feitas com /tier serão anunciadas em {channel}, mencionando {role}.",
+            "Les changements de tarif faits avec /tier seront annoncés dans {channel}, en mentionnant {role}.",
+        ],
+    ),
+    (
+        "announce.cleared",
+        [
+            "Rate changes will no longer be announced.",
+            "Los cambios de tarifa ya no se anunciarán.",
+            "As mudanças de taxa não serão mais anunciadas.",
+            "Les changements de tarif ne seront plus annoncés.",
+        ],
+    ),
+    (
+        "announce.ratechange_title",
+        [
+            "📢 Rates updated",
+            "📢 Tarifas actualizadas",
+            "📢 Taxas atualizadas",
+            "📢 Tarifs mis à jour",
+        ],
+    ),
+    (
+        "announce.effective",
+        [
+            "Effective",
+            "Vigente desde",
+            "Em vigor desde",
+            "En vigueur depuis",
+        ],
+    ),
+    // /order
+    (
+        "opt.order.payment",
+        [
+            "How the buyer paid, shown on the receipt",
+            "Cómo pagó el comprador, se muestra en el recibo",
+            "Como o comprador pagou, exibido no recibo",
+            "Moyen de paiement de l'acheteur, affiché sur le reçu",
+        ],
+    ),
+    (
+        "receipt.title",
+        [
+            "Receipt for order #{id}",
+            "Recibo del pedido #{id}",
+            "Recibo do pedido #{id}",
+            "Reçu de la commande #{id}",
+        ],
+    ),
+    (
+        "receipt.description",
+        [
+            "The receipt for this order is attached as a PDF.",
+            "El recibo de este pedido va adjunto en PDF.",
+            "O recibo deste pedido está anexado em PDF.",
+            "Le reçu de cette commande est joint en PDF.",
+        ],
+    ),
+    (
+        "receipt.order",
+        [
+            "Order",
+            "Pedido",
+            "Pedido",
+            "Commande",
+        ],
+    ),
+    (
+        "receipt.date",
+        [
+            "Completed",
+            "Completado",
+            "Concluído",
+            "Terminée le",
+        ],
+    ),
+    (
+        "receipt.seller",
+        [
+            "Seller",
+            "Vendedor",
+            "Vendedor",
+            "Vendeur",
+        ],
+    ),
+    (
+        "receipt.roblox_fee",
+        [
+            "Roblox fee",
+            "Comisión de Roblox",
+            "Taxa do Roblox",
+            "Frais Roblox",
+        ],
+    ),
+    (
+        "receipt.total",
+        [
+            "Total paid",
+            "Total pagado",
+            "Total pago",
+            "Total payé",
+        ],
+    ),
+    (
+        "receipt.payment",
+        [
+            "Payment method",
+            "Método de pago",
+            "Forma de pagamento",
+            "Moyen de paiement",
+        ],
+    ),
+    (
+        "receipt.footer",
+        [
+            "Keep this receipt for your records.",
+            "Guarda este recibo para tus registros.",
+            "Guarde este recibo para seus registros.",
+            "Conservez ce reçu pour vos archives.",
+        ],
+    ),
+    // /order
+    (
+        "opt.order.dispute",
+        [
+            "Flag this ticket's order as disputed",
+            "Marcar el pedido de este ticket como en disputa",
+            "Marcar o pedido deste ticket como em disputa",
+            "Signaler la commande de ce ticket comme contestée",
+        ],
+    ),
+    (
+        "opt.order.dispute_reason",
+        [
+            "What went wrong",
+            "Qué salió mal",
+            "O que deu errado",
+            "Ce qui s'est mal passé",
+        ],
+    ),
+    (
+        "opt.order.refund",
+        [
+            "Record a refund on this ticket's completed order",
+            "Registrar un reembolso del pedido completado de este ticket",
+            "Registrar um reembolso do pedido concluído deste ticket",
+            "Enregistrer un remboursement sur la commande terminée de ce ticket",
+        ],
+    ),
+    (
+        "opt.order.refund_reason",
+        [
+            "Why the buyer was refunded",
+            "Por qué se reembolsó al comprador",
+            "Por que o comprador foi reembolsado",
+            "Pourquoi l'acheteur a été remboursé",
+        ],
+    ),
+    (
+        "opt.order.refund_amount",
+        [
+            "GBP refunded (everything left if empty)",
+            "GBP reembolsados (todo lo restante si está vacío)",
+            "GBP reembolsados (todo o restante se vazio)",
+            "GBP remboursés (tout le reste si vide)",
+        ],
+    ),
+    (
+        "opt.order.delete",
+        [
+            "Delete an order, with a few minutes to undo it (admin only)",
+            "Elimina un pedido, con unos minutos para deshacerlo (solo administradores)",
+            "Exclui um pedido, com alguns minutos para desfazer (apenas administradores)",
+            "Supprime une commande, avec quelques minutes pour annuler (admins uniquement)",
+        ],
+    ),
+    (
+        "opt.order.id",
+        [
+            "The order's number",
+            "El número del pedido",
+            "O número do pedido",
+            "Le numéro de la commande",
+        ],
+    ),
+    (
+        "dispute.not_allowed",
+        [
+            "Only the buyer or ticket staff can dispute this order.",
+            "Solo el comprador o el personal de tickets pueden disputar este pedido.",
+            "Apenas o comprador ou a equipe de tickets podem disputar este pedido.",
+            "Seuls l'acheteur ou l'équipe des tickets peuvent contester cette commande.",
+        ],
+    ),
+    (
+        "dispute.already_open",
+        [
+            "Order #{id} is already disputed.",
+            "El pedido #{id} ya está en disputa.",
+            "O pedido #{id} já está em disputa.",
+            "La commande #{id} est déjà contestée.",
+        ],
+    ),
+    (
+        "dispute.opened",
+        [
+            "⚠️ Order #{id} disputed",
+            "⚠️ Pedido #{id} en disputa",
+            "⚠️ Pedido #{id} em disputa",
+            "⚠️ Commande #{id} contestée",
+        ],
+    ),
+    (
+        "dispute.opened_description",
+        [
+            "Staff have been notified and will look into it here.",
+            "Se ha avisado al personal y lo revisarán aquí.",
+            "A equipe foi avisada e vai analisar aqui.",
+            "L'équipe a été prévenue et va examiner cela ici.",
+        ],
+    ),
+    (
+        "dispute.opened_by",
+        [
+            "Raised by",
+            "Abierta por",
+            "Aberta por",
+            "Ouverte par",
+        ],
+    ),
+    (
+        "dispute.ticket",
+        [
+            "Ticket",
+            "Ticket",
+            "Ticket",
+            "Ticket",
+        ],
+    ),
+    (
+        "dispute.reason",
+        [
+            "Reason",
+            "Motivo",
+            "Motivo",
+            "Raison",
+        ],
+    ),
+    (
+        "refund.not_completed",
+        [
+            "Only completed orders can be refunded. Close the ticket to cancel an order that wasn't delivered.",
+            "Solo se pueden reembolsar pedidos completados. Cierra el ticket para cancelar un pedido no entregado.",
+            "Apenas pedidos concluídos podem ser reembolsados. Feche o ticket para cancelar um pedido não entregue.",
+            "Seules les commandes terminées peuvent être remboursées. Fermez le ticket pour annuler une commande non livrée.",
+        ],
+    ),
+    (
+        "refund.fully_refunded",
+        [
+            "Order #{id} has already been fully refunded.",
+            "El pedido #{id} ya se reembolsó por completo.",
+            "O pedido #{id} já foi totalmente reembolsado.",
+            "La commande #{id} a déjà été entièrement remboursée.",
+        ],
+    ),
+    (
+        "refund.too_much",
+        [
+            "At most {max} can still be refunded on this order.",
+            "Como máximo se pueden reembolsar {max} en este pedido.",
+            "No máximo {max} ainda pode ser reembolsado neste pedido.",
+            "Au plus {max} peut encore être remboursé sur cette commande.",
+        ],
+    ),
+    (
+        "refund.recorded",
+        [
+            "↩️ Refund on order #{id}",
+            "↩️ Reembolso del pedido #{id}",
+            "↩️ Reembolso do pedido #{id}",
+            "↩️ Remboursement de la commande #{id}",
+        ],
+    ),
+    (
+        "refund.amount",
+        [
+            "Refunded",
+            "Reembolsado",
+            "Reembolsado",
+            "Remboursé",
+        ],
+    ),
+    (
+        "refund.remaining",
+        [
+            "Still refundable",
+            "Aún reembolsable",
+            "Ainda reembolsável",
+            "Encore remboursable",
+        ],
+    ),
+    (
+        "refund.refunded_by",
+        [
+            "Refunded by",
+            "Reembolsado por",
+            "Reembolsado por",
+            "Remboursé par",
+        ],
+    ),
+    // /disputechannel
+    (
+        "cmd.disputechannel.name",
+        [
+            "disputechannel",
+            "canaldisputas",
+            "canaldisputas",
+            "salonlitiges",
+        ],
+    ),
+    (
+        "cmd.disputechannel",
+        [
+            "Set the channel disputes and refunds are logged to",
+            "Elige el canal donde se registran disputas y reembolsos",
+            "Defina o canal onde disputas e reembolsos são registrados",
+            "Choisissez le salon où les litiges et remboursements sont consignés",
+        ],
+    ),
+    (
+        "opt.disputechannel.channel",
+        [
+            "Channel to log to (leave empty to stop logging)",
+            "Canal de registro (déjalo vacío para dejar de registrar)",
+            "Canal de registro (deixe vazio para parar de registrar)",
+            "Salon de journal (laisser vide pour arrêter)",
+        ],
+    ),
+    (
+        "dispute.channel_title",
+        [
+            "Dispute log",
+            "Registro de disputas",
+            "Registro de disputas",
+            "Journal des litiges",
+        ],
+    ),
+    (
+        "dispute.channel_set",
+        [
+            "Disputes and refunds will be logged in {channel}.",
+            "Las disputas y reembolsos se registrarán en {channel}.",
+            "Disputas e reembolsos serão registrados em {channel}.",
+            "Les litiges et remboursements seront consignés dans {channel}.",
+        ],
+    ),
+    (
+        "dispute.channel_cleared",
+        [
+            "Disputes and refunds will no longer be logged.",
+            "Las disputas y reembolsos ya no se registrarán.",
+            "Disputas e reembolsos não serão mais registrados.",
+            "Les litiges et remboursements ne seront plus consignés.",
+        ],
+    ),
+    // /stats
+    (
+        "stats.refunds",
+        [
+            "Refunds",
+            "Reembolsos",
+            "Reembolsos",
+            "Remboursements",
+        ],
+    ),
+    (
+        "stats.refund_total",
+        [
+            "{refunds} totalling {gbp}, taken out of {gross} revenue",
+            "{refunds} por un total de {gbp}, restados de {gross} de ingresos",
+            "{refunds} somando {gbp}, descontados de {gross} de receita",
+            "{refunds} pour un total de {gbp}, déduits de {gross} de revenus",
+        ],
+    ),
+    // /middleman
+    (
+        "cmd.middleman.name",
+        [
+            "middleman",
+            "intermediario",
+            "intermediario",
+            "intermediaire",
+        ],
+    ),
+    (
+        "cmd.middleman",
+        [
+            "Work out the split for a middleman-assisted trade",
+            "Calcula el reparto de un intercambio con intermediario",
+            "Calcule a divisão de uma troca com intermediário",
+            "Calculez la répartition d'un échange avec intermédiaire",
+        ],
+    ),
+    (
+        "opt.middleman.amount",
+        [
+            "Trade amount in GBP",
+            "Importe del intercambio en GBP",
+            "Valor da troca em GBP",
+            "Montant de l'échange en GBP",
+        ],
+    ),
+    (
+        "opt.middleman.fee",
+        [
+            "Middleman fee in percent",
+            "Comisión del intermediario en porcentaje",
+            "Taxa do intermediário em porcentagem",
+            "Commission de l'intermédiaire en pourcentage",
+        ],
+    ),
+    (
+        "opt.middleman.paid_by",
+        [
+            "Who pays the fee (split 50/50 if empty)",
+            "Quién paga la comisión (50/50 si está vacío)",
+            "Quem paga a taxa (50/50 se vazio)",
+            "Qui paie la commission (50/50 si vide)",
+        ],
+    ),
+    (
+        "middleman.calc_title",
+        [
+            "🤝 Middleman split",
+            "🤝 Reparto con intermediario",
+            "🤝 Divisão com intermediário",
+            "🤝 Répartition avec intermédiaire",
+        ],
+    ),
+    (
+        "middleman.paid_by_buyer",
+        [
+            "The buyer pays the middleman's fee.",
+            "El comprador paga la comisión del intermediario.",
+            "O comprador paga a taxa do intermediário.",
+            "L'acheteur paie la commission de l'intermédiaire.",
+        ],
+    ),
+    (
+        "middleman.paid_by_seller",
+        [
+            "The seller pays the middleman's fee.",
+            "El vendedor paga la comisión del intermediario.",
+            "O vendedor paga a taxa do intermediário.",
+            "Le vendeur paie la commission de l'intermédiaire.",
+        ],
+    ),
+    (
+        "middleman.paid_by_split",
+        [
+            "The buyer and seller each pay half the middleman's fee.",
+            "El comprador y el vendedor pagan cada uno la mitad de la comisión.",
+            "O comprador e o vendedor pagam cada um metade da taxa.",
+            "L'acheteur et le vendeur paient chacun la moitié de la commission.",
+        ],
+    ),
+    (
+        "middleman.trade",
+        [
+            "Trade",
+            "Intercambio",
+            "Troca",
+            "Échange",
+        ],
+    ),
+    (
+        "middleman.fee",
+        [
+            "Fee ({percent}%)",
+            "Comisión ({percent}%)",
+            "Taxa ({percent}%)",
+            "Commission ({percent} %)",
+        ],
+    ),
+    (
+        "middleman.buyer_pays",
+        [
+            "Buyer pays",
+            "El comprador paga",
+            "O comprador paga",
+            "L'acheteur paie",
+        ],
+    ),
+    (
+        "middleman.seller_receives",
+        [
+            "Seller receives",
+            "El vendedor recibe",
+            "O vendedor recebe",
+            "Le vendeur reçoit",
+        ],
+    ),
+    // /order
+    (
+        "opt.order.middleman",
+        [
+            "Have a middleman hold the payment for this ticket's order",
+            "Que un intermediario retenga el pago del pedido de este ticket",
+            "Deixe um intermediário reter o pagamento do pedido deste ticket",
+            "Faire garder le paiement de la commande de ce ticket par un intermédiaire",
+        ],
+    ),
+    (
+        "opt.ticket.middleman_role",
+        [
+            "Role that confirms middleman payments",
+            "Rol que confirma los pagos con intermediario",
+            "Cargo que confirma pagamentos com intermediário",
+            "Rôle qui confirme les paiements par intermédiaire",
+        ],
+    ),
+    (
+        "ticket.setup_middleman",
+        [
+            "Middleman role: {role}",
+            "Rol de intermediario: {role}",
+            "Cargo de intermediário: {role}",
+            "Rôle d'intermédiaire : {role}",
+        ],
+    ),
+    (
+        "middleman.no_role",
+        [
+            "No middleman role is set up. An admin can choose one with /ticket setup.",
+            "No hay un rol de intermediario. Un administrador puede elegirlo con /ticket setup.",
+            "Nenhum cargo de intermediário configurado. Um administrador pode escolher com /ticket setup.",
+            "Aucun rôle d'intermédiaire n'est configuré. Un administrateur peut en choisir un avec /ticket setup.",
+        ],
+    ),
+    (
+        "middleman.not_allowed",
+        [
+            "Only the buyer or ticket staff can bring in a middleman.",
+            "Solo el comprador o el personal de tickets pueden llamar a un intermediario.",
+            "Apenas o comprador ou a equipe de tickets podem chamar um intermediário.",
+            "Seuls l'acheteur ou l'équipe des tickets peuvent faire appel à un intermédiaire.",
+        ],
+    ),
+    (
+        "middleman.title",
+        [
+            "🤝 Middleman for order #{id}",
+            "🤝 Intermediario del pedido #{id}",
+            "🤝 Intermediário do pedido #{id}",
+            "🤝 Intermédiaire de la commande #{id}",
+        ],
+    ),
+    (
+        "middleman.description",
+        [
+            "{role} holds the buyer's payment. Confirm when it arrives, then release it to the seller once the Robux are delivered.",
+            "{role} retiene el pago del comprador. Confirma cuando llegue y libéralo al vendedor cuando se entreguen los Robux.",
+            "{role} retém o pagamento do comprador. Confirme quando chegar e libere para o vendedor quando os Robux forem entregues.",
+            "{role} garde le paiement de l'acheteur. Confirmez sa réception, puis versez-le au vendeur une fois les Robux livrés.",
+        ],
+    ),
+    (
+        "middleman.received",
+        [
+            "Payment received",
+            "Pago recibido",
+            "Pagamento recebido",
+            "Paiement reçu",
+        ],
+    ),
+    (
+        "middleman.released",
+        [
+            "Released to seller",
+            "Liberado al vendedor",
+            "Liberado ao vendedor",
+            "Versé au vendeur",
+        ],
+    ),
+    (
+        "middleman.step_waiting",
+        [
+            "⏳ Waiting",
+            "⏳ Pendiente",
+            "⏳ Aguardando",
+            "⏳ En attente",
+        ],
+    ),
+    (
+        "middleman.step_done",
+        [
+            "✅ {user} <t:{at}:R>",
+            "✅ {user} <t:{at}:R>",
+            "✅ {user} <t:{at}:R>",
+            "✅ {user} <t:{at}:R>",
+        ],
+    ),
+    (
+        "middleman.confirm_received",
+        [
+            "Payment received",
+            "Pago recibido",
+            "Pagamento recebido",
+            "Paiement reçu",
+        ],
+    ),
+    (
+        "middleman.confirm_released",
+        [
+            "Release to seller",
+            "Liberar al vendedor",
+            "Liberar ao vendedor",
+            "Verser au vendeur",
+        ],
+    ),
+    (
+        "middleman.role_only",
+        [
+            "Only {role} can confirm middleman steps.",
+            "Solo {role} puede confirmar los pasos del intermediario.",
+            "Apenas {role} pode confirmar as etapas do intermediário.",
+            "Seul {role} peut confirmer les étapes de l'intermédiaire.",
+        ],
+    ),
+    (
+        "middleman.already_done",
+        [
+            "That step has already been confirmed, or the payment hasn't been received yet.",
+            "Ese paso ya se confirmó o el pago aún no se ha recibido.",
+            "Essa etapa já foi confirmada ou o pagamento ainda não foi recebido.",
+            "Cette étape a déjà été confirmée, ou le paiement n'a pas encore été reçu.",
+        ],
+    ),
+    // /pricebatch
+    (
+        "cmd.split.name",
+        [
+            "split",
+            "dividir",
+            "dividir",
+            "partager",
+        ],
+    ),
+    (
+        "cmd.split",
+        [
+            "Split the cost of a Robux purchase between several buyers",
+            "Divide el coste de una compra de Robux entre varios compradores",
+            "Divida o custo de uma compra de Robux entre vários compradores",
+            "Partagez le coût d'un achat de Robux entre plusieurs acheteurs",
+        ],
+    ),
+    (
+        "opt.split.robux",
+        [
+            "Amount of Robux, e.g. 10k",
+            "Cantidad de Robux, p. ej. 10k",
+            "Quantidade de Robux, ex. 10k",
+            "Quantité de Robux, par ex. 10k",
+        ],
+    ),
+    (
+        "opt.split.people",
+        [
+            "How many people are paying",
+            "Cuántas personas pagan",
+            "Quantas pessoas vão pagar",
+            "Combien de personnes paient",
+        ],
+    ),
+    (
+        "opt.split.currency",
+        [
+            "Currency code, e.g. EUR (your /setcurrency one if empty)",
+            "Código de moneda, p. ej. EUR (la de /setcurrency si está vacío)",
+            "Código da moeda, ex. EUR (a do /setcurrency se vazio)",
+            "Code de devise, par ex. EUR (celle de /setcurrency si vide)",
+        ],
+    ),
+    (
+        "split.title",
+        [
+            "{robux} split between {people}",
+            "{robux} entre {people}",
+            "{robux} entre {people}",
+            "{robux} partagés entre {people}",
+        ],
+    ),
+    (
+        "split.share",
+        [
+            "{count} × {amount}",
+            "{count} × {amount}",
+            "{count} × {amount}",
+            "{count} × {amount}",
+        ],
+    ),
+    (
+        "split.total",
+        [
+            "Total",
+            "Total",
+            "Total",
+            "Total",
+        ],
+    ),
+    (
+        "split.footer",
+        [
+            "Shares are rounded so they add up to the total exactly.",
+            "Las partes se redondean para que sumen exactamente el total.",
+            "As partes são arredondadas para somar exatamente o total.",
+            "Les parts sont arrondies pour que leur somme fasse exactement le total.",
+        ],
+    ),
+    // /giftcards
+    (
+        "cmd.giftcards.name",
+        [
+            "giftcards",
+            "tarjetasregalo",
+            "cartoespresente",
+            "cartescadeaux",
+        ],
+    ),
+    (
+        "cmd.giftcards",
+        [
+            "List Roblox gift cards and how they compare with this server's rate",
+            "Muestra las tarjetas regalo de Roblox y cómo se comparan con la tarifa de este servidor",
+            "Mostra os cartões-presente da Roblox e como se comparam com a taxa deste servidor",
+            "Affiche les cartes cadeaux Roblox et leur comparaison avec le tarif de ce serveur",
+        ],
+    ),
+    (
+        "opt.giftcards.region",
+        [
+            "Only show cards sold in this region",
+            "Mostrar solo las tarjetas de esta región",
+            "Mostrar apenas os cartões desta região",
+            "Afficher seulement les cartes vendues dans cette région",
+        ],
+    ),
+    (
+        "giftcards.title",
+        [
+            "🎁 Roblox Gift Cards",
+            "🎁 Tarjetas regalo de Roblox",
+            "🎁 Cartões-presente da Roblox",
+            "🎁 Cartes cadeaux Roblox",
+        ],
+    ),
+    (
+        "giftcards.description",
+        [
+            "What each card redeems for, compared with buying the same Robux at this server's rate in the card's currency.",
+            "Lo que da cada tarjeta, comparado con comprar los mismos Robux a la tarifa de este servidor en la moneda de la tarjeta.",
+            "O que cada cartão dá, comparado com comprar os mesmos Robux pela taxa deste servidor na moeda do cartão.",
+            "Ce que donne chaque carte, comparé à l'achat des mêmes Robux au tarif de ce serveur dans la devise de la carte.",
+        ],
+    ),
+    (
+        "giftcards.premium",
+        [
+            "{robux} with Premium",
+            "{robux} con Premium",
+            "{robux} com Premium",
+            "{robux} avec Premium",
+        ],
+    ),
+    // /value
+    (
+        "cmd.value.name",
+        [
+            "value",
+            "valor",
+            "valor",
+            "valeur",
+        ],
+    ),
+    (
+        "cmd.value",
+        [
+            "See what an amount of money buys here, in official packs and as Nitro",
+            "Mira lo que compra una cantidad de dinero aquí, en paquetes oficiales y en Nitro",
+            "Veja o que uma quantia compra aqui, em pacotes oficiais e em Nitro",
+            "Voyez ce qu'une somme achète ici, en packs officiels et en Nitro",
+        ],
+    ),
+    (
+        "opt.value.amount",
+        [
+            "Amount of money",
+            "Cantidad de dinero",
+            "Quantia em dinheiro",
+            "Somme d'argent",
+        ],
+    ),
+    (
+        "opt.value.currency",
+        [
+            "Currency of the amount",
+            "Moneda de la cantidad",
+            "Moeda da quantia",
+            "Devise de la somme",
+        ],
+    ),
+    (
+        "value.title",
+        [
+            "💸 What {amount} buys",
+            "💸 Lo que compran {amount}",
+            "💸 O que {amount} compram",
+            "💸 Ce que {amount} permettent d'acheter",
+        ],
+    ),
+    (
+        "value.seller",
+        [
+            "This server",
+            "Este servidor",
+            "Este servidor",
+            "Ce serveur",
+        ],
+    ),
+    (
+        "value.official",
+        [
+            "Official packs",
+            "Paquetes oficiales",
+            "Pacotes oficiais",
+            "Packs officiels",
+        ],
+    ),
+    (
+        "value.nitro",
+        [
+            "Discord Nitro",
+            "Discord Nitro",
+            "Discord Nitro",
+            "Discord Nitro",
+        ],
+    ),
+    (
+        "value.months",
+        [
+            "{months} months of {plan}",
+            "{months} meses de {plan}",
+            "{months} meses de {plan}",
+            "{months} mois de {plan}",
+        ],
+    ),
+    (
+        "value.too_little",
+        [
+            "Not enough for the smallest pack",
+            "No alcanza para el paquete más pequeño",
+            "Não dá para o menor pacote",
+            "Pas assez pour le plus petit pack",
+        ],
+    ),
+    (
+        "value.more_robux",
+        [
+            "This server gets you {percent}% more Robux than official packs.",
+            "Este servidor te da un {percent}% más de Robux que los paquetes oficiales.",
+            "Este servidor te dá {percent}% mais Robux que os pacotes oficiais.",
+            "Ce serveur vous donne {percent} % de Robux en plus que les packs officiels.",
+        ],
+    ),
+    // /margin
+    (
+        "cmd.margin.name",
+        [
+            "margin",
+            "margen",
+            "margem",
+            "marge",
+        ],
+    ),
+    (
+        "cmd.margin",
+        [
+            "Work out a reseller's profit after Roblox's cut and payment fees",
+            "Calcula el beneficio de un revendedor tras la comisión de Roblox y de pago",
+            "Calcule o lucro de um revendedor após a taxa da Roblox e do pagamento",
+            "Calculez le bénéfice d'un revendeur après la part de Roblox et les frais de paiement",
+        ],
+    ),
+    (
+        "opt.margin.buy_rate",
+        [
+            "GBP per 1,000 Robux you buy at",
+            "GBP por cada 1.000 Robux a los que compras",
+            "GBP por 1.000 Robux que você compra",
+            "GBP par 1 000 Robux à l'achat",
+        ],
+    ),
+    (
+        "opt.margin.sell_rate",
+        [
+            "GBP per 1,000 Robux you sell at",
+            "GBP por cada 1.000 Robux a los que vendes",
+            "GBP por 1.000 Robux que você vende",
+            "GBP par 1 000 Robux à la vente",
+        ],
+    ),
+    (
+        "opt.margin.amount",
+        [
+            "Robux delivered to the buyer, e.g. 10k",
+            "Robux entregados al comprador, p. ej. 10k",
+            "Robux entregues ao comprador, ex. 10k",
+            "Robux livrés à l'acheteur, par ex. 10k",
+        ],
+    ),
+    (
+        "opt.margin.method",
+        [
+            "How the Robux are delivered (Gamepass if empty)",
+            "Cómo se entregan los Robux (Gamepass si está vacío)",
+            "Como os Robux são entregues (Gamepass se vazio)",
+            "Mode de livraison des Robux (Gamepass si vide)",
+        ],
+    ),
+    (
+        "opt.margin.payment",
+        [
+            "How the buyer pays (PayPal if empty)",
+            "Cómo paga el comprador (PayPal si está vacío)",
+            "Como o comprador paga (PayPal se vazio)",
+            "Moyen de paiement de l'acheteur (PayPal si vide)",
+        ],
+    ),
+    (
+        "margin.title",
+        [
+            "📈 Margin on {robux}",
+            "📈 Margen de {robux}",
+            "📈 Margem de {robux}",
+            "📈 Marge sur {robux}",
+        ],
+    ),
+    (
+        "margin.description",
+        [
+            "Bought at {buy} and sold at {sell} per 1k, delivered by {method} and paid with {payment}.",
+            "Comprados a {buy} y vendidos a {sell} por cada 1k, entregados por {method} y pagados con {payment}.",
+            "Comprados a {buy} e vendidos a {sell} por 1k, entregues por {method} e pagos com {payment}.",
+            "Achetés à {buy} et vendus à {sell} les 1k, livrés par {method} et payés par {payment}.",
+        ],
+    ),
+    (
+        "margin.revenue",
+        [
+            "Buyer pays",
+            "El comprador paga",
+            "O comprador paga",
+            "L'acheteur paie",
+        ],
+    ),
+    (
+        "margin.cost",
+        [
+            "Cost of {robux}",
+            "Coste de {robux}",
+            "Custo de {robux}",
+            "Coût de {robux}",
+        ],
+    ),
+    (
+        "margin.processor_fee",
+        [
+            "Payment fee ({percent}% + {fixed})",
+            "Comisión de pago ({percent}% + {fixed})",
+            "Taxa de pagamento ({percent}% + {fixed})",
+            "Frais de paiement ({percent} % + {fixed})",
+        ],
+    ),
+    (
+        "margin.profit",
+        [
+            "Profit",
+            "Beneficio",
+            "Lucro",
+            "Bénéfice",
+        ],
+    ),
+    (
+        "margin.per_thousand",
+        [
+            "Profit per 1k",
+            "Beneficio por 1k",
+            "Lucro por 1k",
+            "Bénéfice par 1k",
+        ],
+    ),
+    (
+        "margin.loss",
+        [
+            "These rates lose money once the fees are taken.",
+            "Con estas tarifas se pierde dinero tras las comisiones.",
+            "Essas taxas dão prejuízo depois das comissões.",
+            "Ces tarifs font perdre de l'argent une fois les frais déduits.",
+        ],
+    ),
+    // /orderlimits
+    (
+        "cmd.orderlimits.name",
+        [
+            "orderlimits",
+            "limitespedido",
+            "limitespedido",
+            "limitescommande",
+        ],
+    ),
+    (
+        "cmd.orderlimits",
+        [
+            "Set the smallest and largest orders this server takes",
+            "Establece los pedidos mínimo y máximo de este servidor",
+            "Defina os pedidos mínimo e máximo deste servidor",
+            "Définissez les commandes minimale et maximale de ce serveur",
+        ],
+    ),
+    (
+        "opt.orderlimits.min",
+        [
+            "Smallest order in Robux (no minimum if empty)",
+            "Pedido mínimo en Robux (sin mínimo si está vacío)",
+            "Pedido mínimo em Robux (sem mínimo se vazio)",
+            "Commande minimale en Robux (aucun minimum si vide)",
+        ],
+    ),
+    (
+        "opt.orderlimits.max",
+        [
+            "Largest order in Robux (no maximum if empty)",
+            "Pedido máximo en Robux (sin máximo si está vacío)",
+            "Pedido máximo em Robux (sem máximo se vazio)",
+            "Commande maximale en Robux (aucun maximum si vide)",
+        ],
+    ),
+    (
+        "orderlimits.title",
+        [
+            "📏 Order Limits",
+            "📏 Límites de pedido",
+            "📏 Limites de pedido",
+            "📏 Limites de commande",
+        ],
+    ),
+    (
+        "orderlimits.set",
+        [
+            "Orders now need to be between {min} and {max}.",
+            "Ahora los pedidos deben estar entre {min} y {max}.",
+            "Agora os pedidos precisam estar entre {min} e {max}.",
+            "Les commandes doivent maintenant être comprises entre {min} et {max}.",
+        ],
+    ),
+    (
+        "orderlimits.none",
+        [
+            "no limit",
+            "sin límite",
+            "sem limite",
+            "aucune limite",
+        ],
+    ),
+    (
+        "orderlimits.cleared",
+        [
+            "Order limits removed. Any amount can be ordered.",
+            "Límites de pedido eliminados. Se puede pedir cualquier cantidad.",
+            "Limites de pedido removidos. Qualquer quantia pode ser pedida.",
+            "Limites de commande supprimées. Toute quantité peut être commandée.",
+        ],
+    ),
+    (
+        "orderlimits.min_above_max",
+        [
+            "The minimum can't be more than the maximum.",
+            "El mínimo no puede ser mayor que el máximo.",
+            "O mínimo não pode ser maior que o máximo.",
+            "Le minimum ne peut pas dépasser le maximum.",
+        ],
+    ),
+    (
+        "validation.order_min",
+        [
+            "The smallest order here is {min} R$. Try {min} R$ instead.",
+            "El pedido mínimo aquí es de {min} R$. Prueba con {min} R$.",
+            "O pedido mínimo aqui é de {min} R$. Tente {min} R$.",
+            "La commande minimale ici est de {min} R$. Essayez {min} R$.",
+        ],
+    ),
+    (
+        "validation.order_max",
+        [
+            "The largest order here is {max} R$. Try {max} R$ instead, or split it into several orders.",
+            "El pedido máximo aquí es de {max} R$. Prueba con {max} R$ o divídelo en varios pedidos.",
+            "O pedido máximo aqui é de {max} R$. Tente {max} R$ ou divida em vários pedidos.",
+            "La commande maximale ici est de {max} R$. Essayez {max} R$ ou répartissez-la en plusieurs commandes.",
+        ],
+    ),
+    // /rounding
+    (
+        "cmd.rounding.name",
+        [
+            "rounding",
+            "redondeo",
+            "arredondamento",
+            "arrondi",
+        ],
+    ),
+    (
+        "cmd.rounding",
+        [
+            "Choose how prices are rounded in this server's replies",
+            "Elige cómo se redondean los precios en las respuestas del servidor",
+            "Escolha como os preços são arredondados nas respostas do servidor",
+            "Choisissez comment les prix sont arrondis dans les réponses du serveur",
+        ],
+    ),
+    (
+        "opt.rounding.mode",
+        [
+            "Round to the nearest, always up or always down",
+            "Redondear al más cercano, siempre hacia arriba o siempre hacia abajo",
+            "Arredondar para o mais próximo, sempre para cima ou sempre para baixo",
+            "Arrondir au plus proche, toujours au-dessus ou toujours en dessous",
+        ],
+    ),
+    (
+        "opt.rounding.decimals",
+        [
+            "Decimal places to show (2 if empty)",
+            "Decimales que mostrar (2 si está vacío)",
+            "Casas decimais a mostrar (2 se vazio)",
+            "Décimales affichées (2 si vide)",
+        ],
+    ),
+    (
+        "rounding.title",
+        [
+            "🔢 Price Rounding",
+            "🔢 Redondeo de precios",
+            "🔢 Arredondamento de preços",
+            "🔢 Arrondi des prix",
+        ],
+    ),
+    (
+        "rounding.mode_nearest",
+        [
+            "to the nearest",
+            "al más cercano",
+            "para o mais próximo",
+            "au plus proche",
+        ],
+    ),
+    (
+        "rounding.mode_up",
+        [
+            "up",
+            "hacia arriba",
+            "para cima",
+            "au-dessus",
+        ],
+    ),
+    (
+        "rounding.mode_down",
+        [
+            "down",
+            "hacia abajo",
+            "para baixo",
+            "en dessous",
+        ],
+    ),
+    (
+        "rounding.set",
+        [
+            "Prices are now rounded {mode} to {decimals} decimal places, so {exact} is shown as {example}. Orders and stats keep the exact amounts.",
+            "Ahora los precios se redondean {mode} con {decimals} decimales, así que {exact} se muestra como {example}. Los pedidos y las estadísticas guardan los importes exactos.",
+            "Agora os preços são arredondados {mode} com {decimals} casas decimais, então {exact} aparece como {example}. Pedidos e estatísticas mantêm os valores exatos.",
+            "Les prix sont maintenant arrondis {mode} à {decimals} décimales : {exact} s'affiche donc {example}. Les commandes et les statistiques gardent les montants exacts.",
+        ],
+    ),
+    // /rates
+    (
+        "opt.price.side",
+        [
+            "Whether we sell Robux to you or buy them from you (sell if empty)",
+            "Si te vendemos Robux o te los compramos (vender si está vacío)",
+            "Se vendemos Robux para você ou compramos de você (vender se vazio)",
+            "Si nous vous vendons des Robux ou vous en achetons (vente si vide)",
+        ],
+    ),
+    (
+        "cmd.rates.name",
+        [
+            "rates",
+            "tarifas",
+            "taxas",
+            "tarifs",
+        ],
+    ),
+    (
+        "cmd.rates",
+        [
+            "Show this server's sell and buy rates and the spread",
+            "Muestra las tarifas de venta y compra del servidor y su diferencia",
+            "Mostra as taxas de venda e compra do servidor e a diferença",
+            "Affiche les tarifs de vente et d'achat du serveur et l'écart",
+        ],
+    ),
+    (
+        "cmd.setrates.name",
+        [
+            "setrates",
+            "fijartarifas",
+            "definirtaxas",
+            "definirtarifs",
+        ],
+    ),
+    (
+        "cmd.setrates",
+        [
+            "Set the rates this server sells and buys Robux at",
+            "Establece las tarifas a las que el servidor vende y compra Robux",
+            "Defina as taxas de venda e compra de Robux do servidor",
+            "Définissez les tarifs de vente et d'achat de Robux du serveur",
+        ],
+    ),
+    (
+        "opt.setrates.sell",
+        [
+            "GBP per 1,000 Robux sold, below the first tier",
+            "GBP por cada 1.000 Robux vendidos, por debajo del primer nivel",
+            "GBP por 1.000 Robux vendidos, abaixo do primeiro nível",
+            "GBP par 1 000 Robux vendus, sous le premier palier",
+        ],
+    ),
+    (
+        "opt.setrates.buy",
+        [
+            "GBP per 1,000 Robux bought from users (0 to stop buying)",
+            "GBP por cada 1.000 Robux comprados a usuarios (0 para dejar de comprar)",
+            "GBP por 1.000 Robux comprados de usuários (0 para parar de comprar)",
+            "GBP par 1 000 Robux achetés aux utilisateurs (0 pour arrêter d'acheter)",
+        ],
+    ),
+    (
+        "rates.title",
+        [
+            "💱 Sell and Buy Rates",
+            "💱 Tarifas de venta y compra",
+            "💱 Taxas de venda e compra",
+            "💱 Tarifs de vente et d'achat",
+        ],
+    ),
+    (
+        "rates.sell",
+        [
+            "We sell at",
+            "Vendemos a",
+            "Vendemos a",
+            "Nous vendons à",
+        ],
+    ),
+    (
+        "rates.buy",
+        [
+            "We buy at",
+            "Compramos a",
+            "Compramos a",
+            "Nous achetons à",
+        ],
+    ),
+    (
+        "rates.spread",
+        [
+            "Spread",
+            "Diferencia",
+            "Diferença",
+            "Écart",
+        ],
+    ),
+    (
+        "rates.tier_spreads",
+        [
+            "Spread by tier",
+            "Diferencia por nivel",
+            "Diferença por nível",
+            "Écart par palier",
+        ],
+    ),
+    (
+        "rates.not_buying",
+        [
+            "This server doesn't buy Robux.",
+            "Este servidor no compra Robux.",
+            "Este servidor não compra Robux.",
+            "Ce serveur n'achète pas de Robux.",
+        ],
+    ),
+    (
+        "rates.buy_title",
+        [
+            "Robux Buyback Quote",
+            "Cotización de compra de Robux",
+            "Cotação de compra de Robux",
+            "Devis de rachat de Robux",
+        ],
+    ),
+    (
+        "rates.buy_quote",
+        [
+            "We'd pay {gbp} / {usd} for {robux}.",
+            "Pagaríamos {gbp} / {usd} por {robux}.",
+            "Pagaríamos {gbp} / {usd} por {robux}.",
+            "Nous paierions {gbp} / {usd} pour {robux}.",
+        ],
+    ),
+    (
+        "rates.updated",
+        [
+            "Rates saved. We sell at {sell} and buy at {buy} per 1,000 Robux.",
+            "Tarifas guardadas. Vendemos a {sell} y compramos a {buy} por cada 1.000 Robux.",
+            "Taxas salvas. Vendemos a {sell} e compramos a {buy} por 1.000 Robux.",
+            "Tarifs enregistrés. Nous vendons à {sell} et achetons à {buy} les 1 000 Robux.",
+        ],
+    ),
+    // /order frozen rates
+    (
+        "order.frozen_rates",
+        [
+            "Priced at",
+            "Precio calculado con",
+            "Preço calculado com",
+            "Prix calculé avec",
+        ],
+    ),
+    (
+        "order.frozen_rates_value",
+        [
+            "{rate} / 1k R$ before fees\n£1 = {usd}\n{fee}% Roblox fee\n{vat}% VAT",
+            "{rate} / 1k R$ antes de comisiones\n£1 = {usd}\n{fee}% de comisión de Roblox\n{vat}% de IVA",
+            "{rate} / 1k R$ antes das taxas\n£1 = {usd}\n{fee}% de taxa do Roblox\n{vat}% de IVA",
+            "{rate} / 1k R$ avant frais\n£1 = {usd}\n{fee} % de frais Roblox\n{vat} % de TVA",
+        ],
+    ),
+    // /stale rate warnings
+    (
+        "rates.stale_title",
+        [
+            "⚠️ Rates may be out of date",
+            "⚠️ Las tasas pueden estar desactualizadas",
+            "⚠️ As taxas podem estar desatualizadas",
+            "⚠️ Les taux peuvent être obsolètes",
+        ],
+    ),
+    (
+        "rates.stale",
+        [
+            "Live exchange rates couldn't be fetched, so these prices use rates as of {time}.",
+            "No se pudieron obtener las tasas de cambio en vivo, así que estos precios usan las tasas del {time}.",
+            "Não foi possível obter as taxas de câmbio ao vivo, então estes preços usam as taxas de {time}.",
+            "Les taux de change en direct n'ont pas pu être récupérés : ces prix utilisent les taux du {time}.",
+        ],
+    ),
+    (
+        "rates.stale_default",
+        [
+            "Live exchange rates couldn't be fetched, so these prices use the default exchange rate.",
+            "No se pudieron obtener las tasas de cambio en vivo, así que estos precios usan la tasa de cambio predeterminada.",
+            "Não foi possível obter as taxas de câmbio ao vivo, então estes preços usam a taxa de câmbio padrão.",
+            "Les taux de change en direct n'ont pas pu être récupérés : ces prix utilisent le taux de change par défaut.",
+        ],
+    ),
+    // /setrate
+    (
+        "cmd.setrate.name",
+        [
+            "setrate",
+            "fijartasa",
+            "definirtaxa",
+            "definirtaux",
+        ],
+    ),
+    (
+        "cmd.setrate",
+        [
+            "Fix the exchange rate this server prices at",
+            "Fija el tipo de cambio con el que el servidor calcula precios",
+            "Fixe a taxa de câmbio usada nos preços do servidor",
+            "Fixez le taux de change utilisé pour les prix du serveur",
+        ],
+    ),
+    (
+        "opt.setrate.forex",
+        [
+            "Use a fixed exchange rate instead of the market rate",
+            "Usa un tipo de cambio fijo en lugar del de mercado",
+            "Use uma taxa de câmbio fixa em vez da de mercado",
+            "Utilisez un taux de change fixe au lieu du taux du marché",
+        ],
+    ),
+    (
+        "opt.setrate.pair",
+        [
+            "Currency pair the value is for",
+            "Par de divisas del valor",
+            "Par de moedas do valor",
+            "Paire de devises de la valeur",
+        ],
+    ),
+    (
+        "opt.setrate.value",
+        [
+            "Units of the second currency per first (0 to use the market rate)",
+            "Unidades de la segunda divisa por la primera (0 para usar el de mercado)",
+            "Unidades da segunda moeda por primeira (0 para usar a de mercado)",
+            "Unités de la seconde devise par la première (0 pour le taux du marché)",
+        ],
+    ),
+    (
+        "setrate.title",
+        [
+            "Fixed exchange rate",
+            "Tipo de cambio fijo",
+            "Taxa de câmbio fixa",
+            "Taux de change fixe",
+        ],
+    ),
+    (
+        "setrate.fixed",
+        [
+            "Prices now use {pair} {value} regardless of the market.",
+            "Los precios ahora usan {pair} {value} sin importar el mercado.",
+            "Os preços agora usam {pair} {value}, independentemente do mercado.",
+            "Les prix utilisent désormais {pair} {value}, quel que soit le marché.",
+        ],
+    ),
+    (
+        "setrate.market",
+        [
+            "Prices now use the market exchange rate again.",
+            "Los precios vuelven a usar el tipo de cambio de mercado.",
+            "Os preços voltam a usar a taxa de câmbio de mercado.",
+            "Les prix utilisent de nouveau le taux de change du marché.",
+        ],
+    ),
+    // /roleprice
+    (
+        "cmd.roleprice.name",
+        [
+            "roleprice",
+            "preciorol",
+            "precocargo",
+            "prixrole",
+        ],
+    ),
+    (
+        "cmd.roleprice",
+        [
+            "Give members with a role a discount, such as boosters or VIPs",
+            "Da un descuento a los miembros con un rol, como boosters o VIP",
+            "Dê um desconto a membros com um cargo, como boosters ou VIPs",
+            "Accordez une remise aux membres ayant un rôle, comme les boosters ou VIP",
+        ],
+    ),
+    (
+        "opt.roleprice.set",
+        [
+            "Add or update a role's discount",
+            "Añade o actualiza el descuento de un rol",
+            "Adiciona ou atualiza o desconto de um cargo",
+            "Ajoute ou modifie la remise d'un rôle",
+        ],
+    ),
+    (
+        "opt.roleprice.remove",
+        [
+            "Remove a role's discount",
+            "Elimina el descuento de un rol",
+            "Remove o desconto de um cargo",
+            "Supprime la remise d'un rôle",
+        ],
+    ),
+    (
+        "opt.roleprice.list",
+        [
+            "List role discounts",
+            "Lista los descuentos por rol",
+            "Lista os descontos por cargo",
+            "Liste les remises par rôle",
+        ],
+    ),
+    (
+        "opt.roleprice.role",
+        [
+            "Role the discount is for",
+            "Rol del descuento",
+            "Cargo do desconto",
+            "Rôle concerné par la remise",
+        ],
+    ),
+    (
+        "opt.roleprice.percent",
+        [
+            "Percent off every quote",
+            "Porcentaje de descuento en cada cotización",
+            "Porcentagem de desconto em cada cotação",
+            "Pourcentage de remise sur chaque devis",
+        ],
+    ),
+    (
+        "roleprice.title",
+        [
+            "Role Discounts",
+            "Descuentos por rol",
+            "Descontos por cargo",
+            "Remises par rôle",
+        ],
+    ),
+    (
+        "roleprice.saved",
+        [
+            "Role discount saved",
+            "Descuento por rol guardado",
+            "Desconto por cargo salvo",
+            "Remise par rôle enregistrée",
+        ],
+    ),
+    (
+        "roleprice.removed",
+        [
+            "Role discount removed",
+            "Descuento por rol eliminado",
+            "Desconto por cargo removido",
+            "Remise par rôle supprimée",
+        ],
+    ),
+    (
+        "roleprice.not_found",
+        [
+            "{role} has no discount.",
+            "{role} no tiene descuento.",
+            "{role} não tem desconto.",
+            "{role} n'a pas de remise.",
+        ],
+    ),
+    (
+        "roleprice.everyone",
+        [
+            "Everyone has @everyone, so lower your rates or tiers instead.",
+            "Todos tienen @everyone, así que baja tus tarifas o niveles.",
+            "Todos têm @everyone, então reduza suas taxas ou níveis.",
+            "Tout le monde a @everyone : baissez plutôt vos tarifs ou paliers.",
+        ],
+    ),
+    (
+        "roleprice.empty",
+        [
+            "No role discounts are set up.",
+            "No hay descuentos por rol configurados.",
+            "Nenhum desconto por cargo configurado.",
+            "Aucune remise par rôle n'est configurée.",
+        ],
+    ),
+    (
+        "roleprice.discount",
+        [
+            "Role Discount",
+            "Descuento por rol",
+            "Desconto por cargo",
+            "Remise de rôle",
+        ],
+    ),
+    (
+        "roleprice.percent_off",
+        [
+            "{percent}% off for {role}",
+            "{percent}% de descuento para {role}",
+            "{percent}% de desconto para {role}",
+            "{percent} % de remise pour {role}",
+        ],
+    ),
+    (
+        "roleprice.footer",
+        [
+            "Discounts don't stack: members get their biggest role or loyalty discount.",
+            "Los descuentos no se acumulan: los miembros reciben su mayor descuento por rol o fidelidad.",
+            "Os descontos não se acumulam: membros recebem o maior desconto por cargo ou fidelidade.",
+            "Les remises ne se cumulent pas : les membres ont leur plus grande remise de rôle ou de fidélité.",
+        ],
+    ),
+    // /template
+    (
+        "cmd.template.name",
+        [
+            "template",
+            "plantilla",
+            "modelo",
+            "modele",
+        ],
+    ),
+    (
+        "cmd.template",
+        [
+            "Reword the bot's replies to /price and /robux in your shop's own voice (admin only)",
+            "Cambia el texto de /price y /robux con el estilo de tu tienda (solo administradores)",
+            "Reescreva as respostas de /price e /robux com o estilo da sua loja (apenas administradores)",
+            "Reformulez /price et /robux avec le ton de votre boutique (administrateurs uniquement)",
+        ],
+    ),
+    (
+        "opt.template.set",
+        [
+            "Set the reply's title or description",
+            "Define el título o la descripción de la respuesta",
+            "Define o título ou a descrição da resposta",
+            "Définit le titre ou la description de la réponse",
+        ],
+    ),
+    (
+        "opt.template.field",
+        [
+            "Add a field to the reply",
+            "Añade un campo a la respuesta",
+            "Adiciona um campo à resposta",
+            "Ajoute un champ à la réponse",
+        ],
+    ),
+    (
+        "opt.template.reset",
+        [
+            "Go back to the bot's own wording",
+            "Vuelve al texto original del bot",
+            "Volta ao texto original do bot",
+            "Revient au texte d'origine du bot",
+        ],
+    ),
+    (
+        "opt.template.show",
+        [
+            "Show a command's template",
+            "Muestra la plantilla de un comando",
+            "Mostra o modelo de um comando",
+            "Affiche le modèle d'une commande",
+        ],
+    ),
+    (
+        "opt.template.command",
+        [
+            "Command whose reply to change",
+            "Comando cuya respuesta cambiar",
+            "Comando cuja resposta alterar",
+            "Commande dont la réponse change",
+        ],
+    ),
+    (
+        "opt.template.title",
+        [
+            "New title, e.g. {robux} for {gbp}",
+            "Nuevo título, p. ej. {robux} por {gbp}",
+            "Novo título, ex. {robux} por {gbp}",
+            "Nouveau titre, ex. {robux} pour {gbp}",
+        ],
+    ),
+    (
+        "opt.template.description",
+        [
+            "New description; {details} keeps the bot's own text",
+            "Nueva descripción; {details} conserva el texto del bot",
+            "Nova descrição; {details} mantém o texto do bot",
+            "Nouvelle description ; {details} garde le texte du bot",
+        ],
+    ),
+    (
+        "opt.template.field_name",
+        [
+            "Field name",
+            "Nombre del campo",
+            "Nome do campo",
+            "Nom du champ",
+        ],
+    ),
+    (
+        "opt.template.field_value",
+        [
+            "Field text",
+            "Texto del campo",
+            "Texto do campo",
+            "Texte du champ",
+        ],
+    ),
+    (
+        "template.title",
+        [
+            "Template",
+            "Plantilla",
+            "Modelo",
+            "Modèle",
+        ],
+    ),
+    (
+        "template.saved",
+        [
+            "Template saved",
+            "Plantilla guardada",
+            "Modelo salvo",
+            "Modèle enregistré",
+        ],
+    ),
+    (
+        "template.reset",
+        [
+            "Template removed",
+            "Plantilla eliminada",
+            "Modelo removido",
+            "Modèle supprimé",
+        ],
+    ),
+    (
+        "template.none",
+        [
+            "This command uses the bot's own wording.",
+            "Este comando usa el texto original del bot.",
+            "Este comando usa o texto original do bot.",
+            "Cette commande utilise le texte d'origine du bot.",
+        ],
+    ),
+    (
+        "template.not_found",
+        [
+            "/{command} has no template.",
+            "/{command} no tiene plantilla.",
+            "/{command} não tem modelo.",
+            "/{command} n'a pas de modèle.",
+        ],
+    ),
+    (
+        "template.nothing_to_set",
+        [
+            "Give a title, a description or both.",
+            "Indica un título, una descripción o ambos.",
+            "Informe um título, uma descrição ou ambos.",
+            "Indiquez un titre, une description ou les deux.",
+        ],
+    ),
+    (
+        "template.too_long",
+        [
+            "Template text can be at most {max} characters.",
+            "El texto de la plantilla puede tener como máximo {max} caracteres.",
+            "O texto do modelo pode ter no máximo {max} caracteres.",
+            "Le texte du modèle peut contenir au plus {max} caractères.",
+        ],
+    ),
+    (
+        "template.too_many_fields",
+        [
+            "A template can add at most {max} fields.",
+            "Una plantilla puede añadir como máximo {max} campos.",
+            "Um modelo pode adicionar no máximo {max} campos.",
+            "Un modèle peut ajouter au plus {max} champs.",
+        ],
+    ),
+    (
+        "template.unknown_variable",
+        [
+            "`{{variable}}` isn't a placeholder. Use {variables}.",
+            "`{{variable}}` no es un marcador. Usa {variables}.",
+            "`{{variable}}` não é um marcador. Use {variables}.",
+            "`{{variable}}` n'est pas un marqueur. Utilisez {variables}.",
+        ],
+    ),
+    (
+        "template.title_label",
+        [
+            "Title",
+            "Título",
+            "Título",
+            "Titre",
+        ],
+    ),
+    (
+        "template.description_label",
+        [
+            "Description",
+            "Descripción",
+            "Descrição",
+            "Description",
+        ],
+    ),
+    (
+        "template.unset",
+        [
+            "The bot's own",
+            "El del bot",
+            "O do bot",
+            "Celle du bot",
+        ],
+    ),
+    (
+        "template.footer",
+        [
+            "Placeholders: {variables}. Only single quotes from /price use templates.",
+            "Marcadores: {variables}. Solo las cotizaciones simples de /price usan plantillas.",
+            "Marcadores: {variables}. Só cotações simples do /price usam modelos.",
+            "Marqueurs : {variables}. Seuls les devis simples de /price utilisent les modèles.",
+        ],
+    ),
+    // /emojis
+    (
+        "cmd.emojis.name",
+        [
+            "emojis",
+            "emojis",
+            "emojis",
+            "emojis",
+        ],
+    ),
+    (
+        "cmd.emojis",
+        [
+            "Show your server's custom emojis instead of R$, £, $ and checkmarks (admin only)",
+            "Usa los emojis de tu servidor en lugar de R$, £, $ y marcas (solo administradores)",
+            "Use os emojis do seu servidor no lugar de R$, £, $ e marcas (apenas administradores)",
+            "Utilisez les emojis du serveur au lieu de R$, £, $ et des coches (administrateurs uniquement)",
+        ],
+    ),
+    (
+        "opt.emojis.set",
+        [
+            "Replace a symbol with an emoji",
+            "Reemplaza un símbolo por un emoji",
+            "Substitui um símbolo por um emoji",
+            "Remplace un symbole par un emoji",
+        ],
+    ),
+    (
+        "opt.emojis.reset",
+        [
+            "Go back to the plain symbol",
+            "Vuelve al símbolo normal",
+            "Volta ao símbolo normal",
+            "Revient au symbole normal",
+        ],
+    ),
+    (
+        "opt.emojis.list",
+        [
+            "Show the emojis in use",
+            "Muestra los emojis en uso",
+            "Mostra os emojis em uso",
+            "Affiche les emojis utilisés",
+        ],
+    ),
+    (
+        "opt.emojis.kind",
+        [
+            "Symbol to replace",
+            "Símbolo a reemplazar",
+            "Símbolo a substituir",
+            "Symbole à remplacer",
+        ],
+    ),
+    (
+        "opt.emojis.emoji",
+        [
+            "A custom emoji from this server or a unicode emoji",
+            "Un emoji personalizado de este servidor o un emoji unicode",
+            "Um emoji personalizado deste servidor ou um emoji unicode",
+            "Un emoji personnalisé de ce serveur ou un emoji unicode",
+        ],
+    ),
+    (
+        "emojis.title",
+        [
+            "Emojis",
+            "Emojis",
+            "Emojis",
+            "Emojis",
+        ],
+    ),
+    (
+        "emojis.saved",
+        [
+            "Emoji saved",
+            "Emoji guardado",
+            "Emoji salvo",
+            "Emoji enregistré",
+        ],
+    ),
+    (
+        "emojis.reset",
+        [
+            "Emoji removed",
+            "Emoji eliminado",
+            "Emoji removido",
+            "Emoji supprimé",
+        ],
+    ),
+    (
+        "emojis.invalid",
+        [
+            "That isn't an emoji. Pick one from the emoji menu, e.g. a custom emoji from this server.",
+            "Eso no es un emoji. Elige uno del menú de emojis, p. ej. un emoji personalizado de este servidor.",
+            "Isso não é um emoji. Escolha um no menu de emojis, ex. um emoji personalizado deste servidor.",
+            "Ce n'est pas un emoji. Choisissez-en un dans le menu des emojis, ex. un emoji personnalisé de ce serveur.",
+        ],
+    ),
+    (
+        "emojis.footer",
+        [
+            "The bot can only show custom emojis from servers it's in. Images and receipts keep the plain symbols.",
+            "El bot solo puede mostrar emojis personalizados de servidores en los que está. Las imágenes y recibos mantienen los símbolos normales.",
+            "O bot só pode mostrar emojis personalizados de servidores em que está. Imagens e recibos mantêm os símbolos normais.",
+            "Le bot ne peut afficher que les emojis personnalisés des serveurs où il se trouve. Les images et reçus gardent les symboles normaux.",
+        ],
+    ),
+    (
+        "emojis.kind.robux",
+        [
+            "Robux",
+            "Robux",
+            "Robux",
+            "Robux",
+        ],
+    ),
+    (
+        "emojis.kind.gbp",
+        [
+            "Pound",
+            "Libra",
+            "Libra",
+            "Livre",
+        ],
+    ),
+    (
+        "emojis.kind.usd",
+        [
+            "Dollar",
+            "Dólar",
+            "Dólar",
+            "Dollar",
+        ],
+    ),
+    (
+        "emojis.kind.check",
+        [
+            "Checkmark",
+            "Marca de verificación",
+            "Marca de verificação",
+            "Coche",
+        ],
+    ),
+    (
+        "emojis.kind.cross",
+        [
+            "Cross",
+            "Cruz",
+            "Cruz",
+            "Croix",
+        ],
+    ),
+    // /auditlog
+    (
+        "cmd.auditlog.name",
+        [
+            "auditlog",
+            "auditlog",
+            "auditlog",
+            "auditlog",
+        ],
+    ),
+    (
+        "cmd.auditlog",
+        [
+            "See who changed rates, settings, the blacklist and orders (admin only)",
+            "Mira quién cambió tarifas, ajustes, la lista negra y pedidos (solo administradores)",
+            "Veja quem alterou taxas, configurações, a lista negra e pedidos (apenas administradores)",
+            "Voyez qui a modifié les taux, réglages, la liste noire et les commandes (admins uniquement)",
+        ],
+    ),
+    (
+        "opt.auditlog.show",
+        [
+            "List the latest changes",
+            "Muestra los últimos cambios",
+            "Lista as últimas alterações",
+            "Liste les dernières modifications",
+        ],
+    ),
+    (
+        "opt.auditlog.user",
+        [
+            "Only show this member's changes",
+            "Solo los cambios de este miembro",
+            "Só as alterações deste membro",
+            "Uniquement les modifications de ce membre",
+        ],
+    ),
+    (
+        "opt.auditlog.count",
+        [
+            "How many changes to show (default 10)",
+            "Cuántos cambios mostrar (10 por defecto)",
+            "Quantas alterações mostrar (padrão 10)",
+            "Nombre de modifications à afficher (10 par défaut)",
+        ],
+    ),
+    (
+        "opt.auditlog.mirror",
+        [
+            "Also post every change to a channel",
+            "Publica también cada cambio en un canal",
+            "Publica também cada alteração em um canal",
+            "Publie aussi chaque modification dans un salon",
+        ],
+    ),
+    (
+        "opt.auditlog.channel",
+        [
+            "Channel to post changes in; leave empty to stop",
+            "Canal donde publicar los cambios; déjalo vacío para parar",
+            "Canal onde publicar as alterações; deixe vazio para parar",
+            "Salon où publier les modifications ; laissez vide pour arrêter",
+        ],
+    ),
+    (
+        "auditlog.title",
+        [
+            "Audit Log",
+            "Registro de auditoría",
+            "Registro de auditoria",
+            "Journal d'audit",
+        ],
+    ),
+    (
+        "auditlog.empty",
+        [
+            "No changes have been recorded yet.",
+            "Aún no se ha registrado ningún cambio.",
+            "Nenhuma alteração foi registrada ainda.",
+            "Aucune modification n'a encore été enregistrée.",
+        ],
+    ),
+    (
+        "auditlog.mirroring",
+        [
+            "Changes will also be posted in {channel}.",
+            "Los cambios también se publicarán en {channel}.",
+            "As alterações também serão publicadas em {channel}.",
+            "Les modifications seront aussi publiées dans {channel}.",
+        ],
+    ),
+    (
+        "auditlog.mirror_off",
+        [
+            "Changes will no longer be posted to a channel.",
+            "Los cambios ya no se publicarán en un canal.",
+            "As alterações não serão mais publicadas em um canal.",
+            "Les modifications ne seront plus publiées dans un salon.",
+        ],
+    ),
+    // Deleting with an undo button
+    (
+        "order.deleted",
+        [
+            "Order #{id} Deleted",
+            "Pedido #{id} eliminado",
+            "Pedido #{id} excluído",
+            "Commande #{id} supprimée",
+        ],
+    ),
+    (
+        "order.not_found",
+        [
+            "There's no order #{id} in this server.",
+            "No hay ningún pedido #{id} en este servidor.",
+            "Não há nenhum pedido #{id} neste servidor.",
+            "Il n'y a pas de commande #{id} sur ce serveur.",
+        ],
+    ),
+    (
+        "undo.hint",
+        [
+            "Deleted by mistake? You can undo this until <t:{at}:t>.",
+            "¿Lo eliminaste por error? Puedes deshacerlo hasta las <t:{at}:t>.",
+            "Excluiu por engano? Você pode desfazer até <t:{at}:t>.",
+            "Supprimé par erreur ? Vous pouvez annuler jusqu'à <t:{at}:t>.",
+        ],
+    ),
+    (
+        "undo.button",
+        [
+            "Undo",
+            "Deshacer",
+            "Desfazer",
+            "Annuler",
+        ],
+    ),
+    (
+        "undo.restored",
+        [
+            "Restored",
+            "Restaurado",
+            "Restaurado",
+            "Restauré",
+        ],
+    ),
+    (
+        "undo.restored_tier",
+        [
+            "The tier starting at {min} R$ is back.",
+            "El nivel que empieza en {min} R$ ha vuelto.",
+            "A faixa que começa em {min} R$ está de volta.",
+            "Le palier à partir de {min} R$ est de retour.",
+        ],
+    ),
+    (
+        "undo.restored_coupon",
+        [
+            "Coupon `{code}` is back.",
+            "El cupón `{code}` ha vuelto.",
+            "O cupom `{code}` está de volta.",
+            "Le coupon `{code}` est de retour.",
+        ],
+    ),
+    (
+        "undo.restored_order",
+        [
+            "Order #{id} is back.",
+            "El pedido #{id} ha vuelto.",
+            "O pedido #{id} está de volta.",
+            "La commande #{id} est de retour.",
+        ],
+    ),
+    (
+        "undo.expired",
+        [
+            "This can no longer be undone.",
+            "Esto ya no se puede deshacer.",
+            "Isso não pode mais ser desfeito.",
+            "Cela ne peut plus être annulé.",
+        ],
+    ),
+    // /backup
+    (
+        "cmd.backup.name",
+        [
+            "backup",
+            "copiadeseguridad",
+            "backup",
+            "sauvegarde",
+        ],
+    ),
+    (
+        "cmd.backup",
+        [
+            "Send yourself a copy of the bot's database (bot owner only)",
+            "Envíate una copia de la base de datos del bot (solo el dueño del bot)",
+            "Envie a si mesmo uma cópia do banco de dados do bot (apenas o dono do bot)",
+            "Recevez une copie de la base de données du bot (propriétaire du bot uniquement)",
+        ],
+    ),
+    (
+        "backup.owner_only",
+        [
+            "Only the bot owner can back up the database.",
+            "Solo el dueño del bot puede hacer copias de la base de datos.",
+            "Só o dono do bot pode fazer backup do banco de dados.",
+            "Seul le propriétaire du bot peut sauvegarder la base de données.",
+        ],
+    ),
+    (
+        "backup.title",
+        [
+            "Database Backup",
+            "Copia de seguridad",
+            "Backup do banco de dados",
+            "Sauvegarde de la base de données",
+        ],
+    ),
+    (
+        "backup.size",
+        [
+            "{kb} KB",
+            "{kb} KB",
+            "{kb} KB",
+            "{kb} Ko",
+        ],
+    ),
+    (
+        "backup.message",
+        [
+            "Database backup from <t:{at}:f>. The README explains how to restore it.",
+            "Copia de la base de datos del <t:{at}:f>. El README explica cómo restaurarla.",
+            "Backup do banco de dados de <t:{at}:f>. O README explica como restaurá-lo.",
+            "Sauvegarde de la base de données du <t:{at}:f>. Le README explique comment la restaurer.",
+        ],
+    ),
+    (
+        "backup.sent",
+        [
+            "Sent `{file}` ({size}) to {channel}.",
+            "Se envió `{file}` ({size}) a {channel}.",
+            "`{file}` ({size}) foi enviado para {channel}.",
+            "`{file}` ({size}) a été envoyé dans {channel}.",
+        ],
+    ),
+    (
+        "backup.too_large",
+        [
+            "The backup is {size}, too large to upload to Discord. Copy the database from the server instead.",
+            "La copia ocupa {size}, demasiado para subirla a Discord. Copia la base de datos desde el servidor.",
+            "O backup tem {size}, grande demais para enviar ao Discord. Copie o banco de dados direto do servidor.",
+            "La sauvegarde fait {size}, trop pour être envoyée sur Discord. Copiez la base de données depuis le serveur.",
+        ],
+    ),
+    (
+        "backup.send_failed",
+        [
+            "I couldn't send the backup. Check that your DMs are open, or that I can post files in the backup channel.",
+            "No pude enviar la copia. Comprueba que tus MD estén abiertos o que pueda enviar archivos en el canal de copias.",
+            "Não consegui enviar o backup. Verifique se suas DMs estão abertas ou se posso enviar arquivos no canal de backups.",
+            "Je n'ai pas pu envoyer la sauvegarde. Vérifiez que vos MP sont ouverts ou que je peux envoyer des fichiers dans le salon des sauvegardes.",
+        ],
+    ),
+    // /retention
+    (
+        "cmd.retention.name",
+        [
+            "retention",
+            "retencion",
+            "retencao",
+            "conservation",
+        ],
+    ),
+    (
+        "cmd.retention",
+        [
+            "Choose how long calculations and closed orders are kept (admin only)",
+            "Elige cuánto tiempo se guardan los cálculos y pedidos cerrados (solo administradores)",
+            "Escolha por quanto tempo cálculos e pedidos fechados são guardados (apenas administradores)",
+            "Choisissez combien de temps les calculs et commandes fermées sont conservés (admins uniquement)",
+        ],
+    ),
+    (
+        "opt.retention.history_days",
+        [
+            "Days to keep calculations made here (leave out to keep them)",
+            "Días que se guardan los cálculos hechos aquí (déjalo vacío para guardarlos)",
+            "Dias para guardar os cálculos feitos aqui (deixe vazio para mantê-los)",
+            "Jours de conservation des calculs faits ici (laissez vide pour les garder)",
+        ],
+    ),
+    (
+        "opt.retention.order_days",
+        [
+            "Days to keep orders after their ticket closes (leave out to keep them)",
+            "Días que se guardan los pedidos tras cerrar su ticket (déjalo vacío para guardarlos)",
+            "Dias para guardar os pedidos após fechar o ticket (deixe vazio para mantê-los)",
+            "Jours de conservation des commandes après la fermeture du ticket (laissez vide pour les garder)",
+        ],
+    ),
+    (
+        "retention.title",
+        [
+            "Data Retention",
+            "Retención de datos",
+            "Retenção de dados",
+            "Conservation des données",
+        ],
+    ),
+    (
+        "retention.set",
+        [
+            "**Calculations:** {history}\n**Closed orders:** {orders}\nOlder records are deleted within the hour.",
+            "**Cálculos:** {history}\n**Pedidos cerrados:** {orders}\nLos registros más antiguos se borran en menos de una hora.",
+            "**Cálculos:** {history}\n**Pedidos fechados:** {orders}\nRegistros mais antigos são apagados em até uma hora.",
+            "**Calculs :** {history}\n**Commandes fermées :** {orders}\nLes données plus anciennes sont supprimées dans l'heure.",
+        ],
+    ),
+    (
+        "retention.days",
+        [
+            "deleted after {days} days",
+            "se borran tras {days} días",
+            "apagados após {days} dias",
+            "supprimées après {days} jours",
+        ],
+    ),
+    (
+        "retention.kept",
+        [
+            "kept",
+            "se guardan",
+            "guardados",
+            "conservées",
+        ],
+    ),
+    (
+        "retention.cleared",
+        [
+            "Calculations and closed orders are kept until members delete their own with /forgetme.",
+            "Los cálculos y pedidos cerrados se guardan hasta que los miembros borren los suyos con /forgetme.",
+            "Cálculos e pedidos fechados são guardados até os membros apagarem os seus com /forgetme.",
+            "Les calculs et commandes fermées sont conservés jusqu'à ce que les membres suppriment les leurs avec /forgetme.",
+        ],
+    ),
+    // /forgetme
+    (
+        "cmd.forgetme.name",
+        [
+            "forgetme",
+            "olvidame",
+            "esquecerme",
+            "oubliezmoi",
+        ],
+    ),
+    (
+        "cmd.forgetme",
+        [
+            "Delete your calculation history, preferences and Roblox link",
+            "Borra tu historial de cálculos, preferencias y cuenta de Roblox vinculada",
+            "Apague seu histórico de cálculos, preferências e conta Roblox vinculada",
+            "Supprimez votre historique de calculs, vos préférences et votre compte Roblox lié",
+        ],
+    ),
+    (
+        "forgetme.title",
+        [
+            "Your Data Was Deleted",
+            "Tus datos se borraron",
+            "Seus dados foram apagados",
+            "Vos données ont été supprimées",
+        ],
+    ),
+    (
+        "forgetme.calculations",
+        [
+            "{count} calculations",
+            "{count} cálculos",
+            "{count} cálculos",
+            "{count} calculs",
+        ],
+    ),
+    (
+        "forgetme.settings",
+        [
+            "Your preferences",
+            "Tus preferencias",
+            "Suas preferências",
+            "Vos préférences",
+        ],
+    ),
+    (
+        "forgetme.roblox_link",
+        [
+            "Your Roblox link",
+            "Tu cuenta de Roblox vinculada",
+            "Sua conta Roblox vinculada",
+            "Votre compte Roblox lié",
+        ],
+    ),
+    (
+        "forgetme.kept",
+        [
+            "Orders and vouches stay with the servers you bought from, as their record of the sale.",
+            "Los pedidos y valoraciones quedan en los servidores donde compraste, como registro de la venta.",
+            "Pedidos e avaliações ficam nos servidores onde você comprou, como registro da venda.",
+            "Les commandes et avis restent sur les serveurs où vous avez acheté, comme trace de la vente.",
+        ],
+    ),
+    (
+        "forgetme.nothing",
+        [
+            "I had nothing stored about you.",
+            "No tenía nada guardado sobre ti.",
+            "Eu não tinha nada guardado sobre você.",
+            "Je n'avais rien d'enregistré sur vous.",
+        ],
+    ),
+    // /worth
+    (
+        "cmd.worth.name",
+        [
+            "worth",
+            "cuantovale",
+            "quantovale",
+            "combienvaut",
+        ],
+    ),
+    (
+        "cmd.worth",
+        [
+            "Put an amount of Robux in context: prices, hours of work and DevEx value",
+            "Pon una cantidad de Robux en contexto: precios, horas de trabajo y valor DevEx",
+            "Coloque uma quantia de Robux em contexto: preços, horas de trabalho e valor DevEx",
+            "Mettez une somme de Robux en perspective : prix, heures de travail et valeur DevEx",
+        ],
+    ),
+    (
+        "opt.worth.robux",
+        [
+            "Amount of Robux, e.g. 1000 or 10k",
+            "Cantidad de Robux, p. ej. 1000 o 10k",
+            "Quantidade de Robux, ex. 1000 ou 10k",
+            "Nombre de Robux, par ex. 1000 ou 10k",
+        ],
+    ),
+    (
+        "feature.worth",
+        [
+            "Worth",
+            "Valor",
+            "Valor",
+            "Valeur",
+        ],
+    ),
+    (
+        "worth.title",
+        [
+            "What {robux} Is Worth",
+            "Lo que valen {robux}",
+            "Quanto valem {robux}",
+            "Ce que valent {robux}",
+        ],
+    ),
+    (
+        "worth.here",
+        [
+            "At This Server's Rate",
+            "A la tarifa de este servidor",
+            "Na taxa deste servidor",
+            "Au tarif de ce serveur",
+        ],
+    ),
+    (
+        "worth.official",
+        [
+            "From Roblox",
+            "En Roblox",
+            "Na Roblox",
+            "Chez Roblox",
+        ],
+    ),
+    (
+        "worth.wage",
+        [
+            "UK Minimum Wage",
+            "Salario mínimo del Reino Unido",
+            "Salário mínimo do Reino Unido",
+            "Salaire minimum britannique",
+        ],
+    ),
+    (
+        "worth.wage_value",
+        [
+            "**{time}** of work at {wage}/h pays for it here",
+            "**{time}** de trabajo a {wage}/h lo pagan aquí",
+            "**{time}** de trabalho a {wage}/h pagam por isso aqui",
+            "**{time}** de travail à {wage}/h le paient ici",
+        ],
+    ),
+    (
+        "worth.hours",
+        [
+            "{hours} hours",
+            "{hours} horas",
+            "{hours} horas",
+            "{hours} heures",
+        ],
+    ),
+    (
+        "worth.minutes",
+        [
+            "{minutes} minutes",
+            "{minutes} minutos",
+            "{minutes} minutos",
+            "{minutes} minutes",
+        ],
+    ),
+    (
+        "worth.devex",
+        [
+            "DevEx Value",
+            "Valor DevEx",
+            "Valor DevEx",
+            "Valeur DevEx",
+        ],
+    ),
+    (
+        "worth.devex_minimum",
+        [
+            "DevEx needs at least {robux} earned",
+            "DevEx requiere al menos {robux} ganados",
+            "O DevEx exige pelo menos {robux} ganhos",
+            "DevEx exige au moins {robux} gagnés",
+        ],
+    ),
+    (
+        "worth.footer",
+        [
+            "Prices are before delivery fees. DevEx only pays out Robux earned from experiences.",
+            "Precios antes de comisiones de entrega. DevEx solo paga Robux ganados con experiencias.",
+            "Preços antes das taxas de entrega. O DevEx só paga Robux ganhos com experiências.",
+            "Prix avant frais de livraison. DevEx ne rachète que les Robux gagnés avec des expériences.",
+        ],
+    ),
+    // /fees
+    (
+        "cmd.fees.name",
+        [
+            "fees",
+            "comisiones",
+            "custos",
+            "frais",
+        ],
+    ),
+    (
+        "cmd.fees",
+        [
+            "See what each payment and delivery method costs, with example totals",
+            "Mira lo que cuesta cada método de pago y de entrega, con totales de ejemplo",
+            "Veja quanto custa cada método de pagamento e de entrega, com totais de exemplo",
+            "Voyez ce que coûte chaque moyen de paiement et de livraison, avec des totaux d'exemple",
+        ],
+    ),
+    (
+        "opt.fees.robux",
+        [
+            "Robux to work out example totals for (default 1000)",
+            "Robux para los totales de ejemplo (1000 por defecto)",
+            "Robux para os totais de exemplo (padrão 1000)",
+            "Robux pour les totaux d'exemple (1000 par défaut)",
+        ],
+    ),
+    (
+        "fees.title",
+        [
+            "Fees",
+            "Comisiones",
+            "Taxas",
+            "Frais",
+        ],
+    ),
+    (
+        "fees.description",
+        [
+            "Examples for {robux}, which is {price} at this server's rate before any fees.",
+            "Ejemplos para {robux}, que cuestan {price} a la tarifa de este servidor antes de comisiones.",
+            "Exemplos para {robux}, que custam {price} na taxa deste servidor antes das taxas.",
+            "Exemples pour {robux}, soit {price} au tarif de ce serveur avant frais.",
+        ],
+    ),
+    (
+        "fees.payments",
+        [
+            "Payment Methods",
+            "Métodos de pago",
+            "Métodos de pagamento",
+            "Moyens de paiement",
+        ],
+    ),
+    (
+        "fees.payment_line",
+        [
+            "**{method}**: {formula} · {total} leaves the seller the full price",
+            "**{method}**: {formula} · {total} deja al vendedor el precio completo",
+            "**{method}**: {formula} · {total} deixa ao vendedor o preço inteiro",
+            "**{method}** : {formula} · {total} laisse le prix entier au vendeur",
+        ],
+    ),
+    (
+        "fees.formula",
+        [
+            "{percent}% + {fixed}",
+            "{percent}% + {fixed}",
+            "{percent}% + {fixed}",
+            "{percent} % + {fixed}",
+        ],
+    ),
+    (
+        "fees.formula_percent",
+        [
+            "{percent}%",
+            "{percent}%",
+            "{percent}%",
+            "{percent} %",
+        ],
+    ),
+    (
+        "fees.no_fee",
+        [
+            "no fee",
+            "sin comisión",
+            "sem taxa",
+            "sans frais",
+        ],
+    ),
+    (
+        "fees.delivery",
+        [
+            "Delivery Methods",
+            "Métodos de entrega",
+            "Métodos de entrega",
+            "Moyens de livraison",
+        ],
+    ),
+    (
+        "fees.delivery_line",
+        [
+            "**{method}**: Roblox keeps {percent}%, so list {listing} · **{total}** after tax",
+            "**{method}**: Roblox se queda el {percent}%, así que publica {listing} · **{total}** después de impuestos",
+            "**{method}**: a Roblox fica com {percent}%, então anuncie {listing} · **{total}** após a taxa",
+            "**{method}** : Roblox garde {percent} %, donc mettez {listing} · **{total}** après taxe",
+        ],
+    ),
+    (
+        "fees.vat",
+        [
+            "VAT",
+            "IVA",
+            "IVA",
+            "TVA",
+        ],
+    ),
+    (
+        "fees.vat_line",
+        [
+            "{rate}% ({region}) is added to quotes: {tax} on the example",
+            "Se añade un {rate}% ({region}) a las cotizaciones: {tax} en el ejemplo",
+            "{rate}% ({region}) é somado às cotações: {tax} no exemplo",
+            "{rate} % ({region}) s'ajoute aux devis : {tax} sur l'exemple",
+        ],
+    ),
+    (
+        "fees.footer",
+        [
+            "Payment processors keep a cut of each payment, which is why some methods cost more.",
+            "Los procesadores de pago se quedan parte de cada pago, por eso algunos métodos cuestan más.",
+            "Os processadores de pagamento ficam com parte de cada pagamento, por isso alguns métodos custam mais.",
+            "Les services de paiement prélèvent une part de chaque paiement, d'où le coût plus élevé de certains moyens.",
+        ],
+    ),
+    // /quote
+    (
+        "cmd.quote.name",
+        [
+            "quote",
+            "cotizacion",
+            "orcamento",
+            "devis",
+        ],
+    ),
+    (
+        "cmd.quote",
+        [
+            "Make a formal quotation document",
+            "Genera un documento de cotización formal",
+            "Gera um documento de orçamento formal",
+            "Établissez un devis formel",
+        ],
+    ),
+    (
+        "opt.quote.bulk",
+        [
+            "Quote several amounts of Robux at once, e.g. for a group or clan",
+            "Cotiza varias cantidades de Robux a la vez, p. ej. para un grupo o clan",
+            "Orce várias quantidades de Robux de uma vez, ex. para um grupo ou clã",
+            "Chiffrez plusieurs quantités de Robux à la fois, par ex. pour un groupe ou un clan",
+        ],
+    ),
+    (
+        "opt.quote.valid_days",
+        [
+            "How many days the prices hold (7 if not set)",
+            "Cuántos días se mantienen los precios (7 si no se indica)",
+            "Por quantos dias os preços valem (7 se não informado)",
+            "Pendant combien de jours les prix tiennent (7 par défaut)",
+        ],
+    ),
+    (
+        "quote.title",
+        [
+            "Quotation {id}",
+            "Cotización {id}",
+            "Orçamento {id}",
+            "Devis {id}",
+        ],
+    ),
+    (
+        "quote.description",
+        [
+            "Quotation for {count} amounts totalling {robux}: **{gbp}** / {usd}. Prices hold until {until}; the attached PDF has the full breakdown and payment terms.",
+            "Cotización de {count} cantidades que suman {robux}: **{gbp}** / {usd}. Los precios se mantienen hasta {until}; el PDF adjunto tiene el desglose completo y las condiciones de pago.",
+            "Orçamento de {count} quantidades totalizando {robux}: **{gbp}** / {usd}. Os preços valem até {until}; o PDF anexo tem o detalhamento completo e as condições de pagamento.",
+            "Devis pour {count} quantités totalisant {robux} : **{gbp}** / {usd}. Les prix tiennent jusqu'au {until} ; le PDF joint contient le détail complet et les conditions de paiement.",
+        ],
+    ),
+    (
+        "quote.id",
+        [
+            "Quotation",
+            "Cotización",
+            "Orçamento",
+            "Devis",
+        ],
+    ),
+    (
+        "quote.valid_until",
+        [
+            "Valid until",
+            "Válida hasta",
+            "Válido até",
+            "Valable jusqu'au",
+        ],
+    ),
+    (
+        "quote.prepared_for",
+        [
+            "Prepared for",
+            "Preparada para",
+            "Preparado para",
+            "Établi pour",
+        ],
+    ),
+    (
+        "quote.rate_per_1k",
+        [
+            "Per 1,000 R$",
+            "Por 1.000 R$",
+            "Por 1.000 R$",
+            "Pour 1 000 R$",
+        ],
+    ),
+    (
+        "quote.line_total",
+        [
+            "Price",
+            "Precio",
+            "Preço",
+            "Prix",
+        ],
+    ),
+    (
+        "quote.total_robux",
+        [
+            "Total Robux",
+            "Total de Robux",
+            "Total de Robux",
+            "Total de Robux",
+        ],
+    ),
+    (
+        "quote.subtotal",
+        [
+            "Subtotal",
+            "Subtotal",
+            "Subtotal",
+            "Sous-total",
+        ],
+    ),
+    (
+        "quote.vat_amount",
+        [
+            "VAT amount",
+            "Importe del IVA",
+            "Valor do IVA",
+            "Montant de la TVA",
+        ],
+    ),
+    (
+        "quote.total",
+        [
+            "Total due",
+            "Total a pagar",
+            "Total a pagar",
+            "Total dû",
+        ],
+    ),
+    (
+        "quote.terms",
+        [
+            "Payment terms",
+            "Condiciones de pago",
+            "Condições de pagamento",
+            "Conditions de paiement",
+        ],
+    ),
+    (
+        "quote.terms.validity",
+        [
+            "- Prices hold until {date}. After that, ask for a new quotation.",
+            "- Los precios se mantienen hasta {date}. Después, pide una nueva cotización.",
+            "- Os preços valem até {date}. Depois disso, peça um novo orçamento.",
+            "- Les prix tiennent jusqu'au {date}. Ensuite, demandez un nouveau devis.",
+        ],
+    ),
+    (
+        "quote.terms.payment",
+        [
+            "- Payment in full is due before delivery, by {methods}.",
+            "- El pago completo se realiza antes de la entrega, por {methods}.",
+            "- O pagamento integral é feito antes da entrega, por {methods}.",
+            "- Le paiement intégral est dû avant la livraison, par {methods}.",
+        ],
+    ),
+    (
+        "quote.terms.any_method",
+        [
+            "a payment method agreed with staff",
+            "un método de pago acordado con el staff",
+            "um método de pagamento combinado com a equipe",
+            "un moyen de paiement convenu avec l'équipe",
+        ],
+    ),
+    (
+        "quote.terms.delivery",
+        [
+            "- Robux are delivered by {method} once payment has cleared.",
+            "- Los Robux se entregan por {method} una vez confirmado el pago.",
+            "- Os Robux são entregues por {method} assim que o pagamento for confirmado.",
+            "- Les Robux sont livrés par {method} une fois le paiement encaissé.",
+        ],
+    ),
+    (
+        "quote.footer",
+        [
+            "This quotation is not an invoice. Quote {id} when you order.",
+            "Esta cotización no es una factura. Indica {id} al hacer el pedido.",
+            "Este orçamento não é uma fatura. Informe {id} ao fazer o pedido.",
+            "Ce devis n'est pas une facture. Indiquez {id} lors de la commande.",
+        ],
+    ),
+    // /ratehistory
+    (
+        "cmd.ratehistory.name",
+        [
+            "ratehistory",
+            "historialtarifas",
+            "historicotaxas",
+            "historiquetarifs",
+        ],
+    ),
+    (
+        "cmd.ratehistory",
+        [
+            "See when this server changed its rates",
+            "Mira cuándo cambió el servidor sus tarifas",
+            "Veja quando o servidor mudou suas taxas",
+            "Voyez quand le serveur a changé ses tarifs",
+        ],
+    ),
+    (
+        "ratehistory.title",
+        [
+            "Rate History",
+            "Historial de tarifas",
+            "Histórico de taxas",
+            "Historique des tarifs",
+        ],
+    ),
+    (
+        "ratehistory.empty",
+        [
+            "No rate changes have been recorded yet.",
+            "Todavía no se ha registrado ningún cambio de tarifa.",
+            "Nenhuma mudança de taxa foi registrada ainda.",
+            "Aucun changement de tarif n'a encore été enregistré.",
+        ],
+    ),
+    (
+        "ratehistory.line",
+        [
+            "{when} · **{rate}**: {previous} → {value} by {user}",
+            "{when} · **{rate}**: {previous} → {value} por {user}",
+            "{when} · **{rate}**: {previous} → {value} por {user}",
+            "{when} · **{rate}** : {previous} → {value} par {user}",
+        ],
+    ),
+    (
+        "ratehistory.sell",
+        [
+            "Sell rate per 1k",
+            "Tarifa de venta por 1k",
+            "Taxa de venda por 1k",
+            "Tarif de vente pour 1k",
+        ],
+    ),
+    (
+        "ratehistory.buy",
+        [
+            "Buy rate per 1k",
+            "Tarifa de compra por 1k",
+            "Taxa de compra por 1k",
+            "Tarif d'achat pour 1k",
+        ],
+    ),
+    (
+        "ratehistory.forex",
+        [
+            "Exchange rate",
+            "Tipo de cambio",
+            "Taxa de câmbio",
+            "Taux de change",
+        ],
+    ),
+    (
+        "ratehistory.not_buying",
+        [
+            "not buying",
+            "sin comprar",
+            "sem comprar",
+            "pas d'achat",
+        ],
+    ),
+    (
+        "ratehistory.market",
+        [
+            "market rate",
+            "tipo de mercado",
+            "taxa de mercado",
+            "taux du marché",
+        ],
+    ),
+    (
+        "ratehistory.set_at",
+        [
+            "set {when}",
+            "fijada {when}",
+            "definida {when}",
+            "fixé {when}",
+        ],
+    ),
+];
@@ -0,0 +1,189 @@
+use serde::Deserialize;
+use serenity::model::id::GuildId;
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    alerts::Alert, history::Calculation, i18n::Language, priceboard::PriceBoard,
+    rates::RateSnapshot, storage::Store, theme::Theme, tiers::Tier,
+};
+
+/// Where settings were kept before the database, unless `DATA_FILE` said
+/// otherwise.
+pub const DEFAULT_PATH: &str = "data.json";
+
+/// A guild's settings as the JSON file kept them.
+#[derive(Debug, Default, Deserialize)]
+struct LegacyGuild {
+    #[serde(default)]
+    theme: Theme,
+    #[serde(default)]
+    tiers: Vec<Tier>,
+    #[serde(default)]
+    language: Option<Language>,
+    #[serde(default)]
+    priceboard: Option<PriceBoard>,
+}
+
+/// Everything the JSON file held.
+#[derive(Debug, Default, Deserialize)]
+struct LegacyData {
+    #[serde(default)]
+    guilds: HashMap<u64, LegacyGuild>,
+    #[serde(default)]
+    history: Vec<Calculation>,
+    #[serde(default)]
+    alerts: Vec<Alert>,
+    #[serde(default)]
+    rate_history: Vec<RateSnapshot>,
+}
+
+/// How much an import brought over.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Imported {
+    pub guilds: usize,
+    pub calculations: usize,
+    pub alerts: usize,
+    pub rates: usize,
+}
+
+/// The name the file is moved to once imported, so it's kept but not imported
+/// again.
+pub fn imported_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".imported");
+    PathBuf::from(name)
+}
+
+/// Imports the JSON file at `path` into an empty `storage`, then renames it.
+/// Returns `None` when there's no file. Fails without importing anything if the
+/// database already has data, since the two can't be merged safely.
+pub async fn import_file(storage: &dyn Store, path: &Path) -> Result<Option<Imported>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(format!("Cannot read {}: {}", path.display(), error)),
+    };
+    if !storage.is_empty().await? {
+        return Err(format!(
+            "{} still holds settings from before the database, but the database already \
+             has data. Move the file away to start without it, or point DATABASE_URL at an \
+             empty database to import it.",
+            path.display()
+        ));
+    }
+    let data: LegacyData = serde_json::from_str(&contents)
+        .map_err(|error| format!("Cannot parse {}: {}", path.display(), error))?;
+    let imported = import(storage, data).await?;
+    let target = imported_path(path);
+    fs::rename(path, &target).map_err(|error| {
+        format!(
+            "Imported {} but cannot rename it to {}: {}",
+            path.display(),
+            target.display(),
+            error
+        )
+    })?;
+    Ok(Some(imported))
+}
+
+async fn import(storage: &dyn Store, data: LegacyData) -> Result<Imported, String> {
+    let imported = Imported {
+        guilds: data.guilds.len(),
+        calculations: data.history.len(),
+        alerts: data.alerts.len(),
+        rates: data.rate_history.len(),
+    };
+    for (guild_id, guild) in data.guilds {
+        storage
+            .apply_guild_update(
+                GuildId(guild_id),
+                Box::new(move |config| {
+                    config.theme = guild.theme;
+                    config.tiers = guild.tiers;
+                    config.language = guild.language;
+                    config.priceboard = guild.priceboard;
+                }),
+            )
+            .await?;
+    }
+    for calculation in data.history {
+        storage.record_calculation(calculation).await?;
+    }
+    for alert in data.alerts {
+        storage.add_alert(alert).await?;
+    }
+    let mut rate_history = data.rate_history;
+    rate_history.sort_by_key(|snapshot| snapshot.timestamp);
+    for snapshot in rate_history {
+        storage.record_rate(snapshot).await?;
+    }
+    Ok(imported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage;
+
+    /// A file as the JSON store wrote it.
+    const DATA: &str = r#"{
+        "guilds": {
+            "1": {
+                "theme": {"color": 16711680, "footer": "Shop", "thumbnail": null},
+                "tiers": [{"min_robux": 10000, "rate": 3.0}],
+                "language": "es",
+                "priceboard": {"channel_id": 2, "message_id": 3, "refresh_minutes": 15, "updated_at": 100, "language": "es"}
+            }
+        },
+        "history": [
+            {"guild_id": 1, "user_id": 4, "command": "price", "robux": 1000, "gbp": 3.5, "usd": 4.45, "rate": 0.0035, "timestamp": 100}
+        ],
+        "alerts": [
+            {"id": 7, "user_id": 4, "guild_id": null, "channel_id": 5, "pair": "GBP/USD", "threshold": 1.3, "direction": "above"}
+        ],
+        "next_alert_id": 7,
+        "rate_history": [{"timestamp": 100, "gbp_to_usd": 1.27}]
+    }"#;
+
+    #[tokio::test]
+    async fn imports_the_json_file_once() {
+        let storage = storage::connect("sqlite::memory:").await.unwrap();
+        let dir = std::env::temp_dir().join(format!("legacy-import-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("data.json");
+        fs::write(&path, DATA).unwrap();
+
+        let imported = import_file(&*storage, &path).await.unwrap();
+        assert_eq!(
+            imported,
+            Some(Imported {
+                guilds: 1,
+                calculations: 1,
+                alerts: 1,
+                rates: 1,
+            })
+        );
+        assert!(!path.exists() && imported_path(&path).exists());
+
+        let config = storage.guild(GuildId(1)).await.unwrap();
+        assert_eq!(config.theme.footer.as_deref(), Some("Shop"));
+        assert_eq!(config.tiers.len(), 1);
+        assert_eq!(config.language, Some(Language::Es));
+        assert_eq!(config.priceboard.map(|board| board.channel_id), Some(2));
+        assert_eq!(storage.alerts_for_user(4).await.unwrap().len(), 1);
+        assert_eq!(storage.history(Some(1), 4, 10).await.unwrap().len(), 1);
+
+        // With the file renamed there's nothing left to import, and a file that
+        // reappears isn't merged into the data already there.
+        assert_eq!(import_file(&*storage, &path).await.unwrap(), None);
+        fs::write(&path, DATA).unwrap();
+        assert!(import_file(&*storage, &path).await.is_err());
+        assert!(path.exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
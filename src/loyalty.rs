@@ -0,0 +1,232 @@
+/// Points awarded for every £1 spent on completed orders.
+pub const POINTS_PER_GBP: f64 = 10.0;
+
+/// Most a loyalty discount can take off a quote, in percent.
+pub const MAX_DISCOUNT_PERCENT: f64 = 90.0;
+
+/// A loyalty discount: buyers who have purchased at least `min_robux` in total get
+/// `percent` off every quote.
+#[derive(Clone, Debug)]
+pub struct Discount {
+    pub min_robux: u64,
+    pub percent: f64,
+}
+
+/// A buyer's completed orders in one guild.
+#[derive(Clone, Debug, Default)]
+pub struct Purchases {
+    pub orders: u64,
+    pub robux: u64,
+    pub gbp: f64,
+}
+
+impl Purchases {
+    pub fn points(&self) -> u64 {
+        (self.gbp * POINTS_PER_GBP).floor() as u64
+    }
+}
+
+/// Returns the discount with the highest threshold that `purchased` reaches.
+/// `discounts` must be sorted by `min_robux`.
+pub fn discount_for(discounts: &[Discount], purchased: u64) -> Option<&Discount> {
+    discounts
+        .iter()
+        .rev()
+        .find(|discount| purchased >= discount.min_robux)
+}
+
+/// Returns the first discount `purchased` hasn't reached yet.
+pub fn next_discount(discounts: &[Discount], purchased: u64) -> Option<&Discount> {
+    discounts
+        .iter()
+        .find(|discount| purchased < discount.min_robux)
+}
+
+/// Inserts `discount`, replacing any discount with the same threshold and keeping the
+/// list sorted.
+pub fn upsert(discounts: &mut Vec<Discount>, discount: Discount) {
+    discounts.retain(|existing| existing.min_robux != discount.min_robux);
+    discounts.push(discount);
+    discounts.sort_by_key(|discount| discount.min_robux);
+}
+
+/// Removes the discount starting at `min_robux`, returning whether one existed.
+pub fn remove(discounts: &mut Vec<Discount>, min_robux: u64) -> bool {
+    let before = discounts.len();
+    discounts.retain(|discount| discount.min_robux != min_robux);
+    discounts.len() != before
+}
+
+/// A role buyers are given once they've purchased at least `min_robux` in total,
+/// such as "Customer" at 1 or "VIP" at 100,000.
+#[derive(Clone, Debug)]
+pub struct RoleReward {
+    pub min_robux: u64,
+    pub role_id: u64,
+}
+
+/// Returns every reward `purchased` has reached.
+#[cfg(feature = "orders")]
+pub fn rewards_for(rewards: &[RoleReward], purchased: u64) -> Vec<&RoleReward> {
+    rewards
+        .iter()
+        .filter(|reward| purchased >= reward.min_robux)
+        .collect()
+}
+
+/// Inserts `reward`, replacing any reward with the same threshold and keeping the
+/// list sorted.
+pub fn upsert_reward(rewards: &mut Vec<RoleReward>, reward: RoleReward) {
+    rewards.retain(|existing| existing.min_robux != reward.min_robux);
+    rewards.push(reward);
+    rewards.sort_by_key(|reward| reward.min_robux);
+}
+
+/// Removes the reward at `min_robux`, returning whether one existed.
+pub fn remove_reward(rewards: &mut Vec<RoleReward>, min_robux: u64) -> bool {
+    let before = rewards.len();
+    rewards.retain(|reward| reward.min_robux != min_robux);
+    rewards.len() != before
+}
+
+/// A discount for members with a role, such as 5% off for server boosters, set
+/// with `/roleprice`.
+#[derive(Clone, Debug)]
+pub struct RoleDiscount {
+    pub role_id: u64,
+    pub percent: f64,
+}
+
+/// Inserts `discount`, replacing any discount for the same role.
+pub fn upsert_role_discount(discounts: &mut Vec<RoleDiscount>, discount: RoleDiscount) {
+    discounts.retain(|existing| existing.role_id != discount.role_id);
+    discounts.push(discount);
+}
+
+/// Removes the discount for `role_id`, returning whether one existed.
+pub fn remove_role_discount(discounts: &mut Vec<RoleDiscount>, role_id: u64) -> bool {
+    let before = discounts.len();
+    discounts.retain(|discount| discount.role_id != role_id);
+    discounts.len() != before
+}
+
+/// The discount taken off a member's quote, and the role that gave it if it
+/// wasn't their loyalty discount.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppliedDiscount {
+    pub percent: f64,
+    pub role_id: Option<u64>,
+}
+
+impl AppliedDiscount {
+    /// Multiplier to apply to a GBP rate.
+    pub fn factor(&self) -> f64 {
+        1.0 - self.percent / 100.0
+    }
+}
+
+/// The biggest discount a member with `roles` gets: their loyalty discount or
+/// the best of their roles'. Discounts don't stack.
+pub fn best_discount(
+    loyalty: Option<&Discount>,
+    role_discounts: &[RoleDiscount],
+    roles: &[u64],
+) -> Option<AppliedDiscount> {
+    let loyalty = loyalty.map(|discount| AppliedDiscount {
+        percent: discount.percent,
+        role_id: None,
+    });
+    let roles = role_discounts
+        .iter()
+        .filter(|discount| roles.contains(&discount.role_id))
+        .map(|discount| AppliedDiscount {
+            percent: discount.percent,
+            role_id: Some(discount.role_id),
+        });
+    loyalty
+        .into_iter()
+        .chain(roles)
+        .max_by(|a, b| a.percent.total_cmp(&b.percent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn discounts() -> Vec<Discount> {
+        let mut discounts = Vec::new();
+        upsert(
+            &mut discounts,
+            Discount {
+                min_robux: 50_000,
+                percent: 10.0,
+            },
+        );
+        upsert(
+            &mut discounts,
+            Discount {
+                min_robux: 10_000,
+                percent: 5.0,
+            },
+        );
+        discounts
+    }
+
+    #[test]
+    fn loyalty_discounts_follow_the_highest_threshold_reached() {
+        let discounts = discounts();
+        assert!(discount_for(&discounts, 9_999).is_none());
+        assert_eq!(discount_for(&discounts, 10_000).unwrap().percent, 5.0);
+        assert_eq!(discount_for(&discounts, 75_000).unwrap().percent, 10.0);
+        assert_eq!(next_discount(&discounts, 10_000).unwrap().min_robux, 50_000);
+        assert!(next_discount(&discounts, 50_000).is_none());
+    }
+
+    #[test]
+    fn the_biggest_discount_wins_without_stacking() {
+        let loyalty = Discount {
+            min_robux: 10_000,
+            percent: 5.0,
+        };
+        let roles = [
+            RoleDiscount {
+                role_id: 1,
+                percent: 8.0,
+            },
+            RoleDiscount {
+                role_id: 2,
+                percent: 3.0,
+            },
+        ];
+        assert_eq!(
+            best_discount(Some(&loyalty), &roles, &[1, 2]),
+            Some(AppliedDiscount {
+                percent: 8.0,
+                role_id: Some(1),
+            })
+        );
+        assert_eq!(
+            best_discount(Some(&loyalty), &roles, &[2]),
+            Some(AppliedDiscount {
+                percent: 5.0,
+                role_id: None,
+            })
+        );
+        assert_eq!(best_discount(None, &roles, &[3]), None);
+    }
+
+    #[test]
+    fn discounts_scale_the_rate() {
+        let discount = AppliedDiscount {
+            percent: 10.0,
+            role_id: None,
+        };
+        assert!((discount.factor() - 0.9).abs() < 1e-9);
+        let purchases = Purchases {
+            orders: 2,
+            robux: 2000,
+            gbp: 7.05,
+        };
+        assert_eq!(purchases.points(), 70);
+    }
+}
@@ -1,48 +1,487 @@
-use application_command::ApplicationCommandInteraction;
+use application_command::{
+    ApplicationCommandInteraction, CommandDataOption, CommandDataOptionValue,
+};
 use command::CommandOptionType;
+use component::ButtonStyle;
+#[cfg(feature = "orders")]
+use component::{ActionRowComponent, InputTextStyle};
 use dotenv::dotenv;
 use serenity::{
     async_trait,
-    builder::{CreateApplicationCommand, CreateEmbed},
+    builder::{
+        CreateApplicationCommandOption, CreateApplicationCommands, CreateComponents, CreateEmbed,
+    },
+    client::bridge::gateway::{event::ShardStageUpdateEvent, ShardId},
+    gateway::ConnectionStage,
     model::{
-        application::interaction::{Interaction, InteractionResponseType},
+        application::interaction::{
+            message_component::MessageComponentInteraction, Interaction, InteractionResponseType,
+        },
         gateway::Ready,
         id::GuildId,
         prelude::*,
     },
     prelude::*,
 };
-use std::env;
+use std::{
+    env,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "orders")]
+use serenity::model::application::interaction::modal::ModalSubmitInteraction;
+#[cfg(feature = "roblox")]
+use std::cmp::Reverse;
+
+mod alerts;
+mod announcements;
+#[cfg(feature = "api")]
+mod api;
+mod audit;
+mod backup;
+mod blacklist;
+mod botstats;
+mod cache;
+mod cli;
+mod commands;
+mod coupons;
+mod crypto;
+mod digest;
+mod disputes;
+mod emojis;
+mod export;
+mod features;
+mod fees;
+mod giftcards;
+mod history;
+mod i18n;
+mod legacy;
+mod loyalty;
+mod methods;
+#[cfg(feature = "orders")]
+mod middleman;
+mod nitro;
+mod numbers;
+mod opslog;
+mod options;
+mod orders;
+mod packs;
+mod parse;
+mod payments;
+#[cfg(feature = "orders")]
+mod payouts;
+mod plaintext;
+mod priceboard;
+mod pricecard;
+mod pricing;
+mod qr;
+#[cfg(feature = "orders")]
+mod queue;
+mod quotes;
+mod ratechart;
+mod ratehistory;
+mod rates;
+mod receipt;
+mod reminders;
+mod reply;
+#[cfg(feature = "sentry")]
+mod reporting;
+mod retention;
+#[cfg(feature = "orders")]
+mod risk;
+#[cfg(any(feature = "orders", feature = "roblox"))]
+mod roblox;
+mod rolimons;
+mod scheduler;
+mod secrets;
+mod sellers;
+mod sheets;
+mod state;
+mod stats;
+mod storage;
+#[cfg(feature = "telegram")]
+mod telegram;
+mod templates;
+#[cfg(test)]
+mod tests;
+mod theme;
+mod tickets;
+mod tiers;
+mod undo;
+mod validation;
+mod vat;
+#[cfg(any(feature = "orders", feature = "roblox"))]
+mod verification;
+mod vouches;
+mod webhooks;
+mod worth;
+
+use alerts::Alert;
+use announcements::RateAnnouncements;
+use audit::AuditEntry;
+use blacklist::BlacklistEntry;
+use botstats::BotStats;
+use cache::SeenIds;
+use commands::{BotCommand, Plugin, Registry};
+use coupons::{Coupon, CouponKind};
+use crypto::Crypto;
+#[cfg(feature = "orders")]
+use disputes::{Dispute, Refund};
+use emojis::EmojiKind;
+use features::Feature;
+use history::Calculation;
+use i18n::{t, tf, Language, Localized};
+use loyalty::{AppliedDiscount, Discount, RoleDiscount, RoleReward};
+use methods::DeliveryMethod;
+#[cfg(feature = "orders")]
+use middleman::{FeePayer, MiddlemanProgress};
+use numbers::{Rounding, RoundingMode};
+use options::OptionsExt;
+#[cfg(feature = "roblox")]
+use orders::GamepassCheck;
+#[cfg(feature = "orders")]
+use orders::{FrozenRates, Order, OrderLimits, OrderStatus};
+use payments::{PaymentHandle, PaymentMethod};
+#[cfg(feature = "orders")]
+use payouts::{Payout, PayoutHold, PayoutStatus};
+use priceboard::PriceBoard;
+use pricing::ShopRates;
+#[cfg(feature = "orders")]
+use queue::QueueEntry;
+use ratechart::Period;
+use ratehistory::{RateChange, RateKind};
+use rates::{ForexSource, RateSnapshot, Rates};
+use reminders::Reminder;
+use reply::Reply;
+use retention::Retention;
+#[cfg(any(feature = "orders", feature = "roblox"))]
+use roblox::Roblox;
+#[cfg(feature = "roblox")]
+use roblox::RobloxUser;
+use scheduler::{Job, Scheduler};
+use sellers::Seller;
+use sheets::{SheetSettings, Sheets};
+use state::AppState;
+use storage::{GuildConfig, Storage};
+use undo::Deleted;
+use vat::VatSettings;
+#[cfg(feature = "roblox")]
+use verification::PendingVerification;
+#[cfg(any(feature = "orders", feature = "roblox"))]
+use verification::RobloxLink;
+use vouches::Vouch;
+#[cfg(feature = "orders")]
+use webhooks::OrderEvent;
+use webhooks::Webhook;
 
-const ROBUX_TO_GBP_RATE: f64 = 0.0035;
 const GBP_TO_USD_RATE: f64 = 1.38;
 const ROBUX_MARKUP_RATE: f64 = 0.3;
+const PREMIUM_BONUS_RATE: f64 = 0.1;
+const SHARD_REPORT_MINUTES: u64 = 5;
+const MAX_ALERTS_PER_USER: usize = 10;
+const MAX_REMINDERS_PER_USER: usize = 25;
+const MAX_MESSAGE_AMOUNTS: usize = 5;
+/// Most amounts one `/pricebatch` call prices.
+const MAX_BATCH_AMOUNTS: usize = 10;
+/// The price `/rounding` shows its setting on.
+const ROUNDING_EXAMPLE_GBP: f64 = 3.4567;
+/// Most buyers one `/split` divides a purchase between.
+const MAX_SPLIT_PEOPLE: u64 = 50;
+/// The order `/fees` works out example totals for unless given another.
+const FEES_EXAMPLE_ROBUX: u64 = 1000;
+/// Commands listed in `/botstats`.
+const MAX_TOP_COMMANDS: usize = 10;
+/// Where buyers create the gamepass `/setupgamepass` prices.
+#[cfg(feature = "roblox")]
+const GAMEPASS_CREATE_URL: &str = "https://create.roblox.com/dashboard/creations?activeTab=Pass";
+/// Most valuable limiteds listed by `/rap`.
+#[cfg(feature = "roblox")]
+const MAX_RAP_ITEMS: usize = 10;
+/// Entries `/auditlog show` lists unless asked for more, and the most it lists.
+const AUDIT_LOG_PAGE: u64 = 10;
+const MAX_AUDIT_LOG_PAGE: u64 = 25;
+/// How long handled interactions are remembered: the 15 minutes Discord accepts
+/// responses to one for.
+const HANDLED_INTERACTION_SECONDS: u64 = 15 * 60;
+/// Discord's limit on an embed's description, in characters.
+const MAX_EMBED_DESCRIPTION: usize = 4096;
+/// Custom id of the order form's button and of the modal it opens.
+#[cfg(feature = "orders")]
+const ORDER_FORM_ID: &str = "order_form";
+/// Prefix of `/convert` currency menu ids, followed by `:<from>:<amount>`.
+const CONVERT_MENU_ID: &str = "convert";
+/// Prefix of payout approval button ids, followed by `:approve:<id>` or `:reject:<id>`.
+#[cfg(feature = "orders")]
+const PAYOUT_BUTTON_ID: &str = "payout";
+/// Custom id of the button that checks a `/verify` phrase.
+#[cfg(feature = "roblox")]
+const VERIFY_BUTTON_ID: &str = "verify";
+/// Prefix of middleman step button ids, followed by `:received:<order>` or
+/// `:released:<order>`.
+#[cfg(feature = "orders")]
+const MIDDLEMAN_BUTTON_ID: &str = "middleman";
+/// Prefix of review prompt button and modal ids, followed by
+/// `:<guild>:<seller>:<stars>`.
+#[cfg(feature = "orders")]
+const REVIEW_BUTTON_ID: &str = "review";
+/// Prefix of Undo button ids, followed by `:` and a [`Deleted::key`].
+const UNDO_BUTTON_ID: &str = "undo";
 
-struct Handler;
+struct Handler {
+    tasks_started: AtomicBool,
+}
 
 #[async_trait]
 impl EventHandler for Handler {
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
-        if let Interaction::ApplicationCommand(command) = interaction {
-            let result = match command.data.name.as_str() {
-                "price" => handle_price_command(&ctx, &command).await,
-                "convert" => handle_convert_command(&ctx, &command).await,
-                "robux" => handle_robux_command(&ctx, &command).await,
-                "help" => handle_help_command(&ctx, &command).await,
-                _ => Err(format!("Unknown command: {}", command.data.name)),
-            };
+        let first_delivery = state(&ctx)
+            .await
+            .handled_interactions
+            .insert(interaction.id().0, rates::now())
+            .await;
+        if !first_delivery {
+            println!("Skipping interaction {}, already handled", interaction.id());
+            return;
+        }
+        let guild_id = match &interaction {
+            Interaction::ApplicationCommand(command) => command.guild_id,
+            Interaction::MessageComponent(component) => component.guild_id,
+            Interaction::ModalSubmit(modal) => modal.guild_id,
+            _ => None,
+        };
+        // Every reply to the interaction shows money rounded the guild's way, at
+        // its fixed exchange rate if it has one, with its emojis.
+        let config = guild_config(&ctx, guild_id).await;
+        numbers::with_rounding(
+            config.rounding,
+            rates::with_forex_override(
+                config.forex_override,
+                emojis::with_emojis(config.emojis, handle_interaction(ctx, interaction)),
+            ),
+        )
+        .await;
+    }
+
+    async fn message(&self, ctx: Context, message: Message) {
+        if let Err(error) = auto_reply(&ctx, &message).await {
+            eprintln!("Error handling message: {}", error);
+            report_error(
+                &ctx,
+                "Auto-reply error",
+                &error,
+                interaction_context(
+                    ("Channel", &format!("<#{}>", message.channel_id)),
+                    &message.author,
+                    message.guild_id,
+                ),
+            )
+            .await;
+        }
+    }
+
+    async fn guild_create(&self, ctx: Context, guild: Guild) {
+        bot_stats(&ctx).await.join_guilds([guild.id]);
+    }
+
+    async fn guild_delete(&self, ctx: Context, incomplete: UnavailableGuild) {
+        if !incomplete.unavailable {
+            bot_stats(&ctx).await.leave_guild(incomplete.id);
+        }
+    }
 
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        match ready.shard {
+            Some([id, total]) => println!(
+                "{} is connected on shard {}/{}!",
+                ready.user.name,
+                id + 1,
+                total
+            ),
+            None => println!("{} is connected!", ready.user.name),
+        }
+        let stats = bot_stats(&ctx).await;
+        stats.set_shard_connected(ctx.shard_id, true);
+        stats.join_guilds(ready.guilds.iter().map(|guild| guild.id));
+        ctx.set_activity(rate_activity(&ctx).await).await;
+        // Commands are registered over HTTP, so one shard doing it is enough.
+        if ctx.shard_id == 0 {
+            let result = register_commands(&ctx).await.map_err(|e| e.to_string());
             if let Err(error) = result {
-                eprintln!("Error handling command: {}", error);
-                respond_with_error(&ctx, &command, &error).await;
+                eprintln!("Error registering commands: {}", error);
+                report_error(&ctx, "Command registration failed", &error, Vec::new()).await;
             }
         }
+
+        if !self.tasks_started.swap(true, Ordering::SeqCst) {
+            let scheduler = Scheduler::new(ctx.clone());
+            scheduler.every(
+                "rate refresh",
+                Duration::from_secs(rates::refresh_minutes() * 60),
+                RateRefreshJob::new(&ctx).await,
+            );
+            scheduler.every(
+                "shard latency",
+                Duration::from_secs(SHARD_REPORT_MINUTES * 60),
+                report_shard_latency,
+            );
+            scheduler.every(
+                "price boards",
+                Duration::from_secs(60),
+                refresh_due_priceboards,
+            );
+            #[cfg(feature = "orders")]
+            scheduler.every(
+                "payout holds",
+                Duration::from_secs(60),
+                notify_ended_payout_holds,
+            );
+            // More often than the other jobs, since reminders can be minutes away.
+            scheduler.every("reminders", Duration::from_secs(15), deliver_due_reminders);
+            scheduler.every(
+                "deleted items",
+                Duration::from_secs(60),
+                purge_deleted_items,
+            );
+            scheduler.every(
+                "retention",
+                Duration::from_secs(retention::PURGE_MINUTES * 60),
+                purge_expired_records,
+            );
+            scheduler.every(
+                "digests",
+                Duration::from_secs(10 * 60),
+                DigestJob { handled_week: 0 },
+            );
+            ctx.data
+                .write()
+                .await
+                .insert::<Scheduler>(Arc::new(scheduler));
+        }
     }
 
-    async fn ready(&self, ctx: Context, ready: Ready) {
-        println!("{} is connected!", ready.user.name);
-        if let Err(error) = register_commands(&ctx).await {
-            eprintln!("Error registering commands: {}", error);
+    async fn shard_stage_update(&self, ctx: Context, event: ShardStageUpdateEvent) {
+        bot_stats(&ctx)
+            .await
+            .set_shard_connected(event.shard_id.0, event.new == ConnectionStage::Connected);
+        println!(
+            "Shard {} is {} (was {})",
+            event.shard_id.0 + 1,
+            event.new,
+            event.old
+        );
+    }
+}
+
+/// Routes an interaction to its handler, replying with any error.
+async fn handle_interaction(ctx: Context, interaction: Interaction) {
+    if let Interaction::MessageComponent(component) = &interaction {
+        if let Err(error) = handle_component(&ctx, component).await {
+            eprintln!("Error handling component: {}", error);
+            if opslog::is_unexpected(&error) {
+                report_error(
+                    &ctx,
+                    "Component error",
+                    &error,
+                    interaction_context(
+                        ("Component", &component.data.custom_id),
+                        &component.user,
+                        component.guild_id,
+                    ),
+                )
+                .await;
+            }
+            let result = match component
+                .create_interaction_response(&ctx.http, |response| {
+                    response
+                        .kind(InteractionResponseType::ChannelMessageWithSource)
+                        .interaction_response_data(|message| {
+                            message.content(&error).ephemeral(true)
+                        })
+                })
+                .await
+            {
+                // The handler responded before failing, so the error follows it.
+                Err(why) if reply::is_already_acknowledged(&why) => component
+                    .create_followup_message(&ctx.http, |message| {
+                        message.content(&error).ephemeral(true)
+                    })
+                    .await
+                    .map(|_| ()),
+                result => result,
+            };
+            if let Err(why) = result {
+                eprintln!("Cannot respond to component: {}", why);
+            }
+        }
+        return;
+    }
+    #[cfg(feature = "orders")]
+    if let Interaction::ModalSubmit(modal) = &interaction {
+        if let Err(error) = handle_modal_submit(&ctx, modal).await {
+            eprintln!("Error handling modal: {}", error);
+            if opslog::is_unexpected(&error) {
+                report_error(
+                    &ctx,
+                    "Modal error",
+                    &error,
+                    interaction_context(
+                        ("Modal", &modal.data.custom_id),
+                        &modal.user,
+                        modal.guild_id,
+                    ),
+                )
+                .await;
+            }
+            respond_to_modal(
+                &ctx,
+                modal,
+                CreateEmbed::default().description(&error).clone(),
+            )
+            .await;
+        }
+        return;
+    }
+    if let Interaction::ApplicationCommand(command) = interaction {
+        bot_stats(&ctx).await.record_command(&command.data.name);
+        if Feature::for_command(&command.data.name).is_some() {
+            let lang = language(&ctx, &command).await;
+            if let Err(error) =
+                refuse_disabled_feature(&ctx, command.guild_id, &command.data.name, lang).await
+            {
+                respond_with_error(&ctx, &command, &error).await;
+                return;
+            }
+        }
+        let result = match registry().get(&command.data.name) {
+            Some(registered) => run_command(&ctx, &command, registered).await,
+            None => Err(tf(
+                language(&ctx, &command).await,
+                "error.unknown_command",
+                &[("name", &command.data.name)],
+            )),
+        };
+
+        if let Err(error) = result {
+            eprintln!("Error handling command: {}", error);
+            if opslog::is_unexpected(&error) {
+                report_error(
+                    &ctx,
+                    "Command error",
+                    &error,
+                    interaction_context(
+                        ("Command", &format!("/{}", command.data.name)),
+                        &command.user,
+                        command.guild_id,
+                    ),
+                )
+                .await;
+            }
+            respond_with_error(&ctx, &command, &error).await;
         }
     }
 }
@@ -50,14 +489,107 @@ impl EventHandler for Handler {
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv().ok();
+    #[cfg(feature = "sentry")]
+    let _sentry = reporting::init();
+    if let Some(args) = cli::args() {
+        if let Err(error) = cli::run(args).await {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
     let token = env::var("DISCORD_TOKEN")?;
-    let intents = GatewayIntents::GUILD_MESSAGES | GatewayIntents::MESSAGE_CONTENT;
-
+    let storage = storage::connect(
+        &env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite:data.db".to_string()),
+    )
+    .await?;
+    let data_file = env::var("DATA_FILE").unwrap_or_else(|_| legacy::DEFAULT_PATH.to_string());
+    if let Some(imported) = legacy::import_file(&*storage, Path::new(&data_file)).await? {
+        println!(
+            "Imported {} guilds, {} calculations, {} alerts and {} rates from {}",
+            imported.guilds, imported.calculations, imported.alerts, imported.rates, data_file
+        );
+    }
+    let intents =
+        GatewayIntents::GUILDS | GatewayIntents::GUILD_MESSAGES | GatewayIntents::MESSAGE_CONTENT;
     let mut client = Client::builder(&token, intents)
-        .event_handler(Handler)
+        .event_handler(Handler {
+            tasks_started: AtomicBool::new(false),
+        })
         .await?;
 
-    client.start().await?;
+    #[cfg(any(feature = "orders", feature = "roblox"))]
+    let roblox = match env::var("ROBLOX_COOKIE") {
+        Ok(cookie) => {
+            let group_id = env::var("ROBLOX_GROUP_ID")?.parse()?;
+            Some(Arc::new(Roblox::new(cookie, group_id)))
+        }
+        Err(_) => None,
+    };
+    let sheets = match env::var("GOOGLE_SERVICE_ACCOUNT_FILE") {
+        Ok(path) => Some(Arc::new(Sheets::from_file(&path)?)),
+        Err(_) => None,
+    };
+    let state = Arc::new(AppState {
+        storage,
+        rates: Arc::new(RwLock::new(Rates::new(GBP_TO_USD_RATE))),
+        stats: Arc::new(BotStats::new()),
+        handled_interactions: SeenIds::new(HANDLED_INTERACTION_SECONDS),
+        http: reqwest::Client::new(),
+        responder: client.cache_and_http.http.clone(),
+        shard_manager: Some(client.shard_manager.clone()),
+        command_guild: env::var("GUILD_ID")
+            .ok()
+            .map(|id| id.parse())
+            .transpose()?
+            .map(GuildId),
+        log_channel: env::var("LOG_CHANNEL_ID")
+            .ok()
+            .map(|id| id.parse())
+            .transpose()?
+            .map(ChannelId),
+        backup_channel: env::var("BACKUP_CHANNEL_ID")
+            .ok()
+            .map(|id| id.parse())
+            .transpose()?
+            .map(ChannelId),
+        #[cfg(any(feature = "orders", feature = "roblox"))]
+        roblox,
+        sheets,
+    });
+    client.data.write().await.insert::<AppState>(state.clone());
+
+    #[cfg(feature = "api")]
+    if let Ok(addr) = env::var("API_ADDR") {
+        let state = state.clone();
+        tokio::spawn(async move {
+            if let Err(error) = api::serve(&addr, state).await {
+                eprintln!("{}", error);
+            }
+        });
+    }
+
+    #[cfg(feature = "telegram")]
+    if let Ok(telegram_token) = env::var("TELEGRAM_TOKEN") {
+        let guild_id = match env::var("TELEGRAM_GUILD_ID") {
+            Ok(id) => Some(GuildId(id.parse()?)),
+            Err(_) => None,
+        };
+        tokio::spawn(telegram::run(
+            telegram_token,
+            guild_id,
+            state.storage.clone(),
+            state.rates.clone(),
+        ));
+    }
+
+    let result = client.start_autosharded().await;
+    // Lets background jobs finish what they're doing before the process exits.
+    let scheduler = client.data.read().await.get::<Scheduler>().cloned();
+    if let Some(scheduler) = scheduler {
+        scheduler.shutdown().await;
+    }
+    result?;
     Ok(())
 }
 
@@ -65,276 +597,12278 @@ async fn handle_price_command(
     ctx: &Context,
     command: &ApplicationCommandInteraction,
 ) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    refuse_blacklisted(ctx, command, lang).await?;
     let options = &command.data.options;
 
-    if options.len() < 2 {
-        return Err("Insufficient command options".to_string());
-    }
+    let price_type = options
+        .get_str("type")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let amount = parse::parse_robux_amount(
+        options
+            .get_str("amount")
+            .ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )
+    .and_then(|amount| validation::validate_robux(amount, lang))?;
 
-    let price_type = options[0]
-        .value
-        .as_ref()
-        .ok_or("Missing price type")?
-        .as_str()
-        .ok_or("Invalid price type")?;
-    let amount = options[1]
-        .value
+    let config = guild_config(ctx, command.guild_id).await;
+    let buying = options.get_str("side") == Some("buy");
+    if buying {
+        return send_buy_quote(ctx, command, &config, amount, lang).await;
+    }
+    validation::validate_order_size(amount, &config.order_limits, lang)?;
+    let usd_rate = gbp_to_usd(ctx).await;
+    let seller = match options.get_user("seller") {
+        Some(user) => Some(
+            sellers::find(&config.sellers, user.id.0)
+                .ok_or_else(|| tf(lang, "seller.not_registered", &[("user", &user.mention())]))?,
+        ),
+        None => None,
+    };
+    // A seller's own rate replaces the guild's tiers.
+    let seller_tiers = seller.map(Seller::tiers);
+    let tiers = seller_tiers
         .as_ref()
-        .ok_or("Missing amount")?
-        .as_u64()
-        .ok_or("Invalid amount")? as f64;
+        .map_or(&config.tiers[..], |tiers| &tiers[..]);
+    let tier = tiers::tier_for(&config.tiers, amount).filter(|_| seller.is_none());
+    let payment_handles = seller
+        .map(|seller| &seller.payment_handles)
+        .filter(|handles| !handles.is_empty())
+        .unwrap_or(&config.payment_handles);
+    let discount = match (command.guild_id, &command.member) {
+        (Some(guild_id), Some(member)) => {
+            member_discount(ctx, guild_id, member.user.id, &member.roles, &config).await?
+        }
+        _ => None,
+    };
+    let base_rate = pricing::base_rate(tiers, &config.shop_rates, amount, discount.as_ref());
+    let amount = amount as f64;
 
-    let (rate, is_after_tax) = match price_type {
-        "b/t" => (ROBUX_TO_GBP_RATE, false),
-        "a/t" => (ROBUX_TO_GBP_RATE / (1.0 - ROBUX_MARKUP_RATE), true),
-        _ => return Err("Invalid type. Use 'b/t' or 'a/t'.".to_string()),
+    let tax_modes = match price_type {
+        "b/t" => vec![false],
+        "a/t" => vec![true],
+        "both" => vec![false, true],
+        _ => return Err(t(lang, "price.invalid_type").to_string()),
+    };
+    let method = options.get_str("method").unwrap_or("gamepass");
+    let methods = match method {
+        "compare" => DeliveryMethod::ALL.to_vec(),
+        _ => vec![DeliveryMethod::parse(method).ok_or(t(lang, "price.invalid_method"))?],
+    };
+    let premium = bool_option(options, "premium");
+    let preferred = preferred_currency(ctx, command.user.id).await?;
+    let coupon = match options.get_str("coupon") {
+        Some(code) => Some(usable_coupon(ctx, command, lang, code).await?),
+        None => None,
+    };
+    let crypto = match options.get_str("crypto") {
+        Some(code) => {
+            let coin = Crypto::parse(code).ok_or(t(lang, "error.invalid_currency"))?;
+            Some((coin, crypto_price(ctx, lang, coin).await?))
+        }
+        None => None,
     };
 
-    let gbp_amount = amount * rate;
-    let gamepass_price = if is_after_tax {
-        (amount / (1.0 - ROBUX_MARKUP_RATE)).round() as i64
+    let type_label = if price_type == "both" {
+        "b/t + a/t"
     } else {
-        amount as i64
+        price_type
     };
+    let mut description = format!(
+        "**{}:** {}\n**{}:** {}\n**{}:** {} / 1k {}",
+        t(lang, "price.conversion_type"),
+        type_label,
+        t(lang, "price.amount"),
+        numbers::integer(lang, amount),
+        t(lang, "price.rate"),
+        numbers::gbp(lang, base_rate * 1000.0),
+        emojis::robux()
+    );
+    if let Some(tier) = tier {
+        description.push_str(&format!(
+            " ({})",
+            tf(
+                lang,
+                "price.tier",
+                &[("min", &numbers::integer(lang, tier.min_robux as f64))]
+            )
+        ));
+    } else if seller.is_none() {
+        if let Some(set_at) = rate_set_at(ctx, command.guild_id, RateKind::Sell, lang).await {
+            description.push_str(&format!(" ({})", set_at));
+        }
+    }
+    if let Some(seller) = seller {
+        description.push_str(&format!(
+            "\n**{}:** {}",
+            t(lang, "seller.label"),
+            UserId(seller.user_id).mention()
+        ));
+    }
+    if let Some(discount) = &discount {
+        let (label, value) = discount_label(lang, discount);
+        description.push_str(&format!("\n**{}:** {}", label, value));
+    }
+    if let Some(coupon) = &coupon {
+        description.push_str(&format!(
+            "\n**{}:** {}",
+            t(lang, "coupon.label"),
+            coupon_summary(lang, coupon)
+        ));
+    }
+    if let Some(guild_id) = command.guild_id {
+        let available = match seller {
+            Some(seller) => seller_stock(ctx, guild_id, seller.user_id).await?,
+            None => available_stock(ctx, guild_id).await?,
+        };
+        if let Some(available) = available {
+            if amount as u64 > available {
+                description.push_str(&format!(
+                    "\n⚠️ {}",
+                    tf(
+                        lang,
+                        "stock.low",
+                        &[("available", &numbers::integer(lang, available as f64))]
+                    )
+                ));
+            }
+        }
+    }
+    if let Some(vat) = &config.vat {
+        description.push_str(&format!(
+            "\n**{}:** {}",
+            t(lang, "vat.label"),
+            vat_summary(lang, vat)
+        ));
+    }
+    if premium {
+        description.push_str(&format!(
+            "\n**{}:** {}\n*{}*",
+            t(lang, "price.premium_robux"),
+            numbers::robux(lang, premium_robux(amount) as f64),
+            t(lang, "price.premium_note")
+        ));
+    }
 
-    let embed = CreateEmbed::default()
-        .title("Price Calculation")
-        .description(format!(
-            "**Conversion Type:** {}\n**Amount of Robux:** {}",
-            price_type, amount as i64
-        ))
-        .field("Gamepass Price", format!("{} R$", gamepass_price), true)
-        .field("Amount in GBP", format!("£{:.2}", gbp_amount), true)
-        .field(
-            "Amount in USD",
-            format!("${:.2}", gbp_amount * GBP_TO_USD_RATE),
-            true,
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "price.title"));
+    let mut qr_code = None;
+    let summary;
+    // Templates only reword single quotes, which have one price to fill in.
+    let mut template_values = None;
+
+    if let ([method], [is_after_tax]) = (&methods[..], &tax_modes[..]) {
+        let (method, is_after_tax) = (*method, *is_after_tax);
+        let quote = pricing::quote(
+            amount,
+            base_rate,
+            method,
+            is_after_tax,
+            coupon.as_ref(),
+            config.vat.as_ref(),
+        );
+        let rate = quote.rate;
+        let gbp_amount = quote.gbp();
+        template_values = Some([
+            ("robux", numbers::robux(lang, amount)),
+            ("gbp", numbers::gbp(lang, gbp_amount)),
+            ("usd", numbers::usd(lang, gbp_amount * usd_rate)),
+            ("rate", numbers::gbp(lang, base_rate * 1000.0)),
+            ("user", command.user.mention().to_string()),
+        ]);
+        summary = format!(
+            "{} ({}, {}) = {} / {}",
+            numbers::robux(lang, amount),
+            type_label,
+            method.name(lang),
+            numbers::gbp(lang, gbp_amount),
+            numbers::usd(lang, gbp_amount * usd_rate)
+        );
+
+        record_calculation(
+            ctx,
+            Calculation::new(
+                command.guild_id.map(|id| id.0),
+                command.user.id.0,
+                "price",
+                amount as i64,
+                gbp_amount,
+                gbp_amount * usd_rate,
+                rate,
+            ),
         )
-        .color(0x0096FF)
-        .clone();
+        .await;
 
-    send_embed_response(ctx, command, embed).await
+        description.push_str(&format!(
+            "\n**{}:** {}",
+            t(lang, "price.method"),
+            method.name(lang)
+        ));
+        if let Some(note) = method.note(lang) {
+            description.push_str(&format!("\n*{}*", note));
+        }
+
+        embed
+            .field(
+                method.listing_label(lang),
+                numbers::robux(lang, method.listing_price(amount, is_after_tax) as f64),
+                true,
+            )
+            .field(
+                tf(lang, "common.amount_in", &[("currency", &"GBP")]),
+                numbers::gbp(lang, gbp_amount),
+                true,
+            )
+            .field(
+                tf(lang, "common.amount_in", &[("currency", &"USD")]),
+                numbers::usd(lang, gbp_amount * usd_rate),
+                true,
+            );
+        if let Some((code, per_gbp)) = &preferred {
+            embed.field(
+                tf(lang, "common.amount_in", &[("currency", code)]),
+                numbers::money(lang, code, gbp_amount * per_gbp),
+                true,
+            );
+        }
+        if let Some(vat) = &config.vat {
+            embed
+                .field(
+                    t(lang, "vat.excluding"),
+                    numbers::gbp(lang, quote.net),
+                    true,
+                )
+                .field(
+                    tf(lang, "vat.amount", &[("summary", &vat_summary(lang, vat))]),
+                    numbers::gbp(lang, quote.tax),
+                    true,
+                );
+        }
+        if let Some((coin, (price, _))) = crypto {
+            embed.field(
+                tf(lang, "common.amount_in", &[("currency", &coin.symbol())]),
+                numbers::decimal(lang, gbp_amount / price, 8),
+                true,
+            );
+            qr_code = crypto_qr_code(payment_handles, coin, gbp_amount / price)?;
+        }
+        if let Some(links) =
+            payment_links(ctx, payment_handles, gbp_amount, gbp_amount * usd_rate).await
+        {
+            embed.field(t(lang, "payment.pay"), links, false);
+        }
+    } else {
+        let mut prices = Vec::new();
+        for method in methods {
+            let mut totals = Vec::new();
+            let mut lines = Vec::new();
+            for &is_after_tax in &tax_modes {
+                let gbp_amount = pricing::quote(
+                    amount,
+                    base_rate,
+                    method,
+                    is_after_tax,
+                    coupon.as_ref(),
+                    config.vat.as_ref(),
+                )
+                .gbp();
+                let mut line = format!(
+                    "{}: {}\n{} / {}",
+                    method.listing_label(lang),
+                    numbers::robux(lang, method.listing_price(amount, is_after_tax) as f64),
+                    numbers::gbp(lang, gbp_amount),
+                    numbers::usd(lang, gbp_amount * usd_rate)
+                );
+                if let Some((code, per_gbp)) = &preferred {
+                    line.push_str(&format!(
+                        " / {}",
+                        numbers::money(lang, code, gbp_amount * per_gbp)
+                    ));
+                }
+                if let Some((coin, (price, _))) = crypto {
+                    line.push_str(&format!(
+                        "\n{} {}",
+                        numbers::decimal(lang, gbp_amount / price, 8),
+                        coin.symbol()
+                    ));
+                }
+                if tax_modes.len() > 1 {
+                    line = format!("**{}**\n{}", if is_after_tax { "a/t" } else { "b/t" }, line);
+                }
+                prices.push(format!(
+                    "{}{} {}",
+                    method.name(lang),
+                    if tax_modes.len() > 1 {
+                        if is_after_tax {
+                            " a/t"
+                        } else {
+                            " b/t"
+                        }
+                    } else {
+                        ""
+                    },
+                    numbers::gbp(lang, gbp_amount)
+                ));
+                totals.push(gbp_amount);
+                lines.push(line);
+            }
+            if let [before, after] = totals[..] {
+                lines.push(format!(
+                    "**{}: +{} / +{} (+{}%)**",
+                    t(lang, "price.difference"),
+                    numbers::gbp(lang, after - before),
+                    numbers::usd(lang, (after - before) * usd_rate),
+                    numbers::decimal(lang, (after / before - 1.0) * 100.0, 1)
+                ));
+            }
+            let mut value = lines.join("\n");
+            if let Some(note) = method.note(lang) {
+                value.push_str(&format!("\n*{}*", note));
+            }
+            embed.field(method.name(lang), value, true);
+        }
+        summary = format!(
+            "{} ({}): {}",
+            numbers::robux(lang, amount),
+            type_label,
+            prices.join(", ")
+        );
+    }
+
+    if let Some((_, (_, updated_at))) = crypto {
+        description.push_str(&format!("\n*{}*", crypto_updated(lang, updated_at)));
+    }
+
+    embed.description(description);
+    if let (Some(values), Some(template)) =
+        (template_values, templates::find(&config.templates, "price"))
+    {
+        template.apply(&mut embed, &values);
+    }
+    match qr_code {
+        Some(png) if !wants_text(options) => {
+            send_embed_with_image(ctx, command, embed, png, qr::FILENAME).await
+        }
+        _ => send_calculator_response(ctx, command, embed, &summary).await,
+    }
 }
 
-async fn handle_convert_command(
+/// `/price side:buy`: what the guild pays for `robux` at its buy rate.
+async fn send_buy_quote(
     ctx: &Context,
     command: &ApplicationCommandInteraction,
+    config: &GuildConfig,
+    robux: u64,
+    lang: Language,
 ) -> Result<(), String> {
-    let options = &command.data.options;
-
-    if options.len() < 2 {
-        return Err("Insufficient command options".to_string());
+    let buy_rate = config
+        .shop_rates
+        .buy_per_robux()
+        .ok_or(t(lang, "rates.not_buying"))?;
+    let gbp = robux as f64 * buy_rate;
+    let usd = gbp * gbp_to_usd(ctx).await;
+    record_calculation(
+        ctx,
+        Calculation::new(
+            command.guild_id.map(|id| id.0),
+            command.user.id.0,
+            "price",
+            robux as i64,
+            gbp,
+            usd,
+            buy_rate,
+        ),
+    )
+    .await;
+    let mut rate = format!(
+        "{} / 1k {}",
+        numbers::gbp(lang, buy_rate * 1000.0),
+        emojis::robux()
+    );
+    if let Some(set_at) = rate_set_at(ctx, command.guild_id, RateKind::Buy, lang).await {
+        rate.push_str(&format!("\n{}", set_at));
     }
 
-    let currency = options[0]
-        .value
-        .as_ref()
-        .ok_or("Missing currency")?
-        .as_str()
-        .ok_or("Invalid currency")?;
-    let amount = options[1]
-        .value
-        .as_ref()
-        .ok_or("Missing amount")?
-        .as_f64()
-        .ok_or("Invalid amount")?;
+    let embed = CreateEmbed::default()
+        .title(t(lang, "rates.buy_title"))
+        .description(tf(
+            lang,
+            "rates.buy_quote",
+            &[
+                ("robux", &numbers::robux(lang, robux as f64)),
+                ("gbp", &numbers::gbp(lang, gbp)),
+                ("usd", &numbers::usd(lang, usd)),
+            ],
+        ))
+        .field(t(lang, "price.rate"), rate, true)
+        .clone();
+    let summary = format!(
+        "{} = {} / {}",
+        numbers::robux(lang, robux as f64),
+        numbers::gbp(lang, gbp),
+        numbers::usd(lang, usd)
+    );
+    send_calculator_response(ctx, command, embed, &summary).await
+}
 
-    let (from_currency, to_currency, converted_amount) = match currency {
-        "GBP" => ("GBP", "USD", amount * GBP_TO_USD_RATE),
-        "USD" => ("USD", "GBP", amount / GBP_TO_USD_RATE),
-        _ => return Err("Invalid currency. Use 'GBP' or 'USD'.".to_string()),
+/// Shows the guild's sell and buy rates and the spread between them.
+async fn handle_rates_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let config = guild_config(ctx, command.guild_id).await;
+    let rates = config.shop_rates;
+    let usd_rate = gbp_to_usd(ctx).await;
+    let per_1k = |gbp: f64| {
+        format!(
+            "{} / {} {}",
+            numbers::gbp(lang, gbp),
+            numbers::usd(lang, gbp * usd_rate),
+            t(lang, "pricelist.per_1k")
+        )
     };
+    // Each rate followed by when it was last changed, if that was recorded.
+    let with_set_at = |value: String, set_at: Option<String>| match set_at {
+        Some(set_at) => format!("{}\n{}", value, set_at),
+        None => value,
+    };
+    let sell_set_at = rate_set_at(ctx, command.guild_id, RateKind::Sell, lang).await;
+    let buy_set_at = rate_set_at(ctx, command.guild_id, RateKind::Buy, lang).await;
+    let forex_set_at = rate_set_at(ctx, command.guild_id, RateKind::Forex, lang).await;
 
-    let embed = CreateEmbed::default()
-        .title("Currency Conversion")
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "rates.title"))
         .field(
-            format!("Amount in {}", from_currency),
-            format!("{:.2}", amount),
+            t(lang, "rates.sell"),
+            with_set_at(per_1k(rates.sell), sell_set_at),
             true,
         )
         .field(
-            format!("Amount in {}", to_currency),
-            format!("{:.2}", converted_amount),
+            t(lang, "rates.buy"),
+            with_set_at(
+                rates
+                    .buy
+                    .map_or(t(lang, "rates.not_buying").to_string(), per_1k),
+                buy_set_at,
+            ),
             true,
-        )
-        .color(0x0096FF)
-        .clone();
+        );
+    if let Some(forex_override) = config.forex_override {
+        embed.field(
+            t(lang, "setrate.title"),
+            with_set_at(
+                format!("GBP/USD {}", numbers::decimal(lang, forex_override, 4)),
+                forex_set_at,
+            ),
+            true,
+        );
+    }
+    if let Some((spread, percent)) = rates.spread(rates.sell) {
+        embed.field(
+            t(lang, "rates.spread"),
+            format!(
+                "{} ({}%)",
+                per_1k(spread),
+                numbers::decimal(lang, percent, 1)
+            ),
+            true,
+        );
+        // Each tier sells at its own rate, so it has its own spread.
+        let lines: Vec<String> = config
+            .tiers
+            .iter()
+            .filter_map(|tier| {
+                let (spread, percent) = rates.spread(tier.rate)?;
+                Some(format!(
+                    "**{}+ {}:** {} ({}%)",
+                    numbers::integer(lang, tier.min_robux as f64),
+                    emojis::robux(),
+                    per_1k(spread),
+                    numbers::decimal(lang, percent, 1)
+                ))
+            })
+            .collect();
+        if !lines.is_empty() {
+            embed.field(t(lang, "rates.tier_spreads"), lines.join("\n"), false);
+        }
+    }
+    stamp_stale_rates(ctx, lang, &mut embed).await;
 
     send_embed_response(ctx, command, embed).await
 }
 
-async fn handle_robux_command(
+/// Sets the guild's sell rate and buy rate. A buy rate of 0 stops `/price
+/// side:buy` quotes.
+async fn handle_setrates_command(
     ctx: &Context,
     command: &ApplicationCommandInteraction,
 ) -> Result<(), String> {
-    let options = &command.data.options;
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let rate = |name: &str| command.data.options.get_number(name);
+    let sell = rate("sell")
+        .map(|rate| validation::validate_tier_rate(rate, lang))
+        .transpose()?;
+    let buy = rate("buy")
+        .map(|rate| match rate {
+            0.0 => Ok(None),
+            rate => validation::validate_tier_rate(rate, lang).map(Some),
+        })
+        .transpose()?;
+    if sell.is_none() && buy.is_none() {
+        return Err(t(lang, "error.invalid_options").to_string());
+    }
 
-    if options.len() < 2 {
-        return Err("Insufficient command options".to_string());
+    let mut previous = ShopRates::default();
+    let mut rates = ShopRates::default();
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| {
+            previous = config.shop_rates;
+            if let Some(sell) = sell {
+                config.shop_rates.sell = sell;
+            }
+            if let Some(buy) = buy {
+                config.shop_rates.buy = buy;
+            }
+            rates = config.shop_rates;
+        })
+        .await?;
+    record_rate_change(
+        ctx,
+        command,
+        RateKind::Sell,
+        Some(previous.sell),
+        Some(rates.sell),
+    )
+    .await;
+    record_rate_change(ctx, command, RateKind::Buy, previous.buy, rates.buy).await;
+    if state(ctx).await.command_guild == Some(guild_id) {
+        update_presence(ctx).await;
     }
 
-    let currency = options[0]
-        .value
-        .as_ref()
-        .ok_or("Missing currency")?
-        .as_str()
-        .ok_or("Invalid currency")?;
-    let amount = options[1]
-        .value
-        .as_ref()
-        .ok_or("Missing amount")?
-        .as_f64()
-        .ok_or("Invalid amount")?;
+    let buy = rates
+        .buy
+        .map_or(t(lang, "rates.not_buying").to_string(), |buy| {
+            numbers::gbp(lang, buy)
+        });
+    let embed = CreateEmbed::default()
+        .title(t(lang, "rates.title"))
+        .description(tf(
+            lang,
+            "rates.updated",
+            &[("sell", &numbers::gbp(lang, rates.sell)), ("buy", &buy)],
+        ))
+        .clone();
 
-    let (gbp_amount, usd_amount) = match currency {
-        "GBP" => (amount, amount * GBP_TO_USD_RATE),
-        "USD" => (amount / GBP_TO_USD_RATE, amount),
-        _ => return Err("Invalid currency. Use 'GBP' or 'USD'.".to_string()),
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Fixes the exchange rate the guild prices at, or goes back to the market rate
+/// with a value of 0.
+async fn handle_setrate_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .filter(|option| option.name == "forex")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let pair = subcommand
+        .options
+        .get_str("pair")
+        .filter(|pair| rates::PAIRS.contains(pair))
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let value = subcommand
+        .options
+        .get_number("value")
+        .filter(|value| value.is_finite() && *value >= 0.0)
+        .ok_or(t(lang, "error.invalid_options"))?;
+    // Stored as USD per GBP whichever way round it was given.
+    let gbp_to_usd = match (value, pair) {
+        (0.0, _) => None,
+        (value, "USD/GBP") => Some(1.0 / value),
+        (value, _) => Some(value),
     };
 
-    let robux_amount = (gbp_amount / ROBUX_TO_GBP_RATE) as i64;
+    let mut previous = None;
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| {
+            previous = config.forex_override;
+            config.forex_override = gbp_to_usd;
+        })
+        .await?;
+    record_rate_change(ctx, command, RateKind::Forex, previous, gbp_to_usd).await;
 
+    let description = match gbp_to_usd {
+        Some(_) => tf(
+            lang,
+            "setrate.fixed",
+            &[
+                ("pair", &pair),
+                ("value", &numbers::decimal(lang, value, 4)),
+            ],
+        ),
+        None => t(lang, "setrate.market").to_string(),
+    };
     let embed = CreateEmbed::default()
-        .title("Robux Calculation")
-        .description(format!(
-            "{:.2} {} affords {} R$ (£{:.2} / ${:.2})",
-            amount, currency, robux_amount, gbp_amount, usd_amount
-        ))
-        .color(0x0096FF)
+        .title(t(lang, "setrate.title"))
+        .description(description)
         .clone();
 
     send_embed_response(ctx, command, embed).await
 }
 
-async fn handle_help_command(
+/// Records a `/setrates` or `/setrate` change for `/ratehistory`, unless the
+/// rate stayed the same.
+async fn record_rate_change(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    kind: RateKind,
+    previous: Option<f64>,
+    value: Option<f64>,
+) {
+    let Some(guild_id) = command.guild_id else {
+        return;
+    };
+    if previous == value {
+        return;
+    }
+    let change = RateChange {
+        guild_id: guild_id.0,
+        changed_by: command.user.id.0,
+        kind,
+        previous,
+        value,
+        changed_at: rates::now(),
+    };
+    if let Err(error) = storage(ctx).await.record_rate_change(change).await {
+        eprintln!("Error recording rate change: {}", error);
+    }
+}
+
+/// "set 3 days ago" for the guild's `kind` rate, if its last change was
+/// recorded.
+async fn rate_set_at(
+    ctx: &Context,
+    guild_id: Option<GuildId>,
+    kind: RateKind,
+    lang: Language,
+) -> Option<String> {
+    let changed_at = storage(ctx)
+        .await
+        .rate_changed_at(guild_id?.0, kind)
+        .await
+        .unwrap_or_else(|error| {
+            eprintln!("Error loading rate changes: {}", error);
+            None
+        })?;
+    Some(tf(
+        lang,
+        "ratehistory.set_at",
+        &[("when", &format!("<t:{}:R>", changed_at))],
+    ))
+}
+
+/// Lists the guild's latest changes to its sell, buy and exchange rates.
+async fn handle_ratehistory_command(
     ctx: &Context,
     command: &ApplicationCommandInteraction,
 ) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let changes = storage(ctx)
+        .await
+        .rate_changes(guild_id.0, ratehistory::SHOWN)
+        .await?;
+
+    let description = if changes.is_empty() {
+        t(lang, "ratehistory.empty").to_string()
+    } else {
+        changes
+            .iter()
+            .map(|change| {
+                tf(
+                    lang,
+                    "ratehistory.line",
+                    &[
+                        ("when", &format!("<t:{}:f>", change.changed_at)),
+                        ("rate", &change.kind.name(lang)),
+                        ("previous", &change.kind.format(lang, change.previous)),
+                        ("value", &change.kind.format(lang, change.value)),
+                        ("user", &UserId(change.changed_by).mention()),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
     let embed = CreateEmbed::default()
-        .title("Available Commands")
-        .description(
-            "Here are the available commands and their usage:\n\
-        /price: Calculate the price in GBP and USD for a given amount of Robux\n\
-        /convert: Convert between GBP and USD\n\
-        /robux: Convert GBP or USD to the amount of Robux",
-        )
-        .color(0x0096FF)
+        .title(t(lang, "ratehistory.title"))
+        .description(description)
         .clone();
 
     send_embed_response(ctx, command, embed).await
 }
 
-async fn send_embed_response(
+async fn handle_convert_command(
     ctx: &Context,
     command: &ApplicationCommandInteraction,
-    embed: CreateEmbed,
 ) -> Result<(), String> {
-    command
-        .create_interaction_response(&ctx.http, |response| {
+    let lang = language(ctx, command).await;
+    let amount = command
+        .data
+        .options
+        .get_number("amount")
+        .ok_or(t(lang, "error.invalid_options"))
+        .map_err(str::to_string)
+        .and_then(|amount| validation::validate_fiat(amount, lang))?;
+    let currency = command.data.options.get_str("currency").unwrap_or("GBP");
+    if !rates::FIAT.contains(&currency) {
+        return Err(t(lang, "error.invalid_currency").to_string());
+    }
+
+    let to = command.data.options.get_str("to");
+    // A menu can't be copied, so text output converts to the other fiat currency.
+    let to = match to {
+        None if wants_text(&command.data.options) => {
+            Some(if currency == "GBP" { "USD" } else { "GBP" })
+        }
+        to => to,
+    };
+    if let Some(to) = to {
+        let (embed, converted) = conversion_embed(ctx, lang, amount, currency, to).await?;
+        let summary = format!(
+            "{} {} = {} {}",
+            numbers::decimal(lang, amount, 2),
+            currency,
+            converted,
+            to
+        );
+        return send_calculator_response(ctx, command, embed, &summary).await;
+    }
+
+    let mut embed = CreateEmbed::default()
+        .title(t(lang, "convert.title"))
+        .description(t(lang, "convert.pick"))
+        .field(
+            tf(lang, "common.amount_in", &[("currency", &currency)]),
+            numbers::decimal(lang, amount, 2),
+            true,
+        )
+        .clone();
+    guild_config(ctx, command.guild_id)
+        .await
+        .theme
+        .apply(&mut embed);
+
+    reply::with_backoff(|| {
+        command.create_interaction_response(&ctx.http, |response| {
             response
                 .kind(InteractionResponseType::ChannelMessageWithSource)
-                .interaction_response_data(|message| message.add_embed(embed))
+                .interaction_response_data(|message| {
+                    message.add_embed(embed.clone()).components(|components| {
+                        currency_menu(components, lang, amount, currency, None)
+                    })
+                })
         })
-        .await
-        .map_err(|e| format!("Error sending response: {:?}", e))
+    })
+    .await
+    .map_err(|e| format!("Error sending response: {:?}", e))
 }
 
-async fn respond_with_error(
+/// A select menu of every currency `amount` in `from` can be converted to. Its
+/// custom id carries the amount, so picking one needs no stored state.
+fn currency_menu<'a>(
+    components: &'a mut CreateComponents,
+    lang: Language,
+    amount: f64,
+    from: &str,
+    selected: Option<&str>,
+) -> &'a mut CreateComponents {
+    let currencies: Vec<_> = rates::FIAT
+        .into_iter()
+        .chain(Crypto::ALL.iter().map(|coin| coin.symbol()))
+        .filter(|code| *code != from)
+        .collect();
+    components.create_action_row(|row| {
+        row.create_select_menu(|menu| {
+            menu.custom_id(format!("{}:{}:{}", CONVERT_MENU_ID, from, amount))
+                .placeholder(t(lang, "convert.placeholder"))
+                .options(|options| {
+                    for code in currencies {
+                        options.create_option(|option| {
+                            option
+                                .label(code)
+                                .value(code)
+                                .default_selection(selected == Some(code))
+                        });
+                    }
+                    options
+                })
+        })
+    })
+}
+
+/// The `/convert` result for `amount` in `from`, a fiat currency, converted to `to`,
+/// and the converted amount.
+async fn conversion_embed(
     ctx: &Context,
-    command: &ApplicationCommandInteraction,
-    error_message: &str,
-) {
-    if let Err(why) = command
+    lang: Language,
+    amount: f64,
+    from: &str,
+    to: &str,
+) -> Result<(CreateEmbed, String), String> {
+    let usd_rate = gbp_to_usd(ctx).await;
+    let gbp = match from {
+        "GBP" => amount,
+        "USD" => amount / usd_rate,
+        _ => return Err(t(lang, "error.invalid_currency").to_string()),
+    };
+
+    let mut embed = CreateEmbed::default();
+    let converted_amount = match to {
+        "GBP" => numbers::decimal(lang, gbp, 2),
+        "USD" => numbers::decimal(lang, gbp * usd_rate, 2),
+        code => {
+            let coin = Crypto::parse(code).ok_or(t(lang, "error.invalid_currency"))?;
+            let (price, updated_at) = crypto_price(ctx, lang, coin).await?;
+            embed.description(format!("*{}*", crypto_updated(lang, updated_at)));
+            numbers::decimal(lang, gbp / price, 8)
+        }
+    };
+
+    embed
+        .title(t(lang, "convert.title"))
+        .field(
+            tf(lang, "common.amount_in", &[("currency", &from)]),
+            numbers::decimal(lang, amount, 2),
+            true,
+        )
+        .field(
+            tf(lang, "common.amount_in", &[("currency", &to)]),
+            &converted_amount,
+            true,
+        );
+    Ok((embed, converted_amount))
+}
+
+/// Shows the conversion for the currency picked from a `/convert` menu.
+async fn handle_currency_pick(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+    lang: Language,
+) -> Result<(), String> {
+    let mut parts = component.data.custom_id.splitn(3, ':').skip(1);
+    let (from, amount) = parts
+        .next()
+        .zip(parts.next().and_then(|amount| amount.parse::<f64>().ok()))
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let to = component
+        .data
+        .values
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let (mut embed, _) = conversion_embed(ctx, lang, amount, from, to).await?;
+    guild_config(ctx, component.guild_id)
+        .await
+        .theme
+        .apply(&mut embed);
+
+    component
         .create_interaction_response(&ctx.http, |response| {
             response
-                .kind(InteractionResponseType::ChannelMessageWithSource)
-                .interaction_response_data(|message| message.content(error_message))
+                .kind(InteractionResponseType::UpdateMessage)
+                .interaction_response_data(|message| {
+                    message.add_embed(embed).components(|components| {
+                        currency_menu(components, lang, amount, from, Some(to))
+                    })
+                })
         })
         .await
-    {
-        eprintln!("Cannot respond to slash command: {}", why);
+        .map_err(|e| format!("Error updating conversion: {:?}", e))
+}
+
+async fn handle_robux_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    refuse_blacklisted(ctx, command, lang).await?;
+    let options = &command.data.options;
+
+    let currency = options
+        .get_str("currency")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let amount = options
+        .get_number("amount")
+        .ok_or(t(lang, "error.invalid_options"))
+        .map_err(str::to_string)
+        .and_then(|amount| validation::validate_fiat(amount, lang))?;
+
+    let usd_rate = gbp_to_usd(ctx).await;
+    let (gbp_amount, usd_amount) = match currency {
+        "GBP" => (amount, amount * usd_rate),
+        "USD" => (amount / usd_rate, amount),
+        _ => return Err(t(lang, "error.invalid_currency").to_string()),
+    };
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let rate = config.shop_rates.sell_per_robux();
+    let robux_amount = (gbp_amount / rate) as i64;
+
+    record_calculation(
+        ctx,
+        Calculation::new(
+            command.guild_id.map(|id| id.0),
+            command.user.id.0,
+            "robux",
+            robux_amount,
+            gbp_amount,
+            usd_amount,
+            rate,
+        ),
+    )
+    .await;
+
+    let mut description = tf(
+        lang,
+        "robux.affords",
+        &[
+            ("amount", &numbers::decimal(lang, amount, 2)),
+            ("currency", &currency),
+            ("robux", &numbers::integer(lang, robux_amount as f64)),
+            ("gbp", &numbers::gbp(lang, gbp_amount)),
+            ("usd", &numbers::usd(lang, usd_amount)),
+        ],
+    );
+    if let Some((code, per_gbp)) = preferred_currency(ctx, command.user.id).await? {
+        description.push_str(&format!(
+            "\n**{}:** {}",
+            tf(lang, "common.amount_in", &[("currency", &code)]),
+            numbers::money(lang, &code, gbp_amount * per_gbp)
+        ));
+    }
+    if bool_option(options, "premium") {
+        description.push_str(&format!(
+            "\n**{}:** {}\n*{}*",
+            t(lang, "price.premium_robux"),
+            numbers::robux(lang, premium_robux(robux_amount as f64) as f64),
+            t(lang, "price.premium_note")
+        ));
+    }
+
+    let mut embed = CreateEmbed::default()
+        .title(t(lang, "robux.title"))
+        .description(description)
+        .clone();
+    if let Some(template) = templates::find(&config.templates, "robux") {
+        template.apply(
+            &mut embed,
+            &[
+                ("robux", numbers::robux(lang, robux_amount as f64)),
+                ("gbp", numbers::gbp(lang, gbp_amount)),
+                ("usd", numbers::usd(lang, usd_amount)),
+                ("rate", numbers::gbp(lang, rate * 1000.0)),
+                ("user", command.user.mention().to_string()),
+            ],
+        );
     }
+    let summary = format!(
+        "{} {} = {}",
+        numbers::decimal(lang, amount, 2),
+        currency,
+        numbers::robux(lang, robux_amount as f64)
+    );
+
+    send_calculator_response(ctx, command, embed, &summary).await
 }
 
-async fn register_commands(ctx: &Context) -> Result<(), Box<dyn std::error::Error>> {
-    let guild_id = GuildId(env::var("GUILD_ID")?.parse()?);
+async fn handle_packs_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let currency = command.data.options.get_str("currency").unwrap_or("GBP");
+    let gbp_multiplier = match currency {
+        "GBP" => 1.0,
+        "USD" => gbp_to_usd(ctx).await,
+        _ => return Err(t(lang, "error.invalid_currency").to_string()),
+    };
 
-    let commands = guild_id
-        .set_application_commands(&ctx.http, |commands| {
-            commands
-                .create_application_command(|command: &mut CreateApplicationCommand| {
-                    command
-                        .name("help")
-                        .description("Display the available commands and their usage")
-                })
-                .create_application_command(|command: &mut CreateApplicationCommand| {
-                    command
-                        .name("price")
-                        .description(
-                            "Calculate the price in GBP and USD for a given amount of Robux",
-                        )
-                        .create_option(|option| {
+    let config = guild_config(ctx, command.guild_id).await;
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "packs.title")).description(tf(
+        lang,
+        "packs.description",
+        &[("currency", &currency)],
+    ));
+
+    for pack in &packs::OFFICIAL_PACKS {
+        let official_price = if currency == "USD" {
+            pack.usd
+        } else {
+            pack.gbp
+        };
+        let rate = pricing::base_rate(&config.tiers, &config.shop_rates, pack.robux, None);
+        let seller_price = pack.robux as f64 * rate * gbp_multiplier;
+        let savings = packs::savings_percent(official_price, seller_price);
+        let comparison = if savings >= 0.0 {
+            format!(
+                "**{}**",
+                tf(
+                    lang,
+                    "packs.saves",
+                    &[("percent", &format!("{:.0}", savings))]
+                )
+            )
+        } else {
+            tf(
+                lang,
+                "packs.costs_more",
+                &[("percent", &format!("{:.0}", -savings))],
+            )
+        };
+
+        embed.field(
+            numbers::robux(lang, pack.robux as f64),
+            format!(
+                "{}: {} ({} / 1k)\n{}: {}\n{}",
+                t(lang, "packs.official"),
+                numbers::money(lang, currency, official_price),
+                numbers::money(lang, currency, official_price / pack.robux as f64 * 1000.0),
+                t(lang, "packs.seller"),
+                numbers::money(lang, currency, seller_price),
+                comparison
+            ),
+            true,
+        );
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// What an amount of money gets a buyer here, from official packs and as
+/// Discord Nitro, side by side.
+async fn handle_value_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let options = &command.data.options;
+    let amount = options
+        .get_number("amount")
+        .ok_or(t(lang, "error.invalid_options"))
+        .map_err(str::to_string)
+        .and_then(|amount| validation::validate_fiat(amount, lang))?;
+    let currency = options.get_str("currency").unwrap_or("GBP");
+    let gbp_amount = match currency {
+        "GBP" => amount,
+        "USD" => amount / gbp_to_usd(ctx).await,
+        _ => return Err(t(lang, "error.invalid_currency").to_string()),
+    };
+    let in_currency = |gbp: f64, usd: f64| if currency == "USD" { usd } else { gbp };
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let seller_robux = pricing::affordable_robux(&config.tiers, &config.shop_rates, gbp_amount);
+    let (packs, pack_robux) = packs::best_packs(amount, |pack| in_currency(pack.gbp, pack.usd));
+
+    let packs_value = if packs.is_empty() {
+        t(lang, "value.too_little").to_string()
+    } else {
+        format!(
+            "**{}**\n{}",
+            numbers::robux(lang, pack_robux as f64),
+            packs
+                .iter()
+                .map(|(pack, count)| format!(
+                    "{} × {}",
+                    count,
+                    numbers::robux(lang, pack.robux as f64)
+                ))
+                .collect::<Vec<_>>()
+                .join(" + ")
+        )
+    };
+    let nitro_value = nitro::NITRO_PLANS
+        .iter()
+        .map(|plan| {
+            tf(
+                lang,
+                "value.months",
+                &[
+                    (
+                        "months",
+                        &((amount / in_currency(plan.gbp, plan.usd) + 1e-9).floor() as u64),
+                    ),
+                    ("plan", &plan.name),
+                ],
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(
+            lang,
+            "value.title",
+            &[("amount", &numbers::money(lang, currency, amount))],
+        ))
+        .field(
+            t(lang, "value.seller"),
+            format!("**{}**", numbers::robux(lang, seller_robux as f64)),
+            true,
+        )
+        .field(t(lang, "value.official"), packs_value, true)
+        .field(t(lang, "value.nitro"), nitro_value, true);
+    if pack_robux > 0 && seller_robux > pack_robux {
+        embed.footer(|footer| {
+            footer.text(tf(
+                lang,
+                "value.more_robux",
+                &[(
+                    "percent",
+                    &format!(
+                        "{:.0}",
+                        (seller_robux as f64 / pack_robux as f64 - 1.0) * 100.0
+                    ),
+                )],
+            ))
+        });
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Puts an amount of Robux in context: what it costs here and from Roblox, the
+/// work it takes to pay for, and what a creator would cash it out for.
+async fn handle_worth_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let robux = parse::parse_robux_amount(
+        command
+            .data
+            .options
+            .get_str("robux")
+            .ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )
+    .and_then(|amount| validation::validate_robux(amount, lang))?;
+    let gbp_to_usd = gbp_to_usd(ctx).await;
+    let both = |gbp: f64, usd: f64| {
+        format!(
+            "**{}** / {}",
+            numbers::gbp(lang, gbp),
+            numbers::usd(lang, usd)
+        )
+    };
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let gbp = robux as f64 * pricing::base_rate(&config.tiers, &config.shop_rates, robux, None);
+    let (packs, official_gbp) = packs::cheapest_packs(robux, |pack| pack.gbp);
+    let official_usd: f64 = packs
+        .iter()
+        .map(|(pack, count)| *count as f64 * pack.usd)
+        .sum();
+    let official_value = format!(
+        "{}\n{}",
+        both(official_gbp, official_usd),
+        packs
+            .iter()
+            .map(|(pack, count)| format!("{} × {}", count, numbers::robux(lang, pack.robux as f64)))
+            .collect::<Vec<_>>()
+            .join(" + ")
+    );
+    let hours = worth::hours_of_work(gbp);
+    let work = if hours < 1.0 {
+        tf(
+            lang,
+            "worth.minutes",
+            &[("minutes", &((hours * 60.0).ceil() as u64))],
+        )
+    } else {
+        tf(
+            lang,
+            "worth.hours",
+            &[("hours", &numbers::decimal(lang, hours, 1))],
+        )
+    };
+    let wage_value = tf(
+        lang,
+        "worth.wage_value",
+        &[
+            ("time", &work),
+            ("wage", &numbers::gbp(lang, worth::UK_MINIMUM_WAGE_GBP)),
+        ],
+    );
+    let devex_usd = worth::devex_usd(robux);
+    let mut devex_value = both(devex_usd / gbp_to_usd, devex_usd);
+    if robux < worth::DEVEX_MIN_ROBUX {
+        devex_value.push('\n');
+        devex_value.push_str(&tf(
+            lang,
+            "worth.devex_minimum",
+            &[(
+                "robux",
+                &numbers::robux(lang, worth::DEVEX_MIN_ROBUX as f64),
+            )],
+        ));
+    }
+
+    let embed = CreateEmbed::default()
+        .title(tf(
+            lang,
+            "worth.title",
+            &[("robux", &numbers::robux(lang, robux as f64))],
+        ))
+        .field(t(lang, "worth.here"), both(gbp, gbp * gbp_to_usd), true)
+        .field(t(lang, "worth.official"), official_value, true)
+        .field(t(lang, "worth.wage"), wage_value, false)
+        .field(t(lang, "worth.devex"), devex_value, false)
+        .footer(|footer| footer.text(t(lang, "worth.footer")))
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_giftcards_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let region = command
+        .data
+        .options
+        .get_str("region")
+        .and_then(giftcards::Region::parse);
+    let regions: Vec<giftcards::Region> = match region {
+        Some(region) => vec![region],
+        None => giftcards::Region::ALL.to_vec(),
+    };
+    // Before the first fetch only GBP and USD are known.
+    let per_gbp: Vec<Option<f64>> = {
+        let rates = rates(ctx).await;
+        let rates = rates.read().await;
+        regions
+            .iter()
+            .map(|region| rates.per_gbp(region.currency()))
+            .collect()
+    };
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "giftcards.title"))
+        .description(t(lang, "giftcards.description"));
+
+    for (region, per_gbp) in regions.into_iter().zip(per_gbp) {
+        let currency = region.currency();
+        for card in giftcards::in_region(region) {
+            let mut value = format!(
+                "{}\n{}\n{} / 1k",
+                numbers::robux(lang, card.robux as f64),
+                tf(
+                    lang,
+                    "giftcards.premium",
+                    &[(
+                        "robux",
+                        &numbers::robux(lang, premium_robux(card.robux as f64) as f64)
+                    )],
+                ),
+                numbers::money(lang, currency, card.value / card.robux as f64 * 1000.0),
+            );
+            if let Some(per_gbp) = per_gbp {
+                let rate = pricing::base_rate(&config.tiers, &config.shop_rates, card.robux, None);
+                let seller_price = card.robux as f64 * rate * per_gbp;
+                let savings = packs::savings_percent(card.value, seller_price);
+                let comparison = if savings >= 0.0 {
+                    format!(
+                        "**{}**",
+                        tf(
+                            lang,
+                            "packs.saves",
+                            &[("percent", &format!("{:.0}", savings))]
+                        )
+                    )
+                } else {
+                    tf(
+                        lang,
+                        "packs.costs_more",
+                        &[("percent", &format!("{:.0}", -savings))],
+                    )
+                };
+                value.push_str(&format!(
+                    "\n{}: {}\n{}",
+                    t(lang, "packs.seller"),
+                    numbers::money(lang, currency, seller_price),
+                    comparison
+                ));
+            }
+            embed.field(
+                format!(
+                    "{} {}",
+                    region.flag(),
+                    numbers::money(lang, currency, card.value)
+                ),
+                value,
+                true,
+            );
+        }
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_help_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let embed = CreateEmbed::default()
+        .title(t(lang, "help.title"))
+        .description(t(lang, "help.description"))
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_theme_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let storage = storage(ctx).await;
+
+    let title = match subcommand.name.as_str() {
+        "set" => {
+            let mut color = None;
+            let mut footer = None;
+            let mut thumbnail = None;
+
+            for option in &subcommand.options {
+                let value = option
+                    .value
+                    .as_ref()
+                    .and_then(|value| value.as_str())
+                    .ok_or(t(lang, "error.invalid_options"))?;
+                match option.name.as_str() {
+                    "color" => color = Some(theme::parse_color(value, lang)?),
+                    "footer" => footer = Some(value.to_string()),
+                    "thumbnail" => thumbnail = Some(theme::validate_thumbnail(value, lang)?),
+                    _ => {}
+                }
+            }
+
+            if color.is_none() && footer.is_none() && thumbnail.is_none() {
+                return Err(t(lang, "theme.nothing_to_set").to_string());
+            }
+
+            storage
+                .update_guild(guild_id, |config| {
+                    config.theme.color = color.or(config.theme.color);
+                    config.theme.footer = footer.or(config.theme.footer.take());
+                    config.theme.thumbnail = thumbnail.or(config.theme.thumbnail.take());
+                })
+                .await?;
+            t(lang, "theme.updated")
+        }
+        "reset" => {
+            storage
+                .update_guild(guild_id, |config| config.theme = Default::default())
+                .await?;
+            t(lang, "theme.reset")
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let theme = storage.guild(guild_id).await?.theme;
+
+    let none = t(lang, "common.none");
+    let embed = CreateEmbed::default()
+        .title(title)
+        .field(
+            t(lang, "theme.color"),
+            format!("#{:06X}", theme.color.unwrap_or(theme::DEFAULT_COLOR)),
+            true,
+        )
+        .field(
+            t(lang, "theme.footer"),
+            theme.footer.as_deref().unwrap_or(none),
+            true,
+        )
+        .field(
+            t(lang, "theme.thumbnail"),
+            theme.thumbnail.as_deref().unwrap_or(none),
+            true,
+        )
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_tier_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let min_robux = subcommand
+        .options
+        .get_int("min_robux")
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let storage = storage(ctx).await;
+    let mut previous = Vec::new();
+
+    let title = match subcommand.name.as_str() {
+        "add" => {
+            let rate = subcommand
+                .options
+                .get_number("rate")
+                .ok_or(t(lang, "error.invalid_options"))
+                .map_err(str::to_string)
+                .and_then(|rate| validation::validate_tier_rate(rate, lang))?;
+
+            storage
+                .update_guild(guild_id, |config| {
+                    previous = config.tiers.clone();
+                    tiers::upsert(&mut config.tiers, tiers::Tier { min_robux, rate })
+                })
+                .await?;
+            t(lang, "tier.saved")
+        }
+        "remove" => {
+            previous = storage.guild(guild_id).await?.tiers;
+            if !storage
+                .delete_tier(guild_id.0, min_robux, rates::now())
+                .await?
+            {
+                return Err(tf(
+                    lang,
+                    "tier.not_found",
+                    &[("min", &numbers::integer(lang, min_robux as f64))],
+                ));
+            }
+            t(lang, "tier.removed")
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let config = storage.guild(guild_id).await?;
+    announce_rate_change(ctx, &config, &previous, lang).await;
+
+    let embed = pricelist_embed(
+        lang,
+        title,
+        &config.tiers,
+        config.shop_rates.sell,
+        gbp_to_usd(ctx).await,
+    );
+    if subcommand.name == "remove" {
+        send_undoable_response(ctx, command, embed, &Deleted::Tier(min_robux)).await
+    } else {
+        send_embed_response(ctx, command, embed).await
+    }
+}
+
+/// Sets the channel, and optionally the role to ping, for rate change
+/// announcements. Leaving out the channel turns them off.
+async fn handle_announce_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .filter(|subcommand| subcommand.name == "ratechange")
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let mut channel = None;
+    let mut role = None;
+    for option in &subcommand.options {
+        match (option.name.as_str(), &option.resolved) {
+            ("channel", Some(CommandDataOptionValue::Channel(value))) => channel = Some(value.id),
+            ("role", Some(CommandDataOptionValue::Role(value))) => role = Some(value.id),
+            _ => {}
+        }
+    }
+    let announcements = channel.map(|channel| RateAnnouncements {
+        channel_id: channel.0,
+        role_id: role.map(|role| role.0),
+    });
+
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| config.rate_announcements = announcements)
+        .await?;
+
+    let description = match (channel, role) {
+        (Some(channel), Some(role)) => tf(
+            lang,
+            "announce.set_role",
+            &[("channel", &channel.mention()), ("role", &role.mention())],
+        ),
+        (Some(channel), None) => tf(lang, "announce.set", &[("channel", &channel.mention())]),
+        (None, _) => t(lang, "announce.cleared").to_string(),
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "announce.title"))
+        .description(description)
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Posts the rates that changed between `previous` and the guild's current
+/// tiers to its announcement channel, if it set one up.
+async fn announce_rate_change(
+    ctx: &Context,
+    config: &GuildConfig,
+    previous: &[tiers::Tier],
+    lang: Language,
+) {
+    let Some(announcements) = &config.rate_announcements else {
+        return;
+    };
+    let changes = announcements::rate_changes(previous, &config.tiers, config.shop_rates.sell);
+    if changes.is_empty() {
+        return;
+    }
+
+    let lines: Vec<String> = changes
+        .iter()
+        .map(|change| {
+            format!(
+                "**{}+ {}:** ~~{}~~ → {} {}",
+                numbers::integer(lang, change.min_robux as f64),
+                emojis::robux(),
+                numbers::gbp(lang, change.old),
+                numbers::gbp(lang, change.new),
+                t(lang, "pricelist.per_1k")
+            )
+        })
+        .collect();
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "announce.ratechange_title"))
+        .description(lines.join("\n"))
+        .field(
+            t(lang, "announce.effective"),
+            format!("<t:{0}:F> (<t:{0}:R>)", rates::now()),
+            false,
+        );
+    config.theme.apply(&mut embed);
+
+    if let Err(why) = ChannelId(announcements.channel_id)
+        .send_message(&ctx.http, |message| {
+            if let Some(role) = announcements.role_id {
+                message.content(RoleId(role).mention());
+            }
+            message.set_embed(embed)
+        })
+        .await
+    {
+        eprintln!("Cannot post rate announcement: {}", why);
+    }
+}
+
+async fn handle_pricelist_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let config = guild_config(ctx, command.guild_id).await;
+    let usd_rate = gbp_to_usd(ctx).await;
+    let embed = pricelist_embed(
+        lang,
+        t(lang, "pricelist.title"),
+        &config.tiers,
+        config.shop_rates.sell,
+        usd_rate,
+    );
+
+    match subcommand.name.as_str() {
+        "show" => send_embed_response(ctx, command, embed).await,
+        "image" => {
+            // The image is drawn with its own font, which has no Discord emojis.
+            let rows: Vec<pricecard::Row> = emojis::plain(|| {
+                pricelist_rows(lang, &config.tiers, config.shop_rates.sell, usd_rate)
+            })
+            .into_iter()
+            .map(|(label, price)| pricecard::Row { label, price })
+            .collect();
+            let updated = tf(
+                lang,
+                "priceboard.updated",
+                &[("time", &receipt::date(rates::now()))],
+            );
+            let footer = match &config.theme.footer {
+                Some(footer) => format!("{} - {}", footer, updated),
+                None => updated,
+            };
+            let color = config.theme.color.unwrap_or(theme::DEFAULT_COLOR);
+            let png = pricecard::render(t(lang, "pricelist.title"), &rows, &footer, color)?;
+            send_embed_with_image(ctx, command, embed, png, "pricelist.png").await
+        }
+        _ => Err(t(lang, "error.invalid_options").to_string()),
+    }
+}
+
+fn pricelist_embed(
+    lang: Language,
+    title: &str,
+    tiers: &[tiers::Tier],
+    sell_rate: f64,
+    usd_rate: f64,
+) -> CreateEmbed {
+    CreateEmbed::default()
+        .title(title)
+        .description(pricelist_lines(lang, tiers, sell_rate, usd_rate).join("\n"))
+        .clone()
+}
+
+/// Lines listing `tiers`, with `sell_rate` (GBP per 1,000) for amounts below the
+/// first one.
+fn pricelist_lines(
+    lang: Language,
+    tiers: &[tiers::Tier],
+    sell_rate: f64,
+    usd_rate: f64,
+) -> Vec<String> {
+    pricelist_rows(lang, tiers, sell_rate, usd_rate)
+        .into_iter()
+        .map(|(label, price)| format!("**{}:** {}", label, price))
+        .collect()
+}
+
+/// The Robux amounts each line of a price list covers and their rate.
+fn pricelist_rows(
+    lang: Language,
+    tiers: &[tiers::Tier],
+    sell_rate: f64,
+    usd_rate: f64,
+) -> Vec<(String, String)> {
+    let format_rate = |rate: f64| {
+        format!(
+            "{} / {} {}",
+            numbers::gbp(lang, rate),
+            numbers::usd(lang, rate * usd_rate),
+            t(lang, "pricelist.per_1k")
+        )
+    };
+
+    let mut rows = Vec::new();
+    match tiers.first() {
+        Some(first) if first.min_robux > 0 => rows.push((
+            tf(
+                lang,
+                "pricelist.under",
+                &[("min", &numbers::integer(lang, first.min_robux as f64))],
+            ),
+            format_rate(sell_rate),
+        )),
+        Some(_) => {}
+        None => rows.push((
+            t(lang, "pricelist.standard").to_string(),
+            format_rate(sell_rate),
+        )),
+    }
+    for tier in tiers {
+        rows.push((
+            format!(
+                "{}+ {}",
+                numbers::integer(lang, tier.min_robux as f64),
+                emojis::robux()
+            ),
+            format_rate(tier.rate),
+        ));
+    }
+    rows
+}
+
+async fn handle_history_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let mut user = &command.user;
+    let mut count = 10;
+
+    for option in &command.data.options {
+        match (option.name.as_str(), &option.resolved) {
+            ("user", Some(CommandDataOptionValue::User(target, _))) => user = target,
+            ("count", Some(CommandDataOptionValue::Integer(value))) => {
+                count = (*value).clamp(1, 25) as usize
+            }
+            _ => {}
+        }
+    }
+
+    if user.id != command.user.id && !is_staff(command) {
+        return Err(t(lang, "error.staff_only_history").to_string());
+    }
+
+    let entries = storage(ctx)
+        .await
+        .history(command.guild_id.map(|id| id.0), user.id.0, count)
+        .await?;
+
+    let description = if entries.is_empty() {
+        t(lang, "history.empty").to_string()
+    } else {
+        entries
+            .iter()
+            .map(|entry| entry.summary(lang))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let embed = CreateEmbed::default()
+        .title(tf(lang, "history.title", &[("user", &user.name)]))
+        .description(description)
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_language_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let selected = command
+        .data
+        .options
+        .get_str("language")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let selected = Language::ALL
+        .into_iter()
+        .find(|language| language.code() == selected);
+
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| config.language = selected)
+        .await?;
+
+    let lang = language(ctx, command).await;
+    let description = match selected {
+        Some(selected) => tf(lang, "language.set", &[("language", &selected.name())]),
+        None => t(lang, "language.auto").to_string(),
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "language.title"))
+        .description(description)
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Schedules a note to be sent back to the user later, in the channel or by DM.
+async fn handle_remind_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let options = &command.data.options;
+    let string_option = |name: &str| {
+        options
+            .get_str(name)
+            .ok_or(t(lang, "error.invalid_options"))
+    };
+    let delay = parse::parse_duration(string_option("in")?, lang)?;
+    if delay > reminders::MAX_REMINDER_SECONDS {
+        return Err(tf(
+            lang,
+            "remind.too_long",
+            &[("days", &(reminders::MAX_REMINDER_SECONDS / (24 * 60 * 60)))],
+        ));
+    }
+    let note = string_option("note")?.trim().to_string();
+
+    let storage = storage(ctx).await;
+    let user_id = command.user.id.0;
+    if storage.reminders_for_user(user_id).await?.len() >= MAX_REMINDERS_PER_USER {
+        return Err(tf(
+            lang,
+            "remind.limit",
+            &[("max", &MAX_REMINDERS_PER_USER)],
+        ));
+    }
+    // Reminders set in DMs can only go back to DMs.
+    let dm = command.guild_id.is_none() || bool_option(options, "dm");
+    let now = rates::now();
+    let reminder = Reminder {
+        id: 0,
+        user_id,
+        guild_id: command.guild_id.map(|id| id.0),
+        channel_id: (!dm).then_some(command.channel_id.0),
+        note,
+        remind_at: now + delay,
+        created_at: now,
+        language: lang,
+    };
+    storage.add_reminder(reminder.clone()).await?;
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "remind.set"))
+        .description(tf(
+            lang,
+            if dm {
+                "remind.set_dm"
+            } else {
+                "remind.set_channel"
+            },
+            &[("at", &reminder.remind_at)],
+        ))
+        .field(t(lang, "remind.note"), &reminder.note, false);
+    send_ephemeral_embed_response(ctx, command, embed).await
+}
+
+/// Removes tiers, coupons and orders for good once they can't be undone.
+async fn purge_deleted_items(ctx: &Context) {
+    let before = undo::cutoff(rates::now());
+    if let Err(error) = storage(ctx).await.purge_deleted(before).await {
+        eprintln!("Error purging deleted items: {}", error);
+    }
+}
+
+/// Deletes calculations and closed orders past their guild's `/retention`.
+async fn purge_expired_records(ctx: &Context) {
+    if let Err(error) = storage(ctx).await.purge_expired(rates::now()).await {
+        eprintln!("Error purging expired records: {}", error);
+    }
+}
+
+/// Sends every reminder that has come due.
+async fn deliver_due_reminders(ctx: &Context) {
+    let reminders = match storage(ctx).await.take_due_reminders(rates::now()).await {
+        Ok(reminders) => reminders,
+        Err(error) => {
+            eprintln!("Error loading reminders: {}", error);
+            return;
+        }
+    };
+    for reminder in reminders {
+        deliver_reminder(ctx, &reminder).await;
+    }
+}
+
+/// Pings the user in the reminder's channel, or DMs them if it was set for DMs
+/// or the channel is gone.
+async fn deliver_reminder(ctx: &Context, reminder: &Reminder) {
+    let lang = reminder.language;
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "remind.title"))
+        .description(&reminder.note)
+        .footer(|footer| footer.text(t(lang, "remind.footer")))
+        .timestamp(
+            serenity::model::Timestamp::from_unix_timestamp(reminder.created_at as i64)
+                .unwrap_or_else(|_| serenity::model::Timestamp::now()),
+        );
+    guild_config(ctx, reminder.guild_id.map(GuildId))
+        .await
+        .theme
+        .apply(&mut embed);
+
+    let user = UserId(reminder.user_id);
+    if let Some(channel_id) = reminder.channel_id {
+        let result = ChannelId(channel_id)
+            .send_message(&ctx.http, |message| {
+                message.content(user.mention()).set_embed(embed.clone())
+            })
+            .await;
+        if result.is_ok() {
+            return;
+        }
+    }
+    let dm = match user.create_dm_channel(&ctx.http).await {
+        Ok(channel) => channel
+            .send_message(&ctx.http, |message| message.set_embed(embed))
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    };
+    if let Err(error) = dm {
+        eprintln!("Error delivering reminder {}: {}", reminder.id, error);
+    }
+}
+
+async fn handle_alert_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let user_id = command.user.id.0;
+
+    let storage = storage(ctx).await;
+    let mut embed = CreateEmbed::default();
+
+    match subcommand.name.as_str() {
+        "set" => {
+            let pair = subcommand
+                .options
+                .get_str("pair")
+                .ok_or(t(lang, "error.invalid_options"))?;
+            let threshold = subcommand
+                .options
+                .get_number("threshold")
+                .filter(|threshold| threshold.is_finite() && *threshold > 0.0)
+                .ok_or(t(lang, "error.invalid_options"))?;
+            let current_rate = rates(ctx)
+                .await
+                .read()
+                .await
+                .pair(pair)
+                .ok_or(t(lang, "alert.invalid_pair"))?;
+
+            if storage.alerts_for_user(user_id).await?.len() >= MAX_ALERTS_PER_USER {
+                return Err(tf(lang, "alert.limit", &[("max", &MAX_ALERTS_PER_USER)]));
+            }
+
+            let direction = Alert::direction_for(current_rate, threshold);
+            let id = storage
+                .add_alert(Alert {
+                    id: 0,
+                    user_id,
+                    guild_id: command.guild_id.map(|id| id.0),
+                    channel_id: command.channel_id.0,
+                    pair: pair.to_string(),
+                    threshold,
+                    direction,
+                    language: lang,
+                })
+                .await?;
+
+            embed.title(t(lang, "alert.created")).description(tf(
+                lang,
+                "alert.created_description",
+                &[
+                    ("id", &id),
+                    ("pair", &pair),
+                    ("direction", &direction_label(lang, direction)),
+                    ("threshold", &format!("{:.4}", threshold)),
+                    ("rate", &format!("{:.4}", current_rate)),
+                ],
+            ));
+        }
+        "list" => {
+            let alerts = storage.alerts_for_user(user_id).await?;
+            let description = if alerts.is_empty() {
+                t(lang, "alert.list_empty").to_string()
+            } else {
+                alerts
+                    .iter()
+                    .map(|alert| {
+                        format!(
+                            "`#{}` {} {} {:.4}",
+                            alert.id,
+                            alert.pair,
+                            direction_label(lang, alert.direction),
+                            alert.threshold
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            embed
+                .title(t(lang, "alert.list_title"))
+                .description(description);
+        }
+        "remove" => {
+            let id = subcommand
+                .options
+                .get_int("id")
+                .ok_or(t(lang, "error.invalid_options"))?;
+
+            if !storage.remove_alert(user_id, id).await? {
+                return Err(tf(lang, "alert.not_found", &[("id", &id)]));
+            }
+            embed.title(t(lang, "alert.removed")).description(tf(
+                lang,
+                "alert.removed_description",
+                &[("id", &id)],
+            ));
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+fn direction_label(lang: Language, direction: alerts::Direction) -> &'static str {
+    match direction {
+        alerts::Direction::Above => t(lang, "alert.above"),
+        alerts::Direction::Below => t(lang, "alert.below"),
+    }
+}
+
+/// Refreshes exchange rates and notifies users whose alerts were crossed.
+struct RateRefreshJob {
+    sources: Vec<ForexSource>,
+    started_at: u64,
+    /// So the log channel hears about stale rates once per outage.
+    reported_stale: bool,
+    crypto_outage: opslog::Outage,
+    forex_outage: opslog::Outage,
+}
+
+impl RateRefreshJob {
+    async fn new(ctx: &Context) -> Self {
+        let sources = ForexSource::from_env()
+            .unwrap_or_else(|error| {
+                eprintln!("{}, using the ECB", error);
+                ForexSource::Ecb
+            })
+            .with_fallback();
+        Self {
+            sources,
+            started_at: bot_stats(ctx).await.started_unix,
+            reported_stale: false,
+            crypto_outage: opslog::Outage::default(),
+            forex_outage: opslog::Outage::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl Job for RateRefreshJob {
+    async fn run(&mut self, ctx: &Context) {
+        let http = state(ctx).await.http.clone();
+        match crypto::fetch_gbp_prices(&http).await {
+            Ok(prices) => {
+                let fetched_at = rates::now();
+                let rates = rates(ctx).await;
+                let mut rates = rates.write().await;
+                rates.crypto_gbp = prices;
+                rates.crypto_updated_at = Some(fetched_at);
+                drop(rates);
+                if let Some(since) = self.crypto_outage.recover() {
+                    report_recovery(ctx, "Crypto prices fetched again", since).await;
+                }
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                if self.crypto_outage.fail(rates::now()) {
+                    report_error(ctx, "Crypto price fetch failed", &error, Vec::new()).await;
+                }
+            }
+        }
+
+        match rates::fetch_gbp_rates(&http, &self.sources).await {
+            Ok(fiat_gbp) => {
+                let snapshot = RateSnapshot {
+                    timestamp: rates::now(),
+                    gbp_to_usd: fiat_gbp["USD"],
+                };
+                {
+                    let rates = rates(ctx).await;
+                    let mut rates = rates.write().await;
+                    rates.gbp_to_usd = snapshot.gbp_to_usd;
+                    rates.updated_at = Some(snapshot.timestamp);
+                    rates.fiat_gbp = fiat_gbp;
+                }
+                self.reported_stale = false;
+                if let Some(since) = self.forex_outage.recover() {
+                    report_recovery(ctx, "Exchange rates fetched again", since).await;
+                }
+                if let Err(error) = storage(ctx).await.record_rate(snapshot).await {
+                    eprintln!("Error saving rate history: {}", error);
+                    report_error(ctx, "Rate history not saved", &error, Vec::new()).await;
+                }
+                update_presence(ctx).await;
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                if self.forex_outage.fail(rates::now()) {
+                    report_error(ctx, "Exchange rate fetch failed", &error, Vec::new()).await;
+                }
+                let updated_at = {
+                    let rates = rates(ctx).await;
+                    let rates = rates.read().await;
+                    rates.is_stale(self.started_at).then_some(rates.updated_at)
+                };
+                if let (Some(updated_at), false) = (updated_at, self.reported_stale) {
+                    let since = updated_at.map_or_else(
+                        || "never fetched".to_string(),
+                        |updated_at| format!("<t:{}:R>", updated_at),
+                    );
+                    report_error(
+                        ctx,
+                        "Exchange rates are stale",
+                        "Every provider has failed for several refreshes, so prices use the last rates fetched.",
+                        vec![("Last updated", since)],
+                    )
+                    .await;
+                    self.reported_stale = true;
+                }
+                return;
+            }
+        }
+
+        notify_triggered_alerts(ctx).await;
+    }
+}
+
+/// The activity shown under the bot's name, e.g. "Watching 1k R$ = £3.50 / $4.83",
+/// at the `GUILD_ID` guild's `/setrates` sell rate, or the default one without it.
+async fn rate_activity(ctx: &Context) -> Activity {
+    let command_guild = state(ctx).await.command_guild;
+    let gbp = guild_config(ctx, command_guild).await.shop_rates.sell;
+    let usd_rate = gbp_to_usd(ctx).await;
+    Activity::watching(format!(
+        "1k R$ = {} / {}",
+        numbers::gbp(Language::En, gbp),
+        numbers::usd(Language::En, gbp * usd_rate)
+    ))
+}
+
+/// Shows the current rate in every shard's presence.
+async fn update_presence(ctx: &Context) {
+    let activity = rate_activity(ctx).await;
+    let Some(manager) = state(ctx).await.shard_manager.clone() else {
+        ctx.set_activity(activity).await;
+        return;
+    };
+    let manager = manager.lock().await;
+    for runner in manager.runners.lock().await.values() {
+        runner.runner_tx.set_activity(Some(activity.clone()));
+    }
+}
+
+/// Logs each shard's connection stage and heartbeat latency.
+async fn report_shard_latency(ctx: &Context) {
+    let Some(manager) = state(ctx).await.shard_manager.clone() else {
+        return;
+    };
+    let manager = manager.lock().await;
+    let runners = manager.runners.lock().await;
+    let mut shards: Vec<_> = runners.iter().collect();
+    shards.sort_by_key(|(id, _)| id.0);
+    for (id, runner) in shards {
+        match runner.latency {
+            Some(latency) => println!(
+                "Shard {}: {}, {} ms",
+                id.0 + 1,
+                runner.stage,
+                latency.as_millis()
+            ),
+            None => println!("Shard {}: {}, no heartbeat yet", id.0 + 1, runner.stage),
+        }
+    }
+}
+
+async fn notify_triggered_alerts(ctx: &Context) {
+    // A copy, so the lock isn't held while alerts are taken and DMs are sent.
+    let rates = RateSnapshot {
+        timestamp: rates::now(),
+        gbp_to_usd: rates(ctx).await.read().await.gbp_to_usd,
+    };
+    let triggered = match storage(ctx)
+        .await
+        .take_triggered_alerts(|pair| rates.pair(pair))
+        .await
+    {
+        Ok(triggered) => triggered,
+        Err(error) => {
+            eprintln!("Error checking rate alerts: {}", error);
+            return;
+        }
+    };
+
+    for alert in triggered {
+        let lang = alert.language;
+        let rate = rates.pair(&alert.pair).unwrap_or_default();
+        let mut embed = CreateEmbed::default();
+        embed.title(t(lang, "alert.triggered")).description(tf(
+            lang,
+            "alert.triggered_description",
+            &[
+                ("pair", &alert.pair),
+                ("rate", &format!("{:.4}", rate)),
+                ("direction", &direction_label(lang, alert.direction)),
+                ("threshold", &format!("{:.4}", alert.threshold)),
+            ],
+        ));
+        guild_config(ctx, alert.guild_id.map(GuildId))
+            .await
+            .theme
+            .apply(&mut embed);
+
+        let user = UserId(alert.user_id);
+        let dm = match user.create_dm_channel(&ctx.http).await {
+            Ok(channel) => channel
+                .send_message(&ctx.http, |message| message.set_embed(embed.clone()))
+                .await
+                .map(|_| ()),
+            Err(error) => Err(error),
+        };
+
+        if dm.is_err() {
+            if let Err(error) = ChannelId(alert.channel_id)
+                .send_message(&ctx.http, |message| {
+                    message.content(user.mention()).set_embed(embed)
+                })
+                .await
+            {
+                eprintln!("Error delivering rate alert {}: {}", alert.id, error);
+            }
+        }
+    }
+}
+
+async fn handle_priceboard_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let previous = guild_config(ctx, Some(guild_id)).await.priceboard;
+    if let Some(board) = &previous {
+        // Best effort: the old board may already have been deleted by hand.
+        let _ = ChannelId(board.channel_id)
+            .delete_message(&ctx.http, board.message_id)
+            .await;
+    }
+
+    let mut embed = CreateEmbed::default();
+    match subcommand.name.as_str() {
+        "enable" => {
+            let mut channel_id = command.channel_id;
+            let mut refresh_minutes = priceboard::DEFAULT_REFRESH_MINUTES;
+            for option in &subcommand.options {
+                match (option.name.as_str(), &option.resolved) {
+                    ("channel", Some(CommandDataOptionValue::Channel(channel))) => {
+                        channel_id = channel.id
+                    }
+                    ("minutes", Some(CommandDataOptionValue::Integer(minutes))) => {
+                        refresh_minutes = (*minutes).clamp(1, 1440) as u64
+                    }
+                    _ => {}
+                }
+            }
+
+            let message_id = post_priceboard(ctx, guild_id, channel_id, lang)
+                .await
+                .map_err(|_| {
+                    tf(
+                        lang,
+                        "priceboard.post_failed",
+                        &[("channel", &channel_id.mention())],
+                    )
+                })?;
+            storage(ctx)
+                .await
+                .update_guild(guild_id, |config| {
+                    config.priceboard = Some(PriceBoard {
+                        channel_id: channel_id.0,
+                        message_id: message_id.0,
+                        refresh_minutes,
+                        updated_at: rates::now(),
+                        language: lang,
+                    })
+                })
+                .await?;
+
+            embed.title(t(lang, "priceboard.enabled")).description(tf(
+                lang,
+                "priceboard.enabled_description",
+                &[
+                    ("channel", &channel_id.mention()),
+                    ("minutes", &refresh_minutes),
+                ],
+            ));
+        }
+        "disable" => {
+            if previous.is_none() {
+                return Err(t(lang, "priceboard.not_enabled").to_string());
+            }
+            storage(ctx)
+                .await
+                .update_guild(guild_id, |config| config.priceboard = None)
+                .await?;
+
+            embed
+                .title(t(lang, "priceboard.disabled"))
+                .description(t(lang, "priceboard.disabled_description"));
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn priceboard_embed(ctx: &Context, guild_id: GuildId, lang: Language) -> CreateEmbed {
+    let config = guild_config(ctx, Some(guild_id)).await;
+    let usd_rate = gbp_to_usd(ctx).await;
+    let mut description = pricelist_lines(lang, &config.tiers, config.shop_rates.sell, usd_rate);
+    description.push(String::new());
+    description.push(tf(
+        lang,
+        "priceboard.updated",
+        &[("time", &format!("<t:{}:R>", rates::now()))],
+    ));
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "priceboard.title"))
+        .description(description.join("\n"));
+
+    for &amount in &priceboard::BOARD_AMOUNTS {
+        let base_rate = pricing::base_rate(&config.tiers, &config.shop_rates, amount, None);
+        let before_tax = amount as f64 * DeliveryMethod::Gamepass.rate(base_rate, false);
+        let after_tax = amount as f64 * DeliveryMethod::Gamepass.rate(base_rate, true);
+        embed.field(
+            numbers::robux(lang, amount as f64),
+            format!(
+                "b/t: {} / {}\na/t: {} / {}",
+                numbers::gbp(lang, before_tax),
+                numbers::usd(lang, before_tax * usd_rate),
+                numbers::gbp(lang, after_tax),
+                numbers::usd(lang, after_tax * usd_rate)
+            ),
+            true,
+        );
+    }
+    stamp_stale_rates(ctx, lang, &mut embed).await;
+
+    config.theme.apply(&mut embed);
+    embed
+}
+
+/// Posts a fresh price board in `channel_id` and pins it.
+async fn post_priceboard(
+    ctx: &Context,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    lang: Language,
+) -> Result<MessageId, String> {
+    let embed = priceboard_embed(ctx, guild_id, lang).await;
+    let message = channel_id
+        .send_message(&ctx.http, |message| message.set_embed(embed))
+        .await
+        .map_err(|e| format!("Error posting price board: {:?}", e))?;
+
+    if let Err(error) = message.pin(&ctx.http).await {
+        eprintln!("Error pinning price board: {}", error);
+    }
+    Ok(message.id)
+}
+
+/// Pings the buyer and ticket staff in each group payout ticket whose hold has
+/// ended, so the payout isn't forgotten.
+#[cfg(feature = "orders")]
+async fn notify_ended_payout_holds(ctx: &Context) {
+    let now = rates::now();
+    let storage = storage(ctx).await;
+    let holds = match storage.due_payout_holds(now).await {
+        Ok(holds) => holds,
+        Err(error) => {
+            eprintln!("Error loading payout holds: {}", error);
+            return;
+        }
+    };
+    for hold in holds {
+        let guild_id = GuildId(hold.guild_id);
+        let config = guild_config(ctx, Some(guild_id)).await;
+        let lang = guild_language(ctx, Some(guild_id), None).await;
+        let mut content = UserId(hold.buyer_id).mention().to_string();
+        if let Some(role) = config.tickets.staff_role {
+            content.push_str(&format!(" {}", RoleId(role).mention()));
+        }
+        let mut embed = CreateEmbed::default();
+        embed
+            .title(tf(lang, "hold.over", &[("id", &hold.order_id)]))
+            .description(tf(
+                lang,
+                "hold.over_description",
+                &[("days", &payouts::GROUP_PAYOUT_HOLD_DAYS)],
+            ));
+        config.theme.apply(&mut embed);
+
+        let result = ChannelId(hold.channel_id)
+            .send_message(&ctx.http, |message| {
+                message.content(content).set_embed(embed)
+            })
+            .await;
+        if let Err(why) = result {
+            eprintln!(
+                "Cannot post payout hold notice for order {}: {}",
+                hold.order_id, why
+            );
+        }
+        // Marked either way: a deleted ticket would fail every minute otherwise.
+        if let Err(error) = storage.mark_payout_hold_notified(hold.order_id, now).await {
+            eprintln!("Error marking payout hold notified: {}", error);
+        }
+    }
+}
+
+/// Edits every due price board in place, reposting boards whose message is gone.
+async fn refresh_due_priceboards(ctx: &Context) {
+    let now = rates::now();
+    let boards = match storage(ctx).await.priceboards().await {
+        Ok(boards) => boards,
+        Err(error) => {
+            eprintln!("Error loading price boards: {}", error);
+            return;
+        }
+    };
+    for (guild_id, board) in boards {
+        if !board.is_due(now) {
+            continue;
+        }
+
+        let config = guild_config(ctx, Some(guild_id)).await;
+        let lang = config.language.unwrap_or(board.language);
+        // Posted outside an interaction, so the guild's rounding, exchange rate
+        // and emojis are set here.
+        let rounding = config.rounding;
+        let forex_override = config.forex_override;
+        let embed = numbers::with_rounding(
+            rounding,
+            rates::with_forex_override(
+                forex_override,
+                emojis::with_emojis(config.emojis.clone(), priceboard_embed(ctx, guild_id, lang)),
+            ),
+        )
+        .await;
+        let channel_id = ChannelId(board.channel_id);
+        let message_id = match channel_id
+            .edit_message(&ctx.http, board.message_id, |message| {
+                message.set_embed(embed)
+            })
+            .await
+        {
+            Ok(message) => message.id,
+            Err(_) => match numbers::with_rounding(
+                rounding,
+                rates::with_forex_override(
+                    forex_override,
+                    emojis::with_emojis(
+                        config.emojis.clone(),
+                        post_priceboard(ctx, guild_id, channel_id, lang),
+                    ),
+                ),
+            )
+            .await
+            {
+                Ok(message_id) => message_id,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    continue;
+                }
+            },
+        };
+
+        let result = storage(ctx)
+            .await
+            .update_guild(guild_id, |config| {
+                if let Some(board) = &mut config.priceboard {
+                    board.message_id = message_id.0;
+                    board.updated_at = now;
+                }
+            })
+            .await;
+        if let Err(error) = result {
+            eprintln!("Error saving price board: {}", error);
+        }
+    }
+}
+
+async fn handle_ratechart_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let option = |name: &str| command.data.options.get_str(name);
+    let pair = option("pair")
+        .filter(|pair| rates::PAIRS.contains(pair))
+        .ok_or(t(lang, "alert.invalid_pair"))?;
+    let period = option("period")
+        .and_then(Period::parse)
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let since = rates::now().saturating_sub(period.seconds());
+    let points: Vec<(u64, f64)> = storage(ctx)
+        .await
+        .rate_history(since)
+        .await?
+        .iter()
+        .filter_map(|snapshot| Some((snapshot.timestamp, snapshot.pair(pair)?)))
+        .collect();
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) if points.len() >= 2 => (first.1, last.1),
+        _ => return Err(t(lang, "ratechart.not_enough_data").to_string()),
+    };
+    let high = points.iter().map(|point| point.1).fold(f64::MIN, f64::max);
+    let low = points.iter().map(|point| point.1).fold(f64::MAX, f64::min);
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let color = config.theme.color.unwrap_or(theme::DEFAULT_COLOR);
+    let png = ratechart::render(&points, color)?;
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(
+            lang,
+            "ratechart.title",
+            &[("pair", &pair), ("period", &t(lang, period.label_key()))],
+        ))
+        .field(t(lang, "ratechart.current"), format!("{:.4}", last), true)
+        .field(t(lang, "ratechart.high"), format!("{:.4}", high), true)
+        .field(t(lang, "ratechart.low"), format!("{:.4}", low), true)
+        .field(
+            t(lang, "ratechart.change"),
+            format!("{:+.2}%", (last - first) / first * 100.0),
+            true,
+        );
+
+    send_embed_with_image(ctx, command, embed, png, "ratechart.png").await
+}
+
+#[cfg(feature = "orders")]
+async fn handle_buy_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    refuse_blacklisted(ctx, command, lang).await?;
+
+    let option = |name: &str| command.data.options.get_str(name);
+    let after_tax = match option("type") {
+        Some("b/t") => false,
+        Some("a/t") => true,
+        _ => return Err(t(lang, "price.invalid_type").to_string()),
+    };
+    let robux = parse::parse_robux_amount(
+        option("amount").ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )
+    .and_then(|amount| validation::validate_robux(amount, lang))?;
+    let method = option("method")
+        .map_or(Some(DeliveryMethod::Gamepass), DeliveryMethod::parse)
+        .ok_or(t(lang, "price.invalid_method"))?;
+    let crypto = option("crypto")
+        .map(|code| Crypto::parse(code).ok_or(t(lang, "error.invalid_currency")))
+        .transpose()?;
+    let coupon_code = match option("coupon") {
+        Some(code) => Some(usable_coupon(ctx, command, lang, code).await?.code),
+        None => None,
+    };
+
+    let channel_id = open_order(
+        ctx,
+        guild_id,
+        &command.user,
+        command
+            .member
+            .as_ref()
+            .map_or(&[][..], |member| &member.roles),
+        lang,
+        OrderRequest {
+            robux,
+            after_tax,
+            method,
+            crypto,
+            coupon_code,
+            payment: None,
+            roblox_username: None,
+        },
+    )
+    .await?;
+
+    let embed = CreateEmbed::default()
+        .title(t(lang, "ticket.created"))
+        .description(tf(
+            lang,
+            "ticket.created_description",
+            &[("channel", &channel_id.mention())],
+        ))
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// What a buyer asked for with `/buy` or the order form.
+#[cfg(feature = "orders")]
+struct OrderRequest {
+    robux: u64,
+    after_tax: bool,
+    method: DeliveryMethod,
+    crypto: Option<Crypto>,
+    /// Checked as usable, but only redeemed once the ticket exists.
+    coupon_code: Option<String>,
+    /// How the buyer said they'll pay, if they picked one.
+    payment: Option<PaymentMethod>,
+    roblox_username: Option<String>,
+}
+
+/// Opens a ticket channel for `buyer`, records the order and posts the quote in
+/// it, returning the channel.
+#[cfg(feature = "orders")]
+async fn open_order(
+    ctx: &Context,
+    guild_id: GuildId,
+    buyer: &User,
+    buyer_roles: &[RoleId],
+    lang: Language,
+    request: OrderRequest,
+) -> Result<ChannelId, String> {
+    let OrderRequest {
+        robux,
+        after_tax,
+        method,
+        crypto,
+        coupon_code,
+        payment,
+        roblox_username,
+    } = request;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    validation::validate_order_size(robux, &config.order_limits, lang)?;
+    let staff_role = config
+        .tickets
+        .staff_role
+        .ok_or(t(lang, "ticket.not_configured"))?;
+    let link = storage(ctx).await.roblox_link(buyer.id.0).await?;
+    let roblox_username =
+        roblox_username.or_else(|| link.as_ref().map(|link| link.roblox_username.clone()));
+    if let Some(available) = available_stock(ctx, guild_id).await? {
+        if robux > available {
+            return Err(tf(
+                lang,
+                "stock.insufficient",
+                &[("available", &numbers::integer(lang, available as f64))],
+            ));
+        }
+    }
+    let crypto_price = match crypto {
+        Some(coin) => Some(crypto_price(ctx, lang, coin).await?.0),
+        None => None,
+    };
+
+    let discount = member_discount(ctx, guild_id, buyer.id, buyer_roles, &config).await?;
+    let base_rate = pricing::base_rate(&config.tiers, &config.shop_rates, robux, discount.as_ref());
+    let usd_rate = gbp_to_usd(ctx).await;
+
+    let bot_id = ctx
+        .http
+        .get_current_user()
+        .await
+        .map_err(|e| format!("Error fetching current user: {:?}", e))?
+        .id;
+    let access = Permissions::VIEW_CHANNEL
+        | Permissions::SEND_MESSAGES
+        | Permissions::READ_MESSAGE_HISTORY
+        | Permissions::ATTACH_FILES
+        | Permissions::EMBED_LINKS;
+    let overwrites = vec![
+        PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::VIEW_CHANNEL,
+            kind: PermissionOverwriteType::Role(RoleId(guild_id.0)),
+        },
+        PermissionOverwrite {
+            allow: access,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Member(buyer.id),
+        },
+        PermissionOverwrite {
+            allow: access | Permissions::MANAGE_CHANNELS,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Member(bot_id),
+        },
+        PermissionOverwrite {
+            allow: access,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Role(RoleId(staff_role)),
+        },
+    ];
+
+    let channel = guild_id
+        .create_channel(&ctx.http, |channel| {
+            channel
+                .name(tickets::channel_name(&buyer.name))
+                .kind(ChannelType::Text)
+                .permissions(overwrites);
+            if let Some(category) = config.tickets.category {
+                channel.category(ChannelId(category));
+            }
+            channel
+        })
+        .await
+        .map_err(|e| {
+            eprintln!("Error creating ticket channel: {:?}", e);
+            t(lang, "ticket.create_failed").to_string()
+        })?;
+
+    // Redeemed only once the ticket exists so a failed channel doesn't use it up.
+    let coupon = match &coupon_code {
+        Some(code) => match storage(ctx)
+            .await
+            .redeem_coupon(guild_id.0, code, rates::now())
+            .await
+        {
+            Ok(Some(coupon)) => Some(coupon),
+            Ok(None) => {
+                let _ = channel.delete(&ctx.http).await;
+                return Err(tf(lang, "coupon.invalid", &[("code", code)]));
+            }
+            Err(error) => {
+                let _ = channel.delete(&ctx.http).await;
+                return Err(error);
+            }
+        },
+        None => None,
+    };
+    let price = pricing::quote(
+        robux as f64,
+        base_rate,
+        method,
+        after_tax,
+        coupon.as_ref(),
+        config.vat.as_ref(),
+    );
+    let gbp = price.gbp();
+    let usd = gbp * usd_rate;
+
+    let mut order = Order {
+        id: 0,
+        guild_id: guild_id.0,
+        buyer_id: buyer.id.0,
+        channel_id: channel.id.0,
+        robux,
+        after_tax,
+        method,
+        gbp,
+        usd,
+        tax_gbp: price.tax,
+        rate: price.rate,
+        frozen_rates: Some(FrozenRates {
+            base_rate,
+            gbp_to_usd: usd_rate,
+            roblox_fee: method.fee(),
+            vat_percent: config.vat.as_ref().map_or(0.0, |vat| vat.rate),
+        }),
+        status: OrderStatus::Open,
+        created_at: rates::now(),
+        completed_at: None,
+        roblox_username: roblox_username.clone(),
+    };
+    order.id = match storage(ctx).await.create_order(order.clone()).await {
+        Ok(id) => id,
+        Err(error) => {
+            let _ = channel.delete(&ctx.http).await;
+            return Err(error);
+        }
+    };
+    let hold = (method == DeliveryMethod::GroupPayout).then(|| {
+        PayoutHold::starting(
+            order.id,
+            guild_id.0,
+            channel.id.0,
+            buyer.id.0,
+            order.created_at,
+        )
+    });
+    if let Some(hold) = &hold {
+        if let Err(error) = storage(ctx).await.create_payout_hold(hold.clone()).await {
+            eprintln!(
+                "Error starting payout hold for order {}: {}",
+                order.id, error
+            );
+        }
+    }
+    webhooks::dispatch(&config.webhooks, OrderEvent::Created, &order);
+
+    let mut quote = order_embed(lang, &order);
+    if let Some(discount) = &discount {
+        let (label, value) = discount_label(lang, discount);
+        quote.field(label, value, true);
+    }
+    if let Some(coupon) = &coupon {
+        quote.field(t(lang, "coupon.label"), coupon_summary(lang, coupon), true);
+    }
+    if let Some(username) = &roblox_username {
+        quote.field(t(lang, "orderform.roblox_username"), username, true);
+    }
+    if let Some(payment) = payment {
+        quote.field(t(lang, "orderform.payment"), payment.name(), true);
+    }
+    if let Some(hold) = &hold {
+        quote.field(
+            t(lang, "hold.eligible"),
+            format!("<t:{}:R>", hold.eligible_at),
+            true,
+        );
+    }
+    let mut qr_code = None;
+    if let Some((coin, price)) = crypto.zip(crypto_price) {
+        quote.field(
+            tf(lang, "common.amount_in", &[("currency", &coin.symbol())]),
+            numbers::decimal(lang, order.gbp / price, 8),
+            true,
+        );
+        qr_code = crypto_qr_code(&config.payment_handles, coin, order.gbp / price)?;
+    }
+    // Only link the method the buyer picked, if the guild has a handle for it.
+    let handles: Vec<_> = config
+        .payment_handles
+        .iter()
+        .filter(|handle| payment.is_none_or(|payment| handle.method == payment))
+        .cloned()
+        .collect();
+    if let Some(links) = payment_links(ctx, &handles, order.gbp, order.usd).await {
+        quote.field(t(lang, "payment.pay"), links, false);
+    }
+    if qr_code.is_some() {
+        quote.image(format!("attachment://{}", qr::FILENAME));
+    }
+    config.theme.apply(&mut quote);
+    channel
+        .id
+        .send_message(&ctx.http, |message| {
+            message
+                .content(format!(
+                    "{} {}",
+                    buyer.mention(),
+                    RoleId(staff_role).mention()
+                ))
+                .set_embed(quote);
+            if let Some(png) = qr_code {
+                message.add_file(AttachmentType::Bytes {
+                    data: png.into(),
+                    filename: qr::FILENAME.to_string(),
+                });
+            }
+            message
+        })
+        .await
+        .map_err(|e| format!("Error posting order quote: {:?}", e))?;
+    if let Some(link) = link {
+        post_buyer_risk(ctx, channel.id, link, lang);
+    }
+
+    Ok(channel.id)
+}
+
+/// Posts the age, friends and badges of the buyer's linked Roblox account in
+/// their ticket, flagging anything that looks like a throwaway account. Done in
+/// the background so a slow Roblox API doesn't hold up the ticket.
+#[cfg(feature = "orders")]
+fn post_buyer_risk(ctx: &Context, channel_id: ChannelId, link: RobloxLink, lang: Language) {
+    let ctx = ctx.clone();
+    tokio::spawn(async move {
+        let signals = match roblox::account_signals(link.roblox_user_id).await {
+            Ok(signals) => signals,
+            Err(error) => {
+                eprintln!(
+                    "Error checking Roblox account {}: {}",
+                    link.roblox_user_id, error
+                );
+                return;
+            }
+        };
+        let now = rates::now();
+        let flags = signals.flags(now);
+
+        let mut embed = CreateEmbed::default();
+        embed
+            .title(t(lang, "risk.title"))
+            .description(tf(
+                lang,
+                "risk.account",
+                &[
+                    ("username", &link.roblox_username),
+                    ("id", &link.roblox_user_id.to_string()),
+                ],
+            ))
+            .field(
+                t(lang, "risk.age"),
+                tf(
+                    lang,
+                    "risk.age_value",
+                    &[
+                        (
+                            "days",
+                            &numbers::integer(lang, signals.age_days(now) as f64),
+                        ),
+                        ("created_at", &signals.created_at.to_string()),
+                    ],
+                ),
+                true,
+            )
+            .field(
+                t(lang, "risk.friends"),
+                numbers::integer(lang, signals.friends as f64),
+                true,
+            )
+            .field(
+                t(lang, "risk.badges"),
+                numbers::integer(lang, signals.badges as f64),
+                true,
+            );
+        if flags.is_empty() {
+            embed.color(risk::CLEAR_COLOR);
+        } else {
+            embed.color(risk::FLAGGED_COLOR).field(
+                t(lang, "risk.flags"),
+                flags
+                    .iter()
+                    .map(|flag| format!("⚠️ {}", flag.name(lang)))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                false,
+            );
+        }
+        if let Err(why) = channel_id
+            .send_message(&ctx.http, |message| message.set_embed(embed))
+            .await
+        {
+            eprintln!("Cannot post buyer risk check: {}", why);
+        }
+    });
+}
+
+#[cfg(feature = "orders")]
+fn order_embed(lang: Language, order: &Order) -> CreateEmbed {
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(lang, "order.title", &[("id", &order.id)]))
+        .description(tf(
+            lang,
+            "order.locked_in",
+            &[("buyer", &UserId(order.buyer_id).mention())],
+        ))
+        .field(
+            t(lang, "price.amount"),
+            numbers::robux(lang, order.robux as f64),
+            true,
+        )
+        .field(
+            t(lang, "price.conversion_type"),
+            if order.after_tax { "a/t" } else { "b/t" },
+            true,
+        )
+        .field(t(lang, "price.method"), order.method.name(lang), true)
+        .field(
+            order.method.listing_label(lang),
+            numbers::robux(
+                lang,
+                order
+                    .method
+                    .listing_price(order.robux as f64, order.after_tax) as f64,
+            ),
+            true,
+        )
+        .field(
+            tf(lang, "common.amount_in", &[("currency", &"GBP")]),
+            numbers::gbp(lang, order.gbp),
+            true,
+        )
+        .field(
+            tf(lang, "common.amount_in", &[("currency", &"USD")]),
+            numbers::usd(lang, order.usd),
+            true,
+        )
+        .field(
+            t(lang, "price.rate"),
+            format!(
+                "{} / 1k {}",
+                numbers::gbp(lang, order.rate * 1000.0),
+                emojis::robux()
+            ),
+            true,
+        );
+    if order.tax_gbp > 0.0 {
+        embed
+            .field(
+                t(lang, "vat.excluding"),
+                numbers::gbp(lang, order.gbp - order.tax_gbp),
+                true,
+            )
+            .field(
+                t(lang, "vat.label"),
+                numbers::gbp(lang, order.tax_gbp),
+                true,
+            );
+    }
+    embed
+}
+
+#[cfg(feature = "orders")]
+async fn handle_ticket_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    match subcommand.name.as_str() {
+        "setup" => {
+            if !is_staff(command) {
+                return Err(t(lang, "error.manage_guild_only").to_string());
+            }
+
+            let mut settings = tickets::TicketSettings::default();
+            for option in &subcommand.options {
+                match (option.name.as_str(), &option.resolved) {
+                    ("staff_role", Some(CommandDataOptionValue::Role(role))) => {
+                        settings.staff_role = Some(role.id.0)
+                    }
+                    ("category", Some(CommandDataOptionValue::Channel(channel))) => {
+                        settings.category = Some(channel.id.0)
+                    }
+                    ("log_channel", Some(CommandDataOptionValue::Channel(channel))) => {
+                        settings.log_channel = Some(channel.id.0)
+                    }
+                    ("middleman_role", Some(CommandDataOptionValue::Role(role))) => {
+                        settings.middleman_role = Some(role.id.0)
+                    }
+                    _ => {}
+                }
+            }
+            let staff_role = settings
+                .staff_role
+                .ok_or(t(lang, "error.invalid_options"))?;
+
+            let none = t(lang, "common.none").to_string();
+            let mut description = tf(
+                lang,
+                "ticket.setup_description",
+                &[
+                    ("role", &RoleId(staff_role).mention()),
+                    (
+                        "category",
+                        &settings
+                            .category
+                            .map_or(none.clone(), |id| ChannelId(id).mention().to_string()),
+                    ),
+                    (
+                        "log",
+                        &settings
+                            .log_channel
+                            .map_or(none, |id| ChannelId(id).mention().to_string()),
+                    ),
+                ],
+            );
+            if let Some(role) = settings.middleman_role {
+                description.push('\n');
+                description.push_str(&tf(
+                    lang,
+                    "ticket.setup_middleman",
+                    &[("role", &RoleId(role).mention())],
+                ));
+            }
+            storage(ctx)
+                .await
+                .update_guild(guild_id, |config| config.tickets = settings)
+                .await?;
+
+            let embed = CreateEmbed::default()
+                .title(t(lang, "ticket.setup"))
+                .description(description)
+                .clone();
+            send_embed_response(ctx, command, embed).await
+        }
+        "close" => close_ticket(ctx, command, lang).await,
+        _ => Err(t(lang, "error.invalid_options").to_string()),
+    }
+}
+
+#[cfg(feature = "orders")]
+async fn close_ticket(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    lang: Language,
+) -> Result<(), String> {
+    let storage = storage(ctx).await;
+    let order = storage
+        .open_order_for_channel(command.channel_id.0)
+        .await?
+        .ok_or(t(lang, "ticket.not_ticket"))?;
+    let config = guild_config(ctx, command.guild_id).await;
+    if order.buyer_id != command.user.id.0 && !is_ticket_staff(command, &config) {
+        return Err(t(lang, "ticket.not_allowed").to_string());
+    }
+
+    let embed = CreateEmbed::default()
+        .title(t(lang, "ticket.closed"))
+        .description(t(lang, "ticket.closed_description"))
+        .clone();
+    send_embed_response(ctx, command, embed).await?;
+
+    if let Some(log_channel) = config.tickets.log_channel {
+        let messages = fetch_ticket_messages(ctx, command.channel_id).await?;
+        let mut embed = CreateEmbed::default();
+        embed
+            .title(tf(lang, "ticket.transcript_title", &[("id", &order.id)]))
+            .field(
+                t(lang, "ticket.buyer"),
+                UserId(order.buyer_id).mention(),
+                true,
+            )
+            .field(t(lang, "ticket.closed_by"), command.user.mention(), true)
+            .field(
+                t(lang, "price.amount"),
+                format!(
+                    "{} ({} / {})",
+                    numbers::robux(lang, order.robux as f64),
+                    numbers::gbp(lang, order.gbp),
+                    numbers::usd(lang, order.usd)
+                ),
+                true,
+            );
+        config.theme.apply(&mut embed);
+
+        ChannelId(log_channel)
+            .send_message(&ctx.http, |message| {
+                message.set_embed(embed).add_file(AttachmentType::Bytes {
+                    data: tickets::transcript(&messages).into_bytes().into(),
+                    filename: format!("transcript-{}.txt", order.id),
+                })
+            })
+            .await
+            .map_err(|e| format!("Error posting ticket transcript: {:?}", e))?;
+    }
+
+    storage.close_order(order.id, rates::now()).await?;
+    let order = Order {
+        status: OrderStatus::Closed,
+        ..order
+    };
+    webhooks::dispatch(&config.webhooks, OrderEvent::Closed, &order);
+    command
+        .channel_id
+        .delete(&ctx.http)
+        .await
+        .map_err(|e| format!("Error deleting ticket channel: {:?}", e))?;
+    Ok(())
+}
+
+/// Fetches up to `tickets::MAX_TRANSCRIPT_MESSAGES` messages from `channel_id`, oldest first.
+#[cfg(feature = "orders")]
+async fn fetch_ticket_messages(
+    ctx: &Context,
+    channel_id: ChannelId,
+) -> Result<Vec<Message>, String> {
+    let mut messages: Vec<Message> = Vec::new();
+
+    while messages.len() < tickets::MAX_TRANSCRIPT_MESSAGES {
+        let before = messages.last().map(|message| message.id);
+        let page = channel_id
+            .messages(&ctx.http, |request| {
+                if let Some(before) = before {
+                    request.before(before);
+                }
+                request.limit(100)
+            })
+            .await
+            .map_err(|e| format!("Error fetching ticket messages: {:?}", e))?;
+
+        let done = page.len() < 100;
+        messages.extend(page);
+        if done {
+            break;
+        }
+    }
+
+    messages.reverse();
+    Ok(messages)
+}
+
+async fn handle_vouch_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+
+    let mut seller = None;
+    let mut stars = None;
+    let mut comment = None;
+    for option in &command.data.options {
+        match (option.name.as_str(), &option.resolved) {
+            ("seller", Some(CommandDataOptionValue::User(user, _))) => seller = Some(user),
+            ("stars", Some(CommandDataOptionValue::Integer(value))) => stars = Some(*value),
+            ("comment", Some(CommandDataOptionValue::String(value))) => comment = Some(value),
+            _ => {}
+        }
+    }
+    let seller = seller.ok_or(t(lang, "error.invalid_options"))?;
+    let stars = stars
+        .filter(|stars| (1..=i64::from(vouches::MAX_STARS)).contains(stars))
+        .ok_or(t(lang, "error.invalid_options"))? as u8;
+    let comment = comment.ok_or(t(lang, "error.invalid_options"))?;
+    if seller.id == command.user.id {
+        return Err(t(lang, "vouch.self").to_string());
+    }
+
+    let storage = storage(ctx).await;
+    let order_id = storage
+        .unvouched_order(guild_id.0, command.user.id.0, seller.id.0)
+        .await?
+        .ok_or(t(lang, "vouch.no_order"))?;
+    let vouch = Vouch {
+        guild_id: guild_id.0,
+        order_id,
+        buyer_id: command.user.id.0,
+        seller_id: seller.id.0,
+        stars,
+        comment: comment.clone(),
+        created_at: rates::now(),
+    };
+    storage.add_vouch(vouch.clone()).await?;
+
+    let embed = vouch_embed(lang, &vouch);
+    post_vouch(ctx, guild_id, &embed).await;
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Posts a PDF receipt for a just-completed order in the ticket and DMs a copy
+/// to the buyer.
+#[cfg(feature = "orders")]
+async fn send_receipt(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    config: &GuildConfig,
+    order: &Order,
+    payment: Option<PaymentMethod>,
+    lang: Language,
+) {
+    let buyer = match UserId(order.buyer_id).to_user(&ctx.http).await {
+        Ok(user) => format!("{} ({})", user.tag(), user.id),
+        Err(_) => order.buyer_id.to_string(),
+    };
+    let listing = order
+        .method
+        .listing_price(order.robux as f64, order.after_tax);
+    let mut rows = vec![
+        (
+            t(lang, "receipt.order").to_string(),
+            format!("#{}", order.id),
+        ),
+        (
+            t(lang, "receipt.date").to_string(),
+            receipt::date(order.completed_at.unwrap_or(order.created_at)),
+        ),
+        (t(lang, "ticket.buyer").to_string(), buyer),
+        (
+            t(lang, "receipt.seller").to_string(),
+            format!("{} ({})", command.user.tag(), command.user.id),
+        ),
+        (
+            t(lang, "price.amount").to_string(),
+            numbers::robux(lang, order.robux as f64),
+        ),
+        (
+            t(lang, "price.conversion_type").to_string(),
+            (if order.after_tax { "a/t" } else { "b/t" }).to_string(),
+        ),
+        (
+            t(lang, "price.method").to_string(),
+            order.method.name(lang).to_string(),
+        ),
+        (
+            order.method.listing_label(lang).to_string(),
+            numbers::robux(lang, listing as f64),
+        ),
+    ];
+    let roblox_fee = order
+        .frozen_rates
+        .map_or(order.method.fee(), |frozen| frozen.roblox_fee);
+    if roblox_fee > 0.0 {
+        rows.push((
+            t(lang, "receipt.roblox_fee").to_string(),
+            format!(
+                "{} ({}%)",
+                numbers::robux(lang, listing as f64 * roblox_fee),
+                numbers::integer(lang, roblox_fee * 100.0)
+            ),
+        ));
+    }
+    rows.push((
+        t(lang, "price.rate").to_string(),
+        format!(
+            "{} / 1k {}",
+            numbers::gbp(lang, order.rate * 1000.0),
+            emojis::robux()
+        ),
+    ));
+    if order.tax_gbp > 0.0 {
+        rows.push((
+            t(lang, "vat.excluding").to_string(),
+            numbers::gbp(lang, order.gbp - order.tax_gbp),
+        ));
+        rows.push((
+            t(lang, "vat.label").to_string(),
+            numbers::gbp(lang, order.tax_gbp),
+        ));
+    }
+    rows.push((
+        t(lang, "receipt.total").to_string(),
+        format!(
+            "{} / {}",
+            numbers::gbp(lang, order.gbp),
+            numbers::usd(lang, order.usd)
+        ),
+    ));
+    rows.push((
+        t(lang, "receipt.payment").to_string(),
+        payment.map_or("-", PaymentMethod::name).to_string(),
+    ));
+    let pdf = receipt::render(
+        &tf(lang, "receipt.title", &[("id", &order.id)]),
+        &rows,
+        t(lang, "receipt.footer"),
+    );
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(lang, "receipt.title", &[("id", &order.id)]))
+        .description(t(lang, "receipt.description"));
+    config.theme.apply(&mut embed);
+    let file = || AttachmentType::Bytes {
+        data: pdf.clone().into(),
+        filename: receipt::filename(order.id),
+    };
+
+    if let Err(why) = command
+        .channel_id
+        .send_message(&ctx.http, |message| {
+            message.set_embed(embed.clone()).add_file(file())
+        })
+        .await
+    {
+        eprintln!("Cannot post receipt: {}", why);
+    }
+    let dm = match UserId(order.buyer_id).create_dm_channel(&ctx.http).await {
+        Ok(channel) => channel
+            .send_message(&ctx.http, |message| {
+                message.set_embed(embed).add_file(file())
+            })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    };
+    if let Err(why) = dm {
+        eprintln!("Cannot DM receipt: {}", why);
+    }
+}
+
+/// Posts a new vouch to the guild's vouch channel, if it has one.
+async fn post_vouch(ctx: &Context, guild_id: GuildId, embed: &CreateEmbed) {
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if let Some(channel) = config.vouch_channel {
+        let mut embed = embed.clone();
+        config.theme.apply(&mut embed);
+        if let Err(error) = ChannelId(channel)
+            .send_message(&ctx.http, |message| message.set_embed(embed))
+            .await
+        {
+            eprintln!("Error posting vouch: {:?}", error);
+        }
+    }
+}
+
+/// Asks the buyer of a just-completed order to rate the seller, by DM or, if
+/// their DMs are closed, in the ticket.
+#[cfg(feature = "orders")]
+async fn prompt_review(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    config: &GuildConfig,
+    order: &Order,
+    lang: Language,
+) {
+    if !Feature::Vouches.is_on(config) {
+        return;
+    }
+    let seller_id = command.user.id.0;
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "review.title")).description(tf(
+        lang,
+        "review.prompt",
+        &[("id", &order.id), ("seller", &UserId(seller_id).mention())],
+    ));
+    config.theme.apply(&mut embed);
+
+    let dm = match UserId(order.buyer_id).create_dm_channel(&ctx.http).await {
+        Ok(channel) => channel
+            .send_message(&ctx.http, |message| {
+                message
+                    .set_embed(embed.clone())
+                    .components(|components| review_buttons(components, order.guild_id, seller_id))
+            })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    };
+    if dm.is_ok() {
+        return;
+    }
+    if let Err(why) = command
+        .channel_id
+        .send_message(&ctx.http, |message| {
+            message
+                .content(UserId(order.buyer_id).mention())
+                .set_embed(embed)
+                .components(|components| review_buttons(components, order.guild_id, seller_id))
+        })
+        .await
+    {
+        eprintln!("Cannot post review prompt: {}", why);
+    }
+}
+
+/// One button per rating. Their ids carry the guild and seller, so a press in a
+/// DM knows what it's rating.
+#[cfg(feature = "orders")]
+fn review_buttons(
+    components: &mut CreateComponents,
+    guild_id: u64,
+    seller_id: u64,
+) -> &mut CreateComponents {
+    components.create_action_row(|row| {
+        for stars in 1..=vouches::MAX_STARS {
+            row.create_button(|button| {
+                button
+                    .custom_id(format!(
+                        "{}:{}:{}:{}",
+                        REVIEW_BUTTON_ID, guild_id, seller_id, stars
+                    ))
+                    .style(ButtonStyle::Secondary)
+                    .label(format!("{} ★", stars))
+            });
+        }
+        row
+    })
+}
+
+/// The guild, seller and rating in a review button or modal id.
+#[cfg(feature = "orders")]
+fn parse_review_id(custom_id: &str) -> Option<(GuildId, u64, u8)> {
+    let mut parts = custom_id.split(':').skip(1);
+    let guild_id = parts.next()?.parse().ok()?;
+    let seller_id = parts.next()?.parse().ok()?;
+    let stars = parts
+        .next()?
+        .parse()
+        .ok()
+        .filter(|stars| (1..=vouches::MAX_STARS).contains(stars))?;
+    Some((GuildId(guild_id), seller_id, stars))
+}
+
+/// Opens the comment form for the rating picked on a review prompt.
+#[cfg(feature = "orders")]
+async fn handle_review_button(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+) -> Result<(), String> {
+    let (guild_id, seller_id, stars) = parse_review_id(&component.data.custom_id)
+        .ok_or_else(|| format!("Error parsing review id {}", component.data.custom_id))?;
+    let lang = guild_language(ctx, Some(guild_id), component.guild_locale.as_deref()).await;
+    refuse_disabled_feature(ctx, Some(guild_id), "vouch", lang).await?;
+    storage(ctx)
+        .await
+        .unvouched_order(guild_id.0, component.user.id.0, seller_id)
+        .await?
+        .ok_or(t(lang, "review.unavailable"))?;
+
+    component
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::Modal)
+                .interaction_response_data(|modal| {
+                    modal
+                        .custom_id(&component.data.custom_id)
+                        .title(tf(
+                            lang,
+                            "review.modal_title",
+                            &[("stars", &vouches::stars(stars))],
+                        ))
+                        .components(|components| {
+                            components.create_action_row(|row| {
+                                row.create_input_text(|input| {
+                                    input
+                                        .custom_id("comment")
+                                        .label(t(lang, "review.comment"))
+                                        .placeholder(t(lang, "review.comment_placeholder"))
+                                        .style(InputTextStyle::Paragraph)
+                                        .max_length(1000)
+                                        .required(false)
+                                })
+                            })
+                        })
+                })
+        })
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Records the vouch from a submitted review form and replaces the prompt with it.
+#[cfg(feature = "orders")]
+async fn handle_review_submit(ctx: &Context, modal: &ModalSubmitInteraction) -> Result<(), String> {
+    let (guild_id, seller_id, stars) = parse_review_id(&modal.data.custom_id)
+        .ok_or_else(|| format!("Error parsing review id {}", modal.data.custom_id))?;
+    let lang = guild_language(ctx, Some(guild_id), modal.guild_locale.as_deref()).await;
+    let comment = modal
+        .data
+        .components
+        .iter()
+        .flat_map(|row| &row.components)
+        .find_map(|component| match component {
+            ActionRowComponent::InputText(input) if input.custom_id == "comment" => {
+                Some(input.value.trim().to_string())
+            }
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    let storage = storage(ctx).await;
+    let order_id = storage
+        .unvouched_order(guild_id.0, modal.user.id.0, seller_id)
+        .await?
+        .ok_or(t(lang, "review.unavailable"))?;
+    let vouch = Vouch {
+        guild_id: guild_id.0,
+        order_id,
+        buyer_id: modal.user.id.0,
+        seller_id,
+        stars,
+        comment,
+        created_at: rates::now(),
+    };
+    storage.add_vouch(vouch.clone()).await?;
+
+    let mut embed = vouch_embed(lang, &vouch);
+    post_vouch(ctx, guild_id, &embed).await;
+    embed.footer(|footer| footer.text(t(lang, "review.thanks")));
+    guild_config(ctx, Some(guild_id))
+        .await
+        .theme
+        .apply(&mut embed);
+    modal
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::UpdateMessage)
+                .interaction_response_data(|message| {
+                    message.set_embed(embed).components(|components| components)
+                })
+        })
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+fn vouch_embed(lang: Language, vouch: &Vouch) -> CreateEmbed {
+    CreateEmbed::default()
+        .title(t(lang, "vouch.title"))
+        .description(format!(
+            "{}\n\n{}",
+            vouches::stars(vouch.stars),
+            vouch.comment
+        ))
+        .field(
+            t(lang, "ticket.buyer"),
+            UserId(vouch.buyer_id).mention(),
+            true,
+        )
+        .field(
+            t(lang, "vouch.seller"),
+            UserId(vouch.seller_id).mention(),
+            true,
+        )
+        .field(
+            t(lang, "vouch.order"),
+            tf(lang, "order.title", &[("id", &vouch.order_id)]),
+            true,
+        )
+        .clone()
+}
+
+async fn handle_vouch_channel_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let channel = command
+        .data
+        .options
+        .get_channel("channel")
+        .map(|channel| channel.id);
+
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| {
+            config.vouch_channel = channel.map(|channel| channel.0)
+        })
+        .await?;
+
+    let description = match channel {
+        Some(channel) => tf(
+            lang,
+            "vouch.channel_set",
+            &[("channel", &channel.mention())],
+        ),
+        None => t(lang, "vouch.channel_cleared").to_string(),
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "vouch.channel_title"))
+        .description(description)
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+#[cfg(feature = "orders")]
+async fn handle_dispute_channel_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let channel = command
+        .data
+        .options
+        .get_channel("channel")
+        .map(|channel| channel.id);
+
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| {
+            config.dispute_channel = channel.map(|channel| channel.0)
+        })
+        .await?;
+
+    let description = match channel {
+        Some(channel) => tf(
+            lang,
+            "dispute.channel_set",
+            &[("channel", &channel.mention())],
+        ),
+        None => t(lang, "dispute.channel_cleared").to_string(),
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "dispute.channel_title"))
+        .description(description)
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Sets the smallest and largest orders `/price`, `/buy` and the order form take.
+#[cfg(feature = "orders")]
+async fn handle_orderlimits_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let robux = |name: &str| command.data.options.get_int(name);
+    let limits = OrderLimits {
+        min_robux: robux("min"),
+        max_robux: robux("max"),
+    };
+    if let (Some(min), Some(max)) = (limits.min_robux, limits.max_robux) {
+        if min > max {
+            return Err(t(lang, "orderlimits.min_above_max").to_string());
+        }
+    }
+
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| config.order_limits = limits)
+        .await?;
+
+    let bound = |robux: Option<u64>| {
+        robux.map_or(t(lang, "orderlimits.none").to_string(), |robux| {
+            numbers::robux(lang, robux as f64)
+        })
+    };
+    let description = if limits.is_set() {
+        tf(
+            lang,
+            "orderlimits.set",
+            &[
+                ("min", &bound(limits.min_robux)),
+                ("max", &bound(limits.max_robux)),
+            ],
+        )
+    } else {
+        t(lang, "orderlimits.cleared").to_string()
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "orderlimits.title"))
+        .description(description)
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Sets how money is rounded in the guild's embeds.
+async fn handle_rounding_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let rounding = Rounding {
+        mode: RoundingMode::from_code(
+            command
+                .data
+                .options
+                .get_str("mode")
+                .ok_or(t(lang, "error.invalid_options"))?,
+        ),
+        decimals: command
+            .data
+            .options
+            .get_int("decimals")
+            .map_or(2, |decimals| {
+                decimals.min(u64::from(Rounding::MAX_DECIMALS)) as u8
+            }),
+    };
+
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| config.rounding = rounding)
+        .await?;
+
+    // The reply itself is still rounded the old way, so the example is
+    // formatted under the new setting.
+    let example =
+        numbers::with_rounding(rounding, async { numbers::gbp(lang, ROUNDING_EXAMPLE_GBP) }).await;
+    let mode_key = match rounding.mode {
+        RoundingMode::Nearest => "rounding.mode_nearest",
+        RoundingMode::Up => "rounding.mode_up",
+        RoundingMode::Down => "rounding.mode_down",
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "rounding.title"))
+        .description(tf(
+            lang,
+            "rounding.set",
+            &[
+                ("mode", &t(lang, mode_key)),
+                ("decimals", &rounding.decimals),
+                ("exact", &numbers::decimal(lang, ROUNDING_EXAMPLE_GBP, 4)),
+                ("example", &example),
+            ],
+        ))
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_reputation_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let user = command
+        .data
+        .options
+        .get_user("user")
+        .unwrap_or(&command.user);
+
+    let reputation = storage(ctx)
+        .await
+        .reputation(guild_id.0, user.id.0, vouches::RECENT_VOUCHES)
+        .await?;
+
+    let mut embed = CreateEmbed::default();
+    embed.title(tf(lang, "reputation.title", &[("user", &user.name)]));
+    if reputation.total == 0 {
+        embed.description(t(lang, "reputation.none"));
+    } else {
+        let recent = reputation
+            .recent
+            .iter()
+            .map(|vouch| {
+                format!(
+                    "{} {} <t:{}:R>\n> {}",
+                    vouches::stars(vouch.stars),
+                    UserId(vouch.buyer_id).mention(),
+                    vouch.created_at,
+                    vouch.comment
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        embed
+            .field(
+                t(lang, "reputation.average"),
+                format!("{:.1} / {} ★", reputation.average, vouches::MAX_STARS),
+                true,
+            )
+            .field(t(lang, "reputation.total"), reputation.total, true)
+            .field(t(lang, "reputation.recent"), recent, false);
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+#[cfg(feature = "orders")]
+async fn handle_stock_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let storage = storage(ctx).await;
+    let seller_id = command.user.id.0;
+
+    let mut embed = CreateEmbed::default();
+    match subcommand.name.as_str() {
+        "set" | "add" => {
+            let config = guild_config(ctx, Some(guild_id)).await;
+            if !is_ticket_staff(command, &config) {
+                return Err(t(lang, "stock.staff_only").to_string());
+            }
+            let amount = subcommand
+                .options
+                .get_str("amount")
+                .ok_or(t(lang, "error.invalid_options"))
+                .map_err(str::to_string)
+                .and_then(|amount| parse::parse_robux_amount(amount, lang))?;
+
+            let total = if subcommand.name == "set" {
+                storage.set_stock(guild_id.0, seller_id, amount).await?;
+                amount
+            } else {
+                storage.add_stock(guild_id.0, seller_id, amount).await?
+            };
+            embed.title(t(lang, "stock.updated")).description(tf(
+                lang,
+                "stock.updated_description",
+                &[("robux", &numbers::integer(lang, total as f64))],
+            ));
+        }
+        "view" => {
+            let stock = storage.stock(guild_id.0).await?;
+            let description = if stock.is_empty() {
+                t(lang, "stock.empty").to_string()
+            } else {
+                stock
+                    .iter()
+                    .map(|(seller, robux)| {
+                        format!(
+                            "{}: {}",
+                            UserId(*seller).mention(),
+                            numbers::robux(lang, *robux as f64)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            let total: u64 = stock.iter().map(|(_, robux)| robux).sum();
+            embed
+                .title(t(lang, "stock.title"))
+                .description(description)
+                .field(
+                    t(lang, "stock.total"),
+                    numbers::robux(lang, total as f64),
+                    true,
+                );
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Total Robux the guild's sellers have in stock, or `None` if nobody tracks stock.
+async fn available_stock(ctx: &Context, guild_id: GuildId) -> Result<Option<u64>, String> {
+    let stock = storage(ctx).await.stock(guild_id.0).await?;
+    if stock.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(stock.iter().map(|(_, robux)| robux).sum()))
+}
+
+/// Robux one seller has in stock, or `None` if they don't track it.
+async fn seller_stock(
+    ctx: &Context,
+    guild_id: GuildId,
+    seller_id: u64,
+) -> Result<Option<u64>, String> {
+    let stock = storage(ctx).await.stock(guild_id.0).await?;
+    Ok(stock
+        .iter()
+        .find(|(seller, _)| *seller == seller_id)
+        .map(|(_, robux)| *robux))
+}
+
+/// Lets buyers wait their turn for delivery when stock is short, and staff serve
+/// them in order.
+#[cfg(feature = "orders")]
+async fn handle_queue_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let storage = storage(ctx).await;
+    let user_id = command.user.id.0;
+    let now = rates::now();
+    let completed = storage
+        .completed_orders(guild_id.0, now.saturating_sub(queue::RATE_WINDOW_SECONDS))
+        .await?
+        .len();
+
+    let mut embed = CreateEmbed::default();
+    match subcommand.name.as_str() {
+        "join" => {
+            refuse_blacklisted(ctx, command, lang).await?;
+            let robux = match subcommand.options.get_str("amount") {
+                Some(amount) => Some(
+                    parse::parse_robux_amount(amount, lang)
+                        .and_then(|amount| validation::validate_robux(amount, lang))?,
+                ),
+                None => None,
+            };
+            let joined = storage
+                .join_queue(QueueEntry {
+                    guild_id: guild_id.0,
+                    user_id,
+                    robux,
+                    joined_at: now,
+                })
+                .await?;
+            if !joined {
+                return Err(t(lang, "queue.already_joined").to_string());
+            }
+            let entries = storage.queue(guild_id.0).await?;
+            let position = queue::position(&entries, user_id).unwrap_or(entries.len());
+            embed
+                .title(t(lang, "queue.joined"))
+                .description(queue_position(lang, position, completed, now));
+        }
+        "leave" => {
+            if !storage.leave_queue(guild_id.0, user_id).await? {
+                return Err(t(lang, "queue.not_in_queue").to_string());
+            }
+            embed
+                .title(t(lang, "queue.left"))
+                .description(t(lang, "queue.left_description"));
+        }
+        "list" => {
+            let entries = storage.queue(guild_id.0).await?;
+            let mut description = if entries.is_empty() {
+                t(lang, "queue.empty").to_string()
+            } else {
+                entries
+                    .iter()
+                    .take(queue::MAX_LISTED)
+                    .enumerate()
+                    .map(|(index, entry)| {
+                        let mut line =
+                            format!("`{}.` {}", index + 1, UserId(entry.user_id).mention());
+                        if let Some(robux) = entry.robux {
+                            line.push_str(&format!(" — {}", numbers::robux(lang, robux as f64)));
+                        }
+                        line.push_str(&format!(" · <t:{}:R>", entry.joined_at));
+                        line
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            if entries.len() > queue::MAX_LISTED {
+                description.push_str(&format!(
+                    "\n{}",
+                    tf(
+                        lang,
+                        "queue.more",
+                        &[("count", &(entries.len() - queue::MAX_LISTED))]
+                    )
+                ));
+            }
+            if let Some(position) = queue::position(&entries, user_id) {
+                description.push_str("\n\n");
+                description.push_str(&queue_position(lang, position, completed, now));
+            }
+            embed
+                .title(tf(lang, "queue.title", &[("count", &entries.len())]))
+                .description(description);
+            return send_embed_response(ctx, command, embed).await;
+        }
+        "next" => {
+            let config = guild_config(ctx, Some(guild_id)).await;
+            if !is_ticket_staff(command, &config) {
+                return Err(t(lang, "queue.staff_only").to_string());
+            }
+            let entry = storage
+                .pop_queue(guild_id.0)
+                .await?
+                .ok_or(t(lang, "queue.empty"))?;
+            let remaining = storage.queue(guild_id.0).await?.len();
+            embed
+                .title(t(lang, "queue.next_title"))
+                .description(tf(
+                    lang,
+                    "queue.next",
+                    &[("user", &UserId(entry.user_id).mention())],
+                ))
+                .field(
+                    t(lang, "queue.remaining"),
+                    numbers::integer(lang, remaining as f64),
+                    true,
+                );
+            if let Some(robux) = entry.robux {
+                embed.field(
+                    t(lang, "price.amount"),
+                    numbers::robux(lang, robux as f64),
+                    true,
+                );
+            }
+            config.theme.apply(&mut embed);
+            // Content rather than the embed, so the buyer is actually pinged.
+            let reply = Reply {
+                content: Some(UserId(entry.user_id).mention().to_string()),
+                embed: Some(embed),
+                ..Reply::default()
+            };
+            return respond(ctx, command, &reply)
+                .await
+                .map_err(|e| format!("Error sending response: {:?}", e));
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    }
+
+    send_ephemeral_embed_response(ctx, command, embed).await
+}
+
+/// Where a buyer is in the queue and roughly when they'll be served.
+#[cfg(feature = "orders")]
+fn queue_position(lang: Language, position: usize, completed: usize, now: u64) -> String {
+    match queue::estimated_wait(position, completed) {
+        Some(wait) => tf(
+            lang,
+            "queue.position_wait",
+            &[("position", &position), ("at", &(now + wait))],
+        ),
+        None => tf(lang, "queue.position", &[("position", &position)]),
+    }
+}
+
+/// Lets ticket staff sell at their own rate and take payment with their own
+/// handles.
+#[cfg(feature = "orders")]
+async fn handle_seller_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !is_ticket_staff(command, &config) {
+        return Err(t(lang, "seller.staff_only").to_string());
+    }
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let user_id = command.user.id.0;
+    let registered = sellers::find(&config.sellers, user_id).is_some();
+    let storage = storage(ctx).await;
+
+    let title = match subcommand.name.as_str() {
+        "register" => {
+            let rate = subcommand
+                .options
+                .get_number("rate")
+                .ok_or(t(lang, "error.invalid_options"))
+                .map_err(str::to_string)
+                .and_then(|rate| validation::validate_tier_rate(rate, lang))?;
+            storage
+                .update_guild(guild_id, |config| {
+                    match config
+                        .sellers
+                        .iter_mut()
+                        .find(|seller| seller.user_id == user_id)
+                    {
+                        Some(seller) => seller.rate = rate,
+                        None => {
+                            config.sellers.push(Seller {
+                                user_id,
+                                rate,
+                                payment_handles: Vec::new(),
+                            });
+                            config.sellers.sort_by_key(|seller| seller.user_id);
+                        }
+                    }
+                })
+                .await?;
+            t(lang, "seller.registered")
+        }
+        "payment" if !registered => return Err(t(lang, "seller.register_first").to_string()),
+        "payment" => {
+            let method = subcommand
+                .options
+                .get_str("method")
+                .and_then(PaymentMethod::from_code)
+                .ok_or(t(lang, "error.invalid_options"))?;
+            // Leaving out the handle removes the method.
+            let handle = match subcommand.options.get_str("handle") {
+                Some(handle) => Some(method.normalize(handle).ok_or_else(|| {
+                    tf(
+                        lang,
+                        "payment.invalid_handle",
+                        &[("method", &method.name())],
+                    )
+                })?),
+                None => None,
+            };
+            let removing = handle.is_none();
+            storage
+                .update_guild(guild_id, |config| {
+                    let Some(seller) = config
+                        .sellers
+                        .iter_mut()
+                        .find(|seller| seller.user_id == user_id)
+                    else {
+                        return;
+                    };
+                    match handle {
+                        Some(handle) => payments::set(
+                            &mut seller.payment_handles,
+                            PaymentHandle { method, handle },
+                        ),
+                        None => {
+                            payments::remove(&mut seller.payment_handles, method);
+                        }
+                    }
+                })
+                .await?;
+            t(
+                lang,
+                if removing {
+                    "payment.removed"
+                } else {
+                    "payment.saved"
+                },
+            )
+        }
+        "leave" if !registered => return Err(t(lang, "seller.register_first").to_string()),
+        "leave" => {
+            storage
+                .update_guild(guild_id, |config| {
+                    config.sellers.retain(|seller| seller.user_id != user_id)
+                })
+                .await?;
+            let embed = CreateEmbed::default()
+                .title(t(lang, "seller.left"))
+                .description(t(lang, "seller.left_description"))
+                .clone();
+            return send_ephemeral_embed_response(ctx, command, embed).await;
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let config = storage.guild(guild_id).await?;
+    let seller = sellers::find(&config.sellers, user_id).ok_or(t(lang, "seller.register_first"))?;
+    let stock = seller_stock(ctx, guild_id, user_id).await?;
+    let embed = CreateEmbed::default()
+        .title(title)
+        .description(seller_summary(lang, seller, stock, &config.payment_handles))
+        .clone();
+    send_ephemeral_embed_response(ctx, command, embed).await
+}
+
+/// Lists the guild's sellers, cheapest first, with their stock and payment
+/// methods.
+#[cfg(feature = "orders")]
+async fn handle_sellers_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    let stock = storage(ctx).await.stock(guild_id.0).await?;
+
+    let mut sellers: Vec<_> = config.sellers.iter().collect();
+    sellers.sort_by(|a, b| a.rate.total_cmp(&b.rate));
+    let description = if sellers.is_empty() {
+        t(lang, "seller.none").to_string()
+    } else {
+        sellers
+            .iter()
+            .map(|seller| {
+                let stock = stock
+                    .iter()
+                    .find(|(id, _)| *id == seller.user_id)
+                    .map(|(_, robux)| *robux);
+                seller_summary(lang, seller, stock, &config.payment_handles)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "seller.title"))
+        .description(description)
+        .footer(|footer| footer.text(t(lang, "seller.footer")))
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+/// A seller's rate, availability and payment methods, falling back to the guild's
+/// methods when they haven't set their own.
+#[cfg(feature = "orders")]
+fn seller_summary(
+    lang: Language,
+    seller: &Seller,
+    stock: Option<u64>,
+    guild_handles: &[PaymentHandle],
+) -> String {
+    let availability = match stock {
+        Some(0) => t(lang, "seller.out_of_stock").to_string(),
+        Some(robux) => tf(
+            lang,
+            "seller.in_stock",
+            &[("robux", &numbers::robux(lang, robux as f64))],
+        ),
+        None => t(lang, "seller.stock_unknown").to_string(),
+    };
+    let handles = if seller.payment_handles.is_empty() {
+        guild_handles
+    } else {
+        &seller.payment_handles
+    };
+    let methods = if handles.is_empty() {
+        "—".to_string()
+    } else {
+        payments::sorted(handles)
+            .iter()
+            .map(|payment| payment.method.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "{} — **{}** / 1k {}\n{} · 💳 {}",
+        UserId(seller.user_id).mention(),
+        numbers::gbp(lang, seller.rate),
+        emojis::robux(),
+        availability,
+        methods
+    )
+}
+
+#[cfg(feature = "roblox")]
+async fn handle_groupfunds_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let config = guild_config(ctx, command.guild_id).await;
+    if !is_ticket_staff(command, &config) {
+        return Err(t(lang, "groupfunds.staff_only").to_string());
+    }
+    let roblox = roblox_client(ctx)
+        .await
+        .ok_or(t(lang, "groupfunds.not_configured"))?;
+
+    let context = || {
+        interaction_context(
+            ("Command", &format!("/{}", command.data.name)),
+            &command.user,
+            command.guild_id,
+        )
+    };
+    let funds = match roblox.group_funds().await {
+        Ok(Some(funds)) => funds,
+        Ok(None) => {
+            report_error(
+                ctx,
+                "Roblox login rejected",
+                "Roblox refused ROBLOX_COOKIE when fetching the group's funds",
+                context(),
+            )
+            .await;
+            return Err(t(lang, "groupfunds.auth_failed").to_string());
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            report_error(ctx, "Group funds unavailable", &error, context()).await;
+            return Err(t(lang, "groupfunds.unavailable").to_string());
+        }
+    };
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "groupfunds.title"))
+        .description(tf(
+            lang,
+            "groupfunds.description",
+            &[
+                ("group", &roblox.group_id.to_string()),
+                ("fetched_at", &funds.fetched_at.to_string()),
+            ],
+        ))
+        .field(
+            t(lang, "groupfunds.balance"),
+            numbers::robux(lang, funds.robux as f64),
+            true,
+        )
+        .field(
+            t(lang, "groupfunds.pending"),
+            numbers::robux(lang, funds.pending as f64),
+            true,
+        );
+    send_ephemeral_embed_response(ctx, command, embed).await
+}
+
+/// Starts linking the user's Roblox account: picks a phrase for them to put in
+/// their profile and replies with a button that checks for it.
+#[cfg(feature = "roblox")]
+async fn handle_verify_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let username = command
+        .data
+        .options
+        .get_str("username")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let username = validation::validate_roblox_username(username, lang)?;
+    let user = roblox::user_by_name(&username)
+        .await?
+        .ok_or_else(|| tf(lang, "verify.unknown_user", &[("username", &username)]))?;
+
+    let storage = storage(ctx).await;
+    if let Some(link) = storage.roblox_link(command.user.id.0).await? {
+        if link.roblox_user_id == user.id {
+            return Err(tf(
+                lang,
+                "verify.already_linked",
+                &[("username", &link.roblox_username)],
+            ));
+        }
+    }
+    let phrase = verification::new_phrase()?;
+    storage
+        .start_verification(PendingVerification {
+            user_id: command.user.id.0,
+            roblox_user_id: user.id,
+            roblox_username: user.name.clone(),
+            phrase: phrase.clone(),
+            created_at: rates::now(),
+        })
+        .await?;
+
+    let embed = CreateEmbed::default()
+        .title(t(lang, "verify.title"))
+        .description(tf(
+            lang,
+            "verify.instructions",
+            &[
+                ("username", &user.name),
+                ("id", &user.id.to_string()),
+                ("phrase", &phrase),
+                (
+                    "minutes",
+                    &(verification::VERIFICATION_EXPIRY_SECONDS / 60).to_string(),
+                ),
+            ],
+        ))
+        .clone();
+    reply::with_backoff(|| {
+        command.create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|message| {
+                    message
+                        .add_embed(embed.clone())
+                        .ephemeral(true)
+                        .components(|components| {
+                            components.create_action_row(|row| {
+                                row.create_button(|button| {
+                                    button
+                                        .custom_id(VERIFY_BUTTON_ID)
+                                        .style(ButtonStyle::Success)
+                                        .label(t(lang, "verify.check"))
+                                })
+                            })
+                        })
+                })
+        })
+    })
+    .await
+    .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Links the account once the user's pending `/verify` phrase shows up in their
+/// Roblox profile.
+#[cfg(feature = "roblox")]
+async fn handle_verify_button(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+    lang: Language,
+) -> Result<(), String> {
+    let storage = storage(ctx).await;
+    let now = rates::now();
+    let pending = storage
+        .pending_verification(component.user.id.0)
+        .await?
+        .filter(|pending| !pending.is_expired(now))
+        .ok_or(t(lang, "verify.expired"))?;
+    let profile = roblox::profile(pending.roblox_user_id).await?;
+    if !verification::contains_phrase(&profile.description, &pending.phrase) {
+        return Err(tf(
+            lang,
+            "verify.phrase_missing",
+            &[("username", &profile.name)],
+        ));
+    }
+    storage
+        .save_roblox_link(RobloxLink {
+            user_id: component.user.id.0,
+            roblox_user_id: profile.id,
+            roblox_username: profile.name.clone(),
+            verified_at: now,
+        })
+        .await?;
+
+    let embed = CreateEmbed::default()
+        .title(t(lang, "verify.linked"))
+        .description(tf(
+            lang,
+            "verify.linked_description",
+            &[("username", &profile.name)],
+        ))
+        .clone();
+    component
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::UpdateMessage)
+                .interaction_response_data(|message| {
+                    message.set_embed(embed).components(|components| components)
+                })
+        })
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+#[cfg(feature = "orders")]
+async fn handle_order_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    match subcommand.name.as_str() {
+        "complete" => {
+            let config = guild_config(ctx, Some(guild_id)).await;
+            if !is_ticket_staff(command, &config) {
+                return Err(t(lang, "order.staff_only").to_string());
+            }
+            let storage = storage(ctx).await;
+            let order = storage
+                .open_order_for_channel(command.channel_id.0)
+                .await?
+                .ok_or(t(lang, "ticket.not_ticket"))?;
+            if order.completed_at.is_some() {
+                return Err(tf(lang, "order.already_completed", &[("id", &order.id)]));
+            }
+
+            let completed_at = rates::now();
+            storage
+                .complete_order(order.id, command.user.id.0, completed_at)
+                .await?;
+            record_audit(
+                ctx,
+                &config,
+                vec![audit_entry(
+                    command,
+                    guild_id,
+                    format!("order #{}", order.id),
+                    Some("open".to_string()),
+                    Some("completed".to_string()),
+                )],
+            )
+            .await;
+            let order = Order {
+                completed_at: Some(completed_at),
+                ..order
+            };
+            sync_order_to_sheet(ctx, &config, &order, command.user.id.0).await;
+            webhooks::dispatch(&config.webhooks, OrderEvent::Completed, &order);
+            let roblox_username = subcommand
+                .options
+                .get_str("roblox_username")
+                .map(|username| validation::validate_roblox_username(username, lang))
+                .transpose()?
+                .or_else(|| order.roblox_username.clone());
+            let payment = subcommand
+                .options
+                .get_str("payment")
+                .and_then(PaymentMethod::from_code);
+
+            let embed = CreateEmbed::default()
+                .title(tf(lang, "order.completed", &[("id", &order.id)]))
+                .description(tf(
+                    lang,
+                    "order.completed_description",
+                    &[
+                        ("robux", &numbers::integer(lang, order.robux as f64)),
+                        ("buyer", &UserId(order.buyer_id).mention()),
+                        ("seller", &command.user.mention()),
+                    ],
+                ))
+                .clone();
+            send_embed_response(ctx, command, embed).await?;
+            // The PDF can't show Discord emojis.
+            emojis::with_emojis(
+                emojis::Emojis::default(),
+                send_receipt(ctx, command, &config, &order, payment, lang),
+            )
+            .await;
+            award_role_rewards(ctx, command, &config, &order, lang).await;
+            prompt_review(ctx, command, &config, &order, lang).await;
+            request_payout(ctx, command, &order, roblox_username, lang).await;
+            Ok(())
+        }
+        "dispute" => dispute_order(ctx, command, subcommand, guild_id, lang).await,
+        "middleman" => start_middleman(ctx, command, guild_id, lang).await,
+        "refund" => refund_order(ctx, command, subcommand, guild_id, lang).await,
+        "delete" => delete_order(ctx, command, subcommand, guild_id, lang).await,
+        _ => Err(t(lang, "error.invalid_options").to_string()),
+    }
+}
+
+/// Deletes an order by its number, leaving a few minutes to undo it.
+#[cfg(feature = "orders")]
+async fn delete_order(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    subcommand: &CommandDataOption,
+    guild_id: GuildId,
+    lang: Language,
+) -> Result<(), String> {
+    if !is_staff(command) {
+        return Err(t(lang, "error.manage_guild_only").to_string());
+    }
+    let id = subcommand
+        .options
+        .get_int("id")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    if !storage(ctx)
+        .await
+        .delete_order(guild_id.0, id, rates::now())
+        .await?
+    {
+        return Err(tf(lang, "order.not_found", &[("id", &id)]));
+    }
+    let deleted = Deleted::Order(id);
+    record_deletion(ctx, command, guild_id, &deleted).await;
+
+    let embed = CreateEmbed::default()
+        .title(tf(lang, "order.deleted", &[("id", &id)]))
+        .clone();
+    send_undoable_response(ctx, command, embed, &deleted).await
+}
+
+/// Logs a deletion made with `command` in the audit log.
+async fn record_deletion(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    guild_id: GuildId,
+    deleted: &Deleted,
+) {
+    let config = guild_config(ctx, Some(guild_id)).await;
+    let entry = audit_entry(
+        command,
+        guild_id,
+        deleted.label(),
+        None,
+        Some("deleted".to_string()),
+    );
+    record_audit(ctx, &config, vec![entry]).await;
+}
+
+/// Restores what an Undo button's message deleted, if it's still in time.
+async fn handle_undo_button(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+    lang: Language,
+) -> Result<(), String> {
+    let guild_id = component.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    if !is_staff_member(component.member.as_ref()) {
+        return Err(t(lang, "error.manage_guild_only").to_string());
+    }
+    let deleted = component
+        .data
+        .custom_id
+        .strip_prefix(UNDO_BUTTON_ID)
+        .and_then(|key| key.strip_prefix(':'))
+        .and_then(Deleted::from_key)
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let storage = storage(ctx).await;
+    let since = undo::cutoff(rates::now());
+    let previous = storage.guild(guild_id).await?;
+    let (restored, description) = match &deleted {
+        Deleted::Tier(min_robux) => (
+            storage.restore_tier(guild_id.0, *min_robux, since).await?,
+            tf(
+                lang,
+                "undo.restored_tier",
+                &[("min", &numbers::integer(lang, *min_robux as f64))],
+            ),
+        ),
+        Deleted::Coupon(code) => (
+            storage.restore_coupon(guild_id.0, code, since).await?,
+            tf(lang, "undo.restored_coupon", &[("code", code)]),
+        ),
+        Deleted::Order(id) => (
+            storage.restore_order(guild_id.0, *id, since).await?,
+            tf(lang, "undo.restored_order", &[("id", id)]),
+        ),
+    };
+    if !restored {
+        return Err(t(lang, "undo.expired").to_string());
+    }
+
+    let config = storage.guild(guild_id).await?;
+    if let Deleted::Tier(_) = deleted {
+        announce_rate_change(ctx, &config, &previous.tiers, lang).await;
+    }
+    let entry = AuditEntry {
+        guild_id: guild_id.0,
+        actor_id: component.user.id.0,
+        action: "Undo".to_string(),
+        setting: deleted.label(),
+        old_value: Some("deleted".to_string()),
+        new_value: Some("restored".to_string()),
+        created_at: rates::now(),
+    };
+    record_audit(ctx, &config, vec![entry]).await;
+
+    let mut embed = CreateEmbed::default()
+        .title(t(lang, "undo.restored"))
+        .description(description)
+        .clone();
+    config.theme.apply(&mut embed);
+    component
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::UpdateMessage)
+                .interaction_response_data(|message| {
+                    message
+                        .content("")
+                        .set_embed(embed)
+                        .components(|components| components)
+                })
+        })
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Flags the ticket's order as disputed. Its buyer can do this as well as staff.
+#[cfg(feature = "orders")]
+async fn dispute_order(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    subcommand: &CommandDataOption,
+    guild_id: GuildId,
+    lang: Language,
+) -> Result<(), String> {
+    let config = guild_config(ctx, Some(guild_id)).await;
+    let storage = storage(ctx).await;
+    let order = storage
+        .open_order_for_channel(command.channel_id.0)
+        .await?
+        .ok_or(t(lang, "ticket.not_ticket"))?;
+    if order.buyer_id != command.user.id.0 && !is_ticket_staff(command, &config) {
+        return Err(t(lang, "dispute.not_allowed").to_string());
+    }
+    let reason = subcommand
+        .options
+        .get_str("reason")
+        .ok_or(t(lang, "error.invalid_options"))?
+        .trim()
+        .to_string();
+
+    let opened = storage
+        .open_dispute(Dispute {
+            order_id: order.id,
+            guild_id: guild_id.0,
+            opened_by: command.user.id.0,
+            reason: reason.clone(),
+            opened_at: rates::now(),
+        })
+        .await?;
+    if !opened {
+        return Err(tf(lang, "dispute.already_open", &[("id", &order.id)]));
+    }
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(lang, "dispute.opened", &[("id", &order.id)]))
+        .field(
+            t(lang, "ticket.buyer"),
+            UserId(order.buyer_id).mention(),
+            true,
+        )
+        .field(t(lang, "dispute.opened_by"), command.user.mention(), true)
+        .field(
+            t(lang, "dispute.ticket"),
+            command.channel_id.mention(),
+            true,
+        )
+        .field(t(lang, "dispute.reason"), &reason, false);
+    if let Some(frozen) = &order.frozen_rates {
+        embed.field(
+            t(lang, "order.frozen_rates"),
+            frozen_rates_text(lang, frozen),
+            false,
+        );
+    }
+    log_dispute(ctx, &config, &embed).await;
+    embed.description(t(lang, "dispute.opened_description"));
+    send_embed_response(ctx, command, embed).await
+}
+
+/// The rates an order was priced at, one per line, for dispute and refund logs.
+#[cfg(feature = "orders")]
+fn frozen_rates_text(lang: Language, frozen: &FrozenRates) -> String {
+    tf(
+        lang,
+        "order.frozen_rates_value",
+        &[
+            ("rate", &numbers::gbp(lang, frozen.base_rate * 1000.0)),
+            ("usd", &numbers::usd(lang, frozen.gbp_to_usd)),
+            ("fee", &numbers::integer(lang, frozen.roblox_fee * 100.0)),
+            ("vat", &frozen.vat_percent),
+        ],
+    )
+}
+
+/// Records money given back on the ticket's completed order, all that's left of
+/// it unless staff give an amount.
+#[cfg(feature = "orders")]
+async fn refund_order(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    subcommand: &CommandDataOption,
+    guild_id: GuildId,
+    lang: Language,
+) -> Result<(), String> {
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !is_ticket_staff(command, &config) {
+        return Err(t(lang, "order.staff_only").to_string());
+    }
+    let storage = storage(ctx).await;
+    let order = storage
+        .open_order_for_channel(command.channel_id.0)
+        .await?
+        .ok_or(t(lang, "ticket.not_ticket"))?;
+    if order.completed_at.is_none() {
+        return Err(t(lang, "refund.not_completed").to_string());
+    }
+
+    let mut amount = None;
+    let mut reason = None;
+    for option in &subcommand.options {
+        match (option.name.as_str(), &option.resolved) {
+            ("amount", Some(CommandDataOptionValue::Number(value))) => amount = Some(*value),
+            ("reason", Some(CommandDataOptionValue::String(value))) => {
+                reason = Some(value.trim().to_string())
+            }
+            _ => {}
+        }
+    }
+    let reason = reason.ok_or(t(lang, "error.invalid_options"))?;
+    let refundable = disputes::refundable(order.gbp, storage.refunded(order.id).await?);
+    if refundable <= 0.0 {
+        return Err(tf(lang, "refund.fully_refunded", &[("id", &order.id)]));
+    }
+    let gbp = amount.unwrap_or(refundable);
+    if gbp > refundable {
+        return Err(tf(
+            lang,
+            "refund.too_much",
+            &[("max", &numbers::gbp(lang, refundable))],
+        ));
+    }
+
+    let refund = Refund {
+        order_id: order.id,
+        guild_id: guild_id.0,
+        gbp,
+        usd: if order.gbp > 0.0 {
+            gbp * order.usd / order.gbp
+        } else {
+            0.0
+        },
+        reason,
+        refunded_by: command.user.id.0,
+        refunded_at: rates::now(),
+    };
+    storage.add_refund(refund.clone()).await?;
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(lang, "refund.recorded", &[("id", &order.id)]))
+        .field(
+            t(lang, "ticket.buyer"),
+            UserId(order.buyer_id).mention(),
+            true,
+        )
+        .field(
+            t(lang, "refund.amount"),
+            format!(
+                "{} / {}",
+                numbers::gbp(lang, refund.gbp),
+                numbers::usd(lang, refund.usd)
+            ),
+            true,
+        )
+        .field(
+            t(lang, "refund.remaining"),
+            numbers::gbp(lang, disputes::refundable(refundable, gbp)),
+            true,
+        )
+        .field(t(lang, "refund.refunded_by"), command.user.mention(), true)
+        .field(t(lang, "dispute.reason"), &refund.reason, false);
+    if let Some(frozen) = &order.frozen_rates {
+        embed.field(
+            t(lang, "order.frozen_rates"),
+            frozen_rates_text(lang, frozen),
+            false,
+        );
+    }
+    log_dispute(ctx, &config, &embed).await;
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Posts the middleman panel for the ticket's order, pinging the middleman role
+/// to confirm receiving and releasing the buyer's payment.
+#[cfg(feature = "orders")]
+async fn start_middleman(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    guild_id: GuildId,
+    lang: Language,
+) -> Result<(), String> {
+    let config = guild_config(ctx, Some(guild_id)).await;
+    let role = config
+        .tickets
+        .middleman_role
+        .ok_or(t(lang, "middleman.no_role"))?;
+    let storage = storage(ctx).await;
+    let order = storage
+        .open_order_for_channel(command.channel_id.0)
+        .await?
+        .ok_or(t(lang, "ticket.not_ticket"))?;
+    if order.buyer_id != command.user.id.0 && !is_ticket_staff(command, &config) {
+        return Err(t(lang, "middleman.not_allowed").to_string());
+    }
+    let progress = storage.middleman_progress(order.id).await?;
+
+    let mut embed = middleman_embed(lang, order.id, role, progress.as_ref());
+    config.theme.apply(&mut embed);
+    reply::with_backoff(|| {
+        command.create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|message| {
+                    message
+                        .content(RoleId(role).mention())
+                        .add_embed(embed.clone())
+                        .components(|components| {
+                            middleman_buttons(components, lang, order.id, progress.as_ref())
+                        })
+                })
+        })
+    })
+    .await
+    .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+#[cfg(feature = "orders")]
+fn middleman_embed(
+    lang: Language,
+    order_id: u64,
+    role: u64,
+    progress: Option<&MiddlemanProgress>,
+) -> CreateEmbed {
+    let step = |by: Option<u64>, at: Option<u64>| match by.zip(at) {
+        Some((by, at)) => tf(
+            lang,
+            "middleman.step_done",
+            &[("user", &UserId(by).mention()), ("at", &at)],
+        ),
+        None => t(lang, "middleman.step_waiting").to_string(),
+    };
+    CreateEmbed::default()
+        .title(tf(lang, "middleman.title", &[("id", &order_id)]))
+        .description(tf(
+            lang,
+            "middleman.description",
+            &[("role", &RoleId(role).mention())],
+        ))
+        .field(
+            t(lang, "middleman.received"),
+            step(
+                progress.map(|progress| progress.received_by),
+                progress.map(|progress| progress.received_at),
+            ),
+            true,
+        )
+        .field(
+            t(lang, "middleman.released"),
+            step(
+                progress.and_then(|progress| progress.released_by),
+                progress.and_then(|progress| progress.released_at),
+            ),
+            true,
+        )
+        .clone()
+}
+
+/// The receipt and release buttons, each enabled only while it's the next step.
+#[cfg(feature = "orders")]
+fn middleman_buttons<'a>(
+    components: &'a mut CreateComponents,
+    lang: Language,
+    order_id: u64,
+    progress: Option<&MiddlemanProgress>,
+) -> &'a mut CreateComponents {
+    let released = progress.is_some_and(|progress| progress.released_at.is_some());
+    components.create_action_row(|row| {
+        row.create_button(|button| {
+            button
+                .custom_id(format!("{}:received:{}", MIDDLEMAN_BUTTON_ID, order_id))
+                .style(ButtonStyle::Primary)
+                .emoji('📥')
+                .label(t(lang, "middleman.confirm_received"))
+                .disabled(progress.is_some())
+        })
+        .create_button(|button| {
+            button
+                .custom_id(format!("{}:released:{}", MIDDLEMAN_BUTTON_ID, order_id))
+                .style(ButtonStyle::Success)
+                .emoji('📤')
+                .label(t(lang, "middleman.confirm_released"))
+                .disabled(progress.is_none() || released)
+        })
+    })
+}
+
+/// Records a middleman step from the panel's buttons and updates the panel.
+#[cfg(feature = "orders")]
+async fn handle_middleman_button(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+    lang: Language,
+) -> Result<(), String> {
+    let guild_id = component.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    let role = config
+        .tickets
+        .middleman_role
+        .ok_or(t(lang, "middleman.no_role"))?;
+    if !is_staff_member(component.member.as_ref())
+        && !component
+            .member
+            .as_ref()
+            .is_some_and(|member| member.roles.contains(&RoleId(role)))
+    {
+        return Err(tf(
+            lang,
+            "middleman.role_only",
+            &[("role", &RoleId(role).mention())],
+        ));
+    }
+    let mut parts = component.data.custom_id.split(':').skip(1);
+    let (step, order_id) = match (parts.next(), parts.next().and_then(|id| id.parse().ok())) {
+        (Some(step @ ("received" | "released")), Some(id)) => (step, id),
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let storage = storage(ctx).await;
+    let now = rates::now();
+    let confirmed = if step == "received" {
+        storage
+            .confirm_middleman_received(order_id, guild_id.0, component.user.id.0, now)
+            .await?
+    } else {
+        storage
+            .middleman_progress(order_id)
+            .await?
+            .is_some_and(|progress| progress.guild_id == guild_id.0)
+            && storage
+                .confirm_middleman_released(order_id, component.user.id.0, now)
+                .await?
+    };
+    if !confirmed {
+        return Err(t(lang, "middleman.already_done").to_string());
+    }
+    if step == "received" {
+        if let Some(order) = storage
+            .open_order_for_channel(component.channel_id.0)
+            .await?
+            .filter(|order| order.id == order_id)
+        {
+            webhooks::dispatch(&config.webhooks, OrderEvent::Paid, &order);
+        }
+    }
+
+    let progress = storage.middleman_progress(order_id).await?;
+    let mut embed = middleman_embed(lang, order_id, role, progress.as_ref());
+    config.theme.apply(&mut embed);
+    component
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::UpdateMessage)
+                .interaction_response_data(|message| {
+                    message.set_embed(embed).components(|components| {
+                        middleman_buttons(components, lang, order_id, progress.as_ref())
+                    })
+                })
+        })
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Works out who pays what when a middleman takes a cut of a trade.
+#[cfg(feature = "orders")]
+async fn handle_middleman_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let options = &command.data.options;
+    let number = |name: &str| options.get_number(name);
+    let amount = number("amount").ok_or(t(lang, "error.invalid_options"))?;
+    let fee_percent = number("fee").ok_or(t(lang, "error.invalid_options"))?;
+    let payer = options
+        .get_str("paid_by")
+        .map_or(Some(FeePayer::Split), FeePayer::parse)
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let split = middleman::split(amount, fee_percent, payer);
+    let usd_rate = gbp_to_usd(ctx).await;
+    let money = |gbp: f64| {
+        format!(
+            "{} / {}",
+            numbers::gbp(lang, gbp),
+            numbers::usd(lang, gbp * usd_rate)
+        )
+    };
+
+    let fee_key = match payer {
+        FeePayer::Buyer => "middleman.paid_by_buyer",
+        FeePayer::Seller => "middleman.paid_by_seller",
+        FeePayer::Split => "middleman.paid_by_split",
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "middleman.calc_title"))
+        .description(t(lang, fee_key))
+        .field(t(lang, "middleman.trade"), money(amount), true)
+        .field(
+            tf(
+                lang,
+                "middleman.fee",
+                &[("percent", &numbers::decimal(lang, fee_percent, 2))],
+            ),
+            money(split.fee),
+            true,
+        )
+        .field("\u{200b}", "\u{200b}", true)
+        .field(
+            t(lang, "middleman.buyer_pays"),
+            money(split.buyer_pays),
+            true,
+        )
+        .field(
+            t(lang, "middleman.seller_receives"),
+            money(split.seller_receives),
+            true,
+        )
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Works out a reseller's profit on Robux bought at one rate and sold at another.
+async fn handle_margin_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let options = &command.data.options;
+    let buy_rate = options
+        .get_number("buy_rate")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let sell_rate = options
+        .get_number("sell_rate")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let robux = parse::parse_robux_amount(
+        options
+            .get_str("amount")
+            .ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )
+    .and_then(|amount| validation::validate_robux(amount, lang))?;
+    let delivery = DeliveryMethod::parse(options.get_str("method").unwrap_or("gamepass"))
+        .ok_or(t(lang, "price.invalid_method"))?;
+    let payment = PaymentMethod::from_code(options.get_str("payment").unwrap_or("paypal"))
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let margin = fees::margin(robux, buy_rate, sell_rate, delivery, payment);
+    let usd_rate = gbp_to_usd(ctx).await;
+    let money = |gbp: f64| {
+        format!(
+            "{} / {}",
+            numbers::gbp(lang, gbp),
+            numbers::usd(lang, gbp * usd_rate)
+        )
+    };
+    let fee = fees::processor_fee(payment);
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(
+            lang,
+            "margin.title",
+            &[("robux", &numbers::robux(lang, robux as f64))],
+        ))
+        .description(tf(
+            lang,
+            "margin.description",
+            &[
+                ("buy", &numbers::gbp(lang, buy_rate)),
+                ("sell", &numbers::gbp(lang, sell_rate)),
+                ("method", &delivery.name(lang)),
+                ("payment", &payment.name()),
+            ],
+        ))
+        .field(t(lang, "margin.revenue"), money(margin.revenue), true)
+        .field(
+            tf(
+                lang,
+                "margin.cost",
+                &[("robux", &numbers::robux(lang, margin.spent_robux as f64))],
+            ),
+            money(margin.cost),
+            true,
+        )
+        .field(
+            tf(
+                lang,
+                "margin.processor_fee",
+                &[
+                    ("percent", &numbers::decimal(lang, fee.percent, 2)),
+                    ("fixed", &numbers::gbp(lang, fee.fixed)),
+                ],
+            ),
+            money(margin.processor_fee),
+            true,
+        )
+        .field(
+            t(lang, "margin.profit"),
+            format!(
+                "**{}** ({}%)",
+                money(margin.profit()),
+                numbers::decimal(lang, margin.percent(), 1)
+            ),
+            true,
+        )
+        .field(
+            t(lang, "margin.per_thousand"),
+            money(margin.profit_per_thousand()),
+            true,
+        );
+    if margin.profit() < 0.0 {
+        embed.footer(|footer| footer.text(t(lang, "margin.loss")));
+    }
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Explains what each of the guild's payment methods and each delivery method
+/// costs, with totals for an example order.
+async fn handle_fees_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let robux = match command.data.options.get_str("robux") {
+        Some(amount) => parse::parse_robux_amount(amount, lang)
+            .and_then(|amount| validation::validate_robux(amount, lang))?,
+        None => FEES_EXAMPLE_ROBUX,
+    };
+    let config = guild_config(ctx, command.guild_id).await;
+    let base_rate = pricing::base_rate(&config.tiers, &config.shop_rates, robux, None);
+    let price = robux as f64 * base_rate;
+
+    let handles = payments::sorted(&config.payment_handles);
+    let payment_lines = if handles.is_empty() {
+        t(lang, "payment.empty").to_string()
+    } else {
+        handles
+            .iter()
+            .map(|handle| {
+                let fee = fees::processor_fee(handle.method);
+                tf(
+                    lang,
+                    "fees.payment_line",
+                    &[
+                        ("method", &handle.method.name()),
+                        ("formula", &fee.formula(lang)),
+                        ("total", &numbers::gbp(lang, fee.gross_up(price))),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let delivery_lines = DeliveryMethod::ALL
+        .iter()
+        .map(|method| {
+            tf(
+                lang,
+                "fees.delivery_line",
+                &[
+                    ("method", &method.name(lang)),
+                    ("percent", &numbers::decimal(lang, method.fee() * 100.0, 0)),
+                    (
+                        "listing",
+                        &numbers::robux(lang, method.exact_listing_price(robux) as f64),
+                    ),
+                    (
+                        "total",
+                        &numbers::gbp(lang, robux as f64 * method.rate(base_rate, true)),
+                    ),
+                ],
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "fees.title"))
+        .description(tf(
+            lang,
+            "fees.description",
+            &[
+                ("robux", &numbers::robux(lang, robux as f64)),
+                ("price", &numbers::gbp(lang, price)),
+            ],
+        ))
+        .field(t(lang, "fees.payments"), payment_lines, false)
+        .field(t(lang, "fees.delivery"), delivery_lines, false);
+    if let Some(vat) = &config.vat {
+        embed.field(
+            t(lang, "fees.vat"),
+            tf(
+                lang,
+                "fees.vat_line",
+                &[
+                    ("rate", &numbers::decimal(lang, vat.rate, 2)),
+                    ("region", &vat.region),
+                    ("tax", &numbers::gbp(lang, vat.tax_on(price))),
+                ],
+            ),
+            false,
+        );
+    }
+    embed.footer(|footer| footer.text(t(lang, "fees.footer")));
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Posts a dispute or refund to the guild's dispute log channel, if it set one.
+#[cfg(feature = "orders")]
+async fn log_dispute(ctx: &Context, config: &GuildConfig, embed: &CreateEmbed) {
+    let Some(channel) = config.dispute_channel else {
+        return;
+    };
+    let mut embed = embed.clone();
+    config.theme.apply(&mut embed);
+    if let Err(why) = ChannelId(channel)
+        .send_message(&ctx.http, |message| message.set_embed(embed))
+        .await
+    {
+        eprintln!("Cannot log dispute: {}", why);
+    }
+}
+
+async fn handle_blacklist_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !is_ticket_staff(command, &config) {
+        return Err(t(lang, "blacklist.staff_only").to_string());
+    }
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let mut user = None;
+    let mut reason = None;
+    for option in &subcommand.options {
+        match (option.name.as_str(), &option.resolved) {
+            ("user", Some(CommandDataOptionValue::User(value, _))) => user = Some(value),
+            ("reason", Some(CommandDataOptionValue::String(value))) => reason = Some(value),
+            _ => {}
+        }
+    }
+    let user = user.ok_or(t(lang, "error.invalid_options"))?;
+    let global = bool_option(&subcommand.options, "global");
+    if global && !is_bot_owner(ctx, command.user.id).await? {
+        return Err(t(lang, "blacklist.owner_only").to_string());
+    }
+    let scope = if global { None } else { Some(guild_id.0) };
+
+    let storage = storage(ctx).await;
+    let previous = || async {
+        Ok::<_, String>(
+            storage
+                .blacklist_entries(scope, user.id.0)
+                .await?
+                .into_iter()
+                .find(|entry| entry.guild_id == scope)
+                .map(|entry| entry.reason),
+        )
+    };
+    let mut embed = CreateEmbed::default();
+    match subcommand.name.as_str() {
+        "add" => {
+            let reason = reason.ok_or(t(lang, "error.invalid_options"))?;
+            let old_reason = previous().await?;
+            storage
+                .add_blacklist_entry(BlacklistEntry {
+                    guild_id: scope,
+                    user_id: user.id.0,
+                    reason: reason.clone(),
+                    added_by: command.user.id.0,
+                    created_at: rates::now(),
+                })
+                .await?;
+            record_audit(
+                ctx,
+                &config,
+                vec![audit_entry(
+                    command,
+                    guild_id,
+                    blacklist_audit_setting(user.id, global),
+                    old_reason,
+                    Some(reason.clone()),
+                )],
+            )
+            .await;
+            embed.title(t(lang, "blacklist.added")).description(tf(
+                lang,
+                "blacklist.added_description",
+                &[("user", &user.mention()), ("reason", reason)],
+            ));
+        }
+        "remove" => {
+            let old_reason = previous().await?;
+            if !storage.remove_blacklist_entry(scope, user.id.0).await? {
+                return Err(tf(
+                    lang,
+                    "blacklist.not_found",
+                    &[("user", &user.mention())],
+                ));
+            }
+            record_audit(
+                ctx,
+                &config,
+                vec![audit_entry(
+                    command,
+                    guild_id,
+                    blacklist_audit_setting(user.id, global),
+                    old_reason,
+                    None,
+                )],
+            )
+            .await;
+            embed.title(t(lang, "blacklist.removed")).description(tf(
+                lang,
+                "blacklist.removed_description",
+                &[("user", &user.mention())],
+            ));
+        }
+        "check" => {
+            let entries = storage
+                .blacklist_entries(Some(guild_id.0), user.id.0)
+                .await?;
+            embed.title(t(lang, "blacklist.title"));
+            if entries.is_empty() {
+                embed.description(tf(lang, "blacklist.clear", &[("user", &user.mention())]));
+            } else {
+                embed.description(tf(lang, "blacklist.listed", &[("user", &user.mention())]));
+                for entry in &entries {
+                    let scope = if entry.guild_id.is_some() {
+                        t(lang, "blacklist.scope_guild")
+                    } else {
+                        t(lang, "blacklist.scope_global")
+                    };
+                    embed.field(
+                        scope,
+                        format!(
+                            "{}\n{} <t:{}:R>",
+                            entry.reason,
+                            UserId(entry.added_by).mention(),
+                            entry.created_at
+                        ),
+                        false,
+                    );
+                }
+            }
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// What a blacklist change is logged under: the user, marked if the entry was
+/// the global one.
+fn blacklist_audit_setting(user_id: UserId, global: bool) -> String {
+    if global {
+        format!("{} (global)", user_id.mention())
+    } else {
+        user_id.mention().to_string()
+    }
+}
+
+/// Shows who changed what, or sets the channel changes are mirrored to.
+async fn handle_auditlog_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let storage = storage(ctx).await;
+
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "auditlog.title"));
+    match subcommand.name.as_str() {
+        "show" => {
+            let actor = subcommand.options.get_user("user").map(|user| user.id);
+            let count = subcommand
+                .options
+                .get_int("count")
+                .unwrap_or(AUDIT_LOG_PAGE)
+                .min(MAX_AUDIT_LOG_PAGE) as u32;
+            let entries = storage
+                .audit_log(guild_id.0, actor.map(|id| id.0), count)
+                .await?;
+            if entries.is_empty() {
+                embed.description(t(lang, "auditlog.empty"));
+            } else {
+                // Stops short of Discord's description limit if values are long.
+                let mut description = String::new();
+                for line in entries.iter().map(audit::line) {
+                    if description.len() + line.len() >= MAX_EMBED_DESCRIPTION {
+                        break;
+                    }
+                    description.push_str(&line);
+                    description.push('\n');
+                }
+                embed.description(description);
+            }
+        }
+        "mirror" => {
+            let channel = subcommand
+                .options
+                .get_channel("channel")
+                .map(|channel| channel.id);
+            storage
+                .update_guild(guild_id, |config| {
+                    config.audit_channel = channel.map(|channel| channel.0)
+                })
+                .await?;
+            embed.description(match channel {
+                Some(channel) => tf(
+                    lang,
+                    "auditlog.mirroring",
+                    &[("channel", &channel.mention())],
+                ),
+                None => t(lang, "auditlog.mirror_off").to_string(),
+            });
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Refuses the command if the user is blacklisted here or globally, and lets
+/// ticket staff know in the log channel.
+async fn refuse_blacklisted(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    lang: Language,
+) -> Result<(), String> {
+    refuse_blacklisted_user(
+        ctx,
+        command.guild_id,
+        &command.user,
+        &command.data.name,
+        lang,
+    )
+    .await
+}
+
+/// Fails if `user` is blacklisted, telling staff in the ticket log channel that
+/// they tried to use `action`.
+async fn refuse_blacklisted_user(
+    ctx: &Context,
+    guild_id: Option<GuildId>,
+    user: &User,
+    action: &str,
+    lang: Language,
+) -> Result<(), String> {
+    let entries = storage(ctx)
+        .await
+        .blacklist_entries(guild_id.map(|id| id.0), user.id.0)
+        .await?;
+    let entry = match entries.first() {
+        Some(entry) => entry,
+        None => return Ok(()),
+    };
+
+    let config = guild_config(ctx, guild_id).await;
+    if let Some(channel) = config.tickets.log_channel {
+        let mut embed = CreateEmbed::default()
+            .title(t(lang, "blacklist.attempt"))
+            .description(tf(
+                lang,
+                "blacklist.attempt_description",
+                &[
+                    ("user", &user.mention()),
+                    ("command", &action),
+                    ("reason", &entry.reason),
+                ],
+            ))
+            .clone();
+        config.theme.apply(&mut embed);
+        if let Err(error) = ChannelId(channel)
+            .send_message(&ctx.http, |message| message.set_embed(embed))
+            .await
+        {
+            eprintln!("Error posting blacklist warning: {:?}", error);
+        }
+    }
+
+    Err(t(lang, "blacklist.refused").to_string())
+}
+
+/// Sets how many days calculations and closed orders are kept in the guild.
+/// Leaving an option out keeps those records.
+async fn handle_retention_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let retention = Retention {
+        history_days: command.data.options.get_int("history_days"),
+        order_days: command.data.options.get_int("order_days"),
+    };
+
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| config.retention = retention)
+        .await?;
+
+    let period = |days: Option<u64>| {
+        days.map_or(t(lang, "retention.kept").to_string(), |days| {
+            tf(lang, "retention.days", &[("days", &days.to_string())])
+        })
+    };
+    let description = if retention.is_set() {
+        tf(
+            lang,
+            "retention.set",
+            &[
+                ("history", &period(retention.history_days)),
+                ("orders", &period(retention.order_days)),
+            ],
+        )
+    } else {
+        t(lang, "retention.cleared").to_string()
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "retention.title"))
+        .description(description)
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn is_bot_owner(ctx: &Context, user_id: UserId) -> Result<bool, String> {
+    let info = ctx
+        .http
+        .get_current_application_info()
+        .await
+        .map_err(|e| format!("Error fetching application info: {:?}", e))?;
+    Ok(info.owner.id == user_id)
+}
+
+async fn handle_points_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    let purchases = storage(ctx)
+        .await
+        .purchases(guild_id.0, command.user.id.0)
+        .await?;
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "points.title"))
+        .description(tf(
+            lang,
+            "points.balance",
+            &[("points", &purchases.points())],
+        ))
+        .field(t(lang, "points.orders"), purchases.orders, true)
+        .field(
+            t(lang, "points.purchased"),
+            numbers::robux(lang, purchases.robux as f64),
+            true,
+        );
+    if let Some(discount) = loyalty::discount_for(&config.loyalty_discounts, purchases.robux) {
+        embed.field(
+            t(lang, "loyalty.discount"),
+            tf(
+                lang,
+                "loyalty.percent_off",
+                &[("percent", &discount.percent)],
+            ),
+            true,
+        );
+    }
+    if let Some(next) = loyalty::next_discount(&config.loyalty_discounts, purchases.robux) {
+        embed.field(
+            t(lang, "points.next"),
+            tf(
+                lang,
+                "points.next_description",
+                &[
+                    (
+                        "robux",
+                        &numbers::integer(lang, (next.min_robux - purchases.robux) as f64),
+                    ),
+                    ("percent", &next.percent),
+                ],
+            ),
+            false,
+        );
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_loyalty_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let min_robux = subcommand
+        .options
+        .get_int("min_robux")
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let storage = storage(ctx).await;
+
+    let title = match subcommand.name.as_str() {
+        "add" => {
+            let percent = subcommand
+                .options
+                .get_number("percent")
+                .ok_or(t(lang, "error.invalid_options"))
+                .map_err(str::to_string)
+                .and_then(|percent| validation::validate_discount_percent(percent, lang))?;
+
+            storage
+                .update_guild(guild_id, |config| {
+                    loyalty::upsert(
+                        &mut config.loyalty_discounts,
+                        Discount { min_robux, percent },
+                    )
+                })
+                .await?;
+            t(lang, "loyalty.saved")
+        }
+        "remove" => {
+            let mut removed = false;
+            storage
+                .update_guild(guild_id, |config| {
+                    removed = loyalty::remove(&mut config.loyalty_discounts, min_robux)
+                })
+                .await?;
+            if !removed {
+                return Err(tf(
+                    lang,
+                    "loyalty.not_found",
+                    &[("min", &numbers::integer(lang, min_robux as f64))],
+                ));
+            }
+            t(lang, "loyalty.removed")
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let config = storage.guild(guild_id).await?;
+    let description = if config.loyalty_discounts.is_empty() {
+        t(lang, "loyalty.empty").to_string()
+    } else {
+        config
+            .loyalty_discounts
+            .iter()
+            .map(|discount| {
+                tf(
+                    lang,
+                    "loyalty.line",
+                    &[
+                        ("min", &numbers::integer(lang, discount.min_robux as f64)),
+                        ("percent", &discount.percent),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let embed = CreateEmbed::default()
+        .title(title)
+        .description(description)
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Configures the roles buyers are given as their lifetime purchases grow.
+async fn handle_rolereward_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let min_robux = subcommand.options.get_int("min_robux");
+    let storage = storage(ctx).await;
+
+    let title = match subcommand.name.as_str() {
+        "add" => {
+            let min_robux = min_robux.ok_or(t(lang, "error.invalid_options"))?;
+            let role = subcommand
+                .options
+                .get_role("role")
+                .ok_or(t(lang, "error.invalid_options"))?;
+            if role.managed || role.id.0 == guild_id.0 {
+                return Err(t(lang, "rolereward.invalid_role").to_string());
+            }
+            storage
+                .update_guild(guild_id, |config| {
+                    loyalty::upsert_reward(
+                        &mut config.role_rewards,
+                        RoleReward {
+                            min_robux,
+                            role_id: role.id.0,
+                        },
+                    )
+                })
+                .await?;
+            t(lang, "rolereward.saved")
+        }
+        "remove" => {
+            let min_robux = min_robux.ok_or(t(lang, "error.invalid_options"))?;
+            let mut removed = false;
+            storage
+                .update_guild(guild_id, |config| {
+                    removed = loyalty::remove_reward(&mut config.role_rewards, min_robux)
+                })
+                .await?;
+            if !removed {
+                return Err(tf(
+                    lang,
+                    "rolereward.not_found",
+                    &[("min", &numbers::integer(lang, min_robux as f64))],
+                ));
+            }
+            t(lang, "rolereward.removed")
+        }
+        "list" => t(lang, "rolereward.title"),
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let config = storage.guild(guild_id).await?;
+    let description = if config.role_rewards.is_empty() {
+        t(lang, "rolereward.empty").to_string()
+    } else {
+        config
+            .role_rewards
+            .iter()
+            .map(|reward| {
+                tf(
+                    lang,
+                    "rolereward.line",
+                    &[
+                        ("min", &numbers::robux(lang, reward.min_robux as f64)),
+                        ("role", &RoleId(reward.role_id).mention()),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let embed = CreateEmbed::default()
+        .title(title)
+        .description(description)
+        .footer(|footer| footer.text(t(lang, "rolereward.footer")))
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Sets, removes or lists the discounts members get for having a role.
+async fn handle_roleprice_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let role = subcommand.options.get_role("role");
+    let storage = storage(ctx).await;
+
+    let title = match subcommand.name.as_str() {
+        "set" => {
+            let role = role.ok_or(t(lang, "error.invalid_options"))?;
+            if role.id.0 == guild_id.0 {
+                return Err(t(lang, "roleprice.everyone").to_string());
+            }
+            let percent = subcommand
+                .options
+                .get_number("percent")
+                .ok_or(t(lang, "error.invalid_options"))
+                .map_err(str::to_string)
+                .and_then(|percent| validation::validate_discount_percent(percent, lang))?;
+            storage
+                .update_guild(guild_id, |config| {
+                    loyalty::upsert_role_discount(
+                        &mut config.role_discounts,
+                        RoleDiscount {
+                            role_id: role.id.0,
+                            percent,
+                        },
+                    )
+                })
+                .await?;
+            t(lang, "roleprice.saved")
+        }
+        "remove" => {
+            let role = role.ok_or(t(lang, "error.invalid_options"))?;
+            let mut removed = false;
+            storage
+                .update_guild(guild_id, |config| {
+                    removed = loyalty::remove_role_discount(&mut config.role_discounts, role.id.0)
+                })
+                .await?;
+            if !removed {
+                return Err(tf(
+                    lang,
+                    "roleprice.not_found",
+                    &[("role", &role.id.mention())],
+                ));
+            }
+            t(lang, "roleprice.removed")
+        }
+        "list" => t(lang, "roleprice.title"),
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let config = storage.guild(guild_id).await?;
+    let description = if config.role_discounts.is_empty() {
+        t(lang, "roleprice.empty").to_string()
+    } else {
+        config
+            .role_discounts
+            .iter()
+            .map(|discount| {
+                tf(
+                    lang,
+                    "roleprice.percent_off",
+                    &[
+                        ("percent", &discount.percent),
+                        ("role", &RoleId(discount.role_id).mention()),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let embed = CreateEmbed::default()
+        .title(title)
+        .description(description)
+        .footer(|footer| footer.text(t(lang, "roleprice.footer")))
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_template_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let options = &subcommand.options;
+    let command_name = options
+        .get_str("command")
+        .filter(|name| templates::COMMANDS.contains(name))
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let storage = storage(ctx).await;
+
+    let title = match subcommand.name.as_str() {
+        "set" => {
+            let title = options
+                .get_str("title")
+                .map(|title| templates::validate_title(title, lang))
+                .transpose()?;
+            let description = options
+                .get_str("description")
+                .map(|description| templates::validate_description(description, lang))
+                .transpose()?;
+            if title.is_none() && description.is_none() {
+                return Err(t(lang, "template.nothing_to_set").to_string());
+            }
+            storage
+                .update_guild(guild_id, |config| {
+                    let template = templates::entry(&mut config.templates, command_name);
+                    if title.is_some() {
+                        template.title = title;
+                    }
+                    if description.is_some() {
+                        template.description = description;
+                    }
+                })
+                .await?;
+            t(lang, "template.saved")
+        }
+        "field" => {
+            let field = templates::validate_field(
+                options
+                    .get_str("name")
+                    .ok_or(t(lang, "error.invalid_options"))?,
+                options
+                    .get_str("value")
+                    .ok_or(t(lang, "error.invalid_options"))?,
+                lang,
+            )?;
+            let mut full = false;
+            storage
+                .update_guild(guild_id, |config| {
+                    let template = templates::entry(&mut config.templates, command_name);
+                    full = template.fields.len() >= templates::MAX_FIELDS;
+                    if !full {
+                        template.fields.push(field);
+                    }
+                })
+                .await?;
+            if full {
+                return Err(tf(
+                    lang,
+                    "template.too_many_fields",
+                    &[("max", &templates::MAX_FIELDS)],
+                ));
+            }
+            t(lang, "template.saved")
+        }
+        "reset" => {
+            let mut removed = false;
+            storage
+                .update_guild(guild_id, |config| {
+                    removed = templates::remove(&mut config.templates, command_name)
+                })
+                .await?;
+            if !removed {
+                return Err(tf(
+                    lang,
+                    "template.not_found",
+                    &[("command", &command_name)],
+                ));
+            }
+            t(lang, "template.reset")
+        }
+        "show" => t(lang, "template.title"),
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let config = storage.guild(guild_id).await?;
+    let mut embed = CreateEmbed::default();
+    embed.title(format!("{}: /{}", title, command_name));
+    match templates::find(&config.templates, command_name) {
+        Some(template) => {
+            let unset = || t(lang, "template.unset").to_string();
+            embed
+                .field(
+                    t(lang, "template.title_label"),
+                    template.title.clone().unwrap_or_else(unset),
+                    false,
+                )
+                .field(
+                    t(lang, "template.description_label"),
+                    template.description.clone().unwrap_or_else(unset),
+                    false,
+                );
+            for (name, value) in &template.fields {
+                embed.field(name, value, false);
+            }
+        }
+        None => {
+            embed.description(t(lang, "template.none"));
+        }
+    }
+    embed.footer(|footer| {
+        footer.text(tf(
+            lang,
+            "template.footer",
+            &[("variables", &templates::variable_list().replace('`', ""))],
+        ))
+    });
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_emojis_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let kind = subcommand
+        .options
+        .get_str("kind")
+        .and_then(EmojiKind::parse);
+    let storage = storage(ctx).await;
+
+    let title = match subcommand.name.as_str() {
+        "set" => {
+            let kind = kind.ok_or(t(lang, "error.invalid_options"))?;
+            let emoji = emojis::parse(
+                subcommand
+                    .options
+                    .get_str("emoji")
+                    .ok_or(t(lang, "error.invalid_options"))?,
+                lang,
+            )?;
+            storage
+                .update_guild(guild_id, |config| config.emojis.set(kind, Some(emoji)))
+                .await?;
+            t(lang, "emojis.saved")
+        }
+        "reset" => {
+            let kind = kind.ok_or(t(lang, "error.invalid_options"))?;
+            storage
+                .update_guild(guild_id, |config| config.emojis.set(kind, None))
+                .await?;
+            t(lang, "emojis.reset")
+        }
+        "list" => t(lang, "emojis.title"),
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let config = storage.guild(guild_id).await?;
+    let lines: Vec<String> = EmojiKind::ALL
+        .into_iter()
+        .map(|kind| {
+            format!(
+                "**{}:** {}",
+                kind.name(lang),
+                config
+                    .emojis
+                    .get(kind)
+                    .unwrap_or_else(|| kind.default_symbol())
+            )
+        })
+        .collect();
+    let embed = CreateEmbed::default()
+        .title(title)
+        .description(lines.join("\n"))
+        .footer(|footer| footer.text(t(lang, "emojis.footer")))
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Gives the buyer of a just-completed `order` every reward role their lifetime
+/// purchases have reached, announcing the ones this order unlocked in the ticket.
+#[cfg(feature = "orders")]
+async fn award_role_rewards(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    config: &GuildConfig,
+    order: &Order,
+    lang: Language,
+) {
+    if config.role_rewards.is_empty() {
+        return;
+    }
+    let purchased = match storage(ctx)
+        .await
+        .purchases(order.guild_id, order.buyer_id)
+        .await
+    {
+        Ok(purchases) => purchases.robux,
+        Err(error) => {
+            eprintln!("Error loading purchases for order {}: {}", order.id, error);
+            return;
+        }
+    };
+    let before = purchased.saturating_sub(order.robux);
+
+    let mut unlocked = Vec::new();
+    for reward in loyalty::rewards_for(&config.role_rewards, purchased) {
+        // Roles from earlier milestones are given again in case adding them failed
+        // before; Discord ignores roles the member already has.
+        let result = ctx
+            .http
+            .add_member_role(
+                order.guild_id,
+                order.buyer_id,
+                reward.role_id,
+                Some("Purchase milestone reached"),
+            )
+            .await;
+        match result {
+            Ok(()) if reward.min_robux > before => unlocked.push(reward),
+            Ok(()) => {}
+            Err(error) => {
+                let error = format!("Error adding role {}: {:?}", reward.role_id, error);
+                eprintln!("{}", error);
+                report_error(
+                    ctx,
+                    "Role reward not given",
+                    &error,
+                    interaction_context(
+                        ("Order", &order.id.to_string()),
+                        &command.user,
+                        command.guild_id,
+                    ),
+                )
+                .await;
+            }
+        }
+    }
+    if unlocked.is_empty() {
+        return;
+    }
+
+    let roles: Vec<_> = unlocked
+        .iter()
+        .map(|reward| RoleId(reward.role_id).mention().to_string())
+        .collect();
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "rolereward.unlocked")).description(tf(
+        lang,
+        "rolereward.unlocked_description",
+        &[
+            ("buyer", &UserId(order.buyer_id).mention()),
+            ("roles", &roles.join(", ")),
+            ("robux", &numbers::robux(lang, purchased as f64)),
+        ],
+    ));
+    config.theme.apply(&mut embed);
+    if let Err(why) = command
+        .channel_id
+        .send_message(&ctx.http, |message| message.set_embed(embed))
+        .await
+    {
+        eprintln!("Cannot post role reward notice: {}", why);
+    }
+}
+
+/// The best discount the member gets in the guild: the loyalty discount they've
+/// earned or one of their roles' `/roleprice` discounts, whichever is bigger.
+/// None while the guild has the Loyalty feature off.
+async fn member_discount(
+    ctx: &Context,
+    guild_id: GuildId,
+    user_id: UserId,
+    roles: &[RoleId],
+    config: &GuildConfig,
+) -> Result<Option<AppliedDiscount>, String> {
+    if !Feature::Loyalty.is_on(config) {
+        return Ok(None);
+    }
+    let loyalty = if config.loyalty_discounts.is_empty() {
+        None
+    } else {
+        let purchases = storage(ctx).await.purchases(guild_id.0, user_id.0).await?;
+        loyalty::discount_for(&config.loyalty_discounts, purchases.robux).cloned()
+    };
+    let roles: Vec<u64> = roles.iter().map(|role| role.0).collect();
+    Ok(loyalty::best_discount(
+        loyalty.as_ref(),
+        &config.role_discounts,
+        &roles,
+    ))
+}
+
+/// The label and value a quote shows its discount under, naming the role that
+/// gave it.
+fn discount_label(lang: Language, discount: &AppliedDiscount) -> (&'static str, String) {
+    match discount.role_id {
+        Some(role_id) => (
+            t(lang, "roleprice.discount"),
+            tf(
+                lang,
+                "roleprice.percent_off",
+                &[
+                    ("percent", &discount.percent),
+                    ("role", &RoleId(role_id).mention()),
+                ],
+            ),
+        ),
+        None => (
+            t(lang, "loyalty.discount"),
+            tf(
+                lang,
+                "loyalty.percent_off",
+                &[("percent", &discount.percent)],
+            ),
+        ),
+    }
+}
+
+async fn handle_coupon_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let code = subcommand.options.get_str("code").map(coupons::normalize);
+    let storage = storage(ctx).await;
+
+    let mut embed = CreateEmbed::default();
+    match subcommand.name.as_str() {
+        "create" => {
+            let code = code
+                .filter(|code| !code.is_empty())
+                .ok_or(t(lang, "error.invalid_options"))?;
+            let kind =
+                CouponKind::from_code(subcommand.options.get_str("kind").unwrap_or("percent"));
+            let value = subcommand
+                .options
+                .get_number("value")
+                .ok_or(t(lang, "error.invalid_options"))?;
+            let value = match kind {
+                CouponKind::Percent => validation::validate_discount_percent(value, lang)?,
+                CouponKind::Flat => validation::validate_fiat(value, lang)?,
+            };
+            let now = rates::now();
+            let coupon = Coupon {
+                guild_id: guild_id.0,
+                code: code.clone(),
+                kind,
+                value,
+                max_uses: subcommand.options.get_int("max_uses"),
+                uses: 0,
+                expires_at: subcommand
+                    .options
+                    .get_int("expires_in_days")
+                    .map(|days| now + days * 24 * 60 * 60),
+                created_by: command.user.id.0,
+                created_at: now,
+            };
+            if !storage.create_coupon(coupon.clone()).await? {
+                return Err(tf(lang, "coupon.exists", &[("code", &code)]));
+            }
+            embed
+                .title(t(lang, "coupon.created"))
+                .description(coupon_line(lang, &coupon));
+        }
+        "delete" => {
+            let code = code.ok_or(t(lang, "error.invalid_options"))?;
+            if !storage
+                .delete_coupon(guild_id.0, &code, rates::now())
+                .await?
+            {
+                return Err(tf(lang, "coupon.not_found", &[("code", &code)]));
+            }
+            let deleted = Deleted::Coupon(code.clone());
+            record_deletion(ctx, command, guild_id, &deleted).await;
+            embed.title(t(lang, "coupon.deleted")).description(tf(
+                lang,
+                "coupon.deleted_description",
+                &[("code", &code)],
+            ));
+            return send_undoable_response(ctx, command, embed, &deleted).await;
+        }
+        "list" => {
+            let coupons = storage.coupons(guild_id.0).await?;
+            let description = if coupons.is_empty() {
+                t(lang, "coupon.empty").to_string()
+            } else {
+                coupons
+                    .iter()
+                    .map(|coupon| coupon_line(lang, coupon))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            embed
+                .title(t(lang, "coupon.title"))
+                .description(description);
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Looks up `code` in the command's guild, failing unless it can still be redeemed.
+async fn usable_coupon(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    lang: Language,
+    code: &str,
+) -> Result<Coupon, String> {
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    if !Feature::Coupons.is_on(&guild_config(ctx, Some(guild_id)).await) {
+        return Err(tf(
+            lang,
+            "features.refused",
+            &[("feature", &Feature::Coupons.name(lang))],
+        ));
+    }
+    let code = coupons::normalize(code);
+    storage(ctx)
+        .await
+        .coupon(guild_id.0, &code)
+        .await?
+        .filter(|coupon| coupon.is_usable(rates::now()))
+        .ok_or_else(|| tf(lang, "coupon.invalid", &[("code", &code)]))
+}
+
+/// The code, discount and remaining uses, e.g. `SUMMER` (10% off, 4 uses left).
+fn coupon_summary(lang: Language, coupon: &Coupon) -> String {
+    let discount = match coupon.kind {
+        CouponKind::Percent => tf(lang, "loyalty.percent_off", &[("percent", &coupon.value)]),
+        CouponKind::Flat => tf(
+            lang,
+            "coupon.flat_off",
+            &[("amount", &numbers::gbp(lang, coupon.value))],
+        ),
+    };
+    let uses = match coupon.remaining_uses() {
+        Some(remaining) => tf(lang, "coupon.uses_left", &[("uses", &remaining)]),
+        None => t(lang, "coupon.unlimited").to_string(),
+    };
+    format!("`{}` ({}, {})", coupon.code, discount, uses)
+}
+
+fn coupon_line(lang: Language, coupon: &Coupon) -> String {
+    match coupon.expires_at {
+        Some(expiry) => format!(
+            "{} {}",
+            coupon_summary(lang, coupon),
+            tf(
+                lang,
+                "coupon.expires",
+                &[("time", &format!("<t:{}:R>", expiry))]
+            )
+        ),
+        None => coupon_summary(lang, coupon),
+    }
+}
+
+async fn handle_payment_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let option = |name: &str| subcommand.options.get_str(name);
+    let method = option("method").and_then(PaymentMethod::from_code);
+    let storage = storage(ctx).await;
+
+    let title = match subcommand.name.as_str() {
+        "set" => {
+            let method = method.ok_or(t(lang, "error.invalid_options"))?;
+            let handle = option("handle")
+                .and_then(|handle| method.normalize(handle))
+                .ok_or_else(|| {
+                    tf(
+                        lang,
+                        "payment.invalid_handle",
+                        &[("method", &method.name())],
+                    )
+                })?;
+            storage
+                .update_guild(guild_id, |config| {
+                    payments::set(
+                        &mut config.payment_handles,
+                        PaymentHandle { method, handle },
+                    )
+                })
+                .await?;
+            t(lang, "payment.saved")
+        }
+        "remove" => {
+            let method = method.ok_or(t(lang, "error.invalid_options"))?;
+            let mut removed = false;
+            storage
+                .update_guild(guild_id, |config| {
+                    removed = payments::remove(&mut config.payment_handles, method)
+                })
+                .await?;
+            if !removed {
+                return Err(tf(lang, "payment.not_found", &[("method", &method.name())]));
+            }
+            t(lang, "payment.removed")
+        }
+        "list" => t(lang, "payment.title"),
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let config = storage.guild(guild_id).await?;
+    let description = if config.payment_handles.is_empty() {
+        t(lang, "payment.empty").to_string()
+    } else {
+        payments::sorted(&config.payment_handles)
+            .iter()
+            .map(|payment| format!("**{}:** `{}`", payment.method.name(), payment.handle))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let embed = CreateEmbed::default()
+        .title(title)
+        .description(description)
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+/// A QR code of the guild's `coin` payment URI for `amount` of it, if the guild has
+/// an address for that coin.
+fn crypto_qr_code(
+    handles: &[PaymentHandle],
+    coin: Crypto,
+    amount: f64,
+) -> Result<Option<Vec<u8>>, String> {
+    handles
+        .iter()
+        .find(|handle| handle.method == PaymentMethod::Crypto(coin))
+        .map(|handle| qr::render(&handle.link(0.0, 0.0, Some(amount))))
+        .transpose()
+}
+
+/// One line per configured payment handle, each linking to a payment of `gbp`
+/// (or `usd` for Cash App). `None` if the guild hasn't set any up.
+async fn payment_links(
+    ctx: &Context,
+    handles: &[PaymentHandle],
+    gbp: f64,
+    usd: f64,
+) -> Option<String> {
+    if handles.is_empty() {
+        return None;
+    }
+    let rates = rates(ctx).await;
+    let rates = rates.read().await;
+    let lines = payments::sorted(handles)
+        .iter()
+        .map(|payment| {
+            let line = match payment.method {
+                PaymentMethod::Crypto(coin) => {
+                    let amount = rates.crypto_gbp.get(&coin).map(|price| gbp / price);
+                    format!("`{}`", payment.link(gbp, usd, amount))
+                }
+                _ => {
+                    let link = payment.link(gbp, usd, None);
+                    format!("[{}]({})", link.trim_start_matches("https://"), link)
+                }
+            };
+            format!("**{}:** {}", payment.method.name(), line)
+        })
+        .collect::<Vec<_>>();
+    Some(lines.join("\n"))
+}
+
+async fn handle_vat_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let vat = match subcommand.name.as_str() {
+        "set" => {
+            let rate = subcommand
+                .options
+                .get_number("rate")
+                .filter(|rate| *rate > 0.0 && *rate <= vat::MAX_VAT_RATE)
+                .ok_or_else(|| tf(lang, "vat.invalid_rate", &[("max", &vat::MAX_VAT_RATE)]))?;
+            let region = subcommand
+                .options
+                .get_str("region")
+                .and_then(vat::normalize_region)
+                .ok_or(t(lang, "vat.invalid_region"))?;
+            Some(VatSettings { rate, region })
+        }
+        "clear" => None,
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let description = match &vat {
+        Some(vat) => tf(
+            lang,
+            "vat.set_description",
+            &[("summary", &vat_summary(lang, vat))],
+        ),
+        None => t(lang, "vat.cleared_description").to_string(),
+    };
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| config.vat = vat)
+        .await?;
+
+    let embed = CreateEmbed::default()
+        .title(t(lang, "vat.title"))
+        .description(description)
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+/// The rate and region, e.g. `20% (UK)`.
+fn vat_summary(lang: Language, vat: &VatSettings) -> String {
+    tf(
+        lang,
+        "vat.summary",
+        &[("rate", &vat.rate), ("region", &vat.region)],
+    )
+}
+
+async fn handle_stats_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !is_ticket_staff(command, &config) {
+        return Err(t(lang, "stats.staff_only").to_string());
+    }
+
+    let options = &command.data.options;
+    let period = options
+        .get_str("period")
+        .map_or(Some(Period::Week), Period::parse)
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let storage = storage(ctx).await;
+    let since = rates::now().saturating_sub(period.seconds());
+    let orders = storage.completed_orders(guild_id.0, since).await?;
+    let refunds = storage.refunds(guild_id.0, since).await?;
+    let summary = stats::summarize(&orders, &refunds);
+
+    let mut embed = CreateEmbed::default();
+    embed.title(tf(
+        lang,
+        "stats.title",
+        &[("period", &t(lang, period.label_key()))],
+    ));
+    if orders.is_empty() && refunds.is_empty() {
+        embed.description(t(lang, "stats.empty"));
+        return send_embed_response(ctx, command, embed).await;
+    }
+
+    embed
+        .field(t(lang, "stats.orders"), summary.orders, true)
+        .field(
+            t(lang, "stats.robux"),
+            numbers::robux(lang, summary.robux as f64),
+            true,
+        )
+        .field(
+            t(lang, "stats.average"),
+            format!(
+                "{} / {}",
+                numbers::robux(lang, summary.average_robux()),
+                numbers::gbp(lang, summary.average_gbp())
+            ),
+            true,
+        )
+        .field(
+            tf(lang, "stats.revenue_in", &[("currency", &"GBP")]),
+            numbers::gbp(lang, summary.net_gbp()),
+            true,
+        )
+        .field(
+            tf(lang, "stats.revenue_in", &[("currency", &"USD")]),
+            numbers::usd(lang, summary.net_usd()),
+            true,
+        );
+    if summary.refunds > 0 {
+        embed.field(
+            t(lang, "stats.refunds"),
+            tf(
+                lang,
+                "stats.refund_total",
+                &[
+                    ("refunds", &summary.refunds),
+                    ("gbp", &numbers::gbp(lang, summary.refunded_gbp)),
+                    ("gross", &numbers::gbp(lang, summary.gbp)),
+                ],
+            ),
+            true,
+        );
+    }
+    if summary.tax_gbp > 0.0 {
+        embed.field(
+            t(lang, "vat.label"),
+            numbers::gbp(lang, summary.tax_gbp),
+            true,
+        );
+    }
+    let top_customers = summary
+        .top_customers
+        .iter()
+        .enumerate()
+        .map(|(rank, customer)| {
+            format!(
+                "{}. {} — {} ({})",
+                rank + 1,
+                UserId(customer.buyer_id).mention(),
+                numbers::gbp(lang, customer.gbp),
+                tf(lang, "stats.order_count", &[("orders", &customer.orders)])
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    embed.field(t(lang, "stats.top_customers"), top_customers, false);
+
+    if bool_option(options, "csv") {
+        let csv = export::orders(&orders, export::Format::Csv).into_bytes();
+        send_embed_with_file(ctx, command, embed, csv, "orders.csv").await
+    } else {
+        send_embed_response(ctx, command, embed).await
+    }
+}
+
+/// Turns the weekly digest on or off for the staff member running it, or shows
+/// the week so far.
+async fn handle_digest_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !is_ticket_staff(command, &config) {
+        return Err(t(lang, "digest.staff_only").to_string());
+    }
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let user_id = command.user.id.0;
+    let subscribe = match subcommand.name.as_str() {
+        "subscribe" => true,
+        "unsubscribe" => false,
+        "preview" => {
+            let now = rates::now();
+            let embed = digest_embed(ctx, guild_id, lang, digest::week_start(now), now).await?;
+            return send_ephemeral_embed_response(ctx, command, embed).await;
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| {
+            config.digest_recipients.retain(|id| *id != user_id);
+            if subscribe {
+                config.digest_recipients.push(user_id);
+                config.digest_recipients.sort_unstable();
+            }
+        })
+        .await?;
+
+    let embed = CreateEmbed::default()
+        .title(t(lang, "digest.name"))
+        .description(t(
+            lang,
+            if subscribe {
+                "digest.subscribed"
+            } else {
+                "digest.unsubscribed"
+            },
+        ))
+        .clone();
+    send_ephemeral_embed_response(ctx, command, embed).await
+}
+
+/// Last week's digest goes out early on Monday (UTC) to every guild's
+/// recipients.
+struct DigestJob {
+    /// The week most recently handled, so the claims aren't retried every run.
+    handled_week: u64,
+}
+
+#[async_trait]
+impl Job for DigestJob {
+    async fn run(&mut self, ctx: &Context) {
+        let now = rates::now();
+        let end = digest::week_start(now);
+        let start = end.saturating_sub(digest::WEEK_SECONDS);
+        if start == self.handled_week || now < end + digest::SEND_DELAY_SECONDS {
+            return;
+        }
+        let storage = storage(ctx).await;
+        let guilds = match storage.digest_guilds().await {
+            Ok(guilds) => guilds,
+            Err(error) => {
+                eprintln!("Error loading digest guilds: {}", error);
+                return;
+            }
+        };
+        for guild_id in guilds {
+            match storage.claim_digest(guild_id.0, start).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(error) => {
+                    eprintln!("Error claiming digest for guild {}: {}", guild_id, error);
+                    continue;
+                }
+            }
+            if let Err(error) = send_digest(ctx, guild_id, start, end).await {
+                eprintln!("Error sending digest for guild {}: {}", guild_id, error);
+            }
+        }
+        self.handled_week = start;
+    }
+}
+
+async fn send_digest(ctx: &Context, guild_id: GuildId, start: u64, end: u64) -> Result<(), String> {
+    let config = guild_config(ctx, Some(guild_id)).await;
+    let embed = digest_embed(
+        ctx,
+        guild_id,
+        config.language.unwrap_or_default(),
+        start,
+        end,
+    )
+    .await?;
+    for user_id in &config.digest_recipients {
+        let sent = match UserId(*user_id).create_dm_channel(&ctx.http).await {
+            Ok(channel) => channel
+                .send_message(&ctx.http, |message| message.set_embed(embed.clone()))
+                .await
+                .map(|_| ()),
+            Err(error) => Err(error),
+        };
+        // Someone with closed DMs shouldn't stop the rest getting theirs.
+        if let Err(error) = sent {
+            eprintln!("Error sending digest to {}: {}", user_id, error);
+        }
+    }
+    Ok(())
+}
+
+/// The guild's orders, vouches and GBP/USD movement from `start` up to `end`.
+async fn digest_embed(
+    ctx: &Context,
+    guild_id: GuildId,
+    lang: Language,
+    start: u64,
+    end: u64,
+) -> Result<CreateEmbed, String> {
+    let storage = storage(ctx).await;
+    let digest = digest::build(
+        start,
+        end,
+        &storage.completed_orders(guild_id.0, start).await?,
+        &storage.refunds(guild_id.0, start).await?,
+        &storage.vouches(guild_id.0, start).await?,
+        &storage.rate_history(start).await?,
+    );
+    let guild_name = guild_id
+        .to_partial_guild(&ctx.http)
+        .await
+        .map_or_else(|_| guild_id.to_string(), |guild| guild.name);
+
+    let vouches = match digest.average_stars {
+        Some(average) => tf(
+            lang,
+            "digest.vouch_count",
+            &[
+                ("vouches", &digest.vouches),
+                ("stars", &numbers::decimal(lang, average, 1)),
+            ],
+        ),
+        None => t(lang, "digest.no_vouches").to_string(),
+    };
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(lang, "digest.title", &[("guild", &guild_name)]))
+        .description(tf(
+            lang,
+            "digest.period",
+            &[("start", &digest.start), ("end", &(digest.end - 1))],
+        ))
+        .field(t(lang, "stats.orders"), digest.orders.orders, true)
+        .field(
+            t(lang, "stats.robux"),
+            numbers::robux(lang, digest.orders.robux as f64),
+            true,
+        )
+        .field(
+            tf(lang, "stats.revenue_in", &[("currency", &"GBP")]),
+            numbers::gbp(lang, digest.orders.net_gbp()),
+            true,
+        )
+        .field(
+            tf(lang, "stats.revenue_in", &[("currency", &"USD")]),
+            numbers::usd(lang, digest.orders.net_usd()),
+            true,
+        )
+        .field(t(lang, "digest.vouches"), vouches, true);
+    if let (Some((first, last)), Some(change)) = (digest.rate, digest.rate_change_percent()) {
+        embed.field(
+            t(lang, "digest.rate"),
+            format!(
+                "{} → {} ({}{}%)",
+                numbers::decimal(lang, first, 4),
+                numbers::decimal(lang, last, 4),
+                if change < 0.0 { "-" } else { "+" },
+                numbers::decimal(lang, change.abs(), 2)
+            ),
+            true,
+        );
+    }
+    guild_config(ctx, Some(guild_id))
+        .await
+        .theme
+        .apply(&mut embed);
+    Ok(embed)
+}
+
+async fn handle_export_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !is_ticket_staff(command, &config) {
+        return Err(t(lang, "export.staff_only").to_string());
+    }
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let option = |name: &str| subcommand.options.get_str(name);
+    // Without a period everything is exported.
+    let period = option("period")
+        .map(|period| Period::parse(period).ok_or(t(lang, "error.invalid_options")))
+        .transpose()?;
+    let since = period.map_or(0, |period| rates::now().saturating_sub(period.seconds()));
+    let format = option("format")
+        .map_or(Some(export::Format::Csv), export::Format::parse)
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let storage = storage(ctx).await;
+    let (data, count) = match subcommand.name.as_str() {
+        "orders" => {
+            let orders = storage.orders(guild_id.0, since).await?;
+            (export::orders(&orders, format), orders.len())
+        }
+        "calculations" => {
+            let calculations = storage.guild_calculations(guild_id.0, since).await?;
+            (
+                export::calculations(&calculations, format),
+                calculations.len(),
+            )
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+    let filename = format!("{}.{}", subcommand.name, format.extension());
+    let period = period.map_or(t(lang, "export.all_time"), |period| {
+        t(lang, period.label_key())
+    });
+
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "export.title")).description(tf(
+        lang,
+        "export.description",
+        &[("count", &count), ("file", &filename), ("period", &period)],
+    ));
+
+    if bool_option(&subcommand.options, "dm") {
+        config.theme.apply(&mut embed);
+        command
+            .user
+            .create_dm_channel(&ctx.http)
+            .await
+            .map_err(|_| t(lang, "export.dm_failed").to_string())?
+            .send_message(&ctx.http, |message| {
+                message.set_embed(embed).add_file(AttachmentType::Bytes {
+                    data: data.into_bytes().into(),
+                    filename: filename.clone(),
+                })
+            })
+            .await
+            .map_err(|_| t(lang, "export.dm_failed").to_string())?;
+
+        let embed = CreateEmbed::default()
+            .title(t(lang, "export.title"))
+            .description(t(lang, "export.sent_dm"))
+            .clone();
+        send_embed_response(ctx, command, embed).await
+    } else {
+        send_embed_with_file(ctx, command, embed, data.into_bytes(), &filename).await
+    }
+}
+
+async fn handle_sheets_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let option = |name: &str| subcommand.options.get_str(name);
+
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "sheets.title"));
+    let settings = match subcommand.name.as_str() {
+        "set" => {
+            let client = sheets_client(ctx)
+                .await
+                .ok_or(t(lang, "sheets.not_configured"))?;
+            let spreadsheet_id = option("spreadsheet")
+                .and_then(sheets::parse_spreadsheet_id)
+                .ok_or(t(lang, "sheets.invalid_spreadsheet"))?;
+            let sheet = option("sheet")
+                .map(str::trim)
+                .filter(|sheet| !sheet.is_empty())
+                .unwrap_or(sheets::DEFAULT_SHEET)
+                .to_string();
+            let settings = SheetSettings {
+                spreadsheet_id,
+                sheet,
+            };
+            if bool_option(&subcommand.options, "headers") {
+                client
+                    .append_row(&settings, sheets::header_row())
+                    .await
+                    .map_err(|error| {
+                        eprintln!("{}", error);
+                        tf(
+                            lang,
+                            "sheets.append_failed",
+                            &[("email", &client.client_email())],
+                        )
+                    })?;
+            }
+            embed.description(tf(
+                lang,
+                "sheets.set_description",
+                &[
+                    ("sheet", &settings.sheet),
+                    ("email", &client.client_email()),
+                ],
+            ));
+            Some(settings)
+        }
+        "clear" => {
+            embed.description(t(lang, "sheets.cleared_description"));
+            None
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| config.sheets = settings)
+        .await?;
+    send_embed_response(ctx, command, embed).await
+}
+
+/// The Sheets client, if `GOOGLE_SERVICE_ACCOUNT_FILE` was set at startup.
+async fn sheets_client(ctx: &Context) -> Option<Arc<Sheets>> {
+    state(ctx).await.sheets.clone()
+}
+
+/// Appends a just-completed order to the guild's sheet in the background, so a
+/// slow or failing Sheets API doesn't hold up `/order complete`.
+#[cfg(feature = "orders")]
+async fn sync_order_to_sheet(ctx: &Context, config: &GuildConfig, order: &Order, seller_id: u64) {
+    let (Some(settings), Some(client)) = (config.sheets.clone(), sheets_client(ctx).await) else {
+        return;
+    };
+    let row = sheets::order_row(
+        order,
+        seller_id,
+        order.completed_at.unwrap_or_else(rates::now),
+    );
+    let id = order.id;
+    tokio::spawn(async move {
+        if let Err(error) = client.append_row(&settings, row).await {
+            eprintln!("Error syncing order {} to Google Sheets: {}", id, error);
+        }
+    });
+}
+
+#[cfg(any(feature = "orders", feature = "roblox"))]
+async fn roblox_client(ctx: &Context) -> Option<Arc<Roblox>> {
+    state(ctx).await.roblox.clone()
+}
+
+/// Queues a group payout for a just-completed group payout order and posts it
+/// in the ticket for staff to approve. Does nothing unless `ROBLOX_COOKIE` is set.
+#[cfg(feature = "orders")]
+async fn request_payout(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    order: &Order,
+    roblox_username: Option<String>,
+    lang: Language,
+) {
+    if order.method != DeliveryMethod::GroupPayout {
+        return;
+    }
+    if roblox_client(ctx).await.is_none() {
+        return;
+    }
+    let result = match roblox_username {
+        Some(username) => queue_payout(ctx, command, order, &username, lang).await,
+        None => Err(t(lang, "payout.no_username").to_string()),
+    };
+    let Err(error) = result else {
+        return;
+    };
+    if opslog::is_unexpected(&error) {
+        eprintln!("Error queueing payout for order {}: {}", order.id, error);
+        report_error(
+            ctx,
+            "Payout not queued",
+            &error,
+            interaction_context(
+                ("Order", &order.id.to_string()),
+                &command.user,
+                command.guild_id,
+            ),
+        )
+        .await;
+    }
+    let embed = CreateEmbed::default()
+        .title(t(lang, "payout.not_queued"))
+        .description(if opslog::is_unexpected(&error) {
+            t(lang, "payout.queue_failed").to_string()
+        } else {
+            error
+        })
+        .clone();
+    if let Err(why) = command
+        .channel_id
+        .send_message(&ctx.http, |message| message.set_embed(embed))
+        .await
+    {
+        eprintln!("Cannot post payout notice: {}", why);
+    }
+}
+
+#[cfg(feature = "orders")]
+async fn queue_payout(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    order: &Order,
+    username: &str,
+    lang: Language,
+) -> Result<(), String> {
+    let user = roblox::user_by_name(username)
+        .await?
+        .ok_or_else(|| tf(lang, "payout.unknown_user", &[("username", &username)]))?;
+    let mut payout = Payout {
+        id: 0,
+        guild_id: order.guild_id,
+        order_id: order.id,
+        roblox_user_id: user.id,
+        roblox_username: user.name,
+        robux: order.robux,
+        status: PayoutStatus::Pending,
+        requested_by: command.user.id.0,
+        requested_at: rates::now(),
+        reviewed_by: None,
+        reviewed_at: None,
+        transaction_id: None,
+        error: None,
+    };
+    payout.id = storage(ctx).await.create_payout(payout.clone()).await?;
+
+    let embed = payout_embed(&payout, lang);
+    command
+        .channel_id
+        .send_message(&ctx.http, |message| {
+            message
+                .set_embed(embed)
+                .components(|components| payout_buttons(components, &payout, lang))
+        })
+        .await
+        .map_err(|e| format!("Error posting payout request: {:?}", e))?;
+    Ok(())
+}
+
+#[cfg(feature = "orders")]
+fn payout_embed(payout: &Payout, lang: Language) -> CreateEmbed {
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(
+            lang,
+            "payout.title",
+            &[("id", &payout.id), ("order", &payout.order_id)],
+        ))
+        .field(
+            t(lang, "payout.recipient"),
+            format!(
+                "[{}](https://www.roblox.com/users/{}/profile)",
+                payout.roblox_username, payout.roblox_user_id
+            ),
+            true,
+        )
+        .field(
+            t(lang, "payout.amount"),
+            numbers::robux(lang, payout.robux as f64),
+            true,
+        )
+        .field(t(lang, "payout.status"), payout.status.name(lang), true)
+        .field(
+            t(lang, "payout.requested_by"),
+            UserId(payout.requested_by).mention(),
+            true,
+        );
+    if let (Some(reviewed_by), Some(reviewed_at)) = (payout.reviewed_by, payout.reviewed_at) {
+        embed.field(
+            t(lang, "payout.reviewed_by"),
+            format!("{} <t:{}:R>", UserId(reviewed_by).mention(), reviewed_at),
+            true,
+        );
+    }
+    if let Some(transaction_id) = &payout.transaction_id {
+        embed.field(
+            t(lang, "payout.transaction"),
+            format!("`{}`", transaction_id),
+            true,
+        );
+    }
+    if let Some(error) = &payout.error {
+        embed.field(t(lang, "payout.error"), error, false);
+    }
+    embed
+}
+
+/// Approve and Reject buttons while `payout` is pending, and none after.
+#[cfg(feature = "orders")]
+fn payout_buttons<'a>(
+    components: &'a mut CreateComponents,
+    payout: &Payout,
+    lang: Language,
+) -> &'a mut CreateComponents {
+    if payout.status != PayoutStatus::Pending {
+        return components;
+    }
+    components.create_action_row(|row| {
+        row.create_button(|button| {
+            if let Some(emoji) = emojis::button(EmojiKind::Check) {
+                button.emoji(emoji);
+            }
+            button
+                .custom_id(format!("{}:approve:{}", PAYOUT_BUTTON_ID, payout.id))
+                .style(ButtonStyle::Success)
+                .label(t(lang, "payout.approve"))
+        })
+        .create_button(|button| {
+            if let Some(emoji) = emojis::button(EmojiKind::Cross) {
+                button.emoji(emoji);
+            }
+            button
+                .custom_id(format!("{}:reject:{}", PAYOUT_BUTTON_ID, payout.id))
+                .style(ButtonStyle::Danger)
+                .label(t(lang, "payout.reject"))
+        })
+    })
+}
+
+/// Approves or rejects a queued payout. Approving sends it through the Groups
+/// API straight away and records the transaction.
+#[cfg(feature = "orders")]
+async fn handle_payout_button(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+    lang: Language,
+) -> Result<(), String> {
+    let guild_id = component.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !is_ticket_staff_member(component.member.as_ref(), &config) {
+        return Err(t(lang, "payout.staff_only").to_string());
+    }
+    let mut parts = component.data.custom_id.split(':').skip(1);
+    let (approve, id) = match (parts.next(), parts.next().and_then(|id| id.parse().ok())) {
+        (Some("approve"), Some(id)) => (true, id),
+        (Some("reject"), Some(id)) => (false, id),
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+    let roblox = roblox_client(ctx).await;
+    if approve && roblox.is_none() {
+        return Err(t(lang, "payout.not_configured").to_string());
+    }
+
+    let storage = storage(ctx).await;
+    let status = if approve {
+        PayoutStatus::Sending
+    } else {
+        PayoutStatus::Rejected
+    };
+    let reviewed = match storage.payout(id).await? {
+        Some(payout) if payout.guild_id == guild_id.0 => {
+            storage
+                .review_payout(id, status, component.user.id.0, rates::now())
+                .await?
+        }
+        _ => false,
+    };
+    if !reviewed {
+        return Err(t(lang, "payout.already_reviewed").to_string());
+    }
+
+    // Sending can take longer than Discord waits for a reply.
+    component
+        .create_interaction_response(&ctx.http, |response| {
+            response.kind(InteractionResponseType::DeferredUpdateMessage)
+        })
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))?;
+
+    if let Some(roblox) = roblox.filter(|_| approve) {
+        let payout = storage
+            .payout(id)
+            .await?
+            .ok_or(t(lang, "payout.not_found"))?;
+        match roblox.pay_out(payout.roblox_user_id, payout.robux).await {
+            Ok(transaction_id) => {
+                storage
+                    .finish_payout(id, PayoutStatus::Paid, transaction_id.as_deref(), None)
+                    .await?
+            }
+            Err(error) => {
+                eprintln!("Error sending payout {}: {}", id, error);
+                report_error(
+                    ctx,
+                    "Payout failed",
+                    &error,
+                    interaction_context(
+                        ("Payout", &id.to_string()),
+                        &component.user,
+                        component.guild_id,
+                    ),
+                )
+                .await;
+                storage
+                    .finish_payout(id, PayoutStatus::Failed, None, Some(&error))
+                    .await?
+            }
+        }
+    }
+
+    let payout = storage
+        .payout(id)
+        .await?
+        .ok_or(t(lang, "payout.not_found"))?;
+    let mut embed = payout_embed(&payout, lang);
+    config.theme.apply(&mut embed);
+    component
+        .edit_original_interaction_response(&ctx.http, |response| {
+            response
+                .set_embed(embed)
+                .components(|components| payout_buttons(components, &payout, lang))
+        })
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Error editing response: {:?}", e))
+}
+
+async fn handle_webhook_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let url = subcommand.options.get_str("url").map(str::trim);
+    let storage = storage(ctx).await;
+
+    let mut embed = CreateEmbed::default();
+    match subcommand.name.as_str() {
+        "add" => {
+            let url = url
+                .and_then(webhooks::validate_url)
+                .ok_or(t(lang, "webhook.invalid_url"))?;
+            let secret = secrets::generate()?;
+            let webhook = Webhook {
+                url: url.clone(),
+                secret: secret.clone(),
+            };
+            let mut result = Ok(());
+            storage
+                .update_guild(guild_id, |config| {
+                    result = if config.webhooks.len() >= webhooks::MAX_WEBHOOKS {
+                        Err(tf(
+                            lang,
+                            "webhook.limit",
+                            &[("max", &webhooks::MAX_WEBHOOKS)],
+                        ))
+                    } else if !webhooks::add(&mut config.webhooks, webhook) {
+                        Err(t(lang, "webhook.exists").to_string())
+                    } else {
+                        Ok(())
+                    }
+                })
+                .await?;
+            result?;
+
+            // The secret is only ever shown here, so keep it out of the channel.
+            embed.title(t(lang, "webhook.added")).description(tf(
+                lang,
+                "webhook.added_description",
+                &[
+                    ("url", &url),
+                    ("secret", &secret),
+                    ("header", &webhooks::SIGNATURE_HEADER),
+                ],
+            ));
+            return send_ephemeral_embed_response(ctx, command, embed).await;
+        }
+        "remove" => {
+            let url = url.ok_or(t(lang, "error.invalid_options"))?;
+            let mut removed = false;
+            storage
+                .update_guild(guild_id, |config| {
+                    removed = webhooks::remove(&mut config.webhooks, url)
+                })
+                .await?;
+            if !removed {
+                return Err(t(lang, "webhook.not_found").to_string());
+            }
+            embed
+                .title(t(lang, "webhook.removed"))
+                .description(format!("<{}>", url));
+        }
+        "list" => {
+            let config = storage.guild(guild_id).await?;
+            let description = if config.webhooks.is_empty() {
+                t(lang, "webhook.empty").to_string()
+            } else {
+                config
+                    .webhooks
+                    .iter()
+                    .map(|webhook| format!("<{}>", webhook.url))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            embed
+                .title(t(lang, "webhook.title"))
+                .description(description);
+        }
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    }
+
+    send_embed_response(ctx, command, embed).await
+}
+
+#[cfg(feature = "api")]
+async fn handle_apikey_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "apikey.title"));
+    let key = match subcommand.name.as_str() {
+        "rotate" => Some(api::generate_key()?),
+        "revoke" => None,
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+    let key_hash = key.as_deref().map(secrets::sha256);
+    storage(ctx)
+        .await
+        .update_guild(guild_id, |config| config.api_key_hash = key_hash)
+        .await?;
+
+    match key {
+        // Only the hash is stored, so this is the one chance to copy the key.
+        Some(key) => {
+            embed.description(tf(lang, "apikey.rotated_description", &[("key", &key)]));
+            send_ephemeral_embed_response(ctx, command, embed).await
+        }
+        None => {
+            embed.description(t(lang, "apikey.revoked_description"));
+            send_embed_response(ctx, command, embed).await
+        }
+    }
+}
+
+async fn handle_calculate_message_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    refuse_blacklisted(ctx, command, lang).await?;
+    let message = command
+        .data
+        .target_id
+        .and_then(|id| command.data.resolved.messages.get(&id.to_message_id()))
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let amounts: Vec<_> = parse::find_robux_amounts(&message.content)
+        .into_iter()
+        .filter(|robux| validation::validate_robux(*robux, lang).is_ok())
+        .take(MAX_MESSAGE_AMOUNTS)
+        .collect();
+    if amounts.is_empty() {
+        return Err(t(lang, "calculate.none").to_string());
+    }
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let usd_rate = gbp_to_usd(ctx).await;
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "price.title")).description(tf(
+        lang,
+        "calculate.description",
+        &[
+            ("author", &message.author.mention()),
+            ("link", &message.link()),
+        ],
+    ));
+
+    add_quote_fields(&mut embed, lang, &config, usd_rate, &amounts);
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Adds a field per amount with its before- and after-tax gamepass prices.
+fn add_quote_fields(
+    embed: &mut CreateEmbed,
+    lang: Language,
+    config: &GuildConfig,
+    usd_rate: f64,
+    amounts: &[u64],
+) {
+    for &robux in amounts {
+        let base_rate = pricing::base_rate(&config.tiers, &config.shop_rates, robux, None);
+        let value = [("b/t", false), ("a/t", true)]
+            .into_iter()
+            .map(|(label, after_tax)| {
+                let gbp = pricing::quote(
+                    robux as f64,
+                    base_rate,
+                    DeliveryMethod::Gamepass,
+                    after_tax,
+                    None,
+                    config.vat.as_ref(),
+                )
+                .gbp();
+                format!(
+                    "**{}:** {} / {} ({}: {})",
+                    label,
+                    numbers::gbp(lang, gbp),
+                    numbers::usd(lang, gbp * usd_rate),
+                    DeliveryMethod::Gamepass.listing_label(lang),
+                    numbers::robux(
+                        lang,
+                        DeliveryMethod::Gamepass.listing_price(robux as f64, after_tax) as f64
+                    )
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        embed.field(numbers::robux(lang, robux as f64), value, false);
+    }
+}
+
+async fn handle_autoreply_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let channel = subcommand
+        .options
+        .get_channel("channel")
+        .map(|channel| channel.id)
+        .unwrap_or(command.channel_id);
+    let enable = match subcommand.name.as_str() {
+        "enable" => true,
+        "disable" => false,
+        _ => return Err(t(lang, "error.invalid_options").to_string()),
+    };
+
+    let storage = storage(ctx).await;
+    storage
+        .update_guild(guild_id, |config| {
+            config.autoreply_channels.retain(|id| *id != channel.0);
+            if enable {
+                config.autoreply_channels.push(channel.0);
+                config.autoreply_channels.sort_unstable();
+            }
+        })
+        .await?;
+    let channels = storage.guild(guild_id).await?.autoreply_channels;
+
+    let mut description = tf(
+        lang,
+        if enable {
+            "autoreply.enabled"
+        } else {
+            "autoreply.disabled"
+        },
+        &[("channel", &channel.mention())],
+    );
+    description.push_str("\n\n");
+    if channels.is_empty() {
+        description.push_str(t(lang, "autoreply.none"));
+    } else {
+        let channels: Vec<_> = channels
+            .iter()
+            .map(|id| ChannelId(*id).mention().to_string())
+            .collect();
+        description.push_str(&tf(
+            lang,
+            "autoreply.channels",
+            &[("channels", &channels.join(", "))],
+        ));
+    }
+    let embed = CreateEmbed::default()
+        .title(t(lang, "autoreply.title"))
+        .description(description)
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Replies with prices when a message in an auto-reply channel asks about Robux.
+async fn auto_reply(ctx: &Context, message: &Message) -> Result<(), String> {
+    let Some(guild_id) = message.guild_id else {
+        return Ok(());
+    };
+    if message.author.bot {
+        return Ok(());
+    }
+    // Parse before touching storage so ordinary chat stays cheap.
+    let amounts: Vec<_> = parse::mentioned_robux_amounts(&message.content)
+        .into_iter()
+        .filter(|robux| validation::validate_robux(*robux, Language::default()).is_ok())
+        .take(MAX_MESSAGE_AMOUNTS)
+        .collect();
+    if amounts.is_empty() {
+        return Ok(());
+    }
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !config.autoreply_channels.contains(&message.channel_id.0)
+        || !Feature::AutoReply.is_on(&config)
+    {
+        return Ok(());
+    }
+    let storage = storage(ctx).await;
+    if !storage
+        .blacklist_entries(Some(guild_id.0), message.author.id.0)
+        .await?
+        .is_empty()
+    {
+        return Ok(());
+    }
+
+    let lang = config.language.unwrap_or_default();
+    let mut embed = CreateEmbed::default();
+    embed.title(t(lang, "price.title"));
+    add_quote_fields(&mut embed, lang, &config, gbp_to_usd(ctx).await, &amounts);
+    config.theme.apply(&mut embed);
+
+    message
+        .channel_id
+        .send_message(&ctx.http, |reply| {
+            reply
+                .set_embed(embed)
+                .reference_message(message)
+                .allowed_mentions(|mentions| mentions.replied_user(false))
+        })
+        .await
+        .map_err(|e| format!("Error sending auto-reply: {:?}", e))?;
+    Ok(())
+}
+
+#[cfg(feature = "orders")]
+async fn handle_orderform_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let config = guild_config(ctx, command.guild_id).await;
+    if config.tickets.staff_role.is_none() {
+        return Err(t(lang, "ticket.not_configured").to_string());
+    }
+
+    let mut embed = CreateEmbed::default()
+        .title(t(lang, "orderform.title"))
+        .description(t(lang, "orderform.description"))
+        .clone();
+    config.theme.apply(&mut embed);
+
+    reply::with_backoff(|| {
+        command.create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|message| {
+                    message.add_embed(embed.clone()).components(|components| {
+                        components.create_action_row(|row| {
+                            row.create_button(|button| {
+                                button
+                                    .custom_id(ORDER_FORM_ID)
+                                    .style(ButtonStyle::Success)
+                                    .emoji('🛒')
+                                    .label(t(lang, "orderform.button"))
+                            })
+                        })
+                    })
+                })
+        })
+    })
+    .await
+    .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Routes button presses and menu picks by custom id.
+async fn handle_component(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+) -> Result<(), String> {
+    let lang = guild_language(ctx, component.guild_id, component.guild_locale.as_deref()).await;
+    let custom_id = component.data.custom_id.as_str();
+    #[cfg(feature = "orders")]
+    if custom_id == ORDER_FORM_ID {
+        refuse_disabled_feature(ctx, component.guild_id, "orderform", lang).await?;
+        return open_order_form(ctx, component, lang).await;
+    } else if custom_id.starts_with(PAYOUT_BUTTON_ID) {
+        return handle_payout_button(ctx, component, lang).await;
+    } else if custom_id.starts_with(MIDDLEMAN_BUTTON_ID) {
+        return handle_middleman_button(ctx, component, lang).await;
+    } else if custom_id.starts_with(REVIEW_BUTTON_ID) {
+        return handle_review_button(ctx, component).await;
+    }
+    #[cfg(feature = "roblox")]
+    if custom_id == VERIFY_BUTTON_ID {
+        return handle_verify_button(ctx, component, lang).await;
+    }
+    if custom_id.starts_with(CONVERT_MENU_ID) {
+        handle_currency_pick(ctx, component, lang).await
+    } else if custom_id.starts_with(UNDO_BUTTON_ID) {
+        handle_undo_button(ctx, component, lang).await
+    } else {
+        Ok(())
+    }
+}
+
+/// Opens the order form when its button is pressed.
+#[cfg(feature = "orders")]
+async fn open_order_form(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+    lang: Language,
+) -> Result<(), String> {
+    let linked_username = storage(ctx)
+        .await
+        .roblox_link(component.user.id.0)
+        .await?
+        .map(|link| link.roblox_username);
+    component
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::Modal)
+                .interaction_response_data(|modal| {
+                    modal
+                        .custom_id(ORDER_FORM_ID)
+                        .title(t(lang, "orderform.title"))
+                        .components(|components| {
+                            components
+                                .create_action_row(|row| {
+                                    row.create_input_text(|input| {
+                                        if let Some(username) = &linked_username {
+                                            input.value(username);
+                                        }
+                                        input
+                                            .custom_id("roblox_username")
+                                            .label(t(lang, "orderform.roblox_username"))
+                                            .style(InputTextStyle::Short)
+                                            .min_length(3)
+                                            .max_length(20)
+                                            .required(true)
+                                    })
+                                })
+                                .create_action_row(|row| {
+                                    row.create_input_text(|input| {
+                                        input
+                                            .custom_id("amount")
+                                            .label(t(lang, "orderform.amount"))
+                                            .placeholder("10k")
+                                            .style(InputTextStyle::Short)
+                                            .max_length(32)
+                                            .required(true)
+                                    })
+                                })
+                                .create_action_row(|row| {
+                                    row.create_input_text(|input| {
+                                        input
+                                            .custom_id("type")
+                                            .label(t(lang, "orderform.type"))
+                                            .placeholder("b/t, a/t")
+                                            .style(InputTextStyle::Short)
+                                            .max_length(3)
+                                            .required(false)
+                                    })
+                                })
+                                .create_action_row(|row| {
+                                    row.create_input_text(|input| {
+                                        input
+                                            .custom_id("payment")
+                                            .label(t(lang, "orderform.payment"))
+                                            .placeholder("PayPal, Cash App, BTC, ETH, LTC")
+                                            .style(InputTextStyle::Short)
+                                            .max_length(20)
+                                            .required(true)
+                                    })
+                                })
+                        })
+                })
+        })
+        .await
+        .map_err(|e| format!("Error opening order form: {:?}", e))
+}
+
+/// Routes modal submissions by custom id. Only the orders feature opens modals.
+#[cfg(feature = "orders")]
+async fn handle_modal_submit(ctx: &Context, modal: &ModalSubmitInteraction) -> Result<(), String> {
+    if modal.data.custom_id.starts_with(REVIEW_BUTTON_ID) {
+        handle_review_submit(ctx, modal).await
+    } else if modal.data.custom_id == ORDER_FORM_ID {
+        submit_order_form(ctx, modal).await
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates a submitted order form and opens the ticket, like `/buy`.
+#[cfg(feature = "orders")]
+async fn submit_order_form(ctx: &Context, modal: &ModalSubmitInteraction) -> Result<(), String> {
+    let lang = guild_language(ctx, modal.guild_id, modal.guild_locale.as_deref()).await;
+    let guild_id = modal.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    refuse_blacklisted_user(ctx, Some(guild_id), &modal.user, "orderform", lang).await?;
+
+    let input = |id: &str| {
+        modal
+            .data
+            .components
+            .iter()
+            .flat_map(|row| &row.components)
+            .find_map(|component| match component {
+                ActionRowComponent::InputText(input) if input.custom_id == id => {
+                    Some(input.value.trim())
+                }
+                _ => None,
+            })
+            .filter(|value| !value.is_empty())
+    };
+    let roblox_username = validation::validate_roblox_username(
+        input("roblox_username").ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )?;
+    let robux = parse::parse_robux_amount(
+        input("amount").ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )
+    .and_then(|amount| validation::validate_robux(amount, lang))?;
+    let after_tax = match input("type").map(str::to_lowercase).as_deref() {
+        None | Some("b/t") => false,
+        Some("a/t") => true,
+        _ => return Err(t(lang, "price.invalid_type").to_string()),
+    };
+    let payment_input = input("payment").ok_or(t(lang, "error.invalid_options"))?;
+    let payment = PaymentMethod::parse(payment_input).ok_or_else(|| {
+        tf(
+            lang,
+            "orderform.invalid_payment",
+            &[("input", &payment_input)],
+        )
+    })?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !config.payment_handles.is_empty()
+        && !config
+            .payment_handles
+            .iter()
+            .any(|handle| handle.method == payment)
+    {
+        let accepted: Vec<_> = payments::sorted(&config.payment_handles)
+            .iter()
+            .map(|handle| handle.method.name())
+            .collect();
+        return Err(tf(
+            lang,
+            "orderform.payment_not_accepted",
+            &[
+                ("method", &payment.name()),
+                ("accepted", &accepted.join(", ")),
+            ],
+        ));
+    }
+
+    let channel_id = open_order(
+        ctx,
+        guild_id,
+        &modal.user,
+        modal
+            .member
+            .as_ref()
+            .map_or(&[][..], |member| &member.roles),
+        lang,
+        OrderRequest {
+            robux,
+            after_tax,
+            method: DeliveryMethod::Gamepass,
+            crypto: match payment {
+                PaymentMethod::Crypto(coin) => Some(coin),
+                _ => None,
+            },
+            coupon_code: None,
+            payment: Some(payment),
+            roblox_username: Some(roblox_username),
+        },
+    )
+    .await?;
+
+    let mut embed = CreateEmbed::default()
+        .title(t(lang, "ticket.created"))
+        .description(tf(
+            lang,
+            "ticket.created_description",
+            &[("channel", &channel_id.mention())],
+        ))
+        .clone();
+    config.theme.apply(&mut embed);
+    respond_to_modal(ctx, modal, embed).await;
+    Ok(())
+}
+
+/// Replies to a modal submission with `embed`, visible only to the submitter.
+#[cfg(feature = "orders")]
+async fn respond_to_modal(ctx: &Context, modal: &ModalSubmitInteraction, embed: CreateEmbed) {
+    if let Err(why) = modal
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|message| message.add_embed(embed).ephemeral(true))
+        })
+        .await
+    {
+        eprintln!("Cannot respond to modal: {}", why);
+    }
+}
+
+async fn handle_setcurrency_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let currency = command
+        .data
+        .options
+        .get_str("currency")
+        .map(|code| code.trim().to_uppercase());
+    if let Some(code) = &currency {
+        if code != "GBP" && !rates::DISPLAY_CURRENCIES.contains(&code.as_str()) {
+            return Err(tf(
+                lang,
+                "setcurrency.invalid",
+                &[
+                    ("code", code),
+                    ("currencies", &rates::DISPLAY_CURRENCIES.join(", ")),
+                ],
+            ));
+        }
+    }
+
+    let storage = storage(ctx).await;
+    let mut settings = storage.user_settings(command.user.id.0).await?;
+    settings.currency = currency.clone();
+    storage
+        .save_user_settings(command.user.id.0, &settings)
+        .await?;
+
+    let description = match &currency {
+        Some(code) => tf(lang, "setcurrency.set", &[("currency", code)]),
+        None => t(lang, "setcurrency.cleared").to_string(),
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "setcurrency.title"))
+        .description(description)
+        .clone();
+
+    send_ephemeral_embed_response(ctx, command, embed).await
+}
+
+/// Deletes the user's calculation history, settings and Roblox link.
+async fn handle_forgetme_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let forgotten = storage(ctx).await.forget_user(command.user.id.0).await?;
+
+    let description = if forgotten.is_empty() {
+        t(lang, "forgetme.nothing").to_string()
+    } else {
+        let mut lines = Vec::new();
+        if forgotten.calculations > 0 {
+            lines.push(tf(
+                lang,
+                "forgetme.calculations",
+                &[("count", &forgotten.calculations.to_string())],
+            ));
+        }
+        if forgotten.settings {
+            lines.push(t(lang, "forgetme.settings").to_string());
+        }
+        if forgotten.roblox_link {
+            lines.push(t(lang, "forgetme.roblox_link").to_string());
+        }
+        format!(
+            "{}\n\n{}",
+            lines
+                .iter()
+                .map(|line| format!("• {}", line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            t(lang, "forgetme.kept")
+        )
+    };
+    let embed = CreateEmbed::default()
+        .title(t(lang, "forgetme.title"))
+        .description(description)
+        .clone();
+
+    send_ephemeral_embed_response(ctx, command, embed).await
+}
+
+/// The user's `/setcurrency` choice and its rate per GBP. `None` for GBP and USD,
+/// which are always shown, and until the currency's rate has been fetched.
+async fn preferred_currency(
+    ctx: &Context,
+    user_id: UserId,
+) -> Result<Option<(String, f64)>, String> {
+    let Some(code) = storage(ctx).await.user_settings(user_id.0).await?.currency else {
+        return Ok(None);
+    };
+    if rates::FIAT.contains(&code.as_str()) {
+        return Ok(None);
+    }
+    let per_gbp = rates(ctx).await.read().await.per_gbp(&code);
+    Ok(per_gbp.map(|per_gbp| (code, per_gbp)))
+}
+
+/// Divides the price of a group buy between the people chipping in, in the
+/// currency they pick or their `/setcurrency` one.
+async fn handle_split_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let options = &command.data.options;
+    let robux = parse::parse_robux_amount(
+        options
+            .get_str("robux")
+            .ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )
+    .and_then(|amount| validation::validate_robux(amount, lang))?;
+    let people = options
+        .get_int("people")
+        .filter(|people| (2..=MAX_SPLIT_PEOPLE).contains(people))
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let currency = match options.get_str("currency") {
+        Some(code) => code.trim().to_uppercase(),
+        None => storage(ctx)
+            .await
+            .user_settings(command.user.id.0)
+            .await?
+            .currency
+            .unwrap_or_else(|| "GBP".to_string()),
+    };
+    let invalid = || {
+        tf(
+            lang,
+            "setcurrency.invalid",
+            &[
+                ("code", &currency),
+                ("currencies", &rates::DISPLAY_CURRENCIES.join(", ")),
+            ],
+        )
+    };
+    if currency != "GBP" && !rates::DISPLAY_CURRENCIES.contains(&currency.as_str()) {
+        return Err(invalid());
+    }
+    let per_gbp = rates(ctx)
+        .await
+        .read()
+        .await
+        .per_gbp(&currency)
+        .ok_or_else(invalid)?;
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let rate = pricing::base_rate(&config.tiers, &config.shop_rates, robux, None);
+    // The total is rounded the guild's way first, to a unit the currency has, and
+    // the shares are shown exactly so they add up to it.
+    let rounding = numbers::rounding();
+    let decimals = rounding.decimals.min(rates::minor_unit_decimals(&currency));
+    let total = Rounding {
+        decimals,
+        ..rounding
+    }
+    .apply(robux as f64 * rate * per_gbp);
+    let shares = pricing::shares(total, people, decimals);
+    let unit = 10f64.powi(i32::from(decimals));
+
+    // Shares differ by at most one unit, so there are one or two amounts.
+    let mut lines = Vec::new();
+    for share in [shares[0], shares[shares.len() - 1]] {
+        let count = shares.iter().filter(|other| **other == share).count();
+        if lines.iter().any(|(amount, _)| *amount == share) {
+            continue;
+        }
+        lines.push((share, count));
+    }
+    let description = lines
+        .iter()
+        .map(|(share, count)| {
+            tf(
+                lang,
+                "split.share",
+                &[
+                    ("count", count),
+                    (
+                        "amount",
+                        &numbers::exact_money(lang, &currency, *share as f64 / unit, decimals),
+                    ),
+                ],
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let embed = CreateEmbed::default()
+        .title(tf(
+            lang,
+            "split.title",
+            &[
+                ("robux", &numbers::robux(lang, robux as f64)),
+                ("people", &people),
+            ],
+        ))
+        .description(description)
+        .field(
+            t(lang, "split.total"),
+            numbers::exact_money(
+                lang,
+                &currency,
+                shares.iter().sum::<u64>() as f64 / unit,
+                decimals,
+            ),
+            true,
+        )
+        .field(
+            t(lang, "price.rate"),
+            format!(
+                "{} / 1k {}",
+                numbers::gbp(lang, rate * 1000.0),
+                emojis::robux()
+            ),
+            true,
+        )
+        .footer(|footer| footer.text(t(lang, "split.footer")))
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_pricebatch_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    refuse_blacklisted(ctx, command, lang).await?;
+    let options = &command.data.options;
+    let string_option = |name: &str| options.get_str(name);
+
+    let amounts = parse::parse_robux_list(
+        string_option("amounts").ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )?;
+    if amounts.len() > MAX_BATCH_AMOUNTS {
+        return Err(tf(
+            lang,
+            "pricebatch.too_many",
+            &[("max", &MAX_BATCH_AMOUNTS)],
+        ));
+    }
+    for &robux in &amounts {
+        validation::validate_robux(robux, lang)?;
+    }
+    let price_type = string_option("type").unwrap_or("b/t");
+    let is_after_tax = match price_type {
+        "b/t" => false,
+        "a/t" => true,
+        _ => return Err(t(lang, "price.invalid_type").to_string()),
+    };
+    let method = DeliveryMethod::parse(string_option("method").unwrap_or("gamepass"))
+        .ok_or(t(lang, "price.invalid_method"))?;
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let usd_rate = gbp_to_usd(ctx).await;
+    let discount = match (command.guild_id, &command.member) {
+        (Some(guild_id), Some(member)) => {
+            member_discount(ctx, guild_id, member.user.id, &member.roles, &config).await?
+        }
+        _ => None,
+    };
+    let preferred = preferred_currency(ctx, command.user.id).await?;
+
+    let mut header = vec![
+        "R$".to_string(),
+        method.listing_label(lang).to_string(),
+        "GBP".to_string(),
+        "USD".to_string(),
+    ];
+    if let Some((code, _)) = &preferred {
+        header.push(code.clone());
+    }
+    let mut rows = vec![header];
+    let mut prices = Vec::new();
+    for &robux in &amounts {
+        let base_rate =
+            pricing::base_rate(&config.tiers, &config.shop_rates, robux, discount.as_ref());
+        let quote = pricing::quote(
+            robux as f64,
+            base_rate,
+            method,
+            is_after_tax,
+            None,
+            config.vat.as_ref(),
+        );
+        let gbp_amount = quote.gbp();
+        record_calculation(
+            ctx,
+            Calculation::new(
+                command.guild_id.map(|id| id.0),
+                command.user.id.0,
+                "price",
+                robux as i64,
+                gbp_amount,
+                gbp_amount * usd_rate,
+                quote.rate,
+            ),
+        )
+        .await;
+
+        let mut row = vec![
+            numbers::integer(lang, robux as f64),
+            numbers::robux(
+                lang,
+                method.listing_price(robux as f64, is_after_tax) as f64,
+            ),
+            numbers::gbp(lang, gbp_amount),
+            numbers::usd(lang, gbp_amount * usd_rate),
+        ];
+        if let Some((code, per_gbp)) = &preferred {
+            row.push(numbers::money(lang, code, gbp_amount * per_gbp));
+        }
+        rows.push(row);
+        prices.push(format!(
+            "{} {}",
+            numbers::robux(lang, robux as f64),
+            numbers::gbp(lang, gbp_amount)
+        ));
+    }
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "pricebatch.title"))
+        .description(format!(
+            "**{}:** {}\n**{}:** {}\n```\n{}\n```",
+            t(lang, "price.conversion_type"),
+            price_type,
+            t(lang, "price.method"),
+            method.name(lang),
+            text_table(&rows)
+        ));
+    if let Some(discount) = &discount {
+        let (label, value) = discount_label(lang, discount);
+        embed.field(label, value, true);
+    }
+    if let Some(vat) = &config.vat {
+        embed.field(t(lang, "vat.label"), vat_summary(lang, vat), true);
+    }
+    let summary = format!(
+        "{}, {}: {}",
+        price_type,
+        method.name(lang),
+        prices.join(", ")
+    );
+
+    send_calculator_response(ctx, command, embed, &summary).await
+}
+
+async fn handle_quote_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    refuse_blacklisted(ctx, command, lang).await?;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .filter(|option| option.name == "bulk")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let options = &subcommand.options;
+
+    let amounts = parse::parse_robux_list(
+        options
+            .get_str("amounts")
+            .ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )?;
+    if amounts.len() > MAX_BATCH_AMOUNTS {
+        return Err(tf(
+            lang,
+            "pricebatch.too_many",
+            &[("max", &MAX_BATCH_AMOUNTS)],
+        ));
+    }
+    for &robux in &amounts {
+        validation::validate_robux(robux, lang)?;
+    }
+    let price_type = options.get_str("type").unwrap_or("b/t");
+    let is_after_tax = match price_type {
+        "b/t" => false,
+        "a/t" => true,
+        _ => return Err(t(lang, "price.invalid_type").to_string()),
+    };
+    let method = DeliveryMethod::parse(options.get_str("method").unwrap_or("gamepass"))
+        .ok_or(t(lang, "price.invalid_method"))?;
+    let valid_days = options
+        .get_int("valid_days")
+        .unwrap_or(quotes::DEFAULT_VALID_DAYS)
+        .clamp(1, quotes::MAX_VALID_DAYS);
+
+    let config = guild_config(ctx, Some(guild_id)).await;
+    for &robux in &amounts {
+        validation::validate_order_size(robux, &config.order_limits, lang)?;
+    }
+    let discount = match &command.member {
+        Some(member) => {
+            member_discount(ctx, guild_id, member.user.id, &member.roles, &config).await?
+        }
+        None => None,
+    };
+    let usd_rate = gbp_to_usd(ctx).await;
+    let now = rates::now();
+    let valid_until = quotes::valid_until(now, valid_days);
+    let id = quotes::id(command.id.0);
+
+    let mut table = vec![vec![
+        "#".to_string(),
+        t(lang, "price.amount").to_string(),
+        method.listing_label(lang).to_string(),
+        t(lang, "quote.rate_per_1k").to_string(),
+        t(lang, "quote.line_total").to_string(),
+    ]];
+    let (mut total_robux, mut net, mut tax) = (0, 0.0, 0.0);
+    for (index, &robux) in amounts.iter().enumerate() {
+        let base_rate =
+            pricing::base_rate(&config.tiers, &config.shop_rates, robux, discount.as_ref());
+        let quote = pricing::quote(
+            robux as f64,
+            base_rate,
+            method,
+            is_after_tax,
+            None,
+            config.vat.as_ref(),
+        );
+        table.push(vec![
+            (index + 1).to_string(),
+            numbers::robux(lang, robux as f64),
+            numbers::robux(
+                lang,
+                method.listing_price(robux as f64, is_after_tax) as f64,
+            ),
+            numbers::gbp(lang, quote.rate * 1000.0),
+            numbers::gbp(lang, quote.net),
+        ]);
+        total_robux += robux;
+        net += quote.net;
+        tax += quote.tax;
+    }
+    let total = net + tax;
+
+    let guild_name = guild_id
+        .to_partial_guild(&ctx.http)
+        .await
+        .map_or_else(|_| guild_id.to_string(), |guild| guild.name);
+    let mut details = vec![
+        (t(lang, "quote.id").to_string(), id.clone()),
+        (t(lang, "receipt.date").to_string(), receipt::date(now)),
+        (
+            t(lang, "quote.valid_until").to_string(),
+            receipt::date(valid_until),
+        ),
+        (
+            t(lang, "quote.prepared_for").to_string(),
+            format!("{} ({})", command.user.tag(), command.user.id),
+        ),
+        (t(lang, "receipt.seller").to_string(), guild_name),
+        (
+            t(lang, "price.conversion_type").to_string(),
+            price_type.to_string(),
+        ),
+        (
+            t(lang, "price.method").to_string(),
+            method.name(lang).to_string(),
+        ),
+    ];
+    if let Some(discount) = &discount {
+        let (label, value) = discount_label(lang, discount);
+        details.push((label.to_string(), value));
+    }
+    let mut totals = vec![(
+        t(lang, "quote.total_robux").to_string(),
+        numbers::robux(lang, total_robux as f64),
+    )];
+    if let Some(vat) = &config.vat {
+        totals.push((
+            t(lang, "quote.subtotal").to_string(),
+            numbers::gbp(lang, net),
+        ));
+        totals.push((t(lang, "vat.label").to_string(), vat_summary(lang, vat)));
+        totals.push((
+            t(lang, "quote.vat_amount").to_string(),
+            numbers::gbp(lang, tax),
+        ));
+    }
+    totals.push((
+        t(lang, "quote.total").to_string(),
+        format!(
+            "{} / {}",
+            numbers::gbp(lang, total),
+            numbers::usd(lang, total * usd_rate)
+        ),
+    ));
+
+    let handles = payments::sorted(&config.payment_handles);
+    let payment_methods = if handles.is_empty() {
+        t(lang, "quote.terms.any_method").to_string()
+    } else {
+        handles
+            .iter()
+            .map(|handle| handle.method.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let terms = vec![
+        t(lang, "quote.terms").to_string(),
+        tf(
+            lang,
+            "quote.terms.validity",
+            &[("date", &receipt::date(valid_until))],
+        ),
+        tf(
+            lang,
+            "quote.terms.payment",
+            &[("methods", &payment_methods)],
+        ),
+        tf(
+            lang,
+            "quote.terms.delivery",
+            &[("method", &method.name(lang))],
+        ),
+    ];
+    let pdf = receipt::render_blocks(
+        &tf(lang, "quote.title", &[("id", &id)]),
+        &[
+            receipt::Block::Rows(details),
+            receipt::Block::Table(table),
+            receipt::Block::Rows(totals),
+            receipt::Block::Text(terms),
+        ],
+        &tf(lang, "quote.footer", &[("id", &id)]),
+    );
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(lang, "quote.title", &[("id", &id)]))
+        .description(tf(
+            lang,
+            "quote.description",
+            &[
+                ("count", &amounts.len()),
+                ("robux", &numbers::robux(lang, total_robux as f64)),
+                ("gbp", &numbers::gbp(lang, total)),
+                ("usd", &numbers::usd(lang, total * usd_rate)),
+                ("until", &format!("<t:{}:f>", valid_until)),
+            ],
+        ));
+    send_embed_with_file(ctx, command, embed, pdf, &quotes::filename(&id)).await
+}
+
+/// Lays `rows` out as right-aligned columns for a code block, with a rule under
+/// the first row.
+fn text_table(rows: &[Vec<String>]) -> String {
+    let columns = rows.first().map_or(0, Vec::len);
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let line = |row: &Vec<String>| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    let mut lines: Vec<String> = rows.iter().map(line).collect();
+    if !lines.is_empty() {
+        let rule = "-".repeat(widths.iter().sum::<usize>() + 2 * columns.saturating_sub(1));
+        lines.insert(1, rule);
+    }
+    lines.join("\n")
+}
+
+async fn handle_ping_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let started = Instant::now();
+    command
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|message| message.content(t(lang, "ping.pinging")))
+        })
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))?;
+    let rest_latency = started.elapsed();
+    let (gateway_latency, shards) = shard_status(ctx).await;
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "ping.title"))
+        .field(
+            t(lang, "ping.gateway"),
+            gateway_latency.map_or_else(
+                || t(lang, "ping.pending").to_string(),
+                |latency| format!("{} ms", latency.as_millis()),
+            ),
+            true,
+        )
+        .field(
+            t(lang, "ping.rest"),
+            format!("{} ms", rest_latency.as_millis()),
+            true,
+        )
+        .field(
+            t(lang, "ping.shard"),
+            format!("{}/{}", ctx.shard_id + 1, shards.max(1)),
+            true,
+        );
+    guild_config(ctx, command.guild_id)
+        .await
+        .theme
+        .apply(&mut embed);
+
+    command
+        .edit_original_interaction_response(&ctx.http, |response| {
+            response.content("").set_embed(embed)
+        })
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Error editing response: {:?}", e))
+}
+
+async fn handle_uptime_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let stats = bot_stats(ctx).await;
+    let embed = CreateEmbed::default()
+        .title(t(lang, "uptime.title"))
+        .description(tf(
+            lang,
+            "uptime.description",
+            &[
+                ("duration", &botstats::duration_label(stats.uptime())),
+                ("since", &format!("<t:{}:F>", stats.started_unix)),
+            ],
+        ))
+        .clone();
+
+    send_embed_response(ctx, command, embed).await
+}
+
+async fn handle_botstats_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let stats = bot_stats(ctx).await;
+    let (gateway_latency, shards) = shard_status(ctx).await;
+    let counts = stats.command_counts();
+    let total: u64 = counts.iter().map(|(_, count)| count).sum();
+    let top = if counts.is_empty() {
+        t(lang, "botstats.no_commands").to_string()
+    } else {
+        counts
+            .iter()
+            .take(MAX_TOP_COMMANDS)
+            .map(|(name, count)| format!("`/{}`: {}", name, numbers::integer(lang, *count as f64)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "botstats.title"))
+        .field(
+            t(lang, "botstats.guilds"),
+            numbers::integer(lang, stats.guild_count() as f64),
+            true,
+        )
+        .field(t(lang, "ping.shard"), shards.max(1), true)
+        .field(
+            t(lang, "ping.gateway"),
+            gateway_latency.map_or_else(
+                || t(lang, "ping.pending").to_string(),
+                |latency| format!("{} ms", latency.as_millis()),
+            ),
+            true,
+        )
+        .field(
+            t(lang, "uptime.title"),
+            botstats::duration_label(stats.uptime()),
+            true,
+        )
+        .field(
+            t(lang, "botstats.memory"),
+            botstats::memory_bytes().map_or_else(
+                || t(lang, "ping.pending").to_string(),
+                |bytes| {
+                    format!(
+                        "{} MB",
+                        numbers::decimal(lang, bytes as f64 / 1_048_576.0, 1)
+                    )
+                },
+            ),
+            true,
+        )
+        .field(
+            t(lang, "botstats.commands_run"),
+            numbers::integer(lang, total as f64),
+            true,
+        )
+        .field(t(lang, "botstats.top_commands"), top, false);
+
+    send_embed_response(ctx, command, embed).await
+}
+
+/// This shard's heartbeat latency, once one has been acknowledged, and how many
+/// shards the process runs.
+async fn shard_status(ctx: &Context) -> (Option<Duration>, usize) {
+    let Some(manager) = state(ctx).await.shard_manager.clone() else {
+        return (None, 0);
+    };
+    let manager = manager.lock().await;
+    let runners = manager.runners.lock().await;
+    let latency = runners
+        .get(&ShardId(ctx.shard_id))
+        .and_then(|runner| runner.latency);
+    (latency, runners.len())
+}
+
+async fn bot_stats(ctx: &Context) -> Arc<BotStats> {
+    state(ctx).await.stats.clone()
+}
+
+/// Refuses `command_name` if the group it belongs to is off in the guild.
+async fn refuse_disabled_feature(
+    ctx: &Context,
+    guild_id: Option<GuildId>,
+    command_name: &str,
+    lang: Language,
+) -> Result<(), String> {
+    let Some(feature) = Feature::for_command(command_name) else {
+        return Ok(());
+    };
+    if !feature.is_on(&guild_config(ctx, guild_id).await) {
+        return Err(tf(
+            lang,
+            "features.refused",
+            &[("feature", &feature.name(lang))],
+        ));
+    }
+    Ok(())
+}
+
+async fn handle_features_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let feature = subcommand
+        .options
+        .get_str("feature")
+        .and_then(Feature::parse);
+
+    let storage = storage(ctx).await;
+    let mut description = String::new();
+    if let Some(feature) = feature {
+        let enable = match subcommand.name.as_str() {
+            "enable" => true,
+            "disable" => false,
+            _ => return Err(t(lang, "error.invalid_options").to_string()),
+        };
+        storage
+            .update_guild(guild_id, move |config| {
+                config
+                    .disabled_features
+                    .retain(|disabled| *disabled != feature);
+                config
+                    .enabled_features
+                    .retain(|enabled| *enabled != feature);
+                match (enable, feature.on_by_default()) {
+                    (true, false) => config.enabled_features.push(feature),
+                    (false, true) => config.disabled_features.push(feature),
+                    _ => {}
+                }
+            })
+            .await?;
+        description = tf(
+            lang,
+            if enable {
+                "features.enabled"
+            } else {
+                "features.disabled"
+            },
+            &[("feature", &feature.name(lang))],
+        );
+        description.push_str("\n\n");
+    } else if subcommand.name != "list" {
+        return Err(t(lang, "error.invalid_options").to_string());
+    }
+    let config = storage.guild(guild_id).await?;
+
+    let lines: Vec<String> = Feature::ALL
+        .iter()
+        .map(|feature| {
+            let commands: Vec<_> = feature
+                .commands()
+                .iter()
+                .map(|name| format!("`/{}`", name))
+                .collect();
+            format!(
+                "{} **{}**: {}",
+                emojis::mark(feature.is_on(&config)),
+                feature.name(lang),
+                commands.join(", ")
+            )
+        })
+        .collect();
+    description.push_str(&lines.join("\n"));
+    let embed = CreateEmbed::default()
+        .title(t(lang, "features.title"))
+        .description(description)
+        .clone();
+    send_embed_response(ctx, command, embed).await?;
+
+    // Where this server has its own command registrations, hide the commands
+    // that were turned off from the command menu too.
+    if feature.is_some() {
+        let result = match guild_id.get_application_commands(&ctx.http).await {
+            Ok(registered) if registered.is_empty() => Ok(()),
+            Ok(_) => guild_id
+                .set_application_commands(&ctx.http, guild_command_definitions(&config))
+                .await
+                .map(|_| ()),
+            Err(error) => Err(error),
+        };
+        if let Err(error) = result {
+            let error = format!("Error registering commands: {:?}", error);
+            eprintln!("{}", error);
+            report_error(
+                ctx,
+                "Command registration failed",
+                &error,
+                interaction_context(("Command", "/features"), &command.user, command.guild_id),
+            )
+            .await;
+        }
+    }
+    Ok(())
+}
+
+/// The `feature` option of `/features enable` and `/features disable`.
+fn feature_option(
+    option: &mut CreateApplicationCommandOption,
+) -> &mut CreateApplicationCommandOption {
+    option
+        .name("feature")
+        .localized_description("opt.features.feature")
+        .kind(CommandOptionType::String)
+        .required(true);
+    for feature in Feature::ALL {
+        option.add_string_choice(feature.name(Language::En), feature.code());
+    }
+    option
+}
+
+/// The `kind` option of `/emojis set` and `/emojis reset`.
+fn emoji_kind_option(
+    option: &mut CreateApplicationCommandOption,
+) -> &mut CreateApplicationCommandOption {
+    option
+        .name("kind")
+        .localized_description("opt.emojis.kind")
+        .kind(CommandOptionType::String)
+        .required(true);
+    for kind in EmojiKind::ALL {
+        option.add_string_choice(kind.name(Language::En), kind.code());
+    }
+    option
+}
+
+/// The `command` option of every `/template` subcommand.
+fn template_command_option(
+    option: &mut CreateApplicationCommandOption,
+) -> &mut CreateApplicationCommandOption {
+    option
+        .name("command")
+        .localized_description("opt.template.command")
+        .kind(CommandOptionType::String)
+        .required(true);
+    for name in templates::COMMANDS {
+        option.add_string_choice(format!("/{}", name), name);
+    }
+    option
+}
+
+/// Owner only: sends a copy of the whole database to `BACKUP_CHANNEL_ID`, or to
+/// the owner's DMs if it isn't set.
+async fn handle_backup_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    if !is_bot_owner(ctx, command.user.id).await? {
+        return Err(t(lang, "backup.owner_only").to_string());
+    }
+
+    // Copying a large database can take longer than Discord waits for a reply.
+    command
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::DeferredChannelMessageWithSource)
+                .interaction_response_data(|message| message.ephemeral(true))
+        })
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))?;
+
+    let backup = storage(ctx).await.backup().await?;
+    let size = tf(
+        lang,
+        "backup.size",
+        &[(
+            "kb",
+            &numbers::decimal(lang, backup.data.len() as f64 / 1024.0, 1),
+        )],
+    );
+    if !backup.fits_upload() {
+        return Err(tf(lang, "backup.too_large", &[("size", &size)]));
+    }
+    let now = rates::now();
+    let filename = backup.filename(now);
+    let channel = match state(ctx).await.backup_channel {
+        Some(channel) => channel,
+        None => {
+            command
+                .user
+                .create_dm_channel(&ctx.http)
+                .await
+                .map_err(|_| t(lang, "backup.send_failed").to_string())?
+                .id
+        }
+    };
+    channel
+        .send_message(&ctx.http, |message| {
+            message
+                .content(tf(lang, "backup.message", &[("at", &now)]))
+                .add_file(AttachmentType::Bytes {
+                    data: backup.data.into(),
+                    filename: filename.clone(),
+                })
+        })
+        .await
+        .map_err(|_| t(lang, "backup.send_failed").to_string())?;
+
+    let embed = CreateEmbed::default()
+        .title(t(lang, "backup.title"))
+        .description(tf(
+            lang,
+            "backup.sent",
+            &[
+                ("file", &filename),
+                ("size", &size),
+                ("channel", &channel.mention()),
+            ],
+        ))
+        .clone();
+    edit_embed_response(ctx, command, embed).await
+}
+
+/// Owner only: registers the guild-only commands in this guild, or the DM ones globally,
+/// and lists which commands were added or removed, so new commands show up
+/// without restarting the bot.
+async fn handle_sync_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    if !is_bot_owner(ctx, command.user.id).await? {
+        return Err(t(lang, "sync.owner_only").to_string());
+    }
+    let global = command.data.options.get_bool("global").unwrap_or(false);
+    let guild_id = if global {
+        None
+    } else {
+        Some(command.guild_id.ok_or(t(lang, "error.guild_only"))?)
+    };
+
+    // Registering every command can take longer than Discord waits for a reply.
+    command
+        .create_interaction_response(&ctx.http, |response| {
+            response
+                .kind(InteractionResponseType::DeferredChannelMessageWithSource)
+                .interaction_response_data(|message| message.ephemeral(true))
+        })
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))?;
+
+    let (before, after) = match guild_id {
+        Some(guild_id) => {
+            let config = guild_config(ctx, Some(guild_id)).await;
+            (
+                guild_id.get_application_commands(&ctx.http).await,
+                guild_id
+                    .set_application_commands(&ctx.http, guild_command_definitions(&config))
+                    .await,
+            )
+        }
+        None => (
+            command::Command::get_global_application_commands(&ctx.http).await,
+            command::Command::set_global_application_commands(
+                &ctx.http,
+                global_command_definitions,
+            )
+            .await,
+        ),
+    };
+    let before = before.map_err(|e| format!("Error fetching commands: {:?}", e))?;
+    let after = after.map_err(|e| format!("Error registering commands: {:?}", e))?;
+
+    let names = |commands: &[command::Command]| {
+        let mut names: Vec<String> = commands.iter().map(|c| c.name.clone()).collect();
+        names.sort();
+        names
+    };
+    let (before, after) = (names(&before), names(&after));
+    let list = |names: Vec<&String>| {
+        if names.is_empty() {
+            t(lang, "sync.none").to_string()
+        } else {
+            names
+                .iter()
+                .map(|name| format!("`{}`", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    };
+    let added = list(after.iter().filter(|name| !before.contains(name)).collect());
+    let removed = list(before.iter().filter(|name| !after.contains(name)).collect());
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(
+            lang,
+            if global {
+                "sync.title_global"
+            } else {
+                "sync.title_guild"
+            },
+        ))
+        .description(tf(
+            lang,
+            "sync.registered",
+            &[("count", &after.len().to_string())],
+        ))
+        .field(t(lang, "sync.added"), added, false)
+        .field(t(lang, "sync.removed"), removed, false);
+    guild_config(ctx, command.guild_id)
+        .await
+        .theme
+        .apply(&mut embed);
+
+    command
+        .edit_original_interaction_response(&ctx.http, |response| response.set_embed(embed))
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Error editing response: {:?}", e))
+}
+
+/// Walks a buyer through creating the gamepass for their order, with the exact
+/// price to set so they receive the Robux they paid for after Roblox's cut.
+#[cfg(feature = "roblox")]
+async fn handle_setupgamepass_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let robux = command
+        .data
+        .options
+        .get_str("robux")
+        .ok_or(t(lang, "error.invalid_options"))
+        .map_err(str::to_string)
+        .and_then(|amount| parse::parse_robux_amount(amount, lang))
+        .and_then(|amount| validation::validate_robux(amount, lang))?;
+    let method = DeliveryMethod::Gamepass;
+    let price = method.exact_listing_price(robux);
+
+    let embed = CreateEmbed::default()
+        .title(t(lang, "setupgamepass.title"))
+        .description(tf(
+            lang,
+            "setupgamepass.steps",
+            &[
+                ("url", &GAMEPASS_CREATE_URL),
+                ("price", &numbers::integer(lang, price as f64)),
+                ("robux", &numbers::integer(lang, robux as f64)),
+                ("fee", &(method.fee() * 100.0).round()),
+            ],
+        ))
+        .field(
+            t(lang, "setupgamepass.price"),
+            format!("```{}```", price),
+            true,
+        )
+        .field(
+            t(lang, "setupgamepass.receive"),
+            numbers::robux(lang, robux as f64),
+            true,
+        )
+        .clone();
+    send_embed_response(ctx, command, embed).await
+}
+
+/// Checks the gamepass a buyer made for the ticket's order before staff buy it:
+/// that it exists, belongs to the buyer's Roblox account and costs exactly the
+/// expected price. Every check is recorded on the order.
+#[cfg(feature = "roblox")]
+async fn handle_verifygamepass_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let guild_id = command.guild_id.ok_or(t(lang, "error.guild_only"))?;
+    let config = guild_config(ctx, Some(guild_id)).await;
+    if !is_ticket_staff(command, &config) {
+        return Err(t(lang, "verifygamepass.staff_only").to_string());
+    }
+    let string_option = |name: &str| command.data.options.get_str(name);
+    let gamepass_id = parse::parse_gamepass_id(
+        string_option("url").ok_or(t(lang, "error.invalid_options"))?,
+        lang,
+    )?;
+    let storage = storage(ctx).await;
+    let order = storage
+        .open_order_for_channel(command.channel_id.0)
+        .await?
+        .ok_or(t(lang, "ticket.not_ticket"))?;
+    let expected_price = match string_option("expected_price") {
+        Some(price) => parse::parse_robux_amount(price, lang)?,
+        None if order.after_tax => order.method.exact_listing_price(order.robux),
+        None => order.robux,
+    };
+
+    defer_response(ctx, command).await?;
+    let buyer = match &order.roblox_username {
+        Some(username) => roblox::user_by_name(username).await?,
+        None => storage
+            .roblox_link(order.buyer_id)
+            .await?
+            .map(|link| RobloxUser {
+                id: link.roblox_user_id,
+                name: link.roblox_username,
+            }),
+    }
+    .ok_or(t(lang, "verifygamepass.no_account"))?;
+    let gamepass = roblox::gamepass(gamepass_id)
+        .await?
+        .ok_or_else(|| tf(lang, "verifygamepass.not_found", &[("id", &gamepass_id)]))?;
+
+    let check = GamepassCheck {
+        order_id: order.id,
+        gamepass_id,
+        expected_price,
+        price: gamepass.price,
+        owned_by_buyer: gamepass.creator_user_id == Some(buyer.id),
+        checked_by: command.user.id.0,
+        checked_at: rates::now(),
+    };
+    storage.record_gamepass_check(check.clone()).await?;
+
+    let mark = emojis::mark;
+    let owner = if check.owned_by_buyer {
+        tf(
+            lang,
+            "verifygamepass.owner_ok",
+            &[("username", &buyer.name)],
+        )
+    } else {
+        tf(
+            lang,
+            "verifygamepass.owner_wrong",
+            &[("username", &buyer.name)],
+        )
+    };
+    let expected = numbers::robux(lang, expected_price as f64);
+    let price = match gamepass.price {
+        Some(price) => tf(
+            lang,
+            "verifygamepass.price",
+            &[
+                ("price", &numbers::robux(lang, price as f64)),
+                ("expected", &expected),
+            ],
+        ),
+        None => tf(
+            lang,
+            "verifygamepass.not_for_sale",
+            &[("expected", &expected)],
+        ),
+    };
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(
+            lang,
+            if check.passed() {
+                "verifygamepass.passed"
+            } else {
+                "verifygamepass.failed"
+            },
+            &[("name", &gamepass.name)],
+        ))
+        .url(format!("https://www.roblox.com/game-pass/{}", gamepass.id))
+        .description(format!(
+            "{} {}\n{} {}",
+            mark(check.owned_by_buyer),
+            owner,
+            mark(gamepass.price == Some(expected_price)),
+            price
+        ))
+        .footer(|footer| footer.text(tf(lang, "order.title", &[("id", &order.id)])));
+    edit_embed_response(ctx, command, embed).await
+}
+
+/// Looks up a limited's RAP and value on Rolimons and prices it at the guild's
+/// rate, for sellers who also trade limiteds.
+async fn handle_limited_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let query = command
+        .data
+        .options
+        .get_str("item")
+        .ok_or(t(lang, "error.invalid_options"))?;
+
+    // The item list can take a few seconds to download when it isn't cached.
+    defer_response(ctx, command).await?;
+    let item = rolimons::find_item(query)
+        .await?
+        .ok_or_else(|| tf(lang, "limited.not_found", &[("item", &query)]))?;
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let worth = item.worth();
+    let gbp = worth as f64 * pricing::base_rate(&config.tiers, &config.shop_rates, worth, None);
+    let usd = gbp * gbp_to_usd(ctx).await;
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(match &item.acronym {
+            Some(acronym) => format!("{} ({})", item.name, acronym),
+            None => item.name.clone(),
+        })
+        .url(item.url())
+        .field(
+            t(lang, "limited.rap"),
+            numbers::robux(lang, item.rap as f64),
+            true,
+        )
+        .field(
+            t(lang, "limited.value"),
+            item.value.map_or_else(
+                || t(lang, "limited.not_valued").to_string(),
+                |value| numbers::robux(lang, value as f64),
+            ),
+            true,
+        )
+        .field(
+            tf(lang, "common.amount_in", &[("currency", &"GBP")]),
+            numbers::gbp(lang, gbp),
+            true,
+        )
+        .field(
+            tf(lang, "common.amount_in", &[("currency", &"USD")]),
+            numbers::usd(lang, usd),
+            true,
+        )
+        .footer(|footer| {
+            footer.text(t(
+                lang,
+                if item.value.is_some() {
+                    "limited.footer_value"
+                } else {
+                    "limited.footer_rap"
+                },
+            ))
+        });
+    edit_embed_response(ctx, command, embed).await
+}
+
+/// Sums the RAP of a Roblox user's public limiteds and prices it at the guild's
+/// rate, for trust checks and trades.
+#[cfg(feature = "roblox")]
+async fn handle_rap_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    let username = command
+        .data
+        .options
+        .get_str("username")
+        .ok_or(t(lang, "error.invalid_options"))?;
+    let username = validation::validate_roblox_username(username, lang)?;
+
+    // Large inventories take several pages to read.
+    defer_response(ctx, command).await?;
+    let user = roblox::user_by_name(&username)
+        .await?
+        .ok_or_else(|| tf(lang, "rap.unknown_user", &[("username", &username)]))?;
+    let mut inventory = roblox::inventory(user.id)
+        .await?
+        .ok_or_else(|| tf(lang, "rap.private", &[("username", &user.name)]))?;
+
+    let config = guild_config(ctx, command.guild_id).await;
+    let total = inventory.total_rap();
+    let gbp = total as f64 * pricing::base_rate(&config.tiers, &config.shop_rates, total, None);
+    let usd = gbp * gbp_to_usd(ctx).await;
+
+    inventory.items.sort_by_key(|item| Reverse(item.rap));
+    let top = inventory
+        .items
+        .iter()
+        .take(MAX_RAP_ITEMS)
+        .map(|item| {
+            format!(
+                "[{}](https://www.roblox.com/catalog/{}) · {}",
+                item.name,
+                item.asset_id,
+                numbers::robux(lang, item.rap as f64)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(tf(lang, "rap.title", &[("username", &user.name)]))
+        .url(format!(
+            "https://www.roblox.com/users/{}/inventory",
+            user.id
+        ))
+        .description(if top.is_empty() {
+            t(lang, "rap.empty").to_string()
+        } else {
+            top.join("\n")
+        })
+        .field(
+            t(lang, "rap.items"),
+            numbers::integer(lang, inventory.items.len() as f64),
+            true,
+        )
+        .field(
+            t(lang, "rap.total"),
+            numbers::robux(lang, total as f64),
+            true,
+        )
+        .field(
+            tf(lang, "common.amount_in", &[("currency", &"GBP")]),
+            numbers::gbp(lang, gbp),
+            true,
+        )
+        .field(
+            tf(lang, "common.amount_in", &[("currency", &"USD")]),
+            numbers::usd(lang, usd),
+            true,
+        );
+    if inventory.truncated {
+        let count = numbers::integer(lang, inventory.items.len() as f64);
+        embed.footer(|footer| footer.text(tf(lang, "rap.truncated", &[("count", &count)])));
+    }
+    edit_embed_response(ctx, command, embed).await
+}
+
+/// USD per GBP: the guild's `/setrate forex` rate if it set one, else the market.
+async fn gbp_to_usd(ctx: &Context) -> f64 {
+    match rates::forex_override() {
+        Some(rate) => rate,
+        None => rates(ctx).await.read().await.gbp_to_usd,
+    }
+}
+
+/// The GBP price of one `coin` and when it was fetched.
+async fn crypto_price(ctx: &Context, lang: Language, coin: Crypto) -> Result<(f64, u64), String> {
+    let rates = rates(ctx).await;
+    let rates = rates.read().await;
+    rates
+        .crypto_gbp
+        .get(&coin)
+        .copied()
+        .zip(rates.crypto_updated_at)
+        .ok_or_else(|| t(lang, "crypto.unavailable").to_string())
+}
+
+fn crypto_updated(lang: Language, updated_at: u64) -> String {
+    tf(
+        lang,
+        "crypto.updated",
+        &[("time", &format!("<t:{}:R>", updated_at))],
+    )
+}
+
+async fn rates(ctx: &Context) -> Arc<RwLock<Rates>> {
+    state(ctx).await.rates.clone()
+}
+
+/// Resolves the language to reply in: the guild's `/language` setting, then the
+/// guild's preferred locale, then English.
+/// The guild's language, or in DMs the user's.
+async fn language(ctx: &Context, command: &ApplicationCommandInteraction) -> Language {
+    let locale = command.guild_locale.as_deref().unwrap_or(&command.locale);
+    guild_language(ctx, command.guild_id, Some(locale)).await
+}
+
+/// Like [`language`], for interactions other than slash commands.
+async fn guild_language(
+    ctx: &Context,
+    guild_id: Option<GuildId>,
+    guild_locale: Option<&str>,
+) -> Language {
+    guild_config(ctx, guild_id)
+        .await
+        .language
+        .or_else(|| guild_locale.and_then(Language::from_locale))
+        .unwrap_or_default()
+}
+
+/// Reads an optional boolean option by name, treating a missing option as `false`.
+fn bool_option(options: &[CommandDataOption], name: &str) -> bool {
+    options.get_bool(name).unwrap_or(false)
+}
+
+/// Whether the command's `format` option asks for plain text instead of an embed.
+fn wants_text(options: &[CommandDataOption]) -> bool {
+    options.get_str("format") == Some("text")
+}
+
+/// Robux a Premium buyer effectively ends up with for `amount`.
+fn premium_robux(amount: f64) -> i64 {
+    (amount * (1.0 + PREMIUM_BONUS_RATE)).round() as i64
+}
+
+/// Whether the user can manage the guild or has the configured ticket staff role.
+fn is_ticket_staff(command: &ApplicationCommandInteraction, config: &GuildConfig) -> bool {
+    is_ticket_staff_member(command.member.as_ref(), config)
+}
+
+/// Like [`is_ticket_staff`], for any interaction's member.
+fn is_ticket_staff_member(member: Option<&Member>, config: &GuildConfig) -> bool {
+    is_staff_member(member)
+        || member.is_some_and(|member| {
+            config
+                .tickets
+                .staff_role
+                .is_some_and(|role| member.roles.contains(&RoleId(role)))
+        })
+}
+
+/// Whether the invoking member can manage the guild the command was used in.
+fn is_staff(command: &ApplicationCommandInteraction) -> bool {
+    is_staff_member(command.member.as_ref())
+}
+
+fn is_staff_member(member: Option<&Member>) -> bool {
+    member
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.manage_guild())
+}
+
+async fn record_calculation(ctx: &Context, calculation: Calculation) {
+    if let Err(error) = storage(ctx).await.record_calculation(calculation).await {
+        eprintln!("Error recording calculation: {}", error);
+    }
+}
+
+async fn state(ctx: &Context) -> Arc<AppState> {
+    ctx.data
+        .read()
+        .await
+        .get::<AppState>()
+        .cloned()
+        .expect("AppState is inserted at startup")
+}
+
+async fn storage(ctx: &Context) -> Storage {
+    state(ctx).await.storage.clone()
+}
+
+/// Sends `reply` to `command` through the state's [`reply::Responder`].
+async fn respond(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    reply: &Reply,
+) -> serenity::Result<()> {
+    let responder = state(ctx).await.responder.clone();
+    responder.respond(command, reply).await
+}
+
+async fn guild_config(ctx: &Context, guild_id: Option<GuildId>) -> GuildConfig {
+    match guild_id {
+        Some(guild_id) => storage(ctx)
+            .await
+            .guild(guild_id)
+            .await
+            .unwrap_or_else(|error| {
+                eprintln!("Error loading guild config: {}", error);
+                GuildConfig::default()
+            }),
+        None => GuildConfig::default(),
+    }
+}
+
+async fn send_embed_response(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    mut embed: CreateEmbed,
+) -> Result<(), String> {
+    guild_config(ctx, command.guild_id)
+        .await
+        .theme
+        .apply(&mut embed);
+
+    let reply = Reply {
+        embed: Some(embed),
+        ..Reply::default()
+    };
+    respond(ctx, command, &reply)
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Sends `embed` confirming a deletion, with a button that restores `deleted`
+/// for the next [`undo::UNDO_SECONDS`].
+async fn send_undoable_response(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    mut embed: CreateEmbed,
+    deleted: &Deleted,
+) -> Result<(), String> {
+    let lang = language(ctx, command).await;
+    guild_config(ctx, command.guild_id)
+        .await
+        .theme
+        .apply(&mut embed);
+
+    let mut components = CreateComponents::default();
+    components.create_action_row(|row| {
+        row.create_button(|button| {
+            button
+                .custom_id(format!("{}:{}", UNDO_BUTTON_ID, deleted.key()))
+                .style(ButtonStyle::Secondary)
+                .emoji('↩')
+                .label(t(lang, "undo.button"))
+        })
+    });
+    let reply = Reply {
+        content: Some(tf(
+            lang,
+            "undo.hint",
+            &[("at", &(rates::now() + undo::UNDO_SECONDS))],
+        )),
+        embed: Some(embed),
+        components: Some(components),
+        ..Reply::default()
+    };
+    respond(ctx, command, &reply)
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Acknowledges `command` for a reply that will take longer than Discord's three
+/// seconds; send it with [`edit_embed_response`].
+async fn defer_response(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), String> {
+    let result = reply::with_backoff(|| {
+        command.create_interaction_response(&ctx.http, |response| {
+            response.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+        })
+    })
+    .await;
+    match result {
+        // The earlier response stands in for the deferral; the reply edits it.
+        Err(error) if reply::is_already_acknowledged(&error) => Ok(()),
+        result => result.map_err(|e| format!("Error sending response: {:?}", e)),
+    }
+}
+
+/// Sends the reply to a command acknowledged with [`defer_response`].
+async fn edit_embed_response(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    mut embed: CreateEmbed,
+) -> Result<(), String> {
+    guild_config(ctx, command.guild_id)
+        .await
+        .theme
+        .apply(&mut embed);
+
+    command
+        .edit_original_interaction_response(&ctx.http, |response| response.set_embed(embed))
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Error editing response: {:?}", e))
+}
+
+/// Like [`send_embed_response`], but only visible to the user who ran the command.
+async fn send_ephemeral_embed_response(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    mut embed: CreateEmbed,
+) -> Result<(), String> {
+    guild_config(ctx, command.guild_id)
+        .await
+        .theme
+        .apply(&mut embed);
+
+    let reply = Reply {
+        embed: Some(embed),
+        ephemeral: true,
+        ..Reply::default()
+    };
+    respond(ctx, command, &reply)
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Sends `embed` with `png` attached and shown as its image.
+async fn send_embed_with_image(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    mut embed: CreateEmbed,
+    png: Vec<u8>,
+    filename: &str,
+) -> Result<(), String> {
+    embed.image(format!("attachment://{}", filename));
+    send_embed_with_file(ctx, command, embed, png, filename).await
+}
+
+/// Like [`send_embed_response`], with `data` attached as `filename`.
+async fn send_embed_with_file(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    mut embed: CreateEmbed,
+    data: Vec<u8>,
+    filename: &str,
+) -> Result<(), String> {
+    guild_config(ctx, command.guild_id)
+        .await
+        .theme
+        .apply(&mut embed);
+
+    let reply = Reply {
+        embed: Some(embed),
+        file: Some((data, filename.to_string())),
+        ..Reply::default()
+    };
+    respond(ctx, command, &reply)
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Sends a calculator's `embed`, or with `format: text` a copyable code block of
+/// it that starts with `summary`.
+async fn send_calculator_response(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    mut embed: CreateEmbed,
+    summary: &str,
+) -> Result<(), String> {
+    stamp_stale_rates(ctx, language(ctx, command).await, &mut embed).await;
+    if !wants_text(&command.data.options) {
+        return send_embed_response(ctx, command, embed).await;
+    }
+    let reply = Reply {
+        content: Some(plaintext::render(summary, &embed)),
+        ..Reply::default()
+    };
+    respond(ctx, command, &reply)
+        .await
+        .map_err(|e| format!("Error sending response: {:?}", e))
+}
+
+/// Warns on `embed` when live exchange rates haven't been fetched for a while,
+/// saying how old the rates behind its prices are.
+async fn stamp_stale_rates(ctx: &Context, lang: Language, embed: &mut CreateEmbed) {
+    let started_at = bot_stats(ctx).await.started_unix;
+    let rates = rates(ctx).await;
+    let rates = rates.read().await;
+    if !rates.is_stale(started_at) {
+        return;
+    }
+    let warning = match rates.updated_at {
+        Some(updated_at) => tf(
+            lang,
+            "rates.stale",
+            &[("time", &format!("<t:{}:f>", updated_at))],
+        ),
+        None => t(lang, "rates.stale_default").to_string(),
+    };
+    embed.field(t(lang, "rates.stale_title"), warning, false);
+}
+
+/// Posts `error` to the deployment's log channel, if `LOG_CHANNEL_ID` is set, so
+/// operators see failures that would otherwise only reach stderr. Builds with the
+/// `sentry` feature also send it to Sentry.
+async fn report_error(ctx: &Context, title: &str, error: &str, context: Vec<(&str, String)>) {
+    #[cfg(feature = "sentry")]
+    reporting::capture_error(title, error, &context);
+    let channel_id = match state(ctx).await.log_channel {
+        Some(channel_id) => channel_id,
+        None => return,
+    };
+    let embed = opslog::alert(title, error, &context);
+    if let Err(why) = channel_id
+        .send_message(&ctx.http, |message| message.set_embed(embed))
+        .await
+    {
+        eprintln!("Cannot post to log channel: {}", why);
+    }
+}
+
+/// Tells the log channel that a failure [`report_error`] posted at `since` is over.
+async fn report_recovery(ctx: &Context, title: &str, since: u64) {
+    let channel_id = match state(ctx).await.log_channel {
+        Some(channel_id) => channel_id,
+        None => return,
+    };
+    let embed = opslog::recovered(title, &[("Failing since", format!("<t:{}:R>", since))]);
+    if let Err(why) = channel_id
+        .send_message(&ctx.http, |message| message.set_embed(embed))
+        .await
+    {
+        eprintln!("Cannot post to log channel: {}", why);
+    }
+}
+
+/// The log channel fields for an interaction: what was run, who ran it and where.
+fn interaction_context<'a>(
+    (name, value): (&'a str, &str),
+    user: &User,
+    guild_id: Option<GuildId>,
+) -> Vec<(&'a str, String)> {
+    vec![
+        (name, value.to_string()),
+        ("User", format!("<@{}> ({})", user.id, user.tag())),
+        (
+            "Guild",
+            guild_id.map_or_else(|| "DM".to_string(), |id| id.to_string()),
+        ),
+    ]
+}
+
+/// Runs `registered` for `command` unless the user ran it too recently.
+async fn run_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    registered: &BotCommand,
+) -> Result<(), String> {
+    if let Err(available_at) =
+        registry().start_cooldown(registered, command.user.id.0, rates::now())
+    {
+        return Err(tf(
+            language(ctx, command).await,
+            "error.cooldown",
+            &[
+                ("command", &registered.name),
+                ("time", &format!("<t:{}:R>", available_at)),
+            ],
+        ));
+    }
+    // Settings are compared before and after rather than each handler logging
+    // its own changes, so new settings are audited without touching handlers.
+    let audited = match command.guild_id {
+        Some(guild_id) if registered.audited => Some(storage(ctx).await.guild(guild_id).await?),
+        _ => None,
+    };
+    let result = registered.run(ctx, command).await;
+    if let (Some(before), Some(guild_id)) = (audited, command.guild_id) {
+        audit_settings_changes(ctx, command, guild_id, &before).await;
+    }
+    result
+}
+
+/// Records each setting `command` changed since `before` in the audit log.
+async fn audit_settings_changes(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    guild_id: GuildId,
+    before: &GuildConfig,
+) {
+    let after = match storage(ctx).await.guild(guild_id).await {
+        Ok(after) => after,
+        Err(error) => {
+            eprintln!("Cannot audit /{}: {}", command.data.name, error);
+            return;
+        }
+    };
+    let entries = audit::changes(before, &after)
+        .into_iter()
+        .map(|change| {
+            audit_entry(
+                command,
+                guild_id,
+                change.setting.to_string(),
+                Some(change.old),
+                Some(change.new),
+            )
+        })
+        .collect();
+    record_audit(ctx, &after, entries).await;
+}
+
+fn audit_entry(
+    command: &ApplicationCommandInteraction,
+    guild_id: GuildId,
+    setting: String,
+    old_value: Option<String>,
+    new_value: Option<String>,
+) -> AuditEntry {
+    AuditEntry {
+        guild_id: guild_id.0,
+        actor_id: command.user.id.0,
+        action: audit::action(command),
+        setting,
+        old_value,
+        new_value,
+        created_at: rates::now(),
+    }
+}
+
+/// Saves `entries` to the audit log and posts them to the guild's audit
+/// channel, if it set one. Failures are only logged, since the changes have
+/// already been made.
+async fn record_audit(ctx: &Context, config: &GuildConfig, entries: Vec<AuditEntry>) {
+    if entries.is_empty() {
+        return;
+    }
+    let storage = storage(ctx).await;
+    for entry in &entries {
+        if let Err(error) = storage.record_audit(entry.clone()).await {
+            eprintln!("Cannot record audit entry: {}", error);
+        }
+    }
+
+    let Some(channel) = config.audit_channel else {
+        return;
+    };
+    let lang = config.language.unwrap_or_default();
+    let lines: Vec<String> = entries.iter().map(audit::line).collect();
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(t(lang, "auditlog.title"))
+        .description(lines.join("\n"));
+    config.theme.apply(&mut embed);
+    if let Err(why) = ChannelId(channel)
+        .send_message(&ctx.http, |message| message.set_embed(embed))
+        .await
+    {
+        eprintln!("Cannot mirror audit entries: {}", why);
+    }
+}
+
+async fn respond_with_error(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    error_message: &str,
+) {
+    let reply = Reply {
+        content: Some(error_message.to_string()),
+        ephemeral: true,
+        ..Reply::default()
+    };
+    if let Err(why) = respond(ctx, command, &reply).await {
+        eprintln!("Cannot respond to slash command: {}", why);
+    }
+}
+
+/// Registers the commands that work in DMs globally and the rest in `GUILD_ID`,
+/// if it's set.
+async fn register_commands(ctx: &Context) -> Result<(), Box<dyn std::error::Error>> {
+    let commands =
+        command::Command::set_global_application_commands(&ctx.http, global_command_definitions)
+            .await?;
+    println!(
+        "Registered the following global slash commands: {:#?}",
+        commands
+    );
+
+    let Some(guild_id) = state(ctx).await.command_guild else {
+        return Ok(());
+    };
+    let config = guild_config(ctx, Some(guild_id)).await;
+    let commands = guild_id
+        .set_application_commands(&ctx.http, guild_command_definitions(&config))
+        .await?;
+
+    println!("Registered the following slash commands: {:#?}", commands);
+    Ok(())
+}
+
+/// The commands that work in DMs as well as guilds.
+fn global_command_definitions(
+    commands: &mut CreateApplicationCommands,
+) -> &mut CreateApplicationCommands {
+    registry().define_global(commands)
+}
+
+/// The guild-only commands, without the command groups that are off in the guild.
+fn guild_command_definitions(
+    config: &GuildConfig,
+) -> impl FnOnce(&mut CreateApplicationCommands) -> &mut CreateApplicationCommands + '_ {
+    move |commands| {
+        registry().define_guild(commands);
+        commands.0.retain(|command| {
+            command["name"]
+                .as_str()
+                .and_then(Feature::for_command)
+                .is_none_or(|feature| feature.is_on(config))
+        });
+        commands
+    }
+}
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(command_registry)
+}
+
+/// Every command with the handler its interactions go to. Commands that call
+/// rate-limited APIs or render images have a per-user cooldown.
+fn command_registry() -> Registry {
+    let mut registry = Registry::new(core_commands());
+    for plugin in plugins() {
+        registry.register(plugin.as_ref());
+    }
+    registry
+}
+
+/// The optional subsystems this build was compiled with. A third-party plugin
+/// is added to the bot by listing it here.
+fn plugins() -> Vec<Box<dyn Plugin>> {
+    vec![
+        #[cfg(feature = "orders")]
+        Box::new(OrdersPlugin),
+        #[cfg(feature = "roblox")]
+        Box::new(RobloxPlugin),
+        #[cfg(feature = "api")]
+        Box::new(ApiPlugin),
+    ]
+}
+
+/// The commands every build has.
+fn core_commands() -> Vec<BotCommand> {
+    vec![
+        BotCommand::new("help", handle_help_command, |command| {
+            command
+                .localized_name("cmd.help.name")
+                .localized_description("cmd.help")
+        })
+        .in_dms(),
+        BotCommand::new("price", handle_price_command, |command| {
+            command
+                .localized_name("cmd.price.name")
+                .localized_description("cmd.price")
+                .create_option(|option| {
+                    option
+                        .name("type")
+                        .localized_description("opt.price.type")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .add_string_choice("b/t", "b/t")
+                        .add_string_choice("a/t", "a/t")
+                        .add_string_choice("both (compare b/t and a/t)", "both")
+                })
+                .create_option(|option| {
+                    option
+                        .name("amount")
+                        .localized_description("opt.price.amount")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .max_length(32)
+                })
+                .create_option(|option| {
+                    option
+                        .name("method")
+                        .localized_description("opt.price.method")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("Gamepass", "gamepass")
+                        .add_string_choice("Developer Product", "devproduct")
+                        .add_string_choice("Group Payout", "group")
+                        .add_string_choice("Gift Card", "giftcard")
+                        .add_string_choice("Compare all methods", "compare")
+                })
+                .create_option(|option| {
+                    option
+                        .name("premium")
+                        .localized_description("opt.premium")
+                        .kind(CommandOptionType::Boolean)
+                })
+                .create_option(|option| {
+                    option
+                        .name("crypto")
+                        .localized_description("opt.price.crypto")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("BTC", "BTC")
+                        .add_string_choice("ETH", "ETH")
+                        .add_string_choice("LTC", "LTC")
+                })
+                .create_option(|option| {
+                    option
+                        .name("coupon")
+                        .localized_description("opt.coupon")
+                        .kind(CommandOptionType::String)
+                        .max_length(coupons::MAX_CODE_LENGTH)
+                })
+                .create_option(|option| {
+                    option
+                        .name("format")
+                        .localized_description("opt.format")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("Embed", "embed")
+                        .add_string_choice("Text", "text")
+                })
+                .create_option(|option| {
+                    option
+                        .name("seller")
+                        .localized_description("opt.price.seller")
+                        .kind(CommandOptionType::User)
+                })
+                .create_option(|option| {
+                    option
+                        .name("side")
+                        .localized_description("opt.price.side")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("We sell to you", "sell")
+                        .add_string_choice("We buy from you", "buy")
+                })
+        })
+        .in_dms(),
+        BotCommand::new("rates", handle_rates_command, |command| {
+            command
+                .localized_name("cmd.rates.name")
+                .localized_description("cmd.rates")
+        })
+        .in_dms(),
+        BotCommand::new("ratehistory", handle_ratehistory_command, |command| {
+            command
+                .localized_name("cmd.ratehistory.name")
+                .localized_description("cmd.ratehistory")
+                .dm_permission(false)
+        }),
+        BotCommand::new("setrates", handle_setrates_command, |command| {
+            command
+                .localized_name("cmd.setrates.name")
+                .localized_description("cmd.setrates")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("sell")
+                        .localized_description("opt.setrates.sell")
+                        .kind(CommandOptionType::Number)
+                        .min_number_value(0.01)
+                        .max_number_value(validation::MAX_TIER_RATE)
+                })
+                .create_option(|option| {
+                    option
+                        .name("buy")
+                        .localized_description("opt.setrates.buy")
+                        .kind(CommandOptionType::Number)
+                        .min_number_value(0.0)
+                        .max_number_value(validation::MAX_TIER_RATE)
+                })
+        })
+        .audited(),
+        BotCommand::new("setrate", handle_setrate_command, |command| {
+            command
+                .localized_name("cmd.setrate.name")
+                .localized_description("cmd.setrate")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("forex")
+                        .localized_description("opt.setrate.forex")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("pair")
+                                .localized_description("opt.setrate.pair")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .add_string_choice("GBP/USD", "GBP/USD")
+                                .add_string_choice("USD/GBP", "USD/GBP")
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("value")
+                                .localized_description("opt.setrate.value")
+                                .kind(CommandOptionType::Number)
+                                .required(true)
+                                .min_number_value(0.0)
+                                .max_number_value(rates::MAX_FOREX_OVERRIDE)
+                        })
+                })
+        })
+        .audited(),
+        BotCommand::new("convert", handle_convert_command, |command| {
+            command
+                .localized_name("cmd.convert.name")
+                .localized_description("cmd.convert")
+                .create_option(|option| {
+                    option
+                        .name("amount")
+                        .localized_description("opt.convert_amount")
+                        .kind(CommandOptionType::Number)
+                        .required(true)
+                        .min_number_value(validation::MIN_FIAT_AMOUNT)
+                        .max_number_value(validation::MAX_FIAT_AMOUNT)
+                })
+                .create_option(|option| {
+                    option
+                        .name("currency")
+                        .localized_description("opt.currency_from")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("GBP", "GBP")
+                        .add_string_choice("USD", "USD")
+                })
+                .create_option(|option| {
+                    option
+                        .name("to")
+                        .localized_description("opt.convert_to")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("GBP", "GBP")
+                        .add_string_choice("USD", "USD")
+                        .add_string_choice("BTC", "BTC")
+                        .add_string_choice("ETH", "ETH")
+                        .add_string_choice("LTC", "LTC")
+                })
+                .create_option(|option| {
+                    option
+                        .name("format")
+                        .localized_description("opt.format")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("Embed", "embed")
+                        .add_string_choice("Text", "text")
+                })
+        })
+        .in_dms(),
+        BotCommand::new("theme", handle_theme_command, |command| {
+            command
+                .localized_name("cmd.theme.name")
+                .localized_description("cmd.theme")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .create_option(|option| {
+                    option
+                        .name("set")
+                        .localized_description("opt.theme.set")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("color")
+                                .localized_description("opt.theme.color")
+                                .kind(CommandOptionType::String)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("footer")
+                                .localized_description("opt.theme.footer")
+                                .kind(CommandOptionType::String)
+                                .max_length(2048)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("thumbnail")
+                                .localized_description("opt.theme.thumbnail")
+                                .kind(CommandOptionType::String)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("reset")
+                        .localized_description("opt.theme.reset")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited(),
+        BotCommand::new("tier", handle_tier_command, |command| {
+            command
+                .localized_name("cmd.tier.name")
+                .localized_description("cmd.tier")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .create_option(|option| {
+                    option
+                        .name("add")
+                        .localized_description("opt.tier.add")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("min_robux")
+                                .localized_description("opt.tier.min_robux")
+                                .kind(CommandOptionType::Integer)
+                                .required(true)
+                                .min_int_value(0)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("rate")
+                                .localized_description("opt.tier.rate")
+                                .kind(CommandOptionType::Number)
+                                .required(true)
+                                .min_number_value(0.01)
+                                .max_number_value(validation::MAX_TIER_RATE)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("remove")
+                        .localized_description("opt.tier.remove")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("min_robux")
+                                .localized_description("opt.tier.remove_min_robux")
+                                .kind(CommandOptionType::Integer)
+                                .required(true)
+                                .min_int_value(0)
+                        })
+                })
+        })
+        .audited(),
+        BotCommand::new("announce", handle_announce_command, |command| {
+            command
+                .localized_name("cmd.announce.name")
+                .localized_description("cmd.announce")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("ratechange")
+                        .localized_description("opt.announce.ratechange")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("channel")
+                                .localized_description("opt.announce.channel")
+                                .kind(CommandOptionType::Channel)
+                                .channel_types(&[ChannelType::Text, ChannelType::News])
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("role")
+                                .localized_description("opt.announce.role")
+                                .kind(CommandOptionType::Role)
+                        })
+                })
+        })
+        .audited(),
+        BotCommand::new("pricelist", handle_pricelist_command, |command| {
+            command
+                .localized_name("cmd.pricelist.name")
+                .localized_description("cmd.pricelist")
+                .create_option(|option| {
+                    option
+                        .name("show")
+                        .localized_description("opt.pricelist.show")
+                        .kind(CommandOptionType::SubCommand)
+                })
+                .create_option(|option| {
+                    option
+                        .name("image")
+                        .localized_description("opt.pricelist.image")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .in_dms(),
+        BotCommand::new("history", handle_history_command, |command| {
+            command
+                .localized_name("cmd.history.name")
+                .localized_description("cmd.history")
+                .create_option(|option| {
+                    option
+                        .name("count")
+                        .localized_description("opt.history.count")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(1)
+                        .max_int_value(25)
+                })
+                .create_option(|option| {
+                    option
+                        .name("user")
+                        .localized_description("opt.history.user")
+                        .kind(CommandOptionType::User)
+                })
+        }),
+        BotCommand::new("packs", handle_packs_command, |command| {
+            command
+                .localized_name("cmd.packs.name")
+                .localized_description("cmd.packs")
+                .create_option(|option| {
+                    option
+                        .name("currency")
+                        .localized_description("opt.packs.currency")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("GBP", "GBP")
+                        .add_string_choice("USD", "USD")
+                })
+        })
+        .in_dms(),
+        BotCommand::new("value", handle_value_command, |command| {
+            command
+                .localized_name("cmd.value.name")
+                .localized_description("cmd.value")
+                .create_option(|option| {
+                    option
+                        .name("amount")
+                        .localized_description("opt.value.amount")
+                        .kind(CommandOptionType::Number)
+                        .required(true)
+                        .min_number_value(validation::MIN_FIAT_AMOUNT)
+                        .max_number_value(validation::MAX_FIAT_AMOUNT)
+                })
+                .create_option(|option| {
+                    option
+                        .name("currency")
+                        .localized_description("opt.value.currency")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .add_string_choice("GBP", "GBP")
+                        .add_string_choice("USD", "USD")
+                })
+        })
+        .in_dms(),
+        BotCommand::new("worth", handle_worth_command, |command| {
+            command
+                .localized_name("cmd.worth.name")
+                .localized_description("cmd.worth")
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("robux")
+                        .localized_description("opt.worth.robux")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .max_length(32)
+                })
+        }),
+        BotCommand::new("giftcards", handle_giftcards_command, |command| {
+            command
+                .localized_name("cmd.giftcards.name")
+                .localized_description("cmd.giftcards")
+                .create_option(|option| {
+                    option
+                        .name("region")
+                        .localized_description("opt.giftcards.region")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("United States", "us")
+                        .add_string_choice("United Kingdom", "uk")
+                        .add_string_choice("Europe", "eu")
+                })
+        })
+        .in_dms(),
+        BotCommand::new("language", handle_language_command, |command| {
+            command
+                .localized_name("cmd.language.name")
+                .localized_description("cmd.language")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .create_option(|option| {
+                    option
+                        .name("language")
+                        .localized_description("opt.language.language")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .add_string_choice("Auto", "auto");
+                    for language in Language::ALL {
+                        option.add_string_choice(language.name(), language.code());
+                    }
+                    option
+                })
+        })
+        .audited(),
+        BotCommand::new("alert", handle_alert_command, |command| {
+            command
+                .localized_name("cmd.alert.name")
+                .localized_description("cmd.alert")
+                .create_option(|option| {
+                    option
+                        .name("set")
+                        .localized_description("opt.alert.set")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("pair")
+                                .localized_description("opt.alert.pair")
+                                .kind(CommandOptionType::String)
+                                .required(true);
+                            for pair in rates::PAIRS {
+                                option.add_string_choice(pair, pair);
+                            }
+                            option
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("threshold")
+                                .localized_description("opt.alert.threshold")
+                                .kind(CommandOptionType::Number)
+                                .required(true)
+                                .min_number_value(0.0001)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("list")
+                        .localized_description("opt.alert.list")
+                        .kind(CommandOptionType::SubCommand)
+                })
+                .create_option(|option| {
+                    option
+                        .name("remove")
+                        .localized_description("opt.alert.remove")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("id")
+                                .localized_description("opt.alert.id")
+                                .kind(CommandOptionType::Integer)
+                                .required(true)
+                                .min_int_value(1)
+                        })
+                })
+        }),
+        BotCommand::new("priceboard", handle_priceboard_command, |command| {
+            command
+                .localized_name("cmd.priceboard.name")
+                .localized_description("cmd.priceboard")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .create_option(|option| {
+                    option
+                        .name("enable")
+                        .localized_description("opt.priceboard.enable")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("channel")
+                                .localized_description("opt.priceboard.channel")
+                                .kind(CommandOptionType::Channel)
+                                .channel_types(&[ChannelType::Text, ChannelType::News])
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("minutes")
+                                .localized_description("opt.priceboard.minutes")
+                                .kind(CommandOptionType::Integer)
+                                .min_int_value(1)
+                                .max_int_value(1440)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("disable")
+                        .localized_description("opt.priceboard.disable")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited(),
+        BotCommand::new("ratechart", handle_ratechart_command, |command| {
+            command
+                .localized_name("cmd.ratechart.name")
+                .localized_description("cmd.ratechart")
+                .create_option(|option| {
+                    option
+                        .name("pair")
+                        .localized_description("opt.ratechart.pair")
+                        .kind(CommandOptionType::String)
+                        .required(true);
+                    for pair in rates::PAIRS {
+                        option.add_string_choice(pair, pair);
+                    }
+                    option
+                })
+                .create_option(|option| {
+                    option
+                        .name("period")
+                        .localized_description("opt.ratechart.period")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .add_string_choice("24 hours", "24h")
+                        .add_string_choice("7 days", "7d")
+                        .add_string_choice("30 days", "30d")
+                        .add_string_choice("90 days", "90d")
+                })
+        })
+        .cooldown(10)
+        .in_dms(),
+        BotCommand::new("vouch", handle_vouch_command, |command| {
+            command
+                .localized_name("cmd.vouch.name")
+                .localized_description("cmd.vouch")
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("seller")
+                        .localized_description("opt.vouch.seller")
+                        .kind(CommandOptionType::User)
+                        .required(true)
+                })
+                .create_option(|option| {
+                    option
+                        .name("stars")
+                        .localized_description("opt.vouch.stars")
+                        .kind(CommandOptionType::Integer)
+                        .required(true)
+                        .min_int_value(1)
+                        .max_int_value(vouches::MAX_STARS)
+                })
+                .create_option(|option| {
+                    option
+                        .name("comment")
+                        .localized_description("opt.vouch.comment")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .max_length(500)
+                })
+        }),
+        BotCommand::new("vouchchannel", handle_vouch_channel_command, |command| {
+            command
+                .localized_name("cmd.vouchchannel.name")
+                .localized_description("cmd.vouchchannel")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("channel")
+                        .localized_description("opt.vouchchannel.channel")
+                        .kind(CommandOptionType::Channel)
+                        .channel_types(&[ChannelType::Text, ChannelType::News])
+                })
+        })
+        .audited(),
+        BotCommand::new("reputation", handle_reputation_command, |command| {
+            command
+                .localized_name("cmd.reputation.name")
+                .localized_description("cmd.reputation")
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("user")
+                        .localized_description("opt.reputation.user")
+                        .kind(CommandOptionType::User)
+                })
+        }),
+        BotCommand::new("limited", handle_limited_command, |command| {
+            command
+                .localized_name("cmd.limited.name")
+                .localized_description("cmd.limited")
+                .create_option(|option| {
+                    option
+                        .name("item")
+                        .localized_description("opt.limited.item")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .max_length(100)
+                })
+        })
+        .cooldown(10),
+        BotCommand::new("remind", handle_remind_command, |command| {
+            command
+                .localized_name("cmd.remind.name")
+                .localized_description("cmd.remind")
+                .create_option(|option| {
+                    option
+                        .name("in")
+                        .localized_description("opt.remind.in")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .max_length(20)
+                })
+                .create_option(|option| {
+                    option
+                        .name("note")
+                        .localized_description("opt.remind.note")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .max_length(1000)
+                })
+                .create_option(|option| {
+                    option
+                        .name("dm")
+                        .localized_description("opt.remind.dm")
+                        .kind(CommandOptionType::Boolean)
+                })
+        }),
+        BotCommand::new("margin", handle_margin_command, |command| {
+            command
+                .localized_name("cmd.margin.name")
+                .localized_description("cmd.margin")
+                .create_option(|option| {
+                    option
+                        .name("buy_rate")
+                        .localized_description("opt.margin.buy_rate")
+                        .kind(CommandOptionType::Number)
+                        .required(true)
+                        .min_number_value(0.01)
+                })
+                .create_option(|option| {
+                    option
+                        .name("sell_rate")
+                        .localized_description("opt.margin.sell_rate")
+                        .kind(CommandOptionType::Number)
+                        .required(true)
+                        .min_number_value(0.01)
+                })
+                .create_option(|option| {
+                    option
+                        .name("amount")
+                        .localized_description("opt.margin.amount")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .max_length(32)
+                })
+                .create_option(|option| {
+                    option
+                        .name("method")
+                        .localized_description("opt.margin.method")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("Gamepass", "gamepass")
+                        .add_string_choice("Developer Product", "devproduct")
+                        .add_string_choice("Group Payout", "group")
+                        .add_string_choice("Gift Card", "giftcard")
+                })
+                .create_option(|option| {
+                    option
+                        .name("payment")
+                        .localized_description("opt.margin.payment")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("PayPal", "paypal")
+                        .add_string_choice("Cash App", "cashapp")
+                        .add_string_choice("BTC", "btc")
+                        .add_string_choice("ETH", "eth")
+                        .add_string_choice("LTC", "ltc")
+                })
+        })
+        .in_dms(),
+        BotCommand::new("rounding", handle_rounding_command, |command| {
+            command
+                .localized_name("cmd.rounding.name")
+                .localized_description("cmd.rounding")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("mode")
+                        .localized_description("opt.rounding.mode")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .add_string_choice("Nearest", "nearest")
+                        .add_string_choice("Always up", "up")
+                        .add_string_choice("Always down", "down")
+                })
+                .create_option(|option| {
+                    option
+                        .name("decimals")
+                        .localized_description("opt.rounding.decimals")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(0)
+                        .max_int_value(Rounding::MAX_DECIMALS)
+                })
+        })
+        .audited(),
+        BotCommand::new("blacklist", handle_blacklist_command, |command| {
+            command
+                .localized_name("cmd.blacklist.name")
+                .localized_description("cmd.blacklist")
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("add")
+                        .localized_description("opt.blacklist.add")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("user")
+                                .localized_description("opt.blacklist.user")
+                                .kind(CommandOptionType::User)
+                                .required(true)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("reason")
+                                .localized_description("opt.blacklist.reason")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .max_length(500)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("global")
+                                .localized_description("opt.blacklist.global")
+                                .kind(CommandOptionType::Boolean)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("remove")
+                        .localized_description("opt.blacklist.remove")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("user")
+                                .localized_description("opt.blacklist.user")
+                                .kind(CommandOptionType::User)
+                                .required(true)
+                        })
+                        .create_sub_option(|option| {
                             option
-                                .name("type")
-                                .description("Conversion type (b/t or a/t)")
-                                .kind(CommandOptionType::String)
+                                .name("global")
+                                .localized_description("opt.blacklist.global")
+                                .kind(CommandOptionType::Boolean)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("check")
+                        .localized_description("opt.blacklist.check")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("user")
+                                .localized_description("opt.blacklist.user")
+                                .kind(CommandOptionType::User)
                                 .required(true)
-                                .add_string_choice("b/t", "b/t")
-                                .add_string_choice("a/t", "a/t")
                         })
-                        .create_option(|option| {
+                })
+        }),
+        BotCommand::new("auditlog", handle_auditlog_command, |command| {
+            command
+                .localized_name("cmd.auditlog.name")
+                .localized_description("cmd.auditlog")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("show")
+                        .localized_description("opt.auditlog.show")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("user")
+                                .localized_description("opt.auditlog.user")
+                                .kind(CommandOptionType::User)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("count")
+                                .localized_description("opt.auditlog.count")
+                                .kind(CommandOptionType::Integer)
+                                .min_int_value(1)
+                                .max_int_value(MAX_AUDIT_LOG_PAGE)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("mirror")
+                        .localized_description("opt.auditlog.mirror")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("channel")
+                                .localized_description("opt.auditlog.channel")
+                                .kind(CommandOptionType::Channel)
+                                .channel_types(&[ChannelType::Text, ChannelType::News])
+                        })
+                })
+        })
+        .audited(),
+        BotCommand::new("retention", handle_retention_command, |command| {
+            command
+                .localized_name("cmd.retention.name")
+                .localized_description("cmd.retention")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("history_days")
+                        .localized_description("opt.retention.history_days")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(1)
+                        .max_int_value(retention::MAX_DAYS)
+                })
+                .create_option(|option| {
+                    option
+                        .name("order_days")
+                        .localized_description("opt.retention.order_days")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(1)
+                        .max_int_value(retention::MAX_DAYS)
+                })
+        })
+        .audited(),
+        BotCommand::new("points", handle_points_command, |command| {
+            command
+                .localized_name("cmd.points.name")
+                .localized_description("cmd.points")
+                .dm_permission(false)
+        }),
+        BotCommand::new("loyalty", handle_loyalty_command, |command| {
+            command
+                .localized_name("cmd.loyalty.name")
+                .localized_description("cmd.loyalty")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("add")
+                        .localized_description("opt.loyalty.add")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
                             option
-                                .name("amount")
-                                .description("Amount of Robux")
+                                .name("min_robux")
+                                .localized_description("opt.loyalty.min_robux")
                                 .kind(CommandOptionType::Integer)
                                 .required(true)
+                                .min_int_value(0)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("percent")
+                                .localized_description("opt.loyalty.percent")
+                                .kind(CommandOptionType::Number)
+                                .required(true)
+                                .min_number_value(0.1)
+                                .max_number_value(loyalty::MAX_DISCOUNT_PERCENT)
                         })
                 })
-                .create_application_command(|command: &mut CreateApplicationCommand| {
-                    command
-                        .name("convert")
-                        .description("Convert between GBP and USD")
-                        .create_option(|option| {
+                .create_option(|option| {
+                    option
+                        .name("remove")
+                        .localized_description("opt.loyalty.remove")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("min_robux")
+                                .localized_description("opt.loyalty.remove_min_robux")
+                                .kind(CommandOptionType::Integer)
+                                .required(true)
+                                .min_int_value(0)
+                        })
+                })
+        })
+        .audited(),
+        BotCommand::new("rolereward", handle_rolereward_command, |command| {
+            command
+                .localized_name("cmd.rolereward.name")
+                .localized_description("cmd.rolereward")
+                .default_member_permissions(Permissions::MANAGE_ROLES)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("add")
+                        .localized_description("opt.rolereward.add")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("min_robux")
+                                .localized_description("opt.rolereward.min_robux")
+                                .kind(CommandOptionType::Integer)
+                                .required(true)
+                                .min_int_value(1)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("role")
+                                .localized_description("opt.rolereward.role")
+                                .kind(CommandOptionType::Role)
+                                .required(true)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("remove")
+                        .localized_description("opt.rolereward.remove")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("min_robux")
+                                .localized_description("opt.rolereward.remove_min_robux")
+                                .kind(CommandOptionType::Integer)
+                                .required(true)
+                                .min_int_value(1)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("list")
+                        .localized_description("opt.rolereward.list")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited(),
+        BotCommand::new("roleprice", handle_roleprice_command, |command| {
+            command
+                .localized_name("cmd.roleprice.name")
+                .localized_description("cmd.roleprice")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("set")
+                        .localized_description("opt.roleprice.set")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("role")
+                                .localized_description("opt.roleprice.role")
+                                .kind(CommandOptionType::Role)
+                                .required(true)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("percent")
+                                .localized_description("opt.roleprice.percent")
+                                .kind(CommandOptionType::Number)
+                                .required(true)
+                                .min_number_value(0.1)
+                                .max_number_value(loyalty::MAX_DISCOUNT_PERCENT)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("remove")
+                        .localized_description("opt.roleprice.remove")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("role")
+                                .localized_description("opt.roleprice.role")
+                                .kind(CommandOptionType::Role)
+                                .required(true)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("list")
+                        .localized_description("opt.roleprice.list")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited(),
+        BotCommand::new("emojis", handle_emojis_command, |command| {
+            command
+                .localized_name("cmd.emojis.name")
+                .localized_description("cmd.emojis")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("set")
+                        .localized_description("opt.emojis.set")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(emoji_kind_option)
+                        .create_sub_option(|option| {
                             option
-                                .name("currency")
-                                .description("Currency to convert from (GBP or USD)")
+                                .name("emoji")
+                                .localized_description("opt.emojis.emoji")
                                 .kind(CommandOptionType::String)
                                 .required(true)
-                                .add_string_choice("GBP", "GBP")
-                                .add_string_choice("USD", "USD")
                         })
-                        .create_option(|option| {
+                })
+                .create_option(|option| {
+                    option
+                        .name("reset")
+                        .localized_description("opt.emojis.reset")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(emoji_kind_option)
+                })
+                .create_option(|option| {
+                    option
+                        .name("list")
+                        .localized_description("opt.emojis.list")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited(),
+        BotCommand::new("template", handle_template_command, |command| {
+            command
+                .localized_name("cmd.template.name")
+                .localized_description("cmd.template")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("set")
+                        .localized_description("opt.template.set")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(template_command_option)
+                        .create_sub_option(|option| {
+                            option
+                                .name("title")
+                                .localized_description("opt.template.title")
+                                .kind(CommandOptionType::String)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("description")
+                                .localized_description("opt.template.description")
+                                .kind(CommandOptionType::String)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("field")
+                        .localized_description("opt.template.field")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(template_command_option)
+                        .create_sub_option(|option| {
+                            option
+                                .name("name")
+                                .localized_description("opt.template.field_name")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("value")
+                                .localized_description("opt.template.field_value")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("reset")
+                        .localized_description("opt.template.reset")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(template_command_option)
+                })
+                .create_option(|option| {
+                    option
+                        .name("show")
+                        .localized_description("opt.template.show")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(template_command_option)
+                })
+        })
+        .audited(),
+        BotCommand::new("coupon", handle_coupon_command, |command| {
+            command
+                .localized_name("cmd.coupon.name")
+                .localized_description("cmd.coupon")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("create")
+                        .localized_description("opt.coupon.create")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("code")
+                                .localized_description("opt.coupon.code")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .max_length(coupons::MAX_CODE_LENGTH)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("kind")
+                                .localized_description("opt.coupon.kind")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .add_string_choice("Percentage", "percent")
+                                .add_string_choice("Flat (GBP)", "flat")
+                        })
+                        .create_sub_option(|option| {
                             option
-                                .name("amount")
-                                .description("Amount to convert")
+                                .name("value")
+                                .localized_description("opt.coupon.value")
                                 .kind(CommandOptionType::Number)
                                 .required(true)
+                                .min_number_value(validation::MIN_FIAT_AMOUNT)
+                                .max_number_value(validation::MAX_FIAT_AMOUNT)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("max_uses")
+                                .localized_description("opt.coupon.max_uses")
+                                .kind(CommandOptionType::Integer)
+                                .min_int_value(1)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("expires_in_days")
+                                .localized_description("opt.coupon.expires_in_days")
+                                .kind(CommandOptionType::Integer)
+                                .min_int_value(1)
+                                .max_int_value(365)
                         })
                 })
-                .create_application_command(|command: &mut CreateApplicationCommand| {
-                    command
-                        .name("robux")
-                        .description("Convert GBP or USD to the amount of Robux")
-                        .create_option(|option| {
+                .create_option(|option| {
+                    option
+                        .name("delete")
+                        .localized_description("opt.coupon.delete")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
                             option
-                                .name("currency")
-                                .description("Currency to convert from (GBP or USD)")
+                                .name("code")
+                                .localized_description("opt.coupon.code")
                                 .kind(CommandOptionType::String)
                                 .required(true)
-                                .add_string_choice("GBP", "GBP")
-                                .add_string_choice("USD", "USD")
+                                .max_length(coupons::MAX_CODE_LENGTH)
                         })
-                        .create_option(|option| {
+                })
+                .create_option(|option| {
+                    option
+                        .name("list")
+                        .localized_description("opt.coupon.list")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        }),
+        BotCommand::new("payment", handle_payment_command, |command| {
+            command
+                .localized_name("cmd.payment.name")
+                .localized_description("cmd.payment")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("set")
+                        .localized_description("opt.payment.set")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("method")
+                                .localized_description("opt.payment.method")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .add_string_choice("PayPal", "paypal")
+                                .add_string_choice("Cash App", "cashapp")
+                                .add_string_choice("BTC", "btc")
+                                .add_string_choice("ETH", "eth")
+                                .add_string_choice("LTC", "ltc")
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("handle")
+                                .localized_description("opt.payment.handle")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .max_length(100)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("remove")
+                        .localized_description("opt.payment.remove")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("method")
+                                .localized_description("opt.payment.method")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .add_string_choice("PayPal", "paypal")
+                                .add_string_choice("Cash App", "cashapp")
+                                .add_string_choice("BTC", "btc")
+                                .add_string_choice("ETH", "eth")
+                                .add_string_choice("LTC", "ltc")
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("list")
+                        .localized_description("opt.payment.list")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited(),
+        BotCommand::new("fees", handle_fees_command, |command| {
+            command
+                .localized_name("cmd.fees.name")
+                .localized_description("cmd.fees")
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("robux")
+                        .localized_description("opt.fees.robux")
+                        .kind(CommandOptionType::String)
+                        .max_length(32)
+                })
+        }),
+        BotCommand::new("vat", handle_vat_command, |command| {
+            command
+                .localized_name("cmd.vat.name")
+                .localized_description("cmd.vat")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("set")
+                        .localized_description("opt.vat.set")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
                             option
-                                .name("amount")
-                                .description("Amount to convert")
+                                .name("rate")
+                                .localized_description("opt.vat.rate")
                                 .kind(CommandOptionType::Number)
                                 .required(true)
+                                .min_number_value(0.01)
+                                .max_number_value(vat::MAX_VAT_RATE)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("region")
+                                .localized_description("opt.vat.region")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .max_length(8)
                         })
                 })
+                .create_option(|option| {
+                    option
+                        .name("clear")
+                        .localized_description("opt.vat.clear")
+                        .kind(CommandOptionType::SubCommand)
+                })
         })
-        .await?;
+        .audited(),
+        BotCommand::new("stats", handle_stats_command, |command| {
+            command
+                .localized_name("cmd.stats.name")
+                .localized_description("cmd.stats")
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("period")
+                        .localized_description("opt.stats.period")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("24 hours", "24h")
+                        .add_string_choice("7 days", "7d")
+                        .add_string_choice("30 days", "30d")
+                })
+                .create_option(|option| {
+                    option
+                        .name("csv")
+                        .localized_description("opt.stats.csv")
+                        .kind(CommandOptionType::Boolean)
+                })
+        }),
+        BotCommand::new("digest", handle_digest_command, |command| {
+            command
+                .localized_name("cmd.digest.name")
+                .localized_description("cmd.digest")
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("subscribe")
+                        .localized_description("opt.digest.subscribe")
+                        .kind(CommandOptionType::SubCommand)
+                })
+                .create_option(|option| {
+                    option
+                        .name("unsubscribe")
+                        .localized_description("opt.digest.unsubscribe")
+                        .kind(CommandOptionType::SubCommand)
+                })
+                .create_option(|option| {
+                    option
+                        .name("preview")
+                        .localized_description("opt.digest.preview")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited(),
+        BotCommand::new("export", handle_export_command, |command| {
+            command
+                .localized_name("cmd.export.name")
+                .localized_description("cmd.export")
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("orders")
+                        .localized_description("opt.export.orders")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("period")
+                                .localized_description("opt.export.period")
+                                .kind(CommandOptionType::String)
+                                .add_string_choice("24 hours", "24h")
+                                .add_string_choice("7 days", "7d")
+                                .add_string_choice("30 days", "30d")
+                                .add_string_choice("90 days", "90d")
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("format")
+                                .localized_description("opt.export.format")
+                                .kind(CommandOptionType::String)
+                                .add_string_choice("CSV", "csv")
+                                .add_string_choice("JSON", "json")
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("dm")
+                                .localized_description("opt.export.dm")
+                                .kind(CommandOptionType::Boolean)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("calculations")
+                        .localized_description("opt.export.calculations")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("period")
+                                .localized_description("opt.export.period")
+                                .kind(CommandOptionType::String)
+                                .add_string_choice("24 hours", "24h")
+                                .add_string_choice("7 days", "7d")
+                                .add_string_choice("30 days", "30d")
+                                .add_string_choice("90 days", "90d")
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("format")
+                                .localized_description("opt.export.format")
+                                .kind(CommandOptionType::String)
+                                .add_string_choice("CSV", "csv")
+                                .add_string_choice("JSON", "json")
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("dm")
+                                .localized_description("opt.export.dm")
+                                .kind(CommandOptionType::Boolean)
+                        })
+                })
+        }),
+        BotCommand::new("sheets", handle_sheets_command, |command| {
+            command
+                .localized_name("cmd.sheets.name")
+                .localized_description("cmd.sheets")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("set")
+                        .localized_description("opt.sheets.set")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("spreadsheet")
+                                .localized_description("opt.sheets.spreadsheet")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .max_length(200)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("sheet")
+                                .localized_description("opt.sheets.sheet")
+                                .kind(CommandOptionType::String)
+                                .max_length(100)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("headers")
+                                .localized_description("opt.sheets.headers")
+                                .kind(CommandOptionType::Boolean)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("clear")
+                        .localized_description("opt.sheets.clear")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited(),
+        BotCommand::new("webhook", handle_webhook_command, |command| {
+            command
+                .localized_name("cmd.webhook.name")
+                .localized_description("cmd.webhook")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("add")
+                        .localized_description("opt.webhook.add")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("url")
+                                .localized_description("opt.webhook.url")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .max_length(webhooks::MAX_URL_LENGTH)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("remove")
+                        .localized_description("opt.webhook.remove")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("url")
+                                .localized_description("opt.webhook.url")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .max_length(webhooks::MAX_URL_LENGTH)
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("list")
+                        .localized_description("opt.webhook.list")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited(),
+        BotCommand::new("autoreply", handle_autoreply_command, |command| {
+            command
+                .localized_name("cmd.autoreply.name")
+                .localized_description("cmd.autoreply")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("enable")
+                        .localized_description("opt.autoreply.enable")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("channel")
+                                .localized_description("opt.autoreply.channel")
+                                .kind(CommandOptionType::Channel)
+                                .channel_types(&[ChannelType::Text, ChannelType::News])
+                        })
+                })
+                .create_option(|option| {
+                    option
+                        .name("disable")
+                        .localized_description("opt.autoreply.disable")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("channel")
+                                .localized_description("opt.autoreply.channel")
+                                .kind(CommandOptionType::Channel)
+                                .channel_types(&[ChannelType::Text, ChannelType::News])
+                        })
+                })
+        })
+        .audited(),
+        BotCommand::new("setcurrency", handle_setcurrency_command, |command| {
+            command
+                .localized_name("cmd.setcurrency.name")
+                .localized_description("cmd.setcurrency")
+                .create_option(|option| {
+                    option
+                        .name("currency")
+                        .localized_description("opt.setcurrency.currency")
+                        .kind(CommandOptionType::String)
+                        .min_length(3)
+                        .max_length(3)
+                })
+        })
+        .in_dms(),
+        BotCommand::new("forgetme", handle_forgetme_command, |command| {
+            command
+                .localized_name("cmd.forgetme.name")
+                .localized_description("cmd.forgetme")
+        })
+        .in_dms(),
+        BotCommand::new("split", handle_split_command, |command| {
+            command
+                .localized_name("cmd.split.name")
+                .localized_description("cmd.split")
+                .create_option(|option| {
+                    option
+                        .name("robux")
+                        .localized_description("opt.split.robux")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .max_length(32)
+                })
+                .create_option(|option| {
+                    option
+                        .name("people")
+                        .localized_description("opt.split.people")
+                        .kind(CommandOptionType::Integer)
+                        .required(true)
+                        .min_int_value(2)
+                        .max_int_value(MAX_SPLIT_PEOPLE)
+                })
+                .create_option(|option| {
+                    option
+                        .name("currency")
+                        .localized_description("opt.split.currency")
+                        .kind(CommandOptionType::String)
+                        .min_length(3)
+                        .max_length(3)
+                })
+        })
+        .in_dms(),
+        BotCommand::new("pricebatch", handle_pricebatch_command, |command| {
+            command
+                .localized_name("cmd.pricebatch.name")
+                .localized_description("cmd.pricebatch")
+                .create_option(|option| {
+                    option
+                        .name("amounts")
+                        .localized_description("opt.pricebatch.amounts")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .max_length(200)
+                })
+                .create_option(|option| {
+                    option
+                        .name("type")
+                        .localized_description("opt.price.type")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("b/t", "b/t")
+                        .add_string_choice("a/t", "a/t")
+                })
+                .create_option(|option| {
+                    option
+                        .name("method")
+                        .localized_description("opt.price.method")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("Gamepass", "gamepass")
+                        .add_string_choice("Developer Product", "devproduct")
+                        .add_string_choice("Group Payout", "group")
+                        .add_string_choice("Gift Card", "giftcard")
+                })
+                .create_option(|option| {
+                    option
+                        .name("format")
+                        .localized_description("opt.format")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("Embed", "embed")
+                        .add_string_choice("Text", "text")
+                })
+        })
+        .in_dms(),
+        BotCommand::new("quote", handle_quote_command, |command| {
+            command
+                .localized_name("cmd.quote.name")
+                .localized_description("cmd.quote")
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("bulk")
+                        .localized_description("opt.quote.bulk")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(|option| {
+                            option
+                                .name("amounts")
+                                .localized_description("opt.pricebatch.amounts")
+                                .kind(CommandOptionType::String)
+                                .required(true)
+                                .max_length(200)
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("type")
+                                .localized_description("opt.price.type")
+                                .kind(CommandOptionType::String)
+                                .add_string_choice("b/t", "b/t")
+                                .add_string_choice("a/t", "a/t")
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("method")
+                                .localized_description("opt.price.method")
+                                .kind(CommandOptionType::String)
+                                .add_string_choice("Gamepass", "gamepass")
+                                .add_string_choice("Developer Product", "devproduct")
+                                .add_string_choice("Group Payout", "group")
+                                .add_string_choice("Gift Card", "giftcard")
+                        })
+                        .create_sub_option(|option| {
+                            option
+                                .name("valid_days")
+                                .localized_description("opt.quote.valid_days")
+                                .kind(CommandOptionType::Integer)
+                                .min_int_value(1)
+                                .max_int_value(quotes::MAX_VALID_DAYS)
+                        })
+                })
+        }),
+        BotCommand::new("ping", handle_ping_command, |command| {
+            command
+                .localized_name("cmd.ping.name")
+                .localized_description("cmd.ping")
+        }),
+        BotCommand::new("uptime", handle_uptime_command, |command| {
+            command
+                .localized_name("cmd.uptime.name")
+                .localized_description("cmd.uptime")
+        }),
+        BotCommand::new("botstats", handle_botstats_command, |command| {
+            command
+                .localized_name("cmd.botstats.name")
+                .localized_description("cmd.botstats")
+        }),
+        BotCommand::new("sync", handle_sync_command, |command| {
+            command
+                .localized_name("cmd.sync.name")
+                .localized_description("cmd.sync")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .create_option(|option| {
+                    option
+                        .name("global")
+                        .localized_description("opt.sync.global")
+                        .kind(CommandOptionType::Boolean)
+                })
+        })
+        .cooldown(60),
+        BotCommand::new("backup", handle_backup_command, |command| {
+            command
+                .localized_name("cmd.backup.name")
+                .localized_description("cmd.backup")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+        })
+        .cooldown(60),
+        BotCommand::new("features", handle_features_command, |command| {
+            command
+                .localized_name("cmd.features.name")
+                .localized_description("cmd.features")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("list")
+                        .localized_description("opt.features.list")
+                        .kind(CommandOptionType::SubCommand)
+                })
+                .create_option(|option| {
+                    option
+                        .name("enable")
+                        .localized_description("opt.features.enable")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(feature_option)
+                })
+                .create_option(|option| {
+                    option
+                        .name("disable")
+                        .localized_description("opt.features.disable")
+                        .kind(CommandOptionType::SubCommand)
+                        .create_sub_option(feature_option)
+                })
+        })
+        .audited(),
+        BotCommand::new(
+            "Calculate Robux",
+            handle_calculate_message_command,
+            |command| {
+                command
+                    .kind(command::CommandType::Message)
+                    .localized_name("cmd.calculate.name")
+            },
+        ),
+        BotCommand::new("robux", handle_robux_command, |command| {
+            command
+                .localized_name("cmd.robux.name")
+                .localized_description("cmd.robux")
+                .create_option(|option| {
+                    option
+                        .name("currency")
+                        .localized_description("opt.currency_from")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                        .add_string_choice("GBP", "GBP")
+                        .add_string_choice("USD", "USD")
+                })
+                .create_option(|option| {
+                    option
+                        .name("amount")
+                        .localized_description("opt.convert_amount")
+                        .kind(CommandOptionType::Number)
+                        .required(true)
+                        .min_number_value(validation::MIN_FIAT_AMOUNT)
+                        .max_number_value(validation::MAX_FIAT_AMOUNT)
+                })
+                .create_option(|option| {
+                    option
+                        .name("premium")
+                        .localized_description("opt.premium")
+                        .kind(CommandOptionType::Boolean)
+                })
+                .create_option(|option| {
+                    option
+                        .name("format")
+                        .localized_description("opt.format")
+                        .kind(CommandOptionType::String)
+                        .add_string_choice("Embed", "embed")
+                        .add_string_choice("Text", "text")
+                })
+        })
+        .in_dms(),
+    ]
+}
 
-    println!("Registered the following slash commands: {:#?}", commands);
-    Ok(())
+/// Tickets, orders, stock and sellers; the `orders` feature.
+#[cfg(feature = "orders")]
+struct OrdersPlugin;
+
+#[cfg(feature = "orders")]
+impl Plugin for OrdersPlugin {
+    fn name(&self) -> &'static str {
+        "orders"
+    }
+
+    fn commands(&self) -> Vec<BotCommand> {
+        vec![
+            BotCommand::new("buy", handle_buy_command, |command| {
+                command
+                    .localized_name("cmd.buy.name")
+                    .localized_description("cmd.buy")
+                    .dm_permission(false)
+                    .create_option(|option| {
+                        option
+                            .name("type")
+                            .localized_description("opt.price.type")
+                            .kind(CommandOptionType::String)
+                            .required(true)
+                            .add_string_choice("b/t", "b/t")
+                            .add_string_choice("a/t", "a/t")
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("amount")
+                            .localized_description("opt.price.amount")
+                            .kind(CommandOptionType::String)
+                            .required(true)
+                            .max_length(32)
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("method")
+                            .localized_description("opt.price.method")
+                            .kind(CommandOptionType::String)
+                            .add_string_choice("Gamepass", "gamepass")
+                            .add_string_choice("Developer Product", "devproduct")
+                            .add_string_choice("Group Payout", "group")
+                            .add_string_choice("Gift Card", "giftcard")
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("crypto")
+                            .localized_description("opt.buy.crypto")
+                            .kind(CommandOptionType::String)
+                            .add_string_choice("BTC", "BTC")
+                            .add_string_choice("ETH", "ETH")
+                            .add_string_choice("LTC", "LTC")
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("coupon")
+                            .localized_description("opt.coupon")
+                            .kind(CommandOptionType::String)
+                            .max_length(coupons::MAX_CODE_LENGTH)
+                    })
+            }),
+            BotCommand::new("ticket", handle_ticket_command, |command| {
+                command
+                    .localized_name("cmd.ticket.name")
+                    .localized_description("cmd.ticket")
+                    .dm_permission(false)
+                    .create_option(|option| {
+                        option
+                            .name("setup")
+                            .localized_description("opt.ticket.setup")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("staff_role")
+                                    .localized_description("opt.ticket.staff_role")
+                                    .kind(CommandOptionType::Role)
+                                    .required(true)
+                            })
+                            .create_sub_option(|option| {
+                                option
+                                    .name("category")
+                                    .localized_description("opt.ticket.category")
+                                    .kind(CommandOptionType::Channel)
+                                    .channel_types(&[ChannelType::Category])
+                            })
+                            .create_sub_option(|option| {
+                                option
+                                    .name("log_channel")
+                                    .localized_description("opt.ticket.log_channel")
+                                    .kind(CommandOptionType::Channel)
+                                    .channel_types(&[ChannelType::Text])
+                            })
+                            .create_sub_option(|option| {
+                                option
+                                    .name("middleman_role")
+                                    .localized_description("opt.ticket.middleman_role")
+                                    .kind(CommandOptionType::Role)
+                            })
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("close")
+                            .localized_description("opt.ticket.close")
+                            .kind(CommandOptionType::SubCommand)
+                    })
+            })
+            .audited(),
+            BotCommand::new("stock", handle_stock_command, |command| {
+                command
+                    .localized_name("cmd.stock.name")
+                    .localized_description("cmd.stock")
+                    .dm_permission(false)
+                    .create_option(|option| {
+                        option
+                            .name("view")
+                            .localized_description("opt.stock.view")
+                            .kind(CommandOptionType::SubCommand)
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("set")
+                            .localized_description("opt.stock.set")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("amount")
+                                    .localized_description("opt.stock.amount")
+                                    .kind(CommandOptionType::String)
+                                    .required(true)
+                                    .max_length(32)
+                            })
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("add")
+                            .localized_description("opt.stock.add")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("amount")
+                                    .localized_description("opt.stock.amount")
+                                    .kind(CommandOptionType::String)
+                                    .required(true)
+                                    .max_length(32)
+                            })
+                    })
+            }),
+            BotCommand::new("seller", handle_seller_command, |command| {
+                command
+                    .localized_name("cmd.seller.name")
+                    .localized_description("cmd.seller")
+                    .dm_permission(false)
+                    .create_option(|option| {
+                        option
+                            .name("register")
+                            .localized_description("opt.seller.register")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("rate")
+                                    .localized_description("opt.seller.rate")
+                                    .kind(CommandOptionType::Number)
+                                    .required(true)
+                                    .min_number_value(0.01)
+                                    .max_number_value(validation::MAX_TIER_RATE)
+                            })
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("payment")
+                            .localized_description("opt.seller.payment")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("method")
+                                    .localized_description("opt.payment.method")
+                                    .kind(CommandOptionType::String)
+                                    .required(true)
+                                    .add_string_choice("PayPal", "paypal")
+                                    .add_string_choice("Cash App", "cashapp")
+                                    .add_string_choice("BTC", "btc")
+                                    .add_string_choice("ETH", "eth")
+                                    .add_string_choice("LTC", "ltc")
+                            })
+                            .create_sub_option(|option| {
+                                option
+                                    .name("handle")
+                                    .localized_description("opt.seller.handle")
+                                    .kind(CommandOptionType::String)
+                                    .max_length(100)
+                            })
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("leave")
+                            .localized_description("opt.seller.leave")
+                            .kind(CommandOptionType::SubCommand)
+                    })
+            })
+            .audited(),
+            BotCommand::new("sellers", handle_sellers_command, |command| {
+                command
+                    .localized_name("cmd.sellers.name")
+                    .localized_description("cmd.sellers")
+                    .dm_permission(false)
+            }),
+            BotCommand::new("queue", handle_queue_command, |command| {
+                command
+                    .localized_name("cmd.queue.name")
+                    .localized_description("cmd.queue")
+                    .dm_permission(false)
+                    .create_option(|option| {
+                        option
+                            .name("join")
+                            .localized_description("opt.queue.join")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("amount")
+                                    .localized_description("opt.queue.amount")
+                                    .kind(CommandOptionType::String)
+                                    .max_length(20)
+                            })
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("leave")
+                            .localized_description("opt.queue.leave")
+                            .kind(CommandOptionType::SubCommand)
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("list")
+                            .localized_description("opt.queue.list")
+                            .kind(CommandOptionType::SubCommand)
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("next")
+                            .localized_description("opt.queue.next")
+                            .kind(CommandOptionType::SubCommand)
+                    })
+            }),
+            BotCommand::new("order", handle_order_command, |command| {
+                command
+                    .localized_name("cmd.order.name")
+                    .localized_description("cmd.order")
+                    .dm_permission(false)
+                    .create_option(|option| {
+                        option
+                            .name("complete")
+                            .localized_description("opt.order.complete")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("roblox_username")
+                                    .localized_description("opt.order.roblox_username")
+                                    .kind(CommandOptionType::String)
+                            })
+                            .create_sub_option(|option| {
+                                option
+                                    .name("payment")
+                                    .localized_description("opt.order.payment")
+                                    .kind(CommandOptionType::String)
+                                    .add_string_choice("PayPal", "paypal")
+                                    .add_string_choice("Cash App", "cashapp")
+                                    .add_string_choice("BTC", "btc")
+                                    .add_string_choice("ETH", "eth")
+                                    .add_string_choice("LTC", "ltc")
+                            })
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("middleman")
+                            .localized_description("opt.order.middleman")
+                            .kind(CommandOptionType::SubCommand)
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("dispute")
+                            .localized_description("opt.order.dispute")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("reason")
+                                    .localized_description("opt.order.dispute_reason")
+                                    .kind(CommandOptionType::String)
+                                    .required(true)
+                                    .max_length(500)
+                            })
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("refund")
+                            .localized_description("opt.order.refund")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("reason")
+                                    .localized_description("opt.order.refund_reason")
+                                    .kind(CommandOptionType::String)
+                                    .required(true)
+                                    .max_length(500)
+                            })
+                            .create_sub_option(|option| {
+                                option
+                                    .name("amount")
+                                    .localized_description("opt.order.refund_amount")
+                                    .kind(CommandOptionType::Number)
+                                    .min_number_value(0.01)
+                            })
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("delete")
+                            .localized_description("opt.order.delete")
+                            .kind(CommandOptionType::SubCommand)
+                            .create_sub_option(|option| {
+                                option
+                                    .name("id")
+                                    .localized_description("opt.order.id")
+                                    .kind(CommandOptionType::Integer)
+                                    .required(true)
+                                    .min_int_value(1)
+                            })
+                    })
+            }),
+            BotCommand::new("middleman", handle_middleman_command, |command| {
+                command
+                    .localized_name("cmd.middleman.name")
+                    .localized_description("cmd.middleman")
+                    .create_option(|option| {
+                        option
+                            .name("amount")
+                            .localized_description("opt.middleman.amount")
+                            .kind(CommandOptionType::Number)
+                            .required(true)
+                            .min_number_value(0.01)
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("fee")
+                            .localized_description("opt.middleman.fee")
+                            .kind(CommandOptionType::Number)
+                            .required(true)
+                            .min_number_value(0.0)
+                            .max_number_value(100.0)
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("paid_by")
+                            .localized_description("opt.middleman.paid_by")
+                            .kind(CommandOptionType::String)
+                            .add_string_choice("Split 50/50", "split")
+                            .add_string_choice("Buyer", "buyer")
+                            .add_string_choice("Seller", "seller")
+                    })
+            }),
+            BotCommand::new(
+                "disputechannel",
+                handle_dispute_channel_command,
+                |command| {
+                    command
+                        .localized_name("cmd.disputechannel.name")
+                        .localized_description("cmd.disputechannel")
+                        .default_member_permissions(Permissions::MANAGE_GUILD)
+                        .dm_permission(false)
+                        .create_option(|option| {
+                            option
+                                .name("channel")
+                                .localized_description("opt.disputechannel.channel")
+                                .kind(CommandOptionType::Channel)
+                                .channel_types(&[ChannelType::Text, ChannelType::News])
+                        })
+                },
+            )
+            .audited(),
+            BotCommand::new("orderlimits", handle_orderlimits_command, |command| {
+                command
+                    .localized_name("cmd.orderlimits.name")
+                    .localized_description("cmd.orderlimits")
+                    .default_member_permissions(Permissions::MANAGE_GUILD)
+                    .dm_permission(false)
+                    .create_option(|option| {
+                        option
+                            .name("min")
+                            .localized_description("opt.orderlimits.min")
+                            .kind(CommandOptionType::Integer)
+                            .min_int_value(validation::MIN_ROBUX_AMOUNT)
+                            .max_int_value(validation::MAX_ROBUX_AMOUNT)
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("max")
+                            .localized_description("opt.orderlimits.max")
+                            .kind(CommandOptionType::Integer)
+                            .min_int_value(validation::MIN_ROBUX_AMOUNT)
+                            .max_int_value(validation::MAX_ROBUX_AMOUNT)
+                    })
+            })
+            .audited(),
+            BotCommand::new("orderform", handle_orderform_command, |command| {
+                command
+                    .localized_name("cmd.orderform.name")
+                    .localized_description("cmd.orderform")
+                    .default_member_permissions(Permissions::MANAGE_GUILD)
+                    .dm_permission(false)
+            })
+            .audited(),
+        ]
+    }
+}
+
+/// Commands that look things up on Roblox's web APIs; the `roblox` feature.
+#[cfg(feature = "roblox")]
+struct RobloxPlugin;
+
+#[cfg(feature = "roblox")]
+impl Plugin for RobloxPlugin {
+    fn name(&self) -> &'static str {
+        "roblox"
+    }
+
+    fn commands(&self) -> Vec<BotCommand> {
+        vec![
+            BotCommand::new("groupfunds", handle_groupfunds_command, |command| {
+                command
+                    .localized_name("cmd.groupfunds.name")
+                    .localized_description("cmd.groupfunds")
+                    .dm_permission(false)
+            }),
+            BotCommand::new("verify", handle_verify_command, |command| {
+                command
+                    .localized_name("cmd.verify.name")
+                    .localized_description("cmd.verify")
+                    .create_option(|option| {
+                        option
+                            .name("username")
+                            .localized_description("opt.verify.username")
+                            .kind(CommandOptionType::String)
+                            .required(true)
+                            .min_length(3)
+                            .max_length(20)
+                    })
+            }),
+            BotCommand::new("rap", handle_rap_command, |command| {
+                command
+                    .localized_name("cmd.rap.name")
+                    .localized_description("cmd.rap")
+                    .create_option(|option| {
+                        option
+                            .name("username")
+                            .localized_description("opt.rap.username")
+                            .kind(CommandOptionType::String)
+                            .required(true)
+                            .min_length(3)
+                            .max_length(20)
+                    })
+            })
+            .cooldown(10),
+            BotCommand::new("setupgamepass", handle_setupgamepass_command, |command| {
+                command
+                    .localized_name("cmd.setupgamepass.name")
+                    .localized_description("cmd.setupgamepass")
+                    .create_option(|option| {
+                        option
+                            .name("robux")
+                            .localized_description("opt.setupgamepass.robux")
+                            .kind(CommandOptionType::String)
+                            .required(true)
+                            .max_length(32)
+                    })
+            }),
+            BotCommand::new("verifygamepass", handle_verifygamepass_command, |command| {
+                command
+                    .localized_name("cmd.verifygamepass.name")
+                    .localized_description("cmd.verifygamepass")
+                    .dm_permission(false)
+                    .create_option(|option| {
+                        option
+                            .name("url")
+                            .localized_description("opt.verifygamepass.url")
+                            .kind(CommandOptionType::String)
+                            .required(true)
+                            .max_length(200)
+                    })
+                    .create_option(|option| {
+                        option
+                            .name("expected_price")
+                            .localized_description("opt.verifygamepass.expected_price")
+                            .kind(CommandOptionType::String)
+                            .max_length(32)
+                    })
+            }),
+        ]
+    }
+}
+
+/// Keys for the HTTP API; the `api` feature.
+#[cfg(feature = "api")]
+struct ApiPlugin;
+
+#[cfg(feature = "api")]
+impl Plugin for ApiPlugin {
+    fn name(&self) -> &'static str {
+        "api"
+    }
+
+    fn commands(&self) -> Vec<BotCommand> {
+        vec![BotCommand::new("apikey", handle_apikey_command, |command| {
+            command
+                .localized_name("cmd.apikey.name")
+                .localized_description("cmd.apikey")
+                .default_member_permissions(Permissions::MANAGE_GUILD)
+                .dm_permission(false)
+                .create_option(|option| {
+                    option
+                        .name("rotate")
+                        .localized_description("opt.apikey.rotate")
+                        .kind(CommandOptionType::SubCommand)
+                })
+                .create_option(|option| {
+                    option
+                        .name("revoke")
+                        .localized_description("opt.apikey.revoke")
+                        .kind(CommandOptionType::SubCommand)
+                })
+        })
+        .audited()]
+    }
 }
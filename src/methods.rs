@@ -0,0 +1,121 @@
+use crate::{
+    i18n::{t, Language},
+    ROBUX_MARKUP_RATE,
+};
+
+/// How the Robux are delivered to the buyer, which decides how much Roblox takes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeliveryMethod {
+    Gamepass,
+    DevProduct,
+    GroupPayout,
+    GiftCard,
+}
+
+impl DeliveryMethod {
+    pub const ALL: [DeliveryMethod; 4] = [
+        DeliveryMethod::Gamepass,
+        DeliveryMethod::DevProduct,
+        DeliveryMethod::GroupPayout,
+        DeliveryMethod::GiftCard,
+    ];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "gamepass" => Some(DeliveryMethod::Gamepass),
+            "devproduct" => Some(DeliveryMethod::DevProduct),
+            "group" => Some(DeliveryMethod::GroupPayout),
+            "giftcard" => Some(DeliveryMethod::GiftCard),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            DeliveryMethod::Gamepass => "gamepass",
+            DeliveryMethod::DevProduct => "devproduct",
+            DeliveryMethod::GroupPayout => "group",
+            DeliveryMethod::GiftCard => "giftcard",
+        }
+    }
+
+    pub fn name(self, lang: Language) -> &'static str {
+        let key = match self {
+            DeliveryMethod::Gamepass => "method.gamepass",
+            DeliveryMethod::DevProduct => "method.devproduct",
+            DeliveryMethod::GroupPayout => "method.group",
+            DeliveryMethod::GiftCard => "method.giftcard",
+        };
+        t(lang, key)
+    }
+
+    /// Label for the Robux amount the seller has to spend or list.
+    pub fn listing_label(self, lang: Language) -> &'static str {
+        let key = match self {
+            DeliveryMethod::Gamepass => "listing.gamepass",
+            DeliveryMethod::DevProduct => "listing.devproduct",
+            DeliveryMethod::GroupPayout => "listing.group",
+            DeliveryMethod::GiftCard => "listing.giftcard",
+        };
+        t(lang, key)
+    }
+
+    /// Share of the listed price Roblox keeps.
+    pub fn fee(self) -> f64 {
+        match self {
+            DeliveryMethod::Gamepass | DeliveryMethod::DevProduct => ROBUX_MARKUP_RATE,
+            DeliveryMethod::GroupPayout | DeliveryMethod::GiftCard => 0.0,
+        }
+    }
+
+    pub fn note(self, lang: Language) -> Option<&'static str> {
+        match self {
+            DeliveryMethod::GroupPayout => Some(t(lang, "note.group")),
+            DeliveryMethod::GiftCard => Some(t(lang, "note.giftcard")),
+            _ => None,
+        }
+    }
+
+    /// Robux that must be listed (or paid out) so the buyer ends up with `amount`
+    /// when pricing after tax, or `amount` itself before tax.
+    pub fn listing_price(self, amount: f64, after_tax: bool) -> i64 {
+        if after_tax {
+            (amount / (1.0 - self.fee())).round() as i64
+        } else {
+            amount as i64
+        }
+    }
+
+    /// The lowest whole price to list so Roblox pays out at least `robux` after its
+    /// cut, which it rounds down. Unlike [`Self::listing_price`] this never comes
+    /// up a Robux short, so it's what buyers are told to set.
+    pub fn exact_listing_price(self, robux: u64) -> u64 {
+        let kept_per_mille = ((1.0 - self.fee()) * 1000.0).round() as u64;
+        (robux * 1000).div_ceil(kept_per_mille)
+    }
+
+    /// GBP per Robux the buyer pays for `amount` when `base_rate` is the seller's rate.
+    pub fn rate(self, base_rate: f64, after_tax: bool) -> f64 {
+        if after_tax {
+            base_rate / (1.0 - self.fee())
+        } else {
+            base_rate
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_listing_price_covers_roblox_cut() {
+        for robux in [1, 7, 100, 1000, 12345, 1_000_000] {
+            let price = DeliveryMethod::Gamepass.exact_listing_price(robux);
+            assert!(price * 7 / 10 >= robux);
+            assert!((price - 1) * 7 / 10 < robux);
+        }
+        assert_eq!(DeliveryMethod::Gamepass.exact_listing_price(1000), 1429);
+        assert_eq!(DeliveryMethod::GroupPayout.exact_listing_price(1000), 1000);
+    }
+}
@@ -0,0 +1,80 @@
+/// Who covers a middleman's fee in `/middleman`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeePayer {
+    Buyer,
+    Seller,
+    /// Half each.
+    Split,
+}
+
+impl FeePayer {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "buyer" => Some(FeePayer::Buyer),
+            "seller" => Some(FeePayer::Seller),
+            "split" => Some(FeePayer::Split),
+            _ => None,
+        }
+    }
+}
+
+/// What each side of a middleman-assisted trade pays or gets.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeeSplit {
+    pub fee: f64,
+    pub buyer_fee: f64,
+    pub seller_fee: f64,
+    /// The trade amount plus the buyer's share of the fee.
+    pub buyer_pays: f64,
+    /// The trade amount less the seller's share of the fee.
+    pub seller_receives: f64,
+}
+
+/// Splits a `fee_percent` middleman fee on a trade of `amount`.
+pub fn split(amount: f64, fee_percent: f64, payer: FeePayer) -> FeeSplit {
+    let fee = amount * fee_percent / 100.0;
+    let (buyer_fee, seller_fee) = match payer {
+        FeePayer::Buyer => (fee, 0.0),
+        FeePayer::Seller => (0.0, fee),
+        FeePayer::Split => (fee / 2.0, fee / 2.0),
+    };
+    FeeSplit {
+        fee,
+        buyer_fee,
+        seller_fee,
+        buyer_pays: amount + buyer_fee,
+        seller_receives: amount - seller_fee,
+    }
+}
+
+/// How far a middleman has got on an order started with `/order middleman`.
+/// There's no row until they confirm receiving the buyer's payment.
+#[derive(Clone, Debug)]
+pub struct MiddlemanProgress {
+    pub guild_id: u64,
+    pub received_by: u64,
+    /// Unix timestamps in seconds.
+    pub received_at: u64,
+    /// Set once the middleman releases the payment to the seller.
+    pub released_by: Option<u64>,
+    pub released_at: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_the_fee_between_buyer_and_seller() {
+        let split_fee = split(100.0, 5.0, FeePayer::Split);
+        assert_eq!(split_fee.fee, 5.0);
+        assert_eq!(split_fee.buyer_pays, 102.5);
+        assert_eq!(split_fee.seller_receives, 97.5);
+
+        let buyer = split(100.0, 5.0, FeePayer::Buyer);
+        assert_eq!((buyer.buyer_pays, buyer.seller_receives), (105.0, 100.0));
+
+        let seller = split(100.0, 5.0, FeePayer::Seller);
+        assert_eq!((seller.buyer_pays, seller.seller_receives), (100.0, 95.0));
+    }
+}
@@ -0,0 +1,20 @@
+/// A Discord Nitro plan and its monthly price.
+pub struct NitroPlan {
+    pub name: &'static str,
+    pub gbp: f64,
+    pub usd: f64,
+}
+
+/// Monthly Nitro prices as listed on Discord's website.
+pub const NITRO_PLANS: [NitroPlan; 2] = [
+    NitroPlan {
+        name: "Nitro Basic",
+        gbp: 2.99,
+        usd: 2.99,
+    },
+    NitroPlan {
+        name: "Nitro",
+        gbp: 9.99,
+        usd: 9.99,
+    },
+];
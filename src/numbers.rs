@@ -0,0 +1,211 @@
+use std::future::Future;
+
+use crate::{emojis, i18n::Language};
+
+/// Which way money amounts are rounded for display.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    #[default]
+    Nearest,
+    Up,
+    Down,
+}
+
+impl RoundingMode {
+    pub fn code(self) -> &'static str {
+        match self {
+            RoundingMode::Nearest => "nearest",
+            RoundingMode::Up => "up",
+            RoundingMode::Down => "down",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "up" => RoundingMode::Up,
+            "down" => RoundingMode::Down,
+            _ => RoundingMode::Nearest,
+        }
+    }
+}
+
+/// How a guild wants money shown, set with `/rounding`. Only what's displayed is
+/// rounded; orders and stats keep the exact amounts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rounding {
+    pub mode: RoundingMode,
+    pub decimals: u8,
+}
+
+impl Default for Rounding {
+    fn default() -> Self {
+        Self {
+            mode: RoundingMode::Nearest,
+            decimals: 2,
+        }
+    }
+}
+
+impl Rounding {
+    pub const MAX_DECIMALS: u8 = 2;
+
+    pub fn apply(self, amount: f64) -> f64 {
+        let factor = 10f64.powi(i32::from(self.decimals));
+        // Allow for float error so 3.5 * 100 rounding up stays 350.
+        let scaled = amount * factor;
+        let rounded = match self.mode {
+            RoundingMode::Nearest => scaled.round(),
+            RoundingMode::Up => (scaled - 1e-6).ceil(),
+            RoundingMode::Down => (scaled + 1e-6).floor(),
+        };
+        rounded / factor
+    }
+}
+
+tokio::task_local! {
+    static ROUNDING: Rounding;
+}
+
+/// Runs `future` with [`money`] rounding as `rounding` says, for the guild an
+/// interaction came from.
+pub async fn with_rounding<F: Future>(rounding: Rounding, future: F) -> F::Output {
+    ROUNDING.scope(rounding, future).await
+}
+
+/// The rounding [`money`] applies: the current guild's, or the default outside
+/// [`with_rounding`].
+pub fn rounding() -> Rounding {
+    ROUNDING.try_with(|rounding| *rounding).unwrap_or_default()
+}
+
+/// Writes `value` rounded to `decimals` places with `lang`'s digit grouping and
+/// decimal mark: `1,234.50` in English, `1.234,50` in Portuguese, `1 234,50` in
+/// French. Spanish leaves four-digit numbers ungrouped (`1234,50`, `12.345,50`).
+pub fn decimal(lang: Language, value: f64, decimals: usize) -> String {
+    let (group, point, min_grouping) = match lang {
+        Language::En => (',', '.', 1),
+        Language::Es => ('.', ',', 2),
+        Language::Pt => ('.', ',', 1),
+        Language::Fr => ('\u{202f}', ',', 1),
+    };
+    let text = format!("{:.*}", decimals, value.abs());
+    let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+
+    let digits: Vec<char> = whole.chars().collect();
+    let mut grouped = String::new();
+    let grouping = digits.len() >= 3 + min_grouping;
+    for (index, digit) in digits.iter().enumerate() {
+        if grouping && index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(group);
+        }
+        grouped.push(*digit);
+    }
+
+    let sign = if value < 0.0 && text.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        "-"
+    } else {
+        ""
+    };
+    if fraction.is_empty() {
+        format!("{}{}", sign, grouped)
+    } else {
+        format!("{}{}{}{}", sign, grouped, point, fraction)
+    }
+}
+
+/// A whole number with `lang`'s digit grouping.
+pub fn integer(lang: Language, value: f64) -> String {
+    decimal(lang, value.round(), 0)
+}
+
+/// An amount of Robux, e.g. `15,000 R$`, with the guild's Robux emoji if it set
+/// one.
+pub fn robux(lang: Language, amount: f64) -> String {
+    format!("{} {}", integer(lang, amount), emojis::robux())
+}
+
+/// A money amount with its symbol where `lang` puts it: `£1,234.50` in English,
+/// `£ 1.234,50` in Portuguese and `1 234,50 £` in Spanish and French. Currencies
+/// without a symbol are written with their code instead.
+///
+/// Amounts are rounded the way the current guild set with `/rounding`, and
+/// pounds and dollars use its `/emojis`.
+pub fn money(lang: Language, currency: &str, amount: f64) -> String {
+    let rounding = rounding();
+    exact_money(lang, currency, rounding.apply(amount), rounding.decimals)
+}
+
+/// Like [`money`], but written to `decimals` places without the guild's
+/// `/rounding`, for amounts already worked out in that unit.
+pub fn exact_money(lang: Language, currency: &str, amount: f64, decimals: u8) -> String {
+    let number = decimal(lang, amount, usize::from(decimals));
+    let symbol = match currency {
+        "EUR" => "€".to_string(),
+        code => match emojis::currency(code) {
+            Some(symbol) => symbol,
+            None => return format!("{} {}", number, code),
+        },
+    };
+    match lang {
+        Language::En => format!("{}{}", symbol, number),
+        Language::Pt => format!("{} {}", symbol, number),
+        Language::Es | Language::Fr => format!("{}\u{a0}{}", number, symbol),
+    }
+}
+
+/// An amount in pounds.
+pub fn gbp(lang: Language, amount: f64) -> String {
+    money(lang, "GBP", amount)
+}
+
+/// An amount in US dollars.
+pub fn usd(lang: Language, amount: f64) -> String {
+    money(lang, "USD", amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decimal, gbp, robux, usd, with_rounding, Rounding, RoundingMode};
+    use crate::i18n::Language::{En, Es, Fr, Pt};
+
+    #[test]
+    fn groups_digits() {
+        assert_eq!(decimal(En, 1234567.891, 2), "1,234,567.89");
+        assert_eq!(decimal(En, 999.0, 0), "999");
+        assert_eq!(decimal(Pt, 100000.0, 0), "100.000");
+        assert_eq!(decimal(Fr, 1234.5, 2), "1\u{202f}234,50");
+        assert_eq!(decimal(Es, 1234.5, 2), "1234,50");
+        assert_eq!(decimal(Es, 12345.0, 0), "12.345");
+        assert_eq!(decimal(En, -1500.0, 0), "-1,500");
+        assert_eq!(decimal(En, -0.001, 2), "0.00");
+    }
+
+    #[test]
+    fn formats_amounts() {
+        assert_eq!(robux(En, 100000.0), "100,000 R$");
+        assert_eq!(gbp(En, 350.0), "£350.00");
+        assert_eq!(usd(Pt, 1234.5), "$ 1.234,50");
+        assert_eq!(gbp(Fr, 1234.5), "1\u{202f}234,50\u{a0}£");
+    }
+
+    #[tokio::test]
+    async fn rounds_money_for_display() {
+        let up = |decimals| Rounding {
+            mode: RoundingMode::Up,
+            decimals,
+        };
+        assert_eq!(up(2).apply(3.5), 3.5);
+        assert_eq!(up(2).apply(3.501), 3.51);
+        assert_eq!(up(0).apply(3.01), 4.0);
+        let down = Rounding {
+            mode: RoundingMode::Down,
+            decimals: 1,
+        };
+        assert_eq!(down.apply(3.99), 3.9);
+        assert_eq!(Rounding::default().apply(3.456), 3.46);
+
+        let shown = with_rounding(up(0), async { gbp(En, 3.01) }).await;
+        assert_eq!(shown, "£4");
+        assert_eq!(gbp(En, 3.01), "£3.01");
+    }
+}
@@ -0,0 +1,93 @@
+use serenity::builder::CreateEmbed;
+
+/// Embed color for operator alerts.
+const ALERT_COLOR: u32 = 0xE74C3C;
+/// Embed color for notices that something failing works again.
+const RECOVERED_COLOR: u32 = 0x2ECC71;
+/// Longest error text quoted in an alert; debug-formatted HTTP errors can run long.
+const MAX_ERROR_LENGTH: usize = 1000;
+
+/// Whether `error` is a failure of the bot itself, from Discord, the database or
+/// an upstream API, rather than a message telling the user what they got wrong.
+/// Messages for users come from the i18n catalog; internal errors are written as
+/// `Error ...: {:?}` or come from `storage::db_error`.
+pub fn is_unexpected(error: &str) -> bool {
+    error.starts_with("Error ") || error.starts_with("Database error")
+}
+
+/// An alert for the log channel: `error` under `title`, with one inline field
+/// per piece of `context` such as the command, user and guild.
+pub fn alert(title: &str, error: &str, context: &[(&str, String)]) -> CreateEmbed {
+    let mut error = error.to_string();
+    if error.chars().count() > MAX_ERROR_LENGTH {
+        error = error.chars().take(MAX_ERROR_LENGTH - 1).collect::<String>() + "…";
+    }
+
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(format!("⚠️ {}", title))
+        .description(format!("```\n{}\n```", error.replace("```", "'''")))
+        .color(ALERT_COLOR)
+        .timestamp(serenity::model::Timestamp::now());
+    for (name, value) in context {
+        embed.field(name, value, true);
+    }
+    embed
+}
+
+/// A notice for the log channel that whatever `title` names works again.
+pub fn recovered(title: &str, context: &[(&str, String)]) -> CreateEmbed {
+    let mut embed = CreateEmbed::default();
+    embed
+        .title(format!("✅ {}", title))
+        .color(RECOVERED_COLOR)
+        .timestamp(serenity::model::Timestamp::now());
+    for (name, value) in context {
+        embed.field(name, value, true);
+    }
+    embed
+}
+
+/// Whether a recurring task is failing, so the log channel hears when an outage
+/// starts and ends rather than about every failed attempt.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Outage {
+    failing_since: Option<u64>,
+}
+
+impl Outage {
+    /// Records a failure at `at`, returning whether it started an outage.
+    pub fn fail(&mut self, at: u64) -> bool {
+        let started = self.failing_since.is_none();
+        self.failing_since.get_or_insert(at);
+        started
+    }
+
+    /// Records a success, returning when the outage it ended started, if any.
+    pub fn recover(&mut self) -> Option<u64> {
+        self.failing_since.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_unexpected, Outage};
+
+    #[test]
+    fn tells_internal_errors_from_user_errors() {
+        assert!(is_unexpected("Error sending response: Http(...)"));
+        assert!(is_unexpected("Database error: PoolTimedOut"));
+        assert!(!is_unexpected("Please enter a valid amount of Robux."));
+    }
+
+    #[test]
+    fn outages_are_reported_once_and_recover_once() {
+        let mut outage = Outage::default();
+        assert_eq!(outage.recover(), None);
+        assert!(outage.fail(100));
+        assert!(!outage.fail(200));
+        assert_eq!(outage.recover(), Some(100));
+        assert_eq!(outage.recover(), None);
+        assert!(outage.fail(300));
+    }
+}
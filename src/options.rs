@@ -0,0 +1,83 @@
+use serde_json::Value;
+use serenity::model::{
+    application::interaction::application_command::{CommandDataOption, CommandDataOptionValue},
+    channel::PartialChannel,
+    guild::Role,
+    user::User,
+};
+
+/// Typed lookups of a command's or subcommand's options by name, so handlers
+/// don't depend on the order Discord sends optional arguments in.
+pub trait OptionsExt {
+    fn get(&self, name: &str) -> Option<&CommandDataOption>;
+
+    fn get_value(&self, name: &str) -> Option<&Value> {
+        self.get(name).and_then(|option| option.value.as_ref())
+    }
+
+    fn get_str(&self, name: &str) -> Option<&str> {
+        self.get_value(name).and_then(Value::as_str)
+    }
+
+    /// Integer options are all counts or Robux amounts, which can't be negative.
+    fn get_int(&self, name: &str) -> Option<u64> {
+        self.get_value(name).and_then(Value::as_u64)
+    }
+
+    fn get_number(&self, name: &str) -> Option<f64> {
+        self.get_value(name).and_then(Value::as_f64)
+    }
+
+    fn get_bool(&self, name: &str) -> Option<bool> {
+        self.get_value(name).and_then(Value::as_bool)
+    }
+
+    fn get_user(&self, name: &str) -> Option<&User> {
+        match &self.get(name)?.resolved {
+            Some(CommandDataOptionValue::User(user, _)) => Some(user),
+            _ => None,
+        }
+    }
+
+    fn get_role(&self, name: &str) -> Option<&Role> {
+        match &self.get(name)?.resolved {
+            Some(CommandDataOptionValue::Role(role)) => Some(role),
+            _ => None,
+        }
+    }
+
+    fn get_channel(&self, name: &str) -> Option<&PartialChannel> {
+        match &self.get(name)?.resolved {
+            Some(CommandDataOptionValue::Channel(channel)) => Some(channel),
+            _ => None,
+        }
+    }
+}
+
+impl OptionsExt for [CommandDataOption] {
+    fn get(&self, name: &str) -> Option<&CommandDataOption> {
+        self.iter().find(|option| option.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_options_whatever_order_they_come_in() {
+        let options: Vec<CommandDataOption> = serde_json::from_value(serde_json::json!([
+            {"name": "premium", "type": 5, "value": true},
+            {"name": "amount", "type": 10, "value": 12.5},
+            {"name": "currency", "type": 3, "value": "USD"},
+            {"name": "robux", "type": 4, "value": 1000},
+        ]))
+        .unwrap();
+        assert_eq!(options.get_str("currency"), Some("USD"));
+        assert_eq!(options.get_number("amount"), Some(12.5));
+        assert_eq!(options.get_int("robux"), Some(1000));
+        assert_eq!(options.get_bool("premium"), Some(true));
+        assert_eq!(options.get_str("amount"), None);
+        assert_eq!(options.get_str("format"), None);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::methods::DeliveryMethod;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderStatus {
+    Open,
+    Closed,
+}
+
+impl OrderStatus {
+    pub fn code(self) -> &'static str {
+        match self {
+            OrderStatus::Open => "open",
+            OrderStatus::Closed => "closed",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "closed" => OrderStatus::Closed,
+            _ => OrderStatus::Open,
+        }
+    }
+}
+
+/// The smallest and largest orders a guild takes, set with `/orderlimits`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OrderLimits {
+    pub min_robux: Option<u64>,
+    pub max_robux: Option<u64>,
+}
+
+impl OrderLimits {
+    #[cfg(feature = "orders")]
+    pub fn is_set(&self) -> bool {
+        self.min_robux.is_some() || self.max_robux.is_some()
+    }
+}
+
+/// The rates an order was priced at, kept with it so later changes to the
+/// guild's tiers, the exchange rate or Roblox's cut don't change its totals, and
+/// disputes can point at the original numbers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrozenRates {
+    /// GBP per Robux before the delivery method's fee, after any loyalty discount.
+    pub base_rate: f64,
+    /// USD per GBP.
+    pub gbp_to_usd: f64,
+    /// The share of the listing Roblox kept for the delivery method.
+    pub roblox_fee: f64,
+    /// VAT percentage, zero if the guild didn't charge it.
+    pub vat_percent: f64,
+}
+
+impl FrozenRates {
+    /// The rates stored with an order, or `None` for orders opened before they
+    /// were kept.
+    pub fn from_columns(
+        base_rate: Option<f64>,
+        gbp_to_usd: Option<f64>,
+        roblox_fee: Option<f64>,
+        vat_percent: Option<f64>,
+    ) -> Option<Self> {
+        Some(Self {
+            base_rate: base_rate?,
+            gbp_to_usd: gbp_to_usd?,
+            roblox_fee: roblox_fee?,
+            vat_percent: vat_percent.unwrap_or(0.0),
+        })
+    }
+}
+
+/// A purchase opened with `/buy`, priced when the ticket was created.
+#[derive(Clone, Debug)]
+pub struct Order {
+    pub id: u64,
+    #[cfg_attr(not(feature = "orders"), allow(dead_code))]
+    pub guild_id: u64,
+    pub buyer_id: u64,
+    /// The private ticket channel the order is handled in.
+    #[cfg_attr(not(feature = "orders"), allow(dead_code))]
+    pub channel_id: u64,
+    pub robux: u64,
+    pub after_tax: bool,
+    pub method: DeliveryMethod,
+    pub gbp: f64,
+    pub usd: f64,
+    /// VAT included in `gbp`, zero if the guild doesn't charge it.
+    pub tax_gbp: f64,
+    /// GBP per Robux locked in for the order.
+    pub rate: f64,
+    /// What `rate`, `usd` and `tax_gbp` were worked out from.
+    pub frozen_rates: Option<FrozenRates>,
+    pub status: OrderStatus,
+    /// Unix timestamps in seconds.
+    pub created_at: u64,
+    /// Set by `/order complete` once the Robux have been delivered.
+    pub completed_at: Option<u64>,
+    /// The buyer's Roblox account, when they gave it in the order form.
+    #[cfg_attr(not(any(feature = "orders", feature = "roblox")), allow(dead_code))]
+    pub roblox_username: Option<String>,
+}
+
+/// A `/verifygamepass` check of the gamepass a buyer made for their order,
+/// recorded whether or not it passed.
+#[cfg(feature = "roblox")]
+#[derive(Clone, Debug)]
+pub struct GamepassCheck {
+    pub order_id: u64,
+    pub gamepass_id: u64,
+    /// The price staff expected and the one Roblox reported, if it's for sale.
+    pub expected_price: u64,
+    pub price: Option<u64>,
+    /// Whether the pass belongs to the buyer's Roblox account.
+    pub owned_by_buyer: bool,
+    pub checked_by: u64,
+    /// Unix timestamp in seconds.
+    pub checked_at: u64,
+}
+
+#[cfg(feature = "roblox")]
+impl GamepassCheck {
+    pub fn passed(&self) -> bool {
+        self.owned_by_buyer && self.price == Some(self.expected_price)
+    }
+}
@@ -0,0 +1,129 @@
+/// An official Robux package sold by Roblox, with its regional prices.
+pub struct Pack {
+    pub robux: u64,
+    pub gbp: f64,
+    pub usd: f64,
+}
+
+/// Official Robux packages as listed on the Roblox website.
+pub const OFFICIAL_PACKS: [Pack; 5] = [
+    Pack {
+        robux: 400,
+        gbp: 4.99,
+        usd: 4.99,
+    },
+    Pack {
+        robux: 800,
+        gbp: 9.99,
+        usd: 9.99,
+    },
+    Pack {
+        robux: 1700,
+        gbp: 19.99,
+        usd: 19.99,
+    },
+    Pack {
+        robux: 4500,
+        gbp: 49.99,
+        usd: 49.99,
+    },
+    Pack {
+        robux: 10000,
+        gbp: 99.99,
+        usd: 99.99,
+    },
+];
+
+/// Percentage saved by paying `seller_price` instead of `official_price`.
+/// Negative when the seller is more expensive.
+pub fn savings_percent(official_price: f64, seller_price: f64) -> f64 {
+    (official_price - seller_price) / official_price * 100.0
+}
+
+/// Official packs `budget` buys when taking the biggest pack that fits each
+/// time, as `(pack, count)` pairs, and the Robux they add up to. `price` picks
+/// the pack's price in the budget's currency.
+pub fn best_packs(budget: f64, price: impl Fn(&Pack) -> f64) -> (Vec<(&'static Pack, u64)>, u64) {
+    let mut left = budget;
+    let mut bought = Vec::new();
+    for pack in OFFICIAL_PACKS.iter().rev() {
+        let count = (left / price(pack) + 1e-9).floor() as u64;
+        if count > 0 {
+            left -= count as f64 * price(pack);
+            bought.push((pack, count));
+        }
+    }
+    let robux = bought.iter().map(|(pack, count)| pack.robux * count).sum();
+    (bought, robux)
+}
+
+/// The cheapest official packs that add up to at least `robux`, as `(pack,
+/// count)` pairs, and what they cost. `price` picks the pack's price in the
+/// currency wanted.
+pub fn cheapest_packs(
+    robux: u64,
+    price: impl Fn(&Pack) -> f64,
+) -> (Vec<(&'static Pack, u64)>, f64) {
+    let mut best: Option<(Vec<(&'static Pack, u64)>, f64)> = None;
+    let mut consider = |packs: Vec<(&'static Pack, u64)>, cost: f64| {
+        if best.as_ref().is_none_or(|(_, best)| cost < *best - 1e-9) {
+            best = Some((packs, cost));
+        }
+    };
+    // Take the biggest packs that fit, and at each size see whether one more of
+    // it would cover the rest for less than smaller packs would.
+    let mut bought = Vec::new();
+    let mut cost = 0.0;
+    let mut left = robux;
+    for (index, pack) in OFFICIAL_PACKS.iter().enumerate().rev() {
+        if left == 0 {
+            break;
+        }
+        if pack.robux >= left {
+            let mut packs = bought.clone();
+            packs.push((pack, 1));
+            consider(packs, cost + price(pack));
+        }
+        // Whatever the smaller packs didn't cover, the smallest one rounds up.
+        let count = if index == 0 {
+            left.div_ceil(pack.robux)
+        } else {
+            left / pack.robux
+        };
+        if count > 0 {
+            bought.push((pack, count));
+            cost += count as f64 * price(pack);
+            left = left.saturating_sub(count * pack.robux);
+        }
+    }
+    consider(bought, cost);
+    best.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn robux(packs: &[(&Pack, u64)]) -> Vec<(u64, u64)> {
+        packs
+            .iter()
+            .map(|(pack, count)| (pack.robux, *count))
+            .collect()
+    }
+
+    #[test]
+    fn finds_the_cheapest_packs_covering_an_amount() {
+        let (packs, cost) = cheapest_packs(1700, |pack| pack.gbp);
+        assert_eq!(robux(&packs), [(1700, 1)]);
+        assert!((cost - 19.99).abs() < 1e-9);
+
+        // Two 1,700 packs and two 400 ones beat a 4,500 pack by a penny.
+        let (packs, cost) = cheapest_packs(4000, |pack| pack.gbp);
+        assert_eq!(robux(&packs), [(1700, 2), (400, 2)]);
+        assert!((cost - 49.96).abs() < 1e-9);
+
+        let (packs, _) = cheapest_packs(100, |pack| pack.usd);
+        assert_eq!(robux(&packs), [(400, 1)]);
+        assert_eq!(cheapest_packs(0, |pack| pack.gbp).1, 0.0);
+    }
+}
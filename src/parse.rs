@@ -0,0 +1,319 @@
+use crate::i18n::{tf, Language};
+
+/// Words that mark a nearby number as a Robux amount.
+const ROBUX_WORDS: [&str; 3] = ["robux", "r$", "rbx"];
+
+/// Parses a Robux amount written the way users type it: `15000`, `15,000`,
+/// `15_000`, `15k`, `2.5k` or `1m`. An `R$` or `robux` suffix is ignored.
+pub fn parse_robux_amount(input: &str, lang: Language) -> Result<u64, String> {
+    let invalid = || tf(lang, "parse.invalid", &[("input", &input)]);
+
+    let mut text: String = input
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| !matches!(c, ',' | '_' | ' '))
+        .collect();
+    for suffix in ["robux", "r$"] {
+        if let Some(stripped) = text.strip_suffix(suffix) {
+            text = stripped.to_string();
+        }
+    }
+
+    let (number, multiplier) = match text.chars().last() {
+        Some('k') => (&text[..text.len() - 1], 1_000),
+        Some('m') => (&text[..text.len() - 1], 1_000_000),
+        _ => (text.as_str(), 1),
+    };
+
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+    if !whole
+        .chars()
+        .chain(fraction.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let whole: u64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| invalid())?
+    };
+    let mut amount = whole.checked_mul(multiplier).ok_or_else(invalid)?;
+
+    if !fraction.is_empty() {
+        let scale = 10u64
+            .checked_pow(fraction.len() as u32)
+            .ok_or_else(invalid)?;
+        let fraction: u64 = fraction.parse().map_err(|_| invalid())?;
+        let scaled = fraction.checked_mul(multiplier).ok_or_else(invalid)?;
+        if scaled % scale != 0 {
+            return Err(tf(lang, "parse.fractional", &[("input", &input)]));
+        }
+        amount = amount.checked_add(scaled / scale).ok_or_else(invalid)?;
+    }
+
+    Ok(amount)
+}
+
+/// Parses a list of Robux amounts separated by commas or semicolons, such as
+/// `1000, 5k, 10000`. Commas only separate amounts here, so `1,000` is two
+/// amounts; write `1k` or `1_000` instead. Blank entries are skipped.
+pub fn parse_robux_list(input: &str, lang: Language) -> Result<Vec<u64>, String> {
+    let amounts = input
+        .split([',', ';'])
+        .filter(|amount| !amount.trim().is_empty())
+        .map(|amount| parse_robux_amount(amount, lang))
+        .collect::<Result<Vec<_>, _>>()?;
+    if amounts.is_empty() {
+        return Err(tf(lang, "parse.invalid", &[("input", &input)]));
+    }
+    Ok(amounts)
+}
+
+/// Finds the Robux amounts mentioned in a message, such as "can I get 15k robux"
+/// or "R$ 2,500 please", in the order they appear. Numbers with a `k` or `m`
+/// suffix or next to "robux" or "R$" are preferred; bare numbers are only
+/// returned when there are none.
+pub fn find_robux_amounts(text: &str) -> Vec<u64> {
+    let (marked, bare) = scan_robux_amounts(text);
+    if marked.is_empty() {
+        bare
+    } else {
+        marked
+    }
+}
+
+/// Like [`find_robux_amounts`], but never falls back to bare numbers, so
+/// ordinary chat doesn't look like a price request.
+pub fn mentioned_robux_amounts(text: &str) -> Vec<u64> {
+    scan_robux_amounts(text).0
+}
+
+/// Splits the numbers in `text` into those marked as Robux and bare ones.
+fn scan_robux_amounts(text: &str) -> (Vec<u64>, Vec<u64>) {
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric() && c != '$')
+                .to_lowercase()
+        })
+        .collect();
+
+    let (mut marked, mut bare) = (Vec::new(), Vec::new());
+    for (index, word) in words.iter().enumerate() {
+        let (number, prefixed) = match word.strip_prefix("r$") {
+            Some(rest) if !rest.is_empty() => (rest, true),
+            _ => (word.as_str(), false),
+        };
+        let (number, suffixed) = match number.strip_suffix("rbx") {
+            Some(rest) => (rest, true),
+            None => (number, false),
+        };
+        let Ok(amount) = parse_robux_amount(number, Language::En) else {
+            continue;
+        };
+        if amount == 0 {
+            continue;
+        }
+
+        let is_marked = prefixed
+            || suffixed
+            || number.ends_with(['k', 'm'])
+            || number.ends_with("robux")
+            || number.ends_with("r$")
+            || words
+                .get(index + 1)
+                .is_some_and(|next| ROBUX_WORDS.contains(&next.as_str()))
+            || (index > 0 && words[index - 1] == "r$");
+        let amounts = if is_marked { &mut marked } else { &mut bare };
+        if !amounts.contains(&amount) {
+            amounts.push(amount);
+        }
+    }
+
+    (marked, bare)
+}
+
+/// Parses a duration such as `30m`, `2h`, `1d12h` or `1w` into seconds. Units
+/// are `s`, `m`, `h`, `d` and `w`; a bare number is minutes.
+pub fn parse_duration(input: &str, lang: Language) -> Result<u64, String> {
+    let invalid = || tf(lang, "parse.invalid_duration", &[("input", &input)]);
+
+    let text: String = input
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if let Ok(minutes) = text.parse::<u64>() {
+        return minutes.checked_mul(60).ok_or_else(invalid);
+    }
+
+    let mut seconds: u64 = 0;
+    let mut number = String::new();
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 24 * 60 * 60,
+            'w' => 7 * 24 * 60 * 60,
+            _ => return Err(invalid()),
+        };
+        let value: u64 = number.parse().map_err(|_| invalid())?;
+        seconds = value
+            .checked_mul(unit)
+            .and_then(|part| seconds.checked_add(part))
+            .ok_or_else(invalid)?;
+        number.clear();
+    }
+    if !number.is_empty() || seconds == 0 {
+        return Err(invalid());
+    }
+    Ok(seconds)
+}
+
+/// The id in a gamepass link such as `https://www.roblox.com/game-pass/123/Name`,
+/// or a bare id.
+#[cfg(feature = "roblox")]
+pub fn parse_gamepass_id(input: &str, lang: Language) -> Result<u64, String> {
+    let input = input.trim();
+    let id = match input.find("game-pass/") {
+        Some(start) => input[start + "game-pass/".len()..]
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or_default(),
+        None => input,
+    };
+    id.parse()
+        .map_err(|_| tf(lang, "parse.invalid_gamepass", &[("input", &input)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        find_robux_amounts, mentioned_robux_amounts, parse_duration, parse_robux_amount,
+        parse_robux_list,
+    };
+    use crate::i18n::Language::En;
+
+    #[test]
+    fn parses_plain_numbers() {
+        assert_eq!(parse_robux_amount("15000", En), Ok(15000));
+        assert_eq!(parse_robux_amount("  42 ", En), Ok(42));
+        assert_eq!(parse_robux_amount("0", En), Ok(0));
+    }
+
+    #[test]
+    fn parses_separators() {
+        assert_eq!(parse_robux_amount("15,000", En), Ok(15000));
+        assert_eq!(parse_robux_amount("1_000_000", En), Ok(1_000_000));
+        assert_eq!(parse_robux_amount("10 000", En), Ok(10000));
+    }
+
+    #[test]
+    fn parses_suffixes() {
+        assert_eq!(parse_robux_amount("1k", En), Ok(1000));
+        assert_eq!(parse_robux_amount("2.5k", En), Ok(2500));
+        assert_eq!(parse_robux_amount("2.5K", En), Ok(2500));
+        assert_eq!(parse_robux_amount("1m", En), Ok(1_000_000));
+        assert_eq!(parse_robux_amount("1.25m", En), Ok(1_250_000));
+        assert_eq!(parse_robux_amount(".5k", En), Ok(500));
+        assert_eq!(parse_robux_amount("15k robux", En), Ok(15000));
+        assert_eq!(parse_robux_amount("800 R$", En), Ok(800));
+    }
+
+    #[test]
+    fn rejects_fractional_robux() {
+        assert!(parse_robux_amount("1.5", En).is_err());
+        assert!(parse_robux_amount("1.0005k", En).is_err());
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!(parse_robux_amount("", En).is_err());
+        assert!(parse_robux_amount("k", En).is_err());
+        assert!(parse_robux_amount(".", En).is_err());
+        assert!(parse_robux_amount("-5", En).is_err());
+        assert!(parse_robux_amount("1kk", En).is_err());
+        assert!(parse_robux_amount("1.2.3", En).is_err());
+        assert!(parse_robux_amount("abc", En).is_err());
+        assert!(parse_robux_amount("5b", En).is_err());
+    }
+
+    #[test]
+    fn finds_amounts_in_messages() {
+        assert_eq!(find_robux_amounts("can I get 15k robux?"), vec![15000]);
+        assert_eq!(
+            find_robux_amounts("R$ 2,500 or R$5000, maybe 10k."),
+            vec![2500, 5000, 10000]
+        );
+        assert_eq!(find_robux_amounts("need 800rbx, ticket 42"), vec![800]);
+        assert_eq!(find_robux_amounts("how much for 4000"), vec![4000]);
+        assert_eq!(find_robux_amounts("$5 for 1k robux, 1k robux"), vec![1000]);
+        assert!(find_robux_amounts("hello, ok?").is_empty());
+        assert_eq!(
+            mentioned_robux_amounts("how much for 8000 robux?"),
+            vec![8000]
+        );
+        assert!(mentioned_robux_amounts("see you at 8, order 4000").is_empty());
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        assert!(parse_robux_amount("99999999999999999999", En).is_err());
+        assert!(parse_robux_amount("99999999999999m", En).is_err());
+    }
+
+    #[test]
+    fn parses_lists() {
+        assert_eq!(
+            parse_robux_list("1000,5000,10000", En),
+            Ok(vec![1000, 5000, 10000])
+        );
+        assert_eq!(
+            parse_robux_list(" 1k, 2.5k ;1_000, ", En),
+            Ok(vec![1000, 2500, 1000])
+        );
+        assert!(parse_robux_list("1000, lots", En).is_err());
+        assert!(parse_robux_list(" , ", En).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "roblox")]
+    fn parses_gamepass_links() {
+        use super::parse_gamepass_id;
+
+        assert_eq!(
+            parse_gamepass_id("https://www.roblox.com/game-pass/123456/Robux-Pass", En),
+            Ok(123456)
+        );
+        assert_eq!(
+            parse_gamepass_id("roblox.com/game-pass/42?ref=share", En),
+            Ok(42)
+        );
+        assert_eq!(parse_gamepass_id(" 987 ", En), Ok(987));
+        assert!(parse_gamepass_id("https://www.roblox.com/games/1/Place", En).is_err());
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("30m", En), Ok(30 * 60));
+        assert_eq!(parse_duration("1d 12h", En), Ok(36 * 60 * 60));
+        assert_eq!(parse_duration("2W", En), Ok(14 * 24 * 60 * 60));
+        assert_eq!(parse_duration("45", En), Ok(45 * 60));
+        assert!(parse_duration("0m", En).is_err());
+        assert!(parse_duration("h", En).is_err());
+        assert!(parse_duration("10x", En).is_err());
+        assert!(parse_duration("1h30", En).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::crypto::Crypto;
+
+/// Ways a guild can take payment, each configured with a handle by `/payment set`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaymentMethod {
+    PayPal,
+    CashApp,
+    Crypto(Crypto),
+}
+
+impl PaymentMethod {
+    pub const ALL: [PaymentMethod; 5] = [
+        PaymentMethod::PayPal,
+        PaymentMethod::CashApp,
+        PaymentMethod::Crypto(Crypto::Btc),
+        PaymentMethod::Crypto(Crypto::Eth),
+        PaymentMethod::Crypto(Crypto::Ltc),
+    ];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            PaymentMethod::PayPal => "paypal",
+            PaymentMethod::CashApp => "cashapp",
+            PaymentMethod::Crypto(Crypto::Btc) => "btc",
+            PaymentMethod::Crypto(Crypto::Eth) => "eth",
+            PaymentMethod::Crypto(Crypto::Ltc) => "ltc",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|method| method.code() == code)
+    }
+
+    /// Parses a method as a buyer would type it: a code or name in any case, such
+    /// as `paypal`, `Cash App` or `BTC`.
+    #[cfg(feature = "orders")]
+    pub fn parse(input: &str) -> Option<Self> {
+        Self::from_code(&input.to_lowercase().replace(' ', ""))
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PaymentMethod::PayPal => "PayPal",
+            PaymentMethod::CashApp => "Cash App",
+            PaymentMethod::Crypto(coin) => coin.symbol(),
+        }
+    }
+
+    /// Cleans up a handle as typed by staff, e.g. strips a leading `$` from a cashtag
+    /// or the `paypal.me/` prefix. Returns `None` if it isn't a plausible handle.
+    pub fn normalize(self, handle: &str) -> Option<String> {
+        let handle = handle.trim();
+        let handle = match self {
+            PaymentMethod::PayPal => handle
+                .trim_start_matches("https://")
+                .trim_start_matches("www.")
+                .trim_start_matches("paypal.me/")
+                .trim_end_matches('/'),
+            PaymentMethod::CashApp => handle.trim_start_matches('$'),
+            PaymentMethod::Crypto(_) => handle,
+        };
+        let (min, max) = match self {
+            PaymentMethod::PayPal | PaymentMethod::CashApp => (1, 30),
+            PaymentMethod::Crypto(_) => (26, 90),
+        };
+        let valid = (min..=max).contains(&handle.len())
+            && handle.chars().all(|c| c.is_ascii_alphanumeric());
+        valid.then(|| handle.to_string())
+    }
+}
+
+/// A guild's account for one payment method.
+#[derive(Clone, Debug)]
+pub struct PaymentHandle {
+    pub method: PaymentMethod,
+    pub handle: String,
+}
+
+impl PaymentHandle {
+    /// A link or URI that opens the payment prefilled with the amount. Crypto URIs
+    /// need `crypto_amount`, the total in that coin; without it only the address is used.
+    pub fn link(&self, gbp: f64, usd: f64, crypto_amount: Option<f64>) -> String {
+        match (self.method, crypto_amount) {
+            (PaymentMethod::PayPal, _) => {
+                format!("https://paypal.me/{}/{:.2}GBP", self.handle, gbp)
+            }
+            (PaymentMethod::CashApp, _) => format!("https://cash.app/${}/{:.2}", self.handle, usd),
+            (PaymentMethod::Crypto(coin), Some(amount)) => match coin {
+                // EIP-681 amounts are in wei.
+                Crypto::Eth => format!(
+                    "ethereum:{}?value={}",
+                    self.handle,
+                    (amount * 1e18).round() as u128
+                ),
+                Crypto::Btc => format!("bitcoin:{}?amount={:.8}", self.handle, amount),
+                Crypto::Ltc => format!("litecoin:{}?amount={:.8}", self.handle, amount),
+            },
+            (PaymentMethod::Crypto(_), None) => self.handle.clone(),
+        }
+    }
+}
+
+/// Sets the handle for `payment.method`, replacing any existing one.
+pub fn set(handles: &mut Vec<PaymentHandle>, payment: PaymentHandle) {
+    handles.retain(|existing| existing.method != payment.method);
+    handles.push(payment);
+}
+
+/// Removes the handle for `method`, returning whether one existed.
+pub fn remove(handles: &mut Vec<PaymentHandle>, method: PaymentMethod) -> bool {
+    let before = handles.len();
+    handles.retain(|handle| handle.method != method);
+    handles.len() != before
+}
+
+/// The guild's handles in [`PaymentMethod::ALL`] order.
+pub fn sorted(handles: &[PaymentHandle]) -> Vec<&PaymentHandle> {
+    PaymentMethod::ALL
+        .iter()
+        .filter_map(|method| handles.iter().find(|handle| handle.method == *method))
+        .collect()
+}
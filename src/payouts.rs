@@ -0,0 +1,104 @@
+use crate::i18n::{t, Language};
+
+/// Roblox only lets a group pay out to members who joined at least this long ago.
+pub const GROUP_PAYOUT_HOLD_DAYS: u64 = 14;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayoutStatus {
+    /// Waiting for staff to approve or reject it.
+    Pending,
+    /// Approved and being sent to Roblox.
+    Sending,
+    Paid,
+    Rejected,
+    /// Approved, but Roblox refused the payout.
+    Failed,
+}
+
+impl PayoutStatus {
+    pub fn code(self) -> &'static str {
+        match self {
+            PayoutStatus::Pending => "pending",
+            PayoutStatus::Sending => "sending",
+            PayoutStatus::Paid => "paid",
+            PayoutStatus::Rejected => "rejected",
+            PayoutStatus::Failed => "failed",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "sending" => PayoutStatus::Sending,
+            "paid" => PayoutStatus::Paid,
+            "rejected" => PayoutStatus::Rejected,
+            "failed" => PayoutStatus::Failed,
+            _ => PayoutStatus::Pending,
+        }
+    }
+
+    pub fn name(self, lang: Language) -> &'static str {
+        let key = match self {
+            PayoutStatus::Pending => "payout.status.pending",
+            PayoutStatus::Sending => "payout.status.sending",
+            PayoutStatus::Paid => "payout.status.paid",
+            PayoutStatus::Rejected => "payout.status.rejected",
+            PayoutStatus::Failed => "payout.status.failed",
+        };
+        t(lang, key)
+    }
+}
+
+/// A group payout queued by `/order complete` for a group payout order, sent to
+/// the buyer's Roblox account once staff approve it.
+#[derive(Clone, Debug)]
+pub struct Payout {
+    pub id: u64,
+    pub guild_id: u64,
+    pub order_id: u64,
+    pub roblox_user_id: u64,
+    pub roblox_username: String,
+    pub robux: u64,
+    pub status: PayoutStatus,
+    /// The staff member who completed the order.
+    pub requested_by: u64,
+    /// Unix timestamps in seconds.
+    pub requested_at: u64,
+    /// The staff member who approved or rejected it, and when.
+    pub reviewed_by: Option<u64>,
+    pub reviewed_at: Option<u64>,
+    /// Roblox's id for the payout transaction, once it's been found.
+    pub transaction_id: Option<String>,
+    /// Why Roblox refused the payout.
+    pub error: Option<String>,
+}
+
+/// The wait before a group payout order's buyer can be paid, started when the
+/// ticket opens. Staff and the buyer are pinged in the ticket once it's over.
+#[derive(Clone, Debug)]
+pub struct PayoutHold {
+    pub order_id: u64,
+    pub guild_id: u64,
+    pub channel_id: u64,
+    pub buyer_id: u64,
+    /// Unix timestamp in seconds.
+    pub eligible_at: u64,
+}
+
+impl PayoutHold {
+    /// A hold for an order opened at `created_at`.
+    pub fn starting(
+        order_id: u64,
+        guild_id: u64,
+        channel_id: u64,
+        buyer_id: u64,
+        created_at: u64,
+    ) -> Self {
+        Self {
+            order_id,
+            guild_id,
+            channel_id,
+            buyer_id,
+            eligible_at: created_at + GROUP_PAYOUT_HOLD_DAYS * 24 * 60 * 60,
+        }
+    }
+}
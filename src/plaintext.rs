@@ -0,0 +1,59 @@
+use serenity::builder::CreateEmbed;
+
+/// Most characters Discord allows in a message.
+const MAX_MESSAGE_LENGTH: usize = 2000;
+
+/// Renders a calculator embed as a code block for `format: text`: `summary` on
+/// the first line, then the title, description and one `name: value` line per
+/// field, with Discord markdown and nested code fences removed so it pastes
+/// cleanly anywhere.
+pub fn render(summary: &str, embed: &CreateEmbed) -> String {
+    let text = |key: &str| embed.0.get(key).and_then(|value| value.as_str());
+    let mut lines = vec![summary.to_string(), String::new()];
+    if let Some(title) = text("title") {
+        lines.push(strip_markdown(title));
+    }
+    if let Some(description) = text("description") {
+        lines.extend(
+            description
+                .lines()
+                .filter(|line| !line.starts_with("```"))
+                .map(strip_markdown),
+        );
+    }
+    for field in embed
+        .0
+        .get("fields")
+        .and_then(|fields| fields.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let name = strip_markdown(field["name"].as_str().unwrap_or_default());
+        let value = field["value"].as_str().unwrap_or_default();
+        match value.lines().collect::<Vec<_>>()[..] {
+            [line] => lines.push(format!("{}: {}", name, strip_markdown(line))),
+            ref value => {
+                lines.push(format!("{}:", name));
+                lines.extend(
+                    value
+                        .iter()
+                        .map(|line| format!("  {}", strip_markdown(line))),
+                );
+            }
+        }
+    }
+
+    let mut body = lines.join("\n");
+    let limit = MAX_MESSAGE_LENGTH - "```\n\n```".len();
+    if body.chars().count() > limit {
+        body = body.chars().take(limit - 1).collect::<String>() + "…";
+    }
+    format!("```\n{}\n```", body)
+}
+
+/// Removes bold, italic and inline code markers.
+fn strip_markdown(line: &str) -> String {
+    line.replace("**", "")
+        .trim_matches(|c| c == '*' || c == '_')
+        .replace('`', "")
+}
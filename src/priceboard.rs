@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Language;
+
+pub const DEFAULT_REFRESH_MINUTES: u64 = 10;
+
+/// Robux amounts quoted on every price board.
+pub const BOARD_AMOUNTS: [u64; 4] = [1_000, 5_000, 10_000, 50_000];
+
+/// A pinned, auto-updating rate embed posted by `/priceboard enable`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PriceBoard {
+    pub channel_id: u64,
+    pub message_id: u64,
+    pub refresh_minutes: u64,
+    /// Unix timestamp of the last time the message was edited.
+    pub updated_at: u64,
+    #[serde(default)]
+    pub language: Language,
+}
+
+impl PriceBoard {
+    pub fn is_due(&self, now: u64) -> bool {
+        now >= self.updated_at + self.refresh_minutes * 60
+    }
+}
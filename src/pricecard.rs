@@ -0,0 +1,246 @@
+use image::{codecs::png::PngEncoder, ColorType, ImageEncoder, Rgb, RgbImage};
+
+const WIDTH: u32 = 800;
+const MARGIN: u32 = 40;
+const HEADER_HEIGHT: u32 = 90;
+const ROW_HEIGHT: u32 = 48;
+const FOOTER_HEIGHT: u32 = 56;
+/// Pixels per font dot in the title and in the rest of the card.
+const TITLE_SCALE: u32 = 5;
+const TEXT_SCALE: u32 = 3;
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+
+const BACKGROUND: Rgb<u8> = Rgb([255, 255, 255]);
+const TEXT: Rgb<u8> = Rgb([40, 40, 40]);
+const MUTED: Rgb<u8> = Rgb([120, 120, 120]);
+
+/// One line of the card: the Robux amounts it covers and their price.
+pub struct Row {
+    pub label: String,
+    pub price: String,
+}
+
+/// Renders a price list card with a `color` header holding `title`, one line
+/// per row and `footer` underneath, and returns the PNG bytes.
+///
+/// Text is drawn with a built-in pixel font rather than system fonts, in
+/// capitals with accents dropped; characters it has no glyph for are left out.
+pub fn render(title: &str, rows: &[Row], footer: &str, color: u32) -> Result<Vec<u8>, String> {
+    let height = HEADER_HEIGHT + ROW_HEIGHT * rows.len() as u32 + FOOTER_HEIGHT;
+    let mut image = RgbImage::from_pixel(WIDTH, height, BACKGROUND);
+
+    let accent = Rgb([(color >> 16) as u8, (color >> 8) as u8, color as u8]);
+    fill(&mut image, 0, 0, WIDTH, HEADER_HEIGHT, accent);
+    let title_y = (HEADER_HEIGHT - GLYPH_HEIGHT * TITLE_SCALE) / 2;
+    draw_text(
+        &mut image,
+        title,
+        MARGIN,
+        title_y,
+        TITLE_SCALE,
+        contrasting(accent),
+    );
+
+    let stripe = blend(accent, BACKGROUND, 0.9);
+    for (index, row) in rows.iter().enumerate() {
+        let top = HEADER_HEIGHT + ROW_HEIGHT * index as u32;
+        if index % 2 == 1 {
+            fill(&mut image, 0, top, WIDTH, ROW_HEIGHT, stripe);
+        }
+        let y = top + (ROW_HEIGHT - GLYPH_HEIGHT * TEXT_SCALE) / 2;
+        draw_text(&mut image, &row.label, MARGIN, y, TEXT_SCALE, TEXT);
+        let x = (WIDTH - MARGIN).saturating_sub(text_width(&row.price, TEXT_SCALE));
+        draw_text(&mut image, &row.price, x, y, TEXT_SCALE, TEXT);
+    }
+
+    let footer_top = height - FOOTER_HEIGHT;
+    fill(&mut image, 0, footer_top, WIDTH, 2, accent);
+    let y = footer_top + (FOOTER_HEIGHT - GLYPH_HEIGHT * TEXT_SCALE) / 2;
+    draw_text(&mut image, footer, MARGIN, y, TEXT_SCALE, MUTED);
+
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png)
+        .write_image(image.as_raw(), WIDTH, height, ColorType::Rgb8)
+        .map_err(|e| format!("Error encoding price card: {:?}", e))?;
+    Ok(png)
+}
+
+fn fill(image: &mut RgbImage, x: u32, y: u32, width: u32, height: u32, color: Rgb<u8>) {
+    for py in y..(y + height).min(image.height()) {
+        for px in x..(x + width).min(image.width()) {
+            image.put_pixel(px, py, color);
+        }
+    }
+}
+
+/// Draws `text` with its top left corner at `(x, y)`, stopping at the right
+/// edge.
+fn draw_text(image: &mut RgbImage, text: &str, x: u32, y: u32, scale: u32, color: Rgb<u8>) {
+    let mut left = x;
+    for glyph in glyphs(text) {
+        if left + GLYPH_WIDTH * scale > image.width() {
+            break;
+        }
+        for (row, bits) in glyph.iter().enumerate() {
+            for column in 0..GLYPH_WIDTH {
+                if bits & (1 << (GLYPH_WIDTH - 1 - column)) != 0 {
+                    let (px, py) = (left + column * scale, y + row as u32 * scale);
+                    fill(image, px, py, scale, scale, color);
+                }
+            }
+        }
+        left += advance(scale);
+    }
+}
+
+fn text_width(text: &str, scale: u32) -> u32 {
+    match glyphs(text).count() as u32 {
+        0 => 0,
+        count => count * advance(scale) - scale,
+    }
+}
+
+fn advance(scale: u32) -> u32 {
+    (GLYPH_WIDTH + 1) * scale
+}
+
+fn glyphs(text: &str) -> impl Iterator<Item = [u8; 7]> + '_ {
+    text.chars()
+        .flat_map(char::to_uppercase)
+        .filter_map(|c| glyph(fold(c)))
+}
+
+/// Black or white, whichever reads better on `background`.
+fn contrasting(background: Rgb<u8>) -> Rgb<u8> {
+    let [r, g, b] = background.0.map(f64::from);
+    if 0.299 * r + 0.587 * g + 0.114 * b > 160.0 {
+        Rgb([0, 0, 0])
+    } else {
+        Rgb([255, 255, 255])
+    }
+}
+
+/// `from` moved `amount` of the way towards `to`.
+fn blend(from: Rgb<u8>, to: Rgb<u8>, amount: f64) -> Rgb<u8> {
+    let mut mixed = [0; 3];
+    for (channel, (from, to)) in mixed.iter_mut().zip(from.0.into_iter().zip(to.0)) {
+        *channel = (f64::from(from) + (f64::from(to) - f64::from(from)) * amount).round() as u8;
+    }
+    Rgb(mixed)
+}
+
+/// The unaccented capital for accented ones, and a plain space for the
+/// non-breaking ones numbers are grouped with.
+fn fold(c: char) -> char {
+    match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' => 'A',
+        'Ç' => 'C',
+        'È' | 'É' | 'Ê' | 'Ë' => 'E',
+        'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+        'Ñ' => 'N',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' => 'O',
+        'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+        '\u{a0}' | '\u{202f}' => ' ',
+        c => c,
+    }
+}
+
+/// A 5x7 glyph, one byte per row with the leftmost dot in the fifth bit.
+fn glyph(c: char) -> Option<[u8; 7]> {
+    Some(match c {
+        ' ' => [0; 7],
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x0A, 0x04, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '/' => [0x01, 0x01, 0x02, 0x04, 0x08, 0x10, 0x10],
+        '+' => [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '%' => [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03],
+        '<' => [0x02, 0x04, 0x08, 0x10, 0x08, 0x04, 0x02],
+        '£' => [0x06, 0x09, 0x08, 0x1C, 0x08, 0x08, 0x1F],
+        '$' => [0x04, 0x0F, 0x14, 0x0E, 0x05, 0x1E, 0x04],
+        '€' => [0x07, 0x08, 0x1E, 0x08, 0x1E, 0x08, 0x07],
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_accented_and_grouped_text() {
+        assert_eq!(glyphs("Taxa padrão").count(), "TAXA PADRAO".len());
+        assert_eq!(
+            glyphs("1\u{202f}234,50 £").count(),
+            glyphs("1 234,50 £").count()
+        );
+        assert_eq!(glyphs("🚀 Shop").count(), " SHOP".len());
+        assert_eq!(text_width("R$", 3), 33);
+    }
+
+    #[test]
+    fn renders_a_row_per_line() {
+        let rows = [
+            Row {
+                label: "Under 1,000 R$".to_string(),
+                price: "£3.50 / $4.40 per 1k R$".to_string(),
+            },
+            Row {
+                label: "1,000+ R$".to_string(),
+                price: "£3.25 / $4.09 per 1k R$".to_string(),
+            },
+        ];
+        let png = render("Price List", &rows, "Updated 2026-10-14", 0x0096FF).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        let decoded = image::load_from_memory(&png).unwrap();
+        assert_eq!(decoded.width(), WIDTH);
+        assert_eq!(
+            decoded.height(),
+            HEADER_HEIGHT + ROW_HEIGHT * 2 + FOOTER_HEIGHT
+        );
+    }
+
+    #[test]
+    fn picks_readable_header_text() {
+        assert_eq!(contrasting(Rgb([0, 150, 255])), Rgb([255, 255, 255]));
+        assert_eq!(contrasting(Rgb([255, 220, 0])), Rgb([0, 0, 0]));
+    }
+}
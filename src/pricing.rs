@@ -0,0 +1,215 @@
+use crate::{
+    coupons::Coupon,
+    loyalty::AppliedDiscount,
+    methods::DeliveryMethod,
+    tiers::{self, Tier},
+    vat::VatSettings,
+};
+
+/// GBP per 1,000 Robux a guild sells at until it sets its own with `/setrates`.
+pub const DEFAULT_SELL_RATE: f64 = 3.5;
+
+/// A guild's flat rates in GBP per 1,000 Robux, set with `/setrates`: what it
+/// sells Robux at below its first tier, and what it pays for Robux it buys, if
+/// it buys any.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShopRates {
+    pub sell: f64,
+    pub buy: Option<f64>,
+}
+
+impl Default for ShopRates {
+    fn default() -> Self {
+        Self {
+            sell: DEFAULT_SELL_RATE,
+            buy: None,
+        }
+    }
+}
+
+impl ShopRates {
+    pub fn sell_per_robux(&self) -> f64 {
+        self.sell / 1000.0
+    }
+
+    pub fn buy_per_robux(&self) -> Option<f64> {
+        self.buy.map(|buy| buy / 1000.0)
+    }
+
+    /// What the guild keeps per 1,000 Robux it buys and sells again at `sell`,
+    /// as GBP and as a percentage of `sell`.
+    pub fn spread(&self, sell: f64) -> Option<(f64, f64)> {
+        self.buy
+            .map(|buy| (sell - buy, (sell - buy) / sell * 100.0))
+    }
+}
+
+/// GBP per Robux for `robux` before delivery fees: the guild's tier rate, or its
+/// sell rate below the first tier, less any loyalty or role `discount`.
+pub fn base_rate(
+    tiers: &[Tier],
+    rates: &ShopRates,
+    robux: u64,
+    discount: Option<&AppliedDiscount>,
+) -> f64 {
+    discount.map_or(1.0, AppliedDiscount::factor)
+        * tiers::tier_for(tiers, robux).map_or(rates.sell_per_robux(), Tier::rate_per_robux)
+}
+
+/// The price of an amount of Robux, as quoted by `/price`, `/buy` and the HTTP API.
+#[derive(Clone, Copy, Debug)]
+pub struct Quote {
+    /// GBP per Robux once the delivery method's fee is covered.
+    pub rate: f64,
+    /// GBP excluding VAT, after any coupon.
+    pub net: f64,
+    /// VAT on `net`.
+    pub tax: f64,
+}
+
+impl Quote {
+    /// The total the buyer pays in GBP.
+    pub fn gbp(&self) -> f64 {
+        self.net + self.tax
+    }
+}
+
+/// Prices `robux` at `base_rate` through `method`, then applies `coupon` and adds `vat`.
+pub fn quote(
+    robux: f64,
+    base_rate: f64,
+    method: DeliveryMethod,
+    after_tax: bool,
+    coupon: Option<&Coupon>,
+    vat: Option<&VatSettings>,
+) -> Quote {
+    let rate = method.rate(base_rate, after_tax);
+    let net = coupon.map_or(robux * rate, |coupon| coupon.apply(robux * rate));
+    Quote {
+        rate,
+        net,
+        tax: vat.map_or(0.0, |vat| vat.tax_on(net)),
+    }
+}
+
+/// The most Robux `gbp` buys at the guild's tiers, before delivery fees.
+pub fn affordable_robux(tiers: &[Tier], rates: &ShopRates, gbp: f64) -> u64 {
+    // The rate only changes at tier thresholds, so the answer is either the
+    // most a tier's rate affords or the Robux just below a threshold.
+    let candidates =
+        std::iter::once(rates.sell_per_robux()).chain(tiers.iter().map(Tier::rate_per_robux));
+    let thresholds = tiers
+        .iter()
+        .flat_map(|tier| [tier.min_robux.saturating_sub(1), tier.min_robux]);
+    candidates
+        .map(|rate| (gbp / rate).floor() as u64)
+        .chain(thresholds)
+        .filter(|&robux| robux as f64 * base_rate(tiers, rates, robux, None) <= gbp + 1e-9)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Splits `total` between `people` in units of `decimals` places (pennies at 2,
+/// whole yen at 0) that add up to exactly `total` rounded to the unit. The first
+/// shares take a unit more when it doesn't divide evenly. Empty when there's
+/// nobody to split between.
+pub fn shares(total: f64, people: u64, decimals: u8) -> Vec<u64> {
+    if people == 0 {
+        return Vec::new();
+    }
+    let units = (total * 10f64.powi(i32::from(decimals))).round() as u64;
+    let (share, left_over) = (units / people, units % people);
+    (0..people)
+        .map(|person| share + u64::from(person < left_over))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coupons::CouponKind;
+
+    #[test]
+    fn shares_add_up_to_the_total() {
+        assert_eq!(shares(10.0, 3, 2), vec![334, 333, 333]);
+        assert_eq!(shares(3.5, 2, 2), vec![175, 175]);
+        assert_eq!(shares(0.01, 3, 2), vec![1, 0, 0]);
+        let split = shares(123.457, 7, 2);
+        assert_eq!(split.iter().sum::<u64>(), 12346);
+        assert!(split.iter().max().unwrap() - split.iter().min().unwrap() <= 1);
+        // Yen have no minor unit, so the split is in whole yen.
+        assert_eq!(shares(1000.4, 3, 0), vec![334, 333, 333]);
+    }
+
+    #[test]
+    fn nobody_gets_no_shares() {
+        assert!(shares(10.0, 0, 2).is_empty());
+    }
+
+    #[test]
+    fn affordable_robux_follows_tiers() {
+        let rates = ShopRates::default();
+        assert_eq!(affordable_robux(&[], &rates, 3.5), 1000);
+        let tiers = [
+            Tier {
+                min_robux: 1000,
+                rate: 3.0,
+            },
+            Tier {
+                min_robux: 10_000,
+                rate: 2.5,
+            },
+        ];
+        // 999 R$ at the default rate costs more than 1,000 R$ at the first tier.
+        assert_eq!(affordable_robux(&tiers, &rates, 3.0), 1000);
+        assert_eq!(affordable_robux(&tiers, &rates, 3.4), 1133);
+        assert_eq!(affordable_robux(&tiers, &rates, 25.0), 10_000);
+        assert_eq!(affordable_robux(&tiers, &rates, 0.001), 0);
+    }
+
+    #[test]
+    fn quotes_take_the_coupon_off_before_adding_vat() {
+        let coupon = Coupon {
+            guild_id: 1,
+            code: "FLAT".to_string(),
+            kind: CouponKind::Flat,
+            value: 1.0,
+            max_uses: None,
+            uses: 0,
+            expires_at: None,
+            created_by: 2,
+            created_at: 0,
+        };
+        let vat = VatSettings {
+            rate: 20.0,
+            region: "UK".to_string(),
+        };
+        let taxed = quote(
+            1000.0,
+            0.0035,
+            DeliveryMethod::Gamepass,
+            false,
+            Some(&coupon),
+            Some(&vat),
+        );
+        assert!((taxed.net - 2.5).abs() < 1e-9);
+        assert!((taxed.tax - 0.5).abs() < 1e-9);
+        assert!((taxed.gbp() - 3.0).abs() < 1e-9);
+
+        let untaxed = quote(1000.0, 0.0035, DeliveryMethod::Gamepass, false, None, None);
+        assert_eq!(untaxed.tax, 0.0);
+        assert!((untaxed.gbp() - 3.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn spread_is_sell_less_buy() {
+        let rates = ShopRates {
+            sell: 3.5,
+            buy: Some(2.8),
+        };
+        let (spread, percent) = rates.spread(3.5).unwrap();
+        assert!((spread - 0.7).abs() < 1e-9);
+        assert!((percent - 20.0).abs() < 1e-9);
+        assert_eq!(ShopRates::default().spread(3.5), None);
+    }
+}
@@ -0,0 +1,36 @@
+use image::{codecs::png::PngEncoder, ColorType, ImageEncoder};
+use qrcode::{Color, QrCode};
+
+/// Attachment name QR codes are sent under.
+pub const FILENAME: &str = "payment-qr.png";
+
+/// Pixels per QR module.
+const SCALE: usize = 8;
+/// Blank modules around the code, as the QR spec requires.
+const QUIET_ZONE: usize = 4;
+
+/// Renders `data` as a black-on-white QR code PNG.
+pub fn render(data: &str) -> Result<Vec<u8>, String> {
+    let code = QrCode::new(data).map_err(|e| format!("Error generating QR code: {:?}", e))?;
+    let modules = code.to_colors();
+    let width = code.width();
+    let size = (width + QUIET_ZONE * 2) * SCALE;
+
+    let mut buffer = vec![u8::MAX; size * size];
+    for (index, color) in modules.iter().enumerate() {
+        if *color != Color::Dark {
+            continue;
+        }
+        let x = (index % width + QUIET_ZONE) * SCALE;
+        let y = (index / width + QUIET_ZONE) * SCALE;
+        for row in y..y + SCALE {
+            buffer[row * size + x..row * size + x + SCALE].fill(0);
+        }
+    }
+
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png)
+        .write_image(&buffer, size as u32, size as u32, ColorType::L8)
+        .map_err(|e| format!("Error encoding QR code: {:?}", e))?;
+    Ok(png)
+}
@@ -0,0 +1,57 @@
+/// How far back `/queue` looks at completed orders to estimate waits.
+pub const RATE_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+/// Most entries `/queue list` shows.
+pub const MAX_LISTED: usize = 20;
+
+/// A buyer waiting in a guild's delivery queue, joined with `/queue join`.
+#[derive(Clone, Debug)]
+pub struct QueueEntry {
+    pub guild_id: u64,
+    pub user_id: u64,
+    /// How much the buyer wants, if they said.
+    pub robux: Option<u64>,
+    /// Unix timestamp in seconds; the queue is served in this order.
+    pub joined_at: u64,
+}
+
+/// Roughly how long the buyer at `position` (1 for next in line) has to wait, given
+/// that `completed` orders were completed in the last [`RATE_WINDOW_SECONDS`].
+/// `None` when nothing was completed, so there's nothing to go on.
+pub fn estimated_wait(position: usize, completed: usize) -> Option<u64> {
+    (completed > 0).then(|| RATE_WINDOW_SECONDS * position as u64 / completed as u64)
+}
+
+/// The 1-based place of `user_id` in `entries`, which are in queue order.
+pub fn position(entries: &[QueueEntry], user_id: u64) -> Option<usize> {
+    entries
+        .iter()
+        .position(|entry| entry.user_id == user_id)
+        .map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_wait_from_completion_rate() {
+        // 14 orders a week is one every 12 hours.
+        assert_eq!(estimated_wait(1, 14), Some(12 * 60 * 60));
+        assert_eq!(estimated_wait(3, 14), Some(36 * 60 * 60));
+        assert_eq!(estimated_wait(1, 0), None);
+    }
+
+    #[test]
+    fn positions_start_at_one() {
+        let entry = |user_id, joined_at| QueueEntry {
+            guild_id: 1,
+            user_id,
+            robux: None,
+            joined_at,
+        };
+        let entries = [entry(7, 10), entry(8, 20)];
+        assert_eq!(position(&entries, 7), Some(1));
+        assert_eq!(position(&entries, 8), Some(2));
+        assert_eq!(position(&entries, 9), None);
+    }
+}
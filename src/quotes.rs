@@ -0,0 +1,45 @@
+/// How long a `/quote bulk` quotation's prices hold when staff don't say.
+pub const DEFAULT_VALID_DAYS: u64 = 7;
+/// The longest a quotation can hold its prices, since rates move.
+pub const MAX_VALID_DAYS: u64 = 30;
+
+/// A quotation's reference, such as `Q-BV9XK2M7DQ0W`. It comes from the
+/// interaction that asked for the quotation, so it's unique without storing
+/// anything.
+pub fn id(interaction_id: u64) -> String {
+    const DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut digits = Vec::new();
+    let mut rest = interaction_id;
+    loop {
+        digits.push(DIGITS[(rest % 36) as usize]);
+        rest /= 36;
+        if rest == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    format!("Q-{}", String::from_utf8_lossy(&digits))
+}
+
+/// The attached PDF's name.
+pub fn filename(id: &str) -> String {
+    format!("quotation-{}.pdf", id)
+}
+
+/// When a quotation made at `now` stops holding its prices.
+pub fn valid_until(now: u64, days: u64) -> u64 {
+    now + days * 24 * 60 * 60
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_base_36_interaction_ids() {
+        assert_eq!(id(0), "Q-0");
+        assert_eq!(id(35), "Q-Z");
+        assert_eq!(id(36 * 36 + 1), "Q-101");
+        assert_eq!(filename(&id(36)), "quotation-Q-10.pdf");
+    }
+}
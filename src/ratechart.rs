@@ -0,0 +1,94 @@
+use image::{codecs::png::PngEncoder, ColorType, ImageEncoder};
+use plotters::prelude::*;
+
+const WIDTH: u32 = 800;
+const HEIGHT: u32 = 400;
+
+/// How far back `/ratechart` looks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Period {
+    Day,
+    Week,
+    Month,
+    Quarter,
+}
+
+impl Period {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "24h" => Some(Period::Day),
+            "7d" => Some(Period::Week),
+            "30d" => Some(Period::Month),
+            "90d" => Some(Period::Quarter),
+            _ => None,
+        }
+    }
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Period::Day => "ratechart.period.24h",
+            Period::Week => "ratechart.period.7d",
+            Period::Month => "ratechart.period.30d",
+            Period::Quarter => "ratechart.period.90d",
+        }
+    }
+
+    pub fn seconds(self) -> u64 {
+        let days = match self {
+            Period::Day => 1,
+            Period::Week => 7,
+            Period::Month => 30,
+            Period::Quarter => 90,
+        };
+        days * 24 * 60 * 60
+    }
+}
+
+/// Renders `points` of `(timestamp, rate)` as a line chart and returns the PNG bytes.
+///
+/// The chart has no text so it doesn't depend on system fonts; the embed carries
+/// the numbers.
+pub fn render(points: &[(u64, f64)], color: u32) -> Result<Vec<u8>, String> {
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) if points.len() >= 2 => (first.0, last.0),
+        _ => return Err("A rate chart needs at least two points".to_string()),
+    };
+    let (low, high) = points
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(low, high), &(_, rate)| {
+            (low.min(rate), high.max(rate))
+        });
+    let padding = ((high - low) * 0.1).max(high * 0.001);
+    let line_color = RGBColor((color >> 16) as u8, (color >> 8) as u8, color as u8);
+
+    let mut buffer = vec![0; (WIDTH * HEIGHT * 3) as usize];
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (WIDTH, HEIGHT)).into_drawing_area();
+        root.fill(&WHITE).map_err(|e| e.to_string())?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .margin(20)
+            .build_cartesian_2d(first..last, (low - padding)..(high + padding))
+            .map_err(|e| e.to_string())?;
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .light_line_style(WHITE)
+            .bold_line_style(RGBColor(230, 230, 230))
+            .draw()
+            .map_err(|e| e.to_string())?;
+        chart
+            .draw_series(LineSeries::new(
+                points.iter().copied(),
+                line_color.stroke_width(3),
+            ))
+            .map_err(|e| e.to_string())?;
+        root.present().map_err(|e| e.to_string())?;
+    }
+
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png)
+        .write_image(&buffer, WIDTH, HEIGHT, ColorType::Rgb8)
+        .map_err(|e| format!("Error encoding rate chart: {:?}", e))?;
+    Ok(png)
+}
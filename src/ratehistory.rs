@@ -0,0 +1,88 @@
+use crate::{
+    i18n::{t, Language},
+    numbers,
+};
+
+/// How many changes `/ratehistory` lists, newest first.
+pub const SHOWN: u32 = 15;
+
+/// One of a guild's rates that `/setrates` or `/setrate` can change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RateKind {
+    Sell,
+    Buy,
+    /// The exchange rate fixed with `/setrate forex`.
+    Forex,
+}
+
+impl RateKind {
+    pub const ALL: [RateKind; 3] = [RateKind::Sell, RateKind::Buy, RateKind::Forex];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.code() == value)
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            RateKind::Sell => "sell",
+            RateKind::Buy => "buy",
+            RateKind::Forex => "forex",
+        }
+    }
+
+    pub fn name(self, lang: Language) -> &'static str {
+        let key = match self {
+            RateKind::Sell => "ratehistory.sell",
+            RateKind::Buy => "ratehistory.buy",
+            RateKind::Forex => "ratehistory.forex",
+        };
+        t(lang, key)
+    }
+
+    /// `value` as `/ratehistory` shows it: GBP per 1,000 Robux for the sell and
+    /// buy rates, USD per GBP for the exchange rate.
+    pub fn format(self, lang: Language, value: Option<f64>) -> String {
+        match (self, value) {
+            (RateKind::Forex, Some(value)) => {
+                format!("GBP/USD {}", numbers::decimal(lang, value, 4))
+            }
+            (RateKind::Forex, None) => t(lang, "ratehistory.market").to_string(),
+            (_, Some(value)) => numbers::gbp(lang, value),
+            (_, None) => t(lang, "ratehistory.not_buying").to_string(),
+        }
+    }
+}
+
+/// A change to one of a guild's rates, recorded for `/ratehistory`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateChange {
+    pub guild_id: u64,
+    pub changed_by: u64,
+    pub kind: RateKind,
+    /// `None` when the guild wasn't buying or used the market exchange rate.
+    pub previous: Option<f64>,
+    pub value: Option<f64>,
+    pub changed_at: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_round_trip() {
+        for kind in RateKind::ALL {
+            assert_eq!(RateKind::parse(kind.code()), Some(kind));
+        }
+        assert_eq!(RateKind::parse("tier"), None);
+    }
+
+    #[test]
+    fn formats_each_kind_of_rate() {
+        let lang = Language::En;
+        assert_eq!(RateKind::Sell.format(lang, Some(3.5)), "£3.50");
+        assert_eq!(RateKind::Buy.format(lang, None), "not buying");
+        assert_eq!(RateKind::Forex.format(lang, Some(1.27)), "GBP/USD 1.2700");
+        assert_eq!(RateKind::Forex.format(lang, None), "market rate");
+    }
+}
@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    env,
+    future::Future,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::crypto::Crypto;
+
+const DEFAULT_REFRESH_MINUTES: u64 = 15;
+/// Refreshes that can fail in a row before the health check reports rates as stale.
+const STALE_AFTER_REFRESHES: u64 = 3;
+
+/// Fiat currencies amounts can be given in.
+pub const FIAT: [&str; 2] = ["GBP", "USD"];
+
+/// Currencies users can have prices shown in with `/setcurrency`: those the ECB
+/// publishes reference rates for.
+pub const DISPLAY_CURRENCIES: [&str; 30] = [
+    "AUD", "BGN", "BRL", "CAD", "CHF", "CNY", "CZK", "DKK", "EUR", "HKD", "HUF", "IDR", "ILS",
+    "INR", "ISK", "JPY", "KRW", "MXN", "MYR", "NOK", "NZD", "PHP", "PLN", "RON", "SEK", "SGD",
+    "THB", "TRY", "USD", "ZAR",
+];
+
+/// Currencies without a minor unit in use, whose amounts are whole numbers.
+const ZERO_DECIMAL_CURRENCIES: [&str; 5] = ["HUF", "IDR", "ISK", "JPY", "KRW"];
+
+/// Decimal places in `currency`'s smallest unit: none for the yen and won, two
+/// for pence and cents.
+pub fn minor_unit_decimals(currency: &str) -> u8 {
+    if ZERO_DECIMAL_CURRENCIES.contains(&currency) {
+        0
+    } else {
+        2
+    }
+}
+
+/// Currency pairs the bot can quote and alert on.
+pub const PAIRS: [&str; 2] = ["GBP/USD", "USD/GBP"];
+
+/// The highest rate `/setrate forex` takes, either way round.
+pub const MAX_FOREX_OVERRIDE: f64 = 100.0;
+
+/// How long fetched rates are kept for `/ratechart`.
+pub const HISTORY_SECONDS: u64 = 90 * 24 * 60 * 60;
+
+tokio::task_local! {
+    static FOREX_OVERRIDE: Option<f64>;
+}
+
+/// Runs `future` with [`forex_override`] returning `rate`, the fixed GBP/USD
+/// rate of the guild an interaction came from.
+pub async fn with_forex_override<F: Future>(rate: Option<f64>, future: F) -> F::Output {
+    FOREX_OVERRIDE.scope(rate, future).await
+}
+
+/// The GBP/USD rate the guild being served fixed with `/setrate forex`, if any.
+pub fn forex_override() -> Option<f64> {
+    FOREX_OVERRIDE.try_with(|rate| *rate).ok().flatten()
+}
+
+/// Latest exchange rates, refreshed in the background.
+pub struct Rates {
+    pub gbp_to_usd: f64,
+    /// Unix timestamp of the last successful fetch, if any.
+    pub updated_at: Option<u64>,
+    /// GBP price of one coin. Empty until the first successful fetch.
+    pub crypto_gbp: HashMap<Crypto, f64>,
+    pub crypto_updated_at: Option<u64>,
+    /// Units of each [`DISPLAY_CURRENCIES`] code per GBP, from the same fetch as
+    /// `gbp_to_usd`. Empty until the first successful fetch.
+    pub fiat_gbp: HashMap<String, f64>,
+}
+
+impl Rates {
+    pub fn new(fallback_gbp_to_usd: f64) -> Self {
+        Self {
+            gbp_to_usd: fallback_gbp_to_usd,
+            updated_at: None,
+            crypto_gbp: HashMap::new(),
+            crypto_updated_at: None,
+            fiat_gbp: HashMap::new(),
+        }
+    }
+
+    /// Whether several refreshes in a row have failed, counting from `started_at`
+    /// if no fetch has succeeded yet.
+    pub fn is_stale(&self, started_at: u64) -> bool {
+        let since = self.updated_at.unwrap_or(started_at);
+        now().saturating_sub(since) > refresh_minutes() * 60 * STALE_AFTER_REFRESHES
+    }
+
+    /// Units of the fiat currency `code` per GBP, if it's been fetched.
+    pub fn per_gbp(&self, code: &str) -> Option<f64> {
+        match code {
+            "GBP" => Some(1.0),
+            "USD" => Some(forex_override().unwrap_or(self.gbp_to_usd)),
+            _ => self.fiat_gbp.get(code).copied(),
+        }
+    }
+
+    /// Returns the rate for a pair such as `GBP/USD`.
+    pub fn pair(&self, pair: &str) -> Option<f64> {
+        match pair {
+            "GBP/USD" => Some(self.gbp_to_usd),
+            "USD/GBP" => Some(1.0 / self.gbp_to_usd),
+            _ => None,
+        }
+    }
+}
+
+/// A fetched rate, kept so `/ratechart` can plot how it moved.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct RateSnapshot {
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+    pub gbp_to_usd: f64,
+}
+
+impl RateSnapshot {
+    pub fn pair(&self, pair: &str) -> Option<f64> {
+        match pair {
+            "GBP/USD" => Some(self.gbp_to_usd),
+            "USD/GBP" => Some(1.0 / self.gbp_to_usd),
+            _ => None,
+        }
+    }
+}
+
+/// An exchange rate API. Each replies with `{"rates": {"USD": ...}}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ForexSource {
+    /// ECB reference rates via frankfurter.app.
+    Ecb,
+    ExchangerateHost,
+    /// Needs the app id in `OPENEXCHANGERATES_APP_ID`.
+    OpenExchangeRates(String),
+    /// open.er-api.com, tried whenever the chosen source fails.
+    ErApi,
+}
+
+impl ForexSource {
+    /// The source picked with `FOREX_SOURCE`: `ecb` (the default),
+    /// `exchangerate_host` or `openexchangerates`.
+    pub fn from_env() -> Result<Self, String> {
+        match env::var("FOREX_SOURCE").unwrap_or_default().as_str() {
+            "" | "ecb" => Ok(ForexSource::Ecb),
+            "exchangerate_host" => Ok(ForexSource::ExchangerateHost),
+            "openexchangerates" => env::var("OPENEXCHANGERATES_APP_ID")
+                .ok()
+                .filter(|app_id| !app_id.is_empty())
+                .map(ForexSource::OpenExchangeRates)
+                .ok_or_else(|| {
+                    "FOREX_SOURCE=openexchangerates needs OPENEXCHANGERATES_APP_ID".to_string()
+                }),
+            other => Err(format!("Unknown FOREX_SOURCE {:?}", other)),
+        }
+    }
+
+    /// What to try in order: this source, then open.er-api.com.
+    pub fn with_fallback(self) -> Vec<Self> {
+        if self == ForexSource::ErApi {
+            vec![self]
+        } else {
+            vec![self, ForexSource::ErApi]
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ForexSource::Ecb => "Frankfurter",
+            ForexSource::ExchangerateHost => "exchangerate.host",
+            ForexSource::OpenExchangeRates(_) => "Open Exchange Rates",
+            ForexSource::ErApi => "ExchangeRate-API",
+        }
+    }
+
+    /// The URL to fetch, and the currency its rates are from.
+    fn endpoint(&self) -> (String, &'static str) {
+        match self {
+            ForexSource::Ecb => (
+                "https://api.frankfurter.app/latest?from=GBP".to_string(),
+                "GBP",
+            ),
+            ForexSource::ExchangerateHost => (
+                "https://api.exchangerate.host/latest?base=GBP".to_string(),
+                "GBP",
+            ),
+            // The free plan only quotes from USD.
+            ForexSource::OpenExchangeRates(app_id) => (
+                format!(
+                    "https://openexchangerates.org/api/latest.json?app_id={}",
+                    app_id
+                ),
+                "USD",
+            ),
+            ForexSource::ErApi => ("https://open.er-api.com/v6/latest/GBP".to_string(), "GBP"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ForexResponse {
+    rates: HashMap<String, f64>,
+}
+
+/// Fetches rates from GBP to each of [`DISPLAY_CURRENCIES`] the first of
+/// `sources` that answers covers, which always includes USD. Fails only if every
+/// source does.
+pub async fn fetch_gbp_rates(
+    client: &reqwest::Client,
+    sources: &[ForexSource],
+) -> Result<HashMap<String, f64>, String> {
+    let mut errors = Vec::new();
+    for source in sources {
+        match fetch_source(client, source).await {
+            Ok(rates) => return Ok(rates),
+            Err(error) => errors.push(format!("{}: {}", source.name(), error)),
+        }
+    }
+    Err(errors.join("\n"))
+}
+
+async fn fetch_source(
+    client: &reqwest::Client,
+    source: &ForexSource,
+) -> Result<HashMap<String, f64>, String> {
+    let (url, base) = source.endpoint();
+    let response: ForexResponse = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Error fetching exchange rates: {:?}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Error parsing exchange rates: {:?}", e))?;
+
+    let mut rates = response.rates;
+    if base != "GBP" {
+        let per_base = rates
+            .get("GBP")
+            .copied()
+            .ok_or("Exchange rate response did not include GBP")?;
+        rates.insert(base.to_string(), 1.0);
+        for rate in rates.values_mut() {
+            *rate /= per_base;
+        }
+    }
+    let rates: HashMap<_, _> = rates
+        .into_iter()
+        .filter(|(code, rate)| {
+            DISPLAY_CURRENCIES.contains(&code.as_str()) && rate.is_finite() && *rate > 0.0
+        })
+        .collect();
+    if !rates.contains_key("USD") {
+        return Err("Exchange rate response did not include USD".to_string());
+    }
+    Ok(rates)
+}
+
+/// Minutes between rate fetches: `RATE_REFRESH_MINUTES`, or every 15 minutes.
+pub fn refresh_minutes() -> u64 {
+    env::var("RATE_REFRESH_MINUTES")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .filter(|minutes| *minutes > 0)
+        .unwrap_or(DEFAULT_REFRESH_MINUTES)
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
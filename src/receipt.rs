@@ -0,0 +1,253 @@
+/// A4 in PDF points.
+const PAGE_WIDTH: u32 = 595;
+const PAGE_HEIGHT: u32 = 842;
+const MARGIN: u32 = 56;
+/// Where row values start, leaving room for the labels.
+const VALUE_X: u32 = 240;
+const ROW_HEIGHT: u32 = 20;
+
+#[cfg(feature = "orders")]
+pub fn filename(order_id: u64) -> String {
+    format!("receipt-{}.pdf", order_id)
+}
+
+/// A part of a PDF made by [`render_blocks`], laid out top to bottom.
+pub enum Block {
+    /// One `label  value` line per row, the label in bold.
+    Rows(Vec<(String, String)>),
+    /// Columns spread evenly across the page, the first row in bold with a rule
+    /// under it.
+    Table(Vec<Vec<String>>),
+    /// Plain lines of text.
+    Text(Vec<String>),
+}
+
+/// Renders a one-page PDF receipt: `title` in bold, one `label  value` line per
+/// row, then `footer` in small grey text.
+#[cfg(feature = "orders")]
+pub fn render(title: &str, rows: &[(String, String)], footer: &str) -> Vec<u8> {
+    render_blocks(title, &[Block::Rows(rows.to_vec())], footer)
+}
+
+/// Renders a one-page PDF: `title` in bold, each of `blocks` with a gap between
+/// them, then `footer` in small grey text.
+///
+/// It only uses Helvetica, one of the fonts every PDF reader has built in, so
+/// nothing needs embedding. Text outside Windows-1252 shows as `?`.
+pub fn render_blocks(title: &str, blocks: &[Block], footer: &str) -> Vec<u8> {
+    let mut content = Vec::new();
+    let mut y = PAGE_HEIGHT - MARGIN - 20;
+    text(&mut content, "F2", 20, MARGIN, y, title);
+    y -= ROW_HEIGHT;
+    for block in blocks {
+        y -= ROW_HEIGHT;
+        match block {
+            Block::Rows(rows) => {
+                for (label, value) in rows {
+                    text(&mut content, "F2", 11, MARGIN, y, label);
+                    text(&mut content, "F1", 11, VALUE_X, y, value);
+                    y -= ROW_HEIGHT;
+                }
+            }
+            Block::Table(table) => {
+                let columns = table.first().map_or(1, Vec::len).max(1) as u32;
+                let column_width = (PAGE_WIDTH - 2 * MARGIN) / columns;
+                for (index, row) in table.iter().enumerate() {
+                    let font = if index == 0 { "F2" } else { "F1" };
+                    for (column, value) in row.iter().enumerate() {
+                        let x = MARGIN + column as u32 * column_width;
+                        text(&mut content, font, 10, x, y, value);
+                    }
+                    if index == 0 {
+                        content.extend_from_slice(
+                            format!(
+                                "0.5 w {} {} m {} {} l S\n",
+                                MARGIN,
+                                y - 6,
+                                PAGE_WIDTH - MARGIN,
+                                y - 6
+                            )
+                            .as_bytes(),
+                        );
+                    }
+                    y -= ROW_HEIGHT;
+                }
+            }
+            Block::Text(lines) => {
+                for line in lines {
+                    text(&mut content, "F1", 10, MARGIN, y, line);
+                    y -= ROW_HEIGHT - 4;
+                }
+            }
+        }
+    }
+    content.extend_from_slice(b"0.4 g\n");
+    text(&mut content, "F1", 9, MARGIN, y - ROW_HEIGHT, footer);
+
+    let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+    stream.extend_from_slice(&content);
+    stream.extend_from_slice(b"\nendstream");
+
+    let objects: [Vec<u8>; 6] = [
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents 6 0 R >>",
+            PAGE_WIDTH, PAGE_HEIGHT
+        )
+        .into_bytes(),
+        font("Helvetica"),
+        font("Helvetica-Bold"),
+        stream,
+    ];
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+        pdf.extend_from_slice(object);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+    let xref = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+fn font(name: &str) -> Vec<u8> {
+    format!(
+        "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+        name
+    )
+    .into_bytes()
+}
+
+fn text(content: &mut Vec<u8>, font: &str, size: u32, x: u32, y: u32, value: &str) {
+    content.extend_from_slice(format!("BT /{} {} Tf {} {} Td (", font, size, x, y).as_bytes());
+    content.extend_from_slice(&encode(value));
+    content.extend_from_slice(b") Tj ET\n");
+}
+
+/// `value` as a PDF string in Windows-1252, with delimiters escaped.
+fn encode(value: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for c in value.chars() {
+        match c {
+            '(' | ')' | '\\' => bytes.extend_from_slice(&[b'\\', c as u8]),
+            ' '..='~' => bytes.push(c as u8),
+            '€' => bytes.push(0x80),
+            '…' => bytes.push(0x85),
+            // French groups digits with a narrow no-break space.
+            '\u{202f}' => bytes.push(0xA0),
+            '\u{a0}'..='\u{ff}' => bytes.push(c as u8),
+            _ => bytes.push(b'?'),
+        }
+    }
+    bytes
+}
+
+/// A Unix timestamp as `2026-10-14 12:00 UTC`.
+pub fn date(timestamp: u64) -> String {
+    const DAY: u64 = 24 * 60 * 60;
+    let (days, seconds) = ((timestamp / DAY) as i64, timestamp % DAY);
+
+    // The inverse of `risk::parse_timestamp`'s day count.
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(date(0), "1970-01-01 00:00 UTC");
+        assert_eq!(date(1_709_164_800), "2024-02-29 00:00 UTC");
+        assert_eq!(date(1_791_979_200 + 90), "2026-10-14 12:01 UTC");
+    }
+
+    #[test]
+    fn encodes_text_for_helvetica() {
+        assert_eq!(encode("£5 (a\\b)"), b"\xA35 \\(a\\\\b\\)".to_vec());
+        assert_eq!(encode("1\u{202f}000 €"), b"1\xA0000 \x80".to_vec());
+        assert_eq!(encode("✓"), b"?".to_vec());
+    }
+
+    #[test]
+    fn tables_spread_columns_and_rule_off_the_header() {
+        let table = vec![
+            vec!["#".to_string(), "Robux".to_string()],
+            vec!["1".to_string(), "1,000 R$".to_string()],
+        ];
+        let pdf = render_blocks("Quotation", &[Block::Table(table)], "");
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("BT /F2 10 Tf 297 726 Td (Robux) Tj ET"));
+        assert!(text.contains("0.5 w 56 720 m 539 720 l S"));
+        assert!(text.contains("BT /F1 10 Tf 297 706 Td (1,000 R$) Tj ET"));
+    }
+
+    #[test]
+    #[cfg(feature = "orders")]
+    fn cross_reference_table_points_at_objects() {
+        let pdf = render(
+            "Receipt",
+            &[("Robux".to_string(), "1,000 R$".to_string())],
+            "Thanks",
+        );
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        let text = String::from_utf8_lossy(&pdf);
+        let xref: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .unwrap();
+        assert!(pdf[xref..].starts_with(b"xref\n0 7\n"));
+
+        let offsets: Vec<usize> = text[xref..]
+            .lines()
+            .skip(3)
+            .take(6)
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+        for (index, offset) in offsets.into_iter().enumerate() {
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj", index + 1).as_bytes()));
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::i18n::Language;
+
+/// Longest wait `/remind` accepts.
+pub const MAX_REMINDER_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+/// A note a user asked `/remind` to send them later.
+#[derive(Clone, Debug)]
+pub struct Reminder {
+    pub id: u64,
+    pub user_id: u64,
+    pub guild_id: Option<u64>,
+    /// Channel to ping the user in, or `None` to DM them.
+    pub channel_id: Option<u64>,
+    pub note: String,
+    /// Unix timestamps in seconds.
+    pub remind_at: u64,
+    pub created_at: u64,
+    pub language: Language,
+}
@@ -0,0 +1,202 @@
+use async_trait::async_trait;
+use serenity::{
+    builder::{CreateComponents, CreateEmbed},
+    http::{Http, HttpError},
+    model::{
+        application::interaction::{
+            application_command::ApplicationCommandInteraction, InteractionResponseType,
+        },
+        channel::AttachmentType,
+    },
+    Error,
+};
+use std::{future::Future, time::Duration};
+
+/// How many times a REST call is tried before a transient failure is returned.
+const MAX_ATTEMPTS: u32 = 3;
+/// Wait before the first retry, doubled before each one after. Kept short because
+/// Discord only accepts an interaction's response for three seconds.
+const FIRST_RETRY_DELAY: Duration = Duration::from_millis(250);
+/// Discord's "Interaction has already been acknowledged", e.g. when a retried
+/// response reached Discord the first time after all.
+const ALREADY_ACKNOWLEDGED: isize = 40060;
+
+/// A reply to a slash command.
+#[derive(Clone, Default)]
+pub struct Reply {
+    pub content: Option<String>,
+    pub embed: Option<CreateEmbed>,
+    /// Attached file contents and name.
+    pub file: Option<(Vec<u8>, String)>,
+    /// Buttons or menus under the message.
+    pub components: Option<CreateComponents>,
+    pub ephemeral: bool,
+}
+
+/// Where slash command replies go. The bot sends them through Discord's HTTP API;
+/// tests swap in a responder that records them instead.
+#[async_trait]
+pub trait Responder: Send + Sync {
+    async fn respond(
+        &self,
+        command: &ApplicationCommandInteraction,
+        reply: &Reply,
+    ) -> serenity::Result<()>;
+}
+
+#[async_trait]
+impl Responder for Http {
+    async fn respond(
+        &self,
+        command: &ApplicationCommandInteraction,
+        reply: &Reply,
+    ) -> serenity::Result<()> {
+        send(self, command, reply).await
+    }
+}
+
+/// Sends `reply` as the response to `command`, retrying rate limits and server
+/// errors. If the interaction was already responded to, it's sent as a followup
+/// message instead. One whose three-second window passed unanswered can't be
+/// replied to at all, so that error is returned.
+pub async fn send(
+    http: &Http,
+    command: &ApplicationCommandInteraction,
+    reply: &Reply,
+) -> serenity::Result<()> {
+    let result = with_backoff(|| {
+        command.create_interaction_response(http, move |response| {
+            response
+                .kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(move |message| {
+                    if let Some(content) = &reply.content {
+                        message.content(content);
+                    }
+                    if let Some(embed) = &reply.embed {
+                        message.add_embed(embed.clone());
+                    }
+                    if let Some(file) = attachment(reply) {
+                        message.add_file(file);
+                    }
+                    if let Some(components) = &reply.components {
+                        message.set_components(components.clone());
+                    }
+                    message.ephemeral(reply.ephemeral)
+                })
+        })
+    })
+    .await;
+
+    match result {
+        Err(error) if is_already_acknowledged(&error) => with_backoff(|| {
+            command.create_followup_message(http, move |message| {
+                if let Some(content) = &reply.content {
+                    message.content(content);
+                }
+                if let Some(embed) = &reply.embed {
+                    message.add_embed(embed.clone());
+                }
+                if let Some(file) = attachment(reply) {
+                    message.add_file(file);
+                }
+                if let Some(components) = &reply.components {
+                    message.set_components(components.clone());
+                }
+                message.ephemeral(reply.ephemeral)
+            })
+        })
+        .await
+        .map(|_| ()),
+        result => result,
+    }
+}
+
+fn attachment(reply: &Reply) -> Option<AttachmentType<'static>> {
+    reply
+        .file
+        .as_ref()
+        .map(|(data, filename)| AttachmentType::Bytes {
+            data: data.clone().into(),
+            filename: filename.clone(),
+        })
+}
+
+/// Runs `request` until it succeeds, fails for a reason retrying won't fix, or
+/// has been tried [`MAX_ATTEMPTS`] times, backing off exponentially in between.
+pub async fn with_backoff<T, F, Fut>(mut request: F) -> serenity::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = serenity::Result<T>>,
+{
+    let mut delay = FIRST_RETRY_DELAY;
+    let mut attempt = 1;
+    loop {
+        match request().await {
+            Err(error) if attempt < MAX_ATTEMPTS && is_transient(&error) => {
+                eprintln!("Retrying Discord request in {:?}: {}", delay, error);
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Whether Discord rate limited the request, failed with a server error, or
+/// couldn't be reached in time.
+fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::Http(error) => match error.as_ref() {
+            HttpError::UnsuccessfulRequest(response) => {
+                let status = response.status_code.as_u16();
+                status == 429 || status >= 500
+            }
+            HttpError::Request(error) => error.is_timeout() || error.is_connect(),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether Discord refused a response because the interaction already had one.
+/// Followup messages can still be sent.
+pub fn is_already_acknowledged(error: &Error) -> bool {
+    match error {
+        Error::Http(error) => matches!(
+            error.as_ref(),
+            HttpError::UnsuccessfulRequest(response)
+                if response.error.code == ALREADY_ACKNOWLEDGED
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serenity::http::error::{DiscordJsonError, ErrorResponse};
+
+    fn discord_error(code: isize) -> Error {
+        let error: DiscordJsonError =
+            serde_json::from_value(serde_json::json!({"code": code, "message": ""})).unwrap();
+        Error::Http(Box::new(HttpError::UnsuccessfulRequest(ErrorResponse {
+            status_code: reqwest::StatusCode::BAD_REQUEST,
+            url: "https://discord.com/api/v10/interactions/1/token/callback"
+                .parse()
+                .unwrap(),
+            error,
+        })))
+    }
+
+    #[test]
+    fn tells_acknowledged_interactions_from_expired_ones() {
+        assert!(is_already_acknowledged(&discord_error(
+            ALREADY_ACKNOWLEDGED
+        )));
+        // "Unknown interaction": the response window passed, and a followup
+        // would fail too.
+        assert!(!is_already_acknowledged(&discord_error(10062)));
+        assert!(!is_transient(&discord_error(ALREADY_ACKNOWLEDGED)));
+    }
+}
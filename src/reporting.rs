@@ -0,0 +1,33 @@
+use std::env;
+
+/// Starts Sentry when `SENTRY_DSN` is set. Any Sentry-compatible DSN works,
+/// including a self-hosted GlitchTip. Panics are captured from then on, and
+/// events are tagged with the bot's version and `SENTRY_ENVIRONMENT`. Events are
+/// only sent while the returned guard is alive.
+pub fn init() -> Option<sentry::ClientInitGuard> {
+    let dsn = env::var("SENTRY_DSN").ok()?;
+    let guard = sentry::init((
+        dsn,
+        sentry::ClientOptions {
+            release: sentry::release_name!(),
+            environment: env::var("SENTRY_ENVIRONMENT").ok().map(Into::into),
+            ..Default::default()
+        },
+    ));
+    sentry::configure_scope(|scope| scope.set_tag("version", env!("CARGO_PKG_VERSION")));
+    Some(guard)
+}
+
+/// Sends `error` to Sentry under `title`, with `context` such as the command,
+/// user and guild attached as extra data.
+pub fn capture_error(title: &str, error: &str, context: &[(&str, String)]) {
+    sentry::with_scope(
+        |scope| {
+            scope.set_tag("source", title);
+            for (name, value) in context {
+                scope.set_extra(name, value.clone().into());
+            }
+        },
+        || sentry::capture_message(error, sentry::Level::Error),
+    );
+}
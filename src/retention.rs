@@ -0,0 +1,38 @@
+/// Longest `/retention` can keep records for, about ten years.
+pub const MAX_DAYS: u64 = 3650;
+
+/// How often the purge job deletes records past their guild's retention.
+pub const PURGE_MINUTES: u64 = 60;
+
+/// How long a guild keeps records before the purge job deletes them, set with
+/// `/retention`. `None` keeps them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Retention {
+    /// Days calculations made in the guild are kept.
+    pub history_days: Option<u64>,
+    /// Days orders are kept after their ticket is closed.
+    pub order_days: Option<u64>,
+}
+
+impl Retention {
+    pub fn is_set(&self) -> bool {
+        self.history_days.is_some() || self.order_days.is_some()
+    }
+}
+
+/// What `/forgetme` deleted about a user.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Forgotten {
+    /// Calculations across every guild and DMs.
+    pub calculations: u64,
+    /// Whether they had saved settings, such as a `/setcurrency` choice.
+    pub settings: bool,
+    /// Whether they had a Roblox account linked or a verification pending.
+    pub roblox_link: bool,
+}
+
+impl Forgotten {
+    pub fn is_empty(&self) -> bool {
+        *self == Forgotten::default()
+    }
+}
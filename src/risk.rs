@@ -0,0 +1,130 @@
+use crate::i18n::{t, tf, Language};
+
+/// Accounts younger than this are flagged as new.
+pub const NEW_ACCOUNT_DAYS: u64 = 30;
+/// Accounts with fewer friends or badges than these are flagged too. New
+/// accounts made just to buy usually have neither.
+pub const MIN_FRIENDS: u64 = 3;
+pub const MIN_BADGES: u64 = 5;
+/// Embed colors for accounts with and without flags.
+pub const FLAGGED_COLOR: u32 = 0xE74C3C;
+pub const CLEAR_COLOR: u32 = 0x2ECC71;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// What Roblox says about a buyer's linked account, fetched when their ticket
+/// opens.
+#[derive(Clone, Copy, Debug)]
+pub struct AccountSignals {
+    /// Unix timestamp of the account's creation.
+    pub created_at: u64,
+    pub friends: u64,
+    /// Counted up to the first page of badges.
+    pub badges: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RiskFlag {
+    NewAccount,
+    FewFriends,
+    FewBadges,
+}
+
+impl RiskFlag {
+    pub fn name(self, lang: Language) -> String {
+        match self {
+            RiskFlag::NewAccount => tf(
+                lang,
+                "risk.flag.new_account",
+                &[("days", &NEW_ACCOUNT_DAYS)],
+            ),
+            RiskFlag::FewFriends => t(lang, "risk.flag.few_friends").to_string(),
+            RiskFlag::FewBadges => t(lang, "risk.flag.few_badges").to_string(),
+        }
+    }
+}
+
+impl AccountSignals {
+    pub fn age_days(&self, now: u64) -> u64 {
+        now.saturating_sub(self.created_at) / SECONDS_PER_DAY
+    }
+
+    /// Everything about the account staff should double-check before delivering.
+    pub fn flags(&self, now: u64) -> Vec<RiskFlag> {
+        let mut flags = Vec::new();
+        if self.age_days(now) < NEW_ACCOUNT_DAYS {
+            flags.push(RiskFlag::NewAccount);
+        }
+        if self.friends < MIN_FRIENDS {
+            flags.push(RiskFlag::FewFriends);
+        }
+        if self.badges < MIN_BADGES {
+            flags.push(RiskFlag::FewBadges);
+        }
+        flags
+    }
+}
+
+/// Parses a UTC timestamp like Roblox's `2019-03-02T17:45:12.357Z` into Unix
+/// seconds, ignoring fractions of a second.
+pub fn parse_timestamp(text: &str) -> Option<u64> {
+    let (date, time) = text.trim_end_matches('Z').split_once('T')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let time = time.split('.').next()?;
+    let mut time = time.splitn(3, ':').map(str::parse::<u64>);
+    let (hour, minute, second) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+
+    // Days since 1970-01-01 in the proleptic Gregorian calendar.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = u64::try_from(era * 146_097 + day_of_era - 719_468).ok()?;
+    Some(days * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_roblox_timestamps() {
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            parse_timestamp("2019-03-02T17:45:12.357Z"),
+            Some(1_551_548_712)
+        );
+        assert_eq!(parse_timestamp("2024-02-29T00:00:00Z"), Some(1_709_164_800));
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn flags_new_and_empty_accounts() {
+        let now = 100 * SECONDS_PER_DAY;
+        let fresh = AccountSignals {
+            created_at: now - 2 * SECONDS_PER_DAY,
+            friends: 0,
+            badges: 1,
+        };
+        assert_eq!(
+            fresh.flags(now),
+            vec![
+                RiskFlag::NewAccount,
+                RiskFlag::FewFriends,
+                RiskFlag::FewBadges
+            ]
+        );
+
+        let established = AccountSignals {
+            created_at: 0,
+            friends: MIN_FRIENDS,
+            badges: MIN_BADGES,
+        };
+        assert!(established.flags(now).is_empty());
+    }
+}
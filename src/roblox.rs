@@ -0,0 +1,607 @@
+use reqwest::{header::HeaderValue, Method, RequestBuilder, Response, StatusCode};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{sync::OnceLock, time::Duration};
+use tokio::sync::Mutex;
+
+use crate::cache::TtlCache;
+#[cfg(feature = "roblox")]
+use crate::rates;
+#[cfg(feature = "orders")]
+use crate::risk::{self, AccountSignals};
+
+const USERNAMES_URL: &str = "https://users.roblox.com/v1/usernames/users";
+const USERS_URL: &str = "https://users.roblox.com/v1/users";
+#[cfg(feature = "orders")]
+const FRIENDS_URL: &str = "https://friends.roblox.com/v1/users";
+#[cfg(feature = "orders")]
+const BADGES_URL: &str = "https://badges.roblox.com/v1/users";
+/// Badges fetched when counting a user's badges, the most one page holds.
+#[cfg(feature = "orders")]
+const BADGE_PAGE_SIZE: u32 = 100;
+#[cfg(feature = "roblox")]
+const GAMEPASS_URL: &str = "https://apis.roblox.com/game-passes/v1/game-passes";
+#[cfg(feature = "roblox")]
+const INVENTORY_URL: &str = "https://inventory.roblox.com/v1/users";
+/// Pages of 100 collectibles read before an inventory is counted as truncated,
+/// to stay under Roblox's rate limits.
+#[cfg(feature = "roblox")]
+const MAX_INVENTORY_PAGES: usize = 10;
+/// How long a fetched inventory is reused for `/rap`.
+#[cfg(feature = "roblox")]
+const INVENTORY_CACHE_SECONDS: u64 = 10 * 60;
+/// Usernames and profiles rarely change, so lookups are reused for a while.
+const USER_CACHE_SECONDS: u64 = 10 * 60;
+/// Kept short so a buyer who fixes their pass's price can verify it again soon.
+#[cfg(feature = "roblox")]
+const GAMEPASS_CACHE_SECONDS: u64 = 30;
+#[cfg(feature = "orders")]
+const GROUPS_URL: &str = "https://groups.roblox.com/v1/groups";
+#[cfg(feature = "orders")]
+const ECONOMY_URL: &str = "https://economy.roblox.com/v2/groups";
+#[cfg(feature = "roblox")]
+const ECONOMY_V1_URL: &str = "https://economy.roblox.com/v1/groups";
+const CSRF_HEADER: &str = "x-csrf-token";
+/// Recent payouts searched for the one just sent, to record its transaction id.
+#[cfg(feature = "orders")]
+const TRANSACTION_LOOKUP_LIMIT: u32 = 10;
+/// How long a fetched group balance is shown before it's fetched again.
+#[cfg(feature = "roblox")]
+const FUNDS_CACHE_SECONDS: u64 = 60;
+
+#[derive(Deserialize)]
+struct UsersResponse {
+    data: Vec<UserEntry>,
+}
+
+#[derive(Deserialize)]
+struct UserEntry {
+    id: u64,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct ProfileResponse {
+    #[cfg(feature = "roblox")]
+    id: u64,
+    #[cfg(feature = "roblox")]
+    name: String,
+    #[cfg(feature = "roblox")]
+    #[serde(default)]
+    description: String,
+    #[cfg(feature = "orders")]
+    created: String,
+}
+
+#[cfg(feature = "orders")]
+#[derive(Deserialize)]
+struct CountResponse {
+    count: u64,
+}
+
+#[cfg(feature = "orders")]
+#[derive(Deserialize)]
+struct BadgesResponse {
+    data: Vec<Value>,
+}
+
+#[cfg(feature = "roblox")]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CollectiblesResponse {
+    next_page_cursor: Option<String>,
+    data: Vec<CollectibleEntry>,
+}
+
+#[cfg(feature = "roblox")]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CollectibleEntry {
+    asset_id: u64,
+    name: String,
+    recent_average_price: Option<u64>,
+}
+
+#[cfg(feature = "roblox")]
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GamepassResponse {
+    name: String,
+    creator: GamepassCreator,
+    price_in_robux: Option<u64>,
+    is_for_sale: bool,
+}
+
+#[cfg(feature = "roblox")]
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GamepassCreator {
+    creator_type: String,
+    creator_target_id: u64,
+}
+
+#[cfg(feature = "roblox")]
+#[derive(Deserialize)]
+struct CurrencyResponse {
+    robux: u64,
+}
+
+#[cfg(feature = "roblox")]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RevenueSummary {
+    pending_robux: u64,
+}
+
+/// The group's Robux, as shown by `/groupfunds`.
+#[cfg(feature = "roblox")]
+#[derive(Clone, Copy, Debug)]
+pub struct GroupFunds {
+    /// Robux that can be paid out now.
+    pub robux: u64,
+    /// Robux from recent sales that Roblox is still holding.
+    pub pending: u64,
+    /// Unix timestamp of the fetch.
+    pub fetched_at: u64,
+}
+
+/// A Roblox account looked up by username.
+#[derive(Clone, Debug)]
+pub struct RobloxUser {
+    pub id: u64,
+    /// The username with Roblox's capitalization.
+    pub name: String,
+}
+
+/// A Roblox account's public profile.
+#[derive(Clone, Debug)]
+pub struct RobloxProfile {
+    #[cfg(feature = "roblox")]
+    pub id: u64,
+    #[cfg(feature = "roblox")]
+    pub name: String,
+    /// The account's "About" blurb.
+    #[cfg(feature = "roblox")]
+    pub description: String,
+    /// Unix timestamp of the account's creation, if Roblox's date could be read.
+    #[cfg(feature = "orders")]
+    pub created_at: Option<u64>,
+}
+
+/// Looks up the account with `username`, if there is one, reusing a lookup from
+/// the last ten minutes.
+pub async fn user_by_name(username: &str) -> Result<Option<RobloxUser>, String> {
+    static CACHE: OnceLock<TtlCache<String, Option<RobloxUser>>> = OnceLock::new();
+    CACHE
+        .get_or_init(|| TtlCache::new(USER_CACHE_SECONDS))
+        .get_or_fetch(username.to_lowercase(), fetch_user_by_name(username))
+        .await
+}
+
+async fn fetch_user_by_name(username: &str) -> Result<Option<RobloxUser>, String> {
+    let response: UsersResponse = client()
+        .post(USERNAMES_URL)
+        .json(&json!({ "usernames": [username], "excludeBannedUsers": true }))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Error fetching Roblox user: {:?}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Error parsing Roblox user: {:?}", e))?;
+
+    Ok(response.data.into_iter().next().map(|user| RobloxUser {
+        id: user.id,
+        name: user.name,
+    }))
+}
+
+/// The public profile of the account with `user_id`, reusing a fetch from the
+/// last ten minutes.
+pub async fn profile(user_id: u64) -> Result<RobloxProfile, String> {
+    static CACHE: OnceLock<TtlCache<u64, RobloxProfile>> = OnceLock::new();
+    CACHE
+        .get_or_init(|| TtlCache::new(USER_CACHE_SECONDS))
+        .get_or_fetch(user_id, fetch_profile(user_id))
+        .await
+}
+
+async fn fetch_profile(user_id: u64) -> Result<RobloxProfile, String> {
+    let response: ProfileResponse = client()
+        .get(format!("{}/{}", USERS_URL, user_id))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Error fetching Roblox profile: {:?}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Error parsing Roblox profile: {:?}", e))?;
+
+    Ok(RobloxProfile {
+        #[cfg(feature = "roblox")]
+        id: response.id,
+        #[cfg(feature = "roblox")]
+        name: response.name,
+        #[cfg(feature = "roblox")]
+        description: response.description,
+        #[cfg(feature = "orders")]
+        created_at: risk::parse_timestamp(&response.created),
+    })
+}
+
+#[cfg(feature = "orders")]
+pub async fn friend_count(user_id: u64) -> Result<u64, String> {
+    let response: CountResponse = client()
+        .get(format!("{}/{}/friends/count", FRIENDS_URL, user_id))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Error fetching Roblox friend count: {:?}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Error parsing Roblox friend count: {:?}", e))?;
+    Ok(response.count)
+}
+
+/// How many badges the account has, counting no more than [`BADGE_PAGE_SIZE`].
+#[cfg(feature = "orders")]
+pub async fn badge_count(user_id: u64) -> Result<u64, String> {
+    let response: BadgesResponse = client()
+        .get(format!(
+            "{}/{}/badges?limit={}&sortOrder=Desc",
+            BADGES_URL, user_id, BADGE_PAGE_SIZE
+        ))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Error fetching Roblox badges: {:?}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Error parsing Roblox badges: {:?}", e))?;
+    Ok(response.data.len() as u64)
+}
+
+/// The account's age, friends and badges, for flagging risky buyers.
+#[cfg(feature = "orders")]
+pub async fn account_signals(user_id: u64) -> Result<AccountSignals, String> {
+    let (profile, friends, badges) = tokio::try_join!(
+        profile(user_id),
+        friend_count(user_id),
+        badge_count(user_id)
+    )?;
+    Ok(AccountSignals {
+        created_at: profile
+            .created_at
+            .ok_or("Error parsing Roblox account creation date")?,
+        friends,
+        badges,
+    })
+}
+
+/// A gamepass as sold on Roblox.
+#[cfg(feature = "roblox")]
+#[derive(Clone, Debug)]
+pub struct Gamepass {
+    pub id: u64,
+    pub name: String,
+    /// The user who made it, or `None` if a group did.
+    pub creator_user_id: Option<u64>,
+    /// The price in Robux, if the pass is for sale.
+    pub price: Option<u64>,
+}
+
+/// Looks up the gamepass with `id`, if there is one, reusing a lookup from the
+/// last 30 seconds.
+#[cfg(feature = "roblox")]
+pub async fn gamepass(id: u64) -> Result<Option<Gamepass>, String> {
+    static CACHE: OnceLock<TtlCache<u64, Option<Gamepass>>> = OnceLock::new();
+    CACHE
+        .get_or_init(|| TtlCache::new(GAMEPASS_CACHE_SECONDS))
+        .get_or_fetch(id, fetch_gamepass(id))
+        .await
+}
+
+#[cfg(feature = "roblox")]
+async fn fetch_gamepass(id: u64) -> Result<Option<Gamepass>, String> {
+    let response = client()
+        .get(format!("{}/{}/product-info", GAMEPASS_URL, id))
+        .send()
+        .await
+        .map_err(|e| format!("Error fetching gamepass: {:?}", e))?;
+    if matches!(
+        response.status(),
+        StatusCode::NOT_FOUND | StatusCode::BAD_REQUEST
+    ) {
+        return Ok(None);
+    }
+    let response: GamepassResponse = response
+        .error_for_status()
+        .map_err(|e| format!("Error fetching gamepass: {:?}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Error parsing gamepass: {:?}", e))?;
+
+    Ok(Some(Gamepass {
+        id,
+        name: response.name,
+        creator_user_id: (response.creator.creator_type == "User")
+            .then_some(response.creator.creator_target_id),
+        price: response.price_in_robux.filter(|_| response.is_for_sale),
+    }))
+}
+
+/// A limited in someone's inventory.
+#[cfg(feature = "roblox")]
+#[derive(Clone, Debug)]
+pub struct Collectible {
+    pub asset_id: u64,
+    pub name: String,
+    /// Recent average price in Robux; 0 for items that haven't sold recently.
+    pub rap: u64,
+}
+
+/// The limiteds in a user's public inventory, as summed by `/rap`.
+#[cfg(feature = "roblox")]
+#[derive(Clone, Debug)]
+pub struct Inventory {
+    pub items: Vec<Collectible>,
+    /// Whether the inventory had more than [`MAX_INVENTORY_PAGES`] pages, so
+    /// `items` is only part of it.
+    pub truncated: bool,
+}
+
+#[cfg(feature = "roblox")]
+impl Inventory {
+    pub fn total_rap(&self) -> u64 {
+        self.items.iter().map(|item| item.rap).sum()
+    }
+}
+
+/// The limiteds in `user_id`'s inventory, reusing a fetch from the last ten
+/// minutes. `None` if the user hides their inventory.
+#[cfg(feature = "roblox")]
+pub async fn inventory(user_id: u64) -> Result<Option<Inventory>, String> {
+    static CACHE: OnceLock<TtlCache<u64, Option<Inventory>>> = OnceLock::new();
+    CACHE
+        .get_or_init(|| TtlCache::new(INVENTORY_CACHE_SECONDS))
+        .get_or_fetch(user_id, fetch_inventory(user_id))
+        .await
+}
+
+#[cfg(feature = "roblox")]
+async fn fetch_inventory(user_id: u64) -> Result<Option<Inventory>, String> {
+    let mut items = Vec::new();
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_INVENTORY_PAGES {
+        let mut url = format!(
+            "{}/{}/assets/collectibles?limit=100&sortOrder=Asc",
+            INVENTORY_URL, user_id
+        );
+        if let Some(cursor) = &cursor {
+            url.push_str(&format!("&cursor={}", cursor));
+        }
+        let response = client()
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| format!("Error fetching Roblox inventory: {:?}", e))?;
+        if response.status() == StatusCode::FORBIDDEN {
+            return Ok(None);
+        }
+        let response: CollectiblesResponse = response
+            .error_for_status()
+            .map_err(|e| format!("Error fetching Roblox inventory: {:?}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Error parsing Roblox inventory: {:?}", e))?;
+        items.extend(response.data.into_iter().map(|entry| Collectible {
+            asset_id: entry.asset_id,
+            name: entry.name,
+            rap: entry.recent_average_price.unwrap_or(0),
+        }));
+        cursor = response.next_page_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    Ok(Some(Inventory {
+        items,
+        truncated: cursor.is_some(),
+    }))
+}
+
+/// The client for Roblox's public APIs, which work without `ROBLOX_COOKIE`.
+fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_default()
+    })
+}
+
+/// A Roblox web API client signed in with the `.ROBLOSECURITY` cookie from
+/// `ROBLOX_COOKIE`, acting for the group in `ROBLOX_GROUP_ID`. The account needs
+/// permission to spend the group's funds.
+pub struct Roblox {
+    client: reqwest::Client,
+    cookie: String,
+    pub group_id: u64,
+    /// Roblox requires a CSRF token on writes, handed out by rejecting a request
+    /// without one.
+    csrf_token: Mutex<Option<HeaderValue>>,
+    #[cfg(feature = "roblox")]
+    funds: Mutex<Option<GroupFunds>>,
+}
+
+impl Roblox {
+    pub fn new(cookie: String, group_id: u64) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            cookie,
+            group_id,
+            csrf_token: Mutex::new(None),
+            #[cfg(feature = "roblox")]
+            funds: Mutex::new(None),
+        }
+    }
+
+    /// The group's balance and pending Robux, fetched at most once a minute.
+    /// `None` if Roblox refused the cookie, e.g. because it expired or the account
+    /// can't see the group's funds.
+    #[cfg(feature = "roblox")]
+    pub async fn group_funds(&self) -> Result<Option<GroupFunds>, String> {
+        let mut cached = self.funds.lock().await;
+        if let Some(funds) = *cached {
+            if rates::now().saturating_sub(funds.fetched_at) < FUNDS_CACHE_SECONDS {
+                return Ok(Some(funds));
+            }
+        }
+
+        let currency = self
+            .send(
+                Method::GET,
+                &format!("{}/{}/currency", ECONOMY_V1_URL, self.group_id),
+                None,
+            )
+            .await
+            .map_err(|e| format!("Error fetching group funds: {:?}", e))?;
+        if matches!(
+            currency.status(),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        ) {
+            return Ok(None);
+        }
+        let currency: CurrencyResponse = currency
+            .error_for_status()
+            .map_err(|e| format!("Error fetching group funds: {:?}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Error parsing group funds: {:?}", e))?;
+        let summary: RevenueSummary = self
+            .send(
+                Method::GET,
+                &format!("{}/{}/revenue/summary/day", ECONOMY_V1_URL, self.group_id),
+                None,
+            )
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Error fetching pending group funds: {:?}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Error parsing pending group funds: {:?}", e))?;
+
+        let funds = GroupFunds {
+            robux: currency.robux,
+            pending: summary.pending_robux,
+            fetched_at: rates::now(),
+        };
+        *cached = Some(funds);
+        Ok(Some(funds))
+    }
+
+    /// Pays `robux` from the group's funds to `user_id`, returning the payout's
+    /// transaction id if it shows up in the group's recent transactions.
+    #[cfg(feature = "orders")]
+    pub async fn pay_out(&self, user_id: u64, robux: u64) -> Result<Option<String>, String> {
+        let url = format!("{}/{}/payouts", GROUPS_URL, self.group_id);
+        let body = json!({
+            "PayoutType": "FixedAmount",
+            "Recipients": [{
+                "recipientId": user_id,
+                "recipientType": "User",
+                "amount": robux,
+            }],
+        });
+        let response = self
+            .send(Method::POST, &url, Some(&body))
+            .await
+            .map_err(|e| format!("Error sending Roblox payout: {:?}", e))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "Error sending Roblox payout: {}",
+                error_message(response).await
+            ));
+        }
+
+        Ok(self.find_payout_transaction(user_id, robux).await)
+    }
+
+    /// The id of the most recent payout of `robux` to `user_id`, if any.
+    #[cfg(feature = "orders")]
+    async fn find_payout_transaction(&self, user_id: u64, robux: u64) -> Option<String> {
+        let url = format!(
+            "{}/{}/transactions?limit={}&sortOrder=Desc&transactionType=Payout",
+            ECONOMY_URL, self.group_id, TRANSACTION_LOOKUP_LIMIT
+        );
+        let transactions: Value = self
+            .send(Method::GET, &url, None)
+            .await
+            .ok()?
+            .json()
+            .await
+            .ok()?;
+        transactions["data"]
+            .as_array()?
+            .iter()
+            .find(|transaction| {
+                transaction["agent"]["id"].as_u64() == Some(user_id)
+                    && transaction["currency"]["amount"]
+                        .as_i64()
+                        .map(i64::unsigned_abs)
+                        == Some(robux)
+            })
+            .and_then(|transaction| {
+                transaction["idHash"]
+                    .as_str()
+                    .map(str::to_string)
+                    .or_else(|| transaction["id"].as_u64().map(|id| id.to_string()))
+            })
+    }
+
+    /// Sends an authenticated request, fetching a new CSRF token and retrying
+    /// once if Roblox rejects the cached one.
+    async fn send(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&Value>,
+    ) -> Result<Response, reqwest::Error> {
+        let request = |token: Option<HeaderValue>| {
+            let mut request: RequestBuilder = self
+                .client
+                .request(method.clone(), url)
+                .header("Cookie", format!(".ROBLOSECURITY={}", self.cookie));
+            if let Some(token) = token {
+                request = request.header(CSRF_HEADER, token);
+            }
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+            request
+        };
+
+        let token = self.csrf_token.lock().await.clone();
+        let response = request(token).send().await?;
+        if response.status() != StatusCode::FORBIDDEN {
+            return Ok(response);
+        }
+        let Some(token) = response.headers().get(CSRF_HEADER).cloned() else {
+            return Ok(response);
+        };
+        *self.csrf_token.lock().await = Some(token.clone());
+        request(Some(token)).send().await
+    }
+}
+
+/// The first error message in a Roblox error response, or its status.
+#[cfg(feature = "orders")]
+async fn error_message(response: Response) -> String {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or_default();
+    body["errors"][0]["message"]
+        .as_str()
+        .map_or_else(|| status.to_string(), str::to_string)
+}
@@ -0,0 +1,193 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::HashMap, sync::OnceLock, time::Duration};
+use tokio::sync::Mutex;
+
+use crate::rates;
+
+const ITEM_DETAILS_URL: &str = "https://api.rolimons.com/items/v1/itemdetails";
+/// The item list is around a megabyte and Rolimons rate limits it, so it's
+/// fetched at most this often.
+const CACHE_SECONDS: u64 = 10 * 60;
+
+#[derive(Deserialize)]
+struct ItemDetailsResponse {
+    /// Item id to `[name, acronym, rap, value, default value, demand, trend,
+    /// projected, hyped, rare]`.
+    items: HashMap<String, Vec<Value>>,
+}
+
+/// A limited item as listed on Rolimons.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LimitedItem {
+    pub id: u64,
+    pub name: String,
+    pub acronym: Option<String>,
+    /// Recent average price in Robux.
+    pub rap: u64,
+    /// Rolimons' value in Robux, for items they've valued.
+    pub value: Option<u64>,
+}
+
+impl LimitedItem {
+    pub fn url(&self) -> String {
+        format!("https://www.rolimons.com/item/{}", self.id)
+    }
+
+    /// What the item is worth for trading: its value if it has one, else its RAP.
+    pub fn worth(&self) -> u64 {
+        self.value.unwrap_or(self.rap)
+    }
+}
+
+struct Catalog {
+    items: Vec<LimitedItem>,
+    fetched_at: u64,
+}
+
+/// Finds the limited matching `query` by id, acronym or name, fetching the item
+/// list from Rolimons if the cached one is out of date.
+pub async fn find_item(query: &str) -> Result<Option<LimitedItem>, String> {
+    static CATALOG: OnceLock<Mutex<Option<Catalog>>> = OnceLock::new();
+    let mut catalog = CATALOG.get_or_init(|| Mutex::new(None)).lock().await;
+    let now = rates::now();
+    if catalog
+        .as_ref()
+        .is_none_or(|catalog| now.saturating_sub(catalog.fetched_at) >= CACHE_SECONDS)
+    {
+        *catalog = Some(Catalog {
+            items: fetch_items().await?,
+            fetched_at: now,
+        });
+    }
+    Ok(catalog
+        .as_ref()
+        .and_then(|catalog| find(&catalog.items, query))
+        .cloned())
+}
+
+async fn fetch_items() -> Result<Vec<LimitedItem>, String> {
+    let response: ItemDetailsResponse = client()
+        .get(ITEM_DETAILS_URL)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Error fetching Rolimons items: {:?}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Error parsing Rolimons items: {:?}", e))?;
+
+    Ok(response
+        .items
+        .into_iter()
+        .filter_map(|(id, details)| parse_item(&id, &details))
+        .collect())
+}
+
+fn parse_item(id: &str, details: &[Value]) -> Option<LimitedItem> {
+    let acronym = details.get(1)?.as_str()?;
+    Some(LimitedItem {
+        id: id.parse().ok()?,
+        name: details.first()?.as_str()?.to_string(),
+        acronym: (!acronym.is_empty()).then(|| acronym.to_string()),
+        rap: details.get(2)?.as_i64()?.max(0) as u64,
+        // Rolimons uses -1 for items without a value.
+        value: details
+            .get(3)?
+            .as_i64()
+            .filter(|value| *value >= 0)
+            .map(|value| value as u64),
+    })
+}
+
+/// The item whose id, acronym or name is `query`, ignoring case, or failing that
+/// the highest-RAP item whose name contains it.
+fn find<'a>(items: &'a [LimitedItem], query: &str) -> Option<&'a LimitedItem> {
+    let query = query.trim().to_lowercase();
+    if let Ok(id) = query.parse::<u64>() {
+        if let Some(item) = items.iter().find(|item| item.id == id) {
+            return Some(item);
+        }
+    }
+    items
+        .iter()
+        .find(|item| {
+            item.name.to_lowercase() == query
+                || item
+                    .acronym
+                    .as_ref()
+                    .is_some_and(|acronym| acronym.to_lowercase() == query)
+        })
+        .or_else(|| {
+            items
+                .iter()
+                .filter(|item| item.name.to_lowercase().contains(&query))
+                .max_by_key(|item| item.rap)
+        })
+}
+
+fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .timeout(Duration::from_secs(20))
+            .build()
+            .unwrap_or_default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn items() -> Vec<LimitedItem> {
+        vec![
+            parse_item(
+                "1365767",
+                &[
+                    json!("Valkyrie Helm"),
+                    json!("VH"),
+                    json!(120000),
+                    json!(125000),
+                ],
+            )
+            .unwrap(),
+            parse_item(
+                "20573078",
+                &[json!("Shaggy"), json!(""), json!(9000), json!(-1)],
+            )
+            .unwrap(),
+            parse_item(
+                "1029025",
+                &[
+                    json!("The Classic ROBLOX Fedora"),
+                    json!(""),
+                    json!(15000),
+                    json!(-1),
+                ],
+            )
+            .unwrap(),
+        ]
+    }
+
+    #[test]
+    fn parses_rolimons_items() {
+        let items = items();
+        assert_eq!(items[0].acronym.as_deref(), Some("VH"));
+        assert_eq!(items[0].worth(), 125000);
+        assert_eq!(items[1].acronym, None);
+        assert_eq!(items[1].value, None);
+        assert_eq!(items[1].worth(), 9000);
+    }
+
+    #[test]
+    fn finds_items_by_id_acronym_and_name() {
+        let items = items();
+        assert_eq!(find(&items, "20573078").unwrap().name, "Shaggy");
+        assert_eq!(find(&items, "vh").unwrap().id, 1365767);
+        assert_eq!(find(&items, "shaggy").unwrap().id, 20573078);
+        assert_eq!(find(&items, "fedora").unwrap().id, 1029025);
+        assert!(find(&items, "dominus").is_none());
+    }
+}
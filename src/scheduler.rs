@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use serenity::{client::Context, prelude::TypeMapKey};
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+use tokio::{sync::watch, task::JoinHandle};
+
+/// How far each wait between runs is moved either way, as a fraction of the
+/// period, so jobs started together don't keep waking on the same second.
+const JITTER: f64 = 0.1;
+
+/// Periodic background work. Stateless jobs can be plain
+/// `async fn(&Context)`s; ones that keep state between runs implement this.
+#[async_trait]
+pub trait Job: Send + 'static {
+    async fn run(&mut self, ctx: &Context);
+}
+
+/// A job called with a context borrowed for `'a`, so an `async fn`'s future can
+/// borrow it too.
+pub trait JobFn<'a>: Send + 'static {
+    type Future: Future<Output = ()> + Send + 'a;
+
+    fn call(&self, ctx: &'a Context) -> Self::Future;
+}
+
+impl<'a, F, Fut> JobFn<'a> for F
+where
+    F: Fn(&'a Context) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'a,
+{
+    type Future = Fut;
+
+    fn call(&self, ctx: &'a Context) -> Fut {
+        self(ctx)
+    }
+}
+
+#[async_trait]
+impl<F> Job for F
+where
+    F: for<'a> JobFn<'a>,
+{
+    async fn run(&mut self, ctx: &Context) {
+        self.call(ctx).await
+    }
+}
+
+/// Runs registered jobs in their own tasks until [`Scheduler::shutdown`].
+pub struct Scheduler {
+    ctx: Context,
+    shutdown: watch::Sender<bool>,
+    tasks: Mutex<Vec<(&'static str, JoinHandle<()>)>>,
+}
+
+impl TypeMapKey for Scheduler {
+    type Value = Arc<Scheduler>;
+}
+
+impl Scheduler {
+    pub fn new(ctx: Context) -> Self {
+        Self {
+            ctx,
+            shutdown: watch::channel(false).0,
+            tasks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Runs `job` now and then about every `period`, never two runs at once.
+    pub fn every(&self, name: &'static str, period: Duration, mut job: impl Job) {
+        let ctx = self.ctx.clone();
+        let mut shutdown = self.shutdown.subscribe();
+        let task = tokio::spawn(async move {
+            let mut wait = Duration::ZERO;
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(wait) => {}
+                    _ = shutdown.changed() => return,
+                }
+                job.run(&ctx).await;
+                wait = jittered(period, random_seed());
+            }
+        });
+        self.tasks.lock().unwrap().push((name, task));
+    }
+
+    /// Stops every job, letting any that are running finish first.
+    pub async fn shutdown(&self) {
+        self.shutdown.send_replace(true);
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap());
+        for (name, task) in tasks {
+            if let Err(error) = task.await {
+                eprintln!("Background job {} panicked: {}", name, error);
+            }
+        }
+    }
+}
+
+/// `period` moved by up to [`JITTER`] of it either way, picked by `seed`.
+fn jittered(period: Duration, seed: u32) -> Duration {
+    let offset = (f64::from(seed) / f64::from(u32::MAX) * 2.0 - 1.0) * JITTER;
+    period.mul_f64(1.0 + offset)
+}
+
+/// Good enough to spread jobs out; nothing depends on it being unpredictable.
+fn random_seed() -> u32 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |elapsed| {
+            elapsed.subsec_nanos().wrapping_mul(2_654_435_761)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jitters_within_a_tenth_of_the_period() {
+        let minute = Duration::from_secs(60);
+        assert_eq!(jittered(minute, 0), Duration::from_secs(54));
+        assert_eq!(jittered(minute, u32::MAX), Duration::from_secs(66));
+        let middle = jittered(minute, u32::MAX / 2);
+        assert!(middle > Duration::from_millis(59_990) && middle < Duration::from_millis(60_010));
+    }
+}
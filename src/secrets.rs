@@ -0,0 +1,23 @@
+#[cfg(feature = "api")]
+use ring::digest;
+use ring::rand::{SecureRandom, SystemRandom};
+
+/// 32 random bytes as hex, for webhook secrets and API keys.
+pub fn generate() -> Result<String, String> {
+    let mut bytes = [0u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| "Error generating secret".to_string())?;
+    Ok(hex(&bytes))
+}
+
+/// Hex SHA-256 of `secret`, so keys can be stored and looked up without keeping
+/// them in plain text.
+#[cfg(feature = "api")]
+pub fn sha256(secret: &str) -> String {
+    hex(digest::digest(&digest::SHA256, secret.as_bytes()).as_ref())
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -0,0 +1,28 @@
+use crate::{payments::PaymentHandle, tiers::Tier};
+
+/// A staff member who sells at their own rate, registered with `/seller register`.
+/// Their stock is the same per-seller stock `/stock` tracks.
+#[derive(Clone, Debug)]
+pub struct Seller {
+    pub user_id: u64,
+    /// GBP per 1,000 Robux, like a tier's rate.
+    pub rate: f64,
+    /// Where buyers pay this seller. At most one handle per method; the guild's
+    /// handles are used when there are none.
+    pub payment_handles: Vec<PaymentHandle>,
+}
+
+impl Seller {
+    /// The seller's rate as a single tier, so it prices like a guild's tiers.
+    pub fn tiers(&self) -> [Tier; 1] {
+        [Tier {
+            min_robux: 0,
+            rate: self.rate,
+        }]
+    }
+}
+
+/// Returns the registered seller with `user_id`.
+pub fn find(sellers: &[Seller], user_id: u64) -> Option<&Seller> {
+    sellers.iter().find(|seller| seller.user_id == user_id)
+}
@@ -0,0 +1,198 @@
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+
+#[cfg(feature = "orders")]
+use crate::orders::Order;
+use crate::rates;
+
+const SCOPE: &str = "https://www.googleapis.com/auth/spreadsheets";
+const API_URL: &str = "https://sheets.googleapis.com/v4/spreadsheets";
+
+/// Tab completed orders are appended to unless the guild picks another.
+pub const DEFAULT_SHEET: &str = "Orders";
+
+/// Column headings matching [`order_row`].
+const HEADERS: [&str; 11] = [
+    "Order",
+    "Completed",
+    "Buyer",
+    "Seller",
+    "Robux",
+    "Type",
+    "Method",
+    "GBP",
+    "USD",
+    "VAT",
+    "Rate",
+];
+
+/// The spreadsheet a guild's completed orders are appended to.
+#[derive(Clone, Debug)]
+pub struct SheetSettings {
+    pub spreadsheet_id: String,
+    /// Tab name within the spreadsheet.
+    pub sheet: String,
+}
+
+/// The fields of a Google service account key file that are needed to sign in.
+#[derive(Deserialize)]
+struct ServiceAccount {
+    client_email: String,
+    private_key: String,
+    token_uri: String,
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+/// A Sheets API client signed in as the service account from
+/// `GOOGLE_SERVICE_ACCOUNT_FILE`.
+pub struct Sheets {
+    client: reqwest::Client,
+    account: ServiceAccount,
+    key: EncodingKey,
+    /// The current access token and when it expires.
+    token: Mutex<Option<(String, u64)>>,
+}
+
+impl Sheets {
+    /// Reads a service account key file as downloaded from the Google Cloud console.
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Error reading service account file {}: {}", path, e))?;
+        let account: ServiceAccount = serde_json::from_str(&contents)
+            .map_err(|e| format!("Error parsing service account file {}: {}", path, e))?;
+        let key = EncodingKey::from_rsa_pem(account.private_key.as_bytes())
+            .map_err(|e| format!("Invalid service account private key: {}", e))?;
+
+        Ok(Self {
+            client: reqwest::Client::new(),
+            account,
+            key,
+            token: Mutex::new(None),
+        })
+    }
+
+    /// The address sellers share their spreadsheet with.
+    pub fn client_email(&self) -> &str {
+        &self.account.client_email
+    }
+
+    /// Returns a cached access token, exchanging a freshly signed JWT for a new
+    /// one when it's within a minute of expiring.
+    async fn access_token(&self) -> Result<String, String> {
+        let mut token = self.token.lock().await;
+        let now = rates::now();
+        if let Some((access_token, expires_at)) = token.as_ref() {
+            if *expires_at > now + 60 {
+                return Ok(access_token.clone());
+            }
+        }
+
+        let claims = Claims {
+            iss: &self.account.client_email,
+            scope: SCOPE,
+            aud: &self.account.token_uri,
+            iat: now,
+            exp: now + 3600,
+        };
+        let assertion = jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &self.key)
+            .map_err(|e| format!("Error signing Google token request: {}", e))?;
+        let response: TokenResponse = self
+            .client
+            .post(&self.account.token_uri)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", &assertion),
+            ])
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Error fetching Google access token: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Error parsing Google access token: {}", e))?;
+
+        *token = Some((response.access_token.clone(), now + response.expires_in));
+        Ok(response.access_token)
+    }
+
+    /// Appends `row` after the last row of the guild's sheet.
+    pub async fn append_row(
+        &self,
+        settings: &SheetSettings,
+        row: Vec<Value>,
+    ) -> Result<(), String> {
+        let mut url = reqwest::Url::parse(API_URL).map_err(|e| e.to_string())?;
+        url.path_segments_mut()
+            .map_err(|_| "Invalid Sheets API URL".to_string())?
+            .push(&settings.spreadsheet_id)
+            .push("values")
+            .push(&format!("'{}':append", settings.sheet.replace('\'', "''")));
+        url.query_pairs_mut()
+            .append_pair("valueInputOption", "USER_ENTERED")
+            .append_pair("insertDataOption", "INSERT_ROWS");
+
+        self.client
+            .post(url)
+            .bearer_auth(self.access_token().await?)
+            .json(&json!({ "values": [row] }))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Error appending to Google Sheet: {}", e))?;
+        Ok(())
+    }
+}
+
+/// [`HEADERS`] as a sheet row, for sellers setting up a new sheet.
+pub fn header_row() -> Vec<Value> {
+    HEADERS.iter().map(|header| json!(header)).collect()
+}
+
+/// A completed order as a sheet row, in [`HEADERS`] order. Ids are prefixed with
+/// `'` so Sheets keeps them as text instead of rounding them.
+#[cfg(feature = "orders")]
+pub fn order_row(order: &Order, seller_id: u64, completed_at: u64) -> Vec<Value> {
+    vec![
+        json!(order.id),
+        json!(format!("=EPOCHTODATE({})", completed_at)),
+        json!(format!("'{}", order.buyer_id)),
+        json!(format!("'{}", seller_id)),
+        json!(order.robux),
+        json!(if order.after_tax { "a/t" } else { "b/t" }),
+        json!(order.method.code()),
+        json!((order.gbp * 100.0).round() / 100.0),
+        json!((order.usd * 100.0).round() / 100.0),
+        json!((order.tax_gbp * 100.0).round() / 100.0),
+        json!(order.rate),
+    ]
+}
+
+/// Accepts a spreadsheet URL or bare id and returns the id.
+pub fn parse_spreadsheet_id(input: &str) -> Option<String> {
+    let input = input.trim();
+    let id = match input.split_once("/d/") {
+        Some((_, rest)) => rest.split(['/', '?', '#']).next().unwrap_or_default(),
+        None => input,
+    };
+    ((20..=100).contains(&id.len())
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+    .then(|| id.to_string())
+}
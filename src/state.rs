@@ -0,0 +1,46 @@
+use serenity::{
+    client::bridge::gateway::ShardManager,
+    model::id::{ChannelId, GuildId},
+    prelude::{Mutex, RwLock, TypeMapKey},
+};
+use std::sync::Arc;
+
+#[cfg(any(feature = "orders", feature = "roblox"))]
+use crate::roblox::Roblox;
+use crate::{
+    botstats::BotStats, cache::SeenIds, rates::Rates, reply::Responder, sheets::Sheets,
+    storage::Storage,
+};
+
+/// Everything the handlers, background jobs and HTTP API share, set up once at
+/// startup and kept in the client's data.
+pub struct AppState {
+    pub storage: Storage,
+    pub rates: Arc<RwLock<Rates>>,
+    pub stats: Arc<BotStats>,
+    /// Interactions already handled, so one the gateway delivers again, such as
+    /// after a resume, isn't answered twice.
+    pub handled_interactions: SeenIds,
+    /// For the exchange rate and crypto price refreshes.
+    pub http: reqwest::Client,
+    pub responder: Arc<dyn Responder>,
+    /// The client's shard manager, for gateway latency and shard status. Missing
+    /// in tests, which don't connect to the gateway.
+    pub shard_manager: Option<Arc<Mutex<ShardManager>>>,
+    /// `GUILD_ID`, the guild commands are registered in at startup.
+    pub command_guild: Option<GuildId>,
+    /// `LOG_CHANNEL_ID`, where operator alerts are posted.
+    pub log_channel: Option<ChannelId>,
+    /// `BACKUP_CHANNEL_ID`, where `/backup` posts backups instead of the owner's
+    /// DMs.
+    pub backup_channel: Option<ChannelId>,
+    /// Set when `ROBLOX_COOKIE` is.
+    #[cfg(any(feature = "orders", feature = "roblox"))]
+    pub roblox: Option<Arc<Roblox>>,
+    /// Set when `GOOGLE_SERVICE_ACCOUNT_FILE` is.
+    pub sheets: Option<Arc<Sheets>>,
+}
+
+impl TypeMapKey for AppState {
+    type Value = Arc<AppState>;
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use crate::{disputes::Refund, orders::Order};
+
+/// How many buyers `/stats` lists as top customers.
+pub const TOP_CUSTOMERS: usize = 5;
+
+/// A buyer's completed orders within the period.
+#[derive(Clone, Debug)]
+pub struct Customer {
+    pub buyer_id: u64,
+    pub orders: u64,
+    pub gbp: f64,
+}
+
+/// Completed orders and refunds over a period, as shown by `/stats`.
+#[derive(Clone, Debug, Default)]
+pub struct Summary {
+    pub orders: u64,
+    pub robux: u64,
+    pub gbp: f64,
+    pub usd: f64,
+    /// VAT included in `gbp`.
+    pub tax_gbp: f64,
+    /// Refunds made in the period, whenever their orders were completed. They're
+    /// not taken out of `gbp` and `usd`; see [`Summary::net_gbp`].
+    pub refunds: u64,
+    pub refunded_gbp: f64,
+    pub refunded_usd: f64,
+    /// Highest spend first, at most [`TOP_CUSTOMERS`].
+    pub top_customers: Vec<Customer>,
+}
+
+impl Summary {
+    /// Revenue after refunds.
+    pub fn net_gbp(&self) -> f64 {
+        self.gbp - self.refunded_gbp
+    }
+
+    pub fn net_usd(&self) -> f64 {
+        self.usd - self.refunded_usd
+    }
+
+    pub fn average_robux(&self) -> f64 {
+        if self.orders == 0 {
+            0.0
+        } else {
+            self.robux as f64 / self.orders as f64
+        }
+    }
+
+    pub fn average_gbp(&self) -> f64 {
+        if self.orders == 0 {
+            0.0
+        } else {
+            self.gbp / self.orders as f64
+        }
+    }
+}
+
+pub fn summarize(orders: &[Order], refunds: &[Refund]) -> Summary {
+    let mut summary = Summary::default();
+    for refund in refunds {
+        summary.refunds += 1;
+        summary.refunded_gbp += refund.gbp;
+        summary.refunded_usd += refund.usd;
+    }
+    let mut customers: HashMap<u64, Customer> = HashMap::new();
+    for order in orders {
+        summary.orders += 1;
+        summary.robux += order.robux;
+        summary.gbp += order.gbp;
+        summary.usd += order.usd;
+        summary.tax_gbp += order.tax_gbp;
+
+        let customer = customers.entry(order.buyer_id).or_insert(Customer {
+            buyer_id: order.buyer_id,
+            orders: 0,
+            gbp: 0.0,
+        });
+        customer.orders += 1;
+        customer.gbp += order.gbp;
+    }
+
+    let mut customers: Vec<_> = customers.into_values().collect();
+    customers.sort_by(|a, b| b.gbp.total_cmp(&a.gbp).then(a.buyer_id.cmp(&b.buyer_id)));
+    customers.truncate(TOP_CUSTOMERS);
+    summary.top_customers = customers;
+    summary
+}
@@ -0,0 +1,515 @@
+mod postgres;
+mod sqlite;
+
+use async_trait::async_trait;
+use serenity::model::id::GuildId;
+use std::sync::Arc;
+
+#[cfg(any(feature = "orders", feature = "roblox"))]
+use crate::verification::RobloxLink;
+use crate::{
+    alerts::Alert,
+    announcements::RateAnnouncements,
+    audit::AuditEntry,
+    backup::Backup,
+    blacklist::BlacklistEntry,
+    coupons::Coupon,
+    disputes::Refund,
+    emojis::Emojis,
+    features::Feature,
+    history::Calculation,
+    i18n::Language,
+    loyalty::{Discount, Purchases, RoleDiscount, RoleReward},
+    numbers::Rounding,
+    orders::{Order, OrderLimits},
+    payments::PaymentHandle,
+    priceboard::PriceBoard,
+    pricing::ShopRates,
+    ratehistory::{RateChange, RateKind},
+    rates::RateSnapshot,
+    reminders::Reminder,
+    retention::{Forgotten, Retention},
+    sellers::Seller,
+    sheets::SheetSettings,
+    templates::EmbedTemplate,
+    theme::Theme,
+    tickets::TicketSettings,
+    tiers::Tier,
+    vat::VatSettings,
+    vouches::{Reputation, Vouch},
+    webhooks::Webhook,
+};
+#[cfg(feature = "orders")]
+use crate::{
+    disputes::Dispute,
+    middleman::MiddlemanProgress,
+    payouts::{Payout, PayoutHold, PayoutStatus},
+    queue::QueueEntry,
+};
+#[cfg(feature = "roblox")]
+use crate::{orders::GamepassCheck, verification::PendingVerification};
+
+/// Settings persisted for a single guild.
+#[derive(Clone, Debug, Default)]
+pub struct GuildConfig {
+    pub theme: Theme,
+    /// Shown instead of the plain Robux, currency and check symbols, set with
+    /// `/emojis`.
+    pub emojis: Emojis,
+    pub tiers: Vec<Tier>,
+    pub language: Option<Language>,
+    pub priceboard: Option<PriceBoard>,
+    pub tickets: TicketSettings,
+    /// Channel new vouches are posted to.
+    pub vouch_channel: Option<u64>,
+    /// Channel disputes and refunds are logged to.
+    pub dispute_channel: Option<u64>,
+    /// Channel staff changes are mirrored to, set with `/auditlog mirror`.
+    pub audit_channel: Option<u64>,
+    /// Sorted by `min_robux`.
+    pub loyalty_discounts: Vec<Discount>,
+    /// Roles given for lifetime purchases, sorted by `min_robux`.
+    pub role_rewards: Vec<RoleReward>,
+    /// Discounts for members with a role, at most one per role.
+    pub role_discounts: Vec<RoleDiscount>,
+    /// Rewordings of command replies set with `/template`, sorted by command.
+    pub templates: Vec<EmbedTemplate>,
+    /// At most one handle per method.
+    pub payment_handles: Vec<PaymentHandle>,
+    pub vat: Option<VatSettings>,
+    /// Where completed orders are appended, if the guild set up Google Sheets.
+    pub sheets: Option<SheetSettings>,
+    /// Order events are posted to each of these.
+    pub webhooks: Vec<Webhook>,
+    /// SHA-256 of the key the HTTP API accepts for this guild.
+    pub api_key_hash: Option<String>,
+    /// Channels where messages asking about Robux get an automatic price reply.
+    pub autoreply_channels: Vec<u64>,
+    /// Command groups turned off with `/features`.
+    pub disabled_features: Vec<Feature>,
+    /// Command groups that are off by default, turned on with `/features`.
+    pub enabled_features: Vec<Feature>,
+    /// Staff who get the weekly digest by DM, set with `/digest`.
+    pub digest_recipients: Vec<u64>,
+    /// Staff selling at their own rates, sorted by user id.
+    pub sellers: Vec<Seller>,
+    /// Where `/tier` changes are announced, set with `/announce`.
+    pub rate_announcements: Option<RateAnnouncements>,
+    pub order_limits: OrderLimits,
+    pub retention: Retention,
+    pub rounding: Rounding,
+    pub shop_rates: ShopRates,
+    /// USD per GBP set with `/setrate forex`, used instead of the market rate.
+    pub forex_override: Option<f64>,
+}
+
+/// Settings a user picks for themselves.
+#[derive(Clone, Debug, Default)]
+pub struct UserSettings {
+    /// Extra currency `/price` and `/robux` show, set with `/setcurrency`.
+    pub currency: Option<String>,
+}
+
+/// A change to a guild's settings, applied inside the backend's transaction.
+pub type GuildUpdate<'a> = Box<dyn FnOnce(&mut GuildConfig) + Send + 'a>;
+
+/// Returns the current rate for a pair such as `GBP/USD`.
+pub type RateLookup<'a> = &'a (dyn Fn(&str) -> Option<f64> + Send + Sync);
+
+/// Persistence for per-guild settings, calculation history, rate alerts, rate
+/// history, orders and the blacklist. Implemented for SQLite and PostgreSQL.
+#[async_trait]
+pub trait Store: Send + Sync {
+    /// Whether nothing has been stored yet: no guild settings, calculations or
+    /// alerts.
+    async fn is_empty(&self) -> Result<bool, String>;
+
+    async fn guild(&self, guild_id: GuildId) -> Result<GuildConfig, String>;
+
+    /// Returns the guild whose HTTP API key hashes to `key_hash`.
+    #[cfg(feature = "api")]
+    async fn guild_for_api_key(&self, key_hash: &str) -> Result<Option<GuildId>, String>;
+
+    /// Returns every guild that has a price board enabled.
+    async fn priceboards(&self) -> Result<Vec<(GuildId, PriceBoard)>, String>;
+
+    /// Applies `update` to the guild's settings and saves them in one transaction.
+    /// Callers use [`Store::update_guild`], which boxes the closure.
+    async fn apply_guild_update(
+        &self,
+        guild_id: GuildId,
+        update: GuildUpdate<'_>,
+    ) -> Result<(), String>;
+
+    /// Marks the guild's tier starting at `min_robux` deleted at `at`, returning
+    /// whether it existed.
+    async fn delete_tier(&self, guild_id: u64, min_robux: u64, at: u64) -> Result<bool, String>;
+
+    /// Brings back a tier deleted at or after `since`, returning whether one was.
+    /// A tier added at the same amount since then takes its place instead.
+    async fn restore_tier(&self, guild_id: u64, min_robux: u64, since: u64)
+        -> Result<bool, String>;
+
+    /// Removes tiers, coupons and orders deleted before `before` for good,
+    /// returning how many there were.
+    async fn purge_deleted(&self, before: u64) -> Result<u64, String>;
+
+    /// Deletes calculations and closed orders older at `now` than their guild's
+    /// retention allows, returning how many there were.
+    async fn purge_expired(&self, now: u64) -> Result<u64, String>;
+
+    /// Records `calculation`, trimming the user's oldest entries past the limit.
+    async fn record_calculation(&self, calculation: Calculation) -> Result<(), String>;
+
+    /// Returns the user's most recent calculations in `guild_id`, newest first.
+    async fn history(
+        &self,
+        guild_id: Option<u64>,
+        user_id: u64,
+        limit: usize,
+    ) -> Result<Vec<Calculation>, String>;
+
+    /// Every calculation made in the guild at or after `since`, oldest first.
+    async fn guild_calculations(
+        &self,
+        guild_id: u64,
+        since: u64,
+    ) -> Result<Vec<Calculation>, String>;
+
+    /// Stores `alert` under a freshly assigned id, which is returned.
+    async fn add_alert(&self, alert: Alert) -> Result<u64, String>;
+
+    async fn alerts_for_user(&self, user_id: u64) -> Result<Vec<Alert>, String>;
+
+    /// Removes the user's alert with `id`, returning whether one existed.
+    async fn remove_alert(&self, user_id: u64, id: u64) -> Result<bool, String>;
+
+    /// Removes and returns every alert crossed by the rates `rate_for` reports.
+    async fn take_alerts_triggered_by(
+        &self,
+        rate_for: RateLookup<'_>,
+    ) -> Result<Vec<Alert>, String>;
+
+    /// Records a fetched rate and drops snapshots older than the chart window.
+    async fn record_rate(&self, snapshot: RateSnapshot) -> Result<(), String>;
+
+    /// Rate snapshots taken at or after `since`, oldest first.
+    async fn rate_history(&self, since: u64) -> Result<Vec<RateSnapshot>, String>;
+
+    async fn record_rate_change(&self, change: RateChange) -> Result<(), String>;
+
+    /// The guild's most recent rate changes, newest first.
+    async fn rate_changes(&self, guild_id: u64, limit: u32) -> Result<Vec<RateChange>, String>;
+
+    /// When the guild last changed its `kind` rate, if it has since changes
+    /// were first recorded.
+    async fn rate_changed_at(&self, guild_id: u64, kind: RateKind) -> Result<Option<u64>, String>;
+
+    /// Stores `order` under a freshly assigned id, which is returned.
+    #[cfg(feature = "orders")]
+    async fn create_order(&self, order: Order) -> Result<u64, String>;
+
+    /// Marks the guild's order deleted at `at`, returning whether it existed.
+    #[cfg(feature = "orders")]
+    async fn delete_order(&self, guild_id: u64, id: u64, at: u64) -> Result<bool, String>;
+
+    /// Brings back an order deleted at or after `since`, returning whether one was.
+    async fn restore_order(&self, guild_id: u64, id: u64, since: u64) -> Result<bool, String>;
+
+    /// Returns the open order whose ticket is `channel_id`, if any.
+    #[cfg(any(feature = "orders", feature = "roblox"))]
+    async fn open_order_for_channel(&self, channel_id: u64) -> Result<Option<Order>, String>;
+
+    #[cfg(feature = "orders")]
+    async fn close_order(&self, id: u64, closed_at: u64) -> Result<(), String>;
+
+    /// Returns the buyer's most recent order in the guild completed by `seller_id`
+    /// that hasn't been vouched for yet.
+    async fn unvouched_order(
+        &self,
+        guild_id: u64,
+        buyer_id: u64,
+        seller_id: u64,
+    ) -> Result<Option<u64>, String>;
+
+    /// Stores `vouch` under a freshly assigned id, which is returned.
+    async fn add_vouch(&self, vouch: Vouch) -> Result<u64, String>;
+
+    /// Tallies the vouches `seller_id` has received in the guild.
+    async fn reputation(
+        &self,
+        guild_id: u64,
+        seller_id: u64,
+        recent: usize,
+    ) -> Result<Reputation, String>;
+
+    /// Marks the order delivered by `seller_id` and takes its Robux out of the
+    /// seller's stock, if they track any.
+    #[cfg(feature = "orders")]
+    async fn complete_order(
+        &self,
+        id: u64,
+        seller_id: u64,
+        completed_at: u64,
+    ) -> Result<(), String>;
+
+    /// Returns every seller's available Robux in the guild, largest first.
+    async fn stock(&self, guild_id: u64) -> Result<Vec<(u64, u64)>, String>;
+
+    #[cfg(feature = "orders")]
+    async fn set_stock(&self, guild_id: u64, seller_id: u64, robux: u64) -> Result<(), String>;
+
+    /// Adds `robux` to the seller's stock and returns the new total.
+    #[cfg(feature = "orders")]
+    async fn add_stock(&self, guild_id: u64, seller_id: u64, robux: u64) -> Result<u64, String>;
+
+    /// Blacklists the user, replacing any entry they already have in the same scope.
+    async fn add_blacklist_entry(&self, entry: BlacklistEntry) -> Result<(), String>;
+
+    /// Removes the user's entry for `guild_id`, or their global entry when it is
+    /// `None`, returning whether one existed.
+    async fn remove_blacklist_entry(
+        &self,
+        guild_id: Option<u64>,
+        user_id: u64,
+    ) -> Result<bool, String>;
+
+    /// Returns the user's global entry and their entry for `guild_id`, global first.
+    async fn blacklist_entries(
+        &self,
+        guild_id: Option<u64>,
+        user_id: u64,
+    ) -> Result<Vec<BlacklistEntry>, String>;
+
+    /// Totals the buyer's completed orders in the guild.
+    async fn purchases(&self, guild_id: u64, buyer_id: u64) -> Result<Purchases, String>;
+
+    /// The guild's orders completed at or after `since`, oldest first.
+    async fn completed_orders(&self, guild_id: u64, since: u64) -> Result<Vec<Order>, String>;
+
+    /// The guild's orders of any status created at or after `since`, oldest first.
+    async fn orders(&self, guild_id: u64, since: u64) -> Result<Vec<Order>, String>;
+
+    /// Stores `coupon`, returning `false` if the guild already has one with that code.
+    async fn create_coupon(&self, coupon: Coupon) -> Result<bool, String>;
+
+    /// Marks the guild's coupon with `code` deleted at `at`, returning whether
+    /// one existed. It's purged by [`Store::purge_deleted`] unless restored first.
+    async fn delete_coupon(&self, guild_id: u64, code: &str, at: u64) -> Result<bool, String>;
+
+    /// Brings back a coupon deleted at or after `since`, returning whether one was.
+    async fn restore_coupon(&self, guild_id: u64, code: &str, since: u64) -> Result<bool, String>;
+
+    /// Returns the guild's coupons in code order.
+    async fn coupons(&self, guild_id: u64) -> Result<Vec<Coupon>, String>;
+
+    async fn coupon(&self, guild_id: u64, code: &str) -> Result<Option<Coupon>, String>;
+
+    /// Uses up one redemption of the coupon if it hasn't expired or run out,
+    /// returning it with the updated count.
+    #[cfg(feature = "orders")]
+    async fn redeem_coupon(
+        &self,
+        guild_id: u64,
+        code: &str,
+        now: u64,
+    ) -> Result<Option<Coupon>, String>;
+
+    /// The user's settings, or the defaults if they never changed any.
+    async fn user_settings(&self, user_id: u64) -> Result<UserSettings, String>;
+
+    async fn save_user_settings(&self, user_id: u64, settings: &UserSettings)
+        -> Result<(), String>;
+
+    /// Deletes the user's calculations, settings and Roblox link, for `/forgetme`.
+    async fn forget_user(&self, user_id: u64) -> Result<Forgotten, String>;
+
+    /// Checks the database can still be reached, for the health check.
+    #[cfg(feature = "api")]
+    async fn ping(&self) -> Result<(), String>;
+
+    /// A consistent copy of every table, for `/backup`.
+    async fn backup(&self) -> Result<Backup, String>;
+
+    /// Queues a group payout, returning its id.
+    #[cfg(feature = "orders")]
+    async fn create_payout(&self, payout: Payout) -> Result<u64, String>;
+
+    #[cfg(feature = "orders")]
+    async fn payout(&self, id: u64) -> Result<Option<Payout>, String>;
+
+    /// Approves or rejects a pending payout, moving it to `status`. Returns false
+    /// if it was no longer pending, so a payout can't be approved twice.
+    #[cfg(feature = "orders")]
+    async fn review_payout(
+        &self,
+        id: u64,
+        status: PayoutStatus,
+        reviewed_by: u64,
+        reviewed_at: u64,
+    ) -> Result<bool, String>;
+
+    /// Records how sending an approved payout went.
+    #[cfg(feature = "orders")]
+    async fn finish_payout(
+        &self,
+        id: u64,
+        status: PayoutStatus,
+        transaction_id: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<(), String>;
+
+    #[cfg(any(feature = "orders", feature = "roblox"))]
+    async fn roblox_link(&self, user_id: u64) -> Result<Option<RobloxLink>, String>;
+
+    /// Links a user to a Roblox account, replacing any earlier link, and clears
+    /// their pending verification.
+    #[cfg(feature = "roblox")]
+    async fn save_roblox_link(&self, link: RobloxLink) -> Result<(), String>;
+
+    /// Starts a verification, replacing any the user already had pending.
+    #[cfg(feature = "roblox")]
+    async fn start_verification(&self, pending: PendingVerification) -> Result<(), String>;
+
+    #[cfg(feature = "roblox")]
+    async fn pending_verification(
+        &self,
+        user_id: u64,
+    ) -> Result<Option<PendingVerification>, String>;
+
+    #[cfg(feature = "roblox")]
+    async fn record_gamepass_check(&self, check: GamepassCheck) -> Result<(), String>;
+
+    #[cfg(feature = "orders")]
+    async fn create_payout_hold(&self, hold: PayoutHold) -> Result<(), String>;
+
+    /// Holds that ended by `now` without a ping, on orders that are still open.
+    #[cfg(feature = "orders")]
+    async fn due_payout_holds(&self, now: u64) -> Result<Vec<PayoutHold>, String>;
+
+    #[cfg(feature = "orders")]
+    async fn mark_payout_hold_notified(&self, order_id: u64, at: u64) -> Result<(), String>;
+
+    /// Stores `reminder` under a freshly assigned id, which is returned.
+    async fn add_reminder(&self, reminder: Reminder) -> Result<u64, String>;
+
+    async fn reminders_for_user(&self, user_id: u64) -> Result<Vec<Reminder>, String>;
+
+    /// Removes and returns every reminder due by `now`.
+    async fn take_due_reminders(&self, now: u64) -> Result<Vec<Reminder>, String>;
+
+    /// Returns the vouches left in the guild since `since`, oldest first.
+    async fn vouches(&self, guild_id: u64, since: u64) -> Result<Vec<Vouch>, String>;
+
+    /// Returns every guild where some staff turned on the weekly digest.
+    async fn digest_guilds(&self) -> Result<Vec<GuildId>, String>;
+
+    /// Records that the guild's digest for the week starting at `week_start` is
+    /// being sent. Returns false if it already was, so a restart doesn't send it
+    /// twice.
+    async fn claim_digest(&self, guild_id: u64, week_start: u64) -> Result<bool, String>;
+
+    /// Adds `entry` to the back of its guild's queue. Returns false if the user is
+    /// already in it.
+    #[cfg(feature = "orders")]
+    async fn join_queue(&self, entry: QueueEntry) -> Result<bool, String>;
+
+    /// Returns whether the user was in the guild's queue.
+    #[cfg(feature = "orders")]
+    async fn leave_queue(&self, guild_id: u64, user_id: u64) -> Result<bool, String>;
+
+    /// Returns the guild's queue, next in line first.
+    #[cfg(feature = "orders")]
+    async fn queue(&self, guild_id: u64) -> Result<Vec<QueueEntry>, String>;
+
+    /// Removes and returns whoever is next in the guild's queue.
+    #[cfg(feature = "orders")]
+    async fn pop_queue(&self, guild_id: u64) -> Result<Option<QueueEntry>, String>;
+
+    /// Records `dispute`, returning `false` if the order is already disputed.
+    #[cfg(feature = "orders")]
+    async fn open_dispute(&self, dispute: Dispute) -> Result<bool, String>;
+
+    #[cfg(feature = "orders")]
+    async fn add_refund(&self, refund: Refund) -> Result<(), String>;
+
+    /// GBP refunded on the order so far.
+    #[cfg(feature = "orders")]
+    async fn refunded(&self, order_id: u64) -> Result<f64, String>;
+
+    /// The guild's refunds made at or after `since`, oldest first.
+    async fn refunds(&self, guild_id: u64, since: u64) -> Result<Vec<Refund>, String>;
+
+    #[cfg(feature = "orders")]
+    async fn middleman_progress(&self, order_id: u64) -> Result<Option<MiddlemanProgress>, String>;
+
+    /// Records the middleman receiving the buyer's payment, returning `false` if
+    /// someone already did.
+    #[cfg(feature = "orders")]
+    async fn confirm_middleman_received(
+        &self,
+        order_id: u64,
+        guild_id: u64,
+        user_id: u64,
+        at: u64,
+    ) -> Result<bool, String>;
+
+    /// Records the payment being released to the seller, returning `false`
+    /// unless it's been received and not released yet.
+    #[cfg(feature = "orders")]
+    async fn confirm_middleman_released(
+        &self,
+        order_id: u64,
+        user_id: u64,
+        at: u64,
+    ) -> Result<bool, String>;
+
+    async fn record_audit(&self, entry: AuditEntry) -> Result<(), String>;
+
+    /// The guild's most recent audit entries, newest first, only `actor_id`'s if
+    /// it's given.
+    async fn audit_log(
+        &self,
+        guild_id: u64,
+        actor_id: Option<u64>,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, String>;
+}
+
+impl dyn Store {
+    pub async fn update_guild<F>(&self, guild_id: GuildId, update: F) -> Result<(), String>
+    where
+        F: FnOnce(&mut GuildConfig) + Send,
+    {
+        self.apply_guild_update(guild_id, Box::new(update)).await
+    }
+
+    pub async fn take_triggered_alerts<F>(&self, rate_for: F) -> Result<Vec<Alert>, String>
+    where
+        F: Fn(&str) -> Option<f64> + Send + Sync,
+    {
+        self.take_alerts_triggered_by(&rate_for).await
+    }
+}
+
+/// Shared handle to whichever backend `DATABASE_URL` selected.
+pub type Storage = Arc<dyn Store>;
+
+/// Connects to `url` and runs any pending migrations. `postgres://` and
+/// `postgresql://` URLs use PostgreSQL; anything else is treated as a SQLite
+/// `sqlite:` URL or file path.
+pub async fn connect(url: &str) -> Result<Storage, Box<dyn std::error::Error>> {
+    if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+        Ok(Arc::new(postgres::PostgresStore::connect(url).await?))
+    } else {
+        Ok(Arc::new(sqlite::SqliteStore::connect(url).await?))
+    }
+}
+
+fn db_error(error: sqlx::Error) -> String {
+    format!("Database error: {:?}", error)
+}
+
+fn parse_language(code: &str) -> Language {
+    Language::from_locale(code).unwrap_or_default()
+}
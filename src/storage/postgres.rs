@@ -0,0 +1,2772 @@
+use async_trait::async_trait;
+use serenity::model::id::GuildId;
+use sqlx::{
+    postgres::{PgConnection, PgPool},
+    Connection,
+};
+
+use super::{db_error, parse_language, GuildConfig, GuildUpdate, RateLookup, Store, UserSettings};
+#[cfg(any(feature = "orders", feature = "roblox"))]
+use crate::verification::RobloxLink;
+use crate::{
+    alerts::{Alert, Direction},
+    announcements::RateAnnouncements,
+    audit::AuditEntry,
+    backup::Backup,
+    blacklist::BlacklistEntry,
+    coupons::{Coupon, CouponKind},
+    disputes::Refund,
+    emojis::Emojis,
+    features::Feature,
+    history::{self, Calculation},
+    i18n::Language,
+    loyalty::{Discount, Purchases, RoleDiscount, RoleReward},
+    methods::DeliveryMethod,
+    numbers::{Rounding, RoundingMode},
+    orders::{FrozenRates, Order, OrderLimits, OrderStatus},
+    payments::{PaymentHandle, PaymentMethod},
+    priceboard::PriceBoard,
+    pricing::ShopRates,
+    ratehistory::{RateChange, RateKind},
+    rates::{self, RateSnapshot},
+    reminders::Reminder,
+    retention::{Forgotten, Retention},
+    sellers::Seller,
+    sheets::SheetSettings,
+    templates::EmbedTemplate,
+    theme::Theme,
+    tickets::TicketSettings,
+    tiers::Tier,
+    vat::VatSettings,
+    vouches::{Reputation, Vouch},
+    webhooks::Webhook,
+};
+#[cfg(feature = "orders")]
+use crate::{
+    disputes::Dispute,
+    middleman::MiddlemanProgress,
+    payouts::{Payout, PayoutHold, PayoutStatus},
+    queue::QueueEntry,
+};
+#[cfg(feature = "roblox")]
+use crate::{orders::GamepassCheck, verification::PendingVerification};
+
+/// Shared storage for running several bot instances against one managed database.
+pub struct PostgresStore {
+    pool: PgPool,
+}
+
+impl PostgresStore {
+    /// Connects to the database at `url` and runs any pending migrations.
+    pub async fn connect(url: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let pool = PgPool::connect(url).await?;
+        sqlx::migrate!("./migrations/postgres").run(&pool).await?;
+
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait]
+impl Store for PostgresStore {
+    async fn is_empty(&self) -> Result<bool, String> {
+        let row = sqlx::query!(
+            r#"SELECT (SELECT COUNT(*) FROM guilds) + (SELECT COUNT(*) FROM calculations)
+                      + (SELECT COUNT(*) FROM alerts) AS "rows!""#
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.rows == 0)
+    }
+
+    async fn guild(&self, guild_id: GuildId) -> Result<GuildConfig, String> {
+        let mut conn = self.pool.acquire().await.map_err(db_error)?;
+        load_guild(&mut conn, guild_id).await.map_err(db_error)
+    }
+
+    #[cfg(feature = "api")]
+    async fn guild_for_api_key(&self, key_hash: &str) -> Result<Option<GuildId>, String> {
+        let row = sqlx::query!(
+            "SELECT guild_id FROM guilds WHERE api_key_hash = $1",
+            key_hash,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| GuildId(row.guild_id as u64)))
+    }
+
+    async fn priceboards(&self) -> Result<Vec<(GuildId, PriceBoard)>, String> {
+        let rows = sqlx::query!(
+            "SELECT guild_id, channel_id, message_id, refresh_minutes, updated_at, language
+             FROM priceboards ORDER BY guild_id"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let board = PriceBoard {
+                    channel_id: row.channel_id as u64,
+                    message_id: row.message_id as u64,
+                    refresh_minutes: row.refresh_minutes as u64,
+                    updated_at: row.updated_at as u64,
+                    language: parse_language(&row.language),
+                };
+                (GuildId(row.guild_id as u64), board)
+            })
+            .collect())
+    }
+
+    async fn apply_guild_update(
+        &self,
+        guild_id: GuildId,
+        update: GuildUpdate<'_>,
+    ) -> Result<(), String> {
+        let mut conn = self.pool.acquire().await.map_err(db_error)?;
+        let mut tx = conn.begin().await.map_err(db_error)?;
+
+        // Instances sharing the database may update the same guild at once, and
+        // save_guild rewrites its child tables, so the guild's row is locked until
+        // commit. It's created first so there's a row to lock for a new guild.
+        let id = guild_id.0 as i64;
+        sqlx::query!(
+            "INSERT INTO guilds (guild_id) VALUES ($1) ON CONFLICT (guild_id) DO NOTHING",
+            id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        sqlx::query!(
+            "SELECT guild_id FROM guilds WHERE guild_id = $1 FOR UPDATE",
+            id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        let mut config = load_guild(&mut tx, guild_id).await.map_err(db_error)?;
+        update(&mut config);
+        save_guild(&mut tx, guild_id, &config)
+            .await
+            .map_err(db_error)?;
+
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn delete_tier(&self, guild_id: u64, min_robux: u64, at: u64) -> Result<bool, String> {
+        let guild_id = guild_id as i64;
+        let min_robux = min_robux as i64;
+        let at = at as i64;
+        let result = sqlx::query!(
+            "UPDATE tiers SET deleted_at = $1 WHERE guild_id = $2 AND min_robux = $3 AND deleted_at IS NULL",
+            at,
+            guild_id,
+            min_robux,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn restore_tier(
+        &self,
+        guild_id: u64,
+        min_robux: u64,
+        since: u64,
+    ) -> Result<bool, String> {
+        let guild_id = guild_id as i64;
+        let min_robux = min_robux as i64;
+        let since = since as i64;
+        let result = sqlx::query!(
+            "UPDATE tiers SET deleted_at = NULL WHERE guild_id = $1 AND min_robux = $2 AND deleted_at >= $3",
+            guild_id,
+            min_robux,
+            since,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn purge_deleted(&self, before: u64) -> Result<u64, String> {
+        let before = before as i64;
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let mut purged = 0;
+        purged += sqlx::query!("DELETE FROM tiers WHERE deleted_at < $1", before)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?
+            .rows_affected();
+        purged += sqlx::query!("DELETE FROM coupons WHERE deleted_at < $1", before)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?
+            .rows_affected();
+        purged += sqlx::query!("DELETE FROM orders WHERE deleted_at < $1", before)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?
+            .rows_affected();
+        tx.commit().await.map_err(db_error)?;
+
+        Ok(purged)
+    }
+
+    async fn purge_expired(&self, now: u64) -> Result<u64, String> {
+        let now = now as i64;
+        let closed = OrderStatus::Closed.code();
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let mut purged = 0;
+        purged += sqlx::query!(
+            "DELETE FROM calculations WHERE timestamp < $1 - (
+                SELECT retention_history_days FROM guilds WHERE guilds.guild_id = calculations.guild_id
+            ) * 86400",
+            now,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .rows_affected();
+        purged += sqlx::query!(
+            "DELETE FROM orders WHERE status = $1 AND closed_at < $2 - (
+                SELECT retention_order_days FROM guilds WHERE guilds.guild_id = orders.guild_id
+            ) * 86400",
+            closed,
+            now,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .rows_affected();
+        tx.commit().await.map_err(db_error)?;
+
+        Ok(purged)
+    }
+
+    async fn record_calculation(&self, calculation: Calculation) -> Result<(), String> {
+        let guild_id = calculation.guild_id.map(|id| id as i64);
+        let user_id = calculation.user_id as i64;
+        let timestamp = calculation.timestamp as i64;
+        let limit = history::MAX_ENTRIES_PER_USER as i64;
+
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        sqlx::query!(
+            "INSERT INTO calculations (guild_id, user_id, command, robux, gbp, usd, rate, timestamp)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+            guild_id,
+            user_id,
+            calculation.command,
+            calculation.robux,
+            calculation.gbp,
+            calculation.usd,
+            calculation.rate,
+            timestamp,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        sqlx::query!(
+            "DELETE FROM calculations WHERE user_id = $1 AND id NOT IN (
+                SELECT id FROM calculations WHERE user_id = $1 ORDER BY id DESC LIMIT $2
+            )",
+            user_id,
+            limit,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn history(
+        &self,
+        guild_id: Option<u64>,
+        user_id: u64,
+        limit: usize,
+    ) -> Result<Vec<Calculation>, String> {
+        let guild_id = guild_id.map(|id| id as i64);
+        let user_id = user_id as i64;
+        let limit = limit as i64;
+
+        let rows = sqlx::query!(
+            "SELECT guild_id, user_id, command, robux, gbp, usd, rate, timestamp
+             FROM calculations
+             WHERE user_id = $1 AND guild_id IS NOT DISTINCT FROM $2
+             ORDER BY id DESC
+             LIMIT $3",
+            user_id,
+            guild_id,
+            limit,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Calculation {
+                guild_id: row.guild_id.map(|id| id as u64),
+                user_id: row.user_id as u64,
+                command: row.command,
+                robux: row.robux,
+                gbp: row.gbp,
+                usd: row.usd,
+                rate: row.rate,
+                timestamp: row.timestamp as u64,
+            })
+            .collect())
+    }
+
+    async fn guild_calculations(
+        &self,
+        guild_id: u64,
+        since: u64,
+    ) -> Result<Vec<Calculation>, String> {
+        let guild_id = guild_id as i64;
+        let since = since as i64;
+
+        let rows = sqlx::query!(
+            "SELECT guild_id, user_id, command, robux, gbp, usd, rate, timestamp
+             FROM calculations
+             WHERE guild_id = $1 AND timestamp >= $2
+             ORDER BY id",
+            guild_id,
+            since,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Calculation {
+                guild_id: row.guild_id.map(|id| id as u64),
+                user_id: row.user_id as u64,
+                command: row.command,
+                robux: row.robux,
+                gbp: row.gbp,
+                usd: row.usd,
+                rate: row.rate,
+                timestamp: row.timestamp as u64,
+            })
+            .collect())
+    }
+
+    async fn add_alert(&self, alert: Alert) -> Result<u64, String> {
+        let user_id = alert.user_id as i64;
+        let guild_id = alert.guild_id.map(|id| id as i64);
+        let channel_id = alert.channel_id as i64;
+        let direction = alert.direction.code();
+        let language = alert.language.code();
+
+        let row = sqlx::query!(
+            "INSERT INTO alerts (user_id, guild_id, channel_id, pair, threshold, direction, language)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING id",
+            user_id,
+            guild_id,
+            channel_id,
+            alert.pair,
+            alert.threshold,
+            direction,
+            language,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.id as u64)
+    }
+
+    async fn alerts_for_user(&self, user_id: u64) -> Result<Vec<Alert>, String> {
+        let user_id = user_id as i64;
+        let rows = sqlx::query!(
+            "SELECT id, user_id, guild_id, channel_id, pair, threshold, direction, language
+             FROM alerts WHERE user_id = $1 ORDER BY id",
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Alert {
+                id: row.id as u64,
+                user_id: row.user_id as u64,
+                guild_id: row.guild_id.map(|id| id as u64),
+                channel_id: row.channel_id as u64,
+                pair: row.pair,
+                threshold: row.threshold,
+                direction: Direction::from_code(&row.direction),
+                language: parse_language(&row.language),
+            })
+            .collect())
+    }
+
+    async fn remove_alert(&self, user_id: u64, id: u64) -> Result<bool, String> {
+        let user_id = user_id as i64;
+        let id = id as i64;
+        let result = sqlx::query!(
+            "DELETE FROM alerts WHERE user_id = $1 AND id = $2",
+            user_id,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn take_alerts_triggered_by(
+        &self,
+        rate_for: RateLookup<'_>,
+    ) -> Result<Vec<Alert>, String> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let rows = sqlx::query!(
+            "SELECT id, user_id, guild_id, channel_id, pair, threshold, direction, language
+             FROM alerts ORDER BY id FOR UPDATE"
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        let triggered: Vec<Alert> = rows
+            .into_iter()
+            .map(|row| Alert {
+                id: row.id as u64,
+                user_id: row.user_id as u64,
+                guild_id: row.guild_id.map(|id| id as u64),
+                channel_id: row.channel_id as u64,
+                pair: row.pair,
+                threshold: row.threshold,
+                direction: Direction::from_code(&row.direction),
+                language: parse_language(&row.language),
+            })
+            .filter(|alert| rate_for(&alert.pair).is_some_and(|rate| alert.is_triggered(rate)))
+            .collect();
+
+        for alert in &triggered {
+            let id = alert.id as i64;
+            sqlx::query!("DELETE FROM alerts WHERE id = $1", id)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+        }
+
+        tx.commit().await.map_err(db_error)?;
+        Ok(triggered)
+    }
+
+    async fn record_rate(&self, snapshot: RateSnapshot) -> Result<(), String> {
+        let timestamp = snapshot.timestamp as i64;
+        let cutoff = snapshot.timestamp.saturating_sub(rates::HISTORY_SECONDS) as i64;
+
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        sqlx::query!("DELETE FROM rate_history WHERE timestamp < $1", cutoff)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query!(
+            "INSERT INTO rate_history (timestamp, gbp_to_usd) VALUES ($1, $2)",
+            timestamp,
+            snapshot.gbp_to_usd,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn rate_history(&self, since: u64) -> Result<Vec<RateSnapshot>, String> {
+        let since = since as i64;
+        let rows = sqlx::query!(
+            "SELECT timestamp, gbp_to_usd FROM rate_history WHERE timestamp >= $1 ORDER BY timestamp",
+            since,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| RateSnapshot {
+                timestamp: row.timestamp as u64,
+                gbp_to_usd: row.gbp_to_usd,
+            })
+            .collect())
+    }
+
+    async fn record_rate_change(&self, change: RateChange) -> Result<(), String> {
+        let guild_id = change.guild_id as i64;
+        let changed_by = change.changed_by as i64;
+        let changed_at = change.changed_at as i64;
+        let rate = change.kind.code();
+        sqlx::query!(
+            "INSERT INTO rate_changes (guild_id, changed_by, rate, previous, value, changed_at)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+            guild_id,
+            changed_by,
+            rate,
+            change.previous,
+            change.value,
+            changed_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn rate_changes(&self, guild_id: u64, limit: u32) -> Result<Vec<RateChange>, String> {
+        let guild_id = guild_id as i64;
+        let limit = i64::from(limit);
+        let rows = sqlx::query!(
+            "SELECT guild_id, changed_by, rate, previous, value, changed_at
+             FROM rate_changes WHERE guild_id = $1
+             ORDER BY changed_at DESC, id DESC LIMIT $2",
+            guild_id,
+            limit,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                Some(RateChange {
+                    guild_id: row.guild_id as u64,
+                    changed_by: row.changed_by as u64,
+                    kind: RateKind::parse(&row.rate)?,
+                    previous: row.previous,
+                    value: row.value,
+                    changed_at: row.changed_at as u64,
+                })
+            })
+            .collect())
+    }
+
+    async fn rate_changed_at(&self, guild_id: u64, kind: RateKind) -> Result<Option<u64>, String> {
+        let guild_id = guild_id as i64;
+        let rate = kind.code();
+        let row = sqlx::query!(
+            r#"SELECT MAX(changed_at) AS "changed_at?: i64"
+             FROM rate_changes WHERE guild_id = $1 AND rate = $2"#,
+            guild_id,
+            rate,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.changed_at.map(|at| at as u64))
+    }
+
+    #[cfg(feature = "orders")]
+    async fn create_order(&self, order: Order) -> Result<u64, String> {
+        let guild_id = order.guild_id as i64;
+        let buyer_id = order.buyer_id as i64;
+        let channel_id = order.channel_id as i64;
+        let robux = order.robux as i64;
+        let method = order.method.code();
+        let status = order.status.code();
+        let created_at = order.created_at as i64;
+        let frozen = order.frozen_rates;
+        let frozen_base_rate = frozen.map(|frozen| frozen.base_rate);
+        let frozen_gbp_to_usd = frozen.map(|frozen| frozen.gbp_to_usd);
+        let frozen_roblox_fee = frozen.map(|frozen| frozen.roblox_fee);
+        let frozen_vat_percent = frozen.map(|frozen| frozen.vat_percent);
+
+        let row = sqlx::query!(
+            "INSERT INTO orders (guild_id, buyer_id, channel_id, robux, after_tax, method,
+                                 gbp, usd, tax_gbp, rate, status, created_at, roblox_username,
+                                 frozen_base_rate, frozen_gbp_to_usd, frozen_roblox_fee,
+                                 frozen_vat_percent)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+             RETURNING id",
+            guild_id,
+            buyer_id,
+            channel_id,
+            robux,
+            order.after_tax,
+            method,
+            order.gbp,
+            order.usd,
+            order.tax_gbp,
+            order.rate,
+            status,
+            created_at,
+            order.roblox_username,
+            frozen_base_rate,
+            frozen_gbp_to_usd,
+            frozen_roblox_fee,
+            frozen_vat_percent,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.id as u64)
+    }
+
+    #[cfg(feature = "orders")]
+    async fn delete_order(&self, guild_id: u64, id: u64, at: u64) -> Result<bool, String> {
+        let guild_id = guild_id as i64;
+        let id = id as i64;
+        let at = at as i64;
+        let result = sqlx::query!(
+            "UPDATE orders SET deleted_at = $1 WHERE id = $2 AND guild_id = $3 AND deleted_at IS NULL",
+            at,
+            id,
+            guild_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn restore_order(&self, guild_id: u64, id: u64, since: u64) -> Result<bool, String> {
+        let guild_id = guild_id as i64;
+        let id = id as i64;
+        let since = since as i64;
+        let result = sqlx::query!(
+            "UPDATE orders SET deleted_at = NULL WHERE id = $1 AND guild_id = $2 AND deleted_at >= $3",
+            id,
+            guild_id,
+            since,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[cfg(any(feature = "orders", feature = "roblox"))]
+    async fn open_order_for_channel(&self, channel_id: u64) -> Result<Option<Order>, String> {
+        let channel_id = channel_id as i64;
+        let open = OrderStatus::Open.code();
+        let row = sqlx::query!(
+            "SELECT id, guild_id, buyer_id, channel_id, robux, after_tax, method,
+                    gbp, usd, tax_gbp, rate, status, created_at, completed_at, roblox_username,
+                    frozen_base_rate, frozen_gbp_to_usd, frozen_roblox_fee, frozen_vat_percent
+             FROM orders WHERE channel_id = $1 AND status = $2 AND deleted_at IS NULL",
+            channel_id,
+            open,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| Order {
+            id: row.id as u64,
+            guild_id: row.guild_id as u64,
+            buyer_id: row.buyer_id as u64,
+            channel_id: row.channel_id as u64,
+            robux: row.robux as u64,
+            after_tax: row.after_tax,
+            method: DeliveryMethod::parse(&row.method).unwrap_or(DeliveryMethod::Gamepass),
+            gbp: row.gbp,
+            usd: row.usd,
+            tax_gbp: row.tax_gbp,
+            rate: row.rate,
+            frozen_rates: FrozenRates::from_columns(
+                row.frozen_base_rate,
+                row.frozen_gbp_to_usd,
+                row.frozen_roblox_fee,
+                row.frozen_vat_percent,
+            ),
+            status: OrderStatus::from_code(&row.status),
+            created_at: row.created_at as u64,
+            completed_at: row.completed_at.map(|completed_at| completed_at as u64),
+            roblox_username: row.roblox_username,
+        }))
+    }
+
+    #[cfg(feature = "orders")]
+    async fn close_order(&self, id: u64, closed_at: u64) -> Result<(), String> {
+        let id = id as i64;
+        let closed_at = closed_at as i64;
+        let closed = OrderStatus::Closed.code();
+        sqlx::query!(
+            "UPDATE orders SET status = $1, closed_at = $2 WHERE id = $3",
+            closed,
+            closed_at,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn unvouched_order(
+        &self,
+        guild_id: u64,
+        buyer_id: u64,
+        seller_id: u64,
+    ) -> Result<Option<u64>, String> {
+        let guild_id = guild_id as i64;
+        let buyer_id = buyer_id as i64;
+        let seller_id = seller_id as i64;
+        let row = sqlx::query!(
+            "SELECT id FROM orders
+             WHERE guild_id = $1 AND buyer_id = $2 AND seller_id = $3
+               AND completed_at IS NOT NULL AND deleted_at IS NULL
+               AND id NOT IN (SELECT order_id FROM vouches)
+             ORDER BY id DESC
+             LIMIT 1",
+            guild_id,
+            buyer_id,
+            seller_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| row.id as u64))
+    }
+
+    async fn add_vouch(&self, vouch: Vouch) -> Result<u64, String> {
+        let guild_id = vouch.guild_id as i64;
+        let order_id = vouch.order_id as i64;
+        let buyer_id = vouch.buyer_id as i64;
+        let seller_id = vouch.seller_id as i64;
+        let stars = i16::from(vouch.stars);
+        let created_at = vouch.created_at as i64;
+
+        let row = sqlx::query!(
+            "INSERT INTO vouches (guild_id, order_id, buyer_id, seller_id, stars, comment, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING id",
+            guild_id,
+            order_id,
+            buyer_id,
+            seller_id,
+            stars,
+            vouch.comment,
+            created_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.id as u64)
+    }
+
+    async fn reputation(
+        &self,
+        guild_id: u64,
+        seller_id: u64,
+        recent: usize,
+    ) -> Result<Reputation, String> {
+        let guild_id = guild_id as i64;
+        let seller_id = seller_id as i64;
+        let recent = recent as i64;
+
+        let tally = sqlx::query!(
+            r#"SELECT COUNT(*) AS "total!", AVG(stars)::DOUBLE PRECISION AS average
+             FROM vouches WHERE guild_id = $1 AND seller_id = $2"#,
+            guild_id,
+            seller_id,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+        let rows = sqlx::query!(
+            "SELECT guild_id, order_id, buyer_id, seller_id, stars, comment, created_at
+             FROM vouches WHERE guild_id = $1 AND seller_id = $2
+             ORDER BY id DESC
+             LIMIT $3",
+            guild_id,
+            seller_id,
+            recent,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(Reputation {
+            total: tally.total as u64,
+            average: tally.average.unwrap_or_default(),
+            recent: rows
+                .into_iter()
+                .map(|row| Vouch {
+                    guild_id: row.guild_id as u64,
+                    order_id: row.order_id as u64,
+                    buyer_id: row.buyer_id as u64,
+                    seller_id: row.seller_id as u64,
+                    stars: row.stars as u8,
+                    comment: row.comment,
+                    created_at: row.created_at as u64,
+                })
+                .collect(),
+        })
+    }
+
+    #[cfg(feature = "orders")]
+    async fn complete_order(
+        &self,
+        id: u64,
+        seller_id: u64,
+        completed_at: u64,
+    ) -> Result<(), String> {
+        let id = id as i64;
+        let seller_id = seller_id as i64;
+        let completed_at = completed_at as i64;
+
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let order = sqlx::query!(
+            "UPDATE orders SET seller_id = $1, completed_at = $2 WHERE id = $3
+             RETURNING guild_id, robux",
+            seller_id,
+            completed_at,
+            id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        sqlx::query!(
+            "UPDATE stock SET robux = GREATEST(robux - $1, 0) WHERE guild_id = $2 AND seller_id = $3",
+            order.robux,
+            order.guild_id,
+            seller_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn stock(&self, guild_id: u64) -> Result<Vec<(u64, u64)>, String> {
+        let guild_id = guild_id as i64;
+        let rows = sqlx::query!(
+            "SELECT seller_id, robux FROM stock WHERE guild_id = $1 ORDER BY robux DESC",
+            guild_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.seller_id as u64, row.robux as u64))
+            .collect())
+    }
+
+    #[cfg(feature = "orders")]
+    async fn set_stock(&self, guild_id: u64, seller_id: u64, robux: u64) -> Result<(), String> {
+        let guild_id = guild_id as i64;
+        let seller_id = seller_id as i64;
+        let robux = robux as i64;
+        sqlx::query!(
+            "INSERT INTO stock (guild_id, seller_id, robux) VALUES ($1, $2, $3)
+             ON CONFLICT (guild_id, seller_id) DO UPDATE SET robux = excluded.robux",
+            guild_id,
+            seller_id,
+            robux,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "orders")]
+    async fn add_stock(&self, guild_id: u64, seller_id: u64, robux: u64) -> Result<u64, String> {
+        let guild_id = guild_id as i64;
+        let seller_id = seller_id as i64;
+        let robux = robux as i64;
+        let row = sqlx::query!(
+            "INSERT INTO stock (guild_id, seller_id, robux) VALUES ($1, $2, $3)
+             ON CONFLICT (guild_id, seller_id) DO UPDATE SET robux = stock.robux + excluded.robux
+             RETURNING robux",
+            guild_id,
+            seller_id,
+            robux,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.robux as u64)
+    }
+
+    async fn add_blacklist_entry(&self, entry: BlacklistEntry) -> Result<(), String> {
+        let guild_id = entry.guild_id.map(|id| id as i64);
+        let user_id = entry.user_id as i64;
+        let added_by = entry.added_by as i64;
+        let created_at = entry.created_at as i64;
+
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        sqlx::query!(
+            "DELETE FROM blacklist WHERE guild_id IS NOT DISTINCT FROM $1 AND user_id = $2",
+            guild_id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        sqlx::query!(
+            "INSERT INTO blacklist (guild_id, user_id, reason, added_by, created_at)
+             VALUES ($1, $2, $3, $4, $5)",
+            guild_id,
+            user_id,
+            entry.reason,
+            added_by,
+            created_at,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn remove_blacklist_entry(
+        &self,
+        guild_id: Option<u64>,
+        user_id: u64,
+    ) -> Result<bool, String> {
+        let guild_id = guild_id.map(|id| id as i64);
+        let user_id = user_id as i64;
+        let result = sqlx::query!(
+            "DELETE FROM blacklist WHERE guild_id IS NOT DISTINCT FROM $1 AND user_id = $2",
+            guild_id,
+            user_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn blacklist_entries(
+        &self,
+        guild_id: Option<u64>,
+        user_id: u64,
+    ) -> Result<Vec<BlacklistEntry>, String> {
+        let guild_id = guild_id.map(|id| id as i64);
+        let user_id = user_id as i64;
+        let rows = sqlx::query!(
+            "SELECT guild_id, user_id, reason, added_by, created_at FROM blacklist
+             WHERE user_id = $1 AND (guild_id IS NULL OR guild_id = $2)
+             ORDER BY guild_id NULLS FIRST",
+            user_id,
+            guild_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| BlacklistEntry {
+                guild_id: row.guild_id.map(|id| id as u64),
+                user_id: row.user_id as u64,
+                reason: row.reason,
+                added_by: row.added_by as u64,
+                created_at: row.created_at as u64,
+            })
+            .collect())
+    }
+
+    async fn purchases(&self, guild_id: u64, buyer_id: u64) -> Result<Purchases, String> {
+        let guild_id = guild_id as i64;
+        let buyer_id = buyer_id as i64;
+        let row = sqlx::query!(
+            r#"SELECT COUNT(*) AS "orders!", COALESCE(SUM(robux), 0)::BIGINT AS "robux!",
+                      COALESCE(SUM(gbp), 0.0) AS "gbp!"
+             FROM orders WHERE guild_id = $1 AND buyer_id = $2 AND completed_at IS NOT NULL
+               AND deleted_at IS NULL"#,
+            guild_id,
+            buyer_id,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(Purchases {
+            orders: row.orders as u64,
+            robux: row.robux as u64,
+            gbp: row.gbp,
+        })
+    }
+
+    async fn completed_orders(&self, guild_id: u64, since: u64) -> Result<Vec<Order>, String> {
+        let guild_id = guild_id as i64;
+        let since = since as i64;
+        let rows = sqlx::query!(
+            "SELECT id, guild_id, buyer_id, channel_id, robux, after_tax, method,
+                    gbp, usd, tax_gbp, rate, status, created_at, completed_at, roblox_username,
+                    frozen_base_rate, frozen_gbp_to_usd, frozen_roblox_fee, frozen_vat_percent
+             FROM orders WHERE guild_id = $1 AND completed_at >= $2 AND deleted_at IS NULL
+             ORDER BY completed_at",
+            guild_id,
+            since,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Order {
+                id: row.id as u64,
+                guild_id: row.guild_id as u64,
+                buyer_id: row.buyer_id as u64,
+                channel_id: row.channel_id as u64,
+                robux: row.robux as u64,
+                after_tax: row.after_tax,
+                method: DeliveryMethod::parse(&row.method).unwrap_or(DeliveryMethod::Gamepass),
+                gbp: row.gbp,
+                usd: row.usd,
+                tax_gbp: row.tax_gbp,
+                rate: row.rate,
+                frozen_rates: FrozenRates::from_columns(
+                    row.frozen_base_rate,
+                    row.frozen_gbp_to_usd,
+                    row.frozen_roblox_fee,
+                    row.frozen_vat_percent,
+                ),
+                status: OrderStatus::from_code(&row.status),
+                created_at: row.created_at as u64,
+                completed_at: row.completed_at.map(|completed_at| completed_at as u64),
+                roblox_username: row.roblox_username,
+            })
+            .collect())
+    }
+
+    async fn orders(&self, guild_id: u64, since: u64) -> Result<Vec<Order>, String> {
+        let guild_id = guild_id as i64;
+        let since = since as i64;
+        let rows = sqlx::query!(
+            "SELECT id, guild_id, buyer_id, channel_id, robux, after_tax, method,
+                    gbp, usd, tax_gbp, rate, status, created_at, completed_at, roblox_username,
+                    frozen_base_rate, frozen_gbp_to_usd, frozen_roblox_fee, frozen_vat_percent
+             FROM orders WHERE guild_id = $1 AND created_at >= $2 AND deleted_at IS NULL
+             ORDER BY created_at, id",
+            guild_id,
+            since,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Order {
+                id: row.id as u64,
+                guild_id: row.guild_id as u64,
+                buyer_id: row.buyer_id as u64,
+                channel_id: row.channel_id as u64,
+                robux: row.robux as u64,
+                after_tax: row.after_tax,
+                method: DeliveryMethod::parse(&row.method).unwrap_or(DeliveryMethod::Gamepass),
+                gbp: row.gbp,
+                usd: row.usd,
+                tax_gbp: row.tax_gbp,
+                rate: row.rate,
+                frozen_rates: FrozenRates::from_columns(
+                    row.frozen_base_rate,
+                    row.frozen_gbp_to_usd,
+                    row.frozen_roblox_fee,
+                    row.frozen_vat_percent,
+                ),
+                status: OrderStatus::from_code(&row.status),
+                created_at: row.created_at as u64,
+                completed_at: row.completed_at.map(|completed_at| completed_at as u64),
+                roblox_username: row.roblox_username,
+            })
+            .collect())
+    }
+
+    async fn create_coupon(&self, coupon: Coupon) -> Result<bool, String> {
+        let guild_id = coupon.guild_id as i64;
+        let kind = coupon.kind.code();
+        let max_uses = coupon.max_uses.map(|uses| uses as i64);
+        let uses = coupon.uses as i64;
+        let expires_at = coupon.expires_at.map(|at| at as i64);
+        let created_by = coupon.created_by as i64;
+        let created_at = coupon.created_at as i64;
+
+        let result = sqlx::query!(
+            "INSERT INTO coupons (guild_id, code, kind, value, max_uses, uses, expires_at, created_by, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             ON CONFLICT (guild_id, code) DO UPDATE SET
+                 kind = excluded.kind,
+                 value = excluded.value,
+                 max_uses = excluded.max_uses,
+                 uses = excluded.uses,
+                 expires_at = excluded.expires_at,
+                 created_by = excluded.created_by,
+                 created_at = excluded.created_at,
+                 deleted_at = NULL
+             WHERE coupons.deleted_at IS NOT NULL",
+            guild_id,
+            coupon.code,
+            kind,
+            coupon.value,
+            max_uses,
+            uses,
+            expires_at,
+            created_by,
+            created_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_coupon(&self, guild_id: u64, code: &str, at: u64) -> Result<bool, String> {
+        let guild_id = guild_id as i64;
+        let at = at as i64;
+        let result = sqlx::query!(
+            "UPDATE coupons SET deleted_at = $1 WHERE guild_id = $2 AND code = $3 AND deleted_at IS NULL",
+            at,
+            guild_id,
+            code,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn restore_coupon(&self, guild_id: u64, code: &str, since: u64) -> Result<bool, String> {
+        let guild_id = guild_id as i64;
+        let since = since as i64;
+        let result = sqlx::query!(
+            "UPDATE coupons SET deleted_at = NULL WHERE guild_id = $1 AND code = $2 AND deleted_at >= $3",
+            guild_id,
+            code,
+            since,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn coupons(&self, guild_id: u64) -> Result<Vec<Coupon>, String> {
+        let guild_id = guild_id as i64;
+        let rows = sqlx::query!(
+            "SELECT guild_id, code, kind, value, max_uses, uses, expires_at, created_by, created_at FROM coupons WHERE guild_id = $1 AND deleted_at IS NULL ORDER BY code",
+            guild_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Coupon {
+                guild_id: row.guild_id as u64,
+                code: row.code,
+                kind: CouponKind::from_code(&row.kind),
+                value: row.value,
+                max_uses: row.max_uses.map(|uses| uses as u64),
+                uses: row.uses as u64,
+                expires_at: row.expires_at.map(|at| at as u64),
+                created_by: row.created_by as u64,
+                created_at: row.created_at as u64,
+            })
+            .collect())
+    }
+
+    async fn coupon(&self, guild_id: u64, code: &str) -> Result<Option<Coupon>, String> {
+        let guild_id = guild_id as i64;
+        let row = sqlx::query!(
+            "SELECT guild_id, code, kind, value, max_uses, uses, expires_at, created_by, created_at FROM coupons WHERE guild_id = $1 AND code = $2 AND deleted_at IS NULL",
+            guild_id,
+            code,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| Coupon {
+            guild_id: row.guild_id as u64,
+            code: row.code,
+            kind: CouponKind::from_code(&row.kind),
+            value: row.value,
+            max_uses: row.max_uses.map(|uses| uses as u64),
+            uses: row.uses as u64,
+            expires_at: row.expires_at.map(|at| at as u64),
+            created_by: row.created_by as u64,
+            created_at: row.created_at as u64,
+        }))
+    }
+
+    #[cfg(feature = "orders")]
+    async fn redeem_coupon(
+        &self,
+        guild_id: u64,
+        code: &str,
+        now: u64,
+    ) -> Result<Option<Coupon>, String> {
+        let guild_id = guild_id as i64;
+        let now = now as i64;
+        let row = sqlx::query!(
+            "UPDATE coupons SET uses = uses + 1
+             WHERE guild_id = $1 AND code = $2 AND deleted_at IS NULL
+               AND (max_uses IS NULL OR uses < max_uses)
+               AND (expires_at IS NULL OR expires_at > $3)
+             RETURNING guild_id, code, kind, value, max_uses, uses, expires_at, created_by, created_at",
+            guild_id,
+            code,
+            now,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| Coupon {
+            guild_id: row.guild_id as u64,
+            code: row.code,
+            kind: CouponKind::from_code(&row.kind),
+            value: row.value,
+            max_uses: row.max_uses.map(|uses| uses as u64),
+            uses: row.uses as u64,
+            expires_at: row.expires_at.map(|at| at as u64),
+            created_by: row.created_by as u64,
+            created_at: row.created_at as u64,
+        }))
+    }
+
+    async fn user_settings(&self, user_id: u64) -> Result<UserSettings, String> {
+        let user_id = user_id as i64;
+        let row = sqlx::query!(
+            "SELECT currency FROM user_settings WHERE user_id = $1",
+            user_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(UserSettings {
+            currency: row.and_then(|row| row.currency),
+        })
+    }
+
+    async fn save_user_settings(
+        &self,
+        user_id: u64,
+        settings: &UserSettings,
+    ) -> Result<(), String> {
+        let user_id = user_id as i64;
+        sqlx::query!(
+            "INSERT INTO user_settings (user_id, currency) VALUES ($1, $2)
+             ON CONFLICT (user_id) DO UPDATE SET currency = excluded.currency",
+            user_id,
+            settings.currency,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn forget_user(&self, user_id: u64) -> Result<Forgotten, String> {
+        let user_id = user_id as i64;
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let calculations = sqlx::query!("DELETE FROM calculations WHERE user_id = $1", user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?
+            .rows_affected();
+        let settings = sqlx::query!("DELETE FROM user_settings WHERE user_id = $1", user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?
+            .rows_affected();
+        let links = sqlx::query!("DELETE FROM roblox_links WHERE user_id = $1", user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?
+            .rows_affected();
+        let verifications = sqlx::query!(
+            "DELETE FROM roblox_verifications WHERE user_id = $1",
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .rows_affected();
+        tx.commit().await.map_err(db_error)?;
+
+        Ok(Forgotten {
+            calculations,
+            settings: settings > 0,
+            roblox_link: links + verifications > 0,
+        })
+    }
+
+    #[cfg(feature = "api")]
+    async fn ping(&self) -> Result<(), String> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn backup(&self) -> Result<Backup, String> {
+        // Every table is read from the same snapshot, so rows that refer to each
+        // other stay consistent.
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ ONLY")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        let tables: Vec<String> = sqlx::query_scalar(
+            "SELECT table_name::TEXT FROM information_schema.tables
+             WHERE table_schema = current_schema() AND table_type = 'BASE TABLE'
+               AND table_name <> '_sqlx_migrations'
+             ORDER BY table_name",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        let mut export = serde_json::Map::new();
+        for table in tables {
+            let rows: String = sqlx::query_scalar(&format!(
+                r#"SELECT COALESCE(json_agg(t), '[]')::TEXT FROM "{}" t"#,
+                table
+            ))
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(db_error)?;
+            let rows = serde_json::from_str(&rows)
+                .map_err(|e| format!("Error reading {}: {}", table, e))?;
+            export.insert(table, rows);
+        }
+        tx.commit().await.map_err(db_error)?;
+
+        Ok(Backup {
+            data: serde_json::to_vec(&export).map_err(|e| e.to_string())?,
+            extension: "json",
+        })
+    }
+
+    #[cfg(feature = "orders")]
+    async fn create_payout(&self, payout: Payout) -> Result<u64, String> {
+        let guild_id = payout.guild_id as i64;
+        let order_id = payout.order_id as i64;
+        let roblox_user_id = payout.roblox_user_id as i64;
+        let robux = payout.robux as i64;
+        let status = payout.status.code();
+        let requested_by = payout.requested_by as i64;
+        let requested_at = payout.requested_at as i64;
+
+        let row = sqlx::query!(
+            "INSERT INTO payouts (guild_id, order_id, roblox_user_id, roblox_username, robux,
+                                  status, requested_by, requested_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+             RETURNING id",
+            guild_id,
+            order_id,
+            roblox_user_id,
+            payout.roblox_username,
+            robux,
+            status,
+            requested_by,
+            requested_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.id as u64)
+    }
+
+    #[cfg(feature = "orders")]
+    async fn payout(&self, id: u64) -> Result<Option<Payout>, String> {
+        let id = id as i64;
+        let row = sqlx::query!(
+            "SELECT id, guild_id, order_id, roblox_user_id, roblox_username, robux, status,
+                    requested_by, requested_at, reviewed_by, reviewed_at, transaction_id, error
+             FROM payouts WHERE id = $1",
+            id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| Payout {
+            id: row.id as u64,
+            guild_id: row.guild_id as u64,
+            order_id: row.order_id as u64,
+            roblox_user_id: row.roblox_user_id as u64,
+            roblox_username: row.roblox_username,
+            robux: row.robux as u64,
+            status: PayoutStatus::from_code(&row.status),
+            requested_by: row.requested_by as u64,
+            requested_at: row.requested_at as u64,
+            reviewed_by: row.reviewed_by.map(|id| id as u64),
+            reviewed_at: row.reviewed_at.map(|at| at as u64),
+            transaction_id: row.transaction_id,
+            error: row.error,
+        }))
+    }
+
+    #[cfg(feature = "orders")]
+    async fn review_payout(
+        &self,
+        id: u64,
+        status: PayoutStatus,
+        reviewed_by: u64,
+        reviewed_at: u64,
+    ) -> Result<bool, String> {
+        let id = id as i64;
+        let status = status.code();
+        let pending = PayoutStatus::Pending.code();
+        let reviewed_by = reviewed_by as i64;
+        let reviewed_at = reviewed_at as i64;
+        let result = sqlx::query!(
+            "UPDATE payouts SET status = $1, reviewed_by = $2, reviewed_at = $3
+             WHERE id = $4 AND status = $5",
+            status,
+            reviewed_by,
+            reviewed_at,
+            id,
+            pending,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[cfg(feature = "orders")]
+    async fn finish_payout(
+        &self,
+        id: u64,
+        status: PayoutStatus,
+        transaction_id: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<(), String> {
+        let id = id as i64;
+        let status = status.code();
+        sqlx::query!(
+            "UPDATE payouts SET status = $1, transaction_id = $2, error = $3 WHERE id = $4",
+            status,
+            transaction_id,
+            error,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    #[cfg(any(feature = "orders", feature = "roblox"))]
+    async fn roblox_link(&self, user_id: u64) -> Result<Option<RobloxLink>, String> {
+        let user_id = user_id as i64;
+        let row = sqlx::query!(
+            "SELECT user_id, roblox_user_id, roblox_username, verified_at
+             FROM roblox_links WHERE user_id = $1",
+            user_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| RobloxLink {
+            user_id: row.user_id as u64,
+            roblox_user_id: row.roblox_user_id as u64,
+            roblox_username: row.roblox_username,
+            verified_at: row.verified_at as u64,
+        }))
+    }
+
+    #[cfg(feature = "roblox")]
+    async fn save_roblox_link(&self, link: RobloxLink) -> Result<(), String> {
+        let user_id = link.user_id as i64;
+        let roblox_user_id = link.roblox_user_id as i64;
+        let verified_at = link.verified_at as i64;
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        sqlx::query!(
+            "INSERT INTO roblox_links (user_id, roblox_user_id, roblox_username, verified_at)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (user_id) DO UPDATE SET roblox_user_id = excluded.roblox_user_id,
+                 roblox_username = excluded.roblox_username, verified_at = excluded.verified_at",
+            user_id,
+            roblox_user_id,
+            link.roblox_username,
+            verified_at,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        sqlx::query!(
+            "DELETE FROM roblox_verifications WHERE user_id = $1",
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        tx.commit().await.map_err(db_error)
+    }
+
+    #[cfg(feature = "roblox")]
+    async fn start_verification(&self, pending: PendingVerification) -> Result<(), String> {
+        let user_id = pending.user_id as i64;
+        let roblox_user_id = pending.roblox_user_id as i64;
+        let created_at = pending.created_at as i64;
+        sqlx::query!(
+            "INSERT INTO roblox_verifications (user_id, roblox_user_id, roblox_username, phrase, created_at)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (user_id) DO UPDATE SET roblox_user_id = excluded.roblox_user_id,
+                 roblox_username = excluded.roblox_username, phrase = excluded.phrase,
+                 created_at = excluded.created_at",
+            user_id,
+            roblox_user_id,
+            pending.roblox_username,
+            pending.phrase,
+            created_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "roblox")]
+    async fn pending_verification(
+        &self,
+        user_id: u64,
+    ) -> Result<Option<PendingVerification>, String> {
+        let user_id = user_id as i64;
+        let row = sqlx::query!(
+            "SELECT user_id, roblox_user_id, roblox_username, phrase, created_at
+             FROM roblox_verifications WHERE user_id = $1",
+            user_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| PendingVerification {
+            user_id: row.user_id as u64,
+            roblox_user_id: row.roblox_user_id as u64,
+            roblox_username: row.roblox_username,
+            phrase: row.phrase,
+            created_at: row.created_at as u64,
+        }))
+    }
+
+    #[cfg(feature = "roblox")]
+    async fn record_gamepass_check(&self, check: GamepassCheck) -> Result<(), String> {
+        let order_id = check.order_id as i64;
+        let gamepass_id = check.gamepass_id as i64;
+        let expected_price = check.expected_price as i64;
+        let price = check.price.map(|price| price as i64);
+        let checked_by = check.checked_by as i64;
+        let checked_at = check.checked_at as i64;
+        sqlx::query!(
+            "INSERT INTO gamepass_checks (order_id, gamepass_id, expected_price, price,
+                                          owned_by_buyer, checked_by, checked_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            order_id,
+            gamepass_id,
+            expected_price,
+            price,
+            check.owned_by_buyer,
+            checked_by,
+            checked_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "orders")]
+    async fn create_payout_hold(&self, hold: PayoutHold) -> Result<(), String> {
+        let order_id = hold.order_id as i64;
+        let guild_id = hold.guild_id as i64;
+        let channel_id = hold.channel_id as i64;
+        let buyer_id = hold.buyer_id as i64;
+        let eligible_at = hold.eligible_at as i64;
+        sqlx::query!(
+            "INSERT INTO payout_holds (order_id, guild_id, channel_id, buyer_id, eligible_at)
+             VALUES ($1, $2, $3, $4, $5)",
+            order_id,
+            guild_id,
+            channel_id,
+            buyer_id,
+            eligible_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "orders")]
+    async fn due_payout_holds(&self, now: u64) -> Result<Vec<PayoutHold>, String> {
+        let now = now as i64;
+        let open = OrderStatus::Open.code();
+        let rows = sqlx::query!(
+            "SELECT order_id, guild_id, channel_id, buyer_id, eligible_at
+             FROM payout_holds
+             WHERE notified_at IS NULL AND eligible_at <= $1
+               AND order_id IN (SELECT id FROM orders WHERE status = $2 AND deleted_at IS NULL)",
+            now,
+            open,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PayoutHold {
+                order_id: row.order_id as u64,
+                guild_id: row.guild_id as u64,
+                channel_id: row.channel_id as u64,
+                buyer_id: row.buyer_id as u64,
+                eligible_at: row.eligible_at as u64,
+            })
+            .collect())
+    }
+
+    #[cfg(feature = "orders")]
+    async fn mark_payout_hold_notified(&self, order_id: u64, at: u64) -> Result<(), String> {
+        let order_id = order_id as i64;
+        let at = at as i64;
+        sqlx::query!(
+            "UPDATE payout_holds SET notified_at = $1 WHERE order_id = $2",
+            at,
+            order_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn add_reminder(&self, reminder: Reminder) -> Result<u64, String> {
+        let user_id = reminder.user_id as i64;
+        let guild_id = reminder.guild_id.map(|id| id as i64);
+        let channel_id = reminder.channel_id.map(|id| id as i64);
+        let remind_at = reminder.remind_at as i64;
+        let created_at = reminder.created_at as i64;
+        let language = reminder.language.code();
+        let row = sqlx::query!(
+            "INSERT INTO reminders (user_id, guild_id, channel_id, note, remind_at, created_at, language)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING id",
+            user_id,
+            guild_id,
+            channel_id,
+            reminder.note,
+            remind_at,
+            created_at,
+            language,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.id as u64)
+    }
+
+    async fn reminders_for_user(&self, user_id: u64) -> Result<Vec<Reminder>, String> {
+        let user_id = user_id as i64;
+        let rows = sqlx::query!(
+            "SELECT id, user_id, guild_id, channel_id, note, remind_at, created_at, language
+             FROM reminders WHERE user_id = $1 ORDER BY remind_at",
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Reminder {
+                id: row.id as u64,
+                user_id: row.user_id as u64,
+                guild_id: row.guild_id.map(|id| id as u64),
+                channel_id: row.channel_id.map(|id| id as u64),
+                note: row.note,
+                remind_at: row.remind_at as u64,
+                created_at: row.created_at as u64,
+                language: parse_language(&row.language),
+            })
+            .collect())
+    }
+
+    async fn take_due_reminders(&self, now: u64) -> Result<Vec<Reminder>, String> {
+        let now = now as i64;
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let rows = sqlx::query!(
+            "SELECT id, user_id, guild_id, channel_id, note, remind_at, created_at, language
+             FROM reminders WHERE remind_at <= $1 ORDER BY remind_at FOR UPDATE",
+            now,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        sqlx::query!("DELETE FROM reminders WHERE remind_at <= $1", now)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        tx.commit().await.map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Reminder {
+                id: row.id as u64,
+                user_id: row.user_id as u64,
+                guild_id: row.guild_id.map(|id| id as u64),
+                channel_id: row.channel_id.map(|id| id as u64),
+                note: row.note,
+                remind_at: row.remind_at as u64,
+                created_at: row.created_at as u64,
+                language: parse_language(&row.language),
+            })
+            .collect())
+    }
+
+    async fn vouches(&self, guild_id: u64, since: u64) -> Result<Vec<Vouch>, String> {
+        let guild_id = guild_id as i64;
+        let since = since as i64;
+        let rows = sqlx::query!(
+            "SELECT guild_id, order_id, buyer_id, seller_id, stars, comment, created_at
+             FROM vouches WHERE guild_id = $1 AND created_at >= $2
+             ORDER BY id",
+            guild_id,
+            since,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Vouch {
+                guild_id: row.guild_id as u64,
+                order_id: row.order_id as u64,
+                buyer_id: row.buyer_id as u64,
+                seller_id: row.seller_id as u64,
+                stars: row.stars as u8,
+                comment: row.comment,
+                created_at: row.created_at as u64,
+            })
+            .collect())
+    }
+
+    async fn digest_guilds(&self) -> Result<Vec<GuildId>, String> {
+        let rows =
+            sqlx::query!("SELECT DISTINCT guild_id FROM digest_recipients ORDER BY guild_id")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| GuildId(row.guild_id as u64))
+            .collect())
+    }
+
+    async fn claim_digest(&self, guild_id: u64, week_start: u64) -> Result<bool, String> {
+        let guild_id = guild_id as i64;
+        let week_start = week_start as i64;
+        let result = sqlx::query!(
+            "INSERT INTO digests_sent (guild_id, week_start) VALUES ($1, $2)
+             ON CONFLICT (guild_id) DO UPDATE SET week_start = excluded.week_start
+             WHERE digests_sent.week_start < excluded.week_start",
+            guild_id,
+            week_start,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[cfg(feature = "orders")]
+    async fn join_queue(&self, entry: QueueEntry) -> Result<bool, String> {
+        let guild_id = entry.guild_id as i64;
+        let user_id = entry.user_id as i64;
+        let robux = entry.robux.map(|robux| robux as i64);
+        let joined_at = entry.joined_at as i64;
+        let result = sqlx::query!(
+            "INSERT INTO queue_entries (guild_id, user_id, robux, joined_at)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (guild_id, user_id) DO NOTHING",
+            guild_id,
+            user_id,
+            robux,
+            joined_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[cfg(feature = "orders")]
+    async fn leave_queue(&self, guild_id: u64, user_id: u64) -> Result<bool, String> {
+        let guild_id = guild_id as i64;
+        let user_id = user_id as i64;
+        let result = sqlx::query!(
+            "DELETE FROM queue_entries WHERE guild_id = $1 AND user_id = $2",
+            guild_id,
+            user_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[cfg(feature = "orders")]
+    async fn queue(&self, guild_id: u64) -> Result<Vec<QueueEntry>, String> {
+        let guild_id = guild_id as i64;
+        let rows = sqlx::query!(
+            "SELECT guild_id, user_id, robux, joined_at FROM queue_entries
+             WHERE guild_id = $1 ORDER BY joined_at, user_id",
+            guild_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| QueueEntry {
+                guild_id: row.guild_id as u64,
+                user_id: row.user_id as u64,
+                robux: row.robux.map(|robux| robux as u64),
+                joined_at: row.joined_at as u64,
+            })
+            .collect())
+    }
+
+    #[cfg(feature = "orders")]
+    async fn pop_queue(&self, guild_id: u64) -> Result<Option<QueueEntry>, String> {
+        let guild_id = guild_id as i64;
+        let row = sqlx::query!(
+            "DELETE FROM queue_entries
+             WHERE guild_id = $1 AND user_id = (
+                 SELECT user_id FROM queue_entries
+                 WHERE guild_id = $1 ORDER BY joined_at, user_id LIMIT 1
+             )
+             RETURNING guild_id, user_id, robux, joined_at",
+            guild_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| QueueEntry {
+            guild_id: row.guild_id as u64,
+            user_id: row.user_id as u64,
+            robux: row.robux.map(|robux| robux as u64),
+            joined_at: row.joined_at as u64,
+        }))
+    }
+
+    #[cfg(feature = "orders")]
+    async fn open_dispute(&self, dispute: Dispute) -> Result<bool, String> {
+        let order_id = dispute.order_id as i64;
+        let guild_id = dispute.guild_id as i64;
+        let opened_by = dispute.opened_by as i64;
+        let opened_at = dispute.opened_at as i64;
+        let result = sqlx::query!(
+            "INSERT INTO disputes (order_id, guild_id, opened_by, reason, opened_at)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (order_id) DO NOTHING",
+            order_id,
+            guild_id,
+            opened_by,
+            dispute.reason,
+            opened_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[cfg(feature = "orders")]
+    async fn add_refund(&self, refund: Refund) -> Result<(), String> {
+        let order_id = refund.order_id as i64;
+        let guild_id = refund.guild_id as i64;
+        let refunded_by = refund.refunded_by as i64;
+        let refunded_at = refund.refunded_at as i64;
+        sqlx::query!(
+            "INSERT INTO refunds (order_id, guild_id, gbp, usd, reason, refunded_by, refunded_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            order_id,
+            guild_id,
+            refund.gbp,
+            refund.usd,
+            refund.reason,
+            refunded_by,
+            refunded_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "orders")]
+    async fn refunded(&self, order_id: u64) -> Result<f64, String> {
+        let order_id = order_id as i64;
+        let row = sqlx::query!(
+            r#"SELECT COALESCE(SUM(gbp), 0.0) AS "gbp!" FROM refunds WHERE order_id = $1"#,
+            order_id,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.gbp)
+    }
+
+    async fn refunds(&self, guild_id: u64, since: u64) -> Result<Vec<Refund>, String> {
+        let guild_id = guild_id as i64;
+        let since = since as i64;
+        let rows = sqlx::query!(
+            "SELECT order_id, guild_id, gbp, usd, reason, refunded_by, refunded_at FROM refunds
+             WHERE guild_id = $1 AND refunded_at >= $2 ORDER BY refunded_at, id",
+            guild_id,
+            since,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Refund {
+                order_id: row.order_id as u64,
+                guild_id: row.guild_id as u64,
+                gbp: row.gbp,
+                usd: row.usd,
+                reason: row.reason,
+                refunded_by: row.refunded_by as u64,
+                refunded_at: row.refunded_at as u64,
+            })
+            .collect())
+    }
+
+    #[cfg(feature = "orders")]
+    async fn middleman_progress(&self, order_id: u64) -> Result<Option<MiddlemanProgress>, String> {
+        let order_id = order_id as i64;
+        let row = sqlx::query!(
+            "SELECT guild_id, received_by, received_at, released_by, released_at
+             FROM middleman_progress WHERE order_id = $1",
+            order_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(row.map(|row| MiddlemanProgress {
+            guild_id: row.guild_id as u64,
+            received_by: row.received_by as u64,
+            received_at: row.received_at as u64,
+            released_by: row.released_by.map(|id| id as u64),
+            released_at: row.released_at.map(|at| at as u64),
+        }))
+    }
+
+    #[cfg(feature = "orders")]
+    async fn confirm_middleman_received(
+        &self,
+        order_id: u64,
+        guild_id: u64,
+        user_id: u64,
+        at: u64,
+    ) -> Result<bool, String> {
+        let order_id = order_id as i64;
+        let guild_id = guild_id as i64;
+        let user_id = user_id as i64;
+        let at = at as i64;
+        let result = sqlx::query!(
+            "INSERT INTO middleman_progress (order_id, guild_id, received_by, received_at)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (order_id) DO NOTHING",
+            order_id,
+            guild_id,
+            user_id,
+            at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[cfg(feature = "orders")]
+    async fn confirm_middleman_released(
+        &self,
+        order_id: u64,
+        user_id: u64,
+        at: u64,
+    ) -> Result<bool, String> {
+        let order_id = order_id as i64;
+        let user_id = user_id as i64;
+        let at = at as i64;
+        let result = sqlx::query!(
+            "UPDATE middleman_progress SET released_by = $1, released_at = $2
+             WHERE order_id = $3 AND released_at IS NULL",
+            user_id,
+            at,
+            order_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_audit(&self, entry: AuditEntry) -> Result<(), String> {
+        let guild_id = entry.guild_id as i64;
+        let actor_id = entry.actor_id as i64;
+        let created_at = entry.created_at as i64;
+        sqlx::query!(
+            "INSERT INTO audit_log (guild_id, actor_id, action, setting, old_value, new_value, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            guild_id,
+            actor_id,
+            entry.action,
+            entry.setting,
+            entry.old_value,
+            entry.new_value,
+            created_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn audit_log(
+        &self,
+        guild_id: u64,
+        actor_id: Option<u64>,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, String> {
+        let guild_id = guild_id as i64;
+        let actor_id = actor_id.map(|id| id as i64);
+        let limit = i64::from(limit);
+        let rows = sqlx::query!(
+            "SELECT guild_id, actor_id, action, setting, old_value, new_value, created_at
+             FROM audit_log WHERE guild_id = $1 AND ($2::BIGINT IS NULL OR actor_id = $2)
+             ORDER BY created_at DESC, id DESC LIMIT $3",
+            guild_id,
+            actor_id,
+            limit,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| AuditEntry {
+                guild_id: row.guild_id as u64,
+                actor_id: row.actor_id as u64,
+                action: row.action,
+                setting: row.setting,
+                old_value: row.old_value,
+                new_value: row.new_value,
+                created_at: row.created_at as u64,
+            })
+            .collect())
+    }
+}
+
+async fn load_guild(
+    conn: &mut PgConnection,
+    guild_id: GuildId,
+) -> Result<GuildConfig, sqlx::Error> {
+    let id = guild_id.0 as i64;
+    let mut config = GuildConfig::default();
+
+    if let Some(row) = sqlx::query!(
+        "SELECT theme_color, theme_footer, theme_thumbnail, language,
+                ticket_category, ticket_staff_role, ticket_log_channel, ticket_middleman_role, vouch_channel, dispute_channel, vat_rate, vat_region, sheets_spreadsheet_id, sheets_sheet, api_key_hash,
+                rate_announce_channel, rate_announce_role, order_min_robux, order_max_robux, rounding_mode, rounding_decimals, sell_rate, buy_rate, forex_override, emoji_robux, emoji_gbp, emoji_usd, emoji_check, emoji_cross, audit_channel,
+                retention_history_days, retention_order_days
+         FROM guilds WHERE guild_id = $1",
+        id,
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    {
+        config.theme = Theme {
+            color: row.theme_color.map(|color| color as u32),
+            footer: row.theme_footer,
+            thumbnail: row.theme_thumbnail,
+        };
+        config.language = row.language.as_deref().map(parse_language);
+        config.tickets = TicketSettings {
+            category: row.ticket_category.map(|id| id as u64),
+            staff_role: row.ticket_staff_role.map(|id| id as u64),
+            log_channel: row.ticket_log_channel.map(|id| id as u64),
+            middleman_role: row.ticket_middleman_role.map(|id| id as u64),
+        };
+        config.vouch_channel = row.vouch_channel.map(|id| id as u64);
+        config.dispute_channel = row.dispute_channel.map(|id| id as u64);
+        config.vat = row
+            .vat_rate
+            .zip(row.vat_region)
+            .map(|(rate, region)| VatSettings { rate, region });
+        config.sheets = row
+            .sheets_spreadsheet_id
+            .zip(row.sheets_sheet)
+            .map(|(spreadsheet_id, sheet)| SheetSettings {
+                spreadsheet_id,
+                sheet,
+            });
+        config.api_key_hash = row.api_key_hash;
+        config.rate_announcements =
+            row.rate_announce_channel
+                .map(|channel_id| RateAnnouncements {
+                    channel_id: channel_id as u64,
+                    role_id: row.rate_announce_role.map(|id| id as u64),
+                });
+        config.order_limits = OrderLimits {
+            min_robux: row.order_min_robux.map(|robux| robux as u64),
+            max_robux: row.order_max_robux.map(|robux| robux as u64),
+        };
+        config.rounding = Rounding {
+            mode: RoundingMode::from_code(&row.rounding_mode),
+            decimals: row.rounding_decimals as u8,
+        };
+        config.shop_rates = ShopRates {
+            sell: row.sell_rate,
+            buy: row.buy_rate,
+        };
+        config.forex_override = row.forex_override;
+        config.emojis = Emojis {
+            robux: row.emoji_robux,
+            gbp: row.emoji_gbp,
+            usd: row.emoji_usd,
+            check: row.emoji_check,
+            cross: row.emoji_cross,
+        };
+        config.audit_channel = row.audit_channel.map(|id| id as u64);
+        config.retention = Retention {
+            history_days: row.retention_history_days.map(|days| days as u64),
+            order_days: row.retention_order_days.map(|days| days as u64),
+        };
+    }
+
+    config.tiers = sqlx::query!(
+        "SELECT min_robux, rate FROM tiers WHERE guild_id = $1 AND deleted_at IS NULL ORDER BY min_robux",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| Tier {
+        min_robux: row.min_robux as u64,
+        rate: row.rate,
+    })
+    .collect();
+
+    config.loyalty_discounts = sqlx::query!(
+        "SELECT min_robux, percent FROM loyalty_discounts WHERE guild_id = $1 ORDER BY min_robux",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| Discount {
+        min_robux: row.min_robux as u64,
+        percent: row.percent,
+    })
+    .collect();
+
+    config.role_rewards = sqlx::query!(
+        "SELECT min_robux, role_id FROM role_rewards WHERE guild_id = $1 ORDER BY min_robux",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| RoleReward {
+        min_robux: row.min_robux as u64,
+        role_id: row.role_id as u64,
+    })
+    .collect();
+
+    config.role_discounts = sqlx::query!(
+        "SELECT role_id, percent FROM role_discounts WHERE guild_id = $1 ORDER BY role_id",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| RoleDiscount {
+        role_id: row.role_id as u64,
+        percent: row.percent,
+    })
+    .collect();
+
+    config.templates = sqlx::query!(
+        "SELECT command, title, description FROM embed_templates WHERE guild_id = $1 ORDER BY command",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| EmbedTemplate {
+        command: row.command,
+        title: row.title,
+        description: row.description,
+        fields: Vec::new(),
+    })
+    .collect();
+    for row in sqlx::query!(
+        "SELECT command, name, value FROM embed_template_fields WHERE guild_id = $1
+         ORDER BY command, position",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    {
+        if let Some(template) = config
+            .templates
+            .iter_mut()
+            .find(|template| template.command == row.command)
+        {
+            template.fields.push((row.name, row.value));
+        }
+    }
+
+    config.payment_handles = sqlx::query!(
+        "SELECT method, handle FROM payment_handles WHERE guild_id = $1",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .filter_map(|row| {
+        PaymentMethod::from_code(&row.method).map(|method| PaymentHandle {
+            method,
+            handle: row.handle,
+        })
+    })
+    .collect();
+
+    config.webhooks = sqlx::query!(
+        "SELECT url, secret FROM webhooks WHERE guild_id = $1 ORDER BY url",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| Webhook {
+        url: row.url,
+        secret: row.secret,
+    })
+    .collect();
+
+    config.autoreply_channels = sqlx::query!(
+        "SELECT channel_id FROM autoreply_channels WHERE guild_id = $1 ORDER BY channel_id",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| row.channel_id as u64)
+    .collect();
+
+    config.disabled_features = sqlx::query!(
+        "SELECT feature FROM disabled_features WHERE guild_id = $1 ORDER BY feature",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .filter_map(|row| Feature::parse(&row.feature))
+    .collect();
+
+    config.enabled_features = sqlx::query!(
+        "SELECT feature FROM enabled_features WHERE guild_id = $1 ORDER BY feature",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .filter_map(|row| Feature::parse(&row.feature))
+    .collect();
+
+    config.digest_recipients = sqlx::query!(
+        "SELECT user_id FROM digest_recipients WHERE guild_id = $1 ORDER BY user_id",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| row.user_id as u64)
+    .collect();
+
+    config.sellers = sqlx::query!(
+        "SELECT user_id, rate FROM sellers WHERE guild_id = $1 ORDER BY user_id",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| Seller {
+        user_id: row.user_id as u64,
+        rate: row.rate,
+        payment_handles: Vec::new(),
+    })
+    .collect();
+    for row in sqlx::query!(
+        "SELECT user_id, method, handle FROM seller_payment_handles WHERE guild_id = $1",
+        id,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    {
+        let seller = config
+            .sellers
+            .iter_mut()
+            .find(|seller| seller.user_id == row.user_id as u64);
+        if let (Some(seller), Some(method)) = (seller, PaymentMethod::from_code(&row.method)) {
+            seller.payment_handles.push(PaymentHandle {
+                method,
+                handle: row.handle,
+            });
+        }
+    }
+
+    config.priceboard = sqlx::query!(
+        "SELECT channel_id, message_id, refresh_minutes, updated_at, language
+         FROM priceboards WHERE guild_id = $1",
+        id,
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    .map(|row| PriceBoard {
+        channel_id: row.channel_id as u64,
+        message_id: row.message_id as u64,
+        refresh_minutes: row.refresh_minutes as u64,
+        updated_at: row.updated_at as u64,
+        language: parse_language(&row.language),
+    });
+
+    Ok(config)
+}
+
+async fn save_guild(
+    conn: &mut PgConnection,
+    guild_id: GuildId,
+    config: &GuildConfig,
+) -> Result<(), sqlx::Error> {
+    let id = guild_id.0 as i64;
+    let color = config.theme.color.map(i64::from);
+    let language = config.language.map(Language::code);
+    let ticket_category = config.tickets.category.map(|id| id as i64);
+    let ticket_staff_role = config.tickets.staff_role.map(|id| id as i64);
+    let ticket_log_channel = config.tickets.log_channel.map(|id| id as i64);
+    let ticket_middleman_role = config.tickets.middleman_role.map(|id| id as i64);
+    let vouch_channel = config.vouch_channel.map(|id| id as i64);
+    let dispute_channel = config.dispute_channel.map(|id| id as i64);
+    let vat_rate = config.vat.as_ref().map(|vat| vat.rate);
+    let vat_region = config.vat.as_ref().map(|vat| vat.region.as_str());
+    let sheets_spreadsheet_id = config
+        .sheets
+        .as_ref()
+        .map(|sheets| sheets.spreadsheet_id.as_str());
+    let sheets_sheet = config.sheets.as_ref().map(|sheets| sheets.sheet.as_str());
+    let rate_announce_channel = config
+        .rate_announcements
+        .as_ref()
+        .map(|announcements| announcements.channel_id as i64);
+    let rate_announce_role = config
+        .rate_announcements
+        .as_ref()
+        .and_then(|announcements| announcements.role_id)
+        .map(|id| id as i64);
+
+    let order_min_robux = config.order_limits.min_robux.map(|robux| robux as i64);
+    let order_max_robux = config.order_limits.max_robux.map(|robux| robux as i64);
+
+    let rounding_mode = config.rounding.mode.code();
+    let rounding_decimals = i64::from(config.rounding.decimals);
+
+    let sell_rate = config.shop_rates.sell;
+    let buy_rate = config.shop_rates.buy;
+
+    let forex_override = config.forex_override;
+
+    let emoji_robux = config.emojis.robux.as_deref();
+    let emoji_gbp = config.emojis.gbp.as_deref();
+    let emoji_usd = config.emojis.usd.as_deref();
+    let emoji_check = config.emojis.check.as_deref();
+    let emoji_cross = config.emojis.cross.as_deref();
+    let audit_channel = config.audit_channel.map(|id| id as i64);
+    let retention_history_days = config.retention.history_days.map(|days| days as i64);
+    let retention_order_days = config.retention.order_days.map(|days| days as i64);
+    sqlx::query!(
+        "INSERT INTO guilds (guild_id, theme_color, theme_footer, theme_thumbnail, language,
+                             ticket_category, ticket_staff_role, ticket_log_channel, ticket_middleman_role, vouch_channel, dispute_channel, vat_rate, vat_region, sheets_spreadsheet_id, sheets_sheet, api_key_hash,
+                             rate_announce_channel, rate_announce_role, order_min_robux, order_max_robux, rounding_mode, rounding_decimals, sell_rate, buy_rate, forex_override, emoji_robux, emoji_gbp, emoji_usd, emoji_check, emoji_cross, audit_channel,
+                             retention_history_days, retention_order_days)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32, $33)
+         ON CONFLICT (guild_id) DO UPDATE SET
+             theme_color = excluded.theme_color,
+             theme_footer = excluded.theme_footer,
+             theme_thumbnail = excluded.theme_thumbnail,
+             language = excluded.language,
+             ticket_category = excluded.ticket_category,
+             ticket_staff_role = excluded.ticket_staff_role,
+             ticket_log_channel = excluded.ticket_log_channel,
+             ticket_middleman_role = excluded.ticket_middleman_role,
+             vouch_channel = excluded.vouch_channel,
+             dispute_channel = excluded.dispute_channel,
+             vat_rate = excluded.vat_rate,
+             vat_region = excluded.vat_region,
+             sheets_spreadsheet_id = excluded.sheets_spreadsheet_id,
+             sheets_sheet = excluded.sheets_sheet,
+             api_key_hash = excluded.api_key_hash,
+             rate_announce_channel = excluded.rate_announce_channel,
+             rate_announce_role = excluded.rate_announce_role,
+             order_min_robux = excluded.order_min_robux,
+             order_max_robux = excluded.order_max_robux,
+             rounding_mode = excluded.rounding_mode,
+             rounding_decimals = excluded.rounding_decimals,
+             sell_rate = excluded.sell_rate,
+             buy_rate = excluded.buy_rate,
+             forex_override = excluded.forex_override,
+             emoji_robux = excluded.emoji_robux,
+             emoji_gbp = excluded.emoji_gbp,
+             emoji_usd = excluded.emoji_usd,
+             emoji_check = excluded.emoji_check,
+             emoji_cross = excluded.emoji_cross,
+             audit_channel = excluded.audit_channel,
+             retention_history_days = excluded.retention_history_days,
+             retention_order_days = excluded.retention_order_days",
+        id,
+        color,
+        config.theme.footer,
+        config.theme.thumbnail,
+        language,
+        ticket_category,
+        ticket_staff_role,
+        ticket_log_channel,
+        ticket_middleman_role,
+        vouch_channel,
+        dispute_channel,
+        vat_rate,
+        vat_region,
+        sheets_spreadsheet_id,
+        sheets_sheet,
+        config.api_key_hash,
+        rate_announce_channel,
+        rate_announce_role,
+        order_min_robux,
+        order_max_robux,
+        rounding_mode,
+        rounding_decimals,
+        sell_rate,
+        buy_rate,
+        forex_override,
+        emoji_robux,
+        emoji_gbp,
+        emoji_usd,
+        emoji_check,
+        emoji_cross,
+        audit_channel,
+        retention_history_days,
+        retention_order_days,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM tiers WHERE guild_id = $1 AND deleted_at IS NULL",
+        id
+    )
+    .execute(&mut *conn)
+    .await?;
+    for tier in &config.tiers {
+        let min_robux = tier.min_robux as i64;
+        sqlx::query!(
+            "INSERT INTO tiers (guild_id, min_robux, rate) VALUES ($1, $2, $3)
+             ON CONFLICT (guild_id, min_robux) DO UPDATE SET rate = excluded.rate, deleted_at = NULL",
+            id,
+            min_robux,
+            tier.rate,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM loyalty_discounts WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for discount in &config.loyalty_discounts {
+        let min_robux = discount.min_robux as i64;
+        sqlx::query!(
+            "INSERT INTO loyalty_discounts (guild_id, min_robux, percent) VALUES ($1, $2, $3)",
+            id,
+            min_robux,
+            discount.percent,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM role_rewards WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for reward in &config.role_rewards {
+        let min_robux = reward.min_robux as i64;
+        let role_id = reward.role_id as i64;
+        sqlx::query!(
+            "INSERT INTO role_rewards (guild_id, min_robux, role_id) VALUES ($1, $2, $3)",
+            id,
+            min_robux,
+            role_id,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM role_discounts WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for discount in &config.role_discounts {
+        let role_id = discount.role_id as i64;
+        sqlx::query!(
+            "INSERT INTO role_discounts (guild_id, role_id, percent) VALUES ($1, $2, $3)",
+            id,
+            role_id,
+            discount.percent,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM embed_templates WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query!("DELETE FROM embed_template_fields WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for template in &config.templates {
+        sqlx::query!(
+            "INSERT INTO embed_templates (guild_id, command, title, description)
+             VALUES ($1, $2, $3, $4)",
+            id,
+            template.command,
+            template.title,
+            template.description,
+        )
+        .execute(&mut *conn)
+        .await?;
+        for (position, (name, value)) in template.fields.iter().enumerate() {
+            let position = position as i32;
+            sqlx::query!(
+                "INSERT INTO embed_template_fields (guild_id, command, position, name, value)
+                 VALUES ($1, $2, $3, $4, $5)",
+                id,
+                template.command,
+                position,
+                name,
+                value,
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+
+    sqlx::query!("DELETE FROM payment_handles WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for payment in &config.payment_handles {
+        let method = payment.method.code();
+        sqlx::query!(
+            "INSERT INTO payment_handles (guild_id, method, handle) VALUES ($1, $2, $3)",
+            id,
+            method,
+            payment.handle,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM webhooks WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for webhook in &config.webhooks {
+        sqlx::query!(
+            "INSERT INTO webhooks (guild_id, url, secret) VALUES ($1, $2, $3)",
+            id,
+            webhook.url,
+            webhook.secret,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM autoreply_channels WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for channel_id in &config.autoreply_channels {
+        let channel_id = *channel_id as i64;
+        sqlx::query!(
+            "INSERT INTO autoreply_channels (guild_id, channel_id) VALUES ($1, $2)",
+            id,
+            channel_id,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM disabled_features WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for feature in &config.disabled_features {
+        let feature = feature.code();
+        sqlx::query!(
+            "INSERT INTO disabled_features (guild_id, feature) VALUES ($1, $2)",
+            id,
+            feature,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM enabled_features WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for feature in &config.enabled_features {
+        let feature = feature.code();
+        sqlx::query!(
+            "INSERT INTO enabled_features (guild_id, feature) VALUES ($1, $2)",
+            id,
+            feature,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM digest_recipients WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for user_id in &config.digest_recipients {
+        let user_id = *user_id as i64;
+        sqlx::query!(
+            "INSERT INTO digest_recipients (guild_id, user_id) VALUES ($1, $2)",
+            id,
+            user_id,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM sellers WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query!("DELETE FROM seller_payment_handles WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    for seller in &config.sellers {
+        let user_id = seller.user_id as i64;
+        sqlx::query!(
+            "INSERT INTO sellers (guild_id, user_id, rate) VALUES ($1, $2, $3)",
+            id,
+            user_id,
+            seller.rate,
+        )
+        .execute(&mut *conn)
+        .await?;
+        for payment in &seller.payment_handles {
+            let method = payment.method.code();
+            sqlx::query!(
+                "INSERT INTO seller_payment_handles (guild_id, user_id, method, handle)
+                 VALUES ($1, $2, $3, $4)",
+                id,
+                user_id,
+                method,
+                payment.handle,
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+
+    sqlx::query!("DELETE FROM priceboards WHERE guild_id = $1", id)
+        .execute(&mut *conn)
+        .await?;
+    if let Some(board) = &config.priceboard {
+        let channel_id = board.channel_id as i64;
+        let message_id = board.message_id as i64;
+        let refresh_minutes = board.refresh_minutes as i64;
+        let updated_at = board.updated_at as i64;
+        let language = board.language.code();
+        sqlx::query!(
+            "INSERT INTO priceboards (guild_id, channel_id, message_id, refresh_minutes, updated_at, language)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+            id,
+            channel_id,
+            message_id,
+            refresh_minutes,
+            updated_at,
+            language,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
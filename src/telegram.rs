@@ -0,0 +1,232 @@
+use serenity::model::id::GuildId;
+use std::sync::Arc;
+use teloxide::{prelude::*, utils::command::BotCommands};
+use tokio::sync::RwLock;
+
+use crate::{
+    crypto::Crypto,
+    i18n::{t, tf, Language},
+    methods::DeliveryMethod,
+    parse, pricing,
+    rates::Rates,
+    storage::{GuildConfig, Storage},
+    validation, vat_summary,
+};
+
+#[derive(BotCommands, Clone)]
+#[command(rename_rule = "lowercase")]
+enum Command {
+    #[command(description = "price Robux: /price 10k [a/t] [gamepass|devproduct|group|giftcard]")]
+    Price(String),
+    #[command(description = "convert money: /convert 10 GBP [USD|BTC|ETH|LTC]")]
+    Convert(String),
+    #[command(description = "Robux an amount buys: /robux 10 [GBP|USD]")]
+    Robux(String),
+    #[command(description = "list commands")]
+    Help,
+}
+
+/// What the Discord bot shares with the Telegram frontend.
+#[derive(Clone)]
+struct Shared {
+    storage: Storage,
+    rates: Arc<RwLock<Rates>>,
+    /// The Discord server whose tiers, VAT and language Telegram quotes use.
+    guild_id: Option<GuildId>,
+}
+
+impl Shared {
+    async fn config(&self) -> Result<GuildConfig, String> {
+        match self.guild_id {
+            Some(guild_id) => self.storage.guild(guild_id).await,
+            None => Ok(GuildConfig::default()),
+        }
+    }
+}
+
+/// Answers `/price`, `/convert` and `/robux` on Telegram until the bot is stopped.
+pub async fn run(
+    token: String,
+    guild_id: Option<GuildId>,
+    storage: Storage,
+    rates: Arc<RwLock<Rates>>,
+) {
+    let bot = Bot::new(token);
+    if let Err(error) = bot.set_my_commands(Command::bot_commands()).await {
+        eprintln!("Error registering Telegram commands: {}", error);
+    }
+    let shared = Shared {
+        storage,
+        rates,
+        guild_id,
+    };
+
+    println!("Telegram bot started");
+    Dispatcher::builder(
+        bot,
+        Update::filter_message()
+            .filter_command::<Command>()
+            .endpoint(answer),
+    )
+    .dependencies(dptree::deps![shared])
+    .build()
+    .dispatch()
+    .await;
+}
+
+async fn answer(
+    bot: Bot,
+    message: Message,
+    command: Command,
+    shared: Shared,
+) -> ResponseResult<()> {
+    let reply = respond(&shared, command).await.unwrap_or_else(|error| {
+        eprintln!("Error handling Telegram command: {}", error);
+        format!("❌ {}", error)
+    });
+    bot.send_message(message.chat.id, reply).await?;
+    Ok(())
+}
+
+async fn respond(shared: &Shared, command: Command) -> Result<String, String> {
+    let config = shared.config().await?;
+    let lang = config.language.unwrap_or_default();
+    match command {
+        Command::Price(args) => price(shared, &config, lang, &args).await,
+        Command::Convert(args) => convert(shared, lang, &args).await,
+        Command::Robux(args) => robux(shared, &config, lang, &args).await,
+        Command::Help => Ok(Command::descriptions().to_string()),
+    }
+}
+
+async fn price(
+    shared: &Shared,
+    config: &GuildConfig,
+    lang: Language,
+    args: &str,
+) -> Result<String, String> {
+    let mut args = args.split_whitespace();
+    let robux = parse::parse_robux_amount(args.next().unwrap_or_default(), lang)
+        .and_then(|robux| validation::validate_robux(robux, lang))?;
+    let after_tax = match args.next().unwrap_or("b/t") {
+        "b/t" => false,
+        "a/t" => true,
+        _ => return Err(t(lang, "price.invalid_type").to_string()),
+    };
+    let method = DeliveryMethod::parse(args.next().unwrap_or("gamepass"))
+        .ok_or(t(lang, "price.invalid_method"))?;
+
+    let base_rate = pricing::base_rate(&config.tiers, &config.shop_rates, robux, None);
+    let quote = pricing::quote(
+        robux as f64,
+        base_rate,
+        method,
+        after_tax,
+        None,
+        config.vat.as_ref(),
+    );
+    let usd_rate = config
+        .forex_override
+        .unwrap_or(shared.rates.read().await.gbp_to_usd);
+
+    let mut reply = format!(
+        "{}\n{}: {} R$ ({})\n{}: {}\n{}: £{:.2} / 1k R$\n{}: {} R$",
+        t(lang, "price.title"),
+        t(lang, "price.amount"),
+        robux,
+        if after_tax { "a/t" } else { "b/t" },
+        t(lang, "price.method"),
+        method.name(lang),
+        t(lang, "price.rate"),
+        base_rate * 1000.0,
+        method.listing_label(lang),
+        method.listing_price(robux as f64, after_tax),
+    );
+    if let Some(vat) = &config.vat {
+        reply.push_str(&format!(
+            "\n{}: £{:.2}\n{}: £{:.2}",
+            t(lang, "vat.excluding"),
+            quote.net,
+            tf(lang, "vat.amount", &[("summary", &vat_summary(lang, vat))]),
+            quote.tax
+        ));
+    }
+    reply.push_str(&format!(
+        "\n{}: £{:.2}\n{}: ${:.2}",
+        tf(lang, "common.amount_in", &[("currency", &"GBP")]),
+        quote.gbp(),
+        tf(lang, "common.amount_in", &[("currency", &"USD")]),
+        quote.gbp() * usd_rate
+    ));
+    Ok(reply)
+}
+
+async fn convert(shared: &Shared, lang: Language, args: &str) -> Result<String, String> {
+    let mut args = args.split_whitespace();
+    let amount = fiat_amount(args.next(), lang)?;
+    let from = args.next().unwrap_or("GBP").to_uppercase();
+    let to = args
+        .next()
+        .map(str::to_uppercase)
+        .unwrap_or_else(|| if from == "GBP" { "USD" } else { "GBP" }.to_string());
+
+    let rates = shared.rates.read().await;
+    let gbp = match from.as_str() {
+        "GBP" => amount,
+        "USD" => amount / rates.gbp_to_usd,
+        _ => return Err(t(lang, "error.invalid_currency").to_string()),
+    };
+    let converted = match to.as_str() {
+        "GBP" => format!("{:.2}", gbp),
+        "USD" => format!("{:.2}", gbp * rates.gbp_to_usd),
+        code => {
+            let coin = Crypto::parse(code).ok_or(t(lang, "error.invalid_currency"))?;
+            let price = rates
+                .crypto_gbp
+                .get(&coin)
+                .ok_or(t(lang, "crypto.unavailable"))?;
+            format!("{:.8}", gbp / price)
+        }
+    };
+    Ok(format!("{:.2} {} = {} {}", amount, from, converted, to))
+}
+
+async fn robux(
+    shared: &Shared,
+    config: &GuildConfig,
+    lang: Language,
+    args: &str,
+) -> Result<String, String> {
+    let mut args = args.split_whitespace();
+    let amount = fiat_amount(args.next(), lang)?;
+    let currency = args.next().unwrap_or("GBP").to_uppercase();
+    let usd_rate = config
+        .forex_override
+        .unwrap_or(shared.rates.read().await.gbp_to_usd);
+    let (gbp, usd) = match currency.as_str() {
+        "GBP" => (amount, amount * usd_rate),
+        "USD" => (amount / usd_rate, amount),
+        _ => return Err(t(lang, "error.invalid_currency").to_string()),
+    };
+
+    Ok(tf(
+        lang,
+        "robux.affords",
+        &[
+            ("amount", &format!("{:.2}", amount)),
+            ("currency", &currency),
+            (
+                "robux",
+                &((gbp / config.shop_rates.sell_per_robux()) as i64),
+            ),
+            ("gbp", &format!("£{:.2}", gbp)),
+            ("usd", &format!("${:.2}", usd)),
+        ],
+    ))
+}
+
+fn fiat_amount(arg: Option<&str>, lang: Language) -> Result<f64, String> {
+    arg.and_then(|amount| amount.parse::<f64>().ok())
+        .ok_or_else(|| t(lang, "error.invalid_options").to_string())
+        .and_then(|amount| validation::validate_fiat(amount, lang))
+}
@@ -0,0 +1,211 @@
+use serde_json::Value;
+use serenity::builder::CreateEmbed;
+
+use crate::i18n::{t, tf, Language};
+
+/// Commands whose replies a guild can reword with `/template`.
+pub const COMMANDS: [&str; 2] = ["price", "robux"];
+
+/// Placeholders templates can use. `details` is the text the command would
+/// have replied with, so a template can wrap it instead of replacing it.
+pub const VARIABLES: [&str; 6] = ["robux", "gbp", "usd", "rate", "user", "details"];
+
+/// Custom fields a template can add.
+pub const MAX_FIELDS: usize = 5;
+/// Discord's embed limits, kept below so substituted values still fit.
+const MAX_TITLE: usize = 200;
+const MAX_DESCRIPTION: usize = 2000;
+const MAX_FIELD_NAME: usize = 200;
+const MAX_FIELD_VALUE: usize = 800;
+
+/// A guild's own wording for one command's reply, set with `/template`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EmbedTemplate {
+    pub command: String,
+    /// Replaces the command's title.
+    pub title: Option<String>,
+    /// Replaces the command's description.
+    pub description: Option<String>,
+    /// Added after the command's own fields, as name and value.
+    pub fields: Vec<(String, String)>,
+}
+
+impl EmbedTemplate {
+    pub fn new(command: &str) -> Self {
+        Self {
+            command: command.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Rewords `embed` with the template, filling placeholders from `values`
+    /// and `{details}` from the embed's own description.
+    pub fn apply(&self, embed: &mut CreateEmbed, values: &[(&str, String)]) {
+        let details = embed
+            .0
+            .get("description")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let render = |text: &str| {
+            let mut text = text.replace("{details}", &details);
+            for (name, value) in values {
+                text = text.replace(&format!("{{{}}}", name), value);
+            }
+            text
+        };
+
+        if let Some(title) = &self.title {
+            embed.title(render(title));
+        }
+        if let Some(description) = &self.description {
+            embed.description(render(description));
+        }
+        for (name, value) in &self.fields {
+            embed.field(render(name), render(value), false);
+        }
+    }
+}
+
+/// The template for `command`, if the guild set one.
+pub fn find<'a>(templates: &'a [EmbedTemplate], command: &str) -> Option<&'a EmbedTemplate> {
+    templates
+        .iter()
+        .find(|template| template.command == command)
+}
+
+/// The template for `command`, added empty in command order if there isn't one
+/// yet.
+pub fn entry<'a>(templates: &'a mut Vec<EmbedTemplate>, command: &str) -> &'a mut EmbedTemplate {
+    let index = match templates.binary_search_by(|template| template.command.as_str().cmp(command))
+    {
+        Ok(index) => index,
+        Err(index) => {
+            templates.insert(index, EmbedTemplate::new(command));
+            index
+        }
+    };
+    &mut templates[index]
+}
+
+/// Removes the template for `command`, returning whether there was one.
+pub fn remove(templates: &mut Vec<EmbedTemplate>, command: &str) -> bool {
+    let before = templates.len();
+    templates.retain(|template| template.command != command);
+    templates.len() != before
+}
+
+/// Checks a title's text: not too long and only known placeholders.
+pub fn validate_title(text: &str, lang: Language) -> Result<String, String> {
+    validate(text, MAX_TITLE, lang)
+}
+
+pub fn validate_description(text: &str, lang: Language) -> Result<String, String> {
+    validate(text, MAX_DESCRIPTION, lang)
+}
+
+pub fn validate_field(name: &str, value: &str, lang: Language) -> Result<(String, String), String> {
+    Ok((
+        validate(name, MAX_FIELD_NAME, lang)?,
+        validate(value, MAX_FIELD_VALUE, lang)?,
+    ))
+}
+
+fn validate(text: &str, max: usize, lang: Language) -> Result<String, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(t(lang, "error.invalid_options").to_string());
+    }
+    if text.chars().count() > max {
+        return Err(tf(lang, "template.too_long", &[("max", &max)]));
+    }
+    if let Some(unknown) = placeholders(text).find(|name| !VARIABLES.contains(name)) {
+        return Err(tf(
+            lang,
+            "template.unknown_variable",
+            &[("variable", &unknown), ("variables", &variable_list())],
+        ));
+    }
+    Ok(text.to_string())
+}
+
+/// `{robux}, {gbp}, …` for showing admins what they can use.
+pub fn variable_list() -> String {
+    VARIABLES
+        .iter()
+        .map(|name| format!("`{{{}}}`", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The names inside `{…}` in `text`.
+fn placeholders(text: &str) -> impl Iterator<Item = &str> {
+    text.split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(template: &EmbedTemplate, description: &str) -> CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        embed.title("Price").description(description);
+        template.apply(
+            &mut embed,
+            &[("robux", "1,000".to_string()), ("gbp", "£3.50".to_string())],
+        );
+        embed
+    }
+
+    #[test]
+    fn fills_placeholders() {
+        let template = EmbedTemplate {
+            command: "price".to_string(),
+            title: Some("{robux} R$ for {gbp}".to_string()),
+            description: None,
+            fields: vec![("Deal".to_string(), "Only {gbp}!".to_string())],
+        };
+        let embed = rendered(&template, "Rate: £3.50");
+        assert_eq!(embed.0["title"], "1,000 R$ for £3.50");
+        assert_eq!(embed.0["description"], "Rate: £3.50");
+        assert_eq!(embed.0["fields"][0]["name"], "Deal");
+        assert_eq!(embed.0["fields"][0]["value"], "Only £3.50!");
+    }
+
+    #[test]
+    fn wraps_the_original_description() {
+        let template = EmbedTemplate {
+            description: Some("Thanks for shopping!\n{details}".to_string()),
+            ..EmbedTemplate::new("price")
+        };
+        let embed = rendered(&template, "Rate: £3.50");
+        assert_eq!(embed.0["title"], "Price");
+        assert_eq!(embed.0["description"], "Thanks for shopping!\nRate: £3.50");
+    }
+
+    #[test]
+    fn rejects_unknown_placeholders() {
+        assert!(validate_title("{robux} for {gbp}", Language::En).is_ok());
+        assert!(validate_title("{robux} for {price}", Language::En).is_err());
+        assert!(validate_title("   ", Language::En).is_err());
+        assert!(validate_title(&"a".repeat(MAX_TITLE + 1), Language::En).is_err());
+    }
+
+    #[test]
+    fn keeps_one_template_per_command() {
+        let mut templates = Vec::new();
+        entry(&mut templates, "robux").title = Some("Robux".to_string());
+        entry(&mut templates, "price").title = Some("Price".to_string());
+        entry(&mut templates, "robux").description = Some("{details}".to_string());
+        assert_eq!(templates.len(), 2);
+        assert_eq!(templates[0].command, "price");
+        assert_eq!(
+            find(&templates, "robux").unwrap().description.as_deref(),
+            Some("{details}")
+        );
+        assert!(remove(&mut templates, "price"));
+        assert!(!remove(&mut templates, "price"));
+    }
+}
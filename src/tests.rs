@@ -0,0 +1,796 @@
+//! Runs slash commands end to end without a gateway connection. Interactions
+//! are built from the JSON Discord would send, and replies are recorded by a
+//! [`reply::Responder`] instead of going out over HTTP.
+
+use super::*;
+use reply::Responder;
+use serde_json::{json, Value};
+use serenity::{client::bridge::gateway::ShardMessenger, http::Http};
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicU64, Mutex},
+};
+
+const GUILD_ID: u64 = 100;
+const USER_ID: u64 = 200;
+/// Interactions are only handled once per id, so each test command gets its own.
+static NEXT_INTERACTION_ID: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Default)]
+struct Recorder {
+    replies: Mutex<Vec<Reply>>,
+}
+
+#[async_trait]
+impl Responder for Recorder {
+    async fn respond(
+        &self,
+        _command: &ApplicationCommandInteraction,
+        reply: &Reply,
+    ) -> serenity::Result<()> {
+        self.replies.lock().unwrap().push(reply.clone());
+        Ok(())
+    }
+}
+
+/// A bot with an empty in-memory database and the fallback exchange rate.
+struct Harness {
+    ctx: Context,
+    recorder: Arc<Recorder>,
+}
+
+impl Harness {
+    async fn new() -> Self {
+        let storage = storage::connect("sqlite::memory:").await.unwrap();
+        let recorder = Arc::new(Recorder::default());
+        let mut data = TypeMap::new();
+        data.insert::<AppState>(Arc::new(AppState {
+            storage,
+            rates: Arc::new(RwLock::new(Rates::new(GBP_TO_USD_RATE))),
+            stats: Arc::new(BotStats::new()),
+            handled_interactions: SeenIds::new(HANDLED_INTERACTION_SECONDS),
+            http: reqwest::Client::new(),
+            responder: recorder.clone(),
+            shard_manager: None,
+            command_guild: None,
+            log_channel: None,
+            backup_channel: None,
+            #[cfg(any(feature = "orders", feature = "roblox"))]
+            roblox: None,
+            sheets: None,
+        }));
+        // Nothing reads from the shard's end, and the token is never sent.
+        let (shard_tx, _) = futures::channel::mpsc::unbounded();
+
+        Self {
+            ctx: Context {
+                data: Arc::new(RwLock::new(data)),
+                shard: ShardMessenger::new(shard_tx),
+                shard_id: 0,
+                http: Arc::new(Http::new("")),
+            },
+            recorder,
+        }
+    }
+
+    /// Runs `/name` with `options` the way the gateway would and returns the
+    /// replies it sent.
+    async fn run(&self, name: &str, options: Value) -> Vec<Reply> {
+        self.send(command(name, options)).await
+    }
+
+    /// Runs `command` and returns the replies it sent.
+    async fn send(&self, command: ApplicationCommandInteraction) -> Vec<Reply> {
+        let handler = Handler {
+            tasks_started: AtomicBool::new(true),
+        };
+        let interaction = Interaction::ApplicationCommand(command);
+        handler
+            .interaction_create(self.ctx.clone(), interaction)
+            .await;
+        std::mem::take(&mut *self.recorder.replies.lock().unwrap())
+    }
+
+    /// The embed of the single reply to `/name`.
+    async fn embed(&self, name: &str, options: Value) -> HashMap<&'static str, Value> {
+        let mut replies = self.run(name, options).await;
+        assert_eq!(replies.len(), 1, "/{} sent {} replies", name, replies.len());
+        replies
+            .remove(0)
+            .embed
+            .unwrap_or_else(|| panic!("/{} didn't reply with an embed", name))
+            .0
+    }
+}
+
+/// A slash command run by a member with no roles in [`GUILD_ID`].
+fn command(name: &str, options: Value) -> ApplicationCommandInteraction {
+    serde_json::from_value(json!({
+        "id": NEXT_INTERACTION_ID.fetch_add(1, Ordering::Relaxed).to_string(),
+        "application_id": "2",
+        "type": 2,
+        "token": "token",
+        "version": 1,
+        "locale": "en-US",
+        "guild_id": GUILD_ID.to_string(),
+        "channel_id": "3",
+        "data": {"id": "4", "name": name, "type": 1, "options": options},
+        "member": {
+            "user": {
+                "id": USER_ID.to_string(),
+                "username": "buyer",
+                "discriminator": "0001",
+                "avatar": null,
+            },
+            "roles": [],
+            "joined_at": "2026-01-01T00:00:00Z",
+            "deaf": false,
+            "mute": false,
+        },
+    }))
+    .unwrap()
+}
+
+/// Like [`command`], in a DM with the bot.
+fn dm_command(name: &str, options: Value) -> ApplicationCommandInteraction {
+    let mut command = command(name, options);
+    command.guild_id = None;
+    command.member = None;
+    command.locale = "fr".to_string();
+    command
+}
+
+/// A command option, typed from its value the way Discord sends it.
+fn option(name: &str, value: Value) -> Value {
+    let kind = match &value {
+        Value::String(_) => CommandOptionType::String,
+        Value::Bool(_) => CommandOptionType::Boolean,
+        Value::Number(number) if number.is_f64() => CommandOptionType::Number,
+        _ => CommandOptionType::Integer,
+    };
+    json!({"name": name, "type": kind as u8, "value": value})
+}
+
+fn text(embed: &HashMap<&'static str, Value>, key: &str) -> String {
+    embed
+        .get(key)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[tokio::test]
+async fn quotes_a_price() {
+    let harness = Harness::new().await;
+    let embed = harness
+        .embed(
+            "price",
+            json!([option("type", json!("b/t")), option("amount", json!("1k"))]),
+        )
+        .await;
+    let description = text(&embed, "description");
+    assert!(
+        description.contains("**Amount of Robux:** 1,000"),
+        "{}",
+        description
+    );
+    assert!(
+        description.contains("**Rate:** £3.50 / 1k R$"),
+        "{}",
+        description
+    );
+}
+
+#[tokio::test]
+async fn quotes_add_vat_on_top() {
+    let harness = Harness::new().await;
+    harness
+        .embed(
+            "vat",
+            json!([{"name": "set", "type": 1, "options": [
+                option("rate", json!(20.0)),
+                option("region", json!("uk")),
+            ]}]),
+        )
+        .await;
+    let embed = harness
+        .embed(
+            "price",
+            json!([option("type", json!("b/t")), option("amount", json!("1k"))]),
+        )
+        .await;
+    let fields = serde_json::to_string(&embed["fields"]).unwrap();
+    // £3.50 plus 20% VAT of £0.70.
+    for value in ["£4.20", "Excl. VAT", "£3.50", "£0.70"] {
+        assert!(fields.contains(value), "{} not in {}", value, fields);
+    }
+}
+
+#[tokio::test]
+async fn reads_options_by_name_in_any_order() {
+    let harness = Harness::new().await;
+    let currency = option("currency", json!("GBP"));
+    let amount = option("amount", json!(10.5));
+    let in_order = harness
+        .embed("robux", json!([currency.clone(), amount.clone()]))
+        .await;
+    let reordered = harness.embed("robux", json!([amount, currency])).await;
+    assert_eq!(in_order, reordered);
+}
+
+#[tokio::test]
+async fn replies_with_the_error_when_options_are_missing() {
+    let harness = Harness::new().await;
+    let replies = harness
+        .run("price", json!([option("amount", json!("1000"))]))
+        .await;
+    assert_eq!(replies.len(), 1);
+    assert!(replies[0].ephemeral);
+    assert_eq!(
+        replies[0].content.as_deref(),
+        Some(t(Language::En, "error.invalid_options"))
+    );
+}
+
+#[tokio::test]
+async fn saves_guild_settings() {
+    let harness = Harness::new().await;
+    harness
+        .embed(
+            "rounding",
+            json!([option("mode", json!("up")), option("decimals", json!(0))]),
+        )
+        .await;
+    let config = storage(&harness.ctx)
+        .await
+        .guild(GuildId(GUILD_ID))
+        .await
+        .unwrap();
+    assert_eq!(config.rounding.mode, RoundingMode::Up);
+    assert_eq!(config.rounding.decimals, 0);
+}
+
+#[tokio::test]
+async fn split_shares_add_up_to_the_rounded_total() {
+    let harness = Harness::new().await;
+    harness
+        .embed(
+            "rounding",
+            json!([option("mode", json!("up")), option("decimals", json!(0))]),
+        )
+        .await;
+    // 3,500 R$ at £3.50 per 1,000 is £12.25, shown rounded up to £13.
+    let embed = harness
+        .embed(
+            "split",
+            json!([
+                option("robux", json!("3500")),
+                option("people", json!(3)),
+                option("currency", json!("GBP")),
+            ]),
+        )
+        .await;
+    assert_eq!(text(&embed, "description"), "1 × £5\n2 × £4");
+    assert_eq!(embed["fields"][0]["value"], "£13");
+}
+
+#[tokio::test]
+async fn attaches_the_price_list_image() {
+    let harness = Harness::new().await;
+    let replies = harness
+        .run(
+            "pricelist",
+            json!([{"name": "image", "type": 1, "options": []}]),
+        )
+        .await;
+    assert_eq!(replies.len(), 1);
+    let (png, filename) = replies[0].file.as_ref().expect("no image attached");
+    assert_eq!(filename, "pricelist.png");
+    assert!(png.starts_with(b"\x89PNG"));
+}
+
+#[tokio::test]
+async fn bulk_quotes_attach_a_quotation_document() {
+    let harness = Harness::new().await;
+    let replies = harness
+        .run(
+            "quote",
+            json!([{"name": "bulk", "type": 1, "options": [
+                option("amounts", json!("1000, 5k")),
+                option("valid_days", json!(3)),
+            ]}]),
+        )
+        .await;
+    assert_eq!(replies.len(), 1);
+    let embed = &replies[0].embed.as_ref().expect("no embed").0;
+    assert!(text(embed, "description").contains("6,000 R$: **£21.00**"));
+    let (pdf, filename) = replies[0].file.as_ref().expect("no quotation attached");
+    assert!(filename.starts_with("quotation-Q-") && filename.ends_with(".pdf"));
+    // Text in the PDF is Windows-1252, where £ is 0xA3.
+    let contains = |text: &[u8]| pdf.windows(text.len()).any(|window| window == text);
+    for text in [
+        &b"(Quotation Q-"[..],
+        b"(\xA33.50)",
+        b"(\xA321.00 / $",
+        b"(Payment terms)",
+    ] {
+        assert!(
+            contains(text),
+            "{} not in the quotation",
+            String::from_utf8_lossy(text)
+        );
+    }
+}
+
+#[tokio::test]
+#[cfg(feature = "orders")]
+async fn bulk_quotes_respect_order_limits() {
+    let harness = Harness::new().await;
+    harness
+        .embed("orderlimits", json!([option("max", json!(2000))]))
+        .await;
+    let replies = harness
+        .run(
+            "quote",
+            json!([{"name": "bulk", "type": 1, "options": [
+                option("amounts", json!("1000, 5k")),
+            ]}]),
+        )
+        .await;
+    assert_eq!(replies.len(), 1);
+    assert!(replies[0].file.is_none());
+    assert_eq!(
+        replies[0].content.as_deref(),
+        Some("The largest order here is 2,000 R$. Try 2,000 R$ instead, or split it into several orders.")
+    );
+}
+
+#[tokio::test]
+async fn rewords_replies_with_the_guild_template() {
+    let harness = Harness::new().await;
+    harness
+        .embed(
+            "template",
+            json!([{"name": "set", "type": 1, "options": [
+                option("command", json!("robux")),
+                option("title", json!("{robux} for {gbp}")),
+                option("description", json!("Thanks {user}!\n{details}")),
+            ]}]),
+        )
+        .await;
+    let embed = harness
+        .embed(
+            "robux",
+            json!([
+                option("currency", json!("GBP")),
+                option("amount", json!(3.5))
+            ]),
+        )
+        .await;
+    assert_eq!(text(&embed, "title"), "1,000 R$ for £3.50");
+    let description = text(&embed, "description");
+    assert!(
+        description.starts_with(&format!("Thanks <@{}>!\n", USER_ID)),
+        "{}",
+        description
+    );
+    assert!(description.contains("1,000"), "{}", description);
+}
+
+#[tokio::test]
+async fn shows_the_guild_emojis() {
+    let harness = Harness::new().await;
+    harness
+        .embed(
+            "emojis",
+            json!([{"name": "set", "type": 1, "options": [
+                option("kind", json!("robux")),
+                option("emoji", json!("<:robux:600404340292059257>")),
+            ]}]),
+        )
+        .await;
+    let embed = harness
+        .embed(
+            "price",
+            json!([option("type", json!("b/t")), option("amount", json!("1k"))]),
+        )
+        .await;
+    let description = text(&embed, "description");
+    assert!(
+        description.contains("/ 1k <:robux:600404340292059257>"),
+        "{}",
+        description
+    );
+    assert!(!description.contains("R$"), "{}", description);
+}
+
+#[tokio::test]
+async fn quotes_in_dms_with_the_default_rates() {
+    let harness = Harness::new().await;
+    let replies = harness
+        .send(dm_command(
+            "price",
+            json!([option("type", json!("b/t")), option("amount", json!("1k"))]),
+        ))
+        .await;
+    assert_eq!(replies.len(), 1);
+    let embed = &replies[0].embed.as_ref().expect("no embed").0;
+    let description = text(embed, "description");
+    assert!(
+        description.contains(&format!(
+            "**{}:** 3,50\u{a0}£ / 1k R$",
+            t(Language::Fr, "price.rate")
+        )),
+        "{}",
+        description
+    );
+}
+
+#[tokio::test]
+async fn logs_who_changed_the_rates() {
+    let harness = Harness::new().await;
+    harness
+        .embed("setrates", json!([option("sell", json!(4.2))]))
+        .await;
+    let embed = harness
+        .embed(
+            "auditlog",
+            json!([{"name": "show", "type": 1, "options": []}]),
+        )
+        .await;
+    let description = text(&embed, "description");
+    assert!(description.contains("`/setrates`"), "{}", description);
+    assert!(description.contains("**shop_rates**"), "{}", description);
+    assert!(description.contains("sell: 4.2"), "{}", description);
+}
+
+#[tokio::test]
+async fn deleted_coupons_can_be_restored_until_purged() {
+    let harness = Harness::new().await;
+    harness
+        .embed(
+            "coupon",
+            json!([{"name": "create", "type": 1, "options": [
+                option("code", json!("summer")),
+                option("value", json!(10.0)),
+            ]}]),
+        )
+        .await;
+    let replies = harness
+        .run(
+            "coupon",
+            json!([{"name": "delete", "type": 1, "options": [option("code", json!("summer"))]}]),
+        )
+        .await;
+    assert_eq!(replies.len(), 1);
+    let components = replies[0].components.as_ref().expect("no undo button");
+    let components = serde_json::to_string(&components.0).unwrap();
+    assert!(
+        components.contains(r#""undo:coupon:SUMMER""#),
+        "{}",
+        components
+    );
+
+    let storage = storage(&harness.ctx).await;
+    assert!(storage.coupon(GUILD_ID, "SUMMER").await.unwrap().is_none());
+    let since = undo::cutoff(rates::now());
+    assert!(storage
+        .restore_coupon(GUILD_ID, "SUMMER", since)
+        .await
+        .unwrap());
+    assert!(storage.coupon(GUILD_ID, "SUMMER").await.unwrap().is_some());
+
+    storage.delete_coupon(GUILD_ID, "SUMMER", 1).await.unwrap();
+    assert_eq!(storage.purge_deleted(2).await.unwrap(), 1);
+    assert!(!storage.restore_coupon(GUILD_ID, "SUMMER", 0).await.unwrap());
+}
+
+#[tokio::test]
+async fn a_removed_tier_can_be_added_again() {
+    let harness = Harness::new().await;
+    let tier = |name: &str, rate: f64| {
+        json!([{"name": name, "type": 1, "options": [
+            option("min_robux", json!(1000)),
+            option("rate", json!(rate)),
+        ]}])
+    };
+    harness.embed("tier", tier("add", 3.0)).await;
+    harness
+        .run(
+            "tier",
+            json!([{"name": "remove", "type": 1, "options": [option("min_robux", json!(1000))]}]),
+        )
+        .await;
+    harness.embed("tier", tier("add", 3.2)).await;
+
+    let storage = storage(&harness.ctx).await;
+    let config = storage.guild(GuildId(GUILD_ID)).await.unwrap();
+    assert_eq!(config.tiers.len(), 1);
+    assert_eq!(config.tiers[0].rate, 3.2);
+    // The new tier took the deleted one's place, so there's nothing to undo.
+    assert!(!storage.restore_tier(GUILD_ID, 1000, 0).await.unwrap());
+}
+
+#[tokio::test]
+async fn backups_open_as_a_database() {
+    let dir = std::env::temp_dir();
+    let path = |name: &str| dir.join(format!("{}-{}.db", name, std::process::id()));
+    let (original, copy) = (path("original"), path("copy"));
+    let storage = storage::connect(original.to_str().unwrap()).await.unwrap();
+    storage
+        .update_guild(GuildId(GUILD_ID), |config| {
+            config.rounding.mode = RoundingMode::Up
+        })
+        .await
+        .unwrap();
+    let backup = storage.backup().await.unwrap();
+    assert_eq!(backup.extension, "db");
+
+    // Restoring a SQLite backup is using the file as the database.
+    std::fs::write(&copy, &backup.data).unwrap();
+    let restored = storage::connect(copy.to_str().unwrap()).await.unwrap();
+    let config = restored.guild(GuildId(GUILD_ID)).await.unwrap();
+    for path in [original, copy] {
+        std::fs::remove_file(path).unwrap();
+    }
+    assert_eq!(config.rounding.mode, RoundingMode::Up);
+}
+
+#[tokio::test]
+async fn concurrent_guild_updates_all_land() {
+    let path = std::env::temp_dir().join(format!("concurrent-{}.db", std::process::id()));
+    let storage = storage::connect(path.to_str().unwrap()).await.unwrap();
+    let updates = (0..8).map(|channel| {
+        storage.update_guild(GuildId(GUILD_ID), move |config| {
+            config.autoreply_channels.push(channel)
+        })
+    });
+    let results = futures::future::join_all(updates).await;
+    let config = storage.guild(GuildId(GUILD_ID)).await.unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    assert!(results.iter().all(Result::is_ok), "{:?}", results);
+    let mut channels = config.autoreply_channels;
+    channels.sort();
+    assert_eq!(channels, (0..8).collect::<Vec<u64>>());
+}
+
+#[tokio::test]
+async fn old_calculations_are_purged_and_forgotten_on_request() {
+    let harness = Harness::new().await;
+    harness
+        .embed("retention", json!([option("history_days", json!(30))]))
+        .await;
+    let storage = storage(&harness.ctx).await;
+    let now = rates::now();
+    let old = now - 31 * 24 * 60 * 60;
+    for (guild_id, timestamp) in [(Some(GUILD_ID), old), (Some(GUILD_ID), now), (None, old)] {
+        let mut calculation = Calculation::new(guild_id, USER_ID, "price", 1000, 3.5, 4.8, 0.0035);
+        calculation.timestamp = timestamp;
+        storage.record_calculation(calculation).await.unwrap();
+    }
+
+    assert_eq!(storage.purge_expired(now).await.unwrap(), 1);
+    let history = storage.history(Some(GUILD_ID), USER_ID, 10).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].timestamp, now);
+    // Calculations in DMs aren't any guild's to expire.
+    assert_eq!(storage.history(None, USER_ID, 10).await.unwrap().len(), 1);
+
+    let embed = harness.embed("forgetme", json!([])).await;
+    let description = text(&embed, "description");
+    assert!(description.contains("2 calculations"), "{}", description);
+    assert!(storage.history(None, USER_ID, 10).await.unwrap().is_empty());
+    let embed = harness.embed("forgetme", json!([])).await;
+    assert_eq!(
+        text(&embed, "description"),
+        t(Language::En, "forgetme.nothing")
+    );
+}
+
+#[tokio::test]
+async fn interactions_delivered_twice_are_answered_once() {
+    let harness = Harness::new().await;
+    let price = command("price", json!([option("amount", json!("1000"))]));
+    assert_eq!(harness.send(price.clone()).await.len(), 1);
+    assert!(harness.send(price).await.is_empty());
+}
+
+#[tokio::test]
+#[cfg(feature = "orders")]
+async fn capped_coupons_run_out() {
+    let harness = Harness::new().await;
+    harness
+        .embed(
+            "coupon",
+            json!([{"name": "create", "type": 1, "options": [
+                option("code", json!("summer")),
+                option("value", json!(10.0)),
+                option("max_uses", json!(1)),
+            ]}]),
+        )
+        .await;
+    let price = json!([
+        option("type", json!("b/t")),
+        option("amount", json!("1k")),
+        option("coupon", json!("summer")),
+    ]);
+    // 10% off £3.50.
+    let embed = harness.embed("price", price.clone()).await;
+    let fields = serde_json::to_string(&embed["fields"]).unwrap();
+    assert!(fields.contains("£3.15"), "{}", fields);
+
+    let storage = storage(&harness.ctx).await;
+    let redeemed = storage.redeem_coupon(GUILD_ID, "SUMMER", 0).await.unwrap();
+    assert_eq!(redeemed.map(|coupon| coupon.uses), Some(1));
+    assert!(storage
+        .redeem_coupon(GUILD_ID, "SUMMER", 0)
+        .await
+        .unwrap()
+        .is_none());
+
+    let replies = harness.run("price", price).await;
+    assert_eq!(
+        replies[0].content.as_deref(),
+        Some(tf(Language::En, "coupon.invalid", &[("code", &"SUMMER")]).as_str())
+    );
+}
+
+#[tokio::test]
+async fn coupons_are_refused_on_quotes_while_the_feature_is_off() {
+    let harness = Harness::new().await;
+    harness
+        .embed(
+            "coupon",
+            json!([{"name": "create", "type": 1, "options": [
+                option("code", json!("summer")),
+                option("value", json!(10.0)),
+            ]}]),
+        )
+        .await;
+    harness
+        .embed(
+            "features",
+            json!([{"name": "disable", "type": 1, "options": [option("feature", json!("coupons"))]}]),
+        )
+        .await;
+    let replies = harness
+        .run(
+            "price",
+            json!([
+                option("type", json!("b/t")),
+                option("amount", json!("1k")),
+                option("coupon", json!("summer")),
+            ]),
+        )
+        .await;
+    assert_eq!(
+        replies[0].content.as_deref(),
+        Some(tf(Language::En, "features.refused", &[("feature", &"Coupons")]).as_str())
+    );
+}
+
+#[tokio::test]
+async fn worth_is_off_until_enabled() {
+    let harness = Harness::new().await;
+    let worth = json!([option("robux", json!("1000"))]);
+    let replies = harness.run("worth", worth.clone()).await;
+    assert_eq!(
+        replies[0].content.as_deref(),
+        Some(tf(Language::En, "features.refused", &[("feature", &"Worth")]).as_str())
+    );
+
+    harness
+        .embed(
+            "features",
+            json!([{"name": "enable", "type": 1, "options": [option("feature", json!("worth"))]}]),
+        )
+        .await;
+    let embed = harness.embed("worth", worth).await;
+    let fields = serde_json::to_string(&embed["fields"]).unwrap();
+    for value in ["£3.50", "£14.98", "18 minutes", "$3.80", "30,000 R$"] {
+        assert!(fields.contains(value), "{} not in {}", value, fields);
+    }
+}
+
+#[tokio::test]
+async fn fees_show_what_covers_each_payment_method() {
+    let harness = Harness::new().await;
+    harness
+        .embed(
+            "payment",
+            json!([{"name": "set", "type": 1, "options": [
+                option("method", json!("paypal")),
+                option("handle", json!("shop")),
+            ]}]),
+        )
+        .await;
+    let embed = harness.embed("fees", json!([])).await;
+    assert!(text(&embed, "description").contains("£3.50"));
+    let fields = serde_json::to_string(&embed["fields"]).unwrap();
+    // PayPal's 2.9% + £0.30 on £3.91 leaves £3.50; gamepasses list 1,429 R$.
+    for value in ["**PayPal**: 2.90% + £0.30 · £3.91", "1,429 R$", "£5.00"] {
+        assert!(fields.contains(value), "{} not in {}", value, fields);
+    }
+    assert!(!fields.contains("Cash App"), "{}", fields);
+}
+
+#[tokio::test]
+async fn rate_changes_are_listed_and_dated_on_quotes() {
+    let harness = Harness::new().await;
+    let embed = harness.embed("ratehistory", json!([])).await;
+    assert_eq!(
+        text(&embed, "description"),
+        "No rate changes have been recorded yet."
+    );
+
+    harness
+        .embed("setrates", json!([option("sell", json!(3.2))]))
+        .await;
+    // Setting the same rate again isn't a change.
+    harness
+        .embed("setrates", json!([option("sell", json!(3.2))]))
+        .await;
+    harness
+        .embed(
+            "setrate",
+            json!([{"name": "forex", "type": 1, "options": [
+                option("pair", json!("GBP/USD")),
+                option("value", json!(1.25)),
+            ]}]),
+        )
+        .await;
+
+    let embed = harness.embed("ratehistory", json!([])).await;
+    let description = text(&embed, "description");
+    let lines: Vec<&str> = description.lines().collect();
+    assert_eq!(lines.len(), 2, "{:?}", lines);
+    assert!(lines[0].contains("**Exchange rate**: market rate → GBP/USD 1.2500"));
+    assert!(lines[1].contains("**Sell rate per 1k**: £3.50 → £3.20"));
+
+    let embed = harness
+        .embed(
+            "price",
+            json!([option("type", json!("b/t")), option("amount", json!("1k"))]),
+        )
+        .await;
+    let description = text(&embed, "description");
+    assert!(
+        description.contains("£3.20 / 1k R$ (set <t:"),
+        "{}",
+        description
+    );
+}
+
+#[test]
+fn commands_are_registered_under_their_names() {
+    let mut commands = CreateApplicationCommands::default();
+    registry().define_global(&mut commands);
+    registry().define_guild(&mut commands);
+    for definition in &commands.0 {
+        let name = definition["name"].as_str().unwrap();
+        assert!(registry().get(name).is_some(), "/{} has no handler", name);
+    }
+}
+
+#[test]
+fn localized_command_names_are_unique() {
+    let mut commands = CreateApplicationCommands::default();
+    registry().define_global(&mut commands);
+    registry().define_guild(&mut commands);
+    let mut seen = HashMap::new();
+    for definition in &commands.0 {
+        let name = definition["name"].as_str().unwrap();
+        if let Some(localizations) = definition["name_localizations"].as_object() {
+            for (locale, localized) in localizations {
+                let localized = localized.as_str().unwrap();
+                if let Some(other) = seen.insert((locale.clone(), localized), name) {
+                    panic!(
+                        "/{} and /{} are both /{} in {}",
+                        other, name, localized, locale
+                    );
+                }
+            }
+        }
+    }
+}
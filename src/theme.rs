@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use serenity::builder::CreateEmbed;
+
+use crate::i18n::{t, Language};
+
+pub const DEFAULT_COLOR: u32 = 0x0096FF;
+
+/// Per-guild embed styling configured through `/theme set`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Theme {
+    pub color: Option<u32>,
+    pub footer: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+impl Theme {
+    /// Styles `embed` with the theme, falling back to the default color.
+    pub fn apply(&self, embed: &mut CreateEmbed) {
+        embed.color(self.color.unwrap_or(DEFAULT_COLOR));
+
+        if let Some(footer) = &self.footer {
+            embed.footer(|f| f.text(footer));
+        }
+        if let Some(thumbnail) = &self.thumbnail {
+            embed.thumbnail(thumbnail);
+        }
+    }
+}
+
+/// Parses a hex color such as `#FF8800` or `ff8800`.
+pub fn parse_color(input: &str, lang: Language) -> Result<u32, String> {
+    let hex = input.trim().trim_start_matches('#');
+    if hex.len() != 6 {
+        return Err(t(lang, "theme.invalid_color").to_string());
+    }
+
+    u32::from_str_radix(hex, 16).map_err(|_| t(lang, "theme.invalid_color").to_string())
+}
+
+pub fn validate_thumbnail(url: &str, lang: Language) -> Result<String, String> {
+    let url = url.trim();
+    if url.starts_with("https://") || url.starts_with("http://") {
+        Ok(url.to_string())
+    } else {
+        Err(t(lang, "theme.invalid_thumbnail").to_string())
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "orders")]
+use serenity::model::channel::Message;
+
+/// How many messages are kept in a closed ticket's transcript.
+#[cfg(feature = "orders")]
+pub const MAX_TRANSCRIPT_MESSAGES: usize = 1000;
+
+/// Per-guild ticket settings configured through `/ticket setup`.
+#[derive(Clone, Debug, Default)]
+pub struct TicketSettings {
+    /// Category new ticket channels are created under.
+    pub category: Option<u64>,
+    /// Role that can see and close every ticket.
+    pub staff_role: Option<u64>,
+    /// Channel closed tickets' transcripts are posted to.
+    pub log_channel: Option<u64>,
+    /// Role that confirms payments held by a middleman, for `/order middleman`.
+    pub middleman_role: Option<u64>,
+}
+
+/// Builds a channel name such as `ticket-some-user` from the buyer's username.
+#[cfg(feature = "orders")]
+pub fn channel_name(username: &str) -> String {
+    let name: String = username
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .take(90)
+        .collect();
+    let name = name.trim_matches('-');
+
+    if name.is_empty() {
+        "ticket".to_string()
+    } else {
+        format!("ticket-{}", name)
+    }
+}
+
+/// Formats `messages`, oldest first, as a plain text transcript.
+#[cfg(feature = "orders")]
+pub fn transcript(messages: &[Message]) -> String {
+    let mut transcript = String::new();
+
+    for message in messages {
+        transcript.push_str(&format!(
+            "[{}] {}: {}\n",
+            message.timestamp,
+            message.author.tag(),
+            message.content
+        ));
+        for embed in &message.embeds {
+            if let Some(title) = &embed.title {
+                transcript.push_str(&format!("    [embed] {}\n", title));
+            }
+        }
+        for attachment in &message.attachments {
+            transcript.push_str(&format!("    [attachment] {}\n", attachment.url));
+        }
+    }
+
+    transcript
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// A bulk pricing tier: orders of at least `min_robux` are charged `rate` GBP per 1,000 Robux.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Tier {
+    pub min_robux: u64,
+    pub rate: f64,
+}
+
+impl Tier {
+    pub fn rate_per_robux(&self) -> f64 {
+        self.rate / 1000.0
+    }
+}
+
+/// Returns the tier with the highest threshold that `amount` reaches.
+/// `tiers` must be sorted by `min_robux`.
+pub fn tier_for(tiers: &[Tier], amount: u64) -> Option<&Tier> {
+    tiers.iter().rev().find(|tier| amount >= tier.min_robux)
+}
+
+/// Inserts `tier`, replacing any tier with the same threshold and keeping the list sorted.
+pub fn upsert(tiers: &mut Vec<Tier>, tier: Tier) {
+    tiers.retain(|existing| existing.min_robux != tier.min_robux);
+    tiers.push(tier);
+    tiers.sort_by_key(|tier| tier.min_robux);
+}
@@ -0,0 +1,72 @@
+/// How long a deleted tier, coupon or order can be brought back with the Undo
+/// button before it's purged for good.
+pub const UNDO_SECONDS: u64 = 10 * 60;
+
+/// A tier, coupon or order that was deleted and may still be restored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Deleted {
+    /// A price tier, by its `min_robux`.
+    Tier(u64),
+    Coupon(String),
+    Order(u64),
+}
+
+impl Deleted {
+    /// What the Undo button's custom id carries after its prefix, such as
+    /// `tier:1000`.
+    pub fn key(&self) -> String {
+        match self {
+            Deleted::Tier(min_robux) => format!("tier:{}", min_robux),
+            Deleted::Coupon(code) => format!("coupon:{}", code),
+            Deleted::Order(id) => format!("order:{}", id),
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        let (kind, value) = key.split_once(':')?;
+        match kind {
+            "tier" => value.parse().ok().map(Deleted::Tier),
+            "coupon" if !value.is_empty() => Some(Deleted::Coupon(value.to_string())),
+            "order" => value.parse().ok().map(Deleted::Order),
+            _ => None,
+        }
+    }
+
+    /// What the audit log records the deletion under, such as `order #42`.
+    pub fn label(&self) -> String {
+        match self {
+            Deleted::Tier(min_robux) => format!("tier {}", min_robux),
+            Deleted::Coupon(code) => format!("coupon {}", code),
+            Deleted::Order(id) => format!("order #{}", id),
+        }
+    }
+}
+
+/// Deletions made before this can no longer be undone.
+pub fn cutoff(now: u64) -> u64 {
+    now.saturating_sub(UNDO_SECONDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_round_trip() {
+        for deleted in [
+            Deleted::Tier(1000),
+            Deleted::Coupon("SUMMER:10".to_string()),
+            Deleted::Order(42),
+        ] {
+            assert_eq!(Deleted::from_key(&deleted.key()), Some(deleted));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert_eq!(Deleted::from_key("tier:many"), None);
+        assert_eq!(Deleted::from_key("coupon:"), None);
+        assert_eq!(Deleted::from_key("vouch:1"), None);
+        assert_eq!(Deleted::from_key("order"), None);
+    }
+}
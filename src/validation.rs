@@ -0,0 +1,118 @@
+use crate::{
+    i18n::{tf, Language},
+    loyalty::MAX_DISCOUNT_PERCENT,
+    numbers,
+    orders::OrderLimits,
+};
+
+// These limits mirror the min/max constraints set on the command options at registration.
+
+pub const MIN_ROBUX_AMOUNT: u64 = 1;
+pub const MAX_ROBUX_AMOUNT: u64 = 10_000_000;
+pub const MIN_FIAT_AMOUNT: f64 = 0.01;
+pub const MAX_FIAT_AMOUNT: f64 = 100_000.0;
+pub const MAX_TIER_RATE: f64 = 1_000.0;
+
+pub fn validate_robux(amount: u64, lang: Language) -> Result<u64, String> {
+    if amount < MIN_ROBUX_AMOUNT {
+        return Err(tf(
+            lang,
+            "validation.robux_min",
+            &[("min", &numbers::integer(lang, MIN_ROBUX_AMOUNT as f64))],
+        ));
+    }
+    if amount > MAX_ROBUX_AMOUNT {
+        return Err(tf(
+            lang,
+            "validation.robux_max",
+            &[("max", &numbers::integer(lang, MAX_ROBUX_AMOUNT as f64))],
+        ));
+    }
+    Ok(amount)
+}
+
+/// Checks `amount` is within the guild's order limits, suggesting the nearest
+/// amount it would take when it isn't.
+pub fn validate_order_size(
+    amount: u64,
+    limits: &OrderLimits,
+    lang: Language,
+) -> Result<u64, String> {
+    if let Some(min) = limits.min_robux.filter(|min| amount < *min) {
+        return Err(tf(
+            lang,
+            "validation.order_min",
+            &[("min", &numbers::integer(lang, min as f64))],
+        ));
+    }
+    if let Some(max) = limits.max_robux.filter(|max| amount > *max) {
+        return Err(tf(
+            lang,
+            "validation.order_max",
+            &[("max", &numbers::integer(lang, max as f64))],
+        ));
+    }
+    Ok(amount)
+}
+
+pub fn validate_fiat(amount: f64, lang: Language) -> Result<f64, String> {
+    if !amount.is_finite() || amount < MIN_FIAT_AMOUNT {
+        return Err(tf(
+            lang,
+            "validation.fiat_min",
+            &[("min", &numbers::decimal(lang, MIN_FIAT_AMOUNT, 2))],
+        ));
+    }
+    if amount > MAX_FIAT_AMOUNT {
+        return Err(tf(
+            lang,
+            "validation.fiat_max",
+            &[("max", &numbers::integer(lang, MAX_FIAT_AMOUNT))],
+        ));
+    }
+    Ok(amount)
+}
+
+/// Checks `username` follows Roblox's rules: 3 to 20 letters, digits or
+/// underscores, not starting or ending with an underscore.
+#[cfg(any(feature = "orders", feature = "roblox"))]
+pub fn validate_roblox_username(username: &str, lang: Language) -> Result<String, String> {
+    let username = username.trim();
+    let valid = (3..=20).contains(&username.len())
+        && username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !username.starts_with('_')
+        && !username.ends_with('_')
+        && username.matches('_').count() <= 1;
+    if !valid {
+        return Err(tf(
+            lang,
+            "validation.roblox_username",
+            &[("username", &username)],
+        ));
+    }
+    Ok(username.to_string())
+}
+
+pub fn validate_tier_rate(rate: f64, lang: Language) -> Result<f64, String> {
+    if !rate.is_finite() || rate <= 0.0 || rate > MAX_TIER_RATE {
+        return Err(tf(
+            lang,
+            "validation.tier_rate",
+            &[("max", &format!("{:.0}", MAX_TIER_RATE))],
+        ));
+    }
+    Ok(rate)
+}
+
+pub fn validate_discount_percent(percent: f64, lang: Language) -> Result<f64, String> {
+    if !percent.is_finite() || percent <= 0.0 || percent > MAX_DISCOUNT_PERCENT {
+        return Err(tf(
+            lang,
+            "validation.discount_percent",
+            &[("max", &format!("{:.0}", MAX_DISCOUNT_PERCENT))],
+        ));
+    }
+    Ok(percent)
+}
@@ -0,0 +1,49 @@
+/// Highest VAT rate `/vat set` accepts, in percent.
+pub const MAX_VAT_RATE: f64 = 50.0;
+
+/// A guild's VAT or sales tax, added on top of quoted prices.
+#[derive(Clone, Debug)]
+pub struct VatSettings {
+    /// Percent, e.g. `20.0` for UK VAT.
+    pub rate: f64,
+    /// Shown next to the rate, e.g. `UK` or `DE`.
+    pub region: String,
+}
+
+impl VatSettings {
+    /// The tax due on a tax-exclusive amount.
+    pub fn tax_on(&self, net: f64) -> f64 {
+        net * self.rate / 100.0
+    }
+}
+
+/// Uppercases a region code such as `uk`, returning `None` unless it's 2 to 8 letters.
+pub fn normalize_region(region: &str) -> Option<String> {
+    let region = region.trim().to_uppercase();
+    ((2..=8).contains(&region.len()) && region.chars().all(|c| c.is_ascii_alphabetic()))
+        .then_some(region)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn taxes_the_net_amount() {
+        let vat = VatSettings {
+            rate: 20.0,
+            region: "UK".to_string(),
+        };
+        assert!((vat.tax_on(3.5) - 0.7).abs() < 1e-9);
+        assert_eq!(vat.tax_on(0.0), 0.0);
+    }
+
+    #[test]
+    fn normalizes_region_codes() {
+        assert_eq!(normalize_region(" uk "), Some("UK".to_string()));
+        assert_eq!(normalize_region("EU"), Some("EU".to_string()));
+        assert_eq!(normalize_region("u"), None);
+        assert_eq!(normalize_region("UK-1"), None);
+        assert_eq!(normalize_region("ABCDEFGHI"), None);
+    }
+}
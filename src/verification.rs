@@ -0,0 +1,98 @@
+#[cfg(feature = "roblox")]
+use ring::rand::{SecureRandom, SystemRandom};
+
+/// Words in a verification phrase. Plain dictionary words, since Roblox tags
+/// over long numbers and codes in profile blurbs.
+#[cfg(feature = "roblox")]
+const PHRASE_WORDS: usize = 6;
+/// How long a user has to put the phrase in their profile.
+#[cfg(feature = "roblox")]
+pub const VERIFICATION_EXPIRY_SECONDS: u64 = 30 * 60;
+
+#[cfg(feature = "roblox")]
+const WORDS: [&str; 32] = [
+    "apple", "breeze", "candle", "dolphin", "ember", "forest", "garden", "harbor", "island",
+    "jungle", "kettle", "lantern", "meadow", "nectar", "orchid", "pebble", "quartz", "river",
+    "saddle", "timber", "umbrella", "velvet", "willow", "yonder", "zephyr", "anchor", "bamboo",
+    "canyon", "desert", "falcon", "glacier", "hollow",
+];
+
+/// A Discord user's Roblox account, confirmed with `/verify`.
+#[derive(Clone, Debug)]
+pub struct RobloxLink {
+    #[cfg_attr(not(feature = "roblox"), allow(dead_code))]
+    pub user_id: u64,
+    pub roblox_user_id: u64,
+    pub roblox_username: String,
+    /// Unix timestamp in seconds.
+    #[cfg_attr(not(feature = "roblox"), allow(dead_code))]
+    pub verified_at: u64,
+}
+
+/// A `/verify` waiting for the user to put `phrase` in their Roblox profile.
+#[cfg(feature = "roblox")]
+#[derive(Clone, Debug)]
+pub struct PendingVerification {
+    pub user_id: u64,
+    pub roblox_user_id: u64,
+    pub roblox_username: String,
+    pub phrase: String,
+    /// Unix timestamp in seconds.
+    pub created_at: u64,
+}
+
+#[cfg(feature = "roblox")]
+impl PendingVerification {
+    pub fn is_expired(&self, now: u64) -> bool {
+        now.saturating_sub(self.created_at) > VERIFICATION_EXPIRY_SECONDS
+    }
+}
+
+/// A random phrase for a user to put in their Roblox profile.
+#[cfg(feature = "roblox")]
+pub fn new_phrase() -> Result<String, String> {
+    let mut bytes = [0u8; PHRASE_WORDS];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| "Error generating verification phrase".to_string())?;
+    Ok(phrase(&bytes))
+}
+
+#[cfg(feature = "roblox")]
+fn phrase(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| WORDS[*byte as usize % WORDS.len()])
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether `description` contains `phrase`, ignoring case and how the words are
+/// spaced or split across lines.
+#[cfg(feature = "roblox")]
+pub fn contains_phrase(description: &str, phrase: &str) -> bool {
+    let words = |text: &str| {
+        text.split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    words(description).contains(&words(phrase))
+}
+
+#[cfg(all(test, feature = "roblox"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_one_word_per_byte() {
+        assert_eq!(phrase(&[0, 1, 33]), "apple breeze breeze");
+    }
+
+    #[test]
+    fn finds_phrase_regardless_of_case_and_spacing() {
+        let phrase = "apple breeze candle";
+        assert!(contains_phrase("Hi!\nApple  breeze\ncandle :)", phrase));
+        assert!(!contains_phrase("apple candle breeze", phrase));
+    }
+}
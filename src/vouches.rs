@@ -0,0 +1,38 @@
+/// Highest rating a vouch can give.
+pub const MAX_STARS: u8 = 5;
+
+/// How many recent comments `/reputation` shows.
+pub const RECENT_VOUCHES: usize = 5;
+
+/// A buyer's rating of the seller who handled one of their orders.
+#[derive(Clone, Debug)]
+pub struct Vouch {
+    pub guild_id: u64,
+    /// The closed order being vouched for; each order can be vouched for once.
+    pub order_id: u64,
+    pub buyer_id: u64,
+    pub seller_id: u64,
+    pub stars: u8,
+    pub comment: String,
+    /// Unix timestamp in seconds.
+    pub created_at: u64,
+}
+
+/// A seller's vouch tally in one guild.
+#[derive(Clone, Debug, Default)]
+pub struct Reputation {
+    pub total: u64,
+    pub average: f64,
+    /// Newest first.
+    pub recent: Vec<Vouch>,
+}
+
+/// Renders a rating such as `★★★★☆`.
+pub fn stars(rating: u8) -> String {
+    let rating = rating.min(MAX_STARS) as usize;
+    format!(
+        "{}{}",
+        "★".repeat(rating),
+        "☆".repeat(MAX_STARS as usize - rating)
+    )
+}
@@ -0,0 +1,140 @@
+#[cfg(feature = "orders")]
+use ring::hmac;
+#[cfg(feature = "orders")]
+use serde_json::json;
+#[cfg(feature = "orders")]
+use std::{sync::OnceLock, time::Duration};
+
+#[cfg(feature = "orders")]
+use crate::{export, orders::Order, rates, secrets};
+
+/// How many webhook URLs a guild can register.
+pub const MAX_WEBHOOKS: usize = 5;
+
+/// Longest webhook URL `/webhook add` accepts.
+pub const MAX_URL_LENGTH: u16 = 500;
+
+/// Header carrying the hex HMAC-SHA256 of the body, keyed with the webhook's secret.
+pub const SIGNATURE_HEADER: &str = "X-Signature-256";
+
+/// Order lifecycle events posted to a guild's webhooks.
+#[cfg(feature = "orders")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderEvent {
+    /// A buyer accepted a quote with `/buy` or the order form and a ticket was
+    /// opened. There's no separate quote-accepted event; this is it.
+    Created,
+    /// The middleman confirmed they received the buyer's payment.
+    Paid,
+    /// Staff marked the Robux as delivered with `/order complete`.
+    Completed,
+    /// The ticket was closed.
+    Closed,
+}
+
+#[cfg(feature = "orders")]
+impl OrderEvent {
+    pub fn code(self) -> &'static str {
+        match self {
+            OrderEvent::Created => "order.created",
+            OrderEvent::Paid => "order.paid",
+            OrderEvent::Completed => "order.completed",
+            OrderEvent::Closed => "order.closed",
+        }
+    }
+}
+
+/// A URL registered with `/webhook add`.
+#[derive(Clone, Debug)]
+pub struct Webhook {
+    pub url: String,
+    /// Hex key the receiver uses to verify [`SIGNATURE_HEADER`].
+    pub secret: String,
+}
+
+/// Returns `url` if it's an absolute `https` URL.
+pub fn validate_url(url: &str) -> Option<String> {
+    let url = url.trim();
+    let parsed = reqwest::Url::parse(url).ok()?;
+    (parsed.scheme() == "https" && parsed.host_str().is_some()).then(|| url.to_string())
+}
+
+/// `sha256=` followed by the hex HMAC-SHA256 of `body`.
+#[cfg(feature = "orders")]
+pub fn signature(secret: &str, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    format!("sha256={}", secrets::hex(hmac::sign(&key, body).as_ref()))
+}
+
+/// Adds `webhook`, returning `false` if the URL is already registered.
+pub fn add(webhooks: &mut Vec<Webhook>, webhook: Webhook) -> bool {
+    if webhooks.iter().any(|existing| existing.url == webhook.url) {
+        return false;
+    }
+    webhooks.push(webhook);
+    true
+}
+
+/// Removes the webhook for `url`, returning whether one existed.
+pub fn remove(webhooks: &mut Vec<Webhook>, url: &str) -> bool {
+    let before = webhooks.len();
+    webhooks.retain(|webhook| webhook.url != url);
+    webhooks.len() != before
+}
+
+#[cfg(feature = "orders")]
+fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_default()
+    })
+}
+
+/// Posts `event` for `order` to each webhook in the background. Deliveries aren't
+/// retried; failures are logged.
+///
+/// The body is `{"event", "timestamp", "guild_id", "order"}`, where `event` is one of
+/// `order.created` (the quote was accepted), `order.paid`, `order.completed` or
+/// `order.closed`.
+#[cfg(feature = "orders")]
+pub fn dispatch(webhooks: &[Webhook], event: OrderEvent, order: &Order) {
+    if webhooks.is_empty() {
+        return;
+    }
+    let body = json!({
+        "event": event.code(),
+        "timestamp": rates::now(),
+        "guild_id": order.guild_id.to_string(),
+        "order": export::order_json(order),
+    })
+    .to_string();
+
+    for webhook in webhooks.iter().cloned() {
+        let body = body.clone();
+        tokio::spawn(async move {
+            let result = client()
+                .post(&webhook.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(
+                    SIGNATURE_HEADER,
+                    signature(&webhook.secret, body.as_bytes()),
+                )
+                .header("X-Webhook-Event", event.code())
+                .body(body)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            if let Err(error) = result {
+                eprintln!(
+                    "Error delivering {} webhook to {}: {}",
+                    event.code(),
+                    webhook.url,
+                    error
+                );
+            }
+        });
+    }
+}
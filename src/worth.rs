@@ -0,0 +1,19 @@
+/// The UK National Living Wage per hour for workers 21 and over, from April
+/// 2025, which `/worth` measures prices in.
+pub const UK_MINIMUM_WAGE_GBP: f64 = 12.21;
+
+/// USD Roblox pays creators per earned Robux through the Developer Exchange.
+pub const DEVEX_USD_PER_ROBUX: f64 = 0.0038;
+
+/// The fewest earned Robux a creator can cash out through DevEx.
+pub const DEVEX_MIN_ROBUX: u64 = 30_000;
+
+/// Hours someone on the UK minimum wage works to earn `gbp`, before tax.
+pub fn hours_of_work(gbp: f64) -> f64 {
+    gbp / UK_MINIMUM_WAGE_GBP
+}
+
+/// What Roblox would pay a creator for `robux` earned Robux.
+pub fn devex_usd(robux: u64) -> f64 {
+    robux as f64 * DEVEX_USD_PER_ROBUX
+}